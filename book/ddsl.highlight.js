@@ -2,9 +2,9 @@
 // In comparison, this uses hljs 10, is written in JS instead of TS and uses `attr` for the node types instead of `type`
 
 function ddslLanguage(hljs) {
-    const nodeTypes = "manifest device register command buffer block extern enum field fieldset";
+    const nodeTypes = "manifest device register command buffer block extern enum field fieldset template const";
     const KEYWORDS = "default catch-all allow as stride try";
-    const typeKeywords = "bool int uint u8 u16 u32 u64 i8 i16 i32 i64 LE BE _ RO RW WO mapped indexed";
+    const typeKeywords = "bool int uint bytes ascii bcd gray sign-magnitude ones-complement u8 u16 u32 u64 i8 i16 i32 i64 LE BE _ RO RW WO mapped indexed";
 
     return {
         name: 'ddsl',
@@ -30,7 +30,7 @@ function ddslLanguage(hljs) {
             },
             {
                 className: "punctuation",
-                begin: /(->)|\*|,/
+                begin: /(->)|(\.\.=)|(<<)|(>>)|[*+\-\/|&=()]|,/
             },
             {
                 className: 'title',
@@ -1,5 +1,5 @@
 use clap::{Parser, Subcommand};
-use device_driver_core::CompileOptions;
+use device_driver_core::{CompileOptions, FsFileLoader, SourceMap};
 use device_driver_diagnostics::{DynError, Metadata, ResultExt};
 use std::{io::Write, path::PathBuf, process::ExitCode};
 
@@ -87,8 +87,10 @@ fn build(args: BuildArgs) -> Result<ExitCode, DynError> {
     let source = std::fs::read_to_string(&source_path)
         .with_message(|| format!("Failed to open input file at: {:?}", source_path.display()))?;
 
-    let (output, diagnostics) = device_driver_core::compile(&source, args.options)
-        .with_message(|| "internal compilation error")?;
+    let mut source_map = SourceMap::new(source_path.display().to_string(), source);
+    let (output, diagnostics) =
+        device_driver_core::compile(&mut source_map, &FsFileLoader::default(), args.options)
+            .with_message(|| "internal compilation error")?;
 
     let diagnostics_has_error = diagnostics.has_error();
 
@@ -96,8 +98,7 @@ fn build(args: BuildArgs) -> Result<ExitCode, DynError> {
         .print_to(
             std::io::stderr().lock(),
             Metadata {
-                source: &source,
-                source_path: &source_path.display().to_string(),
+                source_map: &source_map,
                 term_width: None,
                 ansi: true,
                 unicode: true,
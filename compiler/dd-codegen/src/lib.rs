@@ -1,4 +1,5 @@
 use clap::Subcommand;
+use device_driver_common::source_map::SourceMap;
 use device_driver_lir::model::Driver;
 use itertools::Itertools;
 
@@ -29,10 +30,10 @@ impl Target {
     }
 }
 
-pub fn codegen(target: &Target, lir_driver: &Driver, source: &str) -> String {
+pub fn codegen(target: &Target, lir_driver: &Driver, source_map: &SourceMap) -> String {
     match target {
         Target::Rust(codegen_options) => {
-            rust::DriverTemplateRust::new(lir_driver, source, codegen_options).to_string()
+            rust::DriverTemplateRust::new(lir_driver, source_map, codegen_options).to_string()
         }
     }
 }
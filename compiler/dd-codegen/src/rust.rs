@@ -1,15 +1,18 @@
-use std::ops::Range;
-
 use askama::Template;
 use clap::Parser;
 use convert_case::Case;
 use device_driver_common::{
     identifier::{Identifier, IdentifierType, Type},
-    specifiers::{Access, AddressMode},
+    source_map::SourceMap,
+    span::Spanned,
+    specifiers::{Access, AddressMode, Bus, Encoding, LevelUnit},
 };
 use device_driver_lir::model::{
-    BlockMethod, BlockMethodType, Driver, Field, FieldConversionMethod, Repeat,
+    Block, BlockMethod, BlockMethodType, Composite, CompositePart, Device, Driver, EnumVariant,
+    Field, FieldConversionMethod, FieldSet, FieldValue, FifoLevel, IndexOverride, Interface,
+    Repeat, Routine, StateMachine, Statement, Transition, Value,
 };
+use itertools::Itertools;
 
 #[derive(Parser, Debug, Clone, Default)]
 #[command(no_binary_name = true, bin_name = "")]
@@ -21,25 +24,28 @@ pub struct RustCodegenOptions {
         require_equals = true
     )]
     pub defmt_feature: Option<String>,
+    /// When specified, the field setters check if the value is in range of the field with a `debug_assert`
+    #[arg(long = "rust-debug-assert-setters")]
+    pub debug_assert_setters: bool,
 }
 
 #[derive(Template)]
 #[template(path = "rust/driver.rs.j2", escape = "none", whitespace = "minimize")]
 pub struct DriverTemplateRust<'a> {
     driver: &'a Driver,
-    source: &'a str,
+    source_map: &'a SourceMap,
     codegen_options: &'a RustCodegenOptions,
 }
 
 impl<'a> DriverTemplateRust<'a> {
     pub fn new(
         device: &'a Driver,
-        source: &'a str,
+        source_map: &'a SourceMap,
         codegen_options: &'a RustCodegenOptions,
     ) -> Self {
         Self {
             driver: device,
-            source,
+            source_map,
             codegen_options,
         }
     }
@@ -48,6 +54,33 @@ impl<'a> DriverTemplateRust<'a> {
         self.codegen_options.defmt_feature.as_deref()
     }
 
+    fn debug_assert_setters(&self) -> bool {
+        self.codegen_options.debug_assert_setters
+    }
+
+    /// Emits a compile error for every pair of chip variants that are enabled at the same time
+    fn chip_variants_guard(&self) -> String {
+        use std::fmt::Write;
+
+        let mut guard = String::new();
+
+        for [left, right] in self
+            .driver
+            .devices
+            .iter()
+            .flat_map(|device| device.chip_variants.iter().array_combinations())
+            .unique()
+        {
+            writeln!(
+                &mut guard,
+                "#[cfg(all(feature = \"{left}\", feature = \"{right}\"))]\ncompile_error!(\"the chip variants `{left}` and `{right}` can't be enabled at the same time\");"
+            )
+            .unwrap();
+        }
+
+        guard
+    }
+
     fn get_block_method_docs(&self, method: &BlockMethod) -> String {
         use std::fmt::Write;
 
@@ -58,6 +91,8 @@ impl<'a> DriverTemplateRust<'a> {
             BlockMethodType::Register { .. } => "Register",
             BlockMethodType::Command { .. } => "Command",
             BlockMethodType::Buffer { .. } => "Buffer",
+            BlockMethodType::Memory { .. } => "Memory",
+            BlockMethodType::Fifo { .. } => "Fifo",
         };
 
         if !method.description.is_empty() {
@@ -67,27 +102,121 @@ impl<'a> DriverTemplateRust<'a> {
         writeln!(&mut docs, "/// {} operation:", operation_type).unwrap();
         writeln!(&mut docs, "/// - Address: `{}`", method.address).unwrap();
 
-        let reset_value_text = match &method.method_type {
-            BlockMethodType::Register { reset_value, .. } => Some(
-                reset_value
-                    .as_ref()
-                    .map(|reset_value| {
-                        self.source
-                            .get(Range::from(reset_value.span))
-                            .unwrap_or("error: invalid span")
-                    })
-                    .unwrap_or("0"),
-            ),
-            _ => None,
-        };
+        if let BlockMethodType::Register {
+            reset_value,
+            reset_from_fields,
+            ..
+        } = &method.method_type
+        {
+            match reset_value {
+                Some(reset_value) if *reset_from_fields => writeln!(
+                    &mut docs,
+                    "/// - Reset value: `[{}]` (from the field resets)",
+                    reset_value
+                        .value
+                        .iter()
+                        .map(|byte| format!("{byte:#04X}"))
+                        .join(", ")
+                ),
+                Some(reset_value) => writeln!(
+                    &mut docs,
+                    "/// - Reset value: `{}`",
+                    self.source_map
+                        .span_text(reset_value.span)
+                        .unwrap_or("error: invalid span")
+                ),
+                None => writeln!(&mut docs, "/// - Reset value: `0`"),
+            }
+            .unwrap();
+        }
 
-        if let Some(reset_value_text) = reset_value_text {
-            writeln!(&mut docs, "/// - Reset value: `{reset_value_text}`").unwrap();
+        if let BlockMethodType::Memory {
+            size_bytes,
+            word_size_bytes,
+            page_size_bytes,
+            write_granularity_bytes,
+            erase_size_bytes,
+            ..
+        } = &method.method_type
+        {
+            writeln!(&mut docs, "/// - Size: `{size_bytes}` bytes").unwrap();
+            if *word_size_bytes != 1 {
+                writeln!(&mut docs, "/// - Word size: `{word_size_bytes}` bytes").unwrap();
+            }
+            if let Some(page_size_bytes) = page_size_bytes {
+                writeln!(&mut docs, "/// - Page size: `{page_size_bytes}` bytes").unwrap();
+            }
+            if write_granularity_bytes != word_size_bytes {
+                writeln!(
+                    &mut docs,
+                    "/// - Write granularity: `{write_granularity_bytes}` bytes"
+                )
+                .unwrap();
+            }
+            if let Some(erase_size_bytes) = erase_size_bytes {
+                writeln!(&mut docs, "/// - Erase size: `{erase_size_bytes}` bytes").unwrap();
+            }
         }
 
-        if let Repeat::Count { count, .. } = method.repeat {
-            writeln!(&mut docs, "/// - Index range: `0..{count}`").unwrap();
-        };
+        if let BlockMethodType::Fifo {
+            record_size_bytes,
+            level,
+            ..
+        } = &method.method_type
+        {
+            writeln!(&mut docs, "/// - Record size: `{record_size_bytes}` bytes").unwrap();
+            if let Some(level) = level {
+                writeln!(
+                    &mut docs,
+                    "/// - Fill level: `{}` of the register at `{}`, counting {}s",
+                    level.field.to_case(Case::Snake),
+                    level.register_address,
+                    level.unit
+                )
+                .unwrap();
+            }
+        }
+
+        match &method.repeat {
+            Repeat::None | Repeat::Enum { .. } => {}
+            repeat => {
+                writeln!(
+                    &mut docs,
+                    "/// - Index range: `{}`",
+                    get_index_range(repeat)
+                )
+                .unwrap();
+            }
+        }
+
+        if !method.chip_variants.is_empty() {
+            writeln!(
+                &mut docs,
+                "/// - Chip variants: {}",
+                method
+                    .chip_variants
+                    .iter()
+                    .map(|variant| format!("`{variant}`"))
+                    .join(", ")
+            )
+            .unwrap();
+        }
+
+        docs
+    }
+
+    fn get_register_view_docs(&self, method: &BlockMethod, view: &Identifier<Type>) -> String {
+        use std::fmt::Write;
+
+        let mut docs = self.get_block_method_docs(method);
+        writeln!(
+            &mut docs,
+            "/// - View of: [`{}`](Self::{}) as [`{}`]",
+            method.name.to_case(Case::Snake),
+            method.name.to_case(Case::Snake),
+            view.to_case(Case::Pascal)
+        )
+        .unwrap();
 
         docs
     }
@@ -143,6 +272,58 @@ fn get_enum_base_type<'d>(driver: &'d Driver, enum_name: &Identifier<Type>) -> &
         .base_type
 }
 
+/// The match pattern of the values an enum variant covers
+fn get_variant_pattern(variant: &EnumVariant) -> String {
+    variant
+        .value_ranges
+        .iter()
+        .map(|range| {
+            if range.start == range.end {
+                range.start.to_string()
+            } else {
+                format!("{}..={}", range.start, range.end)
+            }
+        })
+        .join(" | ")
+}
+
+fn has_operations(block: &Block) -> bool {
+    block
+        .methods
+        .iter()
+        .any(|method| !matches!(method.method_type, BlockMethodType::Block { .. }))
+}
+
+fn get_field_set<'d>(driver: &'d Driver, field_set_name: &Identifier<Type>) -> &'d FieldSet {
+    driver
+        .field_sets
+        .iter()
+        .find(|fs| fs.name == *field_set_name)
+        .expect("This fieldset reference is checked in a mir pass")
+}
+
+fn get_reset_integer(field_set: &FieldSet, reset_value: &Option<Spanned<Vec<u8>>>) -> String {
+    let value = reset_value
+        .as_ref()
+        .map(|reset_value| field_set.bytes_to_integer(&reset_value.value))
+        .unwrap_or_default();
+
+    format!("{value:#X}")
+}
+
+/// The layout of a memory that is passed to its operation
+fn get_memory_layout(
+    size_bytes: &u32,
+    word_size_bytes: &u32,
+    page_size_bytes: &Option<u32>,
+    write_granularity_bytes: &u32,
+    erase_size_bytes: &Option<u32>,
+) -> String {
+    format!(
+        "::device_driver::MemoryLayout {{ size_bytes: {size_bytes}, word_size_bytes: {word_size_bytes}, page_size_bytes: {page_size_bytes:?}, write_granularity_bytes: {write_granularity_bytes}, erase_size_bytes: {erase_size_bytes:?} }}"
+    )
+}
+
 fn get_address_mode_const_value(value: &Option<AddressMode>) -> &'static str {
     match value {
         Some(AddressMode::Mapped) => "::device_driver::MappedAddressMode",
@@ -151,6 +332,625 @@ fn get_address_mode_const_value(value: &Option<AddressMode>) -> &'static str {
     }
 }
 
+fn get_encoding_type(encoding: &Encoding) -> &'static str {
+    match encoding {
+        Encoding::Bcd => "::device_driver::ops::Bcd",
+        Encoding::Gray => "::device_driver::ops::Gray",
+        Encoding::SignMagnitude => "::device_driver::ops::SignMagnitude",
+        Encoding::OnesComplement => "::device_driver::ops::OnesComplement",
+    }
+}
+
+/// The cfg attribute that only enables an item for the given chip variants
+fn chip_variants_cfg(chip_variants: &[String]) -> String {
+    if chip_variants.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "#[cfg(any({}))]",
+        chip_variants
+            .iter()
+            .map(|variant| format!("feature = \"{variant}\""))
+            .join(", ")
+    )
+}
+
+/// The return type and where clause of a block method on the given block type
+fn get_block_method_return_type(block: &Block, method: &BlockMethod, block_type: &str) -> String {
+    match &method.method_type {
+        BlockMethodType::Block { name } => format!("{}<'_, I>", name.to_case(Case::Pascal)),
+        BlockMethodType::Register {
+            field_set_name,
+            access,
+            ..
+        } => get_register_operation_type(block, method, field_set_name, access, block_type),
+        BlockMethodType::Command {
+            field_set_name_in,
+            field_set_name_out,
+        } => format!(
+            "::device_driver::CommandOperation<'_, {block_type}, {}, {}, {}, {}> where I: ::device_driver::CommandInterfaceBase<AddressType = {}>",
+            block.command_address_type,
+            get_command_fieldset_name(field_set_name_in),
+            get_command_fieldset_name(field_set_name_out),
+            get_repeat_type(block, method),
+            block.command_address_type
+        ),
+        BlockMethodType::Buffer { access } => format!(
+            "::device_driver::BufferOperation<'_, {block_type}, {}, ::device_driver::{access}> where I: ::device_driver::BufferInterfaceBase<AddressType = {}>",
+            block.buffer_address_type, block.buffer_address_type
+        ),
+        BlockMethodType::Memory {
+            access,
+            erase_size_bytes,
+            ..
+        } => format!(
+            "::device_driver::MemoryOperation<'_, {block_type}, {}, ::device_driver::{access}, {}> where I: ::device_driver::MemoryInterfaceBase<AddressType = {}>",
+            block.memory_address_type,
+            if erase_size_bytes.is_some() {
+                "::device_driver::Erasable"
+            } else {
+                "()"
+            },
+            block.memory_address_type
+        ),
+        BlockMethodType::Fifo {
+            record_field_set_name,
+            level,
+            ..
+        } => format!(
+            "::device_driver::FifoOperation<'_, {block_type}, {}, {}, {}> where I: ::device_driver::RegisterInterfaceBase<AddressType = {}>",
+            record_field_set_name.to_case(Case::Pascal),
+            block.register_address_type,
+            match level {
+                Some(level) => format!(
+                    "::device_driver::RegisterLevel<{}, {}>",
+                    block.register_address_type,
+                    level.field_set_name.to_case(Case::Pascal)
+                ),
+                None => "::device_driver::NoLevel".into(),
+            },
+            block.register_address_type
+        ),
+    }
+}
+
+/// The fill level that is passed to the operation of a fifo
+fn get_fifo_level(block: &Block, level: &Option<FifoLevel>, record_size_bytes: &u32) -> String {
+    let Some(level) = level else {
+        return "::device_driver::NoLevel".into();
+    };
+
+    let available = match level.unit {
+        LevelUnit::Record => "".into(),
+        LevelUnit::Byte => format!(" / {record_size_bytes}"),
+    };
+
+    format!(
+        "::device_driver::RegisterLevel::new((self.base_address + {}) as {}, |level: &{}| level.{}() as usize{available})",
+        level.register_address,
+        block.register_address_type,
+        level.field_set_name.to_case(Case::Pascal),
+        level.field.to_case(Case::Snake),
+    )
+}
+
+/// The return type and where clause of a register operation on the given block type
+fn get_register_operation_type(
+    block: &Block,
+    method: &BlockMethod,
+    field_set_name: &Identifier<Type>,
+    access: &Access,
+    block_type: &str,
+) -> String {
+    // When an index narrows the access, the operations at an index have to check it
+    let narrowed_by_index = matches!(
+        &method.method_type,
+        BlockMethodType::Register { index_overrides, .. }
+            if index_overrides.iter().any(|index_override| index_override.access != *access)
+    );
+    let access = if narrowed_by_index {
+        format!("::device_driver::PerIndex<::device_driver::{access}>")
+    } else {
+        format!("::device_driver::{access}")
+    };
+
+    format!(
+        "::device_driver::RegisterOperation<'_, {block_type}, {}, {}, {access}, {}> where I: ::device_driver::RegisterInterfaceBase<AddressType = {}>",
+        field_set_name.to_case(Case::Pascal),
+        block.register_address_type,
+        get_repeat_type(block, method),
+        block.register_address_type
+    )
+}
+
+/// The range of the index of a repeat as shown in the docs. Enum indices are shown by the name of the enum
+fn get_index_range(repeat: &Repeat) -> String {
+    match repeat {
+        Repeat::None => String::new(),
+        Repeat::Count { count, .. } => format!("0..{count}"),
+        Repeat::Enum { enum_name, .. } => enum_name.to_case(Case::Pascal),
+        Repeat::Offsets { offsets } => format!("0..{}", offsets.len()),
+        Repeat::Nested { outer, inner } => {
+            format!("({}, {})", get_index_range(outer), get_index_range(inner))
+        }
+    }
+}
+
+fn get_repeat_type(block: &Block, method: &BlockMethod) -> String {
+    get_dimension_repeat_type(block, method, &method.repeat)
+}
+
+fn get_dimension_repeat_type(block: &Block, method: &BlockMethod, repeat: &Repeat) -> String {
+    match repeat {
+        Repeat::None => "()".into(),
+        Repeat::Count { count, stride } => {
+            format!("::device_driver::ArrayRepeat<{count}, {stride}>")
+        }
+        Repeat::Enum {
+            enum_name, stride, ..
+        } => format!(
+            "::device_driver::EnumRepeat<{}, {stride}>",
+            enum_name.to_case(Case::Pascal)
+        ),
+        Repeat::Offsets { .. } => format!(
+            "::device_driver::ListRepeat<{}::{}::Offsets>",
+            block.name.to_case(Case::Snake),
+            method.name.to_case(Case::Snake)
+        ),
+        Repeat::Nested { outer, inner } => format!(
+            "::device_driver::NestedRepeat<{}, {}>",
+            get_dimension_repeat_type(block, method, outer),
+            get_dimension_repeat_type(block, method, inner)
+        ),
+    }
+}
+
+/// The index overrides of a register, passed to the operation of the given fieldset
+fn get_index_overrides(
+    index_overrides: &[IndexOverride],
+    field_set_name: &Identifier<Type>,
+) -> String {
+    if index_overrides.is_empty() {
+        return String::new();
+    }
+
+    let field_set_name = field_set_name.to_case(Case::Pascal);
+    let overrides = index_overrides
+        .iter()
+        .map(|index_override| {
+            format!(
+                "::device_driver::IndexOverride {{ index: {}, reset: {}, readable: {}, writable: {} }}",
+                index_override.index,
+                match &index_override.reset_value {
+                    Some(reset_value) => format!(
+                        "Some(|| {field_set_name}::from([{}]))",
+                        reset_value.iter().join(", ")
+                    ),
+                    None => "None".into(),
+                },
+                index_override.access.is_readable(),
+                index_override.access.is_writable(),
+            )
+        })
+        .join(", ");
+
+    format!(
+        ".with_index_overrides({{ const OVERRIDES: &[::device_driver::IndexOverride<{field_set_name}>] = &[{overrides}]; OVERRIDES }})"
+    )
+}
+
+/// The parameters of a block method, without the receiver
+fn get_block_method_parameters(method: &BlockMethod) -> String {
+    match (&method.method_type, &method.repeat) {
+        (BlockMethodType::Block { .. }, Repeat::Count { .. } | Repeat::Offsets { .. }) => {
+            "index: usize".into()
+        }
+        (BlockMethodType::Block { .. }, Repeat::Enum { enum_name, .. }) => {
+            format!("index: {}", enum_name.to_case(Case::Pascal))
+        }
+        _ => String::new(),
+    }
+}
+
+/// Describes the framing of the interface in its docs
+fn get_interface_docs(interface: &Interface) -> String {
+    use std::fmt::Write;
+
+    let mut docs = String::new();
+
+    let bus = match interface.bus {
+        Bus::Spi => "SPI device",
+        Bus::I2c => "I2C bus",
+    };
+
+    writeln!(
+        &mut docs,
+        "/// Register interface over an embedded-hal {bus}:"
+    )
+    .unwrap();
+    writeln!(
+        &mut docs,
+        "/// - Address: {} byte(s) in {} byte order",
+        interface.address_size_bytes, interface.address_byte_order
+    )
+    .unwrap();
+
+    for (name, bit) in [
+        ("Read bit", interface.read_bit),
+        ("Write bit", interface.write_bit),
+        ("Increment bit", interface.increment_bit),
+    ] {
+        if let Some(bit) = bit {
+            writeln!(&mut docs, "/// - {name}: `{bit}`").unwrap();
+        }
+    }
+
+    if interface.dummy_bytes > 0 {
+        writeln!(
+            &mut docs,
+            "/// - Dummy bytes when reading: `{}`",
+            interface.dummy_bytes
+        )
+        .unwrap();
+    }
+
+    docs
+}
+
+/// Describes the statements of the routine in its docs. The links point to methods of the block type.
+fn get_routine_docs(routine: &Routine, block_type: &str) -> String {
+    use std::fmt::Write;
+
+    let mut docs = String::new();
+
+    if !routine.description.is_empty() {
+        writeln!(&mut docs, "///").unwrap();
+    }
+
+    writeln!(&mut docs, "/// Routine:").unwrap();
+
+    let link = |name: &Identifier<_>| {
+        format!(
+            "[`{}`]({block_type}::{})",
+            name.to_case(Case::Snake),
+            name.to_case(Case::Snake)
+        )
+    };
+    let assignments = |fields: &[FieldValue], operator: &str| {
+        fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "`{} {operator} {}`",
+                    field.name.to_case(Case::Snake),
+                    routine_value(&field.value)
+                )
+            })
+            .join(", ")
+    };
+
+    for statement in &routine.statements {
+        let line = match statement {
+            Statement::Write { register, fields } if fields.is_empty() => {
+                format!("Write {}", link(register))
+            }
+            Statement::Write { register, fields } => {
+                format!("Write {}: {}", link(register), assignments(fields, "="))
+            }
+            Statement::Modify { register, fields } => {
+                format!("Modify {}: {}", link(register), assignments(fields, "="))
+            }
+            Statement::Poll {
+                register,
+                fields,
+                max_attempts,
+                interval,
+                interval_unit,
+            } => {
+                format!(
+                    "Poll {} until {}, at most `{max_attempts}` time(s) with `{interval}` {interval_unit} in between",
+                    link(register),
+                    assignments(fields, "==")
+                )
+            }
+            Statement::Dispatch {
+                command, fields, ..
+            } if fields.is_empty() => format!("Dispatch {}", link(command)),
+            Statement::Dispatch {
+                command, fields, ..
+            } => format!("Dispatch {}: {}", link(command), assignments(fields, "=")),
+            Statement::Delay { duration, unit } => format!("Delay `{duration}` {unit}"),
+            Statement::Return { register, .. } => format!("Return {}", link(register)),
+        };
+        writeln!(&mut docs, "/// - {line}").unwrap();
+    }
+
+    if !routine.chip_variants.is_empty() {
+        writeln!(
+            &mut docs,
+            "/// - Chip variants: {}",
+            routine
+                .chip_variants
+                .iter()
+                .map(|variant| format!("`{variant}`"))
+                .join(", ")
+        )
+        .unwrap();
+    }
+
+    docs
+}
+
+/// The where clause with the bounds on the interface the routine needs
+fn get_routine_where_clause(block: &Block, routine: &Routine, is_async: &bool) -> String {
+    let prefix = if *is_async { "Async" } else { "" };
+
+    match (routine.uses_registers(), routine.uses_commands()) {
+        (true, true) => format!(
+            "where I: ::device_driver::{prefix}RegisterInterface<AddressType = {}> + ::device_driver::{prefix}CommandInterface<AddressType = {}, Error = <I as ::device_driver::RegisterInterfaceBase>::Error>",
+            block.register_address_type, block.command_address_type
+        ),
+        (true, false) => get_register_where_clause(block, is_async),
+        (false, true) => format!(
+            "where I: ::device_driver::{prefix}CommandInterface<AddressType = {}>",
+            block.command_address_type
+        ),
+        (false, false) => String::new(),
+    }
+}
+
+/// The where clause for methods that only use the registers of the block
+fn get_register_where_clause(block: &Block, is_async: &bool) -> String {
+    let prefix = if *is_async { "Async" } else { "" };
+
+    format!(
+        "where I: ::device_driver::{prefix}RegisterInterface<AddressType = {}>",
+        block.register_address_type
+    )
+}
+
+/// The error of the interface the routine uses. Routines that poll can also time out
+fn get_routine_error_type(routine: &Routine) -> String {
+    let interface_error = match (routine.uses_registers(), routine.uses_commands()) {
+        (true, _) => "<I as ::device_driver::RegisterInterfaceBase>::Error",
+        (false, true) => "<I as ::device_driver::CommandInterfaceBase>::Error",
+        (false, false) => "::core::convert::Infallible",
+    };
+
+    if routine.uses_poll() {
+        format!("::device_driver::RoutineError<{interface_error}>")
+    } else {
+        interface_error.into()
+    }
+}
+
+fn get_root_block(device: &Device) -> &Block {
+    device
+        .blocks
+        .iter()
+        .find(|block| block.root)
+        .expect("Every device has a root block")
+}
+
+/// Describes the target state and the routine or statements of the transition in its docs
+fn get_transition_docs(
+    state_machine: &StateMachine,
+    transition: &Transition,
+    device_type: &str,
+) -> String {
+    use std::fmt::Write;
+
+    let mut docs = String::new();
+
+    if !transition.description.is_empty() {
+        writeln!(&mut docs, "///").unwrap();
+    }
+
+    writeln!(
+        &mut docs,
+        "/// Transition to [`{}`]({}::{}).",
+        transition.to.to_case(Case::Pascal),
+        state_machine.name.to_case(Case::Snake),
+        transition.to.to_case(Case::Pascal)
+    )
+    .unwrap();
+
+    if transition.calls_routine {
+        writeln!(
+            &mut docs,
+            "/// Runs [`{}`]({device_type}::{}).",
+            transition.routine.name.to_case(Case::Snake),
+            transition.routine.name.to_case(Case::Snake)
+        )
+        .unwrap();
+    } else if !transition.routine.statements.is_empty() {
+        writeln!(&mut docs, "///").unwrap();
+        docs.push_str(&get_routine_docs(&transition.routine, device_type));
+    }
+
+    docs
+}
+
+/// Describes the parts of the composite and the order of the registers in the docs of its read or write method.
+/// The links point to methods of the block type.
+fn get_composite_docs(composite: &Composite, block_type: &str, write: bool) -> String {
+    use std::fmt::Write;
+
+    let mut docs = String::new();
+
+    if !composite.description.is_empty() {
+        writeln!(&mut docs, "///").unwrap();
+    }
+
+    let link = |name: &Identifier<_>| {
+        format!(
+            "[`{}`]({block_type}::{})",
+            name.to_case(Case::Snake),
+            name.to_case(Case::Snake)
+        )
+    };
+
+    writeln!(
+        &mut docs,
+        "/// Composite value, least significant part first:"
+    )
+    .unwrap();
+
+    for part in &composite.parts {
+        writeln!(
+            &mut docs,
+            "/// - `{}:{}`: `{}` of {}",
+            part.shift + part.size_bits - 1,
+            part.shift,
+            part.field.to_case(Case::Snake),
+            link(&part.register)
+        )
+        .unwrap();
+    }
+
+    let (verb, order, bulk) = if write {
+        ("Written", &composite.write_order, composite.bulk_write)
+    } else {
+        ("Read", &composite.read_order, composite.bulk_read)
+    };
+    writeln!(
+        &mut docs,
+        "/// - {verb} {}: {}",
+        if bulk {
+            "in one bulk transaction"
+        } else {
+            "in this order"
+        },
+        order.iter().map(link).join(", ")
+    )
+    .unwrap();
+
+    if write && !composite.modified.is_empty() {
+        writeln!(
+            &mut docs,
+            "/// - Read first to keep their other fields: {}",
+            composite.modified.iter().map(link).join(", ")
+        )
+        .unwrap();
+    }
+
+    if !composite.chip_variants.is_empty() {
+        writeln!(
+            &mut docs,
+            "/// - Chip variants: {}",
+            composite
+                .chip_variants
+                .iter()
+                .map(|variant| format!("`{variant}`"))
+                .join(", ")
+        )
+        .unwrap();
+    }
+
+    docs
+}
+
+/// The value of the composite as Rust expression. The registers are read into variables with the name of the register
+fn get_composite_value(composite: &Composite) -> String {
+    let value = composite
+        .parts
+        .iter()
+        .map(|part| {
+            let getter = format!(
+                "{}.{}()",
+                part.register.to_case(Case::Snake),
+                part.field.to_case(Case::Snake)
+            );
+            let getter = if part.field_type == composite.base_type {
+                getter
+            } else {
+                format!("({getter} as {})", composite.base_type)
+            };
+
+            if part.shift == 0 {
+                getter
+            } else {
+                format!("{getter} << {}", part.shift)
+            }
+        })
+        .join(" | ");
+
+    // Negative values are sign extended from the highest bit of the value
+    let unused_bits = composite.base_type.size_bits() - composite.size_bits();
+    if composite.base_type.is_signed() && unused_bits > 0 {
+        format!("(({value}) << {unused_bits}) >> {unused_bits}")
+    } else {
+        value
+    }
+}
+
+/// The value the field of the part is set to when the composite is written
+fn get_composite_part_setter(composite: &Composite, part: &CompositePart) -> String {
+    let mut value = composite.name.to_case(Case::Snake);
+
+    if part.shift > 0 {
+        value = format!("({value} >> {})", part.shift);
+    }
+
+    let masked = part.size_bits < part.field_type.size_bits();
+
+    if part.field_type != composite.base_type {
+        value = if masked {
+            format!("({value} as {})", part.field_type)
+        } else {
+            format!("{value} as {}", part.field_type)
+        };
+    }
+
+    if masked {
+        value = format!("{value} & {:#x}", (1u128 << part.size_bits) - 1);
+    }
+
+    value
+}
+
+/// The value of a field in a routine as Rust expression
+fn routine_value(value: &Value) -> String {
+    match value {
+        Value::Number(number) => number.to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Variant {
+            enum_name,
+            variant_name,
+            ..
+        } => format!(
+            "{}::{}",
+            enum_name.to_case(Case::Pascal),
+            variant_name.to_case(Case::Pascal)
+        ),
+    }
+}
+
+/// The condition a field of the register in `reg` must meet in a poll statement
+fn routine_condition(field: &FieldValue) -> String {
+    let getter = format!("reg.{}()", field.name.to_case(Case::Snake));
+
+    match &field.value {
+        Value::Number(number) => format!("{getter} == {number}"),
+        Value::Bool(true) => getter,
+        Value::Bool(false) => format!("!{getter}"),
+        Value::Variant { fallible: true, .. } => {
+            format!("matches!({getter}, Ok({}))", routine_value(&field.value))
+        }
+        Value::Variant {
+            fallible: false, ..
+        } => format!("matches!({getter}, {})", routine_value(&field.value)),
+    }
+}
+
+fn option_to_rust(value: &Option<u32>) -> String {
+    match value {
+        Some(value) => format!("Some({value})"),
+        None => "None".into(),
+    }
+}
+
 fn maybe_doc_alias<T: IdentifierType>(identifier: &Identifier<T>, case: Case) -> String {
     if identifier.to_case(case) == identifier.original() {
         return String::new();
@@ -104,6 +104,9 @@ pub struct Identifier<T: IdentifierType> {
     original: Arc<String>,
     words: Arc<[String]>,
     duplicate_id: Option<NonZeroU32>,
+    /// Tells apart identifiers with the same name of objects that never exist at the same time.
+    /// Unlike the duplicate id, it's not part of the generated name.
+    exclusive_id: Option<NonZeroU32>,
     /// Must never change!
     id_type: T,
 }
@@ -130,6 +133,7 @@ impl<T: IdentifierType> Identifier<T> {
             original: Arc::new(value.into()),
             words: [value.into()].into(),
             duplicate_id: None,
+            exclusive_id: None,
             id_type,
         })
     }
@@ -230,6 +234,40 @@ impl<T: IdentifierType> Identifier<T> {
         words.join("·")
     }
 
+    /// Get the words that make up the identifier
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Create the identifier of a name that's generated from this one, e.g. `read_<name>`.
+    /// The words of this identifier are surrounded by the given words and the original text is the generated name in snake case.
+    pub fn with_surrounding_words(&self, prefix: &[&str], suffix: &[&str]) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            self.boundaries_applied,
+            "Boundaries not applied for `{}`",
+            self.original()
+        );
+
+        let words: Arc<[String]> = prefix
+            .iter()
+            .map(|word| word.to_string())
+            .chain(self.words.iter().cloned())
+            .chain(suffix.iter().map(|word| word.to_string()))
+            .collect();
+
+        Self {
+            boundaries_applied: true,
+            original: Arc::new(words.join("_")),
+            words,
+            duplicate_id: self.duplicate_id,
+            exclusive_id: self.exclusive_id,
+            id_type: self.id_type.clone(),
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.words.iter().all(String::is_empty)
     }
@@ -253,12 +291,23 @@ impl<T: IdentifierType> Identifier<T> {
         self.duplicate_id
     }
 
+    /// Set the id that tells this identifier apart from the same name of an object that never exists at the same time.
+    /// Used for objects of mutually exclusive chip variants.
+    pub fn set_exclusive_id(&mut self, val: NonZeroU32) {
+        self.exclusive_id = Some(val);
+    }
+
+    pub fn exclusive_id(&self) -> Option<NonZeroU32> {
+        self.exclusive_id
+    }
+
     pub fn to_runtime_type(self) -> Identifier<RuntimeType> {
         Identifier {
             boundaries_applied: self.boundaries_applied,
             original: self.original,
             words: self.words,
             duplicate_id: self.duplicate_id,
+            exclusive_id: self.exclusive_id,
             id_type: self.id_type.runtime_value(),
         }
     }
@@ -301,6 +350,7 @@ impl<T: IdentifierType> Identifier<T> {
             original: self.original,
             words: self.words,
             duplicate_id: self.duplicate_id,
+            exclusive_id: self.exclusive_id,
             id_type: U::default(),
         }
     }
@@ -316,6 +366,7 @@ impl<T: IdentifierType> Identifier<T> {
             original: self.original,
             words: self.words,
             duplicate_id: self.duplicate_id,
+            exclusive_id: self.exclusive_id,
             id_type: U::default(),
         }
     }
@@ -328,6 +379,7 @@ impl<T: IdentifierType + Default> Default for Identifier<T> {
             original: Default::default(),
             words: Default::default(),
             duplicate_id: Default::default(),
+            exclusive_id: Default::default(),
             id_type: T::default(),
         }
     }
@@ -337,6 +389,7 @@ impl<T: IdentifierType> std::hash::Hash for Identifier<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.original.hash(state);
         self.duplicate_id.hash(state);
+        self.exclusive_id.hash(state);
         self.id_type.runtime_value().hash(state);
     }
 }
@@ -345,6 +398,7 @@ impl<T: IdentifierType> PartialEq for Identifier<T> {
     fn eq(&self, other: &Self) -> bool {
         (self.original == other.original || self.words == other.words)
             && self.duplicate_id == other.duplicate_id
+            && self.exclusive_id == other.exclusive_id
             && self
                 .id_type
                 .runtime_value()
@@ -1,4 +1,5 @@
 pub mod identifier;
 pub mod instant;
+pub mod source_map;
 pub mod span;
 pub mod specifiers;
@@ -0,0 +1,77 @@
+use std::ops::Range;
+
+use crate::span::{FileId, Span};
+
+/// All source files of a compilation. The spans refer to these files using their [`FileId`].
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    /// Create a new source map with the file the compilation starts with.
+    /// This file gets the [`FileId::MAIN`] id.
+    pub fn new(path: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            files: vec![SourceFile {
+                path: path.into(),
+                source: source.into(),
+            }],
+        }
+    }
+
+    /// Add a file and get its id
+    pub fn add(&mut self, path: impl Into<String>, source: impl Into<String>) -> FileId {
+        self.files.push(SourceFile {
+            path: path.into(),
+            source: source.into(),
+        });
+        FileId(self.files.len() as u32 - 1)
+    }
+
+    /// Get the file with the given id
+    ///
+    /// Panics if the file doesn't exist. File ids can only be created by the source map, so this should not happen.
+    pub fn get(&self, file: FileId) -> &SourceFile {
+        &self.files[file.0 as usize]
+    }
+
+    /// Get the file the compilation started with
+    pub fn main(&self) -> &SourceFile {
+        self.get(FileId::MAIN)
+    }
+
+    /// Iterate over all files and their ids
+    pub fn iter(&self) -> impl Iterator<Item = (FileId, &SourceFile)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| (FileId(index as u32), file))
+    }
+
+    /// Get the source text the span points to, if the span is valid
+    pub fn span_text(&self, span: Span) -> Option<&str> {
+        self.files
+            .get(span.file.0 as usize)?
+            .source
+            .get(Range::from(span))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    path: String,
+    source: String,
+}
+
+impl SourceFile {
+    /// The path of the file as it should be shown to the user
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The contents of the file
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
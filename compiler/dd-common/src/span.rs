@@ -3,15 +3,40 @@ use std::{
     ops::Range,
 };
 
+/// Identifies a source file in a [`SourceMap`](crate::source_map::SourceMap)
+#[derive(Clone, Eq, PartialEq, Copy, Default, Hash, PartialOrd, Ord)]
+pub struct FileId(pub u32);
+
+impl FileId {
+    /// The id of the file the compilation was started with
+    pub const MAIN: Self = Self(0);
+}
+
+impl Debug for FileId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "file#{}", self.0)
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Copy, Default, Hash)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    /// The file the span points into
+    pub file: FileId,
 }
 
 impl Span {
     pub const fn empty() -> Self {
-        Self { start: 0, end: 0 }
+        Self {
+            start: 0,
+            end: 0,
+            file: FileId::MAIN,
+        }
+    }
+
+    pub const fn new(file: FileId, start: usize, end: usize) -> Self {
+        Self { start, end, file }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -26,11 +51,18 @@ impl Span {
 
     /// Get the combined span that encompasses both spans.
     /// The order does not matter.
+    ///
+    /// Spans in different files can't be combined. Then self is returned.
     #[must_use]
     pub fn to(&self, other: Self) -> Self {
+        if self.file != other.file {
+            return *self;
+        }
+
         Self {
             start: self.start.min(other.start),
             end: self.end.max(other.end),
+            file: self.file,
         }
     }
 
@@ -42,6 +74,7 @@ impl Span {
         Self {
             start: skip.end,
             end: self.end,
+            file: self.file,
         }
     }
 
@@ -53,6 +86,7 @@ impl Span {
         Self {
             start: start.start,
             end: self.end,
+            file: self.file,
         }
     }
 
@@ -62,20 +96,23 @@ impl Span {
         Span {
             start: self.end,
             end: self.end,
+            file: self.file,
         }
     }
 }
 
 impl chumsky::span::Span for Span {
-    type Context = ();
+    type Context = FileId;
 
     type Offset = usize;
 
-    fn new(_context: Self::Context, range: Range<Self::Offset>) -> Self {
-        range.into()
+    fn new(context: Self::Context, range: Range<Self::Offset>) -> Self {
+        Self::new(context, range.start, range.end)
     }
 
-    fn context(&self) -> Self::Context {}
+    fn context(&self) -> Self::Context {
+        self.file
+    }
 
     fn start(&self) -> Self::Offset {
         self.start
@@ -102,6 +139,7 @@ impl From<(usize, usize)> for Span {
         Self {
             start: value.0,
             end: value.1,
+            file: FileId::MAIN,
         }
     }
 }
@@ -111,6 +149,7 @@ impl From<Range<usize>> for Span {
         Self {
             start: value.start,
             end: value.end,
+            file: FileId::MAIN,
         }
     }
 }
@@ -251,6 +251,10 @@ pub enum BaseType {
     Uint,
     Int,
     FixedSize(Integer),
+    /// A byte array
+    Bytes,
+    /// A byte array that contains ascii text
+    Ascii,
 }
 
 impl BaseType {
@@ -270,6 +274,15 @@ impl BaseType {
         matches!(self, Self::FixedSize(..))
     }
 
+    /// Returns `true` if the base type is [`Bytes`] or [`Ascii`].
+    ///
+    /// [`Bytes`]: BaseType::Bytes
+    /// [`Ascii`]: BaseType::Ascii
+    #[must_use]
+    pub fn is_byte_array(&self) -> bool {
+        matches!(self, Self::Bytes | Self::Ascii)
+    }
+
     pub fn as_fixed_size(&self) -> Option<Integer> {
         if let Self::FixedSize(v) = self {
             Some(*v)
@@ -287,10 +300,129 @@ impl Display for BaseType {
             BaseType::Uint => write!(f, "uint"),
             BaseType::Int => write!(f, "int"),
             BaseType::FixedSize(integer) => write!(f, "{integer}"),
+            BaseType::Bytes => write!(f, "bytes"),
+            BaseType::Ascii => write!(f, "ascii"),
         }
     }
 }
 
+/// An alternative encoding of the integer bits of a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Encoding {
+    /// Binary coded decimal. Every 4 bits encode a decimal digit
+    Bcd,
+    /// Gray code. Consecutive values differ by only one bit
+    Gray,
+    /// The top bit is the sign and the other bits are the magnitude
+    SignMagnitude,
+    /// Negative values are the bitwise inverse of their positive counterpart
+    OnesComplement,
+}
+
+impl Encoding {
+    /// Returns true if the encoding is used for signed integers
+    #[must_use]
+    pub fn is_signed(&self) -> bool {
+        match self {
+            Encoding::Bcd | Encoding::Gray => false,
+            Encoding::SignMagnitude | Encoding::OnesComplement => true,
+        }
+    }
+
+    /// Decode the raw bits of a field with the given size.
+    /// The raw value must already be sign extended if the field has a signed base type.
+    #[must_use]
+    pub fn decode(&self, raw: i128, size_bits: u32) -> i128 {
+        match self {
+            Encoding::Bcd => {
+                let mut raw = raw;
+                let mut value = 0i128;
+                let mut multiplier = 1i128;
+
+                while raw > 0 {
+                    value += (raw & 0xF) * multiplier;
+                    multiplier *= 10;
+                    raw >>= 4;
+                }
+
+                value
+            }
+            Encoding::Gray => {
+                let mut raw = raw;
+                let mut shift = 1;
+
+                while shift < i128::BITS {
+                    raw ^= raw >> shift;
+                    shift <<= 1;
+                }
+
+                raw
+            }
+            Encoding::SignMagnitude => {
+                let magnitude = raw & ((1 << (size_bits - 1)) - 1);
+                if raw < 0 { -magnitude } else { magnitude }
+            }
+            Encoding::OnesComplement => {
+                if raw < 0 {
+                    raw + 1
+                } else {
+                    raw
+                }
+            }
+        }
+    }
+
+    /// Encode the value to the raw bits of a field with the given size.
+    /// This is the inverse of [`Self::decode`]. Only the lowest `size_bits` bits of the result are used.
+    #[must_use]
+    pub fn encode(&self, value: i128, size_bits: u32) -> i128 {
+        match self {
+            Encoding::Bcd => {
+                let mut value = value;
+                let mut raw = 0i128;
+                let mut shift = 0;
+
+                while value > 0 && shift < i128::BITS {
+                    raw |= (value % 10) << shift;
+                    value /= 10;
+                    shift += 4;
+                }
+
+                raw
+            }
+            Encoding::Gray => value ^ (value >> 1),
+            Encoding::SignMagnitude => {
+                if value < 0 {
+                    -value | (1 << (size_bits - 1))
+                } else {
+                    value
+                }
+            }
+            Encoding::OnesComplement => {
+                if value < 0 {
+                    value - 1
+                } else {
+                    value
+                }
+            }
+        }
+    }
+}
+
+impl VariantNames for Encoding {
+    const VARIANTS: &[&'static str] = &["bcd", "gray", "sign-magnitude", "ones-complement"];
+    fn name(&self) -> &'static str {
+        Self::VARIANTS[*self as usize]
+    }
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Self::VARIANTS[*self as usize])
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeConversion {
     /// The name of the type we're converting to
@@ -302,7 +434,10 @@ pub struct TypeConversion {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Repeat {
     pub source: Spanned<RepeatSource>,
+    /// The stride the index is multiplied with. Offset lists have a stride of 1 spanning the list.
     pub stride: Spanned<i128>,
+    /// The second dimension of a two-dimensional repeat. Its offsets are added to the offsets of this one
+    pub inner: Option<Box<Repeat>>,
     pub span: Span,
 }
 
@@ -310,6 +445,8 @@ pub struct Repeat {
 pub enum RepeatSource {
     Count(NonZeroU32),
     Enum(IdentifierRef<Type>),
+    /// An explicit offset for every index
+    Offsets(Vec<Spanned<i128>>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -341,6 +478,19 @@ pub enum NodeType {
     Enum,
     Extern,
     Field,
+    Template,
+    Const,
+    Interface,
+    Routine,
+    States,
+    State,
+    Transition,
+    Allowed,
+    Composite,
+    Part,
+    Memory,
+    Fifo,
+    Index,
 }
 
 impl FromStr for NodeType {
@@ -358,6 +508,19 @@ impl FromStr for NodeType {
             "enum" => Ok(Self::Enum),
             "extern" => Ok(Self::Extern),
             "field" => Ok(Self::Field),
+            "template" => Ok(Self::Template),
+            "const" => Ok(Self::Const),
+            "interface" => Ok(Self::Interface),
+            "routine" => Ok(Self::Routine),
+            "states" => Ok(Self::States),
+            "state" => Ok(Self::State),
+            "transition" => Ok(Self::Transition),
+            "allowed" => Ok(Self::Allowed),
+            "composite" => Ok(Self::Composite),
+            "part" => Ok(Self::Part),
+            "memory" => Ok(Self::Memory),
+            "fifo" => Ok(Self::Fifo),
+            "index" => Ok(Self::Index),
             _ => Err(()),
         }
     }
@@ -365,8 +528,29 @@ impl FromStr for NodeType {
 
 impl VariantNames for NodeType {
     const VARIANTS: &'static [&'static str] = &[
-        "manifest", "device", "block", "register", "command", "buffer", "fieldset", "enum",
-        "extern", "field",
+        "manifest",
+        "device",
+        "block",
+        "register",
+        "command",
+        "buffer",
+        "fieldset",
+        "enum",
+        "extern",
+        "field",
+        "template",
+        "const",
+        "interface",
+        "routine",
+        "states",
+        "state",
+        "transition",
+        "allowed",
+        "composite",
+        "part",
+        "memory",
+        "fifo",
+        "index",
     ];
     fn name(&self) -> &'static str {
         Self::VARIANTS[*self as usize]
@@ -394,6 +578,180 @@ impl AddressRange {
     }
 }
 
+/// An inclusive range of values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueRange {
+    pub start: i128,
+    /// Inclusive end
+    pub end: i128,
+}
+
+impl ValueRange {
+    /// The range of values a field can hold with the given base type, encoding and size
+    #[must_use]
+    pub fn representable(integer: Integer, encoding: Option<Encoding>, size_bits: u64) -> Self {
+        let size_bits = size_bits.min(integer.size_bits() as u64) as u32;
+
+        match encoding {
+            None if integer.is_signed() => Self {
+                start: -(1 << (size_bits - 1)),
+                end: (1 << (size_bits - 1)) - 1,
+            },
+            None | Some(Encoding::Gray) => Self {
+                start: 0,
+                end: (1 << size_bits) - 1,
+            },
+            Some(Encoding::Bcd) => Self {
+                start: 0,
+                end: 10i128.pow(size_bits / 4) - 1,
+            },
+            Some(Encoding::SignMagnitude | Encoding::OnesComplement) => Self {
+                start: -((1 << (size_bits - 1)) - 1),
+                end: (1 << (size_bits - 1)) - 1,
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i128) -> bool {
+        (self.start..=self.end).contains(&value)
+    }
+
+    /// Returns true if all values of the other range are also in this range
+    #[must_use]
+    pub fn contains_range(&self, other: &ValueRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Get the range that is covered by both ranges
+    #[must_use]
+    pub fn intersection(&self, other: &ValueRange) -> Self {
+        Self {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        }
+    }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// The bus a device interface talks over
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Bus {
+    Spi,
+    I2c,
+}
+
+impl VariantNames for Bus {
+    const VARIANTS: &[&'static str] = &["spi", "i2c"];
+    fn name(&self) -> &'static str {
+        Self::VARIANTS[*self as usize]
+    }
+}
+
+impl Display for Bus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Self::VARIANTS[*self as usize])
+    }
+}
+
+impl FromStr for Bus {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "spi" => Ok(Self::Spi),
+            "i2c" => Ok(Self::I2c),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The unit of the duration of a delay in a routine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TimeUnit {
+    Ns,
+    Us,
+    Ms,
+}
+
+impl VariantNames for TimeUnit {
+    const VARIANTS: &[&'static str] = &["ns", "us", "ms"];
+    fn name(&self) -> &'static str {
+        Self::VARIANTS[*self as usize]
+    }
+}
+
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Self::VARIANTS[*self as usize])
+    }
+}
+
+impl TimeUnit {
+    /// The amount of nanoseconds in one of this unit
+    pub const fn nanos(&self) -> u64 {
+        match self {
+            Self::Ns => 1,
+            Self::Us => 1_000,
+            Self::Ms => 1_000_000,
+        }
+    }
+}
+
+impl FromStr for TimeUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ns" => Ok(Self::Ns),
+            "us" => Ok(Self::Us),
+            "ms" => Ok(Self::Ms),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The unit the fill level of a FIFO counts in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LevelUnit {
+    #[default]
+    Record,
+    Byte,
+}
+
+impl VariantNames for LevelUnit {
+    const VARIANTS: &[&'static str] = &["record", "byte"];
+    fn name(&self) -> &'static str {
+        Self::VARIANTS[*self as usize]
+    }
+}
+
+impl Display for LevelUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Self::VARIANTS[*self as usize])
+    }
+}
+
+impl FromStr for LevelUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "record" => Ok(Self::Record),
+            "byte" => Ok(Self::Byte),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Type to specify how addresses work
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -542,6 +542,7 @@ fn convert_field(field: &Field) -> Result<Node<'static>, DynError> {
                     convert_base_type(field.base_type)
                 }
                 .with_dummy_span(),
+                encoding: None,
                 use_try: field
                     .field_conversion
                     .as_ref()
@@ -660,9 +661,12 @@ fn convert_repeat(repeat: V1Repeat) -> Result<Repeat<'static>, DynError> {
             .ok_or_else(|| DynError::new("converting repeat count"))?,
         )
         .with_dummy_span(),
-        stride: i32::try_from(repeat.stride)
-            .with_message(|| "converting repeat stride")?
-            .with_dummy_span(),
+        stride: Some(
+            i32::try_from(repeat.stride)
+                .with_message(|| "converting repeat stride")?
+                .with_dummy_span(),
+        ),
+        inner: None,
     })
 }
 
@@ -0,0 +1,244 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+};
+
+use device_driver_common::{
+    source_map::SourceMap,
+    span::{FileId, Span, Spanned},
+};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{IncludeCycle, IncludeFailed},
+};
+use device_driver_lexer::Token;
+use device_driver_parser::{Expression, Node, TypeConversion};
+
+/// The name of the property that includes the nodes of another file
+const INCLUDE_PROPERTY: &str = "include";
+
+/// Loads the files that are included by the DDSL source
+pub trait FileLoader {
+    fn load(&self, path: &Path) -> std::io::Result<String>;
+}
+
+/// Loads included files from the filesystem.
+/// Paths are relative to the root, or to the working directory if no root is given.
+#[derive(Debug, Clone, Default)]
+pub struct FsFileLoader {
+    root: PathBuf,
+}
+
+impl FsFileLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl FileLoader for FsFileLoader {
+    fn load(&self, path: &Path) -> std::io::Result<String> {
+        std::fs::read_to_string(self.root.join(path))
+    }
+}
+
+/// Loads included files from memory. Useful when there's no filesystem, like in the playground.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileLoader {
+    files: HashMap<PathBuf, String>,
+}
+
+impl MemoryFileLoader {
+    pub fn insert(&mut self, path: impl AsRef<Path>, source: impl Into<String>) {
+        self.files
+            .insert(normalize_path(path.as_ref()), source.into());
+    }
+}
+
+impl FileLoader for MemoryFileLoader {
+    fn load(&self, path: &Path) -> std::io::Result<String> {
+        self.files
+            .get(&normalize_path(path))
+            .cloned()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))
+    }
+}
+
+/// Load all files that are (transitively) included by the main file of the source map.
+///
+/// Returns the span of every include path and the id of the file it refers to.
+/// Includes that failed to load or that form a cycle are reported and left out.
+pub fn load_includes(
+    source_map: &mut SourceMap,
+    loader: &dyn FileLoader,
+    diagnostics: &mut Diagnostics,
+) -> HashMap<Span, FileId> {
+    let mut loader = IncludeLoader {
+        source_map,
+        loader,
+        diagnostics,
+        loaded_files: HashMap::new(),
+        include_stack: Vec::new(),
+        includes: HashMap::new(),
+    };
+
+    let main_path = normalize_path(Path::new(loader.source_map.main().path()));
+    loader.loaded_files.insert(main_path, FileId::MAIN);
+    loader.load_file(FileId::MAIN);
+
+    loader.includes
+}
+
+struct IncludeLoader<'a> {
+    source_map: &'a mut SourceMap,
+    loader: &'a dyn FileLoader,
+    diagnostics: &'a mut Diagnostics,
+    loaded_files: HashMap<PathBuf, FileId>,
+    include_stack: Vec<FileId>,
+    includes: HashMap<Span, FileId>,
+}
+
+impl IncludeLoader<'_> {
+    fn load_file(&mut self, file: FileId) {
+        self.include_stack.push(file);
+
+        let source_file = self.source_map.get(file);
+        let directory = Path::new(source_file.path())
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let tokens = device_driver_lexer::lex(source_file.source(), file);
+        let include_paths = find_include_paths(&tokens);
+
+        for Spanned { value: path, span } in include_paths {
+            let full_path = normalize_path(&directory.join(&path));
+
+            let included_file = match self.loaded_files.get(&full_path) {
+                Some(included_file) if self.include_stack.contains(included_file) => {
+                    self.diagnostics.add(IncludeCycle {
+                        include: span,
+                        path,
+                    });
+                    continue;
+                }
+                Some(included_file) => *included_file,
+                None => match self.loader.load(&full_path) {
+                    Ok(source) => {
+                        let included_file =
+                            self.source_map.add(full_path.display().to_string(), source);
+                        self.loaded_files.insert(full_path, included_file);
+                        self.load_file(included_file);
+                        included_file
+                    }
+                    Err(e) => {
+                        self.diagnostics.add(IncludeFailed {
+                            include: span,
+                            path,
+                            reason: match e.kind() {
+                                std::io::ErrorKind::NotFound => "file not found".into(),
+                                _ => e.to_string(),
+                            },
+                        });
+                        continue;
+                    }
+                },
+            };
+
+            self.includes.insert(span, included_file);
+        }
+
+        self.include_stack.pop();
+    }
+}
+
+/// Find all `include: "path"` properties in the tokens
+fn find_include_paths(tokens: &[Spanned<Token>]) -> Vec<Spanned<String>> {
+    tokens
+        .windows(3)
+        .filter_map(|window| match window {
+            [
+                Spanned {
+                    value: Token::Ident(INCLUDE_PROPERTY),
+                    ..
+                },
+                Spanned {
+                    value: Token::Colon,
+                    ..
+                },
+                Spanned {
+                    value: Token::String(path),
+                    span,
+                },
+            ] => Some(Spanned::new(*span, path.to_string())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Replace the include properties of the node and all its subnodes with the nodes of the included files.
+/// The included nodes are placed before the subnodes of the node itself.
+pub fn splice_includes<'src>(
+    node: &mut Node<'src>,
+    includes: &HashMap<Span, FileId>,
+    included_nodes: &HashMap<FileId, Vec<Node<'src>>>,
+) {
+    let mut spliced_nodes = Vec::new();
+
+    node.properties.retain(|property| {
+        if property.name.val != INCLUDE_PROPERTY
+            || !matches!(property.expression.value, Expression::String(_))
+        {
+            return true;
+        }
+
+        // Includes that couldn't be loaded have already been reported
+        if let Some(nodes) = includes
+            .get(&property.expression.span)
+            .and_then(|file| included_nodes.get(file))
+        {
+            spliced_nodes.extend(nodes.iter().cloned());
+        }
+
+        false
+    });
+
+    spliced_nodes.append(&mut node.sub_nodes);
+    node.sub_nodes = spliced_nodes;
+
+    for sub_node in node.sub_nodes.iter_mut() {
+        splice_includes(sub_node, includes, included_nodes);
+    }
+
+    for property in node.properties.iter_mut() {
+        if let Expression::SubNode(sub_node) = &mut property.expression.value {
+            splice_includes(sub_node, includes, included_nodes);
+        }
+    }
+
+    if let Some(type_specifier) = node.type_specifier.as_mut()
+        && let Some(TypeConversion::Subnode(sub_node)) = type_specifier.conversion.as_mut()
+    {
+        splice_includes(sub_node, includes, included_nodes);
+    }
+}
+
+/// Normalize the path without touching the filesystem by removing the `.` and `..` components where possible
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) =>
+            {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
@@ -1,17 +1,22 @@
 #![doc = include_str!(concat!("../", env!("CARGO_PKG_README")))]
 
-use std::fmt::Write;
+use std::{collections::HashMap, fmt::Write};
 
 use clap::Parser;
+use device_driver_common::span::FileId;
 use device_driver_diagnostics::{Diagnostics, DynError, ResultExt};
+use device_driver_parser::Ast;
 use itertools::Itertools;
 
 pub use device_driver_codegen::{RustCodegenOptions, Target as CodegenTarget};
+pub use device_driver_common::source_map::SourceMap;
 pub use device_driver_diagnostics::Metadata;
 pub use device_driver_mir::MirOptions;
+pub use includes::{FileLoader, FsFileLoader, MemoryFileLoader};
 
 use crate::timings::Timings;
 
+mod includes;
 mod timings;
 
 #[derive(Parser, Debug, Clone)]
@@ -45,17 +50,45 @@ pub enum TimingsMode {
     Verbose,
 }
 
-pub fn compile(source: &str, options: CompileOptions) -> Result<(String, Diagnostics), DynError> {
+pub fn compile(
+    source_map: &mut SourceMap,
+    file_loader: &dyn FileLoader,
+    options: CompileOptions,
+) -> Result<(String, Diagnostics), DynError> {
     let mut timings = Timings::new(options.general_options.timings);
     let mut diagnostics = Diagnostics::new();
 
-    let tokens = {
+    let (includes, tokens) = {
         let _t = timings.start_lexer();
-        device_driver_lexer::lex(source)
+        // Loading the includes requires lexing all files
+        let includes = includes::load_includes(source_map, file_loader, &mut diagnostics);
+        let tokens = source_map
+            .iter()
+            .map(|(file, source_file)| (file, device_driver_lexer::lex(source_file.source(), file)))
+            .collect::<Vec<_>>();
+        (includes, tokens)
     };
     let ast = {
         let _t = timings.start_parser();
-        device_driver_parser::parse(&tokens, &mut diagnostics)
+        let mut ast = Ast::default();
+        let mut included_nodes = HashMap::new();
+
+        for (file, tokens) in &tokens {
+            if *file == FileId::MAIN {
+                ast = device_driver_parser::parse(tokens, &mut diagnostics);
+            } else {
+                included_nodes.insert(
+                    *file,
+                    device_driver_parser::parse_included(tokens, &mut diagnostics),
+                );
+            }
+        }
+
+        if let Some(root_node) = ast.root_node.as_mut() {
+            includes::splice_includes(root_node, &includes, &included_nodes);
+        }
+
+        ast
     };
     let (mir, mir_timings) = {
         let _t = timings.start_mir();
@@ -69,7 +102,7 @@ pub fn compile(source: &str, options: CompileOptions) -> Result<(String, Diagnos
     };
     let mut code = {
         let _t = timings.start_codegen();
-        device_driver_codegen::codegen(&options.target, &lir, source)
+        device_driver_codegen::codegen(&options.target, &lir, source_map)
     };
 
     if !matches!(options.general_options.timings, TimingsMode::Off) {
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use annotate_snippets::{Group, Level};
-use device_driver_common::instant::Instant;
+use device_driver_common::{instant::Instant, source_map::SourceMap};
 use device_driver_diagnostics::Diagnostic;
 use device_driver_mir::PassTiming;
 
@@ -59,7 +59,7 @@ impl Diagnostic for Timings {
         false
     }
 
-    fn as_report<'a>(&'a self, _source: &'a str, _path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, _source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [Level::INFO
             .primary_title("timings")
             .element(
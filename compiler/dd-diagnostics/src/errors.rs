@@ -5,15 +5,16 @@
 
 use std::borrow::Cow;
 
-use annotate_snippets::{AnnotationKind, Group, Level, Patch, Snippet};
+use annotate_snippets::{AnnotationKind, Group, Level, Patch};
 use device_driver_common::{
     identifier::{self, Identifier, RuntimeType},
+    source_map::SourceMap,
     span::{Span, Spanned},
-    specifiers::{BaseType, Integer, NodeType},
+    specifiers::{BaseType, ByteOrder, Encoding, Integer, NodeType, ValueRange},
 };
 use itertools::Itertools;
 
-use crate::{Diagnostic, encode_ansi_url};
+use crate::{Diagnostic, SourceMapExt};
 
 #[derive(Debug)]
 pub struct IntegerFieldSizeTooBig {
@@ -28,37 +29,38 @@ impl Diagnostic for IntegerFieldSizeTooBig {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         let field_message = format!("field has a size of {} bits", self.size_bits);
 
         [
             Level::ERROR
                 .primary_title("field size exceeds 64-bit size limit")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.field_address,
                             AnnotationKind::Primary
                                 .span(self.field_address.into())
                                 .label(field_message),
-                        )
-                        .annotation(
+                        ),
+                        (
+                            self.base_type,
                             AnnotationKind::Context
                                 .span(self.base_type.into())
                                 .label("field uses an integer as base type"),
-                        )
-                        .annotation(AnnotationKind::Visible.span(self.field_set.into())),
+                        ),
+                        (
+                            self.field_set,
+                            AnnotationKind::Visible.span(self.field_set.into()),
+                        ),
+                    ]),
                 ),
             Group::with_title(
                 Level::NOTE.secondary_title("integer base types are available up to 64-bit"),
             ),
-            Group::with_title(Level::INFO.secondary_title(format!(
-                "if you need an array or string base type, please comment here: {}",
-                encode_ansi_url(
-                    "https://github.com/diondokter/device-driver/issues/131",
-                    "issue 131"
-                )
-            ))),
+            Group::with_title(Level::HELP.secondary_title(
+                "use the `bytes` or `ascii` base type if the field is a byte array or a string",
+            )),
         ]
         .to_vec()
     }
@@ -75,12 +77,12 @@ impl Diagnostic for DeviceNameNotPascal {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "device names tend to be a bit weird, so the casing is not automatically changed from the input. Because of that, they need to be roughly PascalCase shaped.";
 
         [
             Level::ERROR.primary_title("invalid device name").element(
-                Snippet::source(source).path(path).annotation(
+                source_map.snippet(self.device_name).annotation(
                     AnnotationKind::Primary
                         .span(self.device_name.into())
                         .label("device name is not Pascal cased"),
@@ -89,8 +91,8 @@ impl Diagnostic for DeviceNameNotPascal {
             Level::HELP
                 .secondary_title("device names need to be pascal-shaped")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.device_name)
                         .patch(Patch::new(self.device_name.into(), &self.suggestion)),
                 ),
             Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
@@ -112,7 +114,7 @@ impl Diagnostic for DuplicateName {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str =
             "names may not collide within their namespace. There are 4 namespaces:
 - Types: a type definition
@@ -121,10 +123,10 @@ impl Diagnostic for DuplicateName {
 - Enum variants: unique within an enum";
 
         [
-            Level::ERROR.primary_title("duplicate name found").element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(
+            Level::ERROR.primary_title("duplicate name found").elements(
+                source_map.snippets([
+                    (
+                        self.original,
                         AnnotationKind::Context
                             .span(self.original.into())
                             .label(format!(
@@ -132,14 +134,18 @@ impl Diagnostic for DuplicateName {
                                 self.original_value.original(),
                                 self.original_value.words_display()
                             )),
-                    )
-                    .annotation(AnnotationKind::Primary.span(self.duplicate.into()).label(
-                        format!(
-                            "the duplicate: {:?}, after word split: {:?}",
-                            self.duplicate_value.original(),
-                            self.duplicate_value.words_display()
-                        ),
-                    )),
+                    ),
+                    (
+                        self.duplicate,
+                        AnnotationKind::Primary
+                            .span(self.duplicate.into())
+                            .label(format!(
+                                "the duplicate: {:?}, after word split: {:?}",
+                                self.duplicate_value.original(),
+                                self.duplicate_value.words_display()
+                            )),
+                    ),
+                ]),
             ),
             Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
         ]
@@ -157,10 +163,10 @@ impl Diagnostic for EmptyEnum {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR.primary_title("enum has no variants").element(
-                Snippet::source(source).path(path).annotation(
+                source_map.snippet(self.enum_node).annotation(
                     AnnotationKind::Primary
                         .span(self.enum_node.into())
                         .label("empty enum"),
@@ -185,13 +191,13 @@ impl Diagnostic for DuplicateVariantValue {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "all enum variants must have a unique value";
 
         [
             Level::ERROR
                 .primary_title("two or more enum variants share the same value")
-                .element(Snippet::source(source).path(path).annotations(
+                .element(source_map.snippet(self.duplicates[0]).annotations(
                     self.duplicates.iter().map(|dup| {
                         AnnotationKind::Primary.span(dup.into()).label(format!(
                             "variant value is: {} ({:#X})",
@@ -205,6 +211,45 @@ impl Diagnostic for DuplicateVariantValue {
     }
 }
 
+#[derive(Debug)]
+pub struct OverlappingVariantValues {
+    pub variant: Span,
+    pub other: Span,
+    /// The first value both variants cover
+    pub value: i128,
+}
+
+impl Diagnostic for OverlappingVariantValues {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("enum variants cover the same values")
+                .element(
+                    source_map
+                        .snippet(self.variant)
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.variant.into())
+                                .label(format!("covers {} ({:#X})", self.value, self.value)),
+                        )
+                        .annotation(
+                            AnnotationKind::Context
+                                .span(self.other.into())
+                                .label("which is also covered by this variant"),
+                        ),
+                ),
+            Group::with_title(
+                Level::INFO.secondary_title("every value can be covered by only one variant"),
+            ),
+        ]
+        .to_vec()
+    }
+}
+
 #[derive(Debug)]
 pub struct EnumBadBasetype {
     pub enum_name: Span,
@@ -218,13 +263,13 @@ impl Diagnostic for EnumBadBasetype {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("invalid base type for enum")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.base_type)
                         .annotation(
                             AnnotationKind::Primary
                                 .span(self.base_type.into())
@@ -260,13 +305,13 @@ impl Diagnostic for EnumSizeBitsBiggerThanBaseType {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("enum doesn't fit its base type")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.enum_name)
                         .annotation(
                             AnnotationKind::Primary
                                 .span(self.enum_name.into())
@@ -297,7 +342,7 @@ impl Diagnostic for EnumNoAutoBaseTypeSelected {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const NOTE_TEXT: &str =
             "a variant or the size-bits is too big to fit in any of the base types";
 
@@ -305,7 +350,7 @@ impl Diagnostic for EnumNoAutoBaseTypeSelected {
             Level::ERROR
                 .primary_title("no valid base type found")
                 .element(
-                    Snippet::source(source).path(path).annotation(
+                    source_map.snippet(self.enum_name).annotation(
                         AnnotationKind::Primary
                             .span(self.enum_name.into())
                             .label("could not select a valid base type for this enum"),
@@ -330,13 +375,13 @@ impl Diagnostic for VariantValuesTooHigh {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("enum variant value is too high")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.enum_name)
                         .annotation(
                             AnnotationKind::Context
                                 .span(self.enum_name.into())
@@ -368,13 +413,13 @@ impl Diagnostic for VariantValuesTooLow {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("enum variant value is too low")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.enum_name)
                         .annotation(
                             AnnotationKind::Context
                                 .span(self.enum_name.into())
@@ -404,13 +449,13 @@ impl Diagnostic for EnumMultipleDefaults {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("enum defines more than one default variant")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.enum_name)
                         .annotation(
                             AnnotationKind::Context
                                 .span(self.enum_name.into())
@@ -449,13 +494,13 @@ impl Diagnostic for EnumMultipleCatchalls {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("enum defines more than one catch-all variant")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.enum_name)
                         .annotation(
                             AnnotationKind::Context
                                 .span(self.enum_name.into())
@@ -493,14 +538,14 @@ impl Diagnostic for ReferencedObjectDoesNotExist {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "all objects must be specified in the manifest. It's possible a previous analysis step removed it due to some error. See the previous diagnostics";
 
         [
             Level::ERROR
                 .primary_title("referenced object does not exist")
                 .element(
-                    Snippet::source(source).path(path).annotation(
+                    source_map.snippet(self.object_reference).annotation(
                         AnnotationKind::Primary
                             .span(self.object_reference.into())
                             .label("object cannot be found"),
@@ -523,25 +568,27 @@ impl Diagnostic for InvalidConversionType {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const NOTE_TEXT: &str = "the referenced object has an invalid type. Only enums and externs can be used for conversions";
 
         [
             Level::ERROR
                 .primary_title("invalid conversion type")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.object_reference,
                             AnnotationKind::Primary
                                 .span(self.object_reference.into())
                                 .label("object referenced as conversion type"),
-                        )
-                        .annotation(
+                        ),
+                        (
+                            self.referenced_object,
                             AnnotationKind::Context
                                 .span(self.referenced_object.into())
                                 .label("referenced object"),
                         ),
+                    ]),
                 ),
             Group::with_title(Level::NOTE.secondary_title(NOTE_TEXT)),
         ]
@@ -561,27 +608,32 @@ impl Diagnostic for RepeatEnumWithCatchAll {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "to be able to do all analysis passes correctly, the amount of repeats need to be statically known.
 This is not possible with an enum containing a catch-all since it can take on any value";
 
         [
             Level::ERROR
                 .primary_title("enum with catch-all used as repeat source")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.repeat_enum,
                             AnnotationKind::Primary
                                 .span(self.repeat_enum.into())
                                 .label("repeat uses enum with catch-all"),
-                        )
-                        .annotation(AnnotationKind::Visible.span(self.enum_name.into()))
-                        .annotation(
+                        ),
+                        (
+                            self.enum_name,
+                            AnnotationKind::Visible.span(self.enum_name.into()),
+                        ),
+                        (
+                            self.catch_all,
                             AnnotationKind::Context
                                 .span(self.catch_all.into())
                                 .label("catch-all specified here"),
                         ),
+                    ]),
                 ),
             Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
             Group::with_title(Level::HELP.secondary_title(
@@ -592,6 +644,54 @@ This is not possible with an enum containing a catch-all since it can take on an
     }
 }
 
+#[derive(Debug)]
+pub struct RepeatEnumWithValueRanges {
+    pub repeat_enum: Span,
+    pub enum_name: Span,
+    pub variant: Span,
+}
+
+impl Diagnostic for RepeatEnumWithValueRanges {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const INFO_TEXT: &str = "to be able to do all analysis passes correctly, the amount of repeats need to be statically known.
+This is not possible with an enum containing a variant that covers multiple values, since it can take on any of them";
+
+        [
+            Level::ERROR
+                .primary_title("enum with multiple-value variant used as repeat source")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.repeat_enum,
+                            AnnotationKind::Primary.span(self.repeat_enum.into()).label(
+                                "repeat uses enum with a variant that covers multiple values",
+                            ),
+                        ),
+                        (
+                            self.enum_name,
+                            AnnotationKind::Visible.span(self.enum_name.into()),
+                        ),
+                        (
+                            self.variant,
+                            AnnotationKind::Context
+                                .span(self.variant.into())
+                                .label("variant covering multiple values specified here"),
+                        ),
+                    ]),
+                ),
+            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
+            Group::with_title(Level::HELP.secondary_title(
+                "give every variant of the enum a single value or don't use it as repeat source",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
 #[derive(Debug)]
 pub struct RepeatMathOverflow {
     pub repeat_span: Span,
@@ -605,25 +705,27 @@ impl Diagnostic for RepeatMathOverflow {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "repeat math is done with `i32` integers to keep the runtime lean, so all calculations need to fit in a limited range";
 
         [
-            Level::ERROR.primary_title("repeat math overflow").element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(AnnotationKind::Primary.span(self.repeat_span.into()).label(
-                        format!(
+            Level::ERROR
+                .primary_title("repeat math overflow")
+                .elements(source_map.snippets([
+                    (
+                        self.repeat_span,
+                        AnnotationKind::Primary.span(self.repeat_span.into()).label(format!(
                             "repeat calculation overflows the allowed `i32` range at {}",
                             self.max_value * self.stride
-                        ),
-                    ))
-                    .annotation(
+                        )),
+                    ),
+                    (
+                        self.max_value_span,
                         AnnotationKind::Context
                             .span(self.max_value_span.into())
                             .label(format!("biggest index of {} specified here, which gets multiplied with the stride", self.max_value)),
                     ),
-            ),
+                ])),
             Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
         ]
         .to_vec()
@@ -641,15 +743,15 @@ impl Diagnostic for ExternInvalidBaseType {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "externs must specify a fixed size integer type as their base type";
 
         [
             Level::ERROR
                 .primary_title("invalid base type for extern object")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.extern_name)
                         .annotation(
                             if self.base_type.is_some() {
                                 AnnotationKind::Context
@@ -687,12 +789,12 @@ impl Diagnostic for ExternInvalidSizeBits {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [Level::ERROR
             .primary_title("invalid size-bits value for extern object")
             .element(
-                Snippet::source(source)
-                    .path(path)
+                source_map
+                    .snippet(self.size_bits)
                     .annotation(
                         AnnotationKind::Primary
                             .span(self.size_bits.into())
@@ -718,26 +820,28 @@ impl Diagnostic for DifferentBaseTypes {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "conversions can only happen when the same base type is shared";
 
         [
             Level::ERROR
                 .primary_title("field and conversion use different base types")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.conversion,
                             AnnotationKind::Context
                                 .span(self.conversion.into())
                                 .label("conversion specified here"),
-                        )
-                        .annotation(
+                        ),
+                        (
+                            self.field,
                             AnnotationKind::Primary
                                 .span(self.field.into())
                                 .label(format!("field uses base type: {}", self.field_base_type)),
-                        )
-                        .annotation(
+                        ),
+                        (
+                            self.conversion_object,
                             AnnotationKind::Primary
                                 .span(self.conversion_object.into())
                                 .label(format!(
@@ -745,6 +849,7 @@ impl Diagnostic for DifferentBaseTypes {
                                     self.conversion_base_type
                                 )),
                         ),
+                    ]),
                 ),
             // TODO: Add help with patch
             Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
@@ -767,24 +872,27 @@ impl Diagnostic for InvalidInfallibleConversion {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("invalid infallible conversion")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(
+                .elements(
+                    source_map.snippets(
+                        [(
+                            self.conversion,
                             AnnotationKind::Primary
                                 .span(self.conversion.into())
                                 .label("conversion specified here"),
-                        )
-                        .annotations(
-                            self.context.iter().map(|c| {
-                                AnnotationKind::Context.span(c.span.into()).label(&c.value)
-                            }),
-                        )
-                        .annotation(AnnotationKind::Visible.span(self.field.into())),
+                        )]
+                        .into_iter()
+                        .chain(self.context.iter().map(|c| {
+                            (
+                                c.span,
+                                AnnotationKind::Context.span(c.span.into()).label(&*c.value),
+                            )
+                        }))
+                        .chain([(self.field, AnnotationKind::Visible.span(self.field.into()))]),
+                    ),
                 ),
             // TODO: Add patch
             Group::with_title(Level::HELP.secondary_title("mark the conversion fallible")),
@@ -812,31 +920,34 @@ impl Diagnostic for ConversionTypeTooBig {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "a field can only convert to types of equal length or smaller";
 
         [
             Level::ERROR
                 .primary_title("conversion type too big for field")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(AnnotationKind::Visible.span(self.field.into()))
-                        .annotation(
+                .elements(
+                    source_map.snippets([
+                        (self.field, AnnotationKind::Visible.span(self.field.into())),
+                        (
+                            self.field_address,
                             AnnotationKind::Primary
                                 .span(self.field_address.into())
                                 .label(format!("field is {} bits", self.field_len)),
-                        )
-                        .annotation(
+                        ),
+                        (
+                            self.conversion_type,
                             AnnotationKind::Primary
                                 .span(self.conversion_type.into())
                                 .label(format!("target type is {} bits", self.conversion_len)),
-                        )
-                        .annotation(
+                        ),
+                        (
+                            self.conversion,
                             AnnotationKind::Context
                                 .span(self.conversion.into())
                                 .label("field specifies a conversion type here"),
                         ),
+                    ]),
                 ),
             Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
         ]
@@ -855,12 +966,12 @@ impl Diagnostic for UnspecifiedByteOrder {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
                 .primary_title("unspecified byte order")
                 .element(
-                    Snippet::source(source).path(path).annotation(
+                    source_map.snippet(self.fieldset_name).annotation(
                         AnnotationKind::Primary
                             .span(self.fieldset_name.into())
                             .label("fieldset requires a byte order, but none is specified"),
@@ -870,7 +981,7 @@ impl Diagnostic for UnspecifiedByteOrder {
                 "specify the byte order on the fieldset or add a default byte order on the device",
             )
             .elements( self.properties_span.map(|properties_span| {
-                Snippet::source(source).path(path).patch(
+                source_map.snippet(properties_span).patch(
                     Patch::new(properties_span.start..properties_span.start, "byte-order: LE,\n")
                 )}
             )),
@@ -897,10 +1008,10 @@ impl Diagnostic for UnspecifiedAccess {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR.primary_title("unspecified access").element(
-                Snippet::source(source).path(path).annotation(
+                source_map.snippet(self.object_name).annotation(
                     AnnotationKind::Primary
                         .span(self.object_name.into())
                         .label("object requires an access to be specified, but none is"),
@@ -911,7 +1022,7 @@ impl Diagnostic for UnspecifiedAccess {
                     "specify the access on the object or add a `default-access` to a parent object",
                 )
                 .elements(self.properties_span.map(|properties_span| {
-                    Snippet::source(source).path(path).patch(Patch::new(
+                    source_map.snippet(properties_span).patch(Patch::new(
                         if self.short_property {
                             properties_span.end..properties_span.end
                         } else {
@@ -942,13 +1053,12 @@ impl Diagnostic for ResetValueIntTooBig {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "reset values cannot be bigger than their fieldset";
 
         [
             Level::ERROR.primary_title("reset value too big").element(
-                Snippet::source(source)
-                    .path(path)
+                source_map.snippet(self.reset_value)
                     .annotation(AnnotationKind::Primary.span(self.reset_value.into()).label(
                         format!(
                             "the reset value is specified with {} bytes, but the register only has {}",
@@ -963,6 +1073,41 @@ impl Diagnostic for ResetValueIntTooBig {
     }
 }
 
+#[derive(Debug)]
+pub struct ResetValueOutOfRange {
+    pub reset_value: Span,
+    pub field_name: String,
+    pub field_value: i128,
+    pub range: Spanned<ValueRange>,
+}
+
+impl Diagnostic for ResetValueOutOfRange {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("reset value is out of the value range of a field")
+            .element(
+                source_map
+                    .snippet(self.reset_value)
+                    .annotation(AnnotationKind::Primary.span(self.reset_value.into()).label(
+                        format!(
+                            "the reset value sets field `{}` to {}",
+                            self.field_name, self.field_value
+                        ),
+                    ))
+                    .annotation(
+                        AnnotationKind::Context
+                            .span(self.range.span.into())
+                            .label(format!("value range is `{}`", self.range.value)),
+                    ),
+            )]
+        .to_vec()
+    }
+}
+
 #[derive(Debug)]
 pub struct ResetValueArrayWrongSize {
     pub register_context: Span,
@@ -976,15 +1121,15 @@ impl Diagnostic for ResetValueArrayWrongSize {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         const INFO_TEXT: &str = "reset values must have the same size as their associated register";
 
         [
             Level::ERROR
                 .primary_title("reset value wrong size")
                 .element(
-                Snippet::source(source)
-                    .path(path)
+                source_map
+                    .snippet(self.reset_value)
                     .annotation(AnnotationKind::Primary.span(self.reset_value.into()).label(
                         format!(
                             "the reset value is specified with {} bytes while the register has {}",
@@ -1013,22 +1158,29 @@ impl Diagnostic for BoolFieldTooLarge {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
-            Level::ERROR.primary_title("bool field too large").element(
-                Snippet::source(source).path(path).annotations(
+            Level::ERROR.primary_title("bool field too large").elements(
+                source_map.snippets(
                     [
-                        Some(
+                        Some((
+                            self.address,
                             AnnotationKind::Primary
                                 .span(self.address.into())
                                 .label(format!("address is {} bits", self.address_bits)),
-                        ),
+                        )),
                         self.base_type.map(|base_type| {
-                            AnnotationKind::Context
-                                .span(base_type.into())
-                                .label("bool base type set here")
+                            (
+                                base_type,
+                                AnnotationKind::Context
+                                    .span(base_type.into())
+                                    .label("bool base type set here"),
+                            )
                         }),
-                        Some(AnnotationKind::Visible.span(self.field_set_context.into())),
+                        Some((
+                            self.field_set_context,
+                            AnnotationKind::Visible.span(self.field_set_context.into()),
+                        )),
                     ]
                     .into_iter()
                     .flatten(),
@@ -1036,11 +1188,11 @@ impl Diagnostic for BoolFieldTooLarge {
             ),
             Level::HELP
                 .secondary_title("a field with a `bool` base type can only be 1 bit large")
-                .element(Snippet::source(source).path(path).patch(Patch::new(
+                .element(source_map.snippet(self.address).patch(Patch::new(
                     self.address.into(),
                     format!("{}:{}", self.address_start, self.address_start),
                 )))
-                .element(Snippet::source(source).path(path).patch(Patch::new(
+                .element(source_map.snippet(self.address).patch(Patch::new(
                     self.address.into(),
                     format!("{}", self.address_start),
                 ))),
@@ -1050,834 +1202,3034 @@ impl Diagnostic for BoolFieldTooLarge {
 }
 
 #[derive(Debug)]
-pub struct FieldAddressExceedsFieldsetSize {
-    pub address: Span,
-    pub max_field_end: i128,
-    pub repeat_offset: Option<i128>,
-    pub fieldset_size_span: Span,
-    pub fieldset_size_bits: u32,
-}
-
-impl FieldAddressExceedsFieldsetSize {
-    fn get_repeat_message(&self) -> String {
-        match self.repeat_offset {
-            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}"),
-            None => String::new(),
-        }
-    }
+pub struct EncodingWrongBaseType {
+    pub encoding: Spanned<Encoding>,
+    pub base_type: Spanned<BaseType>,
 }
 
-impl Diagnostic for FieldAddressExceedsFieldsetSize {
+impl Diagnostic for EncodingWrongBaseType {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let expected = if self.encoding.is_signed() {
+            "a signed integer (`int`, `i8`, `i16`, `i32` or `i64`)"
+        } else {
+            "an unsigned integer (`uint`, `u8`, `u16`, `u32` or `u64`)"
+        };
+
         [
             Level::ERROR
-                .primary_title("field address exceeds fieldset size")
+                .primary_title("encoding can't be used with this base type")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(AnnotationKind::Primary.span(self.address.into()).label(
-                            format!(
-                                "address goes up to {}{}",
-                                self.max_field_end,
-                                self.get_repeat_message()
-                            ),
-                        ))
+                    source_map
+                        .snippet(self.encoding.span)
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.encoding.span.into())
+                                .label(format!("`{}` encoding used here", self.encoding.value)),
+                        )
                         .annotation(
                             AnnotationKind::Context
-                                .span(self.fieldset_size_span.into())
-                                .label(format!(
-                                    "The fieldset is only {} bits",
-                                    self.fieldset_size_bits
-                                )),
+                                .span(self.base_type.span.into())
+                                .label(format!("base type is `{}`", self.base_type.value)),
                         ),
                 ),
-            Group::with_title(Level::INFO.secondary_title(
-                "fields, including all repeats, must be fully contained in a fieldset",
-            )),
+            Group::with_title(Level::INFO.secondary_title(format!(
+                "the `{}` encoding requires {expected} as base type",
+                self.encoding.value
+            ))),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct FieldAddressNegative {
+pub struct EncodingInvalidFieldSize {
+    pub encoding: Spanned<Encoding>,
     pub address: Span,
-    pub min_field_start: i128,
-    pub repeat_offset: Option<i128>,
-    pub field_set_context: Span,
-}
-
-impl FieldAddressNegative {
-    fn get_repeat_message(&self) -> Cow<'static, str> {
-        match self.repeat_offset {
-            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}").into(),
-            None => "".into(),
-        }
-    }
+    pub address_bits: u64,
 }
 
-impl Diagnostic for FieldAddressNegative {
+impl Diagnostic for EncodingInvalidFieldSize {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let info = match self.encoding.value {
+            Encoding::Bcd => {
+                "every decimal digit of the `bcd` encoding takes 4 bits, so the field size must be a multiple of 4 bits"
+            }
+            Encoding::Gray => "the `gray` encoding can be used with any field size",
+            Encoding::SignMagnitude | Encoding::OnesComplement => {
+                "signed encodings need a sign bit and at least one value bit, so the field size must be at least 2 bits"
+            }
+        };
+
         [
             Level::ERROR
-                .primary_title("field address is negative")
+                .primary_title("field size doesn't fit the encoding")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(AnnotationKind::Primary.span(self.address.into()).label(
-                            format!(
-                                "address goes down to {}{}",
-                                self.min_field_start,
-                                self.get_repeat_message()
-                            ),
-                        ))
-                        .annotation(AnnotationKind::Visible.span(self.field_set_context.into())),
+                    source_map
+                        .snippet(self.address)
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.address.into())
+                                .label(format!("address is {} bits", self.address_bits)),
+                        )
+                        .annotation(
+                            AnnotationKind::Context
+                                .span(self.encoding.span.into())
+                                .label(format!("`{}` encoding used here", self.encoding.value)),
+                        ),
                 ),
-            Group::with_title(Level::INFO.secondary_title(
-                "fields, including all repeats, must be fully contained in a fieldset",
-            )),
+            Group::with_title(Level::INFO.secondary_title(info)),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct OverlappingFields {
-    pub field_address_1: Span,
-    pub repeat_offset_1: Option<i128>,
-    pub field_address_start_1: i128,
-    pub field_address_end_1: i128,
-    pub field_address_2: Span,
-    pub repeat_offset_2: Option<i128>,
-    pub field_address_start_2: i128,
-    pub field_address_end_2: i128,
-
-    pub field_set_context: Span,
-}
-
-impl OverlappingFields {
-    fn get_repeat_message_1(&self) -> Cow<'static, str> {
-        match self.repeat_offset_1 {
-            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}").into(),
-            None => "".into(),
-        }
-    }
-    fn get_repeat_message_2(&self) -> Cow<'static, str> {
-        match self.repeat_offset_2 {
-            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}").into(),
-            None => "".into(),
-        }
-    }
+pub struct ValueRangeWrongOrder {
+    pub range: Span,
+    pub start: i128,
+    pub end: i128,
 }
 
-impl Diagnostic for OverlappingFields {
+impl Diagnostic for ValueRangeWrongOrder {
     fn is_error(&self) -> bool {
-        false
+        true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        const HELP_TEXT: &str = "if overlap is intended, the warning can be suppressed by allowing overlap on both fields";
-        const INFO_TEXT: &str = "overlapping fields are usually the result of a copy paste mistake. This warning exists to alert to that possibility";
-
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
-            Level::WARNING.primary_title("overlapping fields").element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(
-                        AnnotationKind::Primary
-                            .span(self.field_address_1.into())
-                            .label(format!(
-                                "Field sits at address range @{}:{}{}",
-                                self.field_address_end_1 - 1,
-                                self.field_address_start_1,
-                                self.get_repeat_message_1()
-                            )),
-                    )
-                    .annotation(
+            Level::ERROR
+                .primary_title("value range specified in wrong order")
+                .element(
+                    source_map.snippet(self.range).annotation(
                         AnnotationKind::Primary
-                            .span(self.field_address_2.into())
-                            .label(format!(
-                                "Field sits at address range @{}:{}{}",
-                                self.field_address_end_2 - 1,
-                                self.field_address_start_2,
-                                self.get_repeat_message_2()
-                            )),
-                    )
-                    .annotation(AnnotationKind::Visible.span(self.field_set_context.into())),
-                // TODO: Add context annotation for where the repeats are defined
-            ),
-            // TODO: Add patch
-            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
-            Group::with_title(Level::NOTE.secondary_title(INFO_TEXT)),
+                            .span(self.range.into())
+                            .label("range must be specified as `low..=high`"),
+                    ),
+                ),
+            Level::HELP
+                .secondary_title("try switching around the numbers")
+                .element(source_map.snippet(self.range).patch(Patch::new(
+                    self.range.into(),
+                    format!("{}..={}", self.end, self.start),
+                ))),
         ]
-        .to_vec()
+        .into()
     }
 }
 
 #[derive(Debug)]
-pub struct AddressTypeUndefined {
-    pub object_name: Span,
-    pub device: Span,
-    pub properties_span: Option<Span>,
-    pub object_type: &'static str,
+pub struct ValueRangeWrongBaseType {
+    pub range: Span,
+    pub base_type: Spanned<BaseType>,
 }
 
-impl Diagnostic for AddressTypeUndefined {
+impl Diagnostic for ValueRangeWrongBaseType {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        vec![
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
             Level::ERROR
-            .primary_title(format!("{} address type not defined", self.object_type))
-            .element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(
-                        AnnotationKind::Primary
-                            .span(self.device.into())
-                            .label(format!(
-                                "this device doesn't define a {}-address-type",
-                                self.object_type
-                            )),
-                    )
-                    .annotation(
-                        AnnotationKind::Context
-                            .span(self.object_name.into())
-                            .label(format!("{} object defined here", self.object_type)),
-                    ),
-            ),
-            Level::HELP.secondary_title(
-                "add the address type as a global default or as config on the device the object is defined in"
-            ).elements(
-                self.properties_span.map(|properties_span| {
-                    Snippet::source(source).path(path).patch(
-                        Patch::new(properties_span.start..properties_span.start, format!("{}-address-type: u16\n", self.object_type))
-                    )
-                })
-            ),
+                .primary_title("value range can't be used with this base type")
+                .element(
+                    source_map
+                        .snippet(self.range)
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.range.into())
+                                .label("value range specified here"),
+                        )
+                        .annotation(
+                            AnnotationKind::Context
+                                .span(self.base_type.span.into())
+                                .label(format!("base type is `{}`", self.base_type.value)),
+                        ),
+                ),
             Group::with_title(
-                Level::INFO.secondary_title("device-driver is agnostic to the address types being used. As such, it must be manually specified")
+                Level::INFO.secondary_title(
+                    "only fields with an integer base type can have a value range",
+                ),
             ),
         ]
+        .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct AddressOutOfRange {
-    pub object: Span,
+pub struct ValueRangeOutOfBounds {
+    pub range: Spanned<ValueRange>,
     pub address: Span,
-    pub address_value_min: i128,
-    pub address_value_max: i128,
-    pub address_type_config: Span,
-    pub address_type: Integer,
+    pub representable_range: ValueRange,
 }
 
-impl Diagnostic for AddressOutOfRange {
+impl Diagnostic for ValueRangeOutOfBounds {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        vec![
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
             Level::ERROR
-                .primary_title("address out of range")
+                .primary_title("value range doesn't fit in the field")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(AnnotationKind::Primary.span(self.address.into()).label(
-                            if self.address_value_min == self.address_value_max {
-                                format!("address has value: {}", self.address_value_max,)
-                            } else {
-                                format!(
-                                    "address ranges from {} to {}",
-                                    self.address_value_min, self.address_value_max,
+                    source_map
+                        .snippet(self.range.span)
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.range.span.into())
+                                .label(format!("range is `{}`", self.range.value)),
+                        )
+                        .annotation(AnnotationKind::Context.span(self.address.into()).label(
+                            format!("field can only hold `{}`", self.representable_range),
+                        )),
+                ),
+            Group::with_title(Level::INFO.secondary_title(
+                "the representable values depend on the size, base type and encoding of the field",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ByteArrayFieldNotByteAligned {
+    pub base_type: Span,
+    pub address: Span,
+    pub address_start: u32,
+    pub address_bits: u64,
+    pub repeat_stride: Option<Spanned<i128>>,
+    pub field_set_context: Span,
+}
+
+impl Diagnostic for ByteArrayFieldNotByteAligned {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const INFO_TEXT: &str = "fields with a `bytes` or `ascii` base type are accessed per byte, so they must start on a multiple of 8 and have a size that is a multiple of 8 bits";
+
+        let address_label = match (self.address_start % 8, self.address_bits % 8) {
+            (0, 0) => "address is byte aligned".to_string(),
+            (0, _) => format!("address is {} bits", self.address_bits),
+            (_, _) => format!("address starts at bit {}", self.address_start),
+        };
+
+        [
+            Level::ERROR
+                .primary_title("byte array field is not byte aligned")
+                .elements(
+                    source_map.snippets(
+                        [
+                            Some((
+                                self.address,
+                                AnnotationKind::Primary
+                                    .span(self.address.into())
+                                    .label(address_label),
+                            )),
+                            Some((
+                                self.base_type,
+                                AnnotationKind::Context
+                                    .span(self.base_type.into())
+                                    .label("byte array base type set here"),
+                            )),
+                            self.repeat_stride.as_ref().map(|stride| {
+                                (
+                                    stride.span,
+                                    AnnotationKind::Primary.span(stride.span.into()).label(
+                                        format!("repeat moves the field by {} bits", stride.value),
+                                    ),
                                 )
-                            },
-                        ))
-                        .annotation(AnnotationKind::Visible.span(self.object.into())),
-                )
-                .element(
-                    Snippet::source(source).path(path).annotation(
-                        AnnotationKind::Context
-                            .span(self.address_type_config.into())
-                            .label(format!(
-                                "address type supports a range of {} to {}",
-                                self.address_type.min_value(),
-                                self.address_type.max_value()
+                            }),
+                            Some((
+                                self.field_set_context,
+                                AnnotationKind::Visible.span(self.field_set_context.into()),
                             )),
+                        ]
+                        .into_iter()
+                        .flatten(),
                     ),
                 ),
-            if let Some(fitting_integer) =
-                Integer::find_smallest(self.address_value_min, self.address_value_max, 0)
-            {
-                Level::HELP
-                    .secondary_title("use an address type that fits the whole range being used")
-                    .element(Snippet::source(source).path(path).patch(Patch::new(
-                        self.address_type_config.into(),
-                        fitting_integer.to_string(),
-                    )))
-            } else {
-                Group::with_title(
-                    Level::HELP
-                        .secondary_title("address is too big to fit any possible address type"),
-                )
-            },
+            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
         ]
+        .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct AddressOverlap {
-    pub address: i128,
-    pub object_1: Span,
-    pub object_1_address: Span,
-    pub object_1_size: Span,
-    pub repeat_offset_1: Option<i128>,
-    pub object_2: Span,
-    pub object_2_address: Span,
-    pub object_2_size: Span,
-    pub repeat_offset_2: Option<i128>,
+pub struct ByteArrayFieldWithConversion {
+    pub base_type: Span,
+    pub conversion: Span,
 }
 
-impl Diagnostic for AddressOverlap {
+impl Diagnostic for ByteArrayFieldWithConversion {
     fn is_error(&self) -> bool {
-        false
+        true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        let object_1_message = format!(
-            "object 1 overlaps with other object 2{}",
-            if let Some(repeat_offset) = self.repeat_offset_1 {
-                format!(" at repeat offset {repeat_offset}")
-            } else {
-                String::new()
-            }
-        );
-        let object_2_message = format!(
-            "object 2 overlaps with other object 1{}",
-            if let Some(repeat_offset) = self.repeat_offset_2 {
-                format!(" at repeat offset {repeat_offset}")
-            } else {
-                String::new()
-            }
-        );
-
-        const HELP_TEXT: &str = "if overlap is intended, the warning can be suppressed by allowing overlap on both objects";
-        const INFO_TEXT: &str = "overlapping objects are usually the result of a copy paste mistake. This warning exists to alert to that possibility";
-
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
-            Level::WARNING
-                .primary_title(format!(
-                    "address overlap at {} ({:#X})",
-                    self.address, self.address
-                ))
+            Level::ERROR
+                .primary_title("byte array field has a conversion")
                 .element(
-                    Snippet::source(source)
-                        .path(path)
+                    source_map
+                        .snippet(self.conversion)
                         .annotation(
                             AnnotationKind::Primary
-                                .span(self.object_1.into())
-                                .label(object_1_message),
+                                .span(self.conversion.into())
+                                .label("conversion specified here"),
                         )
                         .annotation(
                             AnnotationKind::Context
-                                .span(self.object_1_address.into())
-                                .label("address 1 set here"),
-                        )
-                        .annotations(
-                            (!self.object_1_size.is_empty()).then_some(
-                                AnnotationKind::Context
-                                    .span(self.object_1_size.into())
-                                    .label("size 1 set here"),
-                            ),
-                        ), // TODO: Add context annotation for where the repeat is defined
-                )
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(
+                                .span(self.base_type.into())
+                                .label("byte array base type set here"),
+                        ),
+                ),
+            Group::with_title(Level::INFO.secondary_title(
+                "fields with a `bytes` or `ascii` base type can't be converted to enums or externs",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldAddressExceedsFieldsetSize {
+    pub address: Span,
+    pub max_field_end: i128,
+    pub repeat_offset: Option<i128>,
+    pub fieldset_size_span: Span,
+    pub fieldset_size_bits: u32,
+}
+
+impl FieldAddressExceedsFieldsetSize {
+    fn get_repeat_message(&self) -> String {
+        match self.repeat_offset {
+            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}"),
+            None => String::new(),
+        }
+    }
+}
+
+impl Diagnostic for FieldAddressExceedsFieldsetSize {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("field address exceeds fieldset size")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.address,
                             AnnotationKind::Primary
-                                .span(self.object_2.into())
-                                .label(object_2_message),
-                        )
-                        .annotation(
+                                .span(self.address.into())
+                                .label(format!(
+                                    "address goes up to {}{}",
+                                    self.max_field_end,
+                                    self.get_repeat_message()
+                                )),
+                        ),
+                        (
+                            self.fieldset_size_span,
                             AnnotationKind::Context
-                                .span(self.object_2_address.into())
-                                .label("address 2 set here"),
-                        )
-                        .annotations(
-                            (!self.object_2_size.is_empty()).then_some(
-                                AnnotationKind::Context
-                                    .span(self.object_2_size.into())
-                                    .label("size 2 set here"),
-                            ),
-                        ), // TODO: Add context annotation for where the repeat is defined
+                                .span(self.fieldset_size_span.into())
+                                .label(format!(
+                                    "The fieldset is only {} bits",
+                                    self.fieldset_size_bits
+                                )),
+                        ),
+                    ]),
                 ),
-            // TODO: Add patch
-            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
-            Group::with_title(Level::NOTE.secondary_title(INFO_TEXT)),
+            Group::with_title(Level::INFO.secondary_title(
+                "fields, including all repeats, must be fully contained in a fieldset",
+            )),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidIdentifier {
-    pub error: identifier::Error,
-    pub identifier: Span,
+pub struct FieldAddressNegative {
+    pub address: Span,
+    pub min_field_start: i128,
+    pub repeat_offset: Option<i128>,
+    pub field_set_context: Span,
+}
+
+impl FieldAddressNegative {
+    fn get_repeat_message(&self) -> Cow<'static, str> {
+        match self.repeat_offset {
+            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}").into(),
+            None => "".into(),
+        }
+    }
+}
+
+impl Diagnostic for FieldAddressNegative {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("field address is negative")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.address,
+                            AnnotationKind::Primary
+                                .span(self.address.into())
+                                .label(format!(
+                                    "address goes down to {}{}",
+                                    self.min_field_start,
+                                    self.get_repeat_message()
+                                )),
+                        ),
+                        (
+                            self.field_set_context,
+                            AnnotationKind::Visible.span(self.field_set_context.into()),
+                        ),
+                    ]),
+                ),
+            Group::with_title(Level::INFO.secondary_title(
+                "fields, including all repeats, must be fully contained in a fieldset",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct OverlappingFields {
+    pub field_address_1: Span,
+    pub repeat_offset_1: Option<i128>,
+    pub field_address_start_1: i128,
+    pub field_address_end_1: i128,
+    pub field_address_2: Span,
+    pub repeat_offset_2: Option<i128>,
+    pub field_address_start_2: i128,
+    pub field_address_end_2: i128,
+
+    pub field_set_context: Span,
+}
+
+impl OverlappingFields {
+    fn get_repeat_message_1(&self) -> Cow<'static, str> {
+        match self.repeat_offset_1 {
+            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}").into(),
+            None => "".into(),
+        }
+    }
+    fn get_repeat_message_2(&self) -> Cow<'static, str> {
+        match self.repeat_offset_2 {
+            Some(repeat_offset) => format!(" with a repeat offset of {repeat_offset}").into(),
+            None => "".into(),
+        }
+    }
+}
+
+impl Diagnostic for OverlappingFields {
+    fn is_error(&self) -> bool {
+        false
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const HELP_TEXT: &str = "if overlap is intended, the warning can be suppressed by allowing overlap on both fields";
+        const INFO_TEXT: &str = "overlapping fields are usually the result of a copy paste mistake. This warning exists to alert to that possibility";
+
+        [
+            Level::WARNING.primary_title("overlapping fields").elements(
+                source_map.snippets([
+                    (
+                        self.field_address_1,
+                        AnnotationKind::Primary
+                            .span(self.field_address_1.into())
+                            .label(format!(
+                                "Field sits at address range @{}:{}{}",
+                                self.field_address_end_1 - 1,
+                                self.field_address_start_1,
+                                self.get_repeat_message_1()
+                            )),
+                    ),
+                    (
+                        self.field_address_2,
+                        AnnotationKind::Primary
+                            .span(self.field_address_2.into())
+                            .label(format!(
+                                "Field sits at address range @{}:{}{}",
+                                self.field_address_end_2 - 1,
+                                self.field_address_start_2,
+                                self.get_repeat_message_2()
+                            )),
+                    ),
+                    (
+                        self.field_set_context,
+                        AnnotationKind::Visible.span(self.field_set_context.into()),
+                    ),
+                ]),
+                // TODO: Add context annotation for where the repeats are defined
+            ),
+            // TODO: Add patch
+            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
+            Group::with_title(Level::NOTE.secondary_title(INFO_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct AddressTypeUndefined {
+    pub object_name: Span,
+    pub device: Span,
+    pub properties_span: Option<Span>,
+    pub object_type: &'static str,
+}
+
+impl Diagnostic for AddressTypeUndefined {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        vec![
+            Level::ERROR
+            .primary_title(format!("{} address type not defined", self.object_type))
+            .elements(source_map.snippets([
+                (
+                    self.device,
+                    AnnotationKind::Primary
+                        .span(self.device.into())
+                        .label(format!(
+                            "this device doesn't define a {}-address-type",
+                            self.object_type
+                        )),
+                ),
+                (
+                    self.object_name,
+                    AnnotationKind::Context
+                        .span(self.object_name.into())
+                        .label(format!("{} object defined here", self.object_type)),
+                ),
+            ])),
+            Level::HELP.secondary_title(
+                "add the address type as a global default or as config on the device the object is defined in"
+            ).elements(
+                self.properties_span.map(|properties_span| {
+                    source_map.snippet(properties_span).patch(
+                        Patch::new(properties_span.start..properties_span.start, format!("{}-address-type: u16\n", self.object_type))
+                    )
+                })
+            ),
+            Group::with_title(
+                Level::INFO.secondary_title("device-driver is agnostic to the address types being used. As such, it must be manually specified")
+            ),
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub struct AddressOutOfRange {
+    pub object: Span,
+    pub address: Span,
+    pub address_value_min: i128,
+    pub address_value_max: i128,
+    pub address_type_config: Span,
+    pub address_type: Integer,
+}
+
+impl Diagnostic for AddressOutOfRange {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        vec![
+            Level::ERROR
+                .primary_title("address out of range")
+                .element(
+                    source_map
+                        .snippet(self.address)
+                        .annotation(AnnotationKind::Primary.span(self.address.into()).label(
+                            if self.address_value_min == self.address_value_max {
+                                format!("address has value: {}", self.address_value_max,)
+                            } else {
+                                format!(
+                                    "address ranges from {} to {}",
+                                    self.address_value_min, self.address_value_max,
+                                )
+                            },
+                        ))
+                        .annotation(AnnotationKind::Visible.span(self.object.into())),
+                )
+                .element(
+                    source_map.snippet(self.address_type_config).annotation(
+                        AnnotationKind::Context
+                            .span(self.address_type_config.into())
+                            .label(format!(
+                                "address type supports a range of {} to {}",
+                                self.address_type.min_value(),
+                                self.address_type.max_value()
+                            )),
+                    ),
+                ),
+            if let Some(fitting_integer) =
+                Integer::find_smallest(self.address_value_min, self.address_value_max, 0)
+            {
+                Level::HELP
+                    .secondary_title("use an address type that fits the whole range being used")
+                    .element(
+                        source_map
+                            .snippet(self.address_type_config)
+                            .patch(Patch::new(
+                                self.address_type_config.into(),
+                                fitting_integer.to_string(),
+                            )),
+                    )
+            } else {
+                Group::with_title(
+                    Level::HELP
+                        .secondary_title("address is too big to fit any possible address type"),
+                )
+            },
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub struct AddressOverlap {
+    pub address: i128,
+    pub object_1: Span,
+    pub object_1_address: Span,
+    pub object_1_size: Span,
+    pub repeat_offset_1: Option<i128>,
+    /// The block instance that places object 1 at its offset, if any
+    pub instance_1: Option<Span>,
+    pub object_2: Span,
+    pub object_2_address: Span,
+    pub object_2_size: Span,
+    pub repeat_offset_2: Option<i128>,
+    /// The block instance that places object 2 at its offset, if any
+    pub instance_2: Option<Span>,
+}
+
+impl Diagnostic for AddressOverlap {
+    fn is_error(&self) -> bool {
+        false
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let object_1_message = format!(
+            "object 1 overlaps with other object 2{}",
+            if let Some(repeat_offset) = self.repeat_offset_1 {
+                format!(" at repeat offset {repeat_offset}")
+            } else {
+                String::new()
+            }
+        );
+        let object_2_message = format!(
+            "object 2 overlaps with other object 1{}",
+            if let Some(repeat_offset) = self.repeat_offset_2 {
+                format!(" at repeat offset {repeat_offset}")
+            } else {
+                String::new()
+            }
+        );
+
+        const HELP_TEXT: &str = "if overlap is intended, the warning can be suppressed by allowing overlap on both objects";
+        const INFO_TEXT: &str = "overlapping objects are usually the result of a copy paste mistake. This warning exists to alert to that possibility";
+
+        [
+            Level::WARNING
+                .primary_title(format!(
+                    "address overlap at {} ({:#X})",
+                    self.address, self.address
+                ))
+                .elements(
+                    source_map.snippets(
+                        [
+                            (
+                                self.object_1,
+                                AnnotationKind::Primary
+                                    .span(self.object_1.into())
+                                    .label(object_1_message),
+                            ),
+                            (
+                                self.object_1_address,
+                                AnnotationKind::Context
+                                    .span(self.object_1_address.into())
+                                    .label("address 1 set here"),
+                            ),
+                        ]
+                        .into_iter()
+                        .chain((!self.object_1_size.is_empty()).then(|| {
+                            (
+                                self.object_1_size,
+                                AnnotationKind::Context
+                                    .span(self.object_1_size.into())
+                                    .label("size 1 set here"),
+                            )
+                        }))
+                        .chain(self.instance_1.map(|instance| {
+                            (
+                                instance,
+                                AnnotationKind::Context
+                                    .span(instance.into())
+                                    .label("object 1 is placed by this block instance"),
+                            )
+                        })),
+                    ), // TODO: Add context annotation for where the repeat is defined
+                )
+                .elements(
+                    source_map.snippets(
+                        [
+                            (
+                                self.object_2,
+                                AnnotationKind::Primary
+                                    .span(self.object_2.into())
+                                    .label(object_2_message),
+                            ),
+                            (
+                                self.object_2_address,
+                                AnnotationKind::Context
+                                    .span(self.object_2_address.into())
+                                    .label("address 2 set here"),
+                            ),
+                        ]
+                        .into_iter()
+                        .chain((!self.object_2_size.is_empty()).then(|| {
+                            (
+                                self.object_2_size,
+                                AnnotationKind::Context
+                                    .span(self.object_2_size.into())
+                                    .label("size 2 set here"),
+                            )
+                        }))
+                        .chain(self.instance_2.map(|instance| {
+                            (
+                                instance,
+                                AnnotationKind::Context
+                                    .span(instance.into())
+                                    .label("object 2 is placed by this block instance"),
+                            )
+                        })),
+                    ), // TODO: Add context annotation for where the repeat is defined
+                ),
+            // TODO: Add patch
+            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
+            Group::with_title(Level::NOTE.secondary_title(INFO_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidIdentifier {
+    pub error: identifier::Error,
+    pub identifier: Span,
+}
+
+impl InvalidIdentifier {
+    pub fn new(error: identifier::Error, identifier: Span) -> Self {
+        Self { error, identifier }
+    }
+}
+
+impl Diagnostic for InvalidIdentifier {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const INFO_TEXT: &str = "identifiers are split into words using the 'word-boundaries'.\n\
+After the split the first character of the first word must be a unicode XID start character.\n\
+All other characters must be a unicode XID continue character.\n\
+\n\
+Identifiers must also be able to be converted to different casings. That means the split words must not contain `-` or `_`,\n\
+which in practice means the word-boundaries should always include those characters.";
+
+        let annotation = match &self.error {
+            identifier::Error::Empty => AnnotationKind::Primary
+                .span(self.identifier.into())
+                .label("identifier is empty"),
+            identifier::Error::EmptyAfterSplits => AnnotationKind::Primary
+                .span(self.identifier.into())
+                .label("identifier is empty after word split"),
+            identifier::Error::InvalidCharacter {
+                byte_offset: offset,
+                invalid_char: character,
+            } if !self.identifier.is_empty() => AnnotationKind::Primary
+                .span(
+                    self.identifier.start + offset
+                        ..self.identifier.start + offset + character.len_utf8(),
+                )
+                .label(format!(
+                    "`{character}` (or `{}`) is not a valid character",
+                    character.escape_unicode()
+                )),
+            identifier::Error::InvalidCharacter {
+                byte_offset: _,
+                invalid_char: character,
+            } => AnnotationKind::Primary
+                .span(self.identifier.into())
+                .label(format!(
+                    "`{character}` (or `{}`) is not a valid character",
+                    character.escape_unicode()
+                )),
+            e @ identifier::Error::CannotConvert { .. } => AnnotationKind::Primary
+                .span(self.identifier.into())
+                .label(e.to_string()),
+        };
+
+        [
+            Level::ERROR
+                .primary_title("invalid identifier")
+                .element(source_map.snippet(self.identifier).annotation(annotation)),
+            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidAutoIdentifier {
+    pub auto_identifier: Span,
+}
+
+impl Diagnostic for InvalidAutoIdentifier {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const INFO_TEXT: &str = "auto identifiers can only be used in places where there's a parent node of which the name can be taken";
+
+        [
+            Level::ERROR.primary_title("invalid identifier").element(
+                source_map.snippet(self.auto_identifier).annotation(
+                    AnnotationKind::Primary
+                        .span(self.auto_identifier.into())
+                        .label("auto identifier can't be used here"),
+                ),
+            ),
+            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsingError {
+    pub reason: String,
+    pub span: Span,
+}
+
+impl Diagnostic for ParsingError {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("parsing error").element(
+            source_map.snippet(self.span).annotation(
+                AnnotationKind::Primary
+                    .span(self.span.into())
+                    .label(&self.reason),
+            ),
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownNodeType {
+    pub node_type: Span,
+    pub allowed_node_types: Vec<NodeType>,
+}
+
+impl Diagnostic for UnknownNodeType {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("Unknown node type").element(
+            source_map.snippet(self.node_type).annotation(
+                AnnotationKind::Primary
+                    .span(self.node_type.into())
+                    .label(format!(
+                        "expected one of: {}",
+                        self.allowed_node_types.iter().join(", ")
+                    )),
+            ),
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidPropertyName {
+    pub property: Span,
+    pub node_type: Spanned<NodeType>,
+    pub expected_names: Vec<&'static str>,
+}
+
+impl Diagnostic for InvalidPropertyName {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title(format!(
+                "invalid property name for `{}` nodes",
+                self.node_type
+            ))
+            .element(source_map.snippet(self.property).annotation(
+                AnnotationKind::Primary.span(self.property.into()).label(
+                    if self.expected_names.is_empty() {
+                        "no named properties are expected".into()
+                    } else {
+                        format!("expected one of: {}", self.expected_names.join(", "))
+                    },
+                ),
+            ))]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidExpressionType {
+    pub expression: Spanned<String>,
+    pub node_type: Spanned<NodeType>,
+    pub valid_expression_types: Vec<String>,
+    pub valid_expression_values: Vec<Cow<'static, str>>,
+}
+
+impl Diagnostic for InvalidExpressionType {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let mut report = [Level::ERROR
+            .primary_title(format!(
+                "invalid expression type for this property in {} nodes",
+                self.node_type
+            ))
+            .element(
+                source_map.snippet(self.expression.span).annotation(
+                    AnnotationKind::Primary
+                        .span(self.expression.span.into())
+                        .label(format!(
+                            "got {}, expected one of: {}",
+                            self.expression,
+                            self.valid_expression_types.join(", ")
+                        )),
+                ),
+            )]
+        .to_vec();
+
+        for (name, value) in self
+            .valid_expression_types
+            .iter()
+            .zip(&self.valid_expression_values)
+        {
+            report.push(
+                Level::HELP
+                    .secondary_title(format!("change to a {name} expression"))
+                    .element(
+                        source_map
+                            .snippet(self.expression.span)
+                            .patch(Patch::new(self.expression.span.into(), &**value)),
+                    ),
+            );
+        }
+
+        report
+    }
+}
+
+#[derive(Debug)]
+pub struct DuplicateProperty {
+    pub original: Span,
+    pub duplicate: Span,
+}
+
+impl Diagnostic for DuplicateProperty {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("duplicate property").element(
+            source_map
+                .snippet(self.original)
+                .annotation(
+                    AnnotationKind::Context
+                        .span(self.original.into())
+                        .label("first occurrence"),
+                )
+                .annotation(
+                    AnnotationKind::Primary
+                        .span(self.duplicate.into())
+                        .label("duplicate"),
+                ),
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidNodeType {
+    pub node_type: Span,
+    pub parent_node_type: Option<Spanned<NodeType>>,
+    pub allowed_node_types: Vec<NodeType>,
+}
+
+impl Diagnostic for InvalidNodeType {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("invalid node type").elements(
+                source_map.snippets(
+                    [(
+                        self.node_type,
+                        AnnotationKind::Primary.span(self.node_type.into()).label(
+                            if let Some(parent_node_type) = self.parent_node_type {
+                                format!(
+                                    "node type can't be used as a sub-node of a {parent_node_type}",
+                                )
+                            } else {
+                                "node type can't be used as the root".into()
+                            },
+                        ),
+                    )]
+                    .into_iter()
+                    .chain(self.parent_node_type.map(|pnt| {
+                        (
+                            pnt.span,
+                            AnnotationKind::Context
+                                .span(pnt.span.into())
+                                .label("in this node"),
+                        )
+                    })),
+                ),
+            ),
+            Group::with_title(Level::NOTE.secondary_title(format!(
+                "valid node types are: {}",
+                self.allowed_node_types.iter().join(", ")
+            ))),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingRequiredProperty {
+    pub node_type: Spanned<NodeType>,
+    pub property_name: String,
+    pub short: bool,
+    pub allowed_property_types: Vec<String>,
+    pub example_values: Vec<Cow<'static, str>>,
+    pub properties_span: Option<Span>,
+}
+
+impl Diagnostic for MissingRequiredProperty {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let label = if self.short {
+            format!(
+                "missing short property for `{}`, with one of these expression types: {}",
+                self.property_name,
+                self.allowed_property_types.join(", ")
+            )
+        } else {
+            format!(
+                "missing property `{}`, with one of these expression types: {}",
+                self.property_name,
+                self.allowed_property_types.join(", ")
+            )
+        };
+
+        [Level::ERROR
+            .primary_title(format!(
+                "{} node is missing a required property",
+                self.node_type
+            ))
+            .element(
+                source_map.snippet(self.node_type.span).annotation(
+                    AnnotationKind::Primary
+                        .span(self.node_type.span.into())
+                        .label(label),
+                ),
+            )
+            .elements(self.example_values.iter().flat_map(|example_value| {
+                self.properties_span.map(|properties_span| {
+                    source_map.snippet(properties_span).patch(Patch::new(
+                        if self.short {
+                            properties_span.end..properties_span.end
+                        } else {
+                            properties_span.start..properties_span.start
+                        },
+                        if self.short {
+                            format!(" {example_value}")
+                        } else {
+                            format!("{}: {example_value},\n", self.property_name)
+                        },
+                    ))
+                })
+            }))]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidSubnode {
+    pub node_type: Spanned<NodeType>,
+    pub subnode: Span,
+}
+
+impl Diagnostic for InvalidSubnode {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("invalid subnode").elements(
+            source_map.snippets([
+                (
+                    self.subnode,
+                    AnnotationKind::Primary
+                        .span(self.subnode.into())
+                        .label("subnode not supported in this location"),
+                ),
+                (
+                    self.node_type.span,
+                    AnnotationKind::Context
+                        .span(self.node_type.span.into())
+                        .label(format!("{} nodes don't support subnodes", self.node_type)),
+                ),
+            ]),
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct SizeBytesTooLarge {
+    pub value: Span,
+    pub field_set: Span,
+}
+
+impl Diagnostic for SizeBytesTooLarge {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("size-bytes too large").element(
+                source_map.snippet(self.field_set)
+                    .annotation(AnnotationKind::Context.span(self.field_set.into()))
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.value.into())
+                            .label("value too large"),
+                    ),
+            ),
+            Level::HELP
+                .secondary_title(
+                    "the maximum value of size-bytes is 0x10_0000 (or 1MB). Keep the value below the limit",
+                )
+                .element(
+                    source_map.snippet(self.value)
+                        .patch(Patch::new(self.value.into(), "0xFFFF_FFFF")),
+                ),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldAddressOutOfRange {
+    pub field_address: Span,
+}
+
+impl Diagnostic for FieldAddressOutOfRange {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("field address exceeds the allowed limits")
+            .element(
+                source_map.snippet(self.field_address).annotation(
+                    AnnotationKind::Primary
+                        .span(self.field_address.into())
+                        .label("address must be non-negative and lower than 2^32"),
+                ),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ResetValueNegative {
+    pub reset_value: Span,
+}
+
+impl Diagnostic for ResetValueNegative {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("reset value is negative")
+            .element(
+                source_map.snippet(self.reset_value).annotation(
+                    AnnotationKind::Primary
+                        .span(self.reset_value.into())
+                        .label("value may not be negative"),
+                ),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidShortProperty {
+    pub property: Span,
+    pub node_type: Spanned<NodeType>,
+    pub got: String,
+    pub expected: Vec<(String, String)>,
+}
+
+impl Diagnostic for InvalidShortProperty {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title(format!(
+                "invalid short property for `{}` nodes",
+                self.node_type
+            ))
+            .element(
+                source_map
+                    .snippet(self.property)
+                    .annotation(AnnotationKind::Primary.span(self.property.into()).label(
+                        if self.expected.is_empty() {
+                            "no short properties are expected".into()
+                        } else {
+                            format!(
+                                "expected one of: {}",
+                                self.expected
+                                    .iter()
+                                    .map(|(expression, purpose)| format!(
+                                        "`{expression}` as {purpose}"
+                                    ))
+                                    .join(", ")
+                            )
+                        },
+                    ))
+                    .annotation(
+                        AnnotationKind::Context
+                            .span(self.property.into())
+                            .label(format!("got: `{}`", self.got,)),
+                    ),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldAddressWrongOrder {
+    pub address: Span,
+    pub end: i128,
+    pub start: i128,
+}
+
+impl Diagnostic for FieldAddressWrongOrder {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const NOTE_TEXT: &str = "the ordering is `high:low` because that mirrors the format commonly used in datasheets and HDLs";
+        [
+            Level::ERROR
+                .primary_title("field address specified in wrong order")
+                .element(
+                    source_map.snippet(self.address).annotation(
+                        AnnotationKind::Primary
+                            .span(self.address.into())
+                            .label("address must be specified as `high:low`"),
+                    ),
+                ),
+            Level::HELP
+                .secondary_title("try switching around the numbers")
+                .element(source_map.snippet(self.address).patch(Patch::new(
+                    self.address.into(),
+                    format!("{}:{}", self.start, self.end),
+                ))),
+            Group::with_title(Level::NOTE.secondary_title(NOTE_TEXT)),
+        ]
+        .into()
+    }
+}
+
+#[derive(Debug)]
+pub struct IgnoredDocCommentOnProperty {
+    pub doc_comments: Span,
+    pub property: Span,
+}
+
+impl Diagnostic for IgnoredDocCommentOnProperty {
+    fn is_error(&self) -> bool {
+        false
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::WARNING
+            .primary_title("doc comments placed on property that doesn't use them")
+            .element(
+                source_map
+                    .snippet(self.doc_comments)
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.doc_comments.into())
+                            .label("these doc comments are ignored"),
+                    )
+                    .annotation(AnnotationKind::Visible.span(self.property.into())),
+            )]
+        .into()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidTypeSpecifier {
+    pub node_type: Spanned<NodeType>,
+    pub type_specifier: Span,
+}
+
+impl Diagnostic for InvalidTypeSpecifier {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title(format!(
+                    "invalid type specifier for `{}` nodes",
+                    self.node_type
+                ))
+                .element(
+                    source_map
+                        .snippet(self.node_type.span)
+                        .annotation(AnnotationKind::Visible.span(self.node_type.span.into()))
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.type_specifier.into())
+                                .label("no type specifier is allowed on this node"),
+                        ),
+                ),
+            Level::HELP
+                .secondary_title("remove the type specifier")
+                .element(
+                    source_map
+                        .snippet(self.type_specifier)
+                        .patch(Patch::new(self.type_specifier.into(), "")),
+                ),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidTypeConversion {
+    pub node_type: Spanned<NodeType>,
+    pub type_conversion: Span,
+}
+
+impl Diagnostic for InvalidTypeConversion {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title(format!(
+                    "invalid type conversion for `{}` nodes",
+                    self.node_type
+                ))
+                .element(
+                    source_map
+                        .snippet(self.node_type.span)
+                        .annotation(AnnotationKind::Visible.span(self.node_type.span.into()))
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.type_conversion.into())
+                                .label("no type conversion is allowed on this node"),
+                        ),
+                ),
+            Level::HELP
+                .secondary_title("remove the type conversion")
+                .element(
+                    source_map
+                        .snippet(self.type_conversion)
+                        .patch(Patch::new(self.type_conversion.into(), "")),
+                ),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidEncoding {
+    pub node_type: Spanned<NodeType>,
+    pub encoding: Span,
+}
+
+impl Diagnostic for InvalidEncoding {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title(format!("invalid encoding for `{}` nodes", self.node_type))
+                .element(
+                    source_map
+                        .snippet(self.node_type.span)
+                        .annotation(AnnotationKind::Visible.span(self.node_type.span.into()))
+                        .annotation(
+                            AnnotationKind::Primary
+                                .span(self.encoding.into())
+                                .label("no encoding is allowed on this node"),
+                        ),
+                ),
+            Level::HELP.secondary_title("remove the encoding").element(
+                source_map
+                    .snippet(self.encoding)
+                    .patch(Patch::new(self.encoding.into(), "")),
+            ),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidFieldsetRef {
+    pub reference: Span,
+    pub pointee: Option<Span>,
+}
+
+impl Diagnostic for InvalidFieldsetRef {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid fieldset reference")
+            .elements(
+                source_map.snippets(
+                    [(
+                        self.reference,
+                        AnnotationKind::Primary
+                            .span(self.reference.into())
+                            .label("no fieldset found with this name"),
+                    )]
+                    .into_iter()
+                    .chain(self.pointee.map(|pointee| {
+                        (
+                            pointee,
+                            AnnotationKind::Context
+                                .span(pointee.into())
+                                .label("reference points to this non-fieldset object instead"),
+                        )
+                    })),
+                ),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidBlockRef {
+    pub reference: Span,
+    pub pointee: Option<Span>,
+}
+
+impl Diagnostic for InvalidBlockRef {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid block reference")
+            .elements(
+                source_map.snippets(
+                    [(
+                        self.reference,
+                        AnnotationKind::Primary
+                            .span(self.reference.into())
+                            .label("no block found with this name in this device"),
+                    )]
+                    .into_iter()
+                    .chain(self.pointee.map(|pointee| {
+                        (
+                            pointee,
+                            AnnotationKind::Context
+                                .span(pointee.into())
+                                .label("reference points to this non-block object instead"),
+                        )
+                    })),
+                ),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockInstanceHasObjects {
+    pub reference: Span,
+    pub object: Span,
+}
+
+impl Diagnostic for BlockInstanceHasObjects {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("block instance defines its own objects")
+                .elements(source_map.snippets([
+                    (
+                        self.object,
+                        AnnotationKind::Primary
+                            .span(self.object.into())
+                            .label("object defined here"),
+                    ),
+                    (
+                        self.reference,
+                        AnnotationKind::Context
+                            .span(self.reference.into())
+                            .label("the block is an instance of this block"),
+                    ),
+                ])),
+            Group::with_title(Level::HELP.secondary_title(
+                "an instance shares all objects of the referenced block. Move the object there or make this a normal block",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockRefToInstance {
+    pub reference: Span,
+    pub pointee_reference: Span,
+}
+
+impl Diagnostic for BlockRefToInstance {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("block reference points to another block instance")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.reference,
+                            AnnotationKind::Primary
+                                .span(self.reference.into())
+                                .label("this block is an instance itself"),
+                        ),
+                        (
+                            self.pointee_reference,
+                            AnnotationKind::Context
+                                .span(self.pointee_reference.into())
+                                .label("it references this block"),
+                        ),
+                    ]),
+                ),
+            Group::with_title(
+                Level::HELP.secondary_title("reference the block the instance references instead"),
+            ),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockRefCycle {
+    pub block: Span,
+    pub reference: Span,
+}
+
+impl Diagnostic for BlockRefCycle {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("block instance is part of the block it references")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.block,
+                        AnnotationKind::Context
+                            .span(self.block.into())
+                            .label("block is defined here"),
+                    ),
+                    (
+                        self.reference,
+                        AnnotationKind::Primary
+                            .span(self.reference.into())
+                            .label("the block is (indirectly) instanced inside itself here"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockInstanceVariantsUncovered {
+    pub instance: Span,
+    pub target: Span,
+}
+
+impl Diagnostic for BlockInstanceVariantsUncovered {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("block instance exists for chip variants its block doesn't")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.instance,
+                            AnnotationKind::Primary
+                                .span(self.instance.into())
+                                .label("this instance exists for more variants..."),
+                        ),
+                        (
+                            self.target,
+                            AnnotationKind::Context
+                                .span(self.target.into())
+                                .label("...than the referenced block"),
+                        ),
+                    ]),
+                ),
+            Group::with_title(Level::HELP.secondary_title(
+                "limit the instance with `when` to the variants of the referenced block",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct AutoAddressWithoutAddressMode {
+    pub address: Span,
+}
+
+impl Diagnostic for AutoAddressWithoutAddressMode {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("automatic register address without a register address mode")
+                .element(
+                    source_map.snippet(self.address).annotation(
+                        AnnotationKind::Primary
+                            .span(self.address.into())
+                            .label("can't tell how many addresses the previous register takes up"),
+                    ),
+                ),
+            Group::with_title(Level::HELP.secondary_title(
+                "set `register-address-mode` to `mapped` or `indexed` on the device or manifest",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingAddress {
+    pub object_name: Span,
+    pub properties_span: Option<Span>,
+}
+
+impl Diagnostic for MissingAddress {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("missing address").element(
+                source_map.snippet(self.object_name).annotation(
+                    AnnotationKind::Primary
+                        .span(self.object_name.into())
+                        .label("object requires an address, but none is given"),
+                ),
+            ),
+            Level::HELP
+                .secondary_title(
+                    "give the object an address or add `default-address: auto` to a parent block or device",
+                )
+                .elements(self.properties_span.map(|properties_span| {
+                    source_map.snippet(properties_span).patch(Patch::new(
+                        properties_span.start..properties_span.start,
+                        "address: auto,\n",
+                    ))
+                })),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidRepeat {
+    pub repeat: Span,
+    pub node_type: Spanned<NodeType>,
+}
+
+impl Diagnostic for InvalidRepeat {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("invalid repeat").element(
+                source_map
+                    .snippet(self.repeat)
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.repeat.into())
+                            .label(format!(
+                                "repeats can't be applied on {} nodes",
+                                self.node_type
+                            )),
+                    )
+                    .annotation(AnnotationKind::Visible.span(self.node_type.span.into())),
+            ),
+            Level::HELP.secondary_title("remove the repeat").element(
+                source_map
+                    .snippet(self.repeat)
+                    .patch(Patch::new(self.repeat.into(), "")),
+            ),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidNestedRepeat {
+    pub repeat: Span,
+    pub node_type: Span,
+    pub reason: Cow<'static, str>,
+}
+
+impl Diagnostic for InvalidNestedRepeat {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid two-dimensional repeat")
+            .element(
+                source_map
+                    .snippet(self.repeat)
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.repeat.into())
+                            .label(&self.reason),
+                    )
+                    .annotation(AnnotationKind::Visible.span(self.node_type.into())),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ZeroStrideRepeat {
+    pub stride: Span,
+}
+
+impl Diagnostic for ZeroStrideRepeat {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const INFO_TEXT: &str = "a stride of 0 means the address doesn't change. So the repeat is useless and thus rejected";
+
+        [
+            Level::ERROR
+                .primary_title("repeat stride cannot be 0")
+                .element(
+                    source_map.snippet(self.stride).annotation(
+                        AnnotationKind::Primary
+                            .span(self.stride.into())
+                            .label("stride is 0"),
+                    ),
+                ),
+            Level::HELP
+                .secondary_title("change to a non-zero value")
+                .element(
+                    source_map
+                        .snippet(self.stride)
+                        .patch(Patch::new(self.stride.into(), "1")),
+                ),
+            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ReservedOperationNameUsed {
+    pub name: Span,
+    pub operation_name: String,
+    pub reserved_names: &'static [&'static str],
+}
+
+impl Diagnostic for ReservedOperationNameUsed {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let info_text: String = format!(
+            "reserved names are: {}",
+            self.reserved_names
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .join(", ")
+        );
+
+        [
+            Level::ERROR
+                .primary_title("reserved operation name used")
+                .element(
+                source_map.snippet(self.name).annotation(
+                    AnnotationKind::Primary
+                        .span(self.name.into())
+                        .label(format!(
+                            "`{}` is a reserved name for operations. Change it to something else",
+                            self.operation_name
+                        )),
+                ),
+            ),
+            Group::with_title(Level::INFO.secondary_title(info_text)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ReservedModuleNameUsed {
+    pub name: Span,
+    pub module_name: String,
+    pub reserved_names: &'static [&'static str],
+}
+
+impl Diagnostic for ReservedModuleNameUsed {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let info_text: String = format!(
+            "reserved module names are: {}",
+            self.reserved_names
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .join(", ")
+        );
+
+        [
+            Level::ERROR
+                .primary_title("reserved module name used")
+                .element(
+                source_map.snippet(self.name).annotation(
+                    AnnotationKind::Primary
+                        .span(self.name.into())
+                        .label(format!(
+                            "the generated module `{}` would shadow the crate with the same name. Change it to something else",
+                            self.module_name
+                        )),
+                ),
+            ),
+            Group::with_title(Level::INFO.secondary_title(info_text)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldSetterNameCollision {
+    pub field: Span,
+    pub setter_name: String,
+    pub collision_field: Span,
+}
+
+impl Diagnostic for FieldSetterNameCollision {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const HELP_TEXT: &str = "writable fields generate setter functions that have the word `set` prepended. This can collide with other field names.\nAvoid this by changing the name of one of the fields or by making the field read only so it doesn't generate a setter";
+
+        [
+            Level::ERROR
+                .primary_title("field setter name collision")
+                .element(
+                source_map.snippet(self.field)
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.field.into())
+                            .label(format!(
+                                "this field is writable and generates a setter with a name that collides with another field: `{}`",
+                                self.setter_name
+                            )),
+                    )
+                    .annotation(
+                        AnnotationKind::Context
+                            .span(self.collision_field.into())
+                            .label("collides with this field"),
+                    ),
+            ),
+            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct IncludeFailed {
+    pub include: Span,
+    pub path: String,
+    pub reason: String,
+}
+
+impl Diagnostic for IncludeFailed {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title(format!("could not include `{}`", self.path))
+            .element(
+                source_map.snippet(self.include).annotation(
+                    AnnotationKind::Primary
+                        .span(self.include.into())
+                        .label(&self.reason),
+                ),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct IncludeCycle {
+    pub include: Span,
+    pub path: String,
+}
+
+impl Diagnostic for IncludeCycle {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        const HELP_TEXT: &str =
+            "a file can't include itself, directly or through other included files";
+
+        [
+            Level::ERROR.primary_title("include cycle").element(
+                source_map.snippet(self.include).annotation(
+                    AnnotationKind::Primary
+                        .span(self.include.into())
+                        .label(format!("`{}` is already being included", self.path)),
+                ),
+            ),
+            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct DuplicateTemplate {
+    pub original: Span,
+    pub duplicate: Span,
+}
+
+impl Diagnostic for DuplicateTemplate {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("duplicate template name")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.original,
+                        AnnotationKind::Context
+                            .span(self.original.into())
+                            .label("first template with this name"),
+                    ),
+                    (
+                        self.duplicate,
+                        AnnotationKind::Primary
+                            .span(self.duplicate.into())
+                            .label("duplicate"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct TemplateNameReserved {
+    pub name: Span,
+}
+
+impl Diagnostic for TemplateNameReserved {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("reserved template name").element(
+                source_map.snippet(self.name).annotation(
+                    AnnotationKind::Primary
+                        .span(self.name.into())
+                        .label("this is the name of a node type"),
+                ),
+            ),
+            Group::with_title(Level::HELP.secondary_title(
+                "templates are instantiated by using their name as the node type, so they can't have the name of a built-in node type",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidTemplateBody {
+    pub template_name: Span,
+    pub sub_nodes: Vec<Span>,
+}
+
+impl Diagnostic for InvalidTemplateBody {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("a template must contain exactly one node")
+                .elements(
+                    source_map.snippets(
+                        [(
+                            self.template_name,
+                            AnnotationKind::Primary.span(self.template_name.into()).label(
+                                format!("this template has {} nodes", self.sub_nodes.len()),
+                            ),
+                        )]
+                        .into_iter()
+                        .chain(self.sub_nodes.iter().map(|sub_node| {
+                            (
+                                *sub_node,
+                                AnnotationKind::Context
+                                    .span((*sub_node).into())
+                                    .label("node in template"),
+                            )
+                        })),
+                    ),
+                ),
+            Group::with_title(Level::HELP.secondary_title(
+                "the node in the template is the node that's created for every instance of the template",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownTemplateArgument {
+    pub argument: Span,
+    pub template_name: Span,
+    pub parameters: Vec<String>,
+}
+
+impl Diagnostic for UnknownTemplateArgument {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("unknown template argument")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.argument,
+                        AnnotationKind::Primary.span(self.argument.into()).label(
+                            if self.parameters.is_empty() {
+                                "the template has no parameters".into()
+                            } else {
+                                format!(
+                                    "expected one of: {}",
+                                    self.parameters.iter().map(|p| format!("`{p}`")).join(", ")
+                                )
+                            },
+                        ),
+                    ),
+                    (
+                        self.template_name,
+                        AnnotationKind::Context
+                            .span(self.template_name.into())
+                            .label("template defined here"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct MissingTemplateArgument {
+    pub instance: Span,
+    pub parameter: Span,
+    pub parameter_name: String,
+}
+
+impl Diagnostic for MissingTemplateArgument {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title(format!(
+                    "missing template argument `{}`",
+                    self.parameter_name
+                ))
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.instance,
+                            AnnotationKind::Primary
+                                .span(self.instance.into())
+                                .label("template instance is missing an argument"),
+                        ),
+                        (
+                            self.parameter,
+                            AnnotationKind::Context
+                                .span(self.parameter.into())
+                                .label("parameter defined here without a default"),
+                        ),
+                    ]),
+                ),
+            Group::with_title(Level::HELP.secondary_title(format!(
+                "add the argument as a property to the instance: `{}: <value>`",
+                self.parameter_name
+            ))),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidTemplateArgument {
+    pub argument: Span,
+    pub usage: Span,
+}
+
+impl Diagnostic for InvalidTemplateArgument {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid template argument")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.argument,
+                        AnnotationKind::Primary
+                            .span(self.argument.into())
+                            .label("argument is not a name"),
+                    ),
+                    (
+                        self.usage,
+                        AnnotationKind::Context
+                            .span(self.usage.into())
+                            .label("parameter is used here where a name is expected"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct RecursiveTemplateInstance {
+    pub instance: Span,
+    pub template_name: Span,
+}
+
+impl Diagnostic for RecursiveTemplateInstance {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("recursive template instance")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.instance,
+                        AnnotationKind::Primary
+                            .span(self.instance.into())
+                            .label("template is instantiated inside of itself"),
+                    ),
+                    (
+                        self.template_name,
+                        AnnotationKind::Context
+                            .span(self.template_name.into())
+                            .label("template defined here"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+/// Wraps a diagnostic that was emitted while lowering a template instance
+#[derive(Debug)]
+pub struct InTemplateInstance {
+    pub diagnostic: Box<dyn Diagnostic>,
+    pub instance: Span,
+}
+
+impl Diagnostic for InTemplateInstance {
+    fn is_error(&self) -> bool {
+        self.diagnostic.is_error()
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let mut report = self.diagnostic.as_report(source_map);
+        report.push(
+            Group::with_title(Level::NOTE.secondary_title("in this template instance")).element(
+                source_map.snippet(self.instance).annotation(
+                    AnnotationKind::Context
+                        .span(self.instance.into())
+                        .label("instantiated here"),
+                ),
+            ),
+        );
+        report
+    }
+}
+
+#[derive(Debug)]
+pub struct DuplicateConstant {
+    pub original: Span,
+    pub duplicate: Span,
+}
+
+impl Diagnostic for DuplicateConstant {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("duplicate constant name")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.original,
+                        AnnotationKind::Context
+                            .span(self.original.into())
+                            .label("first constant with this name"),
+                    ),
+                    (
+                        self.duplicate,
+                        AnnotationKind::Primary
+                            .span(self.duplicate.into())
+                            .label("duplicate"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownConstant {
+    pub name: Span,
+}
+
+impl Diagnostic for UnknownConstant {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("unknown constant").element(
+            source_map.snippet(self.name).annotation(
+                AnnotationKind::Primary
+                    .span(self.name.into())
+                    .label("no constant with this name is defined"),
+            ),
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ConstantCycle {
+    pub constant: Span,
+    pub reference: Span,
+}
+
+impl Diagnostic for ConstantCycle {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("constant depends on itself")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.constant,
+                        AnnotationKind::Context
+                            .span(self.constant.into())
+                            .label("constant is defined here"),
+                    ),
+                    (
+                        self.reference,
+                        AnnotationKind::Primary
+                            .span(self.reference.into())
+                            .label("the value of the constant is needed here to calculate itself"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct IntegerOverflow {
+    pub operator: Spanned<String>,
+    pub lhs: Spanned<i128>,
+    pub rhs: Spanned<i128>,
+}
+
+impl Diagnostic for IntegerOverflow {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("integer overflow").element(
+                source_map
+                    .snippet(self.lhs.span.to(self.rhs.span))
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.operator.span.into())
+                            .label(format!(
+                                "`{} {} {}` doesn't fit in an i128",
+                                self.lhs.value, self.operator.value, self.rhs.value
+                            )),
+                    )
+                    .annotation(
+                        AnnotationKind::Context
+                            .span(self.lhs.span.into())
+                            .label(format!("left side is {}", self.lhs.value)),
+                    )
+                    .annotation(
+                        AnnotationKind::Context
+                            .span(self.rhs.span.into())
+                            .label(format!("right side is {}", self.rhs.value)),
+                    ),
+            ),
+            Group::with_title(Level::NOTE.secondary_title(
+                "integer expressions are calculated with 128-bit signed integers. Shifts may not shift out any bits",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct DivisionByZero {
+    pub operator: Span,
+    pub divisor: Span,
+}
+
+impl Diagnostic for DivisionByZero {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("division by zero").element(
+            source_map
+                .snippet(self.operator.to(self.divisor))
+                .annotation(AnnotationKind::Primary.span(self.operator.into()))
+                .annotation(
+                    AnnotationKind::Context
+                        .span(self.divisor.into())
+                        .label("this is zero"),
+                ),
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidChipVariantName {
+    pub name: Span,
+}
+
+impl Diagnostic for InvalidChipVariantName {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("invalid chip variant name").element(
+                source_map.snippet(self.name).annotation(
+                    AnnotationKind::Primary
+                        .span(self.name.into())
+                        .label("this name can't be used as a cargo feature"),
+                ),
+            ),
+            Group::with_title(Level::NOTE.secondary_title(
+                "chip variants are selected with the cargo feature of the same name. Only ASCII letters, digits, `-` and `_` are allowed",
+            )),
+        ]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct DuplicateChipVariant {
+    pub original: Span,
+    pub duplicate: Span,
+}
+
+impl Diagnostic for DuplicateChipVariant {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("duplicate chip variant")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.original,
+                        AnnotationKind::Context
+                            .span(self.original.into())
+                            .label("first mention of the variant"),
+                    ),
+                    (
+                        self.duplicate,
+                        AnnotationKind::Primary
+                            .span(self.duplicate.into())
+                            .label("duplicate"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownChipVariant {
+    pub variant: Span,
+    pub declared_variants: Vec<String>,
 }
 
-impl InvalidIdentifier {
-    pub fn new(error: identifier::Error, identifier: Span) -> Self {
-        Self { error, identifier }
+impl Diagnostic for UnknownChipVariant {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("unknown chip variant").element(
+            source_map.snippet(self.variant).annotation(
+                AnnotationKind::Primary.span(self.variant.into()).label(
+                    if self.declared_variants.is_empty() {
+                        "the device doesn't declare any variants".into()
+                    } else {
+                        format!(
+                            "expected one of: {}",
+                            self.declared_variants
+                                .iter()
+                                .map(|v| format!("`{v}`"))
+                                .join(", ")
+                        )
+                    },
+                ),
+            ),
+        )]
+        .to_vec()
     }
 }
 
-impl Diagnostic for InvalidIdentifier {
+#[derive(Debug)]
+pub struct ChipVariantsConflict {
+    pub object: Span,
+    pub parent: Span,
+}
+
+impl Diagnostic for ChipVariantsConflict {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        const INFO_TEXT: &str = "identifiers are split into words using the 'word-boundaries'.\n\
-After the split the first character of the first word must be a unicode XID start character.\n\
-All other characters must be a unicode XID continue character.\n\
-\n\
-Identifiers must also be able to be converted to different casings. That means the split words must not contain `-` or `_`,\n\
-which in practice means the word-boundaries should always include those characters.";
-
-        let annotation = match &self.error {
-            identifier::Error::Empty => AnnotationKind::Primary
-                .span(self.identifier.into())
-                .label("identifier is empty"),
-            identifier::Error::EmptyAfterSplits => AnnotationKind::Primary
-                .span(self.identifier.into())
-                .label("identifier is empty after word split"),
-            identifier::Error::InvalidCharacter {
-                byte_offset: offset,
-                invalid_char: character,
-            } if !self.identifier.is_empty() => AnnotationKind::Primary
-                .span(
-                    self.identifier.start + offset
-                        ..self.identifier.start + offset + character.len_utf8(),
-                )
-                .label(format!(
-                    "`{character}` (or `{}`) is not a valid character",
-                    character.escape_unicode()
-                )),
-            identifier::Error::InvalidCharacter {
-                byte_offset: _,
-                invalid_char: character,
-            } => AnnotationKind::Primary
-                .span(self.identifier.into())
-                .label(format!(
-                    "`{character}` (or `{}`) is not a valid character",
-                    character.escape_unicode()
-                )),
-            e @ identifier::Error::CannotConvert { .. } => AnnotationKind::Primary
-                .span(self.identifier.into())
-                .label(e.to_string()),
-        };
-
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
-                .primary_title("invalid identifier")
-                .element(Snippet::source(source).path(path).annotation(annotation)),
-            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
+                .primary_title("object doesn't exist for any chip variant")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.object,
+                            AnnotationKind::Primary
+                                .span(self.object.into())
+                                .label("none of the variants of this object..."),
+                        ),
+                        (
+                            self.parent,
+                            AnnotationKind::Context
+                                .span(self.parent.into())
+                                .label("...are variants of the parent block"),
+                        ),
+                    ]),
+                ),
+            Group::with_title(Level::NOTE.secondary_title(
+                "objects in a conditional block only exist for the variants of that block",
+            )),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidAutoIdentifier {
-    pub auto_identifier: Span,
+pub struct ExtendsCycle {
+    pub field_set: Span,
+    pub reference: Span,
 }
 
-impl Diagnostic for InvalidAutoIdentifier {
+impl Diagnostic for ExtendsCycle {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        const INFO_TEXT: &str = "auto identifiers can only be used in places where there's a parent node of which the name can be taken";
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("fieldset extends itself")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.field_set,
+                        AnnotationKind::Context
+                            .span(self.field_set.into())
+                            .label("fieldset is defined here"),
+                    ),
+                    (
+                        self.reference,
+                        AnnotationKind::Primary
+                            .span(self.reference.into())
+                            .label("the fieldset is (indirectly) extended by itself here"),
+                    ),
+                ]),
+            )]
+        .to_vec()
+    }
+}
 
-        [
-            Level::ERROR.primary_title("invalid identifier").element(
-                Snippet::source(source).path(path).annotation(
-                    AnnotationKind::Primary
-                        .span(self.auto_identifier.into())
-                        .label("auto identifier can't be used here"),
-                ),
-            ),
-            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
-        ]
+#[derive(Debug)]
+pub struct UnknownBaseField {
+    pub field: Span,
+    pub base: Span,
+}
+
+impl Diagnostic for UnknownBaseField {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("removed field doesn't exist")
+            .elements(
+                source_map.snippets([
+                    (
+                        self.field,
+                        AnnotationKind::Primary
+                            .span(self.field.into())
+                            .label("no field with this name..."),
+                    ),
+                    (
+                        self.base,
+                        AnnotationKind::Context
+                            .span(self.base.into())
+                            .label("...exists in the extended fieldset"),
+                    ),
+                ]),
+            )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct ParsingError {
-    pub reason: String,
-    pub span: Span,
+pub struct RemoveWithoutExtends {
+    pub property: Span,
 }
 
-impl Diagnostic for ParsingError {
+impl Diagnostic for RemoveWithoutExtends {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR.primary_title("parsing error").element(
-            Snippet::source(source).path(path).annotation(
-                AnnotationKind::Primary
-                    .span(self.span.into())
-                    .label(&self.reason),
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("field removed from a fieldset that doesn't extend another fieldset")
+                .element(
+                    source_map.snippet(self.property).annotation(
+                        AnnotationKind::Primary
+                            .span(self.property.into())
+                            .label("there's no extended fieldset to remove this field from"),
+                    ),
+                ),
+            Group::with_title(
+                Level::HELP
+                    .secondary_title("remove the field definition from the fieldset instead"),
             ),
-        )]
+        ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct UnknownNodeType {
-    pub node_type: Span,
-    pub allowed_node_types: Vec<NodeType>,
+pub struct RegisterViewSizeMismatch {
+    pub view: Span,
+    pub view_size_bytes: u32,
+    pub field_set: Span,
+    pub field_set_size_bytes: u32,
 }
 
-impl Diagnostic for UnknownNodeType {
+impl Diagnostic for RegisterViewSizeMismatch {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR.primary_title("Unknown node type").element(
-            Snippet::source(source).path(path).annotation(
-                AnnotationKind::Primary
-                    .span(self.node_type.into())
-                    .label(format!(
-                        "expected one of: {}",
-                        self.allowed_node_types.iter().join(", ")
-                    )),
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("register view has a different size than the register")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.view,
+                            AnnotationKind::Primary
+                                .span(self.view.into())
+                                .label(format!("this view is {} bytes...", self.view_size_bytes)),
+                        ),
+                        (
+                            self.field_set,
+                            AnnotationKind::Context
+                                .span(self.field_set.into())
+                                .label(format!(
+                                    "...but the fieldset of the register is {} bytes",
+                                    self.field_set_size_bytes
+                                )),
+                        ),
+                    ]),
+                ),
+            Group::with_title(
+                Level::INFO.secondary_title("all views of a register must have the same size"),
             ),
-        )]
+        ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidPropertyName {
-    pub property: Span,
-    pub node_type: Spanned<NodeType>,
-    pub expected_names: Vec<&'static str>,
+pub struct RegisterViewByteOrderMismatch {
+    pub view: Span,
+    pub view_byte_order: ByteOrder,
+    pub field_set: Span,
+    pub field_set_byte_order: ByteOrder,
 }
 
-impl Diagnostic for InvalidPropertyName {
+impl Diagnostic for RegisterViewByteOrderMismatch {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR
-            .primary_title(format!(
-                "invalid property name for `{}` nodes",
-                self.node_type
-            ))
-            .element(Snippet::source(source).path(path).annotation(
-                AnnotationKind::Primary.span(self.property.into()).label(
-                    if self.expected_names.is_empty() {
-                        "no named properties are expected".into()
-                    } else {
-                        format!("expected one of: {}", self.expected_names.join(", "))
-                    },
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("register view has a different byte order than the register")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.view,
+                            AnnotationKind::Primary
+                                .span(self.view.into())
+                                .label(format!("this view is {}...", self.view_byte_order)),
+                        ),
+                        (
+                            self.field_set,
+                            AnnotationKind::Context
+                                .span(self.field_set.into())
+                                .label(format!(
+                                    "...but the fieldset of the register is {}",
+                                    self.field_set_byte_order
+                                )),
+                        ),
+                    ]),
                 ),
-            ))]
+            Group::with_title(Level::INFO.secondary_title(
+                "all views read the same bytes as the register, so they must have the same byte order",
+            )),
+        ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidExpressionType {
-    pub expression: Spanned<String>,
-    pub node_type: Spanned<NodeType>,
-    pub valid_expression_types: Vec<String>,
-    pub valid_expression_values: Vec<Cow<'static, str>>,
+pub struct InvalidInterfaceProperty {
+    pub interface_name: Span,
+    pub property: &'static str,
+    pub value: Span,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for InvalidExpressionType {
+impl Diagnostic for InvalidInterfaceProperty {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        let mut report = [Level::ERROR
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
             .primary_title(format!(
-                "invalid expression type for this property in {} nodes",
-                self.node_type
+                "invalid {} value for interface object",
+                self.property
             ))
             .element(
-                Snippet::source(source).path(path).annotation(
-                    AnnotationKind::Primary
-                        .span(self.expression.span.into())
-                        .label(format!(
-                            "got {}, expected one of: {}",
-                            self.expression,
-                            self.valid_expression_types.join(", ")
-                        )),
-                ),
+                source_map
+                    .snippet(self.value)
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.value.into())
+                            .label(&self.reason),
+                    )
+                    .annotation(AnnotationKind::Visible.span(self.interface_name.into())),
             )]
-        .to_vec();
-
-        for (name, value) in self
-            .valid_expression_types
-            .iter()
-            .zip(&self.valid_expression_values)
-        {
-            report.push(
-                Level::HELP
-                    .secondary_title(format!("change to a {name} expression"))
-                    .element(
-                        Snippet::source(source)
-                            .path(path)
-                            .patch(Patch::new(self.expression.span.into(), &**value)),
-                    ),
-            );
-        }
-
-        report
+        .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct DuplicateProperty {
-    pub original: Span,
-    pub duplicate: Span,
+pub struct InterfaceAddressInvalid {
+    pub interface_name: Span,
+    pub address: i128,
+    pub object: Span,
+    pub object_address: Span,
+    pub repeat_offset: Option<i128>,
+    /// The block instance that places the object at its offset, if any
+    pub instance: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for DuplicateProperty {
+impl Diagnostic for InterfaceAddressInvalid {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR.primary_title("duplicate property").element(
-            Snippet::source(source)
-                .path(path)
-                .annotation(
-                    AnnotationKind::Context
-                        .span(self.original.into())
-                        .label("first occurrence"),
-                )
-                .annotation(
-                    AnnotationKind::Primary
-                        .span(self.duplicate.into())
-                        .label("duplicate"),
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        let object_message = format!(
+            "this object has address {} ({:#X}){}",
+            self.address,
+            self.address,
+            if let Some(repeat_offset) = self.repeat_offset {
+                format!(" at repeat offset {repeat_offset}")
+            } else {
+                String::new()
+            }
+        );
+
+        [Level::ERROR
+            .primary_title(format!(
+                "address {} ({:#X}) can't be sent by the interface",
+                self.address, self.address
+            ))
+            .elements(
+                source_map.snippets(
+                    [
+                        (
+                            self.object,
+                            AnnotationKind::Primary
+                                .span(self.object.into())
+                                .label(object_message),
+                        ),
+                        (
+                            self.object_address,
+                            AnnotationKind::Context
+                                .span(self.object_address.into())
+                                .label("address set here"),
+                        ),
+                        (
+                            self.interface_name,
+                            AnnotationKind::Context
+                                .span(self.interface_name.into())
+                                .label(&self.reason),
+                        ),
+                    ]
+                    .into_iter()
+                    .chain(self.instance.map(|instance| {
+                        (
+                            instance,
+                            AnnotationKind::Context
+                                .span(instance.into())
+                                .label("the object is placed by this block instance"),
+                        )
+                    })),
                 ),
-        )]
+            )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidNodeType {
-    pub node_type: Span,
-    pub parent_node_type: Option<Spanned<NodeType>>,
-    pub allowed_node_types: Vec<NodeType>,
+pub struct InvalidMemoryProperty {
+    pub memory_name: Span,
+    pub property: &'static str,
+    pub value: Span,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for InvalidNodeType {
+impl Diagnostic for InvalidMemoryProperty {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [
-            Level::ERROR.primary_title("invalid node type").element(
-                Snippet::source(source)
-                    .path(path)
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title(format!("invalid {} value for memory object", self.property))
+            .element(
+                source_map
+                    .snippet(self.value)
                     .annotation(
-                        AnnotationKind::Primary.span(self.node_type.into()).label(
-                            if let Some(parent_node_type) = self.parent_node_type {
-                                format!(
-                                    "node type can't be used as a sub-node of a {parent_node_type}",
-                                )
-                            } else {
-                                "node type can't be used as the root".into()
-                            },
-                        ),
+                        AnnotationKind::Primary
+                            .span(self.value.into())
+                            .label(&self.reason),
                     )
-                    .annotations(self.parent_node_type.map(|pnt| {
-                        AnnotationKind::Context
-                            .span(pnt.span.into())
-                            .label("in this node")
-                    })),
-            ),
-            Group::with_title(Level::NOTE.secondary_title(format!(
-                "valid node types are: {}",
-                self.allowed_node_types.iter().join(", ")
-            ))),
-        ]
+                    .annotation(AnnotationKind::Visible.span(self.memory_name.into())),
+            )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct MissingRequiredProperty {
-    pub node_type: Spanned<NodeType>,
-    pub property_name: String,
-    pub short: bool,
-    pub allowed_property_types: Vec<String>,
-    pub example_values: Vec<Cow<'static, str>>,
-    pub properties_span: Option<Span>,
+pub struct InvalidStatement {
+    pub statement: Span,
+    pub problem: Span,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for MissingRequiredProperty {
+impl Diagnostic for InvalidStatement {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        let label = if self.short {
-            format!(
-                "missing short property for `{}`, with one of these expression types: {}",
-                self.property_name,
-                self.allowed_property_types.join(", ")
-            )
-        } else {
-            format!(
-                "missing property `{}`, with one of these expression types: {}",
-                self.property_name,
-                self.allowed_property_types.join(", ")
-            )
-        };
-
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [Level::ERROR
-            .primary_title(format!(
-                "{} node is missing a required property",
-                self.node_type
-            ))
+            .primary_title("invalid statement in routine")
             .element(
-                Snippet::source(source).path(path).annotation(
-                    AnnotationKind::Primary
-                        .span(self.node_type.span.into())
-                        .label(label),
-                ),
-            )
-            .elements(self.example_values.iter().flat_map(|example_value| {
-                self.properties_span.map(|properties_span| {
-                    Snippet::source(source).path(path).patch(Patch::new(
-                        if self.short {
-                            properties_span.end..properties_span.end
-                        } else {
-                            properties_span.start..properties_span.start
-                        },
-                        if self.short {
-                            format!(" {example_value}")
-                        } else {
-                            format!("{}: {example_value},\n", self.property_name)
-                        },
-                    ))
-                })
-            }))]
+                source_map
+                    .snippet(self.problem)
+                    .annotation(
+                        AnnotationKind::Primary
+                            .span(self.problem.into())
+                            .label(&self.reason),
+                    )
+                    .annotation(AnnotationKind::Visible.span(self.statement.into())),
+            )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidSubnode {
-    pub node_type: Spanned<NodeType>,
-    pub subnode: Span,
+pub struct UnknownStatement {
+    pub statement: Span,
 }
 
-impl Diagnostic for InvalidSubnode {
+impl Diagnostic for UnknownStatement {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR.primary_title("invalid subnode").element(
-            Snippet::source(source)
-                .path(path)
-                .annotation(
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR.primary_title("unknown statement in routine").element(
+                source_map.snippet(self.statement).annotation(
                     AnnotationKind::Primary
-                        .span(self.subnode.into())
-                        .label("subnode not supported in this location"),
-                )
-                .annotation(
-                    AnnotationKind::Context
-                        .span(self.node_type.span.into())
-                        .label(format!("{} nodes don't support subnodes", self.node_type)),
+                        .span(self.statement.into())
+                        .label("this statement is not supported"),
                 ),
-        )]
+            ),
+            Group::with_title(Level::INFO.secondary_title(
+                "the supported statements are: `write`, `modify`, `poll`, `dispatch`, `delay` and `return`",
+            )),
+        ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct SizeBytesTooLarge {
-    pub value: Span,
-    pub field_set: Span,
+pub struct InvalidRoutineTarget {
+    pub statement: &'static str,
+    pub target: Span,
+    pub pointee: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for SizeBytesTooLarge {
+impl Diagnostic for InvalidRoutineTarget {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [
-            Level::ERROR.primary_title("size-bytes too large").element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(AnnotationKind::Context.span(self.field_set.into()))
-                    .annotation(
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title(format!("invalid target for `{}` statement", self.statement))
+            .elements(
+                source_map.snippets(
+                    [(
+                        self.target,
                         AnnotationKind::Primary
-                            .span(self.value.into())
-                            .label("value too large"),
-                    ),
-            ),
-            Level::HELP
-                .secondary_title(
-                    "the maximum value of size-bytes is 0x10_0000 (or 1MB). Keep the value below the limit",
-                )
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .patch(Patch::new(self.value.into(), "0xFFFF_FFFF")),
+                            .span(self.target.into())
+                            .label(&self.reason),
+                    )]
+                    .into_iter()
+                    .chain(self.pointee.map(|pointee| {
+                        (
+                            pointee,
+                            AnnotationKind::Context
+                                .span(pointee.into())
+                                .label("the target is defined here"),
+                        )
+                    })),
                 ),
-        ]
+            )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct FieldAddressOutOfRange {
-    pub field_address: Span,
+pub struct InvalidRoutineField {
+    pub field: Span,
+    pub field_definition: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for FieldAddressOutOfRange {
+impl Diagnostic for InvalidRoutineField {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [Level::ERROR
-            .primary_title("field address exceeds the allowed limits")
-            .element(
-                Snippet::source(source).path(path).annotation(
-                    AnnotationKind::Primary
-                        .span(self.field_address.into())
-                        .label("address must be non-negative and lower than 2^32"),
+            .primary_title("invalid field in routine statement")
+            .elements(
+                source_map.snippets(
+                    [(
+                        self.field,
+                        AnnotationKind::Primary
+                            .span(self.field.into())
+                            .label(&self.reason),
+                    )]
+                    .into_iter()
+                    .chain(self.field_definition.map(|field_definition| {
+                        (
+                            field_definition,
+                            AnnotationKind::Context
+                                .span(field_definition.into())
+                                .label("the field is defined here"),
+                        )
+                    })),
                 ),
             )]
         .to_vec()
@@ -1885,401 +4237,529 @@ impl Diagnostic for FieldAddressOutOfRange {
 }
 
 #[derive(Debug)]
-pub struct ResetValueNegative {
-    pub reset_value: Span,
+pub struct InvalidTransition {
+    pub transition: Span,
+    pub problem: Span,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for ResetValueNegative {
+impl Diagnostic for InvalidTransition {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR
-            .primary_title("reset value is negative")
-            .element(
-                Snippet::source(source).path(path).annotation(
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("invalid transition").elements(
+            source_map.snippets([
+                (
+                    self.problem,
                     AnnotationKind::Primary
-                        .span(self.reset_value.into())
-                        .label("value may not be negative"),
+                        .span(self.problem.into())
+                        .label(&self.reason),
                 ),
-            )]
+                (
+                    self.transition,
+                    AnnotationKind::Context
+                        .span(self.transition.into())
+                        .label("in this transition"),
+                ),
+            ]),
+        )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidShortProperty {
-    pub property: Span,
-    pub node_type: Spanned<NodeType>,
-    pub got: String,
-    pub expected: Vec<(String, String)>,
+pub struct UnknownState {
+    pub state: Span,
+    pub state_machine: Span,
 }
 
-impl Diagnostic for InvalidShortProperty {
+impl Diagnostic for UnknownState {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::ERROR
-            .primary_title(format!(
-                "invalid short property for `{}` nodes",
-                self.node_type
-            ))
-            .element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(AnnotationKind::Primary.span(self.property.into()).label(
-                        if self.expected.is_empty() {
-                            "no short properties are expected".into()
-                        } else {
-                            format!(
-                                "expected one of: {}",
-                                self.expected
-                                    .iter()
-                                    .map(|(expression, purpose)| format!(
-                                        "`{expression}` as {purpose}"
-                                    ))
-                                    .join(", ")
-                            )
-                        },
-                    ))
-                    .annotation(
-                        AnnotationKind::Context
-                            .span(self.property.into())
-                            .label(format!("got: `{}`", self.got,)),
-                    ),
-            )]
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("unknown state").elements(
+            source_map.snippets([
+                (
+                    self.state,
+                    AnnotationKind::Primary
+                        .span(self.state.into())
+                        .label("no state with this name"),
+                ),
+                (
+                    self.state_machine,
+                    AnnotationKind::Context
+                        .span(self.state_machine.into())
+                        .label("in this state machine"),
+                ),
+            ]),
+        )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct FieldAddressWrongOrder {
-    pub address: Span,
-    pub end: i128,
-    pub start: i128,
+pub struct InvalidAllowedOperation {
+    pub operation: Span,
+    pub pointee: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for FieldAddressWrongOrder {
+impl Diagnostic for InvalidAllowedOperation {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        const NOTE_TEXT: &str = "the ordering is `high:low` because that mirrors the format commonly used in datasheets and HDLs";
-        [
-            Level::ERROR
-                .primary_title("field address specified in wrong order")
-                .element(
-                    Snippet::source(source).path(path).annotation(
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid allowed operation")
+            .elements(
+                source_map.snippets(
+                    [(
+                        self.operation,
                         AnnotationKind::Primary
-                            .span(self.address.into())
-                            .label("address must be specified as `high:low`"),
-                    ),
+                            .span(self.operation.into())
+                            .label(&self.reason),
+                    )]
+                    .into_iter()
+                    .chain(self.pointee.map(|pointee| {
+                        (
+                            pointee,
+                            AnnotationKind::Context
+                                .span(pointee.into())
+                                .label("the operation is defined here"),
+                        )
+                    })),
                 ),
-            Level::HELP
-                .secondary_title("try switching around the numbers")
-                .element(Snippet::source(source).path(path).patch(Patch::new(
-                    self.address.into(),
-                    format!("{}:{}", self.start, self.end),
-                ))),
-            Group::with_title(Level::NOTE.secondary_title(NOTE_TEXT)),
-        ]
-        .into()
+            )]
+        .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct IgnoredDocCommentOnProperty {
-    pub doc_comments: Span,
-    pub property: Span,
+pub struct DuplicateAllow {
+    pub original: Span,
+    pub duplicate: Span,
 }
 
-impl Diagnostic for IgnoredDocCommentOnProperty {
+impl Diagnostic for DuplicateAllow {
     fn is_error(&self) -> bool {
-        false
+        true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [Level::WARNING
-            .primary_title("doc comments placed on property that doesn't use them")
-            .element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [
+            Level::ERROR
+                .primary_title("operation is allowed more than once")
+                .elements(source_map.snippets([
+                    (
+                        self.duplicate,
                         AnnotationKind::Primary
-                            .span(self.doc_comments.into())
-                            .label("these doc comments are ignored"),
-                    )
-                    .annotation(AnnotationKind::Visible.span(self.property.into())),
-            )]
-        .into()
+                            .span(self.duplicate.into())
+                            .label("the duplicate"),
+                    ),
+                    (
+                        self.original,
+                        AnnotationKind::Context
+                            .span(self.original.into())
+                            .label("the original"),
+                    ),
+                ])),
+            Group::with_title(Level::HELP.secondary_title(
+                "merge the states into one `allowed` node by specifying the `state` property multiple times",
+            )),
+        ]
+        .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidTypeSpecifier {
-    pub node_type: Spanned<NodeType>,
-    pub type_specifier: Span,
+pub struct InvalidFlagField {
+    pub field: Span,
+    pub flags: Span,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for InvalidTypeSpecifier {
+impl Diagnostic for InvalidFlagField {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
-                .primary_title(format!(
-                    "invalid type specifier for `{}` nodes",
-                    self.node_type
-                ))
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(AnnotationKind::Visible.span(self.node_type.span.into()))
-                        .annotation(
+                .primary_title("invalid field in a set of flags")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.field,
                             AnnotationKind::Primary
-                                .span(self.type_specifier.into())
-                                .label("no type specifier is allowed on this node"),
+                                .span(self.field.into())
+                                .label(&self.reason),
                         ),
+                        (
+                            self.flags,
+                            AnnotationKind::Context.span(self.flags.into()).label(
+                                "the fieldset is generated as a set of flags because of this",
+                            ),
+                        ),
+                    ]),
                 ),
-            Level::HELP
-                .secondary_title("remove the type specifier")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .patch(Patch::new(self.type_specifier.into(), "")),
-                ),
+            Group::with_title(Level::NOTE.secondary_title(
+                "the fieldset is generated as a normal fieldset without the flag operations",
+            )),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidTypeConversion {
-    pub node_type: Spanned<NodeType>,
-    pub type_conversion: Span,
+pub struct InvalidReservedField {
+    pub reserved: Span,
+    pub problem: Span,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for InvalidTypeConversion {
+impl Diagnostic for InvalidReservedField {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
-                .primary_title(format!(
-                    "invalid type conversion for `{}` nodes",
-                    self.node_type
-                ))
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .annotation(AnnotationKind::Visible.span(self.node_type.span.into()))
-                        .annotation(
+                .primary_title("invalid reserved field")
+                .elements(
+                    source_map.snippets([
+                        (
+                            self.problem,
                             AnnotationKind::Primary
-                                .span(self.type_conversion.into())
-                                .label("no type conversion is allowed on this node"),
+                                .span(self.problem.into())
+                                .label(&self.reason),
                         ),
+                        (
+                            self.reserved,
+                            AnnotationKind::Context
+                                .span(self.reserved.into())
+                                .label("the field is reserved because of this"),
+                        ),
+                    ]),
                 ),
-            Level::HELP
-                .secondary_title("remove the type conversion")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .patch(Patch::new(self.type_conversion.into(), "")),
-                ),
+            Group::with_title(
+                Level::NOTE
+                    .secondary_title("the field is generated as a normal field with a setter"),
+            ),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidFieldsetRef {
-    pub reference: Span,
-    pub pointee: Option<Span>,
+pub struct ResetValueWrongReserved {
+    pub reset_value: Span,
+    pub field_name: String,
+    pub field_value: i128,
+    pub reserved: Spanned<i128>,
 }
 
-impl Diagnostic for InvalidFieldsetRef {
+impl Diagnostic for ResetValueWrongReserved {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [Level::ERROR
-            .primary_title("invalid fieldset reference")
+            .primary_title("reset value doesn't match the value of a reserved field")
             .element(
-                Snippet::source(source)
-                    .path(path)
+                source_map
+                    .snippet(self.reset_value)
+                    .annotation(AnnotationKind::Primary.span(self.reset_value.into()).label(
+                        format!(
+                            "the reset value sets field `{}` to {}",
+                            self.field_name, self.field_value
+                        ),
+                    ))
                     .annotation(
-                        AnnotationKind::Primary
-                            .span(self.reference.into())
-                            .label("no fieldset found with this name"),
-                    )
-                    .annotations(self.pointee.map(|pointee| {
                         AnnotationKind::Context
-                            .span(pointee.into())
-                            .label("reference points to this non-fieldset object instead")
-                    })),
+                            .span(self.reserved.span.into())
+                            .label(format!("the field must always be {}", self.reserved.value)),
+                    ),
             )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct InvalidRepeat {
-    pub repeat: Span,
-    pub node_type: Spanned<NodeType>,
+pub struct InvalidFieldReset {
+    pub reset: Span,
+    pub context: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for InvalidRepeat {
+impl Diagnostic for InvalidFieldReset {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        [
-            Level::ERROR.primary_title("invalid repeat").element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid field reset value")
+            .elements(
+                source_map.snippets(
+                    [(
+                        self.reset,
                         AnnotationKind::Primary
-                            .span(self.repeat.into())
-                            .label(format!(
-                                "repeats can't be applied on {} nodes",
-                                self.node_type
-                            )),
-                    )
-                    .annotation(AnnotationKind::Visible.span(self.node_type.span.into())),
-            ),
-            Level::HELP.secondary_title("remove the repeat").element(
-                Snippet::source(source)
-                    .path(path)
-                    .patch(Patch::new(self.repeat.into(), "")),
-            ),
-        ]
+                            .span(self.reset.into())
+                            .label(&self.reason),
+                    )]
+                    .into_iter()
+                    .chain(self.context.map(|context| {
+                        (
+                            context,
+                            AnnotationKind::Context
+                                .span(context.into())
+                                .label("because of this"),
+                        )
+                    })),
+                ),
+            )]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct ZeroStrideRepeat {
-    pub stride: Span,
+pub struct ResetValueConflict {
+    pub reset_value: Span,
+    pub field_reset: Span,
+    pub field_name: String,
+    pub register_bits: i128,
+    pub field_bits: i128,
 }
 
-impl Diagnostic for ZeroStrideRepeat {
+impl Diagnostic for ResetValueConflict {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        const INFO_TEXT: &str = "a stride of 0 means the address doesn't change. So the repeat is useless and thus rejected";
-
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
-                .primary_title("repeat stride cannot be 0")
-                .element(
-                    Snippet::source(source).path(path).annotation(
-                        AnnotationKind::Primary
-                            .span(self.stride.into())
-                            .label("stride is 0"),
+                .primary_title("register reset value conflicts with the reset value of a field")
+                .elements(source_map.snippets([
+                    (
+                        self.reset_value,
+                        AnnotationKind::Primary.span(self.reset_value.into()).label(format!(
+                            "the reset value sets the bits of field `{}` to {:#b}",
+                            self.field_name, self.register_bits
+                        )),
                     ),
-                ),
-            Level::HELP
-                .secondary_title("change to a non-zero value")
-                .element(
-                    Snippet::source(source)
-                        .path(path)
-                        .patch(Patch::new(self.stride.into(), "1")),
-                ),
-            Group::with_title(Level::INFO.secondary_title(INFO_TEXT)),
+                    (
+                        self.field_reset,
+                        AnnotationKind::Context.span(self.field_reset.into()).label(format!(
+                            "the reset value of the field has the bits {:#b}",
+                            self.field_bits
+                        )),
+                    ),
+                ])),
+            Group::with_title(Level::HELP.secondary_title(
+                "remove the reset value of the register or make it agree with the reset values of the fields",
+            )),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct ReservedOperationNameUsed {
-    pub name: Span,
-    pub operation_name: String,
-    pub reserved_names: &'static [&'static str],
+pub struct InvalidSplitField {
+    pub address: Span,
+    pub context: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for ReservedOperationNameUsed {
+impl Diagnostic for InvalidSplitField {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        let info_text: String = format!(
-            "reserved names are: {}",
-            self.reserved_names
-                .iter()
-                .map(|name| format!("`{name}`"))
-                .join(", ")
-        );
-
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [
             Level::ERROR
-                .primary_title("reserved operation name used")
-                .element(
-                Snippet::source(source).path(path).annotation(
-                    AnnotationKind::Primary
-                        .span(self.name.into())
-                        .label(format!(
-                            "`{}` is a reserved name for operations. Change it to something else",
-                            self.operation_name
-                        )),
+                .primary_title("invalid split field")
+                .elements(
+                    source_map.snippets(
+                        [
+                            Some((
+                                self.address,
+                                AnnotationKind::Primary
+                                    .span(self.address.into())
+                                    .label(&self.reason),
+                            )),
+                            self.context.map(|context| {
+                                (
+                                    context,
+                                    AnnotationKind::Context
+                                        .span(context.into())
+                                        .label("because of this"),
+                                )
+                            }),
+                        ]
+                        .into_iter()
+                        .flatten(),
+                    ),
                 ),
-            ),
-            Group::with_title(Level::INFO.secondary_title(info_text)),
+            Group::with_title(Level::INFO.secondary_title(
+                "a field can be split over multiple bit ranges by listing all of them, starting with the least significant part",
+            )),
         ]
         .to_vec()
     }
 }
 
 #[derive(Debug)]
-pub struct FieldSetterNameCollision {
-    pub field: Span,
-    pub setter_name: String,
-    pub collision_field: Span,
+pub struct InvalidComposite {
+    pub composite: Span,
+    pub problem: Span,
+    pub pointee: Option<Span>,
+    pub reason: Cow<'static, str>,
 }
 
-impl Diagnostic for FieldSetterNameCollision {
+impl Diagnostic for InvalidComposite {
     fn is_error(&self) -> bool {
         true
     }
 
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>> {
-        const HELP_TEXT: &str = "writable fields generate setter functions that have the word `set` prepended. This can collide with other field names.\nAvoid this by changing the name of one of the fields or by making the field read only so it doesn't generate a setter";
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("invalid composite").elements(
+            source_map.snippets(
+                [
+                    Some((
+                        self.problem,
+                        AnnotationKind::Primary
+                            .span(self.problem.into())
+                            .label(&self.reason),
+                    )),
+                    (self.problem != self.composite).then(|| {
+                        (
+                            self.composite,
+                            AnnotationKind::Context
+                                .span(self.composite.into())
+                                .label("in this composite"),
+                        )
+                    }),
+                    self.pointee.map(|pointee| {
+                        (
+                            pointee,
+                            AnnotationKind::Context
+                                .span(pointee.into())
+                                .label("defined here"),
+                        )
+                    }),
+                ]
+                .into_iter()
+                .flatten(),
+            ),
+        )]
+        .to_vec()
+    }
+}
 
-        [
-            Level::ERROR
-                .primary_title("field setter name collision")
-                .element(
-                Snippet::source(source)
-                    .path(path)
-                    .annotation(
+#[derive(Debug)]
+pub struct InvalidFifo {
+    pub fifo: Span,
+    pub problem: Span,
+    pub pointee: Option<Span>,
+    pub reason: Cow<'static, str>,
+}
+
+impl Diagnostic for InvalidFifo {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR.primary_title("invalid fifo").elements(
+            source_map.snippets(
+                [
+                    Some((
+                        self.problem,
                         AnnotationKind::Primary
-                            .span(self.field.into())
-                            .label(format!(
-                                "this field is writable and generates a setter with a name that collides with another field: `{}`",
-                                self.setter_name
-                            )),
-                    )
-                    .annotation(
-                        AnnotationKind::Context
-                            .span(self.collision_field.into())
-                            .label("collides with this field"),
-                    ),
+                            .span(self.problem.into())
+                            .label(&self.reason),
+                    )),
+                    (self.problem != self.fifo).then(|| {
+                        (
+                            self.fifo,
+                            AnnotationKind::Context
+                                .span(self.fifo.into())
+                                .label("in this fifo"),
+                        )
+                    }),
+                    self.pointee.map(|pointee| {
+                        (
+                            pointee,
+                            AnnotationKind::Context
+                                .span(pointee.into())
+                                .label("defined here"),
+                        )
+                    }),
+                ]
+                .into_iter()
+                .flatten(),
             ),
-            Group::with_title(Level::HELP.secondary_title(HELP_TEXT)),
-        ]
+        )]
+        .to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidIndexOverride {
+    pub index: Span,
+    pub problem: Span,
+    pub pointee: Option<Span>,
+    pub reason: Cow<'static, str>,
+}
+
+impl Diagnostic for InvalidIndexOverride {
+    fn is_error(&self) -> bool {
+        true
+    }
+
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>> {
+        [Level::ERROR
+            .primary_title("invalid index override")
+            .elements(
+                source_map.snippets(
+                    [
+                        Some((
+                            self.problem,
+                            AnnotationKind::Primary
+                                .span(self.problem.into())
+                                .label(&self.reason),
+                        )),
+                        (self.problem != self.index).then(|| {
+                            (
+                                self.index,
+                                AnnotationKind::Context
+                                    .span(self.index.into())
+                                    .label("in this index override"),
+                            )
+                        }),
+                        self.pointee.map(|pointee| {
+                            (
+                                pointee,
+                                AnnotationKind::Context
+                                    .span(pointee.into())
+                                    .label("defined here"),
+                            )
+                        }),
+                    ]
+                    .into_iter()
+                    .flatten(),
+                ),
+            )]
         .to_vec()
     }
 }
@@ -1,6 +1,10 @@
 use std::{borrow::Cow, error::Error, fmt::Debug, fmt::Display, fmt::Write};
 
-use annotate_snippets::{Group, Level, Renderer, renderer::DecorStyle};
+use annotate_snippets::{Annotation, Group, Level, Renderer, Snippet, renderer::DecorStyle};
+use device_driver_common::{
+    source_map::SourceMap,
+    span::{FileId, Span},
+};
 
 pub mod errors;
 
@@ -39,6 +43,17 @@ impl Diagnostics {
         self.diagnostics.is_empty()
     }
 
+    /// Move all diagnostics into the other diagnostics, transforming them along the way
+    pub fn move_into(
+        self,
+        other: &mut Diagnostics,
+        mut f: impl FnMut(Box<dyn Diagnostic>) -> Box<dyn Diagnostic>,
+    ) {
+        other
+            .diagnostics
+            .extend(self.diagnostics.into_iter().map(&mut f));
+    }
+
     pub fn print_to<W: std::io::Write>(
         self,
         mut writer: W,
@@ -47,8 +62,7 @@ impl Diagnostics {
         let renderer = metadata.get_renderer();
 
         for diagnostic in &self.diagnostics {
-            let mut rendered =
-                renderer.render(&diagnostic.as_report(metadata.source, metadata.source_path));
+            let mut rendered = renderer.render(&diagnostic.as_report(metadata.source_map));
 
             if !metadata.ansi {
                 rendered = strip_ansi_urls(&rendered);
@@ -68,8 +82,7 @@ impl Diagnostics {
         let renderer = metadata.get_renderer();
 
         for diagnostic in &self.diagnostics {
-            let mut rendered =
-                renderer.render(&diagnostic.as_report(metadata.source, metadata.source_path));
+            let mut rendered = renderer.render(&diagnostic.as_report(metadata.source_map));
 
             if !metadata.ansi {
                 rendered = strip_ansi_urls(&rendered);
@@ -83,10 +96,8 @@ impl Diagnostics {
 }
 
 pub struct Metadata<'s> {
-    /// The source code
-    pub source: &'s str,
-    /// The path to the source code
-    pub source_path: &'s str,
+    /// All source files of the compilation
+    pub source_map: &'s SourceMap,
     /// When Some, the specified width is used as the terminal width. If None, a reasonable default value is used.
     pub term_width: Option<usize>,
     /// When true, ansi escape codes are used to add color and OSC8 url links
@@ -165,7 +176,45 @@ fn strip_ansi_urls(text: &str) -> String {
 
 pub trait Diagnostic: Debug {
     fn is_error(&self) -> bool;
-    fn as_report<'a>(&'a self, source: &'a str, path: &'a str) -> Vec<Group<'a>>;
+    fn as_report<'a>(&'a self, source_map: &'a SourceMap) -> Vec<Group<'a>>;
+}
+
+pub trait SourceMapExt {
+    /// Create a snippet of the source file the span points into
+    fn snippet<'a, T: Clone>(&'a self, span: Span) -> Snippet<'a, T>;
+
+    /// Create the snippets for annotations that may point into different files.
+    /// There's one snippet per file, in the order the files are first annotated.
+    fn snippets<'a>(
+        &'a self,
+        annotations: impl IntoIterator<Item = (Span, Annotation<'a>)>,
+    ) -> Vec<Snippet<'a, Annotation<'a>>>;
+}
+
+impl SourceMapExt for SourceMap {
+    fn snippet<'a, T: Clone>(&'a self, span: Span) -> Snippet<'a, T> {
+        let file = self.get(span.file);
+        Snippet::source(file.source()).path(file.path())
+    }
+
+    fn snippets<'a>(
+        &'a self,
+        annotations: impl IntoIterator<Item = (Span, Annotation<'a>)>,
+    ) -> Vec<Snippet<'a, Annotation<'a>>> {
+        let mut files: Vec<(FileId, Vec<Annotation<'a>>)> = Vec::new();
+
+        for (span, annotation) in annotations {
+            match files.iter_mut().find(|(file, _)| *file == span.file) {
+                Some((_, file_annotations)) => file_annotations.push(annotation),
+                None => files.push((span.file, vec![annotation])),
+            }
+        }
+
+        files
+            .into_iter()
+            .map(|(file, annotations)| self.snippet(Span::new(file, 0, 0)).annotations(annotations))
+            .collect()
+    }
 }
 
 impl<E: Error> Diagnostic for E {
@@ -173,7 +222,7 @@ impl<E: Error> Diagnostic for E {
         true
     }
 
-    fn as_report<'a>(&'a self, _source: &'a str, _file_path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, _source_map: &'a SourceMap) -> Vec<Group<'a>> {
         let mut sources = Vec::new();
         let mut source = self.source();
 
@@ -202,7 +251,8 @@ impl DynError {
 
     pub fn to_report_string(&self) -> String {
         let mut string = String::new();
-        let report = self.as_report("", "");
+        let source_map = SourceMap::new("", "");
+        let report = self.as_report(&source_map);
         let output = annotate_snippets::Renderer::styled().render(&report);
         write!(&mut string, "{output}").unwrap();
         string
@@ -287,7 +337,7 @@ impl Diagnostic for Message<'_> {
         true
     }
 
-    fn as_report<'a>(&'a self, _source: &'a str, _path: &'a str) -> Vec<Group<'a>> {
+    fn as_report<'a>(&'a self, _source_map: &'a SourceMap) -> Vec<Group<'a>> {
         [Group::with_title(Level::ERROR.primary_title(&*self.string))].to_vec()
     }
 }
@@ -330,11 +380,12 @@ mod tests {
 
     #[test]
     fn error_into_report() {
-        let report = DummyError.as_report("", "");
+        let source_map = SourceMap::new("", "");
+        let report = DummyError.as_report(&source_map);
         let output = annotate_snippets::Renderer::plain().render(&report);
         pretty_assertions::assert_str_eq!("error: Something went wrong!", output);
 
-        let report = DummyErrorWithSource(5).as_report("", "");
+        let report = DummyErrorWithSource(5).as_report(&source_map);
         let output = annotate_snippets::Renderer::plain().render(&report);
         pretty_assertions::assert_str_eq!(
             "error: @level 5 - Something deep down went wrong!
@@ -352,7 +403,8 @@ mod tests {
     #[test]
     fn dyn_error_context() {
         let error = DummyError.with_message("Here's some context!");
-        let report = error.as_report("", "");
+        let source_map = SourceMap::new("", "");
+        let report = error.as_report(&source_map);
         let output = annotate_snippets::Renderer::plain().render(&report);
         pretty_assertions::assert_str_eq!(
             "error: Here's some context!
@@ -1,17 +1,21 @@
 use std::{borrow::Cow, fmt::Display};
 
 use device_driver_common::{
-    span::{SpanExt, Spanned},
-    specifiers::{Access, AddressMode, BaseType, ByteOrder, Integer},
+    span::{FileId, Span, SpanExt, Spanned},
+    specifiers::{Access, AddressMode, BaseType, ByteOrder, Encoding, Integer},
 };
 use logos::Logos;
 
-pub fn lex(source: &str) -> Vec<Spanned<Token<'_>>> {
+/// Lex the source of a file. The spans of the tokens point into the given file.
+pub fn lex(source: &str, file: FileId) -> Vec<Spanned<Token<'_>>> {
     Token::lexer(source)
         .spanned()
-        .map(|(token, span)| match token {
-            Ok(token) => token.with_span(span),
-            Err(()) => Token::Error.with_span(span),
+        .map(|(token, span)| {
+            let span = Span::new(file, span.start, span.end);
+            match token {
+                Ok(token) => token.with_span(span),
+                Err(()) => Token::Error.with_span(span),
+            }
         })
         .collect()
 }
@@ -42,8 +46,30 @@ pub enum Token<'src> {
     Underscore,
     #[token("->")]
     Arrow,
+    #[token("..=")]
+    DotDotEq,
     #[token("*")]
     Star,
+    #[token("=")]
+    Eq,
+    #[token("+")]
+    Plus,
+    #[token("-")]
+    Minus,
+    #[token("/")]
+    Slash,
+    #[token("<<")]
+    ShiftLeft,
+    #[token(">>")]
+    ShiftRight,
+    #[token("|")]
+    Pipe,
+    #[token("&")]
+    Ampersand,
+    #[token("(")]
+    ParenOpen,
+    #[token(")")]
+    ParenClose,
     #[token("try")]
     Try,
     #[token("as")]
@@ -68,11 +94,17 @@ pub enum Token<'src> {
     #[token("BE", |_| ByteOrder::BE)]
     #[token("LE", |_| ByteOrder::LE)]
     ByteOrder(ByteOrder),
-    /// All the base types except fixed integers. Those are [`Self::Integer`].
+    /// All the base types except fixed integers and byte arrays.
+    /// Fixed integers are [`Self::Integer`] and byte arrays are identifiers that are only base types after an arrow.
     #[token("uint", |_| BaseType::Uint)]
     #[token("int", |_| BaseType::Int)]
     #[token("bool", |_| BaseType::Bool)]
     BaseType(BaseType),
+    #[token("bcd", |_| Encoding::Bcd)]
+    #[token("gray", |_| Encoding::Gray)]
+    #[token("sign-magnitude", |_| Encoding::SignMagnitude)]
+    #[token("ones-complement", |_| Encoding::OnesComplement)]
+    Encoding(Encoding),
     #[token("u8", |_| Integer::U8)]
     #[token("u16", |_| Integer::U16)]
     #[token("u32", |_| Integer::U32)]
@@ -106,7 +138,18 @@ impl Display for Token<'_> {
             Token::Underscore => write!(f, "_"),
             Token::Comma => write!(f, ","),
             Token::Arrow => write!(f, "->"),
+            Token::DotDotEq => write!(f, "..="),
             Token::Star => write!(f, "*"),
+            Token::Eq => write!(f, "="),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Slash => write!(f, "/"),
+            Token::ShiftLeft => write!(f, "<<"),
+            Token::ShiftRight => write!(f, ">>"),
+            Token::Pipe => write!(f, "|"),
+            Token::Ampersand => write!(f, "&"),
+            Token::ParenOpen => write!(f, "("),
+            Token::ParenClose => write!(f, ")"),
             Token::Try => write!(f, "try"),
             Token::As => write!(f, "as"),
             Token::Allow => write!(f, "allow"),
@@ -117,6 +160,7 @@ impl Display for Token<'_> {
             Token::Access(_) => write!(f, "access specifier"),
             Token::ByteOrder(_) => write!(f, "byte order"),
             Token::BaseType(_) => write!(f, "base type"),
+            Token::Encoding(_) => write!(f, "encoding"),
             Token::Integer(_) => write!(f, "integer type"),
             Token::AddressMode(_) => write!(f, "address mode"),
             Token::String(_) => write!(f, "string"),
@@ -139,13 +183,25 @@ impl<'src> Token<'src> {
             Token::Underscore => "_".into(),
             Token::Comma => ",".into(),
             Token::Arrow => "->".into(),
+            Token::DotDotEq => "..=".into(),
             Token::Try => "try".into(),
             Token::Star => "*".into(),
+            Token::Eq => "=".into(),
+            Token::Plus => "+".into(),
+            Token::Minus => "-".into(),
+            Token::Slash => "/".into(),
+            Token::ShiftLeft => "<<".into(),
+            Token::ShiftRight => ">>".into(),
+            Token::Pipe => "|".into(),
+            Token::Ampersand => "&".into(),
+            Token::ParenOpen => "(".into(),
+            Token::ParenClose => ")".into(),
             Token::As => "as".into(),
             Token::Num(n) => n.to_string().into(),
             Token::Access(val) => val.to_string().into(),
             Token::ByteOrder(val) => val.to_string().into(),
             Token::BaseType(val) => val.to_string().into(),
+            Token::Encoding(val) => val.to_string().into(),
             Token::Integer(val) => val.to_string().into(),
             Token::AddressMode(val) => val.to_string().into(),
             Token::Allow => "allow".into(),
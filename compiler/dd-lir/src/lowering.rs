@@ -2,9 +2,9 @@ use std::ops::Add;
 
 use convert_case::Case;
 use device_driver_common::{
-    identifier::{All, Identifier},
+    identifier::{All, Identifier, IdentifierRef},
     span::{SpanExt, Spanned},
-    specifiers::{BaseType, Integer, Repeat, RepeatSource},
+    specifiers::{AddressMode, BaseType, ByteOrder, Integer, Repeat, RepeatSource, ValueRange},
 };
 use device_driver_diagnostics::{DynError, ResultExt};
 
@@ -12,7 +12,7 @@ use crate::model as lir;
 use device_driver_mir::{
     find_min_max_addresses,
     model::{self as mir, Object},
-    search_object,
+    search_object, search_object_for_variants, search_operation,
 };
 
 pub fn transform_devices(manifest: &mir::Manifest) -> Result<Vec<lir::Device>, DynError> {
@@ -35,6 +35,7 @@ pub fn transform_devices(manifest: &mir::Manifest) -> Result<Vec<lir::Device>, D
                     name: &device.name.value.clone().cast_unchecked(),
                     address_offset: &0,
                     repeat: &None,
+                    chip_variants: &[],
                     objects: &device.objects,
                 },
                 true,
@@ -43,14 +44,242 @@ pub fn transform_devices(manifest: &mir::Manifest) -> Result<Vec<lir::Device>, D
             )
             .with_message(|| "could not collect into blocks")?;
 
+            let interfaces = device
+                .objects
+                .iter()
+                .filter_map(|object| match object {
+                    Object::Interface(interface) => {
+                        Some(transform_interface(interface, &device_config))
+                    }
+                    _ => None,
+                })
+                .collect::<Result<_, _>>()
+                .with_message(|| "could not transform interfaces")?;
+
+            let state_machines = device
+                .objects
+                .iter()
+                .filter_map(|object| match object {
+                    Object::StateMachine(state_machine) => Some(
+                        transform_state_machine(state_machine, &device.objects, manifest)
+                            .with_message(|| {
+                                format!(
+                                    "could not transform state machine {}",
+                                    state_machine.name.original()
+                                )
+                            }),
+                    ),
+                    _ => None,
+                })
+                .collect::<Result<_, _>>()?;
+
             Ok(lir::Device {
                 internal_address_type: find_best_internal_address_type(manifest, device),
+                chip_variants: device_config
+                    .chip_variants
+                    .as_deref()
+                    .map(chip_variants_to_strings)
+                    .unwrap_or_default(),
                 blocks,
+                interfaces,
+                state_machines,
             })
         })
         .collect()
 }
 
+fn transform_interface(
+    interface: &mir::Interface,
+    device_config: &mir::DeviceConfig,
+) -> Result<lir::Interface, DynError> {
+    Ok(lir::Interface {
+        description: interface.description.clone(),
+        name: interface.name.value.clone(),
+        bus: interface
+            .bus
+            .ok_or_else(|| DynError::new("bus is a required property"))?
+            .value,
+        register_address_type: device_config
+            .register_address_type
+            .map(|v| v.value)
+            .unwrap_or(Integer::U8),
+        address_size_bytes: interface.address_size_bytes.map(|v| v.value).unwrap_or(1),
+        address_byte_order: interface.address_byte_order.unwrap_or(ByteOrder::BE),
+        read_bit: interface.read_bit.map(|v| v.value),
+        write_bit: interface.write_bit.map(|v| v.value),
+        increment_bit: interface.increment_bit.map(|v| v.value),
+        dummy_bytes: interface.dummy_bytes.map(|v| v.value).unwrap_or(0),
+    })
+}
+
+/// Whether the object is the operation or is a block that contains it, directly or through block refs
+fn leads_to(object: &mir::Object, operation: &mir::Object, manifest: &mir::Manifest) -> bool {
+    if std::ptr::eq(object, operation) {
+        return true;
+    }
+
+    match object {
+        Object::Block(mir::Block {
+            block_ref: Some(block_ref),
+            ..
+        }) => search_object_for_variants(
+            manifest,
+            &block_ref.value,
+            &object.chip_variants().cloned().unwrap_or_default(),
+        )
+        .is_some_and(|target| leads_to(target, operation, manifest)),
+        Object::Block(block) => block
+            .objects
+            .iter()
+            .any(|child| leads_to(child, operation, manifest)),
+        _ => false,
+    }
+}
+
+fn transform_state_machine(
+    state_machine: &mir::StateMachine,
+    siblings: &[mir::Object],
+    manifest: &mir::Manifest,
+) -> Result<lir::StateMachine, DynError> {
+    let find_state = |state_ref: &IdentifierRef<All>| {
+        state_machine
+            .states
+            .iter()
+            .find(|state| state_ref.is_ref_to(&state.name))
+            .map(|state| state.name.value.clone())
+            .ok_or_else(|| {
+                DynError::new(format!(
+                    "state {} existence checked in MIR pass",
+                    state_ref.original()
+                ))
+            })
+    };
+
+    let transitions = state_machine
+        .transitions
+        .iter()
+        .map(|transition| {
+            let (routine, calls_routine) = match &transition.routine {
+                Some(routine_ref) => {
+                    let Some(Object::Routine(routine)) =
+                        siblings.iter().find(|o| routine_ref.is_ref_to(o.name()))
+                    else {
+                        return Err(DynError::new(format!(
+                            "routine {} existence checked in MIR pass",
+                            routine_ref.original()
+                        )));
+                    };
+                    (transform_routine(routine, siblings, manifest)?, true)
+                }
+                None => {
+                    // The statements of the transition are transformed as an anonymous routine
+                    let routine = mir::Routine {
+                        description: String::new(),
+                        name: transition.name.clone(),
+                        statements: transition.statements.clone(),
+                        ..Default::default()
+                    };
+                    (transform_routine(&routine, siblings, manifest)?, false)
+                }
+            };
+
+            Ok(lir::Transition {
+                description: transition.description.clone(),
+                name: transition.name.value.clone(),
+                from: transition
+                    .from
+                    .iter()
+                    .map(|state| find_state(state))
+                    .collect::<Result<_, _>>()?,
+                to: find_state(
+                    transition
+                        .to
+                        .as_ref()
+                        .ok_or_else(|| DynError::new("to is a required property"))?,
+                )?,
+                routine,
+                calls_routine,
+            })
+        })
+        .collect::<Result<_, DynError>>()?;
+
+    let allows = state_machine
+        .allows
+        .iter()
+        .map(|allow| {
+            let operation_ref = allow.name.value.clone().take_ref();
+            let operation = search_operation(siblings, &operation_ref).ok_or_else(|| {
+                DynError::new(format!(
+                    "operation {} existence checked in MIR pass",
+                    operation_ref.original()
+                ))
+            })?;
+
+            Ok((
+                lir::Allow {
+                    operation: operation.name().clone().cast_unchecked(),
+                    states: allow
+                        .states
+                        .iter()
+                        .map(|state| find_state(state))
+                        .collect::<Result<_, _>>()?,
+                    nested: !siblings.iter().any(|o| std::ptr::eq(o, operation)),
+                },
+                operation,
+            ))
+        })
+        .collect::<Result<Vec<_>, DynError>>()?;
+
+    // A root operation is gated by all allowed operations it leads to,
+    // so a block can't be used to reach an operation in a state that doesn't allow it
+    let mut gates = Vec::<lir::Gate>::new();
+    for sibling in siblings {
+        let gate_allows = allows
+            .iter()
+            .filter(|(_, operation)| leads_to(sibling, operation, manifest))
+            .map(|(allow, _)| allow.operation.clone())
+            .collect::<Vec<_>>();
+
+        if gate_allows.is_empty() {
+            continue;
+        }
+
+        // Blocks have an `All` name, but are generated as an operation on the root block
+        let operation = sibling.name().clone().cast_unchecked();
+        match gates.iter_mut().find(|gate| gate.allows == gate_allows) {
+            Some(gate) => gate.operations.push(operation),
+            None => gates.push(lir::Gate {
+                allows: gate_allows,
+                operations: vec![operation],
+            }),
+        }
+    }
+
+    let allows = allows.into_iter().map(|(allow, _)| allow).collect();
+
+    Ok(lir::StateMachine {
+        description: state_machine.description.clone(),
+        name: state_machine.name.value.clone(),
+        initial: find_state(
+            state_machine
+                .initial
+                .as_ref()
+                .ok_or_else(|| DynError::new("initial is a required property"))?,
+        )?,
+        states: state_machine
+            .states
+            .iter()
+            .map(|state| lir::State {
+                description: state.description.clone(),
+                name: state.name.value.clone(),
+            })
+            .collect(),
+        transitions,
+        allows,
+        gates,
+    })
+}
+
 fn collect_into_blocks(
     block: BorrowedBlock,
     is_root: bool,
@@ -64,6 +293,7 @@ fn collect_into_blocks(
         name,
         address_offset: _,
         repeat: _,
+        chip_variants,
         objects,
     } = block;
 
@@ -84,6 +314,35 @@ fn collect_into_blocks(
         methods.push(method);
     }
 
+    let routines = objects
+        .iter()
+        .filter_map(|object| match object {
+            Object::Routine(routine) => Some(
+                transform_routine(routine, objects, manifest).with_message(|| {
+                    format!("could not transform routine {}", routine.name.original())
+                }),
+            ),
+            _ => None,
+        })
+        .collect::<Result<_, _>>()?;
+
+    let composites = objects
+        .iter()
+        .filter_map(|object| match object {
+            Object::Composite(composite) => Some(
+                transform_composite(composite, objects, device_config, manifest).with_message(
+                    || {
+                        format!(
+                            "could not transform composite {}",
+                            composite.name.original()
+                        )
+                    },
+                ),
+            ),
+            _ => None,
+        })
+        .collect::<Result<_, _>>()?;
+
     let new_block = lir::Block {
         description: description.clone(),
         root: is_root,
@@ -100,8 +359,15 @@ fn collect_into_blocks(
             .buffer_address_type
             .map(|v| v.value)
             .unwrap_or(Integer::U8),
+        memory_address_type: device_config
+            .memory_address_type
+            .map(|v| v.value)
+            .unwrap_or(Integer::U8),
         register_address_mode: device_config.register_address_mode.map(|v| v.value),
+        chip_variants: chip_variants_to_strings(chip_variants),
         methods,
+        routines,
+        composites,
     };
 
     blocks.insert(0, new_block);
@@ -123,24 +389,42 @@ fn get_method(
                 name,
                 address_offset,
                 repeat,
+                chip_variants,
+                block_ref,
                 ..
             },
         ) => {
-            blocks.extend(collect_into_blocks(
-                b.into(),
-                false,
-                device_config,
-                manifest,
-            )?);
+            // An instance shares the block of the block it references, so only that block gets generated
+            let block_name = match block_ref {
+                Some(block_ref) => {
+                    let Some(Object::Block(target)) =
+                        search_object_for_variants(manifest, &block_ref.value, chip_variants)
+                    else {
+                        return Err(DynError::new(format!(
+                            "returned object for `{}` is none or not a block, but it was safe to assume it would be",
+                            block_ref.original()
+                        )));
+                    };
+                    target.name.value.clone().cast()
+                }
+                None => {
+                    blocks.extend(collect_into_blocks(
+                        b.into(),
+                        false,
+                        device_config,
+                        manifest,
+                    )?);
+                    name.value.clone().cast()
+                }
+            };
 
             Some(lir::BlockMethod {
                 description: description.clone(),
                 name: name.value.clone().cast(),
                 address: address_offset.value,
                 repeat: repeat_to_method_kind(repeat, manifest),
-                method_type: lir::BlockMethodType::Block {
-                    name: name.value.clone().cast(),
-                },
+                chip_variants: chip_variants_to_strings(chip_variants),
+                method_type: lir::BlockMethodType::Block { name: block_name },
             })
         }
         mir::Object::Register(mir::Register {
@@ -149,28 +433,60 @@ fn get_method(
             address,
             access,
             repeat,
+            chip_variants,
             field_set_ref,
+            views,
             reset_value,
+            reset_from_fields,
+            index_overrides,
             ..
         }) => {
             let field_set = search_object(manifest, field_set_ref).ok_or(DynError::new(
                 format!("fieldset {} could not be found", field_set_ref.original()),
             ))?;
+            let views = views
+                .iter()
+                .map(|view| {
+                    search_object(manifest, view)
+                        .map(|view| view.name().clone().cast_assert())
+                        .ok_or(DynError::new(format!(
+                            "fieldset {} could not be found",
+                            view.original()
+                        )))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let access = access.ok_or_else(|| DynError::new("access is not set"))?;
+            let index_overrides = index_overrides
+                .iter()
+                .map(|index_override| lir::IndexOverride {
+                    index: repeat_index_value(repeat, &index_override.index, manifest),
+                    reset_value: index_override
+                        .reset_value
+                        .as_ref()
+                        .and_then(|rv| rv.as_array().cloned()),
+                    access: index_override.access.unwrap_or(access),
+                })
+                .collect();
 
             Some(lir::BlockMethod {
                 description: description.clone(),
                 name: name.value.clone(),
                 address: address.value,
                 repeat: repeat_to_method_kind(repeat, manifest),
+                chip_variants: chip_variants_to_strings(chip_variants),
                 method_type: lir::BlockMethodType::Register {
                     field_set_name: field_set.name().clone().cast_assert(),
-                    access: access.ok_or_else(|| DynError::new("access is not set"))?,
+                    access,
                     reset_value: reset_value.as_ref().map(|rv| {
                         rv.as_array().cloned().map(|array| array.with_span(rv.span)).ok_or_else(
                             || DynError::new("reset value is not an array while it should have been converted to array a mir pass"),
                         )
                     })
                     .transpose()?,
+                    reset_from_fields: *reset_from_fields,
+                    views,
+                    index_overrides,
                 },
             })
         }
@@ -179,6 +495,7 @@ fn get_method(
             name,
             address,
             repeat,
+            chip_variants,
             field_set_ref_in,
             field_set_ref_out,
             ..
@@ -207,6 +524,7 @@ fn get_method(
                 name: name.value.clone(),
                 address: address.value,
                 repeat: repeat_to_method_kind(repeat, manifest),
+                chip_variants: chip_variants_to_strings(chip_variants),
                 method_type: lir::BlockMethodType::Command {
                     field_set_name_in: field_set_in.map(|fs_in| fs_in.name().clone().cast_assert()),
                     field_set_name_out: field_set_out
@@ -219,6 +537,8 @@ fn get_method(
             name,
             access,
             address,
+            address_kind: _,
+            chip_variants,
             short_properties_span: _,
             properties_span: _,
             span: _,
@@ -227,19 +547,407 @@ fn get_method(
             name: name.value.clone(),
             address: address.value,
             repeat: lir::Repeat::None, // Buffers can't be repeated (for now?)
+            chip_variants: chip_variants_to_strings(chip_variants),
             method_type: lir::BlockMethodType::Buffer {
                 access: access.ok_or_else(|| DynError::new("access is not set"))?,
             },
         }),
+        mir::Object::Memory(memory) => Some(lir::BlockMethod {
+            description: memory.description.clone(),
+            name: memory.name.value.clone(),
+            address: memory.address.value,
+            repeat: lir::Repeat::None,
+            chip_variants: chip_variants_to_strings(&memory.chip_variants),
+            method_type: lir::BlockMethodType::Memory {
+                access: memory
+                    .access
+                    .ok_or_else(|| DynError::new("access is not set"))?,
+                size_bytes: memory.size_bytes.value,
+                word_size_bytes: memory.word_size_bytes(),
+                page_size_bytes: memory.page_size_bytes.map(|size| size.value),
+                write_granularity_bytes: memory.write_granularity_bytes(),
+                erase_size_bytes: memory.erase_size_bytes.map(|size| size.value),
+            },
+        }),
+        mir::Object::Fifo(fifo) => {
+            let record = search_object(manifest, &fifo.record_ref)
+                .and_then(|object| object.as_field_set())
+                .ok_or_else(|| {
+                    DynError::new(format!(
+                        "fieldset {} could not be found",
+                        fifo.record_ref.original()
+                    ))
+                })?;
+
+            let level = match (&fifo.level_register, &fifo.level_field) {
+                (Some(register_ref), Some(field_ref)) => {
+                    let Some(Object::Register(register)) =
+                        search_object_for_variants(manifest, register_ref, &fifo.chip_variants)
+                    else {
+                        return Err(DynError::new(format!(
+                            "level register {} existence checked in MIR pass",
+                            register_ref.original()
+                        )));
+                    };
+                    let field_set = search_object(manifest, &register.field_set_ref)
+                        .and_then(|object| object.as_field_set())
+                        .ok_or_else(|| DynError::new("register fieldset checked in MIR pass"))?;
+                    let field = field_set
+                        .fields
+                        .iter()
+                        .find(|f| field_ref.is_ref_to(&f.name))
+                        .ok_or_else(|| DynError::new("field existence checked in MIR pass"))?;
+
+                    Some(lir::FifoLevel {
+                        register_address: register.address.value,
+                        field_set_name: field_set.name.value.clone(),
+                        field: field.name.value.clone(),
+                        unit: fifo.level_unit.map(|unit| unit.value).unwrap_or_default(),
+                    })
+                }
+                _ => None,
+            };
+
+            Some(lir::BlockMethod {
+                description: fifo.description.clone(),
+                name: fifo.name.value.clone(),
+                address: fifo.address.value,
+                repeat: lir::Repeat::None,
+                chip_variants: chip_variants_to_strings(&fifo.chip_variants),
+                method_type: lir::BlockMethodType::Fifo {
+                    record_field_set_name: record.name.value.clone(),
+                    record_size_bytes: record.size_bytes.value,
+                    level,
+                },
+            })
+        }
         mir::Object::FieldSet(_) => None,
         mir::Object::Enum(_) => None,
         mir::Object::Extern(_) => None,
+        mir::Object::Interface(_) => None,
+        mir::Object::Routine(_) => None,
+        mir::Object::Composite(_) => None,
+        mir::Object::StateMachine(_) => None,
         mir::Object::Field(_) => None,
     };
 
     Ok(method)
 }
 
+fn transform_routine(
+    routine: &mir::Routine,
+    siblings: &[mir::Object],
+    manifest: &mir::Manifest,
+) -> Result<lir::Routine, DynError> {
+    let statements = routine
+        .statements
+        .iter()
+        .map(|statement| {
+            let Some(target_statement) = statement.as_target_statement() else {
+                let mir::Statement::Delay { duration, unit, .. } = statement else {
+                    unreachable!("Only delays have no target")
+                };
+                return Ok(lir::Statement::Delay {
+                    duration: duration.value,
+                    unit: *unit,
+                });
+            };
+
+            let target = siblings
+                .iter()
+                .find(|o| {
+                    target_statement.target.is_ref_to(o.name())
+                        && o.coexists_with(&routine.chip_variants)
+                })
+                .ok_or_else(|| {
+                    DynError::new(format!(
+                        "target {} existence checked in MIR pass",
+                        target_statement.target.original()
+                    ))
+                })?;
+            let target_name = target.name().clone().cast_assert();
+
+            let field_set_ref = match target {
+                Object::Register(register) => Some(&register.field_set_ref),
+                Object::Command(command) => command.field_set_ref_in.as_ref(),
+                _ => {
+                    return Err(DynError::new(
+                        "target kind should have been checked in a MIR pass",
+                    ));
+                }
+            };
+            let field_set = field_set_ref
+                .and_then(|field_set_ref| search_object(manifest, field_set_ref))
+                .and_then(|object| object.as_field_set());
+
+            let fields = target_statement
+                .fields
+                .iter()
+                .map(|field_assignment| {
+                    transform_field_value(field_assignment, field_set, manifest)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(match statement {
+                mir::Statement::Write(_) => lir::Statement::Write {
+                    register: target_name,
+                    fields,
+                },
+                mir::Statement::Modify(_) => lir::Statement::Modify {
+                    register: target_name,
+                    fields,
+                },
+                mir::Statement::Poll(_, limit) => lir::Statement::Poll {
+                    register: target_name,
+                    fields,
+                    max_attempts: limit.max_attempts,
+                    interval: limit.interval,
+                    interval_unit: limit.interval_unit,
+                },
+                mir::Statement::Dispatch(_) => {
+                    let Object::Command(command) = target else {
+                        unreachable!("Checked above")
+                    };
+                    lir::Statement::Dispatch {
+                        command: target_name,
+                        input: command.field_set_ref_in.is_some(),
+                        output: command.field_set_ref_out.is_some(),
+                        fields,
+                    }
+                }
+                mir::Statement::Return(_) => lir::Statement::Return {
+                    register: target_name,
+                    field_set_name: field_set
+                        .ok_or_else(|| DynError::new("register fieldset checked in MIR pass"))?
+                        .name
+                        .value
+                        .clone(),
+                },
+                mir::Statement::Delay { .. } => unreachable!("Handled above"),
+            })
+        })
+        .collect::<Result<_, DynError>>()?;
+
+    Ok(lir::Routine {
+        description: routine.description.clone(),
+        name: routine.name.value.clone(),
+        chip_variants: chip_variants_to_strings(&routine.chip_variants),
+        statements,
+    })
+}
+
+fn transform_composite(
+    composite: &mir::Composite,
+    siblings: &[mir::Object],
+    device_config: &mir::DeviceConfig,
+    manifest: &mir::Manifest,
+) -> Result<lir::Composite, DynError> {
+    let mut parts = Vec::new();
+    // The registers of the parts with their field set, without duplicates
+    let mut registers: Vec<(&mir::Register, &mir::FieldSet)> = Vec::new();
+    let mut readable = true;
+    let mut writable = true;
+    let mut shift = 0;
+
+    for part in &composite.parts {
+        let register_ref = part.name.value.clone().take_ref();
+        let Some(Object::Register(register)) = siblings.iter().find(|o| {
+            register_ref.is_ref_to(o.name()) && o.coexists_with(&composite.chip_variants)
+        }) else {
+            return Err(DynError::new(format!(
+                "register {} existence checked in MIR pass",
+                part.name.original()
+            )));
+        };
+        let field_set = search_object(manifest, &register.field_set_ref)
+            .and_then(|object| object.as_field_set())
+            .ok_or_else(|| DynError::new("register fieldset checked in MIR pass"))?;
+        let field = part
+            .field
+            .as_ref()
+            .and_then(|field_ref| {
+                field_set
+                    .fields
+                    .iter()
+                    .find(|f| field_ref.is_ref_to(&f.name))
+            })
+            .ok_or_else(|| DynError::new("field existence checked in MIR pass"))?;
+        let field_type = field
+            .base_type
+            .as_fixed_size()
+            .ok_or_else(|| DynError::new("field type checked in MIR pass"))?;
+
+        let register_access = register
+            .access
+            .ok_or_else(|| DynError::new("access is not set"))?;
+        let field_access = field
+            .access
+            .ok_or_else(|| DynError::new("access is not set"))?;
+        readable &= register_access.is_readable() && field_access.is_readable();
+        writable &= register_access.is_writable() && field_access.is_writable();
+
+        if !registers.iter().any(|(r, _)| r.name == register.name) {
+            registers.push((register, field_set));
+        }
+
+        let size_bits = field.size_bits() as u32;
+        parts.push(lir::CompositePart {
+            register: register.name.value.clone(),
+            field: field.name.value.clone(),
+            field_type,
+            shift,
+            size_bits,
+        });
+        shift += size_bits;
+    }
+
+    // The latch is read before and written after the other registers
+    let latch_index = composite.latch.as_ref().and_then(|latch| {
+        registers
+            .iter()
+            .position(|(register, _)| latch.is_ref_to(&register.name))
+    });
+    let mut read_order = registers.clone();
+    let mut write_order = registers;
+    if let Some(latch_index) = latch_index {
+        let latch = read_order.remove(latch_index);
+        read_order.insert(0, latch);
+        let latch = write_order.remove(latch_index);
+        write_order.push(latch);
+    }
+
+    // A bulk transaction is only possible if the registers follow each other in the order they're accessed
+    let consecutive = |order: &[(&mir::Register, &mir::FieldSet)]| {
+        let Some(address_mode) = device_config.register_address_mode else {
+            return false;
+        };
+        order.len() > 1
+            && order.windows(2).all(|pair| {
+                let [(current, current_field_set), (next, _)] = pair else {
+                    unreachable!()
+                };
+                let size = match address_mode.value {
+                    AddressMode::Mapped => i128::from(current_field_set.size_bytes.value),
+                    AddressMode::Indexed => 1,
+                };
+                current.address.value + size == next.address.value
+            })
+    };
+    // Registers with other writable fields are read before they're written, so those fields keep their value.
+    // Registers that can't be read get the reset value for those fields
+    let modified = write_order
+        .iter()
+        .filter(|(register, field_set)| {
+            register.access.is_some_and(|access| access.is_readable())
+                && field_set.fields.iter().any(|field| {
+                    field.access.is_some_and(|access| access.is_writable())
+                        && !composite.parts.iter().any(|part| {
+                            part.name.value.clone().take_ref().is_ref_to(&register.name)
+                                && part
+                                    .field
+                                    .as_ref()
+                                    .is_some_and(|field_ref| field_ref.is_ref_to(&field.name))
+                        })
+                })
+        })
+        .map(|(register, _)| register.name.value.clone())
+        .collect::<Vec<_>>();
+
+    let bulk_read = readable && consecutive(&read_order);
+    // A bulk modify needs all registers to be readable
+    let bulk_write = writable
+        && consecutive(&write_order)
+        && (modified.is_empty()
+            || write_order
+                .iter()
+                .all(|(register, _)| register.access.is_some_and(|access| access.is_readable())));
+
+    let names = |order: Vec<(&mir::Register, &mir::FieldSet)>| {
+        order
+            .into_iter()
+            .map(|(register, _)| register.name.value.clone())
+            .collect()
+    };
+
+    Ok(lir::Composite {
+        description: composite.description.clone(),
+        name: composite.name.value.clone(),
+        chip_variants: chip_variants_to_strings(&composite.chip_variants),
+        base_type: composite
+            .base_type
+            .as_fixed_size()
+            .ok_or_else(|| DynError::new("composite base type is set in MIR pass"))?,
+        parts,
+        read_order: if readable {
+            names(read_order)
+        } else {
+            Vec::new()
+        },
+        write_order: if writable {
+            names(write_order)
+        } else {
+            Vec::new()
+        },
+        bulk_read,
+        bulk_write,
+        modified: if writable { modified } else { Vec::new() },
+    })
+}
+
+fn transform_field_value(
+    field_assignment: &mir::FieldAssignment,
+    field_set: Option<&mir::FieldSet>,
+    manifest: &mir::Manifest,
+) -> Result<lir::FieldValue, DynError> {
+    let field = field_set
+        .and_then(|field_set| {
+            field_set
+                .fields
+                .iter()
+                .find(|f| field_assignment.field.is_ref_to(&f.name))
+        })
+        .ok_or_else(|| {
+            DynError::new(format!(
+                "field {} existence checked in MIR pass",
+                field_assignment.field.original()
+            ))
+        })?;
+
+    let value = match (&field_assignment.value.value, &field.field_conversion) {
+        (mir::FieldValue::Variant(variant), Some(conversion)) => {
+            let enum_value = manifest
+                .iter_enums()
+                .find(|e| e.name.take_ref() == conversion.type_name.value)
+                .ok_or_else(|| DynError::new("enum existence checked in MIR pass"))?;
+            let variant = enum_value
+                .variants
+                .iter()
+                .find(|v| variant.is_ref_to(&v.name))
+                .ok_or_else(|| DynError::new("variant existence checked in MIR pass"))?;
+
+            lir::Value::Variant {
+                enum_name: enum_value.name.value.clone(),
+                variant_name: variant.name.value.clone(),
+                fallible: conversion.fallible,
+            }
+        }
+        (mir::FieldValue::Number(value), None) if field.base_type.value == BaseType::Bool => {
+            lir::Value::Bool(*value != 0)
+        }
+        (mir::FieldValue::Number(value), None) => lir::Value::Number(*value),
+        _ => {
+            return Err(DynError::new(
+                "field value kind should have been checked in a MIR pass",
+            ));
+        }
+    };
+
+    Ok(lir::FieldValue {
+        name: field.name.value.clone(),
+        value,
+    })
+}
+
 pub fn transform_field_sets(manifest: &mir::Manifest) -> Result<Vec<lir::FieldSet>, DynError> {
     manifest
         .iter_objects()
@@ -278,6 +986,7 @@ fn transform_field_set(
         })?,
         size_bytes: field_set.size_bytes.value,
         fields,
+        flags: field_set.flags.is_some(),
     })
 }
 
@@ -287,21 +996,47 @@ fn transform_field(manifest: &mir::Manifest, field: &mir::Field) -> Result<lir::
         name,
         access,
         base_type,
+        encoding,
         field_conversion,
         field_address,
+        split_field_address,
         repeat,
+        value_range,
+        reserved,
+        reset_value: _,
         short_properties_span: _,
         properties_span: _,
         span: _,
     } = field;
 
+    let value_range = match base_type.value {
+        BaseType::FixedSize(integer) => {
+            let representable_range = ValueRange::representable(
+                integer,
+                encoding.map(|encoding| encoding.value),
+                field.size_bits(),
+            );
+            let value_range = match value_range {
+                Some(value_range) => representable_range.intersection(value_range),
+                None => representable_range,
+            };
+            let base_type_range = ValueRange {
+                start: integer.min_value(),
+                end: integer.max_value(),
+            };
+
+            (value_range != base_type_range).then_some(value_range)
+        }
+        _ => None,
+    };
+
     let (base_type, conversion_method) = match (base_type.value, field_conversion) {
         (BaseType::Unspecified | BaseType::Uint | BaseType::Int, _) => {
             return Err(DynError::new(
                 "base type cannot be left unspecified or unsized after the mir passes",
             ));
         }
-        (BaseType::Bool, None) if field_address.len() == 1 => {
+        (BaseType::Bool, None) if field.size_bits() == 1 => {
             ("u8".to_string(), lir::FieldConversionMethod::Bool)
         }
         (BaseType::Bool, _) => {
@@ -309,11 +1044,18 @@ fn transform_field(manifest: &mir::Manifest, field: &mir::Field) -> Result<lir::
                 "bools can only be 1 bit and have no conversion. Should have been checked in a MIR pass.",
             ));
         }
+        (BaseType::Bytes, None) => ("u8".to_string(), lir::FieldConversionMethod::Bytes),
+        (BaseType::Ascii, None) => ("u8".to_string(), lir::FieldConversionMethod::Ascii),
+        (BaseType::Bytes | BaseType::Ascii, Some(_)) => {
+            return Err(DynError::new(
+                "byte arrays can have no conversion. Should have been checked in a MIR pass.",
+            ));
+        }
         (BaseType::FixedSize(integer), None) => {
             (integer.to_string(), lir::FieldConversionMethod::None)
         }
         (BaseType::FixedSize(integer), Some(fc)) => (integer.to_string(), {
-            let field_bits = field.field_address.len() as u32;
+            let field_bits = field.size_bits() as u32;
 
             let fc_identifier = search_object(manifest, &fc.type_name)
                 .ok_or_else(|| {
@@ -360,10 +1102,17 @@ fn transform_field(manifest: &mir::Manifest, field: &mir::Field) -> Result<lir::
         description: description.clone(),
         name: name.value.clone(),
         address: field_address.value,
+        split_address: split_field_address
+            .iter()
+            .map(|address| address.value)
+            .collect(),
         base_type,
+        encoding: encoding.map(|encoding| encoding.value),
         conversion_method,
         access: access.ok_or_else(|| DynError::new("access is not set"))?,
         repeat: repeat_to_method_kind(repeat, manifest),
+        value_range,
+        reserved: reserved.map(|reserved| reserved.value),
     })
 }
 
@@ -404,6 +1153,10 @@ pub fn transform_enums(manifest: &mir::Manifest) -> Vec<lir::Enum> {
                     discriminant,
                     default: matches!(value, mir::EnumValue::Default(_)),
                     catch_all: matches!(value, mir::EnumValue::CatchAll(_)),
+                    value_ranges: match value {
+                        mir::EnumValue::Values(ranges) => ranges.clone(),
+                        _ => Vec::new(),
+                    },
                 }
             })
             .collect();
@@ -419,6 +1172,23 @@ pub fn transform_enums(manifest: &mir::Manifest) -> Vec<lir::Enum> {
 
 fn repeat_to_method_kind(repeat: &Option<Repeat>, manifest: &mir::Manifest) -> lir::Repeat {
     match repeat {
+        Some(
+            outer @ Repeat {
+                inner: Some(inner), ..
+            },
+        ) => lir::Repeat::Nested {
+            outer: Box::new(repeat_to_method_kind(
+                &Some(Repeat {
+                    inner: None,
+                    ..outer.clone()
+                }),
+                manifest,
+            )),
+            inner: Box::new(repeat_to_method_kind(
+                &Some(inner.as_ref().clone()),
+                manifest,
+            )),
+        },
         Some(Repeat {
             source:
                 Spanned {
@@ -426,6 +1196,7 @@ fn repeat_to_method_kind(repeat: &Option<Repeat>, manifest: &mir::Manifest) -> l
                     ..
                 },
             stride,
+            inner: _,
             span: _,
         }) => lir::Repeat::Count {
             count: count.get(),
@@ -438,6 +1209,7 @@ fn repeat_to_method_kind(repeat: &Option<Repeat>, manifest: &mir::Manifest) -> l
                     ..
                 },
             stride,
+            inner: _,
             span: _,
         }) => {
             let target_enum = search_object(manifest, enum_name)
@@ -454,10 +1226,56 @@ fn repeat_to_method_kind(repeat: &Option<Repeat>, manifest: &mir::Manifest) -> l
                 stride: stride.value,
             }
         }
+        Some(Repeat {
+            source:
+                Spanned {
+                    value: RepeatSource::Offsets(offsets),
+                    ..
+                },
+            stride,
+            inner: _,
+            span: _,
+        }) => lir::Repeat::Offsets {
+            offsets: offsets
+                .iter()
+                .map(|offset| offset.value * stride.value)
+                .collect(),
+        },
         None => lir::Repeat::None,
     }
 }
 
+/// The value of the index at runtime. Enum repeats use the discriminant of the variant
+fn repeat_index_value(
+    repeat: &Option<Repeat>,
+    index: &mir::RepeatIndex,
+    manifest: &mir::Manifest,
+) -> i128 {
+    match (repeat, index) {
+        (_, mir::RepeatIndex::Number(index)) => *index,
+        (
+            Some(Repeat {
+                source:
+                    Spanned {
+                        value: RepeatSource::Enum(enum_name),
+                        ..
+                    },
+                ..
+            }),
+            mir::RepeatIndex::Variant(variant),
+        ) => search_object(manifest, enum_name)
+            .and_then(mir::Object::as_enum)
+            .and_then(|target_enum| {
+                target_enum
+                    .iter_variants_with_discriminant()
+                    .find(|(_, v)| variant.is_ref_to(&v.name))
+            })
+            .map(|(discriminant, _)| discriminant)
+            .expect("checked in MIR pass"),
+        _ => unreachable!("checked in MIR pass"),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BorrowedBlock<'o> {
     pub description: &'o String,
@@ -466,6 +1284,7 @@ pub struct BorrowedBlock<'o> {
     pub address_offset: &'o i128,
     #[expect(unused, reason = "included for completeness")]
     pub repeat: &'o Option<Repeat>,
+    pub chip_variants: &'o [Spanned<String>],
     pub objects: &'o [mir::Object],
 }
 
@@ -476,8 +1295,11 @@ impl<'o> From<&'o mir::Block> for BorrowedBlock<'o> {
             name,
             address_offset,
             repeat,
+            chip_variants,
             objects,
+            block_ref: _,
             default_access: _,
+            default_auto_address: _,
             short_properties_span: _,
             properties_span: _,
             span: _,
@@ -488,11 +1310,19 @@ impl<'o> From<&'o mir::Block> for BorrowedBlock<'o> {
             name,
             address_offset,
             repeat,
+            chip_variants,
             objects,
         }
     }
 }
 
+fn chip_variants_to_strings(chip_variants: &[Spanned<String>]) -> Vec<String> {
+    chip_variants
+        .iter()
+        .map(|variant| variant.value.clone())
+        .collect()
+}
+
 fn find_best_internal_address_type(manifest: &mir::Manifest, device: &mir::Device) -> Integer {
     let (min_address_found, max_address_found) = find_min_max_addresses(manifest, device, |_| true)
         .map(|((min, _), (max, _))| (min, max))
@@ -1,7 +1,10 @@
 use device_driver_common::{
     identifier::{All, Identifier, Operation, Type},
     span::Spanned,
-    specifiers::{Access, AddressMode, AddressRange, ByteOrder, Integer},
+    specifiers::{
+        Access, AddressMode, AddressRange, Bus, ByteOrder, Encoding, Integer, LevelUnit, TimeUnit,
+        ValueRange,
+    },
 };
 
 pub struct Driver {
@@ -12,7 +15,86 @@ pub struct Driver {
 
 pub struct Device {
     pub internal_address_type: Integer,
+    /// The declared chip variants. Only one of them may be enabled at a time
+    pub chip_variants: Vec<String>,
     pub blocks: Vec<Block>,
+    pub interfaces: Vec<Interface>,
+    pub state_machines: Vec<StateMachine>,
+}
+
+/// A ready made register interface over a bus
+pub struct Interface {
+    pub description: String,
+    pub name: Identifier<Type>,
+    pub bus: Bus,
+    pub register_address_type: Integer,
+    pub address_size_bytes: u32,
+    pub address_byte_order: ByteOrder,
+    pub read_bit: Option<u32>,
+    pub write_bit: Option<u32>,
+    pub increment_bit: Option<u32>,
+    pub dummy_bytes: u32,
+}
+
+/// The states of the device, generated as a typestate wrapper around the root block
+pub struct StateMachine {
+    pub description: String,
+    pub name: Identifier<Type>,
+    pub initial: Identifier<All>,
+    pub states: Vec<State>,
+    pub transitions: Vec<Transition>,
+    /// The operations that are only allowed in some states.
+    /// All other operations are allowed in every state
+    pub allows: Vec<Allow>,
+    /// The operations of the root block that are only available in the states that allow all the restricted
+    /// operations they lead to
+    pub gates: Vec<Gate>,
+}
+
+impl StateMachine {
+    /// The transitions that can be taken from the state
+    pub fn transitions_from(&self, state: &Identifier<All>) -> Vec<&Transition> {
+        self.transitions
+            .iter()
+            .filter(|transition| transition.from.contains(state))
+            .collect()
+    }
+
+    /// Whether the operation of the root block is only available in some states
+    pub fn is_gated(&self, operation: &Identifier<Operation>) -> bool {
+        self.gates
+            .iter()
+            .any(|gate| gate.operations.contains(operation))
+    }
+}
+
+pub struct State {
+    pub description: String,
+    pub name: Identifier<All>,
+}
+
+pub struct Transition {
+    pub description: String,
+    pub name: Identifier<Operation>,
+    pub from: Vec<Identifier<All>>,
+    pub to: Identifier<All>,
+    /// The statements that run for the transition. For a transition that calls a routine, this is that routine
+    pub routine: Routine,
+    /// The transition calls the routine instead of running the statements itself
+    pub calls_routine: bool,
+}
+
+pub struct Allow {
+    pub operation: Identifier<Operation>,
+    pub states: Vec<Identifier<All>>,
+    /// The operation is in a block instead of in the root block
+    pub nested: bool,
+}
+
+/// Operations of the root block that are available in the states that allow all of [Self::allows]
+pub struct Gate {
+    pub allows: Vec<Identifier<Operation>>,
+    pub operations: Vec<Identifier<Operation>>,
 }
 
 pub struct Block {
@@ -23,8 +105,22 @@ pub struct Block {
     pub register_address_type: Integer,
     pub command_address_type: Integer,
     pub buffer_address_type: Integer,
+    pub memory_address_type: Integer,
     pub register_address_mode: Option<AddressMode>,
+    /// The chip variants the block exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<String>,
     pub methods: Vec<BlockMethod>,
+    pub routines: Vec<Routine>,
+    pub composites: Vec<Composite>,
+}
+
+impl Block {
+    /// The block has memories of its own. Memories of sub blocks don't count
+    pub fn has_memories(&self) -> bool {
+        self.methods
+            .iter()
+            .any(|method| matches!(method.method_type, BlockMethodType::Memory { .. }))
+    }
 }
 
 pub struct BlockMethod {
@@ -32,6 +128,8 @@ pub struct BlockMethod {
     pub name: Identifier<Operation>,
     pub address: i128,
     pub repeat: Repeat,
+    /// The chip variants the method exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<String>,
     pub method_type: BlockMethodType,
 }
 
@@ -46,6 +144,14 @@ pub enum Repeat {
         enum_variants: Vec<Identifier<All>>,
         stride: i128,
     },
+    Offsets {
+        offsets: Vec<i128>,
+    },
+    /// A two-dimensional repeat. The index is a tuple of the outer and inner index
+    Nested {
+        outer: Box<Repeat>,
+        inner: Box<Repeat>,
+    },
 }
 
 pub enum BlockMethodType {
@@ -56,6 +162,11 @@ pub enum BlockMethodType {
         field_set_name: Identifier<Type>,
         access: Access,
         reset_value: Option<Spanned<Vec<u8>>>,
+        /// The reset value is assembled from the reset values of the fields instead of given in the source
+        reset_from_fields: bool,
+        /// Other fieldsets the register can be accessed as
+        views: Vec<Identifier<Type>>,
+        index_overrides: Vec<IndexOverride>,
     },
     Command {
         field_set_name_in: Option<Identifier<Type>>,
@@ -64,6 +175,198 @@ pub enum BlockMethodType {
     Buffer {
         access: Access,
     },
+    Memory {
+        access: Access,
+        size_bytes: u32,
+        word_size_bytes: u32,
+        /// Writes are split at the page boundaries. Not split if None
+        page_size_bytes: Option<u32>,
+        write_granularity_bytes: u32,
+        /// The memory can't be erased if None
+        erase_size_bytes: Option<u32>,
+    },
+    Fifo {
+        record_field_set_name: Identifier<Type>,
+        record_size_bytes: u32,
+        /// All records that are asked for are read if None
+        level: Option<FifoLevel>,
+    },
+}
+
+/// The reset value and access of one index of a repeated register
+pub struct IndexOverride {
+    /// The index, or the discriminant of the variant for enum repeats
+    pub index: i128,
+    pub reset_value: Option<Vec<u8>>,
+    pub access: Access,
+}
+
+/// The field of a register that holds the fill level of a fifo
+pub struct FifoLevel {
+    pub register_address: i128,
+    pub field_set_name: Identifier<Type>,
+    pub field: Identifier<All>,
+    pub unit: LevelUnit,
+}
+
+/// A sequence of statements that is generated as a method on the block
+pub struct Routine {
+    pub description: String,
+    pub name: Identifier<Operation>,
+    /// The chip variants the routine exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<String>,
+    pub statements: Vec<Statement>,
+}
+
+impl Routine {
+    pub fn uses_registers(&self) -> bool {
+        self.statements.iter().any(|statement| {
+            matches!(
+                statement,
+                Statement::Write { .. }
+                    | Statement::Modify { .. }
+                    | Statement::Poll { .. }
+                    | Statement::Return { .. }
+            )
+        })
+    }
+
+    pub fn uses_commands(&self) -> bool {
+        self.statements
+            .iter()
+            .any(|statement| matches!(statement, Statement::Dispatch { .. }))
+    }
+
+    /// The routine needs a delay. Polls wait between their reads too
+    pub fn uses_delay(&self) -> bool {
+        self.statements
+            .iter()
+            .any(|statement| matches!(statement, Statement::Delay { .. } | Statement::Poll { .. }))
+    }
+
+    /// The routine polls, so it can fail with a timeout
+    pub fn uses_poll(&self) -> bool {
+        self.statements
+            .iter()
+            .any(|statement| matches!(statement, Statement::Poll { .. }))
+    }
+
+    /// The fieldset of the register that is returned, if any
+    pub fn return_field_set(&self) -> Option<&Identifier<Type>> {
+        match self.statements.last() {
+            Some(Statement::Return { field_set_name, .. }) => Some(field_set_name),
+            _ => None,
+        }
+    }
+}
+
+/// A value made of fields in multiple registers, generated as read and write methods on the block
+pub struct Composite {
+    pub description: String,
+    pub name: Identifier<Operation>,
+    /// The chip variants the composite exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<String>,
+    pub base_type: Integer,
+    /// The parts of the value, starting with the least significant part
+    pub parts: Vec<CompositePart>,
+    /// The registers in the order they're read. Empty if the value can't be read
+    pub read_order: Vec<Identifier<Operation>>,
+    /// The registers in the order they're written. Empty if the value can't be written
+    pub write_order: Vec<Identifier<Operation>>,
+    /// The registers are read in one bulk transaction
+    pub bulk_read: bool,
+    /// The registers are written in one bulk transaction
+    pub bulk_write: bool,
+    /// The registers that hold other writable fields. They're read before they're written to keep those fields
+    pub modified: Vec<Identifier<Operation>>,
+}
+
+impl Composite {
+    pub fn size_bits(&self) -> u32 {
+        self.parts.iter().map(|part| part.size_bits).sum()
+    }
+
+    pub fn is_readable(&self) -> bool {
+        !self.read_order.is_empty()
+    }
+
+    pub fn is_writable(&self) -> bool {
+        !self.write_order.is_empty()
+    }
+
+    /// The parts that are in the register
+    pub fn parts_in(&self, register: &Identifier<Operation>) -> Vec<&CompositePart> {
+        self.parts
+            .iter()
+            .filter(|part| part.register == *register)
+            .collect()
+    }
+}
+
+/// A field of a register that holds some of the bits of a composite value
+pub struct CompositePart {
+    pub register: Identifier<Operation>,
+    pub field: Identifier<All>,
+    /// The type of the field
+    pub field_type: Integer,
+    /// The position of the least significant bit of the part in the value
+    pub shift: u32,
+    pub size_bits: u32,
+}
+
+pub enum Statement {
+    Write {
+        register: Identifier<Operation>,
+        fields: Vec<FieldValue>,
+    },
+    Modify {
+        register: Identifier<Operation>,
+        fields: Vec<FieldValue>,
+    },
+    /// Read the register until all fields have the value or the attempts run out
+    Poll {
+        register: Identifier<Operation>,
+        fields: Vec<FieldValue>,
+        /// Always at least 1
+        max_attempts: u32,
+        /// The delay between two reads
+        interval: u32,
+        interval_unit: TimeUnit,
+    },
+    Dispatch {
+        command: Identifier<Operation>,
+        /// The command has an input fieldset
+        input: bool,
+        /// The command has an output fieldset. The output is ignored
+        output: bool,
+        fields: Vec<FieldValue>,
+    },
+    Delay {
+        duration: u32,
+        unit: TimeUnit,
+    },
+    /// Read the register and return it. Always the last statement
+    Return {
+        register: Identifier<Operation>,
+        field_set_name: Identifier<Type>,
+    },
+}
+
+/// The value of a field in a statement
+pub struct FieldValue {
+    pub name: Identifier<All>,
+    pub value: Value,
+}
+
+pub enum Value {
+    Number(i128),
+    Bool(bool),
+    Variant {
+        enum_name: Identifier<Type>,
+        variant_name: Identifier<All>,
+        /// The getter of the field returns a result
+        fallible: bool,
+    },
 }
 
 /// A set of fields, like a register or command in/out
@@ -73,26 +376,157 @@ pub struct FieldSet {
     pub byte_order: ByteOrder,
     pub size_bytes: u32,
     pub fields: Vec<Field>,
+    /// The fieldset is generated as a set of flags. All fields are single bits
+    pub flags: bool,
+}
+
+impl FieldSet {
+    /// The smallest unsigned integer type that can hold all bits of the fieldset, if there is one
+    pub fn native_integer(&self) -> Option<&'static str> {
+        match self.size_bytes {
+            1 => Some("u8"),
+            2 => Some("u16"),
+            3..=4 => Some("u32"),
+            5..=8 => Some("u64"),
+            9..=16 => Some("u128"),
+            _ => None,
+        }
+    }
+
+    /// Read the bytes of the fieldset as one integer using the byte order of the fieldset.
+    /// Bit `n` of the fieldset is bit `n` of the integer.
+    pub fn bytes_to_integer(&self, bytes: &[u8]) -> u128 {
+        let mut value = 0u128;
+        for (index, byte) in bytes.iter().enumerate() {
+            let shift = match self.byte_order {
+                ByteOrder::LE => index,
+                ByteOrder::BE => bytes.len() - index - 1,
+            } * 8;
+            value |= u128::from(*byte) << shift;
+        }
+        value
+    }
+
+    /// True if any of the fields is reserved
+    pub fn has_reserved_fields(&self) -> bool {
+        self.fields.iter().any(|field| field.reserved.is_some())
+    }
+
+    /// The bytes of the fieldset with only the bit of the flag field set
+    pub fn flag_bytes(&self, field: &Field) -> Vec<u8> {
+        self.bytes_with_bits([field.address.start])
+    }
+
+    /// The bytes of the fieldset with the bits of all flag fields set
+    pub fn all_flags_bytes(&self) -> Vec<u8> {
+        self.bytes_with_bits(self.fields.iter().map(|field| field.address.start))
+    }
+
+    fn bytes_with_bits(&self, bits: impl IntoIterator<Item = u32>) -> Vec<u8> {
+        let mut bytes = vec![0; self.size_bytes as usize];
+        for bit in bits {
+            let index = match self.byte_order {
+                ByteOrder::LE => bit / 8,
+                ByteOrder::BE => self.size_bytes - bit / 8 - 1,
+            };
+            bytes[index as usize] |= 1 << (bit % 8);
+        }
+        bytes
+    }
 }
 
 pub struct Field {
     pub description: String,
     pub name: Identifier<All>,
+    /// The least significant bit range of the field
     pub address: AddressRange,
+    /// The further bit ranges of a field that is split over multiple ranges, in order of significance
+    pub split_address: Vec<AddressRange>,
     pub base_type: String,
+    pub encoding: Option<Encoding>,
     pub conversion_method: FieldConversionMethod,
     pub access: Access,
     pub repeat: Repeat,
+    /// The values the field can be set to.
+    /// Only set when this is smaller than the range of the base type, so the value needs to be checked.
+    pub value_range: Option<ValueRange>,
+    /// The value of a reserved field. The field has no setter and is set to this value on every write
+    pub reserved: Option<i128>,
 }
 
 impl Field {
     pub fn address_text(&self) -> String {
+        if self.is_split() {
+            return self
+                .address_parts()
+                .map(|address| format!("{}:{}", address.end, address.start))
+                .collect::<Vec<_>>()
+                .join(", ");
+        }
+
         if self.address.len() <= 1 {
             format!("bit {}", self.address.start)
         } else {
             format!("{}:{}", self.address.end, self.address.start)
         }
     }
+
+    /// All bit ranges of the field, from the least to the most significant part
+    pub fn address_parts(&self) -> impl Iterator<Item = &AddressRange> {
+        std::iter::once(&self.address).chain(&self.split_address)
+    }
+
+    pub fn is_split(&self) -> bool {
+        !self.split_address.is_empty()
+    }
+
+    /// The size of the value of the field, summed over all its bit ranges
+    pub fn size_bits(&self) -> u64 {
+        self.address_parts().map(|address| address.len()).sum()
+    }
+
+    /// The `(start, end)` array of the bit ranges of a split field, with the repeat `offset` added if given
+    pub fn split_parts(&self, offset: Option<&str>) -> String {
+        let parts = self
+            .address_parts()
+            .map(|address| match offset {
+                Some(offset) => {
+                    format!("({} + {offset}, {} + {offset})", address.start, address.end)
+                }
+                None => format!("({}, {})", address.start, address.end),
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", parts.join(", "))
+    }
+
+    /// The amount of bytes of a byte array field
+    pub fn size_bytes(&self) -> u64 {
+        self.address.len() / 8
+    }
+
+    /// The mask of the field's bits when the fieldset is read as one integer. See [`FieldSet::bytes_to_integer`].
+    pub fn mask(&self) -> u128 {
+        (u128::MAX >> (128 - self.address.len())) << self.address.start
+    }
+
+    /// The value of a reserved field as it's returned by the getter
+    pub fn reserved_literal(&self) -> Option<String> {
+        let reserved = self.reserved?;
+        Some(match self.conversion_method {
+            FieldConversionMethod::Bool => (reserved != 0).to_string(),
+            _ => reserved.to_string(),
+        })
+    }
+
+    /// The condition that checks if `value`, as returned by the getter, is the value of the reserved field
+    pub fn reserved_check(&self, value: &str) -> Option<String> {
+        let reserved = self.reserved?;
+        Some(match self.conversion_method {
+            FieldConversionMethod::Bool if reserved != 0 => value.to_string(),
+            FieldConversionMethod::Bool => format!("!{value}"),
+            _ => format!("{value} == {reserved}"),
+        })
+    }
 }
 
 pub enum FieldConversionMethod {
@@ -101,6 +535,10 @@ pub enum FieldConversionMethod {
     UnsafeInto(Identifier<Type>),
     TryInto(Identifier<Type>),
     Bool,
+    /// The field is a byte array
+    Bytes,
+    /// The field is a byte array containing ascii text
+    Ascii,
 }
 
 impl FieldConversionMethod {
@@ -111,6 +549,8 @@ impl FieldConversionMethod {
             FieldConversionMethod::UnsafeInto(type_path) => Some(type_path),
             FieldConversionMethod::TryInto(type_path) => Some(type_path),
             FieldConversionMethod::Bool => None,
+            FieldConversionMethod::Bytes => None,
+            FieldConversionMethod::Ascii => None,
         }
     }
 }
@@ -130,6 +570,14 @@ impl Enum {
     pub fn catch_all_variant(&self) -> Option<&EnumVariant> {
         self.variants.iter().find(|v| v.catch_all)
     }
+
+    /// The variants that cover a set of values instead of only their discriminant
+    pub fn value_set_variants(&self) -> Vec<&EnumVariant> {
+        self.variants
+            .iter()
+            .filter(|v| !v.value_ranges.is_empty())
+            .collect()
+    }
 }
 
 pub struct EnumVariant {
@@ -138,4 +586,13 @@ pub struct EnumVariant {
     pub discriminant: i128,
     pub default: bool,
     pub catch_all: bool,
+    /// The values the variant covers when it covers more than its discriminant. It then carries the raw value
+    pub value_ranges: Vec<ValueRange>,
+}
+
+impl EnumVariant {
+    /// The variant is a tuple variant that carries the raw value
+    pub fn carries_value(&self) -> bool {
+        self.catch_all || !self.value_ranges.is_empty()
+    }
 }
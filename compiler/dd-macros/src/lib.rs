@@ -8,7 +8,8 @@ use std::{
 
 use clap::Parser;
 use device_driver_core::{
-    CodegenTarget, CompileOptions, GeneralOptions, MirOptions, RustCodegenOptions,
+    CodegenTarget, CompileOptions, FsFileLoader, GeneralOptions, MirOptions, RustCodegenOptions,
+    SourceMap,
 };
 use device_driver_diagnostics::{DynError, Metadata, ResultExt};
 use proc_macro::TokenStream;
@@ -106,14 +107,18 @@ fn try_create_device(input: Input) -> Result<TokenStream, DynError> {
         .map(String::from)
         .collect::<Vec<_>>();
     let compile_options = MacroCompileOptions::try_parse_from(compile_options).into_dyn_result()?;
-    let (output, diagnostics) = device_driver_core::compile(&source, compile_options.into())?;
+    let mut source_map = SourceMap::new(source_path, source);
+    let (output, diagnostics) = device_driver_core::compile(
+        &mut source_map,
+        &FsFileLoader::default(),
+        compile_options.into(),
+    )?;
 
     diagnostics
         .print_to(
             stderr().lock(),
             Metadata {
-                source: &source,
-                source_path: &source_path,
+                source_map: &source_map,
                 term_width: None,
                 ansi: true,
                 unicode: true,
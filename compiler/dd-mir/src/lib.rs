@@ -3,7 +3,7 @@ use std::{collections::HashSet, num::NonZero, time::Duration};
 use clap::Parser;
 use device_driver_common::{
     identifier::{IdentifierRef, IdentifierType},
-    span::{Span, SpanExt},
+    span::{Span, SpanExt, Spanned},
     specifiers::{Repeat, RepeatSource},
 };
 use device_driver_diagnostics::{Diagnostics, DynError};
@@ -57,24 +57,83 @@ pub fn search_object<'o, T: IdentifierType>(
     manifest.iter_objects().find(|o| name.is_ref_to(o.name()))
 }
 
+/// Same as [search_object], but skips the objects that can't exist at the same time as the given chip variants.
+/// Use this for refs from objects that can be conditional, since objects of exclusive chip variants may share their name.
+///
+/// This assumes [passes::Assumption::NamesUnique] & [passes::Assumption::ChipVariantsPropagated]
+pub fn search_object_for_variants<'o, T: IdentifierType>(
+    manifest: &'o Manifest,
+    name: &IdentifierRef<T>,
+    chip_variants: &[Spanned<String>],
+) -> Option<&'o Object> {
+    manifest
+        .iter_objects()
+        .find(|o| name.is_ref_to(o.name()) && o.coexists_with(chip_variants))
+}
+
+/// Search an operation in the objects and in the blocks among them.
+///
+/// This assumes [passes::Assumption::NamesUnique]
+pub fn search_operation<'o, T: IdentifierType>(
+    objects: &'o [Object],
+    name: &IdentifierRef<T>,
+) -> Option<&'o Object> {
+    objects.iter().find_map(|o| match o {
+        Object::Block(block) if !name.is_ref_to(o.name()) => search_operation(&block.objects, name),
+        Object::Block(_)
+        | Object::Register(_)
+        | Object::Command(_)
+        | Object::Buffer(_)
+        | Object::Memory(_)
+        | Object::Fifo(_)
+        | Object::Routine(_)
+        | Object::Composite(_)
+            if name.is_ref_to(o.name()) =>
+        {
+            Some(o)
+        }
+        _ => None,
+    })
+}
+
 /// Returns None if device has no objects that pass the filter
 ///
-/// This assumes [passes::Assumption::RepeatStrideNonZero], [passes::Assumption::NamesUnique] & [passes::Assumption::RepeatEnumRefValid]
+/// This assumes [passes::Assumption::RepeatStrideNonZero], [passes::Assumption::NamesUnique], [passes::Assumption::RepeatEnumRefValid]
+/// & [passes::Assumption::BlockRefsValid]
 #[expect(clippy::type_complexity, reason = "I disagree")]
 pub fn find_min_max_addresses<'m>(
     manifest: &'m Manifest,
     device: &'m Device,
     filter: impl Fn(&'m Object) -> bool,
+) -> Option<((i128, &'m Object), (i128, &'m Object))> {
+    find_min_max_addresses_in(
+        manifest,
+        device,
+        device.iter_objects(),
+        device.objects.len(),
+        &filter,
+    )
+}
+
+/// Find the min and max addresses of the given objects, relative to their parent.
+/// The objects of block instances are taken from the block they reference.
+#[expect(clippy::type_complexity, reason = "I disagree")]
+fn find_min_max_addresses_in<'m>(
+    manifest: &'m Manifest,
+    device: &'m Device,
+    objects: impl Iterator<Item = &'m Object>,
+    object_count: usize,
+    filter: &dyn Fn(&'m Object) -> bool,
 ) -> Option<((i128, &'m Object), (i128, &'m Object))> {
     let mut min_address_found = i128::MAX;
     let mut min_obj_found = None;
     let mut max_address_found = i128::MIN;
     let mut max_obj_found = None;
 
-    let mut children_left = vec![device.objects.len()];
+    let mut children_left = vec![object_count];
     let mut address_offsets = vec![0];
 
-    for object in device.iter_objects() {
+    for object in objects {
         while children_left.last() == Some(&0) {
             children_left.pop();
             address_offsets.pop();
@@ -90,18 +149,44 @@ pub fn find_min_max_addresses<'m>(
             let repeat = object.repeat().cloned().unwrap_or(Repeat {
                 source: RepeatSource::Count(NonZero::new(1).unwrap()).with_dummy_span(),
                 stride: 0.with_dummy_span(),
+                inner: None,
                 span: Span::empty(),
             });
 
             let total_address_offsets = address_offsets.iter().sum::<i128>();
+            let inner_offsets = inner_repeat_offsets(&repeat, manifest);
+            let mut inner_min = inner_offsets.iter().copied().min().unwrap_or_default();
+            let mut inner_max = inner_offsets.iter().copied().max().unwrap_or_default();
+
+            // A block instance spans the objects of the block it references
+            if let Object::Block(block) = object
+                && let Some(block_ref) = &block.block_ref
+                && let Some(Object::Block(target)) = device
+                    .iter_objects()
+                    .find(|o| block_ref.is_ref_to(o.name()))
+                && let Some(((target_min, _), (target_max, _))) = find_min_max_addresses_in(
+                    manifest,
+                    device,
+                    target.iter_objects(),
+                    target.objects.len(),
+                    filter,
+                )
+            {
+                inner_min += target_min.min(0);
+                inner_max += target_max.max(0);
+            }
 
             match repeat.source.value {
                 RepeatSource::Count(count) => {
                     let count_0_address = total_address_offsets + address.value;
-                    let count_max_address = count_0_address
+                    let mut count_max_address = count_0_address
                         + (i128::from(count.get().saturating_sub(1)) * repeat.stride.value);
-                    let min_address = count_0_address.min(count_max_address);
-                    let max_address = count_0_address.max(count_max_address);
+                    // A memory takes up all the addresses of its words
+                    if let Object::Memory(memory) = object {
+                        count_max_address += i128::from(memory.size_words().saturating_sub(1));
+                    }
+                    let min_address = count_0_address.min(count_max_address) + inner_min;
+                    let max_address = count_0_address.max(count_max_address) + inner_max;
 
                     if min_address < min_address_found {
                         min_address_found = min_address;
@@ -123,13 +208,29 @@ pub fn find_min_max_addresses<'m>(
                         let address = total_address_offsets
                             + address.value
                             + (discriminant * repeat.stride.value);
-                        if address < min_address_found {
-                            min_address_found = address;
+                        if address + inner_min < min_address_found {
+                            min_address_found = address + inner_min;
                             min_obj_found = Some(object);
                         }
 
-                        if address > max_address_found {
-                            max_address_found = address;
+                        if address + inner_max > max_address_found {
+                            max_address_found = address + inner_max;
+                            max_obj_found = Some(object);
+                        }
+                    }
+                }
+                RepeatSource::Offsets(offsets) => {
+                    for offset in offsets {
+                        let address = total_address_offsets
+                            + address.value
+                            + (offset.value * repeat.stride.value);
+                        if address + inner_min < min_address_found {
+                            min_address_found = address + inner_min;
+                            min_obj_found = Some(object);
+                        }
+
+                        if address + inner_max > max_address_found {
+                            max_address_found = address + inner_max;
                             max_obj_found = Some(object);
                         }
                     }
@@ -156,6 +257,32 @@ pub fn find_min_max_addresses<'m>(
     ))
 }
 
+/// The offsets the inner dimension of a two-dimensional repeat adds to every index of the outer dimension.
+/// A repeat with one dimension only has the offset 0.
+///
+/// This assumes [passes::Assumption::NamesUnique] & [passes::Assumption::RepeatEnumRefValid]
+pub(crate) fn inner_repeat_offsets(repeat: &Repeat, manifest: &Manifest) -> Vec<i128> {
+    let Some(inner) = &repeat.inner else {
+        return vec![0];
+    };
+
+    match &inner.source.value {
+        RepeatSource::Count(count) => (0..i128::from(count.get()))
+            .map(|index| index * inner.stride.value)
+            .collect(),
+        RepeatSource::Enum(enum_name) => search_object(manifest, enum_name)
+            .and_then(Object::as_enum)
+            .expect("A mir pass checked this enum exists")
+            .iter_variants_with_discriminant()
+            .map(|(discriminant, _)| discriminant * inner.stride.value)
+            .collect(),
+        RepeatSource::Offsets(offsets) => offsets
+            .iter()
+            .map(|offset| offset.value * inner.stride.value)
+            .collect(),
+    }
+}
+
 fn remove_objects(manifest: &mut Manifest, mut removals: HashSet<UniqueId>) {
     fn try_remove_from_vec(objects: &mut Vec<Object>, removals: &mut HashSet<UniqueId>) {
         removals.retain(|removal| {
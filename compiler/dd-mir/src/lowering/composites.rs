@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use device_driver_common::{span::SpanExt, specifiers::NodeType};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{InvalidNodeType, UnknownNodeType},
+};
+use device_driver_parser::{Ident, Node};
+
+use crate::{
+    lowering::{Definitions, parse_node_to_shape},
+    model::{Composite, CompositePart, Object},
+};
+
+const SUBNODE_TYPES: &[NodeType] = &[NodeType::Part];
+
+/// Lower a composite node with its parts
+pub fn lower_composite<'src>(
+    node: &Node<'src>,
+    parent_node_name: Option<Ident<'src>>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<(Composite, Vec<Object>), Vec<Object>> {
+    // The sub nodes are not objects, so they're lowered here
+    let composite_node = Node {
+        sub_nodes: Vec::new(),
+        ..node.clone()
+    };
+    let (mut composite, siblings) = parse_node_to_shape::<Composite>(
+        &composite_node,
+        parent_node_name,
+        definitions,
+        diagnostics,
+    )?;
+
+    let mut error = false;
+
+    for sub_node in &node.sub_nodes {
+        match NodeType::from_str(sub_node.node_type.val) {
+            Ok(NodeType::Part) => {
+                match parse_node_to_shape::<CompositePart>(sub_node, None, definitions, diagnostics)
+                {
+                    Ok((part, _)) => composite.parts.push(part),
+                    Err(_) => error = true,
+                }
+            }
+            Ok(_) => {
+                diagnostics.add(InvalidNodeType {
+                    node_type: sub_node.node_type.span,
+                    parent_node_type: Some(NodeType::Composite.with_span(node.node_type.span)),
+                    allowed_node_types: SUBNODE_TYPES.to_vec(),
+                });
+                error = true;
+            }
+            Err(()) => {
+                diagnostics.add(UnknownNodeType {
+                    node_type: sub_node.node_type.span,
+                    allowed_node_types: SUBNODE_TYPES.to_vec(),
+                });
+                error = true;
+            }
+        }
+    }
+
+    if error {
+        Err(siblings)
+    } else {
+        Ok((composite, siblings))
+    }
+}
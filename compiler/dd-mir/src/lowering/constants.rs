@@ -0,0 +1,184 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use device_driver_common::{
+    span::{Span, SpanExt, Spanned},
+    specifiers::NodeType,
+};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{ConstantCycle, DivisionByZero, DuplicateConstant, IntegerOverflow, UnknownConstant},
+};
+use device_driver_parser::{BinaryOperator, Expression, Ident, IntExpression, Node};
+use itertools::Itertools;
+
+/// All constants defined in the AST
+#[derive(Default)]
+pub struct Constants<'a, 'src> {
+    constants: HashMap<&'src str, Constant<'a, 'src>>,
+    /// The values of the constants that have been evaluated, keyed by the span of their name.
+    /// None if the evaluation failed, which has already been reported.
+    values: RefCell<HashMap<Span, Option<i128>>>,
+    /// The name spans of the constants that are being evaluated right now. Used to detect cycles.
+    active: RefCell<Vec<Span>>,
+}
+
+struct Constant<'a, 'src> {
+    name: Ident<'src>,
+    value: &'a Spanned<Expression<'src>>,
+}
+
+impl<'a, 'src> Constants<'a, 'src> {
+    /// Collect all constant nodes in the tree and evaluate them
+    pub fn collect(root_node: &'a Node<'src>, diagnostics: &mut Diagnostics) -> Self {
+        let mut constants = Self::default();
+        constants.collect_node(root_node, diagnostics);
+
+        // Evaluate every constant so errors are reported even when the constant is never used
+        // Go in source order so the diagnostics are stable
+        for name in constants
+            .constants
+            .values()
+            .map(|constant| constant.name)
+            .sorted_by_key(|name| (name.span.file, name.span.start))
+        {
+            constants.constant_value(name, diagnostics).ok();
+        }
+
+        constants
+    }
+
+    fn collect_node(&mut self, node: &'a Node<'src>, diagnostics: &mut Diagnostics) {
+        if let Some(value) = constant_definition(node) {
+            if let Some(original) = self.constants.get(node.name.val) {
+                diagnostics.add(DuplicateConstant {
+                    original: original.name.span,
+                    duplicate: node.name.span,
+                });
+            } else {
+                self.constants.insert(
+                    node.name.val,
+                    Constant {
+                        name: node.name,
+                        value,
+                    },
+                );
+            }
+        }
+
+        for sub_node in node.sub_nodes.iter() {
+            self.collect_node(sub_node, diagnostics);
+        }
+    }
+
+    /// Evaluate an expression that's used where a number is expected.
+    ///
+    /// Returns None if the expression is not an integer expression or a reference to a constant.
+    /// Returns Some(Err) if the evaluation failed. This has been reported.
+    pub fn evaluate(
+        &self,
+        expression: &Spanned<Expression<'src>>,
+        diagnostics: &mut Diagnostics,
+    ) -> Option<Result<i128, ()>> {
+        match &expression.value {
+            Expression::IntExpression(int_expression) => {
+                Some(self.evaluate_int(int_expression, diagnostics))
+            }
+            Expression::TypeReference(ident) if self.constants.contains_key(ident.val) => {
+                Some(self.constant_value(*ident, diagnostics))
+            }
+            _ => None,
+        }
+    }
+
+    fn constant_value(&self, name: Ident<'src>, diagnostics: &mut Diagnostics) -> Result<i128, ()> {
+        let Some(constant) = self.constants.get(name.val) else {
+            diagnostics.add(UnknownConstant { name: name.span });
+            return Err(());
+        };
+
+        if let Some(value) = self.values.borrow().get(&constant.name.span) {
+            return value.ok_or(());
+        }
+
+        if self.active.borrow().contains(&constant.name.span) {
+            diagnostics.add(ConstantCycle {
+                constant: constant.name.span,
+                reference: name.span,
+            });
+            return Err(());
+        }
+
+        self.active.borrow_mut().push(constant.name.span);
+        let value = match &constant.value.value {
+            Expression::Number(value) => Ok(*value),
+            Expression::TypeReference(ident) => self.constant_value(*ident, diagnostics),
+            Expression::IntExpression(int_expression) => {
+                self.evaluate_int(int_expression, diagnostics)
+            }
+            _ => unreachable!("The parser only creates constants with integer expressions"),
+        };
+        self.active.borrow_mut().pop();
+
+        self.values
+            .borrow_mut()
+            .insert(constant.name.span, value.ok());
+        value
+    }
+
+    fn evaluate_int(
+        &self,
+        expression: &IntExpression<'src>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<i128, ()> {
+        let (operator, lhs, rhs) = match expression {
+            IntExpression::Number(value) => return Ok(*value),
+            IntExpression::Constant(ident) => return self.constant_value(*ident, diagnostics),
+            IntExpression::Binary { operator, lhs, rhs } => (operator, lhs, rhs),
+        };
+
+        // Evaluate both sides before bailing so all errors get reported
+        let lhs_value = self.evaluate_int(&lhs.value, diagnostics);
+        let rhs_value = self.evaluate_int(&rhs.value, diagnostics);
+        let (lhs_value, rhs_value) = (lhs_value?, rhs_value?);
+
+        let result = match operator.value {
+            BinaryOperator::Add => lhs_value.checked_add(rhs_value),
+            BinaryOperator::Sub => lhs_value.checked_sub(rhs_value),
+            BinaryOperator::Mul => lhs_value.checked_mul(rhs_value),
+            BinaryOperator::Div if rhs_value == 0 => {
+                diagnostics.add(DivisionByZero {
+                    operator: operator.span,
+                    divisor: rhs.span,
+                });
+                return Err(());
+            }
+            BinaryOperator::Div => lhs_value.checked_div(rhs_value),
+            BinaryOperator::ShiftLeft => u32::try_from(rhs_value)
+                .ok()
+                .and_then(|rhs_value| lhs_value.checked_shl(rhs_value))
+                // Bits that are shifted out are an overflow too
+                .filter(|result| result >> rhs_value == lhs_value),
+            BinaryOperator::ShiftRight => u32::try_from(rhs_value)
+                .ok()
+                .and_then(|rhs_value| lhs_value.checked_shr(rhs_value)),
+            BinaryOperator::BitOr => Some(lhs_value | rhs_value),
+            BinaryOperator::BitAnd => Some(lhs_value & rhs_value),
+        };
+
+        result.ok_or_else(|| {
+            diagnostics.add(IntegerOverflow {
+                operator: operator.value.to_string().with_span(operator.span),
+                lhs: lhs_value.with_span(lhs.span),
+                rhs: rhs_value.with_span(rhs.span),
+            });
+        })
+    }
+}
+
+/// Get the value of the node if it's a constant definition (`const NAME = value`)
+fn constant_definition<'a, 'src>(node: &'a Node<'src>) -> Option<&'a Spanned<Expression<'src>>> {
+    match node.short_properties.as_slice() {
+        [value] if node.node_type.val == NodeType::Const.to_string() => Some(value),
+        _ => None,
+    }
+}
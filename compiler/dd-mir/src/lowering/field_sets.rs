@@ -0,0 +1,196 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use device_driver_common::{span::Span, specifiers::NodeType};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{ExtendsCycle, InvalidFieldsetRef, UnknownBaseField},
+};
+use device_driver_parser::{Expression, Ident, Node};
+
+/// The property that names the fieldset a fieldset extends
+pub const EXTENDS: &str = "extends";
+/// The property that removes a field of the extended fieldset
+pub const REMOVE: &str = "remove";
+
+/// All fieldsets in the AST, so fieldsets can extend each other
+#[derive(Default)]
+pub struct FieldSets<'a, 'src> {
+    field_sets: HashMap<&'src str, &'a Node<'src>>,
+    /// The name spans of the fieldsets that are being extended right now. Used to detect cycles.
+    active: RefCell<Vec<Span>>,
+}
+
+impl<'a, 'src> FieldSets<'a, 'src> {
+    /// Collect all fieldset nodes in the tree. Fieldsets in templates are skipped since they don't exist until instantiated.
+    pub fn collect(root_node: &'a Node<'src>) -> Self {
+        let mut field_sets = Self::default();
+        field_sets.collect_node(root_node);
+        field_sets
+    }
+
+    fn collect_node(&mut self, node: &'a Node<'src>) {
+        if node.node_type.val == NodeType::Template.to_string() {
+            return;
+        }
+
+        if node.node_type.val == NodeType::FieldSet.to_string() {
+            // Duplicates are reported by the names unique pass
+            self.field_sets.entry(node.name.val).or_insert(node);
+        }
+
+        for property in node.properties.iter() {
+            if let Expression::SubNode(sub_node) = &property.expression.value {
+                if sub_node.node_type.val == NodeType::FieldSet.to_string()
+                    && sub_node.name.val == "_"
+                {
+                    // Inline fieldsets without a name take the name of the parent
+                    self.field_sets.entry(node.name.val).or_insert(sub_node);
+                } else {
+                    self.collect_node(sub_node);
+                }
+            }
+        }
+
+        for sub_node in node.sub_nodes.iter() {
+            self.collect_node(sub_node);
+        }
+    }
+
+    /// Create the node of a fieldset that extends another fieldset.
+    /// The fields of the base are copied, unless they're removed or overridden by a field with the same name.
+    /// Properties that aren't specified are copied from the base too.
+    ///
+    /// Returns None if the node doesn't extend a fieldset.
+    /// Returns Some(Err) if the node could not be resolved. This has been reported.
+    pub fn extend(
+        &self,
+        node: &Node<'src>,
+        diagnostics: &mut Diagnostics,
+    ) -> Option<Result<Node<'src>, ()>> {
+        let Expression::TypeReference(base_name) = node
+            .properties
+            .iter()
+            .find(|property| property.name.val == EXTENDS)?
+            .expression
+            .value
+        else {
+            // Let the normal lowering report the wrong expression
+            return None;
+        };
+
+        Some(self.extend_from(node, base_name, diagnostics))
+    }
+
+    fn extend_from(
+        &self,
+        node: &Node<'src>,
+        base_name: Ident<'src>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Node<'src>, ()> {
+        let Some(base) = self.field_sets.get(base_name.val) else {
+            diagnostics.add(InvalidFieldsetRef {
+                reference: base_name.span,
+                pointee: None,
+            });
+            return Err(());
+        };
+
+        if self.active.borrow().contains(&base.name.span) {
+            diagnostics.add(ExtendsCycle {
+                field_set: base.name.span,
+                reference: base_name.span,
+            });
+            return Err(());
+        }
+
+        // The base can extend a fieldset too
+        self.active.borrow_mut().push(base.name.span);
+        let extended_base = self.extend(base, diagnostics);
+        self.active.borrow_mut().pop();
+        let base = match &extended_base {
+            Some(Ok(extended_base)) => extended_base,
+            Some(Err(())) => return Err(()),
+            None => base,
+        };
+
+        let mut error = false;
+
+        let removed_fields = node
+            .properties
+            .iter()
+            .filter(|property| property.name.val == REMOVE)
+            .filter_map(|property| match property.expression.value {
+                Expression::TypeReference(ident) => Some(ident),
+                // Let the normal lowering report the wrong expression
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for removed_field in removed_fields.iter() {
+            if !base
+                .sub_nodes
+                .iter()
+                .any(|field| field.name.val == removed_field.val)
+            {
+                diagnostics.add(UnknownBaseField {
+                    field: removed_field.span,
+                    base: base_name.span,
+                });
+                error = true;
+            }
+        }
+
+        if error {
+            return Err(());
+        }
+
+        let mut extended = node.clone();
+
+        extended.properties = base
+            .properties
+            .iter()
+            .filter(|base_property| {
+                !node
+                    .properties
+                    .iter()
+                    .any(|property| property.name.val == base_property.name.val)
+            })
+            .chain(
+                node.properties
+                    .iter()
+                    .filter(|property| !matches!(property.name.val, EXTENDS | REMOVE)),
+            )
+            .cloned()
+            .collect();
+
+        // Overridden fields keep the position they have in the base
+        extended.sub_nodes = base
+            .sub_nodes
+            .iter()
+            .filter(|field| {
+                !removed_fields
+                    .iter()
+                    .any(|removed_field| removed_field.val == field.name.val)
+            })
+            .map(|field| {
+                node.sub_nodes
+                    .iter()
+                    .find(|override_field| override_field.name.val == field.name.val)
+                    .unwrap_or(field)
+            })
+            // New fields are added at the end. That includes fields that replace a removed field
+            .chain(node.sub_nodes.iter().filter(|field| {
+                removed_fields
+                    .iter()
+                    .any(|removed_field| removed_field.val == field.name.val)
+                    || !base
+                        .sub_nodes
+                        .iter()
+                        .any(|base_field| base_field.name.val == field.name.val)
+            }))
+            .cloned()
+            .collect();
+
+        Ok(extended)
+    }
+}
@@ -3,7 +3,7 @@ use std::{fmt::Write, fs, num::NonZero, path::Path};
 use device_driver_common::{
     identifier::IdentifierType,
     span::{Span, SpanExt},
-    specifiers::{BaseType, VariantNames},
+    specifiers::{BaseType, NodeType, VariantNames},
 };
 use device_driver_diagnostics::{DynError, ResultExt};
 use device_driver_parser::{Ident, Node, Property, Repeat, TypeSpecifier};
@@ -11,7 +11,11 @@ use itertools::Itertools;
 
 use crate::{
     lowering::{PropertyInfo, PropertyName, Shape},
-    model::{Block, Buffer, Command, Device, Enum, Extern, Field, FieldSet, Manifest, Register},
+    model::{
+        Allow, Block, Buffer, Command, Composite, CompositePart, Device, Enum, Extern, Field,
+        FieldSet, Fifo, IndexOverride, Interface, Manifest, Memory, Register, Routine, State,
+        StateMachine, Transition,
+    },
 };
 
 /// Generate docs for all object shapes
@@ -20,12 +24,23 @@ pub fn gen_docs(folder: &Path) -> Result<(), DynError> {
     gen_doc::<Device>(folder)?;
     gen_doc::<Block>(folder)?;
     gen_doc::<Register>(folder)?;
+    gen_doc::<IndexOverride>(folder)?;
     gen_doc::<Command>(folder)?;
     gen_doc::<Buffer>(folder)?;
+    gen_doc::<Memory>(folder)?;
+    gen_doc::<Fifo>(folder)?;
     gen_doc::<FieldSet>(folder)?;
     gen_doc::<Enum>(folder)?;
     gen_doc::<Extern>(folder)?;
     gen_doc::<Field>(folder)?;
+    gen_doc::<Interface>(folder)?;
+    gen_doc::<Routine>(folder)?;
+    gen_doc::<StateMachine>(folder)?;
+    gen_doc::<State>(folder)?;
+    gen_doc::<Transition>(folder)?;
+    gen_doc::<Allow>(folder)?;
+    gen_doc::<Composite>(folder)?;
+    gen_doc::<CompositePart>(folder)?;
 
     Ok(())
 }
@@ -192,18 +207,24 @@ fn generate_shape_example<S: Shape>() -> Node<'static> {
     Node {
         doc_comments: vec![" doc comment line".with_dummy_span()],
         node_type: Ident::new_no_span(S::NODE_TYPE.name()),
-        name: Ident::new_no_span("Example"),
+        name: Ident::new_no_span(match S::NODE_TYPE {
+            // Index nodes are named after the index they override
+            NodeType::Index => "3",
+            _ => "Example",
+        }),
         repeat: shape.repeat().map(|_| {
             Repeat {
                 source: device_driver_parser::RepeatSource::Count(NonZero::new(8).unwrap())
                     .with_dummy_span(),
-                stride: 4.with_dummy_span(),
+                stride: Some(4.with_dummy_span()),
+                inner: None,
             }
             .with_dummy_span()
         }),
         type_specifier: shape.base_type().is_some().then(|| {
             TypeSpecifier {
                 base_type: BaseType::Uint.with_dummy_span(),
+                encoding: None,
                 use_try: true,
                 conversion: shape.conversion_type().map(|_| {
                     device_driver_parser::TypeConversion::Reference(Ident::new_no_span("Foo"))
@@ -5,38 +5,55 @@ use std::{
     str::FromStr,
 };
 
-use crate::model::{Manifest, Object};
+use crate::{
+    lowering::{constants::Constants, field_sets::FieldSets, templates::Templates},
+    model::{AddressKind, Manifest, Object, Routine},
+};
 use device_driver_common::{
     identifier::{Identifier, IdentifierRef, IdentifierType, Type},
     span::{Span, SpanExt, Spanned},
-    specifiers::{BaseType, NodeType, Repeat, RepeatSource, TypeConversion},
+    specifiers::{BaseType, Encoding, NodeType, Repeat, RepeatSource, TypeConversion},
 };
 use device_driver_diagnostics::{
     Diagnostics,
     errors::{
-        DuplicateProperty, IgnoredDocCommentOnProperty, InvalidAutoIdentifier,
-        InvalidExpressionType, InvalidIdentifier, InvalidNodeType, InvalidPropertyName,
-        InvalidRepeat, InvalidShortProperty, InvalidSubnode, InvalidTypeConversion,
-        InvalidTypeSpecifier, MissingRequiredProperty, UnknownNodeType,
+        DuplicateProperty, IgnoredDocCommentOnProperty, InTemplateInstance, InvalidAutoIdentifier,
+        InvalidEncoding, InvalidExpressionType, InvalidIdentifier, InvalidNestedRepeat,
+        InvalidNodeType, InvalidPropertyName, InvalidRepeat, InvalidShortProperty, InvalidSubnode,
+        InvalidTypeConversion, InvalidTypeSpecifier, MissingRequiredProperty, UnknownNodeType,
     },
 };
 use device_driver_parser::{Ast, Expression, Ident, Node, Property};
 use itertools::Itertools;
 
+mod composites;
+mod constants;
+mod field_sets;
 #[cfg(feature = "gen-docs")]
 pub mod gen_docs;
+mod registers;
+mod routines;
 mod shape_impls;
+mod state_machines;
+mod templates;
 
 pub fn lower(ast: Ast, diagnostics: &mut Diagnostics) -> Manifest {
     let Some(root_node) = ast.root_node else {
         return Default::default();
     };
 
+    let definitions = Definitions {
+        templates: Templates::collect(&root_node, diagnostics),
+        constants: Constants::collect(&root_node, diagnostics),
+        field_sets: FieldSets::collect(&root_node),
+    };
+
     let result = lower_node(
         &root_node,
         None,
         None,
         &[NodeType::Manifest, NodeType::Device],
+        &definitions,
         diagnostics,
     );
 
@@ -46,25 +63,65 @@ pub fn lower(ast: Ast, diagnostics: &mut Diagnostics) -> Manifest {
             assert!(siblings.is_empty(), "Device doesn't have sibling objects");
             d.into()
         }
-        LowerResult::Objects(_, _) => unreachable!(),
+        LowerResult::Objects(_, _) | LowerResult::Definition => unreachable!(),
         LowerResult::Error(_) => Default::default(),
     }
 }
 
+#[allow(
+    clippy::large_enum_variant,
+    reason = "Only returned by the lowering functions, where it's almost always the objects"
+)]
 enum LowerResult {
     Manifest(Manifest),
     Objects(Object, Vec<Object>),
     Error(Vec<Object>),
+    /// Templates are only lowered when they're instantiated and constants are only used by other nodes
+    Definition,
+}
+
+/// The templates, constants and fieldsets that nodes can refer to
+struct Definitions<'a, 'src> {
+    templates: Templates<'a, 'src>,
+    constants: Constants<'a, 'src>,
+    field_sets: FieldSets<'a, 'src>,
 }
 
-fn lower_node(
-    node: &Node,
+fn lower_node<'src>(
+    node: &Node<'src>,
     parent_node_type: Option<Spanned<NodeType>>,
-    parent_node_name: Option<Ident>,
+    parent_node_name: Option<Ident<'src>>,
     allowed_node_types: &[NodeType],
+    definitions: &Definitions<'_, 'src>,
     diagnostics: &mut Diagnostics,
 ) -> LowerResult {
     let Ok(node_type) = NodeType::from_str(node.node_type.val) else {
+        if let Some(instance) = definitions.templates.instantiate(node, diagnostics) {
+            let Ok(instance_node) = instance else {
+                return LowerResult::Error(Vec::new());
+            };
+
+            let mut instance_diagnostics = Diagnostics::new();
+            let result = definitions.templates.with_active(node, || {
+                lower_node(
+                    &instance_node,
+                    parent_node_type,
+                    parent_node_name,
+                    allowed_node_types,
+                    definitions,
+                    &mut instance_diagnostics,
+                )
+            });
+            instance_diagnostics.move_into(diagnostics, |diagnostic| {
+                Box::new(InTemplateInstance {
+                    diagnostic,
+                    instance: node.node_type.span.to(node.name.span),
+                })
+            });
+
+            return result;
+        }
+
         diagnostics.add(UnknownNodeType {
             node_type: node.node_type.span,
             allowed_node_types: allowed_node_types.to_vec(),
@@ -83,55 +140,159 @@ fn lower_node(
     }
 
     match node_type.value {
-        NodeType::Manifest => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => {
-                assert!(siblings.is_empty(), "Manifest has no siblings");
-                LowerResult::Manifest(val)
+        NodeType::Manifest => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => {
+                    assert!(siblings.is_empty(), "Manifest has no siblings");
+                    LowerResult::Manifest(val)
+                }
+                Err(siblings) => LowerResult::Error(siblings),
             }
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Device => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Device(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Block => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Block(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Register => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Register(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Command => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Command(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Buffer => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Buffer(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::FieldSet => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::FieldSet(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Enum => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Enum(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Extern => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Extern(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
-        NodeType::Field => match parse_node_to_shape(node, parent_node_name, diagnostics) {
-            Ok((val, siblings)) => LowerResult::Objects(Object::Field(val), siblings),
-            Err(siblings) => LowerResult::Error(siblings),
-        },
+        }
+        NodeType::Device => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Device(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Block => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Block(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Register => {
+            match registers::lower_register(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Register(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Command => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Command(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Buffer => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Buffer(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Memory => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Memory(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Fifo => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Fifo(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::FieldSet => {
+            let extended_node;
+            let node = match definitions.field_sets.extend(node, diagnostics) {
+                Some(Ok(extended)) => {
+                    extended_node = extended;
+                    &extended_node
+                }
+                Some(Err(())) => return LowerResult::Error(Vec::new()),
+                None => node,
+            };
+
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::FieldSet(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Enum => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Enum(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Extern => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Extern(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Interface => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Interface(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Routine => {
+            // The sub nodes are statements, not objects
+            let routine_node = Node {
+                sub_nodes: Vec::new(),
+                ..node.clone()
+            };
+            let routine = parse_node_to_shape::<Routine>(
+                &routine_node,
+                parent_node_name,
+                definitions,
+                diagnostics,
+            );
+            let statements = routines::lower_statements(node, definitions, diagnostics);
+
+            match (routine, statements) {
+                (Ok((mut val, siblings)), Ok(statements)) => {
+                    if val.properties_span.is_none() {
+                        val.properties_span = statements
+                            .iter()
+                            .map(|statement| statement.span())
+                            .reduce(|acc, val| acc.to(val));
+                    }
+                    val.statements = statements;
+                    LowerResult::Objects(Object::Routine(val), siblings)
+                }
+                (Ok((_, siblings)) | Err(siblings), _) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Composite => {
+            match composites::lower_composite(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Composite(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::States => {
+            match state_machines::lower_state_machine(
+                node,
+                parent_node_name,
+                definitions,
+                diagnostics,
+            ) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::StateMachine(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Field => {
+            match parse_node_to_shape(node, parent_node_name, definitions, diagnostics) {
+                Ok((val, siblings)) => LowerResult::Objects(Object::Field(val), siblings),
+                Err(siblings) => LowerResult::Error(siblings),
+            }
+        }
+        NodeType::Template | NodeType::Const => LowerResult::Definition,
+        NodeType::State | NodeType::Transition | NodeType::Allowed => {
+            unreachable!("Only allowed in a states node, which lowers them itself")
+        }
+        NodeType::Part => {
+            unreachable!("Only allowed in a composite node, which lowers them itself")
+        }
+        NodeType::Index => {
+            unreachable!("Only allowed in a register node, which lowers them itself")
+        }
     }
 }
 
 fn parse_node_to_shape<'src, S: Shape>(
     node: &Node<'src>,
     parent_node_name: Option<Ident<'src>>,
+    definitions: &Definitions<'_, 'src>,
     diagnostics: &mut Diagnostics,
 ) -> Result<(S, Vec<Object>), Vec<Object>> {
     let mut target = S::default();
@@ -178,7 +339,7 @@ fn parse_node_to_shape<'src, S: Shape>(
 
     // Repeat
 
-    match (target.repeat(), node.repeat) {
+    match (target.repeat(), &node.repeat) {
         (None, Some(node_repeat)) => {
             diagnostics.add(InvalidRepeat {
                 repeat: node_repeat.span,
@@ -186,17 +347,35 @@ fn parse_node_to_shape<'src, S: Shape>(
             });
         }
         (Some(target_repeat), Some(node_repeat)) => {
-            *target_repeat = Some(Repeat {
-                source: match node_repeat.source.value {
-                    device_driver_parser::RepeatSource::Count(count) => RepeatSource::Count(count),
-                    device_driver_parser::RepeatSource::Enum(ident) => {
-                        RepeatSource::Enum(IdentifierRef::new(ident.val.into()))
-                    }
+            if let Some(inner) = &node_repeat.inner {
+                let reason = if S::NODE_TYPE != NodeType::Register {
+                    Some(format!(
+                        "only register nodes can be repeated in two dimensions, not {} nodes",
+                        S::NODE_TYPE
+                    ))
+                } else if [&node_repeat.source.value, &inner.source.value]
+                    .iter()
+                    .any(|source| matches!(source, device_driver_parser::RepeatSource::Offsets(_)))
+                {
+                    Some(
+                        "the dimensions of a two-dimensional repeat must be a count or an enum"
+                            .into(),
+                    )
+                } else {
+                    None
+                };
+
+                if let Some(reason) = reason {
+                    diagnostics.add(InvalidNestedRepeat {
+                        repeat: inner.span,
+                        node_type: node.node_type.span,
+                        reason: reason.into(),
+                    });
+                    error = true;
                 }
-                .with_span(node_repeat.source.span),
-                stride: (node_repeat.stride.value as i128).with_span(node_repeat.stride.span),
-                span: node_repeat.span,
-            })
+            }
+
+            *target_repeat = Some(convert_repeat(node_repeat));
         }
         (_, None) => {}
     }
@@ -217,6 +396,25 @@ fn parse_node_to_shape<'src, S: Shape>(
         }
     }
 
+    // Encoding
+
+    match (
+        target.encoding(),
+        node.type_specifier.as_ref().and_then(|ts| ts.encoding),
+    ) {
+        (None, Some(encoding)) => {
+            if target.base_type().is_some() {
+                // Only emit this diagnostic if a base type is supported. Otherwise we'll get double diagnostics
+                diagnostics.add(InvalidEncoding {
+                    node_type: S::NODE_TYPE.with_span(node.node_type.span),
+                    encoding: encoding.span,
+                });
+            }
+        }
+        (None, None) => {}
+        (Some(target_encoding), encoding) => *target_encoding = encoding,
+    }
+
     // Conversion
 
     match (target.conversion_type(), node.type_specifier.as_ref()) {
@@ -243,11 +441,12 @@ fn parse_node_to_shape<'src, S: Shape>(
                             Some(NodeType::Field.with_span(node.node_type.span)),
                             Some(node.name),
                             &[NodeType::Enum, NodeType::Extern],
+                            definitions,
                             diagnostics,
                         );
 
                         match sub_node {
-                            LowerResult::Manifest(_) => unreachable!(),
+                            LowerResult::Manifest(_) | LowerResult::Definition => unreachable!(),
                             LowerResult::Objects(object, objects) => {
                                 let reference = object
                                     .name()
@@ -281,6 +480,8 @@ fn parse_node_to_shape<'src, S: Shape>(
     let mut possible_properties = S::supported_properties().to_vec();
     let mut removed_properties = HashMap::new();
     let mut removed_short_properties = HashMap::new();
+    // Properties that may be given multiple times stay possible, so they're tracked here
+    let mut seen_multiple_properties = Vec::new();
     for property in &node.properties {
         *target.properties_span() = Some(
             target
@@ -328,6 +529,62 @@ fn parse_node_to_shape<'src, S: Shape>(
             });
         }
 
+        // `auto` is the keyword for an automatic value where one is expected. `_` is the same
+        let auto_property;
+        let property = match &property.expression.value {
+            Expression::TypeReference(ident)
+                if ident.val == "auto"
+                    && property_info
+                        .allowed_expression_types
+                        .iter()
+                        .any(|e| matches!(e, Expression::Auto)) =>
+            {
+                auto_property = Property {
+                    expression: Expression::Auto.with_span(property.expression.span),
+                    ..property.value.clone()
+                }
+                .with_span(property.span);
+                &auto_property
+            }
+            _ => property,
+        };
+
+        // Integer expressions and constants are evaluated to a number where a number is expected
+        let evaluated_property;
+        let property = match property_info
+            .allowed_expression_types
+            .iter()
+            .any(|e| matches!(e, Expression::Number(_)))
+            .then(|| {
+                definitions
+                    .constants
+                    .evaluate(&property.expression, diagnostics)
+            })
+            .flatten()
+        {
+            Some(Ok(value)) => {
+                evaluated_property = Property {
+                    expression: Expression::Number(value).with_span(property.expression.span),
+                    ..property.value.clone()
+                }
+                .with_span(property.span);
+                &evaluated_property
+            }
+            Some(Err(())) => {
+                // The property is treated as seen to prevent a missing property error
+                error = true;
+                if property_info.multiple_allowed {
+                    seen_multiple_properties.push(property_info.name);
+                } else {
+                    possible_properties
+                        .remove(possible_properties.element_offset(property_info).unwrap());
+                    removed_properties.insert(property.name.val, property.name.span);
+                }
+                continue;
+            }
+            None => property,
+        };
+
         // Get the discriminant and cast it to the static lifetime which is explicitly allowed in the rust docs
         let current_expression_type = unsafe {
             std::mem::transmute::<Discriminant<Expression<'src>>, Discriminant<Expression<'static>>>(
@@ -368,11 +625,14 @@ fn parse_node_to_shape<'src, S: Shape>(
             target_object: &mut target,
             property,
             node,
+            definitions,
             diagnostics,
             sibling_objects: &mut sibling_objects,
         });
 
-        if !property_info.multiple_allowed {
+        if property_info.multiple_allowed {
+            seen_multiple_properties.push(property_info.name);
+        } else {
             possible_properties.remove(possible_properties.element_offset(property_info).unwrap());
             removed_properties.insert(property.name.val, property.name.span);
         }
@@ -382,6 +642,8 @@ fn parse_node_to_shape<'src, S: Shape>(
             .sub_nodes
             .iter()
             .map(|n| n.span)
+            // Included nodes live in other files
+            .filter(|span| span.file == node.span.file)
             .reduce(|acc, val| acc.to(val));
     }
 
@@ -440,11 +702,14 @@ fn parse_node_to_shape<'src, S: Shape>(
             }
             .with_span(short_property.span),
             node,
+            definitions,
             diagnostics,
             sibling_objects: &mut sibling_objects,
         });
 
-        if !property_info.multiple_allowed {
+        if property_info.multiple_allowed {
+            seen_multiple_properties.push(property_info.name);
+        } else {
             for allowed_expression in property_info.allowed_expression_types.iter() {
                 removed_short_properties
                     .insert(discriminant(allowed_expression), short_property.span);
@@ -455,16 +720,26 @@ fn parse_node_to_shape<'src, S: Shape>(
     if target.short_properties_span().is_empty() {
         *target.short_properties_span() = node
             .repeat
+            .as_ref()
             .map(|r| r.span)
             .unwrap_or_default()
             .or(node.name.span)
             .collapse_to_end();
     }
 
+    // A left out address is only allowed in a block with a default address, which is checked in a MIR pass
+    if let Some(address_kind) = target.address_kind()
+        && possible_properties
+            .iter()
+            .any(|p| p.name == PropertyName::Exact("address"))
+    {
+        *address_kind = AddressKind::Missing;
+    }
+
     // Required properties that haven't been seen
     let missing_properties = possible_properties
         .iter()
-        .filter(|info| info.required)
+        .filter(|info| info.required && !seen_multiple_properties.contains(&info.name))
         .collect::<Vec<_>>();
 
     if !missing_properties.is_empty() {
@@ -507,6 +782,7 @@ fn parse_node_to_shape<'src, S: Shape>(
                 Some(S::NODE_TYPE.with_span(node.node_type.span)),
                 None,
                 supported_subnodes,
+                definitions,
                 diagnostics,
             );
 
@@ -519,6 +795,7 @@ fn parse_node_to_shape<'src, S: Shape>(
                 LowerResult::Error(siblings) => {
                     sibling_objects.extend(siblings);
                 }
+                LowerResult::Definition => {}
             }
         }
     } else if let Some(subnode) = node.sub_nodes.first() {
@@ -544,6 +821,33 @@ fn parse_node_to_shape<'src, S: Shape>(
     }
 }
 
+fn convert_repeat(node_repeat: &Spanned<device_driver_parser::Repeat>) -> Repeat {
+    Repeat {
+        source: match &node_repeat.source.value {
+            device_driver_parser::RepeatSource::Count(count) => RepeatSource::Count(*count),
+            device_driver_parser::RepeatSource::Enum(ident) => {
+                RepeatSource::Enum(IdentifierRef::new(ident.val.into()))
+            }
+            device_driver_parser::RepeatSource::Offsets(offsets) => RepeatSource::Offsets(
+                offsets
+                    .iter()
+                    .map(|offset| i128::from(offset.value).with_span(offset.span))
+                    .collect(),
+            ),
+        }
+        .with_span(node_repeat.source.span),
+        stride: match node_repeat.stride {
+            Some(stride) => i128::from(stride.value).with_span(stride.span),
+            None => 1.with_span(node_repeat.source.span),
+        },
+        inner: node_repeat
+            .inner
+            .as_deref()
+            .map(|inner| Box::new(convert_repeat(inner))),
+        span: node_repeat.span,
+    }
+}
+
 trait Shape: Default + 'static {
     const NODE_TYPE: NodeType;
     type NameIdentifierType: IdentifierType + Default;
@@ -567,6 +871,11 @@ trait Shape: Default + 'static {
         None
     }
 
+    /// If the shape supports an encoding on its base type, Some is returned
+    fn encoding(&mut self) -> Option<&mut Option<Spanned<Encoding>>> {
+        None
+    }
+
     fn conversion_type(&mut self) -> Option<&mut Option<TypeConversion>> {
         None
     }
@@ -575,6 +884,11 @@ trait Shape: Default + 'static {
         None
     }
 
+    /// If the shape has an address, Some is returned
+    fn address_kind(&mut self) -> Option<&mut AddressKind> {
+        None
+    }
+
     fn properties_span(&mut self) -> &mut Option<Span>;
     fn short_properties_span(&mut self) -> &mut Span;
     fn span(&mut self) -> &mut Span;
@@ -590,7 +904,6 @@ struct PropertyInfo<T: ?Sized> {
     /// If true, multiple of these properties are allowed
     multiple_allowed: bool,
     /// If true, the property must be set by the user.
-    /// Doesn't work well with [`Self::multiple_allowed`] set at the same time.
     required: bool,
     /// If false, a warning is emitted when the property has doc comments
     supports_doc_comments: bool,
@@ -619,6 +932,7 @@ struct SetterArgs<'a, 'src, T: ?Sized> {
     property: &'a Spanned<Property<'src>>,
     /// The node that's being parsed
     node: &'a Node<'src>,
+    definitions: &'a Definitions<'a, 'src>,
     diagnostics: &'a mut Diagnostics,
     sibling_objects: &'a mut Vec<Object>,
 }
@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use device_driver_common::{identifier::IdentifierRef, span::SpanExt, specifiers::NodeType};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{InvalidIndexOverride, InvalidNodeType, UnknownNodeType},
+};
+use device_driver_parser::{Ident, Node, parse_num};
+
+use crate::{
+    lowering::{Definitions, parse_node_to_shape},
+    model::{IndexOverride, Object, Register, RepeatIndex},
+};
+
+const SUBNODE_TYPES: &[NodeType] = &[NodeType::Index];
+
+/// Lower a register node with the overrides of its indices
+pub fn lower_register<'src>(
+    node: &Node<'src>,
+    parent_node_name: Option<Ident<'src>>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<(Register, Vec<Object>), Vec<Object>> {
+    // The sub nodes are not objects, so they're lowered here
+    let register_node = Node {
+        sub_nodes: Vec::new(),
+        ..node.clone()
+    };
+    let (mut register, siblings) = parse_node_to_shape::<Register>(
+        &register_node,
+        parent_node_name,
+        definitions,
+        diagnostics,
+    )?;
+
+    let mut error = false;
+
+    for sub_node in &node.sub_nodes {
+        match NodeType::from_str(sub_node.node_type.val) {
+            Ok(NodeType::Index) => {
+                let Some(index) = repeat_index(sub_node.name) else {
+                    diagnostics.add(InvalidIndexOverride {
+                        index: sub_node.name.span,
+                        problem: sub_node.name.span,
+                        pointee: None,
+                        reason: "expected a number or an enum variant".into(),
+                    });
+                    error = true;
+                    continue;
+                };
+
+                // The node is named after the index, so give it the name of the register instead
+                let index_node = Node {
+                    name: Ident::new_auto(sub_node.name.span),
+                    ..sub_node.clone()
+                };
+                match parse_node_to_shape::<IndexOverride>(
+                    &index_node,
+                    Some(Ident::new(register.name.original(), register.name.span)),
+                    definitions,
+                    diagnostics,
+                ) {
+                    Ok((mut index_override, _)) => {
+                        index_override.index = index.with_span(sub_node.name.span);
+                        register.index_overrides.push(index_override);
+                    }
+                    Err(_) => error = true,
+                }
+            }
+            Ok(_) => {
+                diagnostics.add(InvalidNodeType {
+                    node_type: sub_node.node_type.span,
+                    parent_node_type: Some(NodeType::Register.with_span(node.node_type.span)),
+                    allowed_node_types: SUBNODE_TYPES.to_vec(),
+                });
+                error = true;
+            }
+            Err(()) => {
+                diagnostics.add(UnknownNodeType {
+                    node_type: sub_node.node_type.span,
+                    allowed_node_types: SUBNODE_TYPES.to_vec(),
+                });
+                error = true;
+            }
+        }
+    }
+
+    if error {
+        Err(siblings)
+    } else {
+        Ok((register, siblings))
+    }
+}
+
+/// Get the index an index node is named after
+fn repeat_index(name: Ident) -> Option<RepeatIndex> {
+    if name.is_auto() {
+        None
+    } else if name.val.starts_with(|c: char| c.is_ascii_digit()) {
+        parse_num::<i128>(name.val).ok().map(RepeatIndex::Number)
+    } else {
+        Some(RepeatIndex::Variant(IdentifierRef::new(name.val.into())))
+    }
+}
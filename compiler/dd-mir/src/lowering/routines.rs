@@ -0,0 +1,450 @@
+use std::str::FromStr;
+
+use device_driver_common::{
+    identifier::IdentifierRef,
+    span::{SpanExt, Spanned},
+    specifiers::{TimeUnit, VariantNames},
+};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{InvalidStatement, UnknownStatement},
+};
+use device_driver_parser::{Expression, Node};
+use itertools::Itertools;
+
+use crate::{
+    lowering::Definitions,
+    model::{FieldAssignment, FieldValue, PollLimit, Statement, TargetStatement},
+};
+
+/// Lower the sub nodes of a routine node to statements.
+///
+/// Returns Err if any of the statements is invalid. This has been reported.
+pub fn lower_statements<'src>(
+    routine_node: &Node<'src>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<Vec<Statement>, ()> {
+    let mut statements = Vec::new();
+    let mut error = false;
+
+    for (index, node) in routine_node.sub_nodes.iter().enumerate() {
+        match lower_statement(node, definitions, diagnostics) {
+            Ok(statement) => {
+                if let Statement::Return(_) = statement
+                    && index != routine_node.sub_nodes.len() - 1
+                {
+                    diagnostics.add(InvalidStatement {
+                        statement: node.node_type.span,
+                        problem: node.node_type.span,
+                        reason: "`return` must be the last statement of the routine".into(),
+                    });
+                    error = true;
+                }
+
+                statements.push(statement);
+            }
+            Err(()) => error = true,
+        }
+    }
+
+    if error { Err(()) } else { Ok(statements) }
+}
+
+fn lower_statement<'src>(
+    node: &Node<'src>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<Statement, ()> {
+    let mut error = false;
+    let mut report = |problem, reason: &'static str| {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem,
+            reason: reason.into(),
+        });
+        error = true;
+    };
+
+    if let Some(repeat) = &node.repeat {
+        report(repeat.span, "statements can't have a repeat");
+    }
+    if let Some(type_specifier) = node.type_specifier.as_ref() {
+        report(
+            type_specifier.span,
+            "statements can't have a type specifier",
+        );
+    }
+    if let Some(sub_node) = node.sub_nodes.first() {
+        report(sub_node.span, "statements can't have sub nodes");
+    }
+
+    let statement = match node.node_type.val {
+        "delay" => lower_delay(node, definitions, diagnostics),
+        "write" => lower_target_statement(node, definitions, diagnostics).map(Statement::Write),
+        "modify" => lower_target_statement(node, definitions, diagnostics).map(Statement::Modify),
+        "dispatch" => {
+            lower_target_statement(node, definitions, diagnostics).map(Statement::Dispatch)
+        }
+        "poll" => lower_poll(node, definitions, diagnostics),
+        "return" => {
+            let statement = lower_target_statement(node, definitions, diagnostics)?;
+            if let Some(field) = statement.fields.first() {
+                diagnostics.add(InvalidStatement {
+                    statement: node.node_type.span,
+                    problem: field.field.span,
+                    reason: "`return` can't set fields".into(),
+                });
+                return Err(());
+            }
+            Ok(Statement::Return(statement))
+        }
+        _ => {
+            diagnostics.add(UnknownStatement {
+                statement: node.node_type.span,
+            });
+            Err(())
+        }
+    };
+
+    if error { Err(()) } else { statement }
+}
+
+/// Lower `delay <unit> <duration>`
+fn lower_delay<'src>(
+    node: &Node<'src>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<Statement, ()> {
+    let unit = TimeUnit::from_str(node.name.val).map_err(|()| {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem: node.name.span,
+            reason: format!(
+                "unknown time unit, expected one of: {}",
+                TimeUnit::VARIANTS
+                    .iter()
+                    .map(|unit| format!("`{unit}`"))
+                    .join(", ")
+            )
+            .into(),
+        });
+    })?;
+
+    if let Some(property) = node.properties.first() {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem: property.span,
+            reason: "`delay` has no properties".into(),
+        });
+        return Err(());
+    }
+
+    let [duration] = node.short_properties.as_slice() else {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem: node
+                .short_properties
+                .iter()
+                .map(|p| p.span)
+                .reduce(|x, y| x.to(y))
+                .unwrap_or(node.name.span),
+            reason: "`delay` needs exactly one duration, like `delay ms 10`".into(),
+        });
+        return Err(());
+    };
+
+    let value = lower_u32(node, duration, "duration", definitions, diagnostics)?;
+
+    Ok(Statement::Delay {
+        duration: value.with_span(duration.span),
+        unit,
+        span: node.span,
+    })
+}
+
+/// Lower `poll <Target> { field: value, ..., max-attempts: n, timeout-<unit>: n, interval-<unit>: n }`
+fn lower_poll<'src>(
+    node: &Node<'src>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<Statement, ()> {
+    // The limits are given in the body next to the field values. Split them off
+    let mut field_node = node.clone();
+    field_node
+        .properties
+        .retain(|property| PollLimitProperty::parse(property.name.val).is_none());
+    let statement = lower_target_statement(&field_node, definitions, diagnostics);
+
+    let mut error = false;
+    let mut report = |diagnostics: &mut Diagnostics, problem, reason: &'static str| {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem,
+            reason: reason.into(),
+        });
+        error = true;
+    };
+
+    let mut invalid_value = false;
+    let mut max_attempts: Option<Spanned<u32>> = None;
+    let mut timeout: Option<(Spanned<u32>, TimeUnit)> = None;
+    let mut interval: Option<(Spanned<u32>, TimeUnit)> = None;
+
+    for property in &node.properties {
+        let Some(limit) = PollLimitProperty::parse(property.name.val) else {
+            continue;
+        };
+
+        let (already_given, what) = match limit {
+            PollLimitProperty::MaxAttempts => (max_attempts.is_some(), "attempt count"),
+            PollLimitProperty::Timeout(_) => (timeout.is_some(), "timeout"),
+            PollLimitProperty::Interval(_) => (interval.is_some(), "interval"),
+        };
+        if already_given {
+            report(
+                diagnostics,
+                property.name.span,
+                "the limit is given more than once",
+            );
+            continue;
+        }
+
+        let Ok(value) = lower_u32(node, &property.expression, what, definitions, diagnostics)
+        else {
+            invalid_value = true;
+            continue;
+        };
+        let value = value.with_span(property.expression.span);
+
+        match limit {
+            PollLimitProperty::MaxAttempts => max_attempts = Some(value),
+            PollLimitProperty::Timeout(unit) => timeout = Some((value, unit)),
+            PollLimitProperty::Interval(unit) => interval = Some((value, unit)),
+        }
+    }
+
+    if let Ok(statement) = &statement
+        && statement.fields.is_empty()
+    {
+        report(
+            diagnostics,
+            node.name.span,
+            "`poll` needs at least one field value to wait for",
+        );
+    }
+
+    if invalid_value {
+        return Err(());
+    }
+
+    if interval.is_none() {
+        report(
+            diagnostics,
+            node.name.span,
+            "`poll` needs an interval to wait between reads, like `interval-us: 100`",
+        );
+    }
+    if max_attempts.is_none() && timeout.is_none() {
+        report(
+            diagnostics,
+            node.name.span,
+            "`poll` needs a `max-attempts` or a timeout, like `timeout-ms: 10`, so it can't wait forever",
+        );
+    }
+    if let Some(max_attempts) = max_attempts
+        && max_attempts.value == 0
+    {
+        report(
+            diagnostics,
+            max_attempts.span,
+            "`max-attempts` must be at least 1",
+        );
+    }
+
+    let Some((interval, interval_unit)) = interval else {
+        return Err(());
+    };
+
+    // The first read is done right away, the next ones after every interval
+    let timeout_attempts = match timeout {
+        Some(_) if interval.value == 0 => {
+            report(
+                diagnostics,
+                interval.span,
+                "a timeout needs an interval larger than 0 to count the reads",
+            );
+            None
+        }
+        Some((timeout, timeout_unit)) => {
+            let attempts = (u64::from(timeout.value) * timeout_unit.nanos())
+                / (u64::from(interval.value) * interval_unit.nanos())
+                + 1;
+            Some(u32::try_from(attempts).unwrap_or(u32::MAX))
+        }
+        None => None,
+    };
+
+    if error {
+        return Err(());
+    }
+
+    let max_attempts = match (max_attempts, timeout_attempts) {
+        (Some(max_attempts), Some(timeout_attempts)) => max_attempts.value.min(timeout_attempts),
+        (Some(max_attempts), None) => max_attempts.value,
+        (None, Some(timeout_attempts)) => timeout_attempts,
+        (None, None) => unreachable!("A missing limit has been reported"),
+    };
+
+    Ok(Statement::Poll(
+        statement?,
+        PollLimit {
+            max_attempts,
+            interval: interval.value,
+            interval_unit,
+        },
+    ))
+}
+
+/// The properties of a poll that limit how long it keeps reading
+#[derive(Debug, Clone, Copy)]
+enum PollLimitProperty {
+    MaxAttempts,
+    Timeout(TimeUnit),
+    Interval(TimeUnit),
+}
+
+impl PollLimitProperty {
+    fn parse(name: &str) -> Option<Self> {
+        if name == "max-attempts" {
+            return Some(Self::MaxAttempts);
+        }
+
+        if let Some(unit) = name.strip_prefix("timeout-") {
+            return TimeUnit::from_str(unit).ok().map(Self::Timeout);
+        }
+        if let Some(unit) = name.strip_prefix("interval-") {
+            return TimeUnit::from_str(unit).ok().map(Self::Interval);
+        }
+
+        None
+    }
+}
+
+/// Evaluate a number or constant that must fit in a u32
+fn lower_u32<'src>(
+    node: &Node<'src>,
+    expression: &Spanned<Expression<'src>>,
+    what: &str,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<u32, ()> {
+    let value = match definitions.constants.evaluate(expression, diagnostics) {
+        Some(value) => value?,
+        None => match expression.value {
+            Expression::Number(value) => value,
+            _ => {
+                diagnostics.add(InvalidStatement {
+                    statement: node.node_type.span,
+                    problem: expression.span,
+                    reason: format!("the {what} must be a number").into(),
+                });
+                return Err(());
+            }
+        },
+    };
+
+    u32::try_from(value).map_err(|_| {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem: expression.span,
+            reason: format!("the {what} must be in the range 0..2^32").into(),
+        });
+    })
+}
+
+/// Lower `<keyword> <Target> { field: value, ... }`
+fn lower_target_statement<'src>(
+    node: &Node<'src>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<TargetStatement, ()> {
+    let mut error = false;
+
+    if node.name.is_auto() {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem: node.name.span,
+            reason: "the target must be named".into(),
+        });
+        error = true;
+    }
+
+    if let Some(short_property) = node.short_properties.first() {
+        diagnostics.add(InvalidStatement {
+            statement: node.node_type.span,
+            problem: short_property.span,
+            reason: "unexpected value. Field values are given in the body, like `{ field: 1 }`"
+                .into(),
+        });
+        error = true;
+    }
+
+    let mut fields: Vec<FieldAssignment> = Vec::new();
+
+    for property in &node.properties {
+        if let Some(original) = fields
+            .iter()
+            .find(|f| f.field.original() == property.name.val)
+        {
+            diagnostics.add(InvalidStatement {
+                statement: original.field.span,
+                problem: property.name.span,
+                reason: "the field is given more than once".into(),
+            });
+            error = true;
+            continue;
+        }
+
+        let value = match definitions
+            .constants
+            .evaluate(&property.expression, diagnostics)
+        {
+            Some(Ok(value)) => FieldValue::Number(value),
+            Some(Err(())) => {
+                error = true;
+                continue;
+            }
+            None => match &property.expression.value {
+                Expression::Number(value) => FieldValue::Number(*value),
+                Expression::TypeReference(variant) => {
+                    FieldValue::Variant(IdentifierRef::new(variant.val.into()))
+                }
+                _ => {
+                    diagnostics.add(InvalidStatement {
+                        statement: node.node_type.span,
+                        problem: property.expression.span,
+                        reason: "expected a number or the name of an enum variant".into(),
+                    });
+                    error = true;
+                    continue;
+                }
+            },
+        };
+
+        fields.push(FieldAssignment {
+            field: IdentifierRef::new(property.name.val.into()).with_span(property.name.span),
+            value: value.with_span(property.expression.span),
+        });
+    }
+
+    if error {
+        return Err(());
+    }
+
+    Ok(TargetStatement {
+        target: IdentifierRef::new(node.name.val.into()).with_span(node.name.span),
+        fields,
+        span: node.span,
+    })
+}
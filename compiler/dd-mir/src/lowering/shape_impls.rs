@@ -1,10 +1,13 @@
 use std::{borrow::Cow, sync::LazyLock};
 
 use crate::{
-    lowering::{LowerResult, PropertyInfo, PropertyName, SetterArgs, Shape, lower_node},
+    lowering::{
+        LowerResult, PropertyInfo, PropertyName, SetterArgs, Shape, field_sets, lower_node,
+    },
     model::{
-        Block, Buffer, Command, Device, Enum, EnumValue, EnumVariant, Extern, Field, FieldSet,
-        Manifest, Object, Register,
+        AddressKind, Allow, Block, Buffer, Command, Composite, CompositePart, Device, Enum,
+        EnumValue, EnumVariant, Extern, Field, FieldSet, FieldValue, Fifo, IndexOverride,
+        Interface, Manifest, Memory, Object, Register, Routine, State, StateMachine, Transition,
     },
 };
 use convert_case::Boundary;
@@ -12,15 +15,20 @@ use device_driver_common::{
     identifier::{All, Identifier, IdentifierRef, Operation, Type},
     span::{Span, SpanExt, Spanned},
     specifiers::{
-        Access, AddressMode, AddressRange, BaseType, ByteOrder, Integer, NodeType, Repeat,
-        ResetValue, TypeConversion,
+        Access, AddressMode, AddressRange, BaseType, Bus, ByteOrder, Encoding, Integer, LevelUnit,
+        NodeType, Repeat, ResetValue, TypeConversion, ValueRange, VariantNames,
     },
 };
-use device_driver_diagnostics::errors::{
-    ExternInvalidSizeBits, FieldAddressOutOfRange, FieldAddressWrongOrder, InvalidIdentifier,
-    ResetValueNegative, SizeBytesTooLarge,
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{
+        ExternInvalidSizeBits, FieldAddressOutOfRange, FieldAddressWrongOrder,
+        InvalidChipVariantName, InvalidFifo, InvalidIdentifier, InvalidInterfaceProperty,
+        InvalidMemoryProperty, RemoveWithoutExtends, ResetValueNegative, SizeBytesTooLarge,
+        ValueRangeWrongOrder,
+    },
 };
-use device_driver_parser::{Expression, Ident, Node};
+use device_driver_parser::{Expression, Ident, Node, Property};
 use itertools::Itertools;
 
 const FIELD_SET_EXAMPLE: Node<'static> = Node {
@@ -35,6 +43,69 @@ const FIELD_SET_EXAMPLE: Node<'static> = Node {
     span: Span::empty(),
 };
 
+/// Get the reset value of a register from the property. Returns none if the value can't be used.
+fn reset_value(property: &Property, diagnostics: &mut Diagnostics) -> Option<Spanned<ResetValue>> {
+    match &property.expression.value {
+        Expression::Number(num) => match u128::try_from(*num) {
+            Ok(num) => Some(ResetValue::Integer(num).with_span(property.expression.span)),
+            Err(_) => {
+                diagnostics.add(ResetValueNegative {
+                    reset_value: property.expression.span,
+                });
+                None
+            }
+        },
+        Expression::ByteArray(bytes) => {
+            Some(ResetValue::Array(bytes.to_vec()).with_span(property.expression.span))
+        }
+        _ => unreachable!("Only numbers and byte arrays are allowed for reset values"),
+    }
+}
+
+const CHIP_VARIANT_EXAMPLE: Expression<'static> =
+    Expression::TypeReference(device_driver_parser::Ident::new_no_span("variant-a"));
+
+/// Set the address to the number of the property or mark it as automatic when the property is `auto` or `_`
+fn set_address(address: &mut Spanned<i128>, address_kind: &mut AddressKind, property: &Property) {
+    match property.expression.value {
+        Expression::Auto => {
+            *address_kind = AddressKind::Auto;
+            *address = 0.with_span(property.expression.span);
+        }
+        _ => {
+            *address = property
+                .expression
+                .as_number()
+                .unwrap()
+                .with_span(property.expression.span);
+        }
+    }
+}
+
+/// Add the chip variant named by the property to the list.
+/// Returns true if the name can't be used as a variant.
+fn push_chip_variant(
+    chip_variants: &mut Vec<Spanned<String>>,
+    property: &Property,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let Expression::TypeReference(name) = property.expression.value else {
+        unreachable!("Only type references are allowed for chip variants")
+    };
+
+    if !name
+        .val
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        diagnostics.add(InvalidChipVariantName { name: name.span });
+        return true;
+    }
+
+    chip_variants.push(name.val.to_string().with_span(name.span));
+    false
+}
+
 impl Shape for Manifest {
     const NODE_TYPE: NodeType = NodeType::Manifest;
     type NameIdentifierType = All;
@@ -131,6 +202,28 @@ impl Shape for Manifest {
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("memory-address-type"),
+                description: "Sets the global type used to address the memories for all devices. This can be overridden per device.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Integer(Integer::U32)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs {
+                             target_object: manifest,
+                             property,
+                             ..
+                         }| {
+                    manifest.config.memory_address_type = Some(
+                        property
+                            .expression
+                            .as_integer()
+                            .unwrap()
+                            .with_span(property.expression.span),
+                    );
+                    false
+                },
+            },
             PropertyInfo {
                 name: PropertyName::Exact("word-boundaries"),
                 description: "\
@@ -191,6 +284,30 @@ If this value is specified, then it permits bulk register reads and writes.",
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("variant"),
+                description: "\
+Declares a chip variant for all devices. Specify it multiple times to declare multiple variants. This can be overridden per device.
+
+Objects can be made conditional on a variant with the `when` property.
+The variant is selected with the cargo feature of the same name. Only one variant can be selected at a time.",
+                allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs {
+                             target_object: manifest,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    push_chip_variant(
+                        manifest.config.chip_variants.get_or_insert_default(),
+                        property,
+                        diagnostics,
+                    )
+                },
+            },
             PropertyInfo {
                 name: PropertyName::Exact("default-access"),
                 description: "When set, all subobjects use this value as their access value (unless overridden) and don't require an access specifier anymore",
@@ -217,6 +334,8 @@ If this value is specified, then it permits bulk register reads and writes.",
             NodeType::FieldSet,
             NodeType::Enum,
             NodeType::Extern,
+            NodeType::Template,
+            NodeType::Const,
         ])
     }
 
@@ -334,6 +453,28 @@ impl Shape for Device {
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("memory-address-type"),
+                description: "Sets the type used to address the memories in this device.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Integer(Integer::U32)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs {
+                             target_object: dev,
+                             property,
+                             ..
+                         }| {
+                    dev.device_config.memory_address_type = Some(
+                        property
+                            .expression
+                            .as_integer()
+                            .unwrap()
+                            .with_span(property.expression.span),
+                    );
+                    false
+                },
+            },
             PropertyInfo {
                 name: PropertyName::Exact("word-boundaries"),
                 description: "\
@@ -394,6 +535,30 @@ If this value is specified, then it permits bulk register reads and writes.",
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("variant"),
+                description: "\
+Declares a chip variant of this device. Specify it multiple times to declare multiple variants.
+
+Objects can be made conditional on a variant with the `when` property.
+The variant is selected with the cargo feature of the same name. Only one variant can be selected at a time.",
+                allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs {
+                             target_object: device,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    push_chip_variant(
+                        device.device_config.chip_variants.get_or_insert_default(),
+                        property,
+                        diagnostics,
+                    )
+                },
+            },
             PropertyInfo {
                 name: PropertyName::Exact("default-access"),
                 description: "When set, all subobjects use this value as their access value (unless overridden) and don't require an access specifier anymore",
@@ -410,6 +575,18 @@ If this value is specified, then it permits bulk register reads and writes.",
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("default-address"),
+                description: "When set to `auto`, the registers, commands, buffers, memories and FIFOs in the device (and its blocks) may leave out their address. They're then placed like with `address: auto`.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Auto]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs { target_object, .. }| {
+                    target_object.default_auto_address = true;
+                    false
+                },
+            },
         ];
         MAP
     }
@@ -420,9 +597,17 @@ If this value is specified, then it permits bulk register reads and writes.",
             NodeType::Register,
             NodeType::Command,
             NodeType::Buffer,
+            NodeType::Memory,
+            NodeType::Fifo,
             NodeType::FieldSet,
             NodeType::Enum,
             NodeType::Extern,
+            NodeType::Interface,
+            NodeType::Routine,
+            NodeType::Composite,
+            NodeType::States,
+            NodeType::Template,
+            NodeType::Const,
         ])
     }
 
@@ -480,6 +665,30 @@ If this is not desired, then keep the address offset at 0.",
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("block"),
+                description: "\
+Makes this block an instance of another block in the same device. The instance has all objects of the referenced block, relative to its own address offset.
+The referenced block is generated once and shared between all its instances. An instance can't define objects of its own.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                    device_driver_parser::Ident::new_no_span("MyBlock"),
+                )]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Block> {
+                             target_object: block,
+                             property,
+                             ..
+                         }| {
+                    let Expression::TypeReference(name) = property.expression.value else {
+                        unreachable!("Only type references are allowed for blocks")
+                    };
+                    block.block_ref =
+                        Some(IdentifierRef::new(name.val.into()).with_span(name.span));
+                    false
+                },
+            },
             PropertyInfo {
                 name: PropertyName::Exact("default-access"),
                 description: "When set, all subobjects use this value as their access value (unless overridden) and don't require an access specifier anymore",
@@ -496,6 +705,36 @@ If this is not desired, then keep the address offset at 0.",
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("default-address"),
+                description: "When set to `auto`, the registers, commands, buffers, memories and FIFOs in the block (and its blocks) may leave out their address. They're then placed like with `address: auto`.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Auto]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs { target_object, .. }| {
+                    target_object.default_auto_address = true;
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("when"),
+                description: "\
+Makes the block only exist for the given chip variant. Specify it multiple times to make the block exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Block> {
+                             target_object: block,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    push_chip_variant(&mut block.chip_variants, property, diagnostics)
+                },
+            },
         ];
         MAP
     }
@@ -506,9 +745,15 @@ If this is not desired, then keep the address offset at 0.",
             NodeType::Register,
             NodeType::Command,
             NodeType::Buffer,
+            NodeType::Memory,
+            NodeType::Fifo,
             NodeType::FieldSet,
             NodeType::Enum,
             NodeType::Extern,
+            NodeType::Routine,
+            NodeType::Composite,
+            NodeType::Template,
+            NodeType::Const,
         ])
     }
 
@@ -550,21 +795,17 @@ impl Shape for Register {
             [
                 PropertyInfo {
                     name: PropertyName::Exact("address"),
-                    description: "The address of the register.",
-                    allowed_expression_types: Cow::Borrowed(&[Expression::Number(0)]),
+                    description: "The address of the register. Use `auto` (or `_`) to place the register right after the previous register or FIFO in the same block, using the size the `register-address-mode` gives it. It may be left out in a block with `default-address: auto`.",
+                    allowed_expression_types: Cow::Borrowed(&[Expression::Number(0), Expression::Auto]),
                     multiple_allowed: false,
-                    required: true,
+                    required: false,
                     supports_doc_comments: false,
                     setter: |SetterArgs::<Register> {
                                  target_object: r,
                                  property,
                                  ..
                              }| {
-                        r.address = property
-                            .expression
-                            .as_number()
-                            .unwrap()
-                            .with_span(property.expression.span);
+                        set_address(&mut r.address, &mut r.address_kind, property);
                         false
                     },
                 },
@@ -618,29 +859,9 @@ The value can be expressed in two ways:
                                  property,
                                  diagnostics,
                                  ..
-                             }| match &property.expression.value {
-                        Expression::Number(num) => match u128::try_from(*num) {
-                            Ok(num) => {
-                                r.reset_value = Some(
-                                    ResetValue::Integer(num).with_span(property.expression.span),
-                                );
-                                false
-                            }
-                            Err(_) => {
-                                diagnostics.add(ResetValueNegative {
-                                    reset_value: property.expression.span,
-                                });
-                                true
-                            }
-                        },
-                        Expression::ByteArray(bytes) => {
-                            r.reset_value = Some(
-                                ResetValue::Array(bytes.to_vec())
-                                    .with_span(property.expression.span),
-                            );
-                            false
-                        }
-                        _ => unreachable!(),
+                             }| {
+                        r.reset_value = reset_value(property, diagnostics);
+                        r.reset_value.is_none()
                     },
                 },
                 PropertyInfo {
@@ -659,6 +880,7 @@ The value can be expressed in two ways:
                                  target_object: r,
                                  property,
                                  node,
+                                 definitions,
                                  diagnostics,
                                  sibling_objects,
                              }| {
@@ -674,6 +896,7 @@ The value can be expressed in two ways:
                                     Some(NodeType::Register.with_span(node.node_type.span)),
                                     Some(Ident::new(r.name.original(), r.name.span)),
                                     &[NodeType::FieldSet],
+                                    definitions,
                                     diagnostics,
                                 );
 
@@ -694,13 +917,98 @@ The value can be expressed in two ways:
                                         sibling_objects.extend(fs_siblings);
                                         true
                                     }
-                                    LowerResult::Manifest(_) => unreachable!(),
+                                    LowerResult::Manifest(_) | LowerResult::Definition => unreachable!(),
+                                }
+                            }
+                            _ => unreachable!(),
+                        }
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("view"),
+                    description: "\
+Another fieldset the data of the register can be read and written as. This can be a reference to an existing fieldset or a completely new inline fieldset, which must be named.
+The fieldset must have the same size as the fieldset of the register. Specify it multiple times to add multiple views.
+
+For every view an extra operation named `<register>_as_<view>` is generated that shares the address, repeat and reset value with the register.",
+                    allowed_expression_types: Cow::Owned(vec![
+                        Expression::TypeReference(device_driver_parser::Ident::new_no_span(
+                            "MyFieldset",
+                        )),
+                        Expression::SubNode(Box::new(FIELD_SET_EXAMPLE)),
+                    ]),
+                    multiple_allowed: true,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Register> {
+                                 target_object: r,
+                                 property,
+                                 node,
+                                 definitions,
+                                 diagnostics,
+                                 sibling_objects,
+                             }| {
+                        match &property.expression.value {
+                            Expression::TypeReference(ident) => {
+                                r.views.push(
+                                    IdentifierRef::new(ident.val.into()).with_span(ident.span),
+                                );
+                                false
+                            }
+                            Expression::SubNode(sub_node) => {
+                                let result = lower_node(
+                                    sub_node,
+                                    Some(NodeType::Register.with_span(node.node_type.span)),
+                                    // The name of the register is already used by the fields of the register
+                                    None,
+                                    &[NodeType::FieldSet],
+                                    definitions,
+                                    diagnostics,
+                                );
+
+                                match result {
+                                    LowerResult::Objects(fs, fs_siblings) => {
+                                        r.views.push(
+                                            fs.name()
+                                                .clone()
+                                                // This should always be a fieldset is a Type identifier
+                                                .cast_assert()
+                                                .take_ref()
+                                                .with_span(fs.name_span()),
+                                        );
+                                        sibling_objects.push(fs);
+                                        sibling_objects.extend(fs_siblings);
+                                        false
+                                    }
+                                    LowerResult::Error(fs_siblings) => {
+                                        sibling_objects.extend(fs_siblings);
+                                        true
+                                    }
+                                    LowerResult::Manifest(_) | LowerResult::Definition => unreachable!(),
                                 }
                             }
                             _ => unreachable!(),
                         }
                     },
                 },
+                PropertyInfo {
+                    name: PropertyName::Exact("when"),
+                    description: "\
+Makes the register only exist for the given chip variant. Specify it multiple times to make the register exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                    allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                    multiple_allowed: true,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Register> {
+                                 target_object: r,
+                                 property,
+                                 diagnostics,
+                                 ..
+                             }| {
+                        push_chip_variant(&mut r.chip_variants, property, diagnostics)
+                    },
+                },
             ]
             .into()
         });
@@ -715,6 +1023,80 @@ The value can be expressed in two ways:
         &mut self.span
     }
 
+    fn address_kind(&mut self) -> Option<&mut AddressKind> {
+        Some(&mut self.address_kind)
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for IndexOverride {
+    const NODE_TYPE: NodeType = NodeType::Index;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: LazyLock<Vec<PropertyInfo<IndexOverride>>> = LazyLock::new(|| {
+            [
+                PropertyInfo {
+                    name: PropertyName::Exact("access"),
+                    description: "Limits how the register can be accessed at this index. It can only be more limited than the access of the register.",
+                    allowed_expression_types: Cow::Borrowed(&[Expression::Access(Access::RO)]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<IndexOverride> {
+                                 target_object: index_override,
+                                 property,
+                                 ..
+                             }| {
+                        index_override.access = Some(property.expression.as_access().unwrap());
+                        false
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("reset"),
+                    description: "The reset value of the register at this index. It's expressed the same way as the reset value of the register.",
+                    allowed_expression_types: Cow::Owned(vec![
+                        Expression::ByteArray(vec![12, 34]),
+                        Expression::Number(1234),
+                    ]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<IndexOverride> {
+                                 target_object: index_override,
+                                 property,
+                                 diagnostics,
+                                 ..
+                             }| {
+                        index_override.reset_value = reset_value(property, diagnostics);
+                        index_override.reset_value.is_none()
+                    },
+                },
+            ]
+            .into()
+        });
+        &MAP
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
     fn properties_span(&mut self) -> &mut Option<Span> {
         &mut self.properties_span
     }
@@ -784,19 +1166,72 @@ impl Shape for FieldSet {
                 },
             },
             PropertyInfo {
-                name: PropertyName::Exact("bit-overlap"),
-                description: "Allows fields to overlap. This is not allowed by default to prevent copy-paste mistakes.",
-                allowed_expression_types: Cow::Borrowed(&[Expression::Allow]),
+                name: PropertyName::Exact(field_sets::EXTENDS),
+                description: "\
+Copies the fields and properties of another fieldset. Properties and fields with the same name as in the extended fieldset override the copied ones.
+Fields that aren't wanted can be removed with the `remove` property.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                    device_driver_parser::Ident::new_no_span("MyFieldSet"),
+                )]),
                 multiple_allowed: false,
                 required: false,
                 supports_doc_comments: false,
-                setter: |SetterArgs::<FieldSet> {
-                             target_object: fs, ..
+                // Resolved before the fieldset is lowered
+                setter: |_| false,
+            },
+            PropertyInfo {
+                name: PropertyName::Exact(field_sets::REMOVE),
+                description: "Removes a field that was copied from the extended fieldset. Can be specified multiple times.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                    device_driver_parser::Ident::new_no_span("my-field"),
+                )]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                // Resolved before the fieldset is lowered, so this is only called when the fieldset doesn't extend anything
+                setter: |SetterArgs::<FieldSet> {
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    diagnostics.add(RemoveWithoutExtends {
+                        property: property.span,
+                    });
+                    true
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("bit-overlap"),
+                description: "Allows fields to overlap. This is not allowed by default to prevent copy-paste mistakes.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Allow]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<FieldSet> {
+                             target_object: fs, ..
                          }| {
                     fs.allow_bit_overlap = true;
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("flags"),
+                description: "\
+Generates the fieldset as a set of flags with a constant for every field and set operations like `contains`.
+All fields must be one bit wide.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Allow]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<FieldSet> {
+                             target_object: fs,
+                             property,
+                             ..
+                         }| {
+                    fs.flags = Some(property.span);
+                    false
+                },
+            },
             PropertyInfo {
                 name: PropertyName::Exact("default-access"),
                 description: "When set, all subobjects use this value as their access value (unless overridden) and don't require an access specifier anymore",
@@ -952,24 +1387,38 @@ impl Shape for Buffer {
             },
             PropertyInfo {
                 name: PropertyName::Exact("address"),
-                description: "The address of the buffer",
-                allowed_expression_types: Cow::Borrowed(&[Expression::Number(0)]),
+                description: "The address of the buffer. Use `auto` (or `_`) to place the buffer right after the previous buffer in the same block. It may be left out in a block with `default-address: auto`.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(0), Expression::Auto]),
                 multiple_allowed: false,
-                required: true,
+                required: false,
                 supports_doc_comments: false,
                 setter: |SetterArgs::<Buffer> {
                              target_object: buf,
                              property,
                              ..
                          }| {
-                    buf.address = property
-                        .expression
-                        .as_number()
-                        .unwrap()
-                        .with_span(property.expression.span);
+                    set_address(&mut buf.address, &mut buf.address_kind, property);
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("when"),
+                description: "\
+Makes the buffer only exist for the given chip variant. Specify it multiple times to make the buffer exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Buffer> {
+                             target_object: buf,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    push_chip_variant(&mut buf.chip_variants, property, diagnostics)
+                },
+            },
         ];
         MAP
     }
@@ -978,6 +1427,10 @@ impl Shape for Buffer {
         &mut self.span
     }
 
+    fn address_kind(&mut self) -> Option<&mut AddressKind> {
+        Some(&mut self.address_kind)
+    }
+
     fn properties_span(&mut self) -> &mut Option<Span> {
         &mut self.properties_span
     }
@@ -987,9 +1440,9 @@ impl Shape for Buffer {
     }
 }
 
-impl Shape for Enum {
-    const NODE_TYPE: NodeType = NodeType::Enum;
-    type NameIdentifierType = Type;
+impl Shape for Memory {
+    const NODE_TYPE: NodeType = NodeType::Memory;
+    type NameIdentifierType = Operation;
 
     fn doc_comments(&mut self) -> &mut String {
         &mut self.description
@@ -1000,65 +1453,187 @@ impl Shape for Enum {
     }
 
     fn supported_properties() -> &'static [PropertyInfo<Self>] {
-        static MAP: &[PropertyInfo<Enum>] = &[PropertyInfo {
-            name: PropertyName::Any,
-            description: "Defines a variant for the enum. The name of the property becomes the variant name.",
-            allowed_expression_types: Cow::Borrowed(&[
-                Expression::Auto,
-                Expression::Number(0),
-                Expression::DefaultNumber(Some(0)),
-                Expression::DefaultNumber(None),
-                Expression::CatchAllNumber(Some(0)),
-                Expression::CatchAllNumber(None),
-            ]),
-            multiple_allowed: true,
-            required: false,
-            supports_doc_comments: true,
-            setter: |SetterArgs::<Enum> {
-                         target_object: enum_value,
-                         property,
-                         diagnostics,
-                         ..
-                     }| {
-                let identifier = match Identifier::try_parse(property.name.val) {
-                    Ok(identifier) => identifier,
-                    Err(e) => {
-                        diagnostics.add(InvalidIdentifier {
-                            error: e,
-                            identifier: property.name.span,
-                        });
-                        return true;
+        static MAP: &[PropertyInfo<Memory>] = &[
+            PropertyInfo {
+                name: PropertyName::Exact("access"),
+                description: "Limits how the memory can be accessed. Must be specified unless a `default-access` is set by a parent object.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Access(Access::RW)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             ..
+                         }| {
+                    memory.access = Some(property.expression.as_access().unwrap());
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("address"),
+                description: "The address of the first word of the memory. Every word of the memory has its own address. Use `auto` (or `_`) to place the memory right after the previous memory in the same block. It may be left out in a block with `default-address: auto`.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(0), Expression::Auto]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             ..
+                         }| {
+                    set_address(&mut memory.address, &mut memory.address_kind, property);
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("size-bytes"),
+                description: "The size of the memory in number of bytes. Must be a multiple of the word size.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(4096)]),
+                multiple_allowed: false,
+                required: true,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    let mut size = None;
+                    let error = set_memory_size(&mut size, "size-bytes", property, node, diagnostics);
+                    if let Some(size) = size {
+                        memory.size_bytes = size;
                     }
-                };
-
-                enum_value.variants.push(EnumVariant {
-                    description: property.doc_comments.iter().map(|c| c.value).join("\n"),
-                    name: identifier.with_span(property.name.span),
-                    value: match &property.expression.value {
-                        Expression::Number(num) => EnumValue::Specified(*num),
-                        Expression::DefaultNumber(Some(num)) => EnumValue::Default(*num),
-                        Expression::DefaultNumber(None) => EnumValue::UnspecifiedDefault,
-                        Expression::CatchAllNumber(Some(num)) => EnumValue::CatchAll(*num),
-                        Expression::CatchAllNumber(None) => EnumValue::UnspecifiedCatchAll,
-                        Expression::Auto => EnumValue::Unspecified,
-                        _ => unreachable!(),
-                    },
-                    span: property.span,
-                });
-                false
+                    error
+                },
             },
-        }];
+            PropertyInfo {
+                name: PropertyName::Exact("word-size-bytes"),
+                description: "The amount of bytes of one address. Reads must be aligned to the word size. Defaults to 1.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(1)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_memory_size(
+                        &mut memory.word_size_bytes,
+                        "word-size-bytes",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("page-size-bytes"),
+                description: "\
+The size of the pages of the memory in bytes. Writes are split at the page boundaries, so a write never wraps around in a page.
+Without a page size the writes are not split.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(32)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_memory_size(
+                        &mut memory.page_size_bytes,
+                        "page-size-bytes",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("write-granularity-bytes"),
+                description: "The amount of bytes that are written at once. Writes must be aligned to it. Defaults to the word size.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(4)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_memory_size(
+                        &mut memory.write_granularity_bytes,
+                        "write-granularity-bytes",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("erase-size-bytes"),
+                description: "\
+The size of the blocks of the memory that are erased at once, like the sectors of a flash.
+When specified, erase operations are generated that call the erase function of the interface for every block.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(4096)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_memory_size(
+                        &mut memory.erase_size_bytes,
+                        "erase-size-bytes",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("when"),
+                description: "\
+Makes the memory only exist for the given chip variant. Specify it multiple times to make the memory exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Memory> {
+                             target_object: memory,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    push_chip_variant(&mut memory.chip_variants, property, diagnostics)
+                },
+            },
+        ];
         MAP
     }
 
-    fn base_type(&mut self) -> Option<&mut Spanned<BaseType>> {
-        Some(&mut self.base_type)
-    }
-
     fn span(&mut self) -> &mut Span {
         &mut self.span
     }
 
+    fn address_kind(&mut self) -> Option<&mut AddressKind> {
+        Some(&mut self.address_kind)
+    }
+
     fn properties_span(&mut self) -> &mut Option<Span> {
         &mut self.properties_span
     }
@@ -1068,8 +1643,34 @@ impl Shape for Enum {
     }
 }
 
-impl Shape for Command {
-    const NODE_TYPE: NodeType = NodeType::Command;
+/// Set one of the sizes of a memory. How the sizes relate to each other is checked in a pass.
+fn set_memory_size(
+    size: &mut Option<Spanned<u32>>,
+    property_name: &'static str,
+    property: &Spanned<Property>,
+    node: &Node,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    // Memories are limited to 2 GiB, so every word index fits in an i32
+    match u32::try_from(property.expression.as_number().unwrap()) {
+        Ok(value @ 1..=0x8000_0000) => {
+            *size = Some(value.with_span(property.expression.span));
+            false
+        }
+        _ => {
+            diagnostics.add(InvalidMemoryProperty {
+                memory_name: node.name.span,
+                property: property_name,
+                value: property.expression.span,
+                reason: "value must be in the range 1..=0x8000_0000".into(),
+            });
+            true
+        }
+    }
+}
+
+impl Shape for Fifo {
+    const NODE_TYPE: NodeType = NodeType::Fifo;
     type NameIdentifierType = Operation;
 
     fn doc_comments(&mut self) -> &mut String {
@@ -1081,46 +1682,27 @@ impl Shape for Command {
     }
 
     fn supported_properties() -> &'static [PropertyInfo<Self>] {
-        static MAP: LazyLock<Vec<PropertyInfo<Command>>> = LazyLock::new(|| {
+        static MAP: LazyLock<Vec<PropertyInfo<Fifo>>> = LazyLock::new(|| {
             [
                 PropertyInfo {
                     name: PropertyName::Exact("address"),
-                    description: "The address of the command",
-                    allowed_expression_types: Cow::Borrowed(&[Expression::Number(0)]),
-                    multiple_allowed: false,
-                    required: true,
-                    supports_doc_comments: false,
-                    setter: |SetterArgs::<Command> {
-                                 target_object: command,
-                                 property,
-                                 ..
-                             }| {
-                        command.address = property
-                            .expression
-                            .as_number()
-                            .unwrap()
-                            .with_span(property.expression.span);
-                        false
-                    },
-                },
-                PropertyInfo {
-                    name: PropertyName::Exact("address-overlap"),
-                    description: "Allows addresses to overlap with other commands. This is not allowed by default to prevent copy-paste mistakes.",
-                    allowed_expression_types: Cow::Borrowed(&[Expression::Allow]),
+                    description: "The address of the data register the records are read from. It's in the same address space as the registers. Use `auto` (or `_`) to place the FIFO right after the previous register or FIFO in the same block. It may be left out in a block with `default-address: auto`.",
+                    allowed_expression_types: Cow::Borrowed(&[Expression::Number(0), Expression::Auto]),
                     multiple_allowed: false,
                     required: false,
                     supports_doc_comments: false,
-                    setter: |SetterArgs::<Command> {
-                                 target_object: command,
+                    setter: |SetterArgs::<Fifo> {
+                                 target_object: fifo,
+                                 property,
                                  ..
                              }| {
-                        command.allow_address_overlap = true;
+                        set_address(&mut fifo.address, &mut fifo.address_kind, property);
                         false
                     },
                 },
                 PropertyInfo {
-                    name: PropertyName::Exact("fields-in"),
-                    description: "The fieldset that represents the input data of the command. This can be a reference to an existing fieldset or a completely new inline fieldset.",
+                    name: PropertyName::Exact("record"),
+                    description: "The fieldset of one record of the FIFO. This can be a reference to an existing fieldset or a completely new inline fieldset.",
                     allowed_expression_types: Cow::Owned(vec![
                         Expression::TypeReference(device_driver_parser::Ident::new_no_span(
                             "MyFieldset",
@@ -1128,41 +1710,40 @@ impl Shape for Command {
                         Expression::SubNode(Box::new(FIELD_SET_EXAMPLE)),
                     ]),
                     multiple_allowed: false,
-                    required: false,
+                    required: true,
                     supports_doc_comments: false,
-                    setter: |SetterArgs::<Command> {
-                                 target_object: command,
+                    setter: |SetterArgs::<Fifo> {
+                                 target_object: fifo,
                                  property,
                                  node,
+                                 definitions,
                                  diagnostics,
                                  sibling_objects,
                              }| {
                         match &property.expression.value {
                             Expression::TypeReference(ident) => {
-                                command.field_set_ref_in = Some(
-                                    IdentifierRef::new(ident.val.into()).with_span(ident.span),
-                                );
+                                fifo.record_ref =
+                                    IdentifierRef::new(ident.val.into()).with_span(ident.span);
                                 false
                             }
                             Expression::SubNode(sub_node) => {
                                 let result = lower_node(
                                     sub_node,
-                                    Some(NodeType::Register.with_span(node.node_type.span)),
-                                    Some(Ident::new(command.name.original(), command.name.span)),
+                                    Some(NodeType::Fifo.with_span(node.node_type.span)),
+                                    Some(Ident::new(fifo.name.original(), fifo.name.span)),
                                     &[NodeType::FieldSet],
+                                    definitions,
                                     diagnostics,
                                 );
 
                                 match result {
                                     LowerResult::Objects(fs, fs_siblings) => {
-                                        command.field_set_ref_in = Some(
-                                            fs.name()
-                                                .clone()
-                                                // Always a fieldset, so should be fine
-                                                .cast_assert()
-                                                .take_ref()
-                                                .with_span(fs.name_span()),
-                                        );
+                                        fifo.record_ref = fs
+                                            .name()
+                                            .clone()
+                                            .cast_assert()
+                                            .take_ref()
+                                            .with_span(fs.name_span());
                                         sibling_objects.push(fs);
                                         sibling_objects.extend(fs_siblings);
                                         false
@@ -1171,7 +1752,7 @@ impl Shape for Command {
                                         sibling_objects.extend(fs_siblings);
                                         true
                                     }
-                                    LowerResult::Manifest(_) => unreachable!(),
+                                    LowerResult::Manifest(_) | LowerResult::Definition => unreachable!(),
                                 }
                             }
                             _ => unreachable!(),
@@ -1179,8 +1760,313 @@ impl Shape for Command {
                     },
                 },
                 PropertyInfo {
-                    name: PropertyName::Exact("fields-out"),
-                    description: "The fieldset that represents the output data of the command. This can be a reference to an existing fieldset or a completely new inline fieldset.",
+                    name: PropertyName::Exact("level-register"),
+                    description: "\
+The register that holds the fill level of the FIFO. It must be in the same block as the FIFO.
+When set, the fill level is read before the records so only the available records are read.",
+                    allowed_expression_types: Cow::Owned(vec![Expression::TypeReference(
+                        device_driver_parser::Ident::new_no_span("FifoStatus"),
+                    )]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Fifo> {
+                                 target_object: fifo,
+                                 property,
+                                 ..
+                             }| {
+                        let Expression::TypeReference(name) = property.expression.value else {
+                            unreachable!("Only type references are allowed for registers")
+                        };
+                        fifo.level_register =
+                            Some(IdentifierRef::new(name.val.into()).with_span(name.span));
+                        false
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("level-field"),
+                    description: "The field of the level register that holds the fill level. It must be an unsigned integer field. Required when `level-register` is set.",
+                    allowed_expression_types: Cow::Owned(vec![Expression::TypeReference(
+                        device_driver_parser::Ident::new_no_span("count"),
+                    )]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Fifo> {
+                                 target_object: fifo,
+                                 property,
+                                 ..
+                             }| {
+                        let Expression::TypeReference(name) = property.expression.value else {
+                            unreachable!("Only type references are allowed for fields")
+                        };
+                        fifo.level_field =
+                            Some(IdentifierRef::new(name.val.into()).with_span(name.span));
+                        false
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("level-unit"),
+                    description: "The unit the fill level counts in. This can be `record` or `byte`. Defaults to `record`.",
+                    allowed_expression_types: Cow::Owned(vec![Expression::TypeReference(
+                        device_driver_parser::Ident::new_no_span("byte"),
+                    )]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Fifo> {
+                                 target_object: fifo,
+                                 property,
+                                 node,
+                                 diagnostics,
+                                 ..
+                             }| {
+                        let Expression::TypeReference(ident) = &property.expression.value else {
+                            unreachable!()
+                        };
+
+                        match ident.val.parse::<LevelUnit>() {
+                            Ok(unit) => {
+                                fifo.level_unit = Some(unit.with_span(ident.span));
+                                false
+                            }
+                            Err(()) => {
+                                diagnostics.add(InvalidFifo {
+                                    fifo: node.name.span,
+                                    problem: ident.span,
+                                    pointee: None,
+                                    reason: format!(
+                                        "unknown level unit, expected one of: {}",
+                                        LevelUnit::VARIANTS
+                                            .iter()
+                                            .map(|unit| format!("`{unit}`"))
+                                            .join(", ")
+                                    )
+                                    .into(),
+                                });
+                                true
+                            }
+                        }
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("when"),
+                    description: "\
+Makes the FIFO only exist for the given chip variant. Specify it multiple times to make the FIFO exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                    allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                    multiple_allowed: true,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Fifo> {
+                                 target_object: fifo,
+                                 property,
+                                 diagnostics,
+                                 ..
+                             }| {
+                        push_chip_variant(&mut fifo.chip_variants, property, diagnostics)
+                    },
+                },
+            ]
+            .into()
+        });
+        &MAP
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn address_kind(&mut self) -> Option<&mut AddressKind> {
+        Some(&mut self.address_kind)
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Enum {
+    const NODE_TYPE: NodeType = NodeType::Enum;
+    type NameIdentifierType = Type;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<Enum>] = &[PropertyInfo {
+            name: PropertyName::Any,
+            description: "Defines a variant for the enum. The name of the property becomes the variant name. A variant with a range or a list of values covers all of them and carries the raw value.",
+            allowed_expression_types: Cow::Borrowed(&[
+                Expression::Auto,
+                Expression::Number(0),
+                Expression::DefaultNumber(Some(0)),
+                Expression::DefaultNumber(None),
+                Expression::CatchAllNumber(Some(0)),
+                Expression::CatchAllNumber(None),
+                Expression::ValueRange { start: 0, end: 0 },
+                Expression::ValueSet(Vec::new()),
+                Expression::ByteArray(Vec::new()),
+            ]),
+            multiple_allowed: true,
+            required: false,
+            supports_doc_comments: true,
+            setter: |SetterArgs::<Enum> {
+                         target_object: enum_value,
+                         property,
+                         diagnostics,
+                         ..
+                     }| {
+                let identifier = match Identifier::try_parse(property.name.val) {
+                    Ok(identifier) => identifier,
+                    Err(e) => {
+                        diagnostics.add(InvalidIdentifier {
+                            error: e,
+                            identifier: property.name.span,
+                        });
+                        return true;
+                    }
+                };
+
+                let value_ranges = match &property.expression.value {
+                    Expression::ValueRange { start, end } => {
+                        vec![
+                            ValueRange {
+                                start: *start,
+                                end: *end,
+                            }
+                            .with_span(property.expression.span),
+                        ]
+                    }
+                    Expression::ValueSet(ranges) => ranges.clone(),
+                    Expression::ByteArray(bytes) => bytes
+                        .iter()
+                        .map(|byte| {
+                            ValueRange {
+                                start: i128::from(*byte),
+                                end: i128::from(*byte),
+                            }
+                            .with_span(property.expression.span)
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+
+                let mut wrong_order = false;
+                for range in &value_ranges {
+                    if range.end < range.start {
+                        diagnostics.add(ValueRangeWrongOrder {
+                            range: range.span,
+                            start: range.start,
+                            end: range.end,
+                        });
+                        wrong_order = true;
+                    }
+                }
+                if wrong_order {
+                    return true;
+                }
+
+                enum_value.variants.push(EnumVariant {
+                    description: property.doc_comments.iter().map(|c| c.value).join("\n"),
+                    name: identifier.with_span(property.name.span),
+                    value: match &property.expression.value {
+                        Expression::Number(num) => EnumValue::Specified(*num),
+                        Expression::DefaultNumber(Some(num)) => EnumValue::Default(*num),
+                        Expression::DefaultNumber(None) => EnumValue::UnspecifiedDefault,
+                        Expression::CatchAllNumber(Some(num)) => EnumValue::CatchAll(*num),
+                        Expression::CatchAllNumber(None) => EnumValue::UnspecifiedCatchAll,
+                        Expression::Auto => EnumValue::Unspecified,
+                        Expression::ValueRange { .. }
+                        | Expression::ValueSet(_)
+                        | Expression::ByteArray(_) => EnumValue::Values(merge_value_ranges(
+                            value_ranges.into_iter().map(|range| range.value),
+                        )),
+                        _ => unreachable!(),
+                    },
+                    span: property.span,
+                });
+                false
+            },
+        }];
+        MAP
+    }
+
+    fn base_type(&mut self) -> Option<&mut Spanned<BaseType>> {
+        Some(&mut self.base_type)
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Command {
+    const NODE_TYPE: NodeType = NodeType::Command;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: LazyLock<Vec<PropertyInfo<Command>>> = LazyLock::new(|| {
+            [
+                PropertyInfo {
+                    name: PropertyName::Exact("address"),
+                    description: "The address of the command. Use `auto` (or `_`) to place the command right after the previous command in the same block. It may be left out in a block with `default-address: auto`.",
+                    allowed_expression_types: Cow::Borrowed(&[Expression::Number(0), Expression::Auto]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Command> {
+                                 target_object: command,
+                                 property,
+                                 ..
+                             }| {
+                        set_address(&mut command.address, &mut command.address_kind, property);
+                        false
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("address-overlap"),
+                    description: "Allows addresses to overlap with other commands. This is not allowed by default to prevent copy-paste mistakes.",
+                    allowed_expression_types: Cow::Borrowed(&[Expression::Allow]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Command> {
+                                 target_object: command,
+                                 ..
+                             }| {
+                        command.allow_address_overlap = true;
+                        false
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("fields-in"),
+                    description: "The fieldset that represents the input data of the command. This can be a reference to an existing fieldset or a completely new inline fieldset.",
                     allowed_expression_types: Cow::Owned(vec![
                         Expression::TypeReference(device_driver_parser::Ident::new_no_span(
                             "MyFieldset",
@@ -1194,12 +2080,13 @@ impl Shape for Command {
                                  target_object: command,
                                  property,
                                  node,
+                                 definitions,
                                  diagnostics,
                                  sibling_objects,
                              }| {
                         match &property.expression.value {
                             Expression::TypeReference(ident) => {
-                                command.field_set_ref_out = Some(
+                                command.field_set_ref_in = Some(
                                     IdentifierRef::new(ident.val.into()).with_span(ident.span),
                                 );
                                 false
@@ -1210,42 +2097,745 @@ impl Shape for Command {
                                     Some(NodeType::Register.with_span(node.node_type.span)),
                                     Some(Ident::new(command.name.original(), command.name.span)),
                                     &[NodeType::FieldSet],
+                                    definitions,
                                     diagnostics,
                                 );
 
-                                match result {
-                                    LowerResult::Objects(fs, fs_siblings) => {
-                                        command.field_set_ref_out = Some(
-                                            fs.name()
-                                                .clone()
-                                                // Always a fieldset, so should be fine
-                                                .cast_assert()
-                                                .take_ref()
-                                                .with_span(fs.name_span()),
-                                        );
-                                        sibling_objects.push(fs);
-                                        sibling_objects.extend(fs_siblings);
-                                        false
-                                    }
-                                    LowerResult::Error(fs_siblings) => {
-                                        sibling_objects.extend(fs_siblings);
-                                        true
-                                    }
-                                    LowerResult::Manifest(_) => unreachable!(),
-                                }
-                            }
-                            _ => unreachable!(),
-                        }
-                    },
+                                match result {
+                                    LowerResult::Objects(fs, fs_siblings) => {
+                                        command.field_set_ref_in = Some(
+                                            fs.name()
+                                                .clone()
+                                                // Always a fieldset, so should be fine
+                                                .cast_assert()
+                                                .take_ref()
+                                                .with_span(fs.name_span()),
+                                        );
+                                        sibling_objects.push(fs);
+                                        sibling_objects.extend(fs_siblings);
+                                        false
+                                    }
+                                    LowerResult::Error(fs_siblings) => {
+                                        sibling_objects.extend(fs_siblings);
+                                        true
+                                    }
+                                    LowerResult::Manifest(_) | LowerResult::Definition => unreachable!(),
+                                }
+                            }
+                            _ => unreachable!(),
+                        }
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("fields-out"),
+                    description: "The fieldset that represents the output data of the command. This can be a reference to an existing fieldset or a completely new inline fieldset.",
+                    allowed_expression_types: Cow::Owned(vec![
+                        Expression::TypeReference(device_driver_parser::Ident::new_no_span(
+                            "MyFieldset",
+                        )),
+                        Expression::SubNode(Box::new(FIELD_SET_EXAMPLE)),
+                    ]),
+                    multiple_allowed: false,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Command> {
+                                 target_object: command,
+                                 property,
+                                 node,
+                                 definitions,
+                                 diagnostics,
+                                 sibling_objects,
+                             }| {
+                        match &property.expression.value {
+                            Expression::TypeReference(ident) => {
+                                command.field_set_ref_out = Some(
+                                    IdentifierRef::new(ident.val.into()).with_span(ident.span),
+                                );
+                                false
+                            }
+                            Expression::SubNode(sub_node) => {
+                                let result = lower_node(
+                                    sub_node,
+                                    Some(NodeType::Register.with_span(node.node_type.span)),
+                                    Some(Ident::new(command.name.original(), command.name.span)),
+                                    &[NodeType::FieldSet],
+                                    definitions,
+                                    diagnostics,
+                                );
+
+                                match result {
+                                    LowerResult::Objects(fs, fs_siblings) => {
+                                        command.field_set_ref_out = Some(
+                                            fs.name()
+                                                .clone()
+                                                // Always a fieldset, so should be fine
+                                                .cast_assert()
+                                                .take_ref()
+                                                .with_span(fs.name_span()),
+                                        );
+                                        sibling_objects.push(fs);
+                                        sibling_objects.extend(fs_siblings);
+                                        false
+                                    }
+                                    LowerResult::Error(fs_siblings) => {
+                                        sibling_objects.extend(fs_siblings);
+                                        true
+                                    }
+                                    LowerResult::Manifest(_) | LowerResult::Definition => unreachable!(),
+                                }
+                            }
+                            _ => unreachable!(),
+                        }
+                    },
+                },
+                PropertyInfo {
+                    name: PropertyName::Exact("when"),
+                    description: "\
+Makes the command only exist for the given chip variant. Specify it multiple times to make the command exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                    allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                    multiple_allowed: true,
+                    required: false,
+                    supports_doc_comments: false,
+                    setter: |SetterArgs::<Command> {
+                                 target_object: command,
+                                 property,
+                                 diagnostics,
+                                 ..
+                             }| {
+                        push_chip_variant(&mut command.chip_variants, property, diagnostics)
+                    },
+                },
+            ]
+            .into()
+        });
+        &MAP
+    }
+
+    fn repeat(&mut self) -> Option<&mut Option<Repeat>> {
+        Some(&mut self.repeat)
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn address_kind(&mut self) -> Option<&mut AddressKind> {
+        Some(&mut self.address_kind)
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Interface {
+    const NODE_TYPE: NodeType = NodeType::Interface;
+    type NameIdentifierType = Type;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<Interface>] = &[
+            PropertyInfo {
+                name: PropertyName::Exact("bus"),
+                description: "The bus the device is connected to. This can be `spi` or `i2c`.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                    device_driver_parser::Ident::new_no_span("spi"),
+                )]),
+                multiple_allowed: false,
+                required: true,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    let Expression::TypeReference(ident) = &property.expression.value else {
+                        unreachable!()
+                    };
+
+                    match ident.val.parse::<Bus>() {
+                        Ok(bus) => {
+                            interface.bus = Some(bus.with_span(ident.span));
+                            false
+                        }
+                        Err(()) => {
+                            diagnostics.add(InvalidInterfaceProperty {
+                                interface_name: node.name.span,
+                                property: "bus",
+                                value: ident.span,
+                                reason: format!(
+                                    "unknown bus, expected one of: {}",
+                                    Bus::VARIANTS
+                                        .iter()
+                                        .map(|bus| format!("`{bus}`"))
+                                        .join(", ")
+                                )
+                                .into(),
+                            });
+                            true
+                        }
+                    }
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("address-size-bytes"),
+                description: "The amount of bytes the register address is sent with. Defaults to 1.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(1)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| match u32::try_from(
+                    property.expression.as_number().unwrap(),
+                ) {
+                    Ok(size_bytes @ 1..=8) => {
+                        interface.address_size_bytes =
+                            Some(size_bytes.with_span(property.expression.span));
+                        false
+                    }
+                    _ => {
+                        diagnostics.add(InvalidInterfaceProperty {
+                            interface_name: node.name.span,
+                            property: "address-size-bytes",
+                            value: property.expression.span,
+                            reason: "value must be in the range 1..=8".into(),
+                        });
+                        true
+                    }
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("address-byte-order"),
+                description: "The byte order the register address is sent in. Defaults to `BE`.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::ByteOrder(ByteOrder::BE)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             ..
+                         }| {
+                    interface.address_byte_order =
+                        Some(property.expression.as_byte_order().unwrap());
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("read-bit"),
+                description: "The bit of the sent address that is set when reading. Bit 0 is the lowest bit of the address.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(7)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_interface_bit(
+                        &mut interface.read_bit,
+                        "read-bit",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("write-bit"),
+                description: "The bit of the sent address that is set when writing. Bit 0 is the lowest bit of the address.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(7)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_interface_bit(
+                        &mut interface.write_bit,
+                        "write-bit",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("increment-bit"),
+                description: "The bit of the sent address that is set when more than one byte is read or written, so the device increments the address after every byte. Bit 0 is the lowest bit of the address.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(6)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| {
+                    set_interface_bit(
+                        &mut interface.increment_bit,
+                        "increment-bit",
+                        property,
+                        node,
+                        diagnostics,
+                    )
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("dummy-bytes"),
+                description: "The amount of bytes the device sends between the address and the data when reading. These bytes are discarded. Defaults to 0.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(1)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Interface> {
+                             target_object: interface,
+                             property,
+                             node,
+                             diagnostics,
+                             ..
+                         }| match u32::try_from(
+                    property.expression.as_number().unwrap(),
+                ) {
+                    Ok(dummy_bytes @ 0..=8) => {
+                        interface.dummy_bytes =
+                            Some(dummy_bytes.with_span(property.expression.span));
+                        false
+                    }
+                    _ => {
+                        diagnostics.add(InvalidInterfaceProperty {
+                            interface_name: node.name.span,
+                            property: "dummy-bytes",
+                            value: property.expression.span,
+                            reason: "value must be in the range 0..=8".into(),
+                        });
+                        true
+                    }
+                },
+            },
+        ];
+        MAP
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Routine {
+    const NODE_TYPE: NodeType = NodeType::Routine;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<Routine>] = &[PropertyInfo {
+            name: PropertyName::Exact("when"),
+            description: "\
+Makes the routine only exist for the given chip variant. Specify it multiple times to make the routine exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+            allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+            multiple_allowed: true,
+            required: false,
+            supports_doc_comments: false,
+            setter: |SetterArgs::<Routine> {
+                         target_object: routine,
+                         property,
+                         diagnostics,
+                         ..
+                     }| {
+                push_chip_variant(&mut routine.chip_variants, property, diagnostics)
+            },
+        }];
+        MAP
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Composite {
+    const NODE_TYPE: NodeType = NodeType::Composite;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<Composite>] = &[
+            PropertyInfo {
+                name: PropertyName::Exact("latch"),
+                description: "\
+The register that latches the value, like a high byte register that captures the low byte when it's read.
+It's read before and written after the other registers. Without a latch, the registers are read and written in the order of the parts.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                    device_driver_parser::Ident::new_no_span("TimerHigh"),
+                )]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Composite> {
+                             target_object: composite,
+                             property,
+                             ..
+                         }| {
+                    let Expression::TypeReference(name) = property.expression.value else {
+                        unreachable!("Only type references are allowed for registers")
+                    };
+                    composite.latch = Some(IdentifierRef::new(name.val.into()).with_span(name.span));
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("when"),
+                description: "\
+Makes the composite only exist for the given chip variant. Specify it multiple times to make the composite exist for any of the given variants.
+The variant must be declared with the `variant` property on the device or manifest.",
+                allowed_expression_types: Cow::Borrowed(&[CHIP_VARIANT_EXAMPLE]),
+                multiple_allowed: true,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Composite> {
+                             target_object: composite,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    push_chip_variant(&mut composite.chip_variants, property, diagnostics)
+                },
+            },
+        ];
+        MAP
+    }
+
+    fn base_type(&mut self) -> Option<&mut Spanned<BaseType>> {
+        Some(&mut self.base_type)
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for CompositePart {
+    const NODE_TYPE: NodeType = NodeType::Part;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<CompositePart>] = &[PropertyInfo {
+            name: PropertyName::Exact("field"),
+            description: "The field of the register that holds the bits of this part. It must be an unsigned integer field.",
+            allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                device_driver_parser::Ident::new_no_span("count"),
+            )]),
+            multiple_allowed: false,
+            required: true,
+            supports_doc_comments: false,
+            setter: |SetterArgs::<CompositePart> {
+                         target_object: part,
+                         property,
+                         ..
+                     }| {
+                let Expression::TypeReference(name) = property.expression.value else {
+                    unreachable!("Only type references are allowed for fields")
+                };
+                part.field = Some(IdentifierRef::new(name.val.into()).with_span(name.span));
+                false
+            },
+        }];
+        MAP
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+const STATE_EXAMPLE: Expression<'static> =
+    Expression::TypeReference(device_driver_parser::Ident::new_no_span("Standby"));
+
+/// Reference a state of the state machine by name. Whether the state exists is checked in a pass.
+fn state_ref(property: &Property) -> Spanned<IdentifierRef<All>> {
+    let Expression::TypeReference(name) = property.expression.value else {
+        unreachable!("Only type references are allowed for states")
+    };
+
+    IdentifierRef::new(name.val.into()).with_span(name.span)
+}
+
+impl Shape for StateMachine {
+    const NODE_TYPE: NodeType = NodeType::States;
+    type NameIdentifierType = Type;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<StateMachine>] = &[PropertyInfo {
+            name: PropertyName::Exact("initial"),
+            description: "The state the device is in when the state machine wrapper is created.",
+            allowed_expression_types: Cow::Borrowed(&[STATE_EXAMPLE]),
+            multiple_allowed: false,
+            required: true,
+            supports_doc_comments: false,
+            setter: |SetterArgs::<StateMachine> {
+                         target_object: state_machine,
+                         property,
+                         ..
+                     }| {
+                state_machine.initial = Some(state_ref(property));
+                false
+            },
+        }];
+        MAP
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for State {
+    const NODE_TYPE: NodeType = NodeType::State;
+    type NameIdentifierType = All;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        &[]
+    }
+
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Transition {
+    const NODE_TYPE: NodeType = NodeType::Transition;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<Transition>] = &[
+            PropertyInfo {
+                name: PropertyName::Exact("from"),
+                description: "The state the transition starts in. Specify it multiple times to allow the transition from multiple states.",
+                allowed_expression_types: Cow::Borrowed(&[STATE_EXAMPLE]),
+                multiple_allowed: true,
+                required: true,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Transition> {
+                             target_object: transition,
+                             property,
+                             ..
+                         }| {
+                    transition.from.push(state_ref(property));
+                    false
                 },
-            ]
-            .into()
-        });
-        &MAP
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("to"),
+                description: "The state the device is in after the transition.",
+                allowed_expression_types: Cow::Borrowed(&[STATE_EXAMPLE]),
+                multiple_allowed: false,
+                required: true,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Transition> {
+                             target_object: transition,
+                             property,
+                             ..
+                         }| {
+                    transition.to = Some(state_ref(property));
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("routine"),
+                description: "\
+The routine that's run to make the transition. The routine must be in the same device as the state machine.
+Instead of a routine, the statements can be given in the body of the transition like in a routine.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::TypeReference(
+                    device_driver_parser::Ident::new_no_span("wake_up"),
+                )]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Transition> {
+                             target_object: transition,
+                             property,
+                             ..
+                         }| {
+                    let Expression::TypeReference(name) = property.expression.value else {
+                        unreachable!("Only type references are allowed for routines")
+                    };
+                    transition.routine =
+                        Some(IdentifierRef::new(name.val.into()).with_span(name.span));
+                    false
+                },
+            },
+        ];
+        MAP
     }
 
-    fn repeat(&mut self) -> Option<&mut Option<Repeat>> {
-        Some(&mut self.repeat)
+    fn span(&mut self) -> &mut Span {
+        &mut self.span
+    }
+
+    fn properties_span(&mut self) -> &mut Option<Span> {
+        &mut self.properties_span
+    }
+
+    fn short_properties_span(&mut self) -> &mut Span {
+        &mut self.short_properties_span
+    }
+}
+
+impl Shape for Allow {
+    const NODE_TYPE: NodeType = NodeType::Allowed;
+    type NameIdentifierType = Operation;
+
+    fn doc_comments(&mut self) -> &mut String {
+        &mut self.description
+    }
+
+    fn name(&mut self) -> &mut Spanned<Identifier<Self::NameIdentifierType>> {
+        &mut self.name
+    }
+
+    fn supported_properties() -> &'static [PropertyInfo<Self>] {
+        static MAP: &[PropertyInfo<Allow>] = &[PropertyInfo {
+            name: PropertyName::Exact("state"),
+            description: "\
+A state the operation may be used in. Specify it multiple times to allow the operation in multiple states.
+Operations without an `allowed` node may be used in every state.",
+            allowed_expression_types: Cow::Borrowed(&[STATE_EXAMPLE]),
+            multiple_allowed: true,
+            required: true,
+            supports_doc_comments: false,
+            setter: |SetterArgs::<Allow> {
+                         target_object: allow,
+                         property,
+                         ..
+                     }| {
+                allow.states.push(state_ref(property));
+                false
+            },
+        }];
+        MAP
     }
 
     fn span(&mut self) -> &mut Span {
@@ -1261,6 +2851,31 @@ impl Shape for Command {
     }
 }
 
+/// Set one of the address bits of an interface. The bit is checked against the address size in a pass.
+fn set_interface_bit(
+    bit: &mut Option<Spanned<u32>>,
+    property_name: &'static str,
+    property: &Spanned<Property>,
+    node: &Node,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    match u32::try_from(property.expression.as_number().unwrap()) {
+        Ok(value @ 0..64) => {
+            *bit = Some(value.with_span(property.expression.span));
+            false
+        }
+        _ => {
+            diagnostics.add(InvalidInterfaceProperty {
+                interface_name: node.name.span,
+                property: property_name,
+                value: property.expression.span,
+                reason: "value must be in the range 0..64".into(),
+            });
+            true
+        }
+    }
+}
+
 impl Shape for Field {
     const NODE_TYPE: NodeType = NodeType::Field;
     type NameIdentifierType = All;
@@ -1277,12 +2892,12 @@ impl Shape for Field {
         static MAP: &[PropertyInfo<Field>] = &[
             PropertyInfo {
                 name: PropertyName::Short("address"),
-                description: "The bit address of the field within the fieldset",
+                description: "The bit address of the field within the fieldset. A field split over multiple bit ranges lists all of them, starting with the least significant part",
                 allowed_expression_types: Cow::Borrowed(&[
                     Expression::AddressRange { end: 8, start: 0 },
                     Expression::Number(0),
                 ]),
-                multiple_allowed: false,
+                multiple_allowed: true,
                 required: true,
                 supports_doc_comments: false,
                 setter: |SetterArgs::<Field> {
@@ -1293,7 +2908,7 @@ impl Shape for Field {
                          }| {
                     let u32_range = 0..=u32::MAX as i128;
 
-                    field.field_address = match property.expression.value {
+                    let field_address = match property.expression.value {
                         Expression::AddressRange { end, start }
                             if u32_range.contains(&end) && u32_range.contains(&start) =>
                         {
@@ -1324,6 +2939,12 @@ impl Shape for Field {
                         _ => unreachable!(),
                     }
                     .with_span(property.expression.span);
+
+                    if field.field_address.span.is_empty() {
+                        field.field_address = field_address;
+                    } else {
+                        field.split_field_address.push(field_address);
+                    }
                     false
                 },
             },
@@ -1343,6 +2964,87 @@ impl Shape for Field {
                     false
                 },
             },
+            PropertyInfo {
+                name: PropertyName::Exact("range"),
+                description: "Limits the values the field may be set to. The checked setters return an error for values outside of this range.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::ValueRange {
+                    start: 10,
+                    end: 200,
+                }]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Field> {
+                             target_object: field,
+                             property,
+                             diagnostics,
+                             ..
+                         }| {
+                    let Expression::ValueRange { start, end } = property.expression.value else {
+                        unreachable!()
+                    };
+
+                    if end < start {
+                        diagnostics.add(ValueRangeWrongOrder {
+                            range: property.expression.span,
+                            start,
+                            end,
+                        });
+                        return true;
+                    }
+
+                    field.value_range =
+                        Some(ValueRange { start, end }.with_span(property.expression.span));
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("reserved"),
+                description: "Marks the field as reserved with a value it must always have, like datasheets that say \"must be written as 0b10\".\nThe field gets no setter and is set to this value on every write.",
+                allowed_expression_types: Cow::Borrowed(&[Expression::Number(2)]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Field> {
+                             target_object: field,
+                             property,
+                             ..
+                         }| {
+                    let Expression::Number(value) = property.expression.value else {
+                        unreachable!()
+                    };
+
+                    field.reserved = Some(value.with_span(property.span));
+                    false
+                },
+            },
+            PropertyInfo {
+                name: PropertyName::Exact("reset"),
+                description: "The reset value of the field. Fields that are converted to an enum can use the name of a variant.\nThe reset values of the fields are combined into the reset value of the registers that use the fieldset.\nRegisters with their own `reset` must agree with the reset values of the fields.",
+                allowed_expression_types: Cow::Borrowed(&[
+                    Expression::Number(3),
+                    Expression::TypeReference(Ident::new_no_span("Enabled")),
+                ]),
+                multiple_allowed: false,
+                required: false,
+                supports_doc_comments: false,
+                setter: |SetterArgs::<Field> {
+                             target_object: field,
+                             property,
+                             ..
+                         }| {
+                    let value = match property.expression.value {
+                        Expression::Number(value) => FieldValue::Number(value),
+                        Expression::TypeReference(variant) => {
+                            FieldValue::Variant(IdentifierRef::new(variant.val.into()))
+                        }
+                        _ => unreachable!(),
+                    };
+
+                    field.reset_value = Some(value.with_span(property.expression.span));
+                    false
+                },
+            },
         ];
         MAP
     }
@@ -1351,6 +3053,10 @@ impl Shape for Field {
         Some(&mut self.base_type)
     }
 
+    fn encoding(&mut self) -> Option<&mut Option<Spanned<Encoding>>> {
+        Some(&mut self.encoding)
+    }
+
     fn conversion_type(&mut self) -> Option<&mut Option<TypeConversion>> {
         Some(&mut self.field_conversion)
     }
@@ -1371,3 +3077,20 @@ impl Shape for Field {
         &mut self.short_properties_span
     }
 }
+
+/// Sort the ranges and merge the ones that overlap or are adjacent
+fn merge_value_ranges(ranges: impl IntoIterator<Item = ValueRange>) -> Vec<ValueRange> {
+    let mut merged = Vec::<ValueRange>::new();
+
+    for range in ranges
+        .into_iter()
+        .sorted_unstable_by_key(|range| range.start)
+    {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end + 1 => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use device_driver_common::{span::SpanExt, specifiers::NodeType};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{InvalidNodeType, InvalidTransition, UnknownNodeType},
+};
+use device_driver_parser::{Ident, Node};
+
+use crate::{
+    lowering::{Definitions, parse_node_to_shape, routines},
+    model::{Allow, Object, State, StateMachine, Statement, Transition},
+};
+
+const SUBNODE_TYPES: &[NodeType] = &[NodeType::State, NodeType::Transition, NodeType::Allowed];
+
+/// Lower a states node with its states, transitions and allowed operations
+pub fn lower_state_machine<'src>(
+    node: &Node<'src>,
+    parent_node_name: Option<Ident<'src>>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<(StateMachine, Vec<Object>), Vec<Object>> {
+    // The sub nodes are not objects, so they're lowered here
+    let state_machine_node = Node {
+        sub_nodes: Vec::new(),
+        ..node.clone()
+    };
+    let (mut state_machine, siblings) = parse_node_to_shape::<StateMachine>(
+        &state_machine_node,
+        parent_node_name,
+        definitions,
+        diagnostics,
+    )?;
+
+    let mut error = false;
+
+    for sub_node in &node.sub_nodes {
+        match NodeType::from_str(sub_node.node_type.val) {
+            Ok(NodeType::State) => {
+                match parse_node_to_shape::<State>(sub_node, None, definitions, diagnostics) {
+                    Ok((state, _)) => state_machine.states.push(state),
+                    Err(_) => error = true,
+                }
+            }
+            Ok(NodeType::Transition) => {
+                match lower_transition(sub_node, definitions, diagnostics) {
+                    Ok(transition) => state_machine.transitions.push(transition),
+                    Err(()) => error = true,
+                }
+            }
+            Ok(NodeType::Allowed) => {
+                match parse_node_to_shape::<Allow>(sub_node, None, definitions, diagnostics) {
+                    Ok((allow, _)) => state_machine.allows.push(allow),
+                    Err(_) => error = true,
+                }
+            }
+            Ok(_) => {
+                diagnostics.add(InvalidNodeType {
+                    node_type: sub_node.node_type.span,
+                    parent_node_type: Some(NodeType::States.with_span(node.node_type.span)),
+                    allowed_node_types: SUBNODE_TYPES.to_vec(),
+                });
+                error = true;
+            }
+            Err(()) => {
+                diagnostics.add(UnknownNodeType {
+                    node_type: sub_node.node_type.span,
+                    allowed_node_types: SUBNODE_TYPES.to_vec(),
+                });
+                error = true;
+            }
+        }
+    }
+
+    if error {
+        Err(siblings)
+    } else {
+        Ok((state_machine, siblings))
+    }
+}
+
+/// Lower a transition. Its sub nodes are statements like in a routine.
+fn lower_transition<'src>(
+    node: &Node<'src>,
+    definitions: &Definitions<'_, 'src>,
+    diagnostics: &mut Diagnostics,
+) -> Result<Transition, ()> {
+    let transition_node = Node {
+        sub_nodes: Vec::new(),
+        ..node.clone()
+    };
+    let transition =
+        parse_node_to_shape::<Transition>(&transition_node, None, definitions, diagnostics);
+    let statements = routines::lower_statements(node, definitions, diagnostics);
+
+    let (Ok((mut transition, _)), Ok(statements)) = (transition, statements) else {
+        return Err(());
+    };
+
+    if let Some(routine) = &transition.routine
+        && let Some(statement) = statements.first()
+    {
+        diagnostics.add(InvalidTransition {
+            transition: transition.name.span,
+            problem: statement.span(),
+            reason: format!(
+                "the transition runs routine `{}`, so it can't have statements too",
+                routine.original()
+            )
+            .into(),
+        });
+        return Err(());
+    }
+
+    if let Some(statement @ Statement::Return(_)) = statements.last() {
+        diagnostics.add(InvalidTransition {
+            transition: transition.name.span,
+            problem: statement.span(),
+            reason: "a transition can't return a value. Use a routine for this instead".into(),
+        });
+        return Err(());
+    }
+
+    transition.statements = statements;
+    Ok(transition)
+}
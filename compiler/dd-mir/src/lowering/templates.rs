@@ -0,0 +1,374 @@
+use std::{cell::RefCell, collections::HashMap, num::NonZeroU32, str::FromStr};
+
+use device_driver_common::{
+    span::{Span, SpanExt, Spanned},
+    specifiers::NodeType,
+};
+use device_driver_diagnostics::{
+    Diagnostics,
+    errors::{
+        DuplicateProperty, DuplicateTemplate, InvalidRepeat, InvalidShortProperty,
+        InvalidTemplateArgument, InvalidTemplateBody, InvalidTypeSpecifier,
+        MissingTemplateArgument, RecursiveTemplateInstance, TemplateNameReserved,
+        UnknownTemplateArgument,
+    },
+};
+use device_driver_parser::{Expression, Ident, IntExpression, Node, RepeatSource, TypeConversion};
+
+/// All templates defined in the AST
+#[derive(Default)]
+pub struct Templates<'a, 'src> {
+    templates: HashMap<&'src str, Template<'a, 'src>>,
+    /// The name spans of the templates that are being instantiated right now. Used to detect recursion.
+    active: RefCell<Vec<Span>>,
+}
+
+struct Template<'a, 'src> {
+    name: Ident<'src>,
+    doc_comments: &'a [Spanned<&'src str>],
+    parameters: Vec<Parameter<'a, 'src>>,
+    body: &'a Node<'src>,
+}
+
+struct Parameter<'a, 'src> {
+    name: Ident<'src>,
+    /// The default value of the parameter. If None, the parameter is required.
+    default: Option<&'a Spanned<Expression<'src>>>,
+}
+
+impl<'a, 'src> Templates<'a, 'src> {
+    /// Collect all template nodes in the tree
+    pub fn collect(root_node: &'a Node<'src>, diagnostics: &mut Diagnostics) -> Self {
+        let mut templates = Self::default();
+        templates.collect_node(root_node, diagnostics);
+        templates
+    }
+
+    fn collect_node(&mut self, node: &'a Node<'src>, diagnostics: &mut Diagnostics) {
+        if node.node_type.val == NodeType::Template.to_string()
+            && let Some(template) = Template::new(node, diagnostics)
+        {
+            if let Some(original) = self.templates.get(template.name.val) {
+                diagnostics.add(DuplicateTemplate {
+                    original: original.name.span,
+                    duplicate: template.name.span,
+                });
+            } else {
+                self.templates.insert(template.name.val, template);
+            }
+        }
+
+        for sub_node in node.sub_nodes.iter() {
+            self.collect_node(sub_node, diagnostics);
+        }
+    }
+
+    /// Create the node for a template instance. The node type of the instance is the name of the template.
+    ///
+    /// Returns None if the node is not a template instance.
+    /// Returns Some(Err) if the node is a template instance that could not be instantiated.
+    pub fn instantiate(
+        &self,
+        instance: &Node<'src>,
+        diagnostics: &mut Diagnostics,
+    ) -> Option<Result<Node<'src>, ()>> {
+        let template = self.templates.get(instance.node_type.val)?;
+
+        if self.active.borrow().contains(&template.name.span) {
+            diagnostics.add(RecursiveTemplateInstance {
+                instance: instance.node_type.span,
+                template_name: template.name.span,
+            });
+            return Some(Err(()));
+        }
+
+        Some(template.instantiate(instance, diagnostics))
+    }
+
+    /// Run the function while the template of the instance is marked as being instantiated
+    pub fn with_active<R>(&self, instance: &Node<'src>, f: impl FnOnce() -> R) -> R {
+        self.active.borrow_mut().extend(
+            self.templates
+                .get(instance.node_type.val)
+                .map(|t| t.name.span),
+        );
+        let result = f();
+        self.active.borrow_mut().pop();
+        result
+    }
+}
+
+impl<'a, 'src> Template<'a, 'src> {
+    fn new(node: &'a Node<'src>, diagnostics: &mut Diagnostics) -> Option<Self> {
+        let node_type = NodeType::Template.with_span(node.node_type.span);
+        let mut error = false;
+
+        if NodeType::from_str(node.name.val).is_ok() {
+            diagnostics.add(TemplateNameReserved {
+                name: node.name.span,
+            });
+            error = true;
+        }
+
+        if let Some(repeat) = &node.repeat {
+            diagnostics.add(InvalidRepeat {
+                repeat: repeat.span,
+                node_type,
+            });
+        }
+
+        for short_property in node.short_properties.iter() {
+            diagnostics.add(InvalidShortProperty {
+                property: short_property.span,
+                node_type,
+                got: short_property.to_string(),
+                expected: Vec::new(),
+            });
+        }
+
+        if let Some(type_specifier) = node.type_specifier.as_ref() {
+            diagnostics.add(InvalidTypeSpecifier {
+                node_type,
+                type_specifier: type_specifier.span,
+            });
+        }
+
+        let mut parameters = Vec::<Parameter>::new();
+        for property in node.properties.iter() {
+            if let Some(original) = parameters
+                .iter()
+                .find(|parameter| parameter.name.val == property.name.val)
+            {
+                diagnostics.add(DuplicateProperty {
+                    original: original.name.span,
+                    duplicate: property.name.span,
+                });
+                continue;
+            }
+
+            parameters.push(Parameter {
+                name: property.name,
+                default: (!matches!(property.expression.value, Expression::Auto))
+                    .then_some(&property.expression),
+            });
+        }
+
+        let [body] = node.sub_nodes.as_slice() else {
+            diagnostics.add(InvalidTemplateBody {
+                template_name: node.name.span,
+                sub_nodes: node
+                    .sub_nodes
+                    .iter()
+                    .map(|sub_node| sub_node.span)
+                    .collect(),
+            });
+            return None;
+        };
+
+        (!error).then_some(Self {
+            name: node.name,
+            doc_comments: &node.doc_comments,
+            parameters,
+            body,
+        })
+    }
+
+    fn instantiate(
+        &self,
+        instance: &Node<'src>,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<Node<'src>, ()> {
+        let mut error = false;
+
+        // Match the arguments to the parameters
+
+        let mut arguments = HashMap::new();
+        let mut argument_names = HashMap::new();
+        for argument in instance.properties.iter() {
+            if !self
+                .parameters
+                .iter()
+                .any(|parameter| parameter.name.val == argument.name.val)
+            {
+                diagnostics.add(UnknownTemplateArgument {
+                    argument: argument.name.span,
+                    template_name: self.name.span,
+                    parameters: self
+                        .parameters
+                        .iter()
+                        .map(|parameter| parameter.name.val.to_string())
+                        .collect(),
+                });
+                error = true;
+                continue;
+            }
+
+            if let Some(original) = argument_names.insert(argument.name.val, argument.name.span) {
+                diagnostics.add(DuplicateProperty {
+                    original,
+                    duplicate: argument.name.span,
+                });
+                error = true;
+                continue;
+            }
+
+            arguments.insert(argument.name.val, &argument.expression);
+        }
+
+        for parameter in self.parameters.iter() {
+            if arguments.contains_key(parameter.name.val) {
+                continue;
+            }
+
+            match parameter.default {
+                Some(default) => {
+                    arguments.insert(parameter.name.val, default);
+                }
+                None => {
+                    diagnostics.add(MissingTemplateArgument {
+                        instance: instance.node_type.span.to(instance.name.span),
+                        parameter: parameter.name.span,
+                        parameter_name: parameter.name.val.to_string(),
+                    });
+                    error = true;
+                }
+            }
+        }
+
+        if error {
+            return Err(());
+        }
+
+        // Create the node
+
+        let mut node = self.body.clone();
+        let mut substitution = Substitution {
+            arguments,
+            diagnostics,
+            error: false,
+        };
+        substitution.substitute_node(&mut node);
+        if substitution.error {
+            return Err(());
+        }
+
+        node.name = instance.name;
+        if !instance.doc_comments.is_empty() {
+            node.doc_comments = instance.doc_comments.clone();
+        } else if node.doc_comments.is_empty() {
+            node.doc_comments = self.doc_comments.to_vec();
+        }
+        if instance.repeat.is_some() {
+            node.repeat = instance.repeat.clone();
+        }
+        node.short_properties
+            .extend(instance.short_properties.iter().cloned());
+        if instance.type_specifier.is_some() {
+            node.type_specifier = instance.type_specifier.clone();
+        }
+        node.sub_nodes.extend(instance.sub_nodes.iter().cloned());
+
+        Ok(node)
+    }
+}
+
+/// Replaces the uses of the parameters in a node with the arguments
+struct Substitution<'a, 'src> {
+    arguments: HashMap<&'src str, &'a Spanned<Expression<'src>>>,
+    diagnostics: &'a mut Diagnostics,
+    error: bool,
+}
+
+impl<'src> Substitution<'_, 'src> {
+    fn substitute_node(&mut self, node: &mut Node<'src>) {
+        self.substitute_ident(&mut node.name);
+
+        if let Some(repeat) = node.repeat.as_mut()
+            && let RepeatSource::Enum(ident) = repeat.source.value
+            && let Some(argument) = self.arguments.get(ident.val)
+        {
+            match argument.value {
+                Expression::TypeReference(argument_ident) => {
+                    repeat.source.value = RepeatSource::Enum(argument_ident);
+                }
+                Expression::Number(count) => {
+                    match u32::try_from(count).ok().and_then(NonZeroU32::new) {
+                        Some(count) => repeat.source.value = RepeatSource::Count(count),
+                        None => self.invalid_argument(argument.span, ident.span),
+                    }
+                }
+                _ => self.invalid_argument(argument.span, ident.span),
+            }
+        }
+
+        if let Some(type_specifier) = node.type_specifier.as_mut() {
+            match type_specifier.conversion.as_mut() {
+                Some(TypeConversion::Reference(ident)) => self.substitute_ident(ident),
+                Some(TypeConversion::Subnode(sub_node)) => self.substitute_node(sub_node),
+                None => {}
+            }
+        }
+
+        for property in node.properties.iter_mut() {
+            match &mut property.expression.value {
+                Expression::TypeReference(ident) => {
+                    if let Some(argument) = self.arguments.get(ident.val) {
+                        property.expression = (*argument).clone();
+                    }
+                }
+                Expression::IntExpression(expression) => self.substitute_int_expression(expression),
+                Expression::SubNode(sub_node) => self.substitute_node(sub_node),
+                _ => {}
+            }
+        }
+
+        for sub_node in node.sub_nodes.iter_mut() {
+            self.substitute_node(sub_node);
+        }
+    }
+
+    /// Substitute the constants of an integer expression that are parameters
+    fn substitute_int_expression(&mut self, expression: &mut IntExpression<'src>) {
+        match expression {
+            IntExpression::Number(_) => {}
+            IntExpression::Constant(ident) => {
+                let Some(argument) = self.arguments.get(ident.val) else {
+                    return;
+                };
+
+                match &argument.value {
+                    Expression::Number(num) => *expression = IntExpression::Number(*num),
+                    Expression::TypeReference(argument_ident) => {
+                        *expression = IntExpression::Constant(*argument_ident)
+                    }
+                    Expression::IntExpression(argument_expression) => {
+                        *expression = argument_expression.clone()
+                    }
+                    _ => self.invalid_argument(argument.span, ident.span),
+                }
+            }
+            IntExpression::Binary { lhs, rhs, .. } => {
+                self.substitute_int_expression(&mut lhs.value);
+                self.substitute_int_expression(&mut rhs.value);
+            }
+        }
+    }
+
+    /// Substitute an identifier that is used as a name
+    fn substitute_ident(&mut self, ident: &mut Ident<'src>) {
+        let Some(argument) = self.arguments.get(ident.val) else {
+            return;
+        };
+
+        match argument.value {
+            Expression::TypeReference(argument_ident) => *ident = argument_ident,
+            _ => self.invalid_argument(argument.span, ident.span),
+        }
+    }
+
+    fn invalid_argument(&mut self, argument: Span, usage: Span) {
+        self.diagnostics
+            .add(InvalidTemplateArgument { argument, usage });
+        self.error = true;
+    }
+}
@@ -7,8 +7,8 @@ use device_driver_common::{
     identifier::{All, Identifier, IdentifierRef, Operation, RuntimeType, Type},
     span::{Span, SpanExt, Spanned},
     specifiers::{
-        Access, AddressMode, AddressRange, BaseType, ByteOrder, Integer, NodeType, Repeat,
-        ResetValue, TypeConversion,
+        Access, AddressMode, AddressRange, BaseType, Bus, ByteOrder, Encoding, Integer, LevelUnit,
+        NodeType, Repeat, ResetValue, TimeUnit, TypeConversion, ValueRange,
     },
 };
 
@@ -251,6 +251,8 @@ pub struct Device {
     pub description: String,
     pub name: Spanned<Identifier<Type>>,
     pub default_access: Option<Access>,
+    /// The objects in the device may leave out their address to get an automatic one
+    pub default_auto_address: bool,
     pub device_config: DeviceConfig,
     pub objects: Vec<Object>,
 
@@ -281,8 +283,11 @@ pub struct DeviceConfig {
     pub register_address_type: Option<Spanned<Integer>>,
     pub command_address_type: Option<Spanned<Integer>>,
     pub buffer_address_type: Option<Spanned<Integer>>,
+    pub memory_address_type: Option<Spanned<Integer>>,
     pub name_word_boundaries: Option<Vec<Boundary>>,
     pub register_address_mode: Option<Spanned<AddressMode>>,
+    /// The chip variants that objects can be made conditional on. Only one variant can be active at a time.
+    pub chip_variants: Option<Vec<Spanned<String>>>,
 }
 
 impl DeviceConfig {
@@ -294,12 +299,18 @@ impl DeviceConfig {
             register_address_type: other.register_address_type.or(self.register_address_type),
             command_address_type: other.command_address_type.or(self.command_address_type),
             buffer_address_type: other.buffer_address_type.or(self.buffer_address_type),
+            memory_address_type: other.memory_address_type.or(self.memory_address_type),
             name_word_boundaries: other
                 .name_word_boundaries
                 .as_ref()
                 .or(self.name_word_boundaries.as_ref())
                 .cloned(),
             register_address_mode: other.register_address_mode.or(self.register_address_mode),
+            chip_variants: other
+                .chip_variants
+                .as_ref()
+                .or(self.chip_variants.as_ref())
+                .cloned(),
         }
     }
 }
@@ -311,10 +322,16 @@ pub enum Object {
     Register(Register),
     Command(Command),
     Buffer(Buffer),
+    Memory(Memory),
+    Fifo(Fifo),
     FieldSet(FieldSet),
     Enum(Enum),
     Extern(Extern),
     Field(Field),
+    Interface(Interface),
+    Routine(Routine),
+    Composite(Composite),
+    StateMachine(StateMachine),
 }
 
 impl Object {
@@ -357,9 +374,15 @@ impl Object {
             Object::Register(val) => val.name.as_runtime_type_mut(),
             Object::Command(val) => val.name.as_runtime_type_mut(),
             Object::Buffer(val) => val.name.as_runtime_type_mut(),
+            Object::Memory(val) => val.name.as_runtime_type_mut(),
+            Object::Fifo(val) => val.name.as_runtime_type_mut(),
             Object::FieldSet(val) => val.name.as_runtime_type_mut(),
             Object::Enum(val) => val.name.as_runtime_type_mut(),
             Object::Extern(val) => val.name.as_runtime_type_mut(),
+            Object::Interface(val) => val.name.as_runtime_type_mut(),
+            Object::Routine(val) => val.name.as_runtime_type_mut(),
+            Object::Composite(val) => val.name.as_runtime_type_mut(),
+            Object::StateMachine(val) => val.name.as_runtime_type_mut(),
             Object::Field(val) => val.name.as_runtime_type_mut(),
         }
     }
@@ -372,9 +395,15 @@ impl Object {
             Object::Register(val) => val.name.as_runtime_type(),
             Object::Command(val) => val.name.as_runtime_type(),
             Object::Buffer(val) => val.name.as_runtime_type(),
+            Object::Memory(val) => val.name.as_runtime_type(),
+            Object::Fifo(val) => val.name.as_runtime_type(),
             Object::FieldSet(val) => val.name.as_runtime_type(),
             Object::Enum(val) => val.name.as_runtime_type(),
             Object::Extern(val) => val.name.as_runtime_type(),
+            Object::Interface(val) => val.name.as_runtime_type(),
+            Object::Routine(val) => val.name.as_runtime_type(),
+            Object::Composite(val) => val.name.as_runtime_type(),
+            Object::StateMachine(val) => val.name.as_runtime_type(),
             Object::Field(val) => val.name.as_runtime_type(),
         }
     }
@@ -387,9 +416,15 @@ impl Object {
             Object::Register(val) => val.name.span,
             Object::Command(val) => val.name.span,
             Object::Buffer(val) => val.name.span,
+            Object::Memory(val) => val.name.span,
+            Object::Fifo(val) => val.name.span,
             Object::FieldSet(val) => val.name.span,
             Object::Enum(val) => val.name.span,
             Object::Extern(val) => val.name.span,
+            Object::Interface(val) => val.name.span,
+            Object::Routine(val) => val.name.span,
+            Object::Composite(val) => val.name.span,
+            Object::StateMachine(val) => val.name.span,
             Object::Field(val) => val.name.span,
         }
     }
@@ -402,13 +437,44 @@ impl Object {
             Object::Register(register) => Some(register.address),
             Object::Command(command) => Some(command.address),
             Object::Buffer(buffer) => Some(buffer.address),
+            Object::Memory(memory) => Some(memory.address),
+            Object::Fifo(fifo) => Some(fifo.address),
             Object::FieldSet(_) => None,
             Object::Enum(_) => None,
             Object::Extern(_) => None,
+            Object::Interface(_) => None,
+            Object::Routine(_) => None,
+            Object::Composite(_) => None,
+            Object::StateMachine(_) => None,
             Object::Field(_) => None,
         }
     }
 
+    /// Return the address if it is specified.
+    pub fn address_mut(&mut self) -> Option<&mut Spanned<i128>> {
+        match self {
+            Object::Block(block) => Some(&mut block.address_offset),
+            Object::Register(register) => Some(&mut register.address),
+            Object::Command(command) => Some(&mut command.address),
+            Object::Buffer(buffer) => Some(&mut buffer.address),
+            Object::Memory(memory) => Some(&mut memory.address),
+            Object::Fifo(fifo) => Some(&mut fifo.address),
+            _ => None,
+        }
+    }
+
+    /// How the address of the object is given. None if the object has no address
+    pub fn address_kind(&self) -> Option<AddressKind> {
+        match self {
+            Object::Register(register) => Some(register.address_kind),
+            Object::Command(command) => Some(command.address_kind),
+            Object::Buffer(buffer) => Some(buffer.address_kind),
+            Object::Memory(memory) => Some(memory.address_kind),
+            Object::Fifo(fifo) => Some(fifo.address_kind),
+            _ => None,
+        }
+    }
+
     /// Return the repeat value if it exists
     pub fn repeat(&self) -> Option<&Repeat> {
         match self {
@@ -417,9 +483,15 @@ impl Object {
             Object::Register(register) => register.repeat.as_ref(),
             Object::Command(command) => command.repeat.as_ref(),
             Object::Buffer(_) => None,
+            Object::Memory(_) => None,
+            Object::Fifo(_) => None,
             Object::FieldSet(_) => None,
             Object::Enum(_) => None,
             Object::Extern(_) => None,
+            Object::Interface(_) => None,
+            Object::Routine(_) => None,
+            Object::Composite(_) => None,
+            Object::StateMachine(_) => None,
             Object::Field(field) => field.repeat.as_ref(),
         }
     }
@@ -432,9 +504,15 @@ impl Object {
             Object::Register(register) => register.repeat.as_mut(),
             Object::Command(command) => command.repeat.as_mut(),
             Object::Buffer(_) => None,
+            Object::Memory(_) => None,
+            Object::Fifo(_) => None,
             Object::FieldSet(_) => None,
             Object::Enum(_) => None,
             Object::Extern(_) => None,
+            Object::Interface(_) => None,
+            Object::Routine(_) => None,
+            Object::Composite(_) => None,
+            Object::StateMachine(_) => None,
             Object::Field(field) => field.repeat.as_mut(),
         }
     }
@@ -470,13 +548,70 @@ impl Object {
             Object::Register(register) => register.allow_address_overlap,
             Object::Command(command) => command.allow_address_overlap,
             Object::Buffer(_) => false,
+            Object::Memory(_) => false,
+            Object::Fifo(_) => false,
             Object::FieldSet(_) => false,
             Object::Enum(_) => false,
             Object::Extern(_) => false,
+            Object::Interface(_) => false,
+            Object::Routine(_) => false,
+            Object::Composite(_) => false,
+            Object::StateMachine(_) => false,
             Object::Field(_) => false,
         }
     }
 
+    /// The chip variants the object exists for. None if the object can't be conditional
+    pub fn chip_variants(&self) -> Option<&Vec<Spanned<String>>> {
+        match self {
+            Object::Device(_) => None,
+            Object::Block(block) => Some(&block.chip_variants),
+            Object::Register(register) => Some(&register.chip_variants),
+            Object::Command(command) => Some(&command.chip_variants),
+            Object::Buffer(buffer) => Some(&buffer.chip_variants),
+            Object::Memory(memory) => Some(&memory.chip_variants),
+            Object::Fifo(fifo) => Some(&fifo.chip_variants),
+            Object::FieldSet(_) => None,
+            Object::Enum(_) => None,
+            Object::Extern(_) => None,
+            Object::Interface(_) => None,
+            Object::Routine(routine) => Some(&routine.chip_variants),
+            Object::Composite(composite) => Some(&composite.chip_variants),
+            Object::StateMachine(_) => None,
+            Object::Field(_) => None,
+        }
+    }
+
+    /// The chip variants the object exists for. None if the object can't be conditional
+    pub fn chip_variants_mut(&mut self) -> Option<&mut Vec<Spanned<String>>> {
+        match self {
+            Object::Device(_) => None,
+            Object::Block(block) => Some(&mut block.chip_variants),
+            Object::Register(register) => Some(&mut register.chip_variants),
+            Object::Command(command) => Some(&mut command.chip_variants),
+            Object::Buffer(buffer) => Some(&mut buffer.chip_variants),
+            Object::Memory(memory) => Some(&mut memory.chip_variants),
+            Object::Fifo(fifo) => Some(&mut fifo.chip_variants),
+            Object::FieldSet(_) => None,
+            Object::Enum(_) => None,
+            Object::Extern(_) => None,
+            Object::Interface(_) => None,
+            Object::Routine(routine) => Some(&mut routine.chip_variants),
+            Object::Composite(composite) => Some(&mut composite.chip_variants),
+            Object::StateMachine(_) => None,
+            Object::Field(_) => None,
+        }
+    }
+
+    /// Whether the object can exist at the same time as an object of the given chip variants.
+    /// Objects of mutually exclusive chip variants may share their name, so refs use this to find the object of their own variants.
+    pub fn coexists_with(&self, chip_variants: &[Spanned<String>]) -> bool {
+        !chip_variants_exclusive(
+            self.chip_variants().map_or(&[], Vec::as_slice),
+            chip_variants,
+        )
+    }
+
     /// The span of the entire object
     pub fn span(&self) -> Span {
         match self {
@@ -485,9 +620,15 @@ impl Object {
             Object::Register(val) => val.span,
             Object::Command(val) => val.span,
             Object::Buffer(val) => val.span,
+            Object::Memory(val) => val.span,
+            Object::Fifo(val) => val.span,
             Object::FieldSet(val) => val.span,
             Object::Enum(val) => val.span,
             Object::Extern(val) => val.span,
+            Object::Interface(val) => val.span,
+            Object::Routine(val) => val.span,
+            Object::Composite(val) => val.span,
+            Object::StateMachine(val) => val.span,
             Object::Field(val) => val.span,
         }
     }
@@ -499,27 +640,42 @@ impl Object {
             Object::Register(_) => NodeType::Register,
             Object::Command(_) => NodeType::Command,
             Object::Buffer(_) => NodeType::Buffer,
+            Object::Memory(_) => NodeType::Memory,
+            Object::Fifo(_) => NodeType::Fifo,
             Object::FieldSet(_) => NodeType::FieldSet,
             Object::Enum(_) => NodeType::Enum,
             Object::Extern(_) => NodeType::Extern,
+            Object::Interface(_) => NodeType::Interface,
+            Object::Routine(_) => NodeType::Routine,
+            Object::Composite(_) => NodeType::Composite,
+            Object::StateMachine(_) => NodeType::States,
             Object::Field(_) => NodeType::Field,
         }
     }
 
-    /// Get the fieldset refs of the object. Only returns non-zero for registers and commands
+    /// Get the fieldset refs of the object. Only returns non-zero for registers (including their views), commands and fifos
     pub fn fieldset_refs(&self) -> Vec<Spanned<IdentifierRef<Type>>> {
         match self {
             Object::Device(_) => Vec::new(),
             Object::Block(_) => Vec::new(),
-            Object::Register(r) => vec![r.field_set_ref.clone()],
+            Object::Register(r) => [r.field_set_ref.clone()]
+                .into_iter()
+                .chain(r.views.iter().cloned())
+                .collect(),
             Object::Command(c) => [c.field_set_ref_in.clone(), c.field_set_ref_out.clone()]
                 .into_iter()
                 .flatten()
                 .collect(),
             Object::Buffer(_) => Vec::new(),
+            Object::Memory(_) => Vec::new(),
+            Object::Fifo(fifo) => vec![fifo.record_ref.clone()],
             Object::FieldSet(_) => Vec::new(),
             Object::Enum(_) => Vec::new(),
             Object::Extern(_) => Vec::new(),
+            Object::Interface(_) => Vec::new(),
+            Object::Routine(_) => Vec::new(),
+            Object::Composite(_) => Vec::new(),
+            Object::StateMachine(_) => Vec::new(),
             Object::Field(_) => Vec::new(),
         }
     }
@@ -531,22 +687,55 @@ impl Object {
             Object::Register(val) => val.properties_span,
             Object::Command(val) => val.properties_span,
             Object::Buffer(val) => val.properties_span,
+            Object::Memory(val) => val.properties_span,
+            Object::Fifo(val) => val.properties_span,
             Object::FieldSet(val) => val.properties_span,
             Object::Enum(val) => val.properties_span,
             Object::Extern(val) => val.properties_span,
+            Object::Interface(val) => val.properties_span,
+            Object::Routine(val) => val.properties_span,
+            Object::Composite(val) => val.properties_span,
+            Object::StateMachine(val) => val.properties_span,
             Object::Field(val) => val.properties_span,
         }
     }
 }
 
+/// Checks if two sets of chip variants can never be active at the same time.
+/// An empty set means the object exists for all variants, so it's never exclusive.
+pub fn chip_variants_exclusive(left: &[Spanned<String>], right: &[Spanned<String>]) -> bool {
+    !left.is_empty()
+        && !right.is_empty()
+        && !left
+            .iter()
+            .any(|l| right.iter().any(|r| l.value == r.value))
+}
+
+/// How the address of an object is given
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AddressKind {
+    #[default]
+    Number,
+    /// Given as `auto` or `_`. The address is assigned after the previous object by a MIR pass
+    Auto,
+    /// Left out. This is the same as [AddressKind::Auto] in a block with `default-address: auto`, and an error elsewhere
+    Missing,
+}
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Block {
     pub description: String,
     pub name: Spanned<Identifier<All>>,
     pub address_offset: Spanned<i128>,
     pub repeat: Option<Repeat>,
+    /// The block whose objects this block shares. An instance block has no objects of its own
+    pub block_ref: Option<Spanned<IdentifierRef<All>>>,
     pub objects: Vec<Object>,
     pub default_access: Option<Access>,
+    /// The objects in the block may leave out their address to get an automatic one
+    pub default_auto_address: bool,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
 
     pub short_properties_span: Span,
     pub properties_span: Option<Span>,
@@ -574,9 +763,18 @@ pub struct Register {
     pub access: Option<Access>,
     pub allow_address_overlap: bool,
     pub address: Spanned<i128>,
+    pub address_kind: AddressKind,
     pub reset_value: Option<Spanned<ResetValue>>,
+    /// The reset value is assembled from the reset values of the fields. Its span is the name of the register
+    pub reset_from_fields: bool,
     pub repeat: Option<Repeat>,
+    /// The indices of the repeat that have a different reset value or access than the register
+    pub index_overrides: Vec<IndexOverride>,
     pub field_set_ref: Spanned<IdentifierRef<Type>>,
+    /// Other fieldsets the data of the register can be read and written as
+    pub views: Vec<Spanned<IdentifierRef<Type>>>,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
 
     pub short_properties_span: Span,
     pub properties_span: Option<Span>,
@@ -584,6 +782,46 @@ pub struct Register {
     pub span: Span,
 }
 
+/// The reset value and access of one index of a repeated register that differ from the other indices
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexOverride {
+    pub description: String,
+    /// The name of the register. The index node itself is named after the index
+    pub name: Spanned<Identifier<Operation>>,
+    pub index: Spanned<RepeatIndex>,
+    pub access: Option<Access>,
+    pub reset_value: Option<Spanned<ResetValue>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// An index of a repeat as written in the source
+#[derive(Debug, Clone, PartialEq)]
+pub enum RepeatIndex {
+    /// The index of a count or offset list repeat
+    Number(i128),
+    /// The variant of the enum of an enum repeat
+    Variant(IdentifierRef<All>),
+}
+
+impl Default for RepeatIndex {
+    fn default() -> Self {
+        Self::Number(0)
+    }
+}
+
+impl Display for RepeatIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(index) => write!(f, "{index}"),
+            Self::Variant(variant) => write!(f, "{}", variant.original()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct FieldSet {
     pub description: String,
@@ -591,6 +829,8 @@ pub struct FieldSet {
     pub size_bytes: Spanned<u32>,
     pub byte_order: Option<ByteOrder>,
     pub allow_bit_overlap: bool,
+    /// The span of the `flags` property when the fieldset is generated as a set of flags
+    pub flags: Option<Span>,
     pub default_access: Option<Access>,
     pub fields: Vec<Field>,
 
@@ -612,9 +852,18 @@ pub struct Field {
     pub name: Spanned<Identifier<All>>,
     pub access: Option<Access>,
     pub base_type: Spanned<BaseType>,
+    pub encoding: Option<Spanned<Encoding>>,
     pub field_conversion: Option<TypeConversion>,
     pub field_address: Spanned<AddressRange>,
+    /// The further bit ranges of a field that is split over multiple ranges, in order of significance.
+    /// The `field_address` holds the least significant bits. Empty for fields with a single range.
+    pub split_field_address: Vec<Spanned<AddressRange>>,
     pub repeat: Option<Repeat>,
+    pub value_range: Option<Spanned<ValueRange>>,
+    /// The value a reserved field must always have. The span is of the `reserved` property
+    pub reserved: Option<Spanned<i128>>,
+    /// The reset value of the field. It's assembled into the reset value of the registers using the fieldset
+    pub reset_value: Option<Spanned<FieldValue>>,
 
     pub short_properties_span: Span,
     pub properties_span: Option<Span>,
@@ -623,6 +872,34 @@ pub struct Field {
 }
 
 impl Field {
+    /// All bit ranges of the field, from the least to the most significant part
+    pub fn field_address_parts(&self) -> impl Iterator<Item = &Spanned<AddressRange>> {
+        std::iter::once(&self.field_address).chain(&self.split_field_address)
+    }
+
+    /// The span of all bit ranges of the field
+    #[must_use]
+    pub fn field_address_span(&self) -> Span {
+        self.field_address_parts()
+            .fold(self.field_address.span, |span, address| {
+                span.to(address.span)
+            })
+    }
+
+    /// The bit positions of the value of the field, from the least to the most significant bit
+    pub fn field_bits(&self) -> impl Iterator<Item = u32> {
+        self.field_address_parts()
+            .flat_map(|address| address.start..=address.end)
+    }
+
+    /// The size of the value of the field, summed over all its bit ranges
+    #[must_use]
+    pub fn size_bits(&self) -> u64 {
+        self.field_address_parts()
+            .map(|address| address.len())
+            .sum()
+    }
+
     #[must_use]
     pub fn get_type_specifier_string(&self) -> String {
         match &self.field_conversion {
@@ -708,7 +985,14 @@ impl Enum {
         let mut next_discriminant = 0;
         self.variants.iter().map(move |variant| {
             if let Some(discriminant) = variant.value.specified_discriminant() {
-                next_discriminant = discriminant + 1;
+                next_discriminant = variant
+                    .value
+                    .value_ranges(discriminant)
+                    .iter()
+                    .map(|range| range.end)
+                    .max()
+                    .unwrap_or(discriminant)
+                    + 1;
                 (discriminant, variant)
             } else {
                 let discriminant = next_discriminant;
@@ -728,7 +1012,14 @@ impl Enum {
         let mut next_discriminant = 0;
         self.variants.iter_mut().map(move |variant| {
             if let Some(discriminant) = variant.value.specified_discriminant() {
-                next_discriminant = discriminant + 1;
+                next_discriminant = variant
+                    .value
+                    .value_ranges(discriminant)
+                    .iter()
+                    .map(|range| range.end)
+                    .max()
+                    .unwrap_or(discriminant)
+                    + 1;
                 (discriminant, variant)
             } else {
                 let discriminant = next_discriminant;
@@ -778,6 +1069,8 @@ pub enum EnumValue {
     UnspecifiedDefault,
     CatchAll(i128),
     UnspecifiedCatchAll,
+    /// The variant covers all values of the ranges and carries the raw value
+    Values(Vec<ValueRange>),
 }
 
 impl EnumValue {
@@ -791,10 +1084,29 @@ impl EnumValue {
         matches!(self, Self::CatchAll(_) | Self::UnspecifiedCatchAll)
     }
 
+    /// Returns `true` if the variant covers multiple values given as ranges.
+    #[must_use]
+    pub fn is_values(&self) -> bool {
+        matches!(self, Self::Values(_))
+    }
+
+    /// The discriminant if it's specified. For variants covering multiple values, this is the lowest value.
     pub fn specified_discriminant(&self) -> Option<i128> {
         match self {
             Self::Unspecified | Self::UnspecifiedDefault | Self::UnspecifiedCatchAll => None,
             Self::Specified(val) | EnumValue::Default(val) | EnumValue::CatchAll(val) => Some(*val),
+            Self::Values(ranges) => ranges.iter().map(|range| range.start).min(),
+        }
+    }
+
+    /// The values the variant covers, given its discriminant
+    pub fn value_ranges(&self, discriminant: i128) -> Vec<ValueRange> {
+        match self {
+            Self::Values(ranges) => ranges.clone(),
+            _ => vec![ValueRange {
+                start: discriminant,
+                end: discriminant,
+            }],
         }
     }
 
@@ -803,6 +1115,7 @@ impl EnumValue {
             EnumValue::Unspecified | EnumValue::Specified(_) => Self::Specified(num),
             EnumValue::Default(_) | EnumValue::UnspecifiedDefault => Self::Default(num),
             EnumValue::CatchAll(_) | EnumValue::UnspecifiedCatchAll => Self::CatchAll(num),
+            EnumValue::Values(ranges) => Self::Values(std::mem::take(ranges)),
         };
     }
 }
@@ -812,11 +1125,14 @@ pub struct Command {
     pub description: String,
     pub name: Spanned<Identifier<Operation>>,
     pub address: Spanned<i128>,
+    pub address_kind: AddressKind,
     pub allow_address_overlap: bool,
     pub repeat: Option<Repeat>,
 
     pub field_set_ref_in: Option<Spanned<IdentifierRef<Type>>>,
     pub field_set_ref_out: Option<Spanned<IdentifierRef<Type>>>,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
 
     pub short_properties_span: Span,
     pub properties_span: Option<Span>,
@@ -830,6 +1146,76 @@ pub struct Buffer {
     pub name: Spanned<Identifier<Operation>>,
     pub access: Option<Access>,
     pub address: Spanned<i128>,
+    pub address_kind: AddressKind,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// A large addressable region, like the array of an EEPROM or flash
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Memory {
+    pub description: String,
+    pub name: Spanned<Identifier<Operation>>,
+    pub access: Option<Access>,
+    /// The address of the first word of the memory
+    pub address: Spanned<i128>,
+    pub address_kind: AddressKind,
+    pub size_bytes: Spanned<u32>,
+    /// The amount of bytes of one address. Defaults to 1
+    pub word_size_bytes: Option<Spanned<u32>>,
+    /// Writes are split at the page boundaries. Not split if None
+    pub page_size_bytes: Option<Spanned<u32>>,
+    /// The amount of bytes writes must be aligned to. Defaults to the word size
+    pub write_granularity_bytes: Option<Spanned<u32>>,
+    /// The size of the blocks that are erased at once. The memory can't be erased if None
+    pub erase_size_bytes: Option<Spanned<u32>>,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+impl Memory {
+    pub fn word_size_bytes(&self) -> u32 {
+        self.word_size_bytes.map_or(1, |size| size.value)
+    }
+
+    pub fn write_granularity_bytes(&self) -> u32 {
+        self.write_granularity_bytes
+            .map_or(self.word_size_bytes(), |size| size.value)
+    }
+
+    /// The amount of addresses the memory takes up
+    pub fn size_words(&self) -> u32 {
+        self.size_bytes.value / self.word_size_bytes()
+    }
+}
+
+/// A data register that delivers a stream of records, like the output of a sensor FIFO
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Fifo {
+    pub description: String,
+    pub name: Spanned<Identifier<Operation>>,
+    /// The address of the data register the records are read from
+    pub address: Spanned<i128>,
+    pub address_kind: AddressKind,
+    /// The fieldset of one record
+    pub record_ref: Spanned<IdentifierRef<Type>>,
+    /// The register that holds the fill level. All records that are asked for are read if None
+    pub level_register: Option<Spanned<IdentifierRef<Operation>>>,
+    /// The field of the level register that holds the fill level
+    pub level_field: Option<Spanned<IdentifierRef<All>>>,
+    pub level_unit: Option<Spanned<LevelUnit>>,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
 
     pub short_properties_span: Span,
     pub properties_span: Option<Span>,
@@ -854,6 +1240,254 @@ pub struct Extern {
     pub span: Span,
 }
 
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Interface {
+    pub description: String,
+    pub name: Spanned<Identifier<Type>>,
+    pub bus: Option<Spanned<Bus>>,
+    /// The amount of bytes the address is sent with
+    pub address_size_bytes: Option<Spanned<u32>>,
+    pub address_byte_order: Option<ByteOrder>,
+    /// The bit in the address that is set when reading
+    pub read_bit: Option<Spanned<u32>>,
+    /// The bit in the address that is set when writing
+    pub write_bit: Option<Spanned<u32>>,
+    /// The bit in the address that is set when more than one byte is transferred
+    pub increment_bit: Option<Spanned<u32>>,
+    /// The amount of bytes that are skipped between the address and the data when reading
+    pub dummy_bytes: Option<Spanned<u32>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// A sequence of statements that is generated as a method on the block it's in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Routine {
+    pub description: String,
+    pub name: Spanned<Identifier<Operation>>,
+    pub statements: Vec<Statement>,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// A value made of fields in multiple registers that is generated as read and write methods on the block it's in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Composite {
+    pub description: String,
+    pub name: Spanned<Identifier<Operation>>,
+    pub base_type: Spanned<BaseType>,
+    /// The parts of the value, starting with the least significant part
+    pub parts: Vec<CompositePart>,
+    /// The register that latches the value. It's read first and written last
+    pub latch: Option<Spanned<IdentifierRef<Operation>>>,
+    /// The chip variants this object exists for. Empty if it exists for all variants
+    pub chip_variants: Vec<Spanned<String>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// A field of a register that makes up some of the bits of a composite value
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompositePart {
+    pub description: String,
+    /// The name of the register the field is in
+    pub name: Spanned<Identifier<Operation>>,
+    pub field: Option<Spanned<IdentifierRef<All>>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// Write the register. Fields that aren't given keep their reset value
+    Write(TargetStatement),
+    /// Read the register, change the given fields and write it back
+    Modify(TargetStatement),
+    /// Read the register until all given fields have the given value or the limit is reached
+    Poll(TargetStatement, PollLimit),
+    /// Dispatch the command with the given fields as input
+    Dispatch(TargetStatement),
+    /// Wait for the given duration
+    Delay {
+        duration: Spanned<u32>,
+        unit: TimeUnit,
+        /// Span of the whole statement
+        span: Span,
+    },
+    /// Read the register and return its value from the routine
+    Return(TargetStatement),
+}
+
+impl Statement {
+    /// The keyword of the statement as it's written in the DDSL
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Statement::Write(_) => "write",
+            Statement::Modify(_) => "modify",
+            Statement::Poll(..) => "poll",
+            Statement::Dispatch(_) => "dispatch",
+            Statement::Delay { .. } => "delay",
+            Statement::Return(_) => "return",
+        }
+    }
+
+    /// The span of the whole statement
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Write(target_statement)
+            | Statement::Modify(target_statement)
+            | Statement::Poll(target_statement, _)
+            | Statement::Dispatch(target_statement)
+            | Statement::Return(target_statement) => target_statement.span,
+            Statement::Delay { span, .. } => *span,
+        }
+    }
+
+    /// Get the statement that operates on a register or command. Only returns None for delays
+    pub fn as_target_statement(&self) -> Option<&TargetStatement> {
+        match self {
+            Statement::Write(target_statement)
+            | Statement::Modify(target_statement)
+            | Statement::Poll(target_statement, _)
+            | Statement::Dispatch(target_statement)
+            | Statement::Return(target_statement) => Some(target_statement),
+            Statement::Delay { .. } => None,
+        }
+    }
+}
+
+/// A statement that operates on a register or command
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetStatement {
+    pub target: Spanned<IdentifierRef<Operation>>,
+    pub fields: Vec<FieldAssignment>,
+    /// Span of the whole statement
+    pub span: Span,
+}
+
+/// How long a poll keeps reading before it gives up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollLimit {
+    /// The maximum amount of reads. Always at least 1
+    pub max_attempts: u32,
+    /// The time to wait between two reads
+    pub interval: u32,
+    pub interval_unit: TimeUnit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldAssignment {
+    pub field: Spanned<IdentifierRef<All>>,
+    pub value: Spanned<FieldValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// A number for integer fields. Bool fields use 0 and 1
+    Number(i128),
+    /// The name of a variant of the enum the field is converted to
+    Variant(IdentifierRef<All>),
+}
+
+/// The states of a device and the transitions between them.
+/// This is generated as a typestate wrapper around the device.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateMachine {
+    pub description: String,
+    pub name: Spanned<Identifier<Type>>,
+    /// The state the device is in when the wrapper is created
+    pub initial: Option<Spanned<IdentifierRef<All>>>,
+    pub states: Vec<State>,
+    pub transitions: Vec<Transition>,
+    pub allows: Vec<Allow>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct State {
+    pub description: String,
+    pub name: Spanned<Identifier<All>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// A transition between states that runs a routine or its own statements
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Transition {
+    pub description: String,
+    pub name: Spanned<Identifier<Operation>>,
+    pub from: Vec<Spanned<IdentifierRef<All>>>,
+    pub to: Option<Spanned<IdentifierRef<All>>>,
+    /// The routine that is run for the transition
+    pub routine: Option<Spanned<IdentifierRef<Operation>>>,
+    /// The statements that are run for the transition if there's no routine
+    pub statements: Vec<Statement>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+/// The states an operation may be used in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Allow {
+    pub description: String,
+    /// The name of the operation
+    pub name: Spanned<Identifier<Operation>>,
+    pub states: Vec<Spanned<IdentifierRef<All>>>,
+
+    pub short_properties_span: Span,
+    pub properties_span: Option<Span>,
+    /// Span of the whole object
+    pub span: Span,
+}
+
+impl Unique for State {
+    type Metadata = UniqueId;
+
+    fn id(&self) -> UniqueId {
+        unreachable!()
+    }
+
+    fn id_with(&self, parent: Self::Metadata) -> UniqueId {
+        UniqueId::Variant {
+            parent_id: Box::new(parent),
+            variant_name: self
+                .name
+                .value
+                .clone()
+                .to_runtime_type()
+                .with_span(self.name.span),
+        }
+    }
+
+    fn has_id(&self, _id: &UniqueId) -> bool {
+        unreachable!()
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum UniqueId {
     Object {
@@ -969,10 +1603,17 @@ impl_unique_object!(Device);
 impl_unique_object!(Register);
 impl_unique_object!(Command);
 impl_unique_object!(Buffer);
+impl_unique_object!(Memory);
+impl_unique_object!(Fifo);
 impl_unique_object!(Block);
 impl_unique_object!(Enum);
 impl_unique_object!(FieldSet);
 impl_unique_object!(Extern);
+impl_unique_object!(Interface);
+impl_unique_object!(Routine);
+impl_unique_object!(Composite);
+impl_unique_object!(StateMachine);
+impl_unique_object!(Transition);
 
 impl Unique for Field {
     type Metadata = UniqueId;
@@ -1032,9 +1673,15 @@ impl Unique for Object {
             Object::Register(val) => val.id(),
             Object::Command(val) => val.id(),
             Object::Buffer(val) => val.id(),
+            Object::Memory(val) => val.id(),
+            Object::Fifo(val) => val.id(),
             Object::FieldSet(val) => val.id(),
             Object::Enum(val) => val.id(),
             Object::Extern(val) => val.id(),
+            Object::Interface(val) => val.id(),
+            Object::Routine(val) => val.id(),
+            Object::Composite(val) => val.id(),
+            Object::StateMachine(val) => val.id(),
             // Special
             Object::Field(_) => unimplemented!(),
         }
@@ -1051,9 +1698,15 @@ impl Unique for Object {
             Object::Register(val) => val.has_id(id),
             Object::Command(val) => val.has_id(id),
             Object::Buffer(val) => val.has_id(id),
+            Object::Memory(val) => val.has_id(id),
+            Object::Fifo(val) => val.has_id(id),
             Object::FieldSet(val) => val.has_id(id),
             Object::Enum(val) => val.has_id(id),
             Object::Extern(val) => val.has_id(id),
+            Object::Interface(val) => val.has_id(id),
+            Object::Routine(val) => val.has_id(id),
+            Object::Composite(val) => val.has_id(id),
+            Object::StateMachine(val) => val.has_id(id),
             // Special
             Object::Field(_) => unimplemented!(),
         }
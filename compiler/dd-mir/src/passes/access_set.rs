@@ -79,11 +79,30 @@ fn set_access(
                     });
                 }
             }
+            Object::Memory(memory) => {
+                memory.access = memory.access.or(default_access);
+
+                if memory.access.is_none() {
+                    memory.access = Some(Access::RW);
+                    diagnostics.add(UnspecifiedAccess {
+                        object_name: memory.name.span,
+                        short_property: false,
+                        properties_span: memory.properties_span,
+                    });
+                }
+            }
 
             Object::Field(_) => {
                 // Intentionally left empty as fields are done inline in the fieldset case
             }
-            Object::Command(_) | Object::Enum(_) | Object::Extern(_) => {
+            Object::Command(_)
+            | Object::Enum(_)
+            | Object::Extern(_)
+            | Object::Interface(_)
+            | Object::Routine(_)
+            | Object::Composite(_)
+            | Object::Fifo(_)
+            | Object::StateMachine(_) => {
                 // Intentionally left empty as they don't have children we care about and they don't carry an access specifier themselves
             }
         }
@@ -19,6 +19,8 @@ impl Pass for AddressTypesBigEnough {
         Assumption::NamesUnique,
         Assumption::RepeatEnumRefValid,
         Assumption::RepeatMathChecked,
+        Assumption::BlockRefsValid,
+        Assumption::AddressesAssigned,
     ];
     const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
 
@@ -37,7 +39,7 @@ impl Pass for AddressTypesBigEnough {
                 device.device_config.register_address_type.as_ref(),
                 manifest,
                 device,
-                |o| matches!(o, Object::Block(_) | Object::Register(_)),
+                |o| matches!(o, Object::Block(_) | Object::Register(_) | Object::Fifo(_)),
                 diagnostics,
                 &mut removals,
             );
@@ -57,6 +59,14 @@ impl Pass for AddressTypesBigEnough {
                 diagnostics,
                 &mut removals,
             );
+            check_device(
+                device.device_config.memory_address_type.as_ref(),
+                manifest,
+                device,
+                |o| matches!(o, Object::Block(_) | Object::Memory(_)),
+                diagnostics,
+                &mut removals,
+            );
         }
 
         Ok(removals)
@@ -75,26 +85,33 @@ fn check_device(
         return;
     };
 
-    let Some(((min_address, min_obj), (max_address, _))) =
+    let Some(((min_address, min_obj), (max_address, max_obj))) =
         find_min_max_addresses(manifest, device, filter)
     else {
         return;
     };
 
-    if min_address < address_type.min_value() || max_address > address_type.max_value() {
-        diagnostics.add(AddressOutOfRange {
-            object: min_obj.name_span(),
-            address: min_obj
-                .address()
-                .expect("All objects here should have addresses")
-                .span,
-            address_value_min: min_address,
-            address_value_max: max_address,
-            address_type_config: address_type.span,
-            address_type: address_type.value,
-        });
-        removals.insert(device.id());
-    }
+    // Point at the object that's out of range. For a block instance that's the instance itself
+    let out_of_range_obj = if min_address < address_type.min_value() {
+        min_obj
+    } else if max_address > address_type.max_value() {
+        max_obj
+    } else {
+        return;
+    };
+
+    diagnostics.add(AddressOutOfRange {
+        object: out_of_range_obj.name_span(),
+        address: out_of_range_obj
+            .address()
+            .expect("All objects here should have addresses")
+            .span,
+        address_value_min: min_address,
+        address_value_max: max_address,
+        address_type_config: address_type.span,
+        address_type: address_type.value,
+    });
+    removals.insert(device.id());
 }
 
 #[cfg(test)]
@@ -23,13 +23,15 @@ impl Pass for AddressTypesSpecified {
         let mut register_removals = HashSet::new();
         let mut command_removals = HashSet::new();
         let mut buffer_removals = HashSet::new();
+        let mut memory_removals = HashSet::new();
 
         for (object, config) in manifest.iter_objects_with_config() {
             match object {
-                Object::Register(r) if config.register_address_type.is_none() => {
+                // Fifos are read with the register interface
+                Object::Register(_) | Object::Fifo(_) if config.register_address_type.is_none() => {
                     let device = config.owner.as_ref().ok_or_else(|| {
                     DynError::new(
-                        format!("found register {}, but the config that applies to it doesn't have an owner", r.name.original()),
+                        format!("found register {}, but the config that applies to it doesn't have an owner", object.name().original()),
                     )
                 })?;
                     if register_removals.contains(device) {
@@ -99,6 +101,30 @@ impl Pass for AddressTypesSpecified {
                     });
                     buffer_removals.insert(device.clone());
                 }
+                Object::Memory(m) if config.memory_address_type.is_none() => {
+                    let device = config.owner.as_ref().ok_or_else(|| {
+                    DynError::new(format!(
+                        "found memory {}, but the config that applies to it doesn't have an owner",
+                        m.name.original()
+                    ))
+                })?;
+                    if memory_removals.contains(device) {
+                        continue;
+                    }
+
+                    let device_properties_span =
+                        search_object(manifest, &device.identifier().take_ref())
+                            .ok_or_else(|| DynError::new("config owner doesn't exist for memory"))?
+                            .properties_span();
+
+                    diagnostics.add(AddressTypeUndefined {
+                        object_name: object.name_span(),
+                        device: device.span(),
+                        properties_span: device_properties_span,
+                        object_type: "memory",
+                    });
+                    memory_removals.insert(device.clone());
+                }
                 _ => {}
             }
         }
@@ -107,6 +133,7 @@ impl Pass for AddressTypesSpecified {
         removals.extend(register_removals);
         removals.extend(command_removals);
         removals.extend(buffer_removals);
+        removals.extend(memory_removals);
         Ok(removals)
     }
 }
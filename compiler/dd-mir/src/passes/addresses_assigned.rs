@@ -0,0 +1,257 @@
+use std::collections::{HashMap, HashSet};
+
+use device_driver_common::{
+    span::Spanned,
+    specifiers::{AddressMode, Repeat, RepeatSource},
+};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{AutoAddressWithoutAddressMode, MissingAddress},
+};
+
+use crate::{
+    inner_repeat_offsets,
+    model::{
+        AddressKind, DeviceConfig, Manifest, Object, Unique, UniqueId, chip_variants_exclusive,
+    },
+    passes::{Assumption, Pass},
+    search_object,
+};
+
+/// Assigns the addresses of objects with an automatic address (`auto` or `_`, or left out in a block with `default-address: auto`).
+/// The object gets the first address after the previous object of the same address space in the same block.
+/// The first object of a block starts at 0.
+/// Objects of mutually exclusive chip variants that follow each other start at the same address,
+/// and the next object is placed after the largest of them.
+pub struct AddressesAssigned;
+
+impl Pass for AddressesAssigned {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::NamesUnique,
+        Assumption::FieldsetRefsValid,
+        Assumption::RepeatEnumRefValid,
+        Assumption::RepeatMathChecked,
+        Assumption::ChipVariantsPropagated,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[Assumption::AddressesAssigned];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+        // Keyed on the index path of the object, since objects of exclusive chip variants can share their name
+        let mut assigned_addresses = HashMap::new();
+
+        for (index, object) in manifest.objects.iter().enumerate() {
+            if let Object::Device(device) = object {
+                assign_addresses(
+                    manifest,
+                    &device.objects,
+                    &mut vec![index],
+                    &manifest.config.override_with(&device.device_config),
+                    device.default_auto_address,
+                    &mut assigned_addresses,
+                    diagnostics,
+                    &mut removals,
+                );
+            }
+        }
+
+        for (path, assigned_address) in assigned_addresses {
+            let (first, rest) = path.split_first().expect("paths are never empty");
+            let object = rest
+                .iter()
+                .fold(&mut manifest.objects[*first], |object, index| {
+                    &mut object.child_objects_mut()[*index]
+                });
+
+            if let Some(address) = object.address_mut() {
+                address.value = assigned_address;
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AddressSpace {
+    Register,
+    Command,
+    Buffer,
+    Memory,
+}
+
+/// Objects in a row that are mutually exclusive through their chip variants, so they share their start address
+struct ExclusiveRun<'o> {
+    start: i128,
+    end: i128,
+    chip_variants: Vec<&'o [Spanned<String>]>,
+}
+
+#[expect(clippy::too_many_arguments, reason = "the state of the walk")]
+fn assign_addresses<'o>(
+    manifest: &Manifest,
+    objects: &'o [Object],
+    path: &mut Vec<usize>,
+    config: &DeviceConfig,
+    default_auto_address: bool,
+    assigned_addresses: &mut HashMap<Vec<usize>, i128>,
+    diagnostics: &mut Diagnostics,
+    removals: &mut HashSet<UniqueId>,
+) {
+    // The run of objects that was placed last in every address space. The next object goes after its end
+    let mut runs = HashMap::<AddressSpace, ExclusiveRun<'o>>::new();
+
+    for (index, object) in objects.iter().enumerate() {
+        path.push(index);
+
+        let address_space = match object {
+            Object::Block(block) => {
+                assign_addresses(
+                    manifest,
+                    &block.objects,
+                    path,
+                    config,
+                    default_auto_address || block.default_auto_address,
+                    assigned_addresses,
+                    diagnostics,
+                    removals,
+                );
+                path.pop();
+                continue;
+            }
+            Object::Register(_) | Object::Fifo(_) => AddressSpace::Register,
+            Object::Command(_) => AddressSpace::Command,
+            Object::Buffer(_) => AddressSpace::Buffer,
+            Object::Memory(_) => AddressSpace::Memory,
+            _ => {
+                path.pop();
+                continue;
+            }
+        };
+
+        let (Some(address), Some(address_kind)) = (object.address(), object.address_kind()) else {
+            path.pop();
+            continue;
+        };
+        let chip_variants = object.chip_variants().map_or(&[][..], Vec::as_slice);
+
+        // The object joins the run when it's exclusive with all objects in it
+        let run = runs.get(&address_space);
+        let joins_run = run.is_some_and(|run| {
+            run.chip_variants
+                .iter()
+                .all(|run_chip_variants| chip_variants_exclusive(run_chip_variants, chip_variants))
+        });
+
+        let address = match address_kind {
+            AddressKind::Number => address.value,
+            AddressKind::Missing if !default_auto_address => {
+                diagnostics.add(MissingAddress {
+                    object_name: object.name_span(),
+                    properties_span: object.properties_span(),
+                });
+                removals.insert(object.id());
+                path.pop();
+                continue;
+            }
+            AddressKind::Auto | AddressKind::Missing => {
+                if address_space == AddressSpace::Register && config.register_address_mode.is_none()
+                {
+                    diagnostics.add(AutoAddressWithoutAddressMode {
+                        address: match address_kind {
+                            AddressKind::Missing => object.name_span(),
+                            _ => address.span,
+                        },
+                    });
+                    removals.insert(object.id());
+                    path.pop();
+                    continue;
+                }
+
+                let address = match run {
+                    Some(run) if joins_run => run.start,
+                    Some(run) => run.end,
+                    None => 0,
+                };
+                assigned_addresses.insert(path.clone(), address);
+                address
+            }
+        };
+
+        path.pop();
+
+        let Some(size) = object_size(manifest, object, config) else {
+            // Without a size, the next object can't be placed automatically
+            runs.remove(&address_space);
+            continue;
+        };
+
+        let max_repeat_offset = object
+            .repeat()
+            .map(|repeat| max_repeat_offset(repeat, manifest))
+            .unwrap_or_default();
+        let end = address + max_repeat_offset + size;
+
+        match runs.get_mut(&address_space) {
+            Some(run) if joins_run => {
+                run.end = run.end.max(end);
+                run.chip_variants.push(chip_variants);
+            }
+            _ => {
+                runs.insert(
+                    address_space,
+                    ExclusiveRun {
+                        start: address,
+                        end,
+                        chip_variants: vec![chip_variants],
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// The amount of addresses the object takes up.
+/// Returns None for registers when the register address mode is unknown.
+fn object_size(manifest: &Manifest, object: &Object, config: &DeviceConfig) -> Option<i128> {
+    match object {
+        Object::Register(register) => match config.register_address_mode.map(|m| m.value)? {
+            AddressMode::Mapped => search_object(manifest, &register.field_set_ref)
+                .and_then(Object::as_field_set)
+                .map(|field_set| i128::from(field_set.size_bytes.value)),
+            AddressMode::Indexed => Some(1),
+        },
+        Object::Memory(memory) => Some(i128::from(memory.size_words())),
+        _ => Some(1),
+    }
+}
+
+/// The biggest offset any index of the repeat adds to the address
+fn max_repeat_offset(repeat: &Repeat, manifest: &Manifest) -> i128 {
+    let stride = repeat.stride.value;
+
+    let outer_max = match &repeat.source.value {
+        RepeatSource::Count(count) => ((i128::from(count.get()) - 1) * stride).max(0),
+        RepeatSource::Enum(enum_name) => search_object(manifest, enum_name)
+            .and_then(Object::as_enum)
+            .into_iter()
+            .flat_map(|enum_value| enum_value.iter_variants_with_discriminant())
+            .map(|(discriminant, _)| discriminant * stride)
+            .max()
+            .unwrap_or_default(),
+        RepeatSource::Offsets(offsets) => offsets
+            .iter()
+            .map(|offset| offset.value * stride)
+            .max()
+            .unwrap_or_default(),
+    };
+    let inner_max = inner_repeat_offsets(repeat, manifest)
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+
+    outer_max + inner_max
+}
@@ -6,7 +6,8 @@ use device_driver_common::{
 };
 
 use crate::{
-    model::{Device, DeviceConfig, Manifest, Object, Unique, UniqueId},
+    inner_repeat_offsets,
+    model::{Device, DeviceConfig, Manifest, Object, Unique, UniqueId, chip_variants_exclusive},
     passes::{Assumption, Pass},
     search_object,
 };
@@ -21,6 +22,9 @@ impl Pass for AddressesNonOverlapping {
         Assumption::RepeatEnumRefValid,
         Assumption::NamesUnique,
         Assumption::FieldsetRefsValid,
+        Assumption::ChipVariantsPropagated,
+        Assumption::AddressesAssigned,
+        Assumption::BlockRefsValid,
     ];
     const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
 
@@ -30,7 +34,7 @@ impl Pass for AddressesNonOverlapping {
     ) -> Result<HashSet<UniqueId>, DynError> {
         for (device, config) in manifest.iter_devices_with_config() {
             let register_addresses = find_object_addresses(manifest, device, &config, |o| {
-                matches!(o, Object::Block(_) | Object::Register(_))
+                matches!(o, Object::Block(_) | Object::Register(_) | Object::Fifo(_))
             })
             .with_message(|| "finding register object addresses")?;
             check_for_overlap(&register_addresses, diagnostics);
@@ -44,6 +48,11 @@ impl Pass for AddressesNonOverlapping {
             })
             .with_message(|| "finding buffer object addresses")?;
             check_for_overlap(&buffer_addresses, diagnostics);
+            let memory_addresses = find_object_addresses(manifest, device, &config, |o| {
+                matches!(o, Object::Block(_) | Object::Memory(_))
+            })
+            .with_message(|| "finding memory object addresses")?;
+            check_for_overlap(&memory_addresses, diagnostics);
         }
 
         Ok(Default::default())
@@ -81,6 +90,7 @@ fn check_for_overlap(addresses: &[ObjectAddress], diagnostics: &mut Diagnostics)
                 check_address_start,
                 check_address_end,
             ) && (!address.allow_overlap || !check_address.allow_overlap)
+                && !chip_variants_exclusive(&address.chip_variants, &check_address.chip_variants)
             {
                 diagnostics.add(AddressOverlap {
                     address: overlap_point,
@@ -88,44 +98,59 @@ fn check_for_overlap(addresses: &[ObjectAddress], diagnostics: &mut Diagnostics)
                     object_1_address: address.address.span,
                     object_1_size: address.size.span,
                     repeat_offset_1: address.repeat_offset,
+                    instance_1: address.instance,
                     object_2: check_address.id.span(),
                     object_2_address: check_address.address.span,
                     object_2_size: check_address.size.span,
                     repeat_offset_2: check_address.repeat_offset,
+                    instance_2: check_address.instance,
                 });
             }
         }
     }
 }
 
-struct ObjectAddress {
-    id: UniqueId,
+pub(super) struct ObjectAddress {
+    pub(super) id: UniqueId,
     // Address including repeat offset
-    address: Spanned<i128>,
+    pub(super) address: Spanned<i128>,
     size: Spanned<u32>,
-    repeat_offset: Option<i128>,
+    pub(super) repeat_offset: Option<i128>,
     allow_overlap: bool,
+    /// Objects of mutually exclusive chip variants never overlap
+    chip_variants: Vec<Spanned<String>>,
+    /// The block instance that places the object at its offset, if any
+    pub(super) instance: Option<Span>,
 }
 
-fn find_object_addresses<'m>(
+pub(super) fn find_object_addresses<'m>(
     manifest: &'m Manifest,
     device: &'m Device,
     config: &DeviceConfig,
     filter: impl Fn(&'m Object) -> bool,
 ) -> Result<Vec<ObjectAddress>, DynError> {
-    let mut object_addresses = Vec::new();
-
-    let mut children_left = vec![device.objects.len()];
-    let mut address_offsets = vec![0];
+    collect_object_addresses(manifest, device, config, &device.objects, 0, None, &filter)
+}
 
-    for object in device.iter_objects() {
-        while children_left.last() == Some(&0) {
-            children_left.pop();
-            address_offsets.pop();
-        }
+/// The block instance the objects of another block are placed by
+#[derive(Clone, Copy)]
+struct Instance<'m> {
+    span: Span,
+    chip_variants: Option<&'m Vec<Spanned<String>>>,
+}
 
-        *children_left.last_mut().unwrap() -= 1;
+fn collect_object_addresses<'m>(
+    manifest: &'m Manifest,
+    device: &'m Device,
+    config: &DeviceConfig,
+    objects: &'m [Object],
+    address_offset: i128,
+    instance: Option<Instance<'m>>,
+    filter: &impl Fn(&'m Object) -> bool,
+) -> Result<Vec<ObjectAddress>, DynError> {
+    let mut object_addresses = Vec::new();
 
+    for object in objects {
         if !filter(object) {
             continue;
         }
@@ -134,7 +159,11 @@ fn find_object_addresses<'m>(
         if let Some(address) = object.address()
             && matches!(
                 object,
-                Object::Register(_) | Object::Command(_) | Object::Buffer(_)
+                Object::Register(_)
+                    | Object::Command(_)
+                    | Object::Buffer(_)
+                    | Object::Memory(_)
+                    | Object::Fifo(_)
             )
         {
             let size = if matches!(
@@ -152,35 +181,50 @@ fn find_object_addresses<'m>(
                 };
 
                 fs.size_bytes
+            } else if let Object::Memory(memory) = object {
+                memory.size_words().with_span(memory.size_bytes.span)
             } else {
                 1.with_dummy_span()
             };
 
+            // An instance only exists for its own chip variants
+            let chip_variants = instance
+                .and_then(|instance| instance.chip_variants)
+                .filter(|variants| !variants.is_empty())
+                .or(object.chip_variants())
+                .cloned()
+                .unwrap_or_default();
+
             let repeat = object.repeat().cloned().unwrap_or(Repeat {
                 source: RepeatSource::Count(NonZero::new(1).unwrap()).with_dummy_span(),
                 stride: 0.with_dummy_span(),
+                inner: None,
                 span: Span::empty(),
             });
 
-            let total_address_offsets = address_offsets.iter().sum::<i128>();
-
             // If the stride is 0, everything overlaps. We don't need infinite diagnostics about that,
             // so limit the elements we look at. Otherwise we could OOM
             let max_elements = if repeat.stride == 0 { 5 } else { usize::MAX };
+            let inner_offsets = inner_repeat_offsets(&repeat, manifest);
 
             match repeat.source.value {
                 RepeatSource::Count(count) => {
                     for index in (0..i128::from(count.get())).take(max_elements) {
                         let repeat_offset = index * repeat.stride.value;
-                        let address_value = total_address_offsets + address.value + repeat_offset;
-
-                        object_addresses.push(ObjectAddress {
-                            id: object.id(),
-                            address: address_value.with_span(address.span),
-                            size,
-                            repeat_offset: object.repeat().map(|_| repeat_offset),
-                            allow_overlap: object.allow_address_overlap(),
-                        });
+                        for inner_offset in &inner_offsets {
+                            let repeat_offset = repeat_offset + inner_offset;
+                            let address_value = address_offset + address.value + repeat_offset;
+
+                            object_addresses.push(ObjectAddress {
+                                id: object.id(),
+                                address: address_value.with_span(address.span),
+                                size,
+                                repeat_offset: object.repeat().map(|_| repeat_offset),
+                                allow_overlap: object.allow_address_overlap(),
+                                chip_variants: chip_variants.clone(),
+                                instance: instance.map(|instance| instance.span),
+                            });
+                        }
                     }
                 }
                 RepeatSource::Enum(enum_name) => {
@@ -194,28 +238,90 @@ fn find_object_addresses<'m>(
                         .take(max_elements)
                     {
                         let repeat_offset = discriminant * repeat.stride.value;
-                        let address_value = total_address_offsets + address.value + repeat_offset;
-
-                        object_addresses.push(ObjectAddress {
-                            id: object.id(),
-                            address: address_value.with_span(address.span),
-                            size,
-                            repeat_offset: Some(repeat_offset),
-                            allow_overlap: object.allow_address_overlap(),
-                        });
+                        for inner_offset in &inner_offsets {
+                            let repeat_offset = repeat_offset + inner_offset;
+                            let address_value = address_offset + address.value + repeat_offset;
+
+                            object_addresses.push(ObjectAddress {
+                                id: object.id(),
+                                address: address_value.with_span(address.span),
+                                size,
+                                repeat_offset: Some(repeat_offset),
+                                allow_overlap: object.allow_address_overlap(),
+                                chip_variants: chip_variants.clone(),
+                                instance: instance.map(|instance| instance.span),
+                            });
+                        }
+                    }
+                }
+                RepeatSource::Offsets(offsets) => {
+                    for offset in offsets.iter().take(max_elements) {
+                        let repeat_offset = offset.value * repeat.stride.value;
+                        for inner_offset in &inner_offsets {
+                            let repeat_offset = repeat_offset + inner_offset;
+                            let address_value = address_offset + address.value + repeat_offset;
+
+                            object_addresses.push(ObjectAddress {
+                                id: object.id(),
+                                address: address_value.with_span(address.span),
+                                size,
+                                repeat_offset: Some(repeat_offset),
+                                allow_overlap: object.allow_address_overlap(),
+                                chip_variants: chip_variants.clone(),
+                                instance: instance.map(|instance| instance.span),
+                            });
+                        }
                     }
                 }
             }
         }
 
         match object {
-            Object::Device(d) => {
-                address_offsets.push(0);
-                children_left.push(d.objects.len());
-            }
+            Object::Device(d) => object_addresses.extend(collect_object_addresses(
+                manifest,
+                device,
+                config,
+                &d.objects,
+                address_offset,
+                instance,
+                filter,
+            )?),
             Object::Block(b) => {
-                address_offsets.push(b.address_offset.value);
-                children_left.push(b.objects.len());
+                let address_offset = address_offset + b.address_offset.value;
+
+                // An instance places the objects of the referenced block at its own offset
+                let (objects, instance) = match &b.block_ref {
+                    Some(block_ref) => {
+                        let Some(Object::Block(target)) = device
+                            .iter_objects()
+                            .find(|o| block_ref.is_ref_to(o.name()))
+                        else {
+                            return Err(DynError::new(format!(
+                                "block ref `{}` is not a block, but it was safe to assume it would be",
+                                block_ref.original()
+                            )));
+                        };
+
+                        (
+                            &target.objects,
+                            instance.or(Some(Instance {
+                                span: b.name.span,
+                                chip_variants: Some(&b.chip_variants),
+                            })),
+                        )
+                    }
+                    None => (&b.objects, instance),
+                };
+
+                object_addresses.extend(collect_object_addresses(
+                    manifest,
+                    device,
+                    config,
+                    objects,
+                    address_offset,
+                    instance,
+                    filter,
+                )?);
             }
             _ => (),
         }
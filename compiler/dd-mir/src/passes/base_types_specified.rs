@@ -36,7 +36,7 @@ impl Pass for BaseTypesSpecified {
             if let Some(field_set) = object.as_field_set_mut() {
                 for field in &mut field_set.fields {
                     loop {
-                        let size_bits = field.field_address.len();
+                        let size_bits = field.size_bits();
                         field.base_type.value = match field.base_type.value {
                             BaseType::Unspecified => {
                                 match field.field_conversion.as_ref().and_then(|conversion| {
@@ -62,13 +62,14 @@ impl Pass for BaseTypesSpecified {
                                     BaseType::FixedSize(integer)
                                 } else {
                                     diagnostics.add(IntegerFieldSizeTooBig {
-                                        field_address: field.field_address.span,
+                                        field_address: field.field_address_span(),
                                         size_bits,
                                         base_type: field.base_type.span.or(field.name.span),
                                         field_set: field_set.name.span,
                                     });
                                     // Fix the size for now so we can continue using this field later
                                     field.field_address.end = field.field_address.start + 63;
+                                    field.split_field_address.clear();
                                     continue;
                                 }
                             }
@@ -77,17 +78,19 @@ impl Pass for BaseTypesSpecified {
                                     BaseType::FixedSize(integer)
                                 } else {
                                     diagnostics.add(IntegerFieldSizeTooBig {
-                                        field_address: field.field_address.span,
+                                        field_address: field.field_address_span(),
                                         size_bits,
                                         base_type: field.base_type.span.or(field.name.span),
                                         field_set: field_set.name.span,
                                     });
                                     // Fix the size for now so we can continue using this field later
                                     field.field_address.end = field.field_address.start + 64;
+                                    field.split_field_address.clear();
                                     continue;
                                 }
                             }
                             BaseType::FixedSize(_) => break,
+                            BaseType::Bytes | BaseType::Ascii => break,
                         }
                     }
                 }
@@ -9,10 +9,14 @@ use crate::{
 };
 use device_driver_diagnostics::{
     Diagnostics, DynError,
-    errors::{FieldAddressExceedsFieldsetSize, FieldAddressNegative, OverlappingFields},
+    errors::{
+        ByteArrayFieldNotByteAligned, FieldAddressExceedsFieldsetSize, FieldAddressNegative,
+        InvalidSplitField, OverlappingFields,
+    },
 };
 
-/// Validate that the bit ranges of fields fall within the max size and don't have overlap if they're not allowed
+/// Validate that the bit ranges of fields fall within the max size and don't have overlap if they're not allowed.
+/// The bit ranges of a split field may never overlap each other.
 pub struct BitRangesValidated;
 
 impl Pass for BitRangesValidated {
@@ -32,6 +36,8 @@ impl Pass for BitRangesValidated {
         for object in manifest.iter_objects() {
             if let Some(field_set) = object.as_field_set() {
                 validate_len(field_set, manifest, diagnostics, &mut removals);
+                validate_split(field_set, diagnostics, &mut removals);
+                validate_byte_alignment(field_set, diagnostics, &mut removals);
                 if !field_set.allow_bit_overlap {
                     validate_overlap(field_set, manifest, diagnostics);
                 }
@@ -49,36 +55,116 @@ fn validate_len(
     removals: &mut HashSet<UniqueId>,
 ) {
     for field in &field_set.fields {
-        let field_len = field.field_address.len();
-
-        if field_len == 0 {
-            panic!("A zero-sized field can't be specified");
-        }
-
         let (offset_iter, repeated) = get_repeat_iter(manifest, field);
 
         let max_repeat_offset = offset_iter.iter().max().unwrap();
         let min_repeat_offset = offset_iter.iter().min().unwrap();
 
-        let max_field_end = i128::from(field.field_address.end) + max_repeat_offset;
-        let min_field_start = i128::from(field.field_address.start) + min_repeat_offset;
+        for field_address in field.field_address_parts() {
+            if field_address.len() == 0 {
+                panic!("A zero-sized field can't be specified");
+            }
+
+            let max_field_end = i128::from(field_address.end) + max_repeat_offset;
+            let min_field_start = i128::from(field_address.start) + min_repeat_offset;
+
+            if max_field_end >= i128::from(field_set.size_bits()) {
+                diagnostics.add(FieldAddressExceedsFieldsetSize {
+                    address: field_address.span,
+                    max_field_end,
+                    repeat_offset: repeated.then_some(*max_repeat_offset),
+                    fieldset_size_bits: field_set.size_bits(),
+                    fieldset_size_span: field_set.size_bytes.span,
+                });
+                removals.insert(field.id_with(field_set.id()));
+            }
 
-        if max_field_end >= i128::from(field_set.size_bits()) {
-            diagnostics.add(FieldAddressExceedsFieldsetSize {
-                address: field.field_address.span,
-                max_field_end,
-                repeat_offset: repeated.then_some(*max_repeat_offset),
-                fieldset_size_bits: field_set.size_bits(),
-                fieldset_size_span: field_set.size_bytes.span,
+            if min_field_start < 0 {
+                diagnostics.add(FieldAddressNegative {
+                    address: field_address.span,
+                    min_field_start,
+                    repeat_offset: repeated.then_some(*min_repeat_offset),
+                    field_set_context: field_set.name.span,
+                });
+                removals.insert(field.id_with(field_set.id()));
+            }
+        }
+    }
+}
+
+fn validate_split(
+    field_set: &FieldSet,
+    diagnostics: &mut Diagnostics,
+    removals: &mut HashSet<UniqueId>,
+) {
+    for field in &field_set.fields {
+        let Some(first_split) = field.split_field_address.first() else {
+            continue;
+        };
+
+        if field.base_type.is_byte_array() {
+            diagnostics.add(InvalidSplitField {
+                address: first_split.span,
+                context: Some(field.base_type.span),
+                reason: "byte array fields can't be split over multiple bit ranges".into(),
             });
             removals.insert(field.id_with(field_set.id()));
+            continue;
+        }
+
+        let parts = field.field_address_parts().collect::<Vec<_>>();
+        for (i, part) in parts.iter().enumerate() {
+            if let Some(overlapping) = parts
+                .iter()
+                .skip(i + 1)
+                .find(|second_part| ranges_overlap(part, 0, second_part, 0))
+            {
+                diagnostics.add(InvalidSplitField {
+                    address: overlapping.span,
+                    context: Some(part.span),
+                    reason: "the bit ranges of a split field can't overlap".into(),
+                });
+                removals.insert(field.id_with(field_set.id()));
+                break;
+            }
+        }
+    }
+}
+
+fn validate_byte_alignment(
+    field_set: &FieldSet,
+    diagnostics: &mut Diagnostics,
+    removals: &mut HashSet<UniqueId>,
+) {
+    for field in &field_set.fields {
+        // Split byte arrays are reported in `validate_split`
+        if !field.base_type.is_byte_array() || !field.split_field_address.is_empty() {
+            continue;
         }
 
-        if min_field_start < 0 {
-            diagnostics.add(FieldAddressNegative {
+        // Enum repeats are multiplied with the discriminant, so the stride alone decides the alignment.
+        // Offset lists give every offset explicitly, so all of them must be aligned.
+        let misaligned_stride =
+            field
+                .repeat
+                .as_ref()
+                .and_then(|repeat| match &repeat.source.value {
+                    RepeatSource::Offsets(offsets) => {
+                        offsets.iter().find(|offset| offset.value % 8 != 0).copied()
+                    }
+                    _ => Some(repeat.stride).filter(|stride| stride.value % 8 != 0),
+                });
+
+        if field.field_address.start % 8 != 0
+            || field.field_address.len() % 8 != 0
+            || misaligned_stride.is_some()
+        {
+            diagnostics.add(ByteArrayFieldNotByteAligned {
+                base_type: field.base_type.span,
                 address: field.field_address.span,
-                min_field_start,
-                repeat_offset: repeated.then_some(*min_repeat_offset),
+                address_start: field.field_address.start,
+                address_bits: field.field_address.len(),
+                repeat_stride: misaligned_stride,
                 field_set_context: field_set.name.span,
             });
             removals.insert(field.id_with(field_set.id()));
@@ -95,28 +181,32 @@ fn validate_overlap(field_set: &FieldSet, manifest: &Manifest, diagnostics: &mut
 
             for offset in &offsets {
                 for second_offset in &second_offsets {
-                    if ranges_overlap(
-                        &field.field_address,
-                        *offset,
-                        &second_field.field_address,
-                        *second_offset,
-                    ) {
-                        diagnostics.add(OverlappingFields {
-                            field_address_1: field.field_address.span,
-                            repeat_offset_1: repeated.then_some(*offset),
-                            field_address_start_1: i128::from(field.field_address.start) + offset,
-                            field_address_end_1: i128::from(field.field_address.end) + offset,
-                            field_address_2: second_field.field_address.span,
-                            repeat_offset_2: second_repeated.then_some(*second_offset),
-                            field_address_start_2: i128::from(second_field.field_address.start)
-                                + second_offset,
-                            field_address_end_2: i128::from(second_field.field_address.end)
-                                + second_offset,
-
-                            field_set_context: field_set.name.span,
-                        });
-
-                        continue 'second_field;
+                    for field_address in field.field_address_parts() {
+                        for second_field_address in second_field.field_address_parts() {
+                            if ranges_overlap(
+                                field_address,
+                                *offset,
+                                second_field_address,
+                                *second_offset,
+                            ) {
+                                diagnostics.add(OverlappingFields {
+                                    field_address_1: field_address.span,
+                                    repeat_offset_1: repeated.then_some(*offset),
+                                    field_address_start_1: i128::from(field_address.start) + offset,
+                                    field_address_end_1: i128::from(field_address.end) + offset,
+                                    field_address_2: second_field_address.span,
+                                    repeat_offset_2: second_repeated.then_some(*second_offset),
+                                    field_address_start_2: i128::from(second_field_address.start)
+                                        + second_offset,
+                                    field_address_end_2: i128::from(second_field_address.end)
+                                        + second_offset,
+
+                                    field_set_context: field_set.name.span,
+                                });
+
+                                continue 'second_field;
+                            }
+                        }
                     }
                 }
             }
@@ -129,7 +219,7 @@ fn ranges_overlap(l: &AddressRange, offset: i128, r: &AddressRange, second_offse
         && (i128::from(r.start) + second_offset) <= (i128::from(l.end) + offset)
 }
 
-fn get_repeat_iter(manifest: &Manifest, field: &Field) -> (Vec<i128>, bool) {
+pub(super) fn get_repeat_iter(manifest: &Manifest, field: &Field) -> (Vec<i128>, bool) {
     if let Some(repeat) = &field.repeat {
         let stride = repeat.stride;
         match &repeat.source.value {
@@ -149,6 +239,13 @@ fn get_repeat_iter(manifest: &Manifest, field: &Field) -> (Vec<i128>, bool) {
                     .collect(),
                 true,
             ),
+            RepeatSource::Offsets(offsets) => (
+                offsets
+                    .iter()
+                    .map(|offset| offset.value * stride.value)
+                    .collect(),
+                true,
+            ),
         }
     } else {
         (vec![0], false)
@@ -162,7 +259,7 @@ mod tests {
     use device_driver_common::{
         identifier::Identifier,
         span::{Span, SpanExt},
-        specifiers::Repeat,
+        specifiers::{BaseType, Repeat},
     };
 
     use crate::model::{Device, Field, Object};
@@ -225,6 +322,7 @@ mod tests {
                     repeat: Some(Repeat {
                         source: RepeatSource::Count(NonZero::new(3).unwrap()).with_dummy_span(),
                         stride: 5.with_dummy_span(),
+                        inner: None,
                         span: Span::empty(),
                     }),
                     ..Default::default()
@@ -347,6 +445,7 @@ mod tests {
                         repeat: Some(Repeat {
                             source: RepeatSource::Count(NonZero::new(6).unwrap()).with_dummy_span(),
                             stride: 1.with_dummy_span(),
+                            inner: None,
                             span: Span::empty(),
                         }),
                         ..Default::default()
@@ -370,4 +469,150 @@ mod tests {
         assert!(!diagnostics.has_error());
         assert!(!diagnostics.is_empty());
     }
+
+    #[test]
+    fn byte_array_alignment() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                size_bytes: 4.with_dummy_span(),
+                fields: vec![Field {
+                    name: Identifier::try_parse("my_field").unwrap().with_dummy_span(),
+                    base_type: BaseType::Bytes.with_dummy_span(),
+                    field_address: AddressRange { start: 8, end: 23 }.with_dummy_span(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(!diagnostics.has_error());
+
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                size_bytes: 4.with_dummy_span(),
+                fields: vec![Field {
+                    name: Identifier::try_parse("my_field").unwrap().with_dummy_span(),
+                    base_type: BaseType::Ascii.with_dummy_span(),
+                    field_address: AddressRange { start: 4, end: 19 }.with_dummy_span(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(diagnostics.has_error());
+
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                size_bytes: 4.with_dummy_span(),
+                fields: vec![Field {
+                    name: Identifier::try_parse("my_field").unwrap().with_dummy_span(),
+                    base_type: BaseType::Bytes.with_dummy_span(),
+                    field_address: AddressRange { start: 0, end: 7 }.with_dummy_span(),
+                    repeat: Some(Repeat {
+                        source: RepeatSource::Count(NonZero::new(2).unwrap()).with_dummy_span(),
+                        stride: 12.with_dummy_span(),
+                        inner: None,
+                        span: Span::empty(),
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(diagnostics.has_error());
+    }
+
+    #[test]
+    fn split_fields() {
+        let split_device = |split_field_address: Vec<AddressRange>| -> Manifest {
+            Device {
+                description: String::new(),
+                name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+                objects: vec![Object::FieldSet(FieldSet {
+                    name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                    size_bytes: 2.with_dummy_span(),
+                    fields: vec![
+                        Field {
+                            name: Identifier::try_parse("my_field").unwrap().with_dummy_span(),
+                            field_address: AddressRange { start: 0, end: 3 }.with_dummy_span(),
+                            split_field_address: split_field_address
+                                .into_iter()
+                                .map(|address| address.with_dummy_span())
+                                .collect(),
+                            ..Default::default()
+                        },
+                        Field {
+                            name: Identifier::try_parse("my_field2")
+                                .unwrap()
+                                .with_dummy_span(),
+                            field_address: AddressRange { start: 4, end: 9 }.with_dummy_span(),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                })],
+                ..Default::default()
+            }
+            .into()
+        };
+
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(
+            &mut split_device(vec![AddressRange { start: 12, end: 15 }]),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert!(diagnostics.is_empty());
+
+        // The split part overlaps with the other field
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(
+            &mut split_device(vec![AddressRange { start: 8, end: 11 }]),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert!(!diagnostics.has_error());
+        assert!(!diagnostics.is_empty());
+
+        // The split part overlaps with its own field
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(
+            &mut split_device(vec![AddressRange { start: 2, end: 2 }]),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert!(diagnostics.has_error());
+
+        // The split part doesn't fit
+        let mut diagnostics = Diagnostics::new();
+        BitRangesValidated::run_pass(
+            &mut split_device(vec![AddressRange { start: 15, end: 16 }]),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert!(diagnostics.has_error());
+    }
 }
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use crate::{
+    model::{Manifest, Object, Unique, UniqueId},
+    passes::{Assumption, Pass},
+    search_object,
+};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{
+        BlockInstanceHasObjects, BlockInstanceVariantsUncovered, BlockRefCycle, BlockRefToInstance,
+        InvalidBlockRef,
+    },
+};
+
+/// Checks whether all block instances point to an existing block in the same device
+/// that isn't an instance itself, doesn't contain the instance and exists for all chip variants of the instance
+pub struct BlockRefsValid;
+
+impl Pass for BlockRefsValid {
+    const ASSUMPTIONS_MADE: &[Assumption] =
+        &[Assumption::NamesUnique, Assumption::ChipVariantsPropagated];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[Assumption::BlockRefsValid];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for (device, _) in manifest.iter_devices_with_config() {
+            for object in device.iter_objects() {
+                let Object::Block(block) = object else {
+                    continue;
+                };
+                let Some(block_ref) = &block.block_ref else {
+                    continue;
+                };
+
+                if let Some(child) = block.objects.first() {
+                    diagnostics.add(BlockInstanceHasObjects {
+                        reference: block_ref.span,
+                        object: child.name_span(),
+                    });
+                    removals.insert(object.id());
+                    continue;
+                }
+
+                let Some(Object::Block(target)) = device.iter_objects().find(|o| {
+                    block_ref.is_ref_to(o.name()) && o.coexists_with(&block.chip_variants)
+                }) else {
+                    diagnostics.add(InvalidBlockRef {
+                        reference: block_ref.span,
+                        pointee: search_object(manifest, &block_ref.value)
+                            .filter(|o| !matches!(o, Object::Block(_)))
+                            .map(|o| o.name_span()),
+                    });
+                    removals.insert(object.id());
+                    continue;
+                };
+
+                if let Some(target_ref) = &target.block_ref {
+                    diagnostics.add(BlockRefToInstance {
+                        reference: block_ref.span,
+                        pointee_reference: target_ref.span,
+                    });
+                    removals.insert(object.id());
+                    continue;
+                }
+
+                if target.iter_objects().any(|o| o.id() == object.id()) {
+                    diagnostics.add(BlockRefCycle {
+                        block: target.name.span,
+                        reference: block_ref.span,
+                    });
+                    removals.insert(object.id());
+                    continue;
+                }
+
+                // The shared block only exists for its own variants, so the instance can't exist for more
+                if !target.chip_variants.is_empty()
+                    && (block.chip_variants.is_empty()
+                        || block.chip_variants.iter().any(|variant| {
+                            !target
+                                .chip_variants
+                                .iter()
+                                .any(|v| v.value == variant.value)
+                        }))
+                {
+                    diagnostics.add(BlockInstanceVariantsUncovered {
+                        instance: block.name.span,
+                        target: target.name.span,
+                    });
+                    removals.insert(object.id());
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
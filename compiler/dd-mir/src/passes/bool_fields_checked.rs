@@ -25,21 +25,22 @@ impl Pass for BoolFieldsChecked {
             };
 
             for field in field_set.fields.iter_mut() {
-                if field.base_type == BaseType::Bool && field.field_address.len() != 1 {
+                if field.base_type == BaseType::Bool && field.size_bits() != 1 {
                     diagnostics.add(BoolFieldTooLarge {
                         base_type: if field.base_type.span.is_empty() {
                             None
                         } else {
                             Some(field.base_type.span)
                         },
-                        address: field.field_address.span,
-                        address_bits: field.field_address.len() as u32,
+                        address: field.field_address_span(),
+                        address_bits: field.size_bits() as u32,
                         address_start: field.field_address.start,
 
                         field_set_context: field_set.name.span,
                     });
                     // To fix for further use, set the len to just 1
                     field.field_address.end = field.field_address.start;
+                    field.split_field_address.clear();
                 }
             }
         }
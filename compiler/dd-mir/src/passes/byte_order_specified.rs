@@ -1,13 +1,18 @@
 use std::collections::HashSet;
 
 use crate::{
-    model::{LendingIterator, Manifest, UniqueId},
+    model::{LendingIterator, Manifest, Object, Unique, UniqueId},
     passes::{Assumption, Pass},
+    search_object,
 };
 use device_driver_common::specifiers::ByteOrder;
-use device_driver_diagnostics::{Diagnostics, DynError, errors::UnspecifiedByteOrder};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{RegisterViewByteOrderMismatch, UnspecifiedByteOrder},
+};
 
-/// Checks if the byte order is set for all registers and commands that need it and fills it out for the ones that aren't specified
+/// Checks if the byte order is set for all registers and commands that need it and fills it out for the ones that aren't specified.
+/// The views of a register read the same bytes as the register, so they must have the byte order of the fieldset of the register.
 pub struct ByteOrderSpecified;
 
 impl Pass for ByteOrderSpecified {
@@ -37,7 +42,40 @@ impl Pass for ByteOrderSpecified {
             }
         }
 
-        Ok(Default::default())
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let Object::Register(register) = object else {
+                continue;
+            };
+            // Missing fieldsets are reported elsewhere
+            let Some(Object::FieldSet(field_set)) =
+                search_object(manifest, &register.field_set_ref)
+            else {
+                continue;
+            };
+
+            for view in &register.views {
+                let Some(Object::FieldSet(view_field_set)) = search_object(manifest, view) else {
+                    continue;
+                };
+
+                // The byte order doesn't matter for a single byte
+                if field_set.size_bytes.value > 1
+                    && view_field_set.byte_order != field_set.byte_order
+                {
+                    diagnostics.add(RegisterViewByteOrderMismatch {
+                        view: view.span,
+                        view_byte_order: view_field_set.byte_order.unwrap_or_default(),
+                        field_set: field_set.name.span,
+                        field_set_byte_order: field_set.byte_order.unwrap_or_default(),
+                    });
+                    removals.insert(register.id());
+                }
+            }
+        }
+
+        Ok(removals)
     }
 }
 
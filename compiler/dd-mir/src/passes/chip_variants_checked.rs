@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use device_driver_common::span::{Span, Spanned};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{ChipVariantsConflict, DuplicateChipVariant, UnknownChipVariant},
+};
+use itertools::Itertools;
+
+use crate::{
+    model::{DeviceConfig, Manifest, Object, UniqueId},
+    passes::{Assumption, Pass},
+};
+
+/// Checks the declared chip variants and the variants objects are conditional on.
+/// After this pass, every object has the variants it actually exists for, including the ones inherited from its parents.
+/// Objects that can't exist for any variant are removed.
+pub struct ChipVariantsChecked;
+
+impl Pass for ChipVariantsChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[Assumption::ChipVariantsPropagated];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        check_declarations(&manifest.config, diagnostics);
+
+        for object in manifest.objects.iter_mut() {
+            let Object::Device(device) = object else {
+                continue;
+            };
+
+            check_declarations(&device.device_config, diagnostics);
+            let config = manifest.config.override_with(&device.device_config);
+
+            propagate(
+                &mut device.objects,
+                &config.chip_variants.unwrap_or_default(),
+                None,
+                diagnostics,
+            );
+        }
+
+        Ok(Default::default())
+    }
+}
+
+fn check_declarations(config: &DeviceConfig, diagnostics: &mut Diagnostics) {
+    let Some(chip_variants) = config.chip_variants.as_ref() else {
+        return;
+    };
+
+    for (index, variant) in chip_variants.iter().enumerate() {
+        if let Some(original) = chip_variants[..index]
+            .iter()
+            .find(|original| original.value == variant.value)
+        {
+            diagnostics.add(DuplicateChipVariant {
+                original: original.span,
+                duplicate: variant.span,
+            });
+        }
+    }
+}
+
+/// Check the variants of the objects and make them inherit the variants of the parent.
+/// Objects that can't exist are removed right away instead of by id,
+/// because objects of exclusive variants still share their id at this point.
+fn propagate(
+    objects: &mut Vec<Object>,
+    declared_variants: &[Spanned<String>],
+    parent: Option<(Span, &[Spanned<String>])>,
+    diagnostics: &mut Diagnostics,
+) {
+    objects.retain_mut(|object| {
+        let name_span = object.name_span();
+
+        let Some(chip_variants) = object.chip_variants_mut() else {
+            return true;
+        };
+
+        if !chip_variants.is_empty() {
+            chip_variants.retain(|variant| {
+                let known = declared_variants.iter().any(|v| v.value == variant.value);
+                if !known {
+                    diagnostics.add(UnknownChipVariant {
+                        variant: variant.span,
+                        declared_variants: declared_variants
+                            .iter()
+                            .map(|v| v.value.clone())
+                            .unique()
+                            .collect(),
+                    });
+                }
+                known
+            });
+
+            // All variants were unknown. The object can't exist
+            if chip_variants.is_empty() {
+                return false;
+            }
+        }
+
+        if let Some((parent_span, parent_variants)) = parent
+            && !parent_variants.is_empty()
+        {
+            if chip_variants.is_empty() {
+                chip_variants.extend_from_slice(parent_variants);
+            } else {
+                chip_variants
+                    .retain(|variant| parent_variants.iter().any(|v| v.value == variant.value));
+
+                if chip_variants.is_empty() {
+                    diagnostics.add(ChipVariantsConflict {
+                        object: name_span,
+                        parent: parent_span,
+                    });
+                    return false;
+                }
+            }
+        }
+
+        let chip_variants = chip_variants.clone();
+        if let Some(child_objects) = object.child_objects_vec() {
+            propagate(
+                child_objects,
+                declared_variants,
+                Some((name_span, &chip_variants)),
+                diagnostics,
+            );
+        }
+
+        true
+    });
+}
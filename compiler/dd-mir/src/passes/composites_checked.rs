@@ -0,0 +1,303 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use device_driver_common::{
+    span::Span,
+    specifiers::{Access, BaseType, Integer},
+};
+use device_driver_diagnostics::{Diagnostics, DynError, errors::InvalidComposite};
+use itertools::Itertools;
+
+use crate::{
+    model::{Composite, LendingIterator, Manifest, Object, Unique, UniqueId},
+    passes::{Assumption, Pass},
+    search_object,
+};
+
+/// Checks that the parts of composites point to unsigned integer fields of registers in the same block
+/// and that the value fits its type. Composites without a fixed size type get the smallest type that fits.
+pub struct CompositesChecked;
+
+impl Pass for CompositesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::NamesUnique,
+        Assumption::FieldsetRefsValid,
+        Assumption::AccessSet,
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::ChipVariantsPropagated,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+        // Collected first since the composites can't be changed while the manifest is searched
+        let mut base_types = HashMap::new();
+
+        for object in manifest.iter_objects() {
+            let siblings = object.child_objects();
+
+            for composite in siblings.iter().filter_map(|o| match o {
+                Object::Composite(composite) => Some(composite),
+                _ => None,
+            }) {
+                match check_composite(manifest, siblings, composite, diagnostics) {
+                    Some(base_type) => {
+                        base_types.insert(composite.id(), base_type);
+                    }
+                    None => {
+                        removals.insert(composite.id());
+                    }
+                }
+            }
+        }
+
+        let mut iter = manifest.iter_objects_with_config_mut();
+        while let Some((object, _)) = iter.next() {
+            if let Object::Composite(composite) = object
+                && let Some(base_type) = base_types.get(&composite.id())
+            {
+                composite.base_type.value = *base_type;
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+/// Check the parts and the latch of the composite. Returns the base type of the value or None if there's an error.
+fn check_composite(
+    manifest: &Manifest,
+    siblings: &[Object],
+    composite: &Composite,
+    diagnostics: &mut Diagnostics,
+) -> Option<BaseType> {
+    let mut report = |problem: Span, pointee: Option<Span>, reason: Cow<'static, str>| {
+        diagnostics.add(InvalidComposite {
+            composite: composite.name.span,
+            problem,
+            pointee,
+            reason,
+        });
+        false
+    };
+
+    if composite.parts.len() < 2 {
+        report(
+            composite.name.span,
+            None,
+            "a composite needs at least two parts".into(),
+        );
+        return None;
+    }
+
+    let mut ok = true;
+    let mut readable = true;
+    let mut writable = true;
+    let mut size_bits = 0;
+    // The register and field of every part that's been checked
+    let mut seen_fields = Vec::new();
+
+    for part in &composite.parts {
+        let register_ref = part.name.value.clone().take_ref();
+
+        let register = match siblings
+            .iter()
+            .find(|o| register_ref.is_ref_to(o.name()) && o.coexists_with(&composite.chip_variants))
+        {
+            Some(Object::Register(register)) => register,
+            Some(object) => {
+                ok &= report(
+                    part.name.span,
+                    Some(object.name_span()),
+                    "expected a register".into(),
+                );
+                continue;
+            }
+            None => {
+                ok &= match search_object(manifest, &register_ref) {
+                    Some(object) => report(
+                        part.name.span,
+                        Some(object.name_span()),
+                        "the register must be in the same block as the composite".into(),
+                    ),
+                    None => report(
+                        part.name.span,
+                        None,
+                        "no register found with this name".into(),
+                    ),
+                };
+                continue;
+            }
+        };
+
+        if register.repeat.is_some() {
+            ok &= report(
+                part.name.span,
+                Some(register.name.span),
+                "repeated registers can't be used in composites".into(),
+            );
+            continue;
+        }
+
+        if !register.chip_variants.is_empty()
+            && (composite.chip_variants.is_empty()
+                || !composite.chip_variants.iter().all(|variant| {
+                    register
+                        .chip_variants
+                        .iter()
+                        .any(|v| v.value == variant.value)
+                }))
+        {
+            ok &= report(
+                part.name.span,
+                Some(register.name.span),
+                format!(
+                    "the register only exists for the chip variants {}. Use `when` to make the composite conditional on them",
+                    register
+                        .chip_variants
+                        .iter()
+                        .map(|v| format!("`{}`", v.value))
+                        .join(", ")
+                )
+                .into(),
+            );
+            continue;
+        }
+
+        let access = register.access.unwrap_or(Access::RW);
+        readable &= access.is_readable();
+        writable &= access.is_writable();
+
+        let (Some(field_ref), Some(Object::FieldSet(field_set))) = (
+            &part.field,
+            search_object(manifest, &register.field_set_ref),
+        ) else {
+            // Missing properties and fieldset refs are already reported
+            ok = false;
+            continue;
+        };
+
+        let Some(field) = field_set
+            .fields
+            .iter()
+            .find(|f| field_ref.is_ref_to(&f.name))
+        else {
+            ok &= report(
+                field_ref.span,
+                None,
+                format!(
+                    "no field with this name in fieldset `{}`",
+                    field_set.name.original()
+                )
+                .into(),
+            );
+            continue;
+        };
+
+        let problem = if field.repeat.is_some() {
+            Some("repeated fields can't be used in composites")
+        } else if field.reserved.is_some() {
+            Some("reserved fields can't be used in composites")
+        } else if field.field_conversion.is_some() {
+            Some("fields with a conversion can't be used in composites")
+        } else if field.encoding.is_some() {
+            Some("fields with an encoding can't be used in composites")
+        } else if !matches!(field.base_type.value, BaseType::FixedSize(integer) if !integer.is_signed())
+        {
+            Some("only unsigned integer fields can be used in composites")
+        } else {
+            None
+        };
+        if let Some(problem) = problem {
+            ok &= report(field_ref.span, Some(field.name.span), problem.into());
+            continue;
+        }
+
+        if let Some((_, _, previous)) = seen_fields.iter().find(|(register_name, field_name, _)| {
+            *register_name == &register.name.value && *field_name == &field.name.value
+        }) {
+            ok &= report(
+                field_ref.span,
+                Some(*previous),
+                "the field is already a part of the composite".into(),
+            );
+            continue;
+        }
+        seen_fields.push((&register.name.value, &field.name.value, part.span));
+
+        let access = field.access.unwrap_or(Access::RW);
+        readable &= access.is_readable();
+        writable &= access.is_writable();
+
+        size_bits += field.size_bits();
+    }
+
+    if let Some(latch) = &composite.latch
+        && !composite
+            .parts
+            .iter()
+            .any(|part| latch.is_ref_to(&part.name.value))
+    {
+        ok &= report(
+            latch.span,
+            None,
+            "the latch must be the register of one of the parts".into(),
+        );
+    }
+
+    if !ok {
+        return None;
+    }
+
+    if !readable && !writable {
+        report(
+            composite.name.span,
+            None,
+            "the composite can't be read or written because of the access of its registers and fields"
+                .into(),
+        );
+        return None;
+    }
+
+    let base_type = composite.base_type;
+    let integer = match base_type.value {
+        BaseType::Unspecified | BaseType::Uint => Integer::find_smallest(0, 0, size_bits),
+        BaseType::Int => Integer::find_smallest(-1, 0, size_bits),
+        BaseType::FixedSize(integer) if u64::from(integer.size_bits()) >= size_bits => {
+            Some(integer)
+        }
+        BaseType::FixedSize(integer) => {
+            report(
+                base_type.span,
+                None,
+                format!("the value has {size_bits} bits, which don't fit in `{integer}`").into(),
+            );
+            return None;
+        }
+        BaseType::Bool | BaseType::Bytes | BaseType::Ascii => {
+            report(
+                base_type.span,
+                None,
+                "the value of a composite must be an integer".into(),
+            );
+            return None;
+        }
+    };
+
+    match integer {
+        Some(integer) => Some(BaseType::FixedSize(integer)),
+        None => {
+            report(
+                composite.name.span,
+                None,
+                format!("the value has {size_bits} bits, which don't fit in any integer").into(),
+            );
+            None
+        }
+    }
+}
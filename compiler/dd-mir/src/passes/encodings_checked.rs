@@ -0,0 +1,220 @@
+use std::collections::HashSet;
+
+use crate::{
+    model::{Manifest, Unique, UniqueId},
+    passes::{Assumption, Pass},
+};
+use device_driver_common::specifiers::{BaseType, Encoding};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{EncodingInvalidFieldSize, EncodingWrongBaseType},
+};
+
+/// Check all fields with an encoding. The base type must have the right signedness and the field must be big enough.
+pub struct EncodingsChecked;
+
+impl Pass for EncodingsChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[Assumption::FieldBaseTypesSpecified];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let Some(field_set) = object.as_field_set() else {
+                continue;
+            };
+
+            for field in &field_set.fields {
+                let Some(encoding) = field.encoding else {
+                    continue;
+                };
+
+                let base_type_fits = match field.base_type.value {
+                    BaseType::FixedSize(integer) => integer.is_signed() == encoding.is_signed(),
+                    _ => false,
+                };
+
+                if !base_type_fits {
+                    diagnostics.add(EncodingWrongBaseType {
+                        encoding,
+                        base_type: field.base_type,
+                    });
+                    removals.insert(field.id_with(field_set.id()));
+                    continue;
+                }
+
+                let size_bits = field.size_bits();
+                let size_fits = match encoding.value {
+                    Encoding::Bcd => size_bits.is_multiple_of(4),
+                    Encoding::Gray => true,
+                    Encoding::SignMagnitude | Encoding::OnesComplement => size_bits >= 2,
+                };
+
+                if !size_fits {
+                    diagnostics.add(EncodingInvalidFieldSize {
+                        encoding,
+                        address: field.field_address_span(),
+                        address_bits: size_bits,
+                    });
+                    removals.insert(field.id_with(field_set.id()));
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use device_driver_common::{
+        identifier::Identifier,
+        span::SpanExt,
+        specifiers::{AddressRange, Integer},
+    };
+
+    use crate::model::{Device, Field, FieldSet, Object};
+
+    use super::*;
+
+    #[test]
+    fn encodings_correct() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                size_bytes: 4.with_dummy_span(),
+                fields: vec![
+                    Field {
+                        name: Identifier::try_parse("bcd").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        encoding: Some(Encoding::Bcd.with_dummy_span()),
+                        field_address: AddressRange { start: 0, end: 7 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("gray").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        encoding: Some(Encoding::Gray.with_dummy_span()),
+                        field_address: AddressRange { start: 8, end: 12 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("sign_magnitude")
+                            .unwrap()
+                            .with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::I8).with_dummy_span(),
+                        encoding: Some(Encoding::SignMagnitude.with_dummy_span()),
+                        field_address: AddressRange { start: 16, end: 23 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("ones_complement")
+                            .unwrap()
+                            .with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::I8).with_dummy_span(),
+                        encoding: Some(Encoding::OnesComplement.with_dummy_span()),
+                        field_address: AddressRange { start: 24, end: 25 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        let removals = EncodingsChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(!diagnostics.has_error());
+        assert!(removals.is_empty());
+    }
+
+    #[test]
+    fn encodings_wrong_base_type() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                size_bytes: 3.with_dummy_span(),
+                fields: vec![
+                    Field {
+                        name: Identifier::try_parse("bcd").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::I8).with_dummy_span(),
+                        encoding: Some(Encoding::Bcd.with_dummy_span()),
+                        field_address: AddressRange { start: 0, end: 7 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("sign_magnitude")
+                            .unwrap()
+                            .with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        encoding: Some(Encoding::SignMagnitude.with_dummy_span()),
+                        field_address: AddressRange { start: 8, end: 15 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("gray").unwrap().with_dummy_span(),
+                        base_type: BaseType::Bool.with_dummy_span(),
+                        encoding: Some(Encoding::Gray.with_dummy_span()),
+                        field_address: AddressRange { start: 16, end: 16 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        let removals = EncodingsChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(diagnostics.has_error());
+        assert_eq!(removals.len(), 3);
+    }
+
+    #[test]
+    fn encodings_wrong_field_size() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                size_bytes: 2.with_dummy_span(),
+                fields: vec![
+                    Field {
+                        name: Identifier::try_parse("bcd").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        encoding: Some(Encoding::Bcd.with_dummy_span()),
+                        field_address: AddressRange { start: 0, end: 5 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("sign_magnitude")
+                            .unwrap()
+                            .with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::I8).with_dummy_span(),
+                        encoding: Some(Encoding::SignMagnitude.with_dummy_span()),
+                        field_address: AddressRange { start: 8, end: 8 }.with_dummy_span(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        let removals = EncodingsChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(diagnostics.has_error());
+        assert_eq!(removals.len(), 2);
+    }
+}
@@ -15,7 +15,7 @@ use device_driver_diagnostics::{
     errors::{
         DuplicateVariantValue, EmptyEnum, EnumBadBasetype, EnumMultipleCatchalls,
         EnumMultipleDefaults, EnumNoAutoBaseTypeSelected, EnumSizeBitsBiggerThanBaseType,
-        VariantValuesTooHigh, VariantValuesTooLow,
+        OverlappingVariantValues, VariantValuesTooHigh, VariantValuesTooLow,
     },
 };
 
@@ -51,12 +51,23 @@ impl Pass for EnumValuesChecked {
 
             // Record all variant values
             let e_id = enum_value.id();
-            let seen_values = enum_value
+            let seen_variants = enum_value
                 .iter_variants_with_discriminant_mut()
                 .map(|(discriminant, variant)| {
                     if variant.value.specified_discriminant().is_none() {
                         variant.value.specify(discriminant);
                     }
+                    (
+                        variant.value.value_ranges(discriminant),
+                        variant.value.is_values(),
+                        variant.span,
+                    )
+                })
+                .collect_vec();
+            let seen_values = enum_value
+                .iter_variants_with_discriminant()
+                .filter(|(_, variant)| !variant.value.is_values())
+                .map(|(discriminant, variant)| {
                     (discriminant, (variant.id_with(e_id.clone()), variant.span))
                 })
                 .collect_vec();
@@ -81,22 +92,60 @@ impl Pass for EnumValuesChecked {
                 }
             }
 
-            let (seen_min, (_, seen_min_span)) = seen_values
+            // Variants with multiple values must not overlap with any other variant
+            for (index, (ranges, is_values, span)) in seen_variants.iter().enumerate() {
+                if !is_values {
+                    continue;
+                }
+
+                let overlap = seen_variants
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_index, (_, other_is_values, _))| {
+                        *other_index != index && (!other_is_values || *other_index > index)
+                    })
+                    .find_map(|(_, (other_ranges, _, other_span))| {
+                        ranges
+                            .iter()
+                            .cartesian_product(other_ranges)
+                            .find(|(range, other)| {
+                                range.start <= other.end && other.start <= range.end
+                            })
+                            .map(|(range, other)| (range.start.max(other.start), *other_span))
+                    });
+
+                if let Some((value, other_span)) = overlap {
+                    diagnostics.add(OverlappingVariantValues {
+                        variant: *span,
+                        other: other_span,
+                        value,
+                    });
+                    removals.insert(enum_value.id());
+                }
+            }
+
+            let all_ranges = seen_variants
+                .iter()
+                .flat_map(|(ranges, _, span)| ranges.iter().map(move |range| (*range, *span)))
+                .collect_vec();
+            let (seen_min, seen_min_span) = all_ranges
                 .iter()
-                .min_by_key(|(val, _)| val)
+                .map(|(range, span)| (range.start, *span))
+                .min_by_key(|(val, _)| *val)
                 .expect("Enums must not be empty");
-            let (seen_max, _) = seen_values
+            let seen_max = all_ranges
                 .iter()
-                .max_by_key(|(val, _)| val)
+                .map(|(range, _)| range.end)
+                .max()
                 .expect("Enums must not be empty");
 
             let base_type_integer = match enum_value.base_type.value {
                 BaseType::Unspecified => Integer::find_smallest(
-                    *seen_min,
-                    *seen_max,
+                    seen_min,
+                    seen_max,
                     enum_value.size_bits.unwrap_or_default().into(),
                 ),
-                BaseType::Bool => {
+                BaseType::Bool | BaseType::Bytes | BaseType::Ascii => {
                     diagnostics.add(EnumBadBasetype {
                         enum_name: enum_value.name.span,
                         base_type: enum_value.base_type.span,
@@ -108,8 +157,8 @@ impl Pass for EnumValuesChecked {
                 }
                 BaseType::Uint => {
                     let integer = Integer::find_smallest(
-                        *seen_min,
-                        *seen_max,
+                        seen_min,
+                        seen_max,
                         enum_value.size_bits.unwrap_or_default().into(),
                     );
 
@@ -119,7 +168,7 @@ impl Pass for EnumValuesChecked {
                         enum_name: enum_value.name.span,
                         base_type: enum_value.base_type.span,
                         info: "enums must use a signed integer when any variant has a negative value",
-                        context: vec![format!("variant with negative value: {seen_min}").with_span(*seen_min_span)],
+                        context: vec![format!("variant with negative value: {seen_min}").with_span(seen_min_span)],
                     });
                         removals.insert(enum_value.id());
                         continue;
@@ -128,8 +177,8 @@ impl Pass for EnumValuesChecked {
                     integer
                 }
                 BaseType::Int => Integer::find_smallest(
-                    (*seen_min).min(-1),
-                    *seen_max,
+                    seen_min.min(-1),
+                    seen_max,
                     enum_value.size_bits.unwrap_or_default().into(),
                 ),
                 BaseType::FixedSize(integer) => {
@@ -150,7 +199,7 @@ impl Pass for EnumValuesChecked {
                         enum_name: enum_value.name.span,
                         base_type: enum_value.base_type.span,
                         info: "enums must use a signed integer when any variant has a negative value",
-                        context: vec![format!("variant with negative value: {seen_min}").with_span(*seen_min_span)],
+                        context: vec![format!("variant with negative value: {seen_min}").with_span(seen_min_span)],
                     });
                         removals.insert(enum_value.id());
                         continue;
@@ -170,7 +219,7 @@ impl Pass for EnumValuesChecked {
 
             let size_bits = match enum_value.size_bits {
                 None => base_type_integer
-                    .bits_required(*seen_min, *seen_max)
+                    .bits_required(seen_min, seen_max)
                     .min(base_type_integer.size_bits()),
                 Some(size_bits) => size_bits,
             };
@@ -190,9 +239,19 @@ impl Pass for EnumValuesChecked {
                 .variants
                 .iter()
                 .any(|v| matches!(v.value, EnumValue::Default(_) | EnumValue::CatchAll(_)));
-            let has_bits_covered = all_values
-                .clone()
-                .all(|val| seen_values.iter().any(|(seen_val, _)| val == *seen_val));
+            let has_bits_covered = {
+                let mut next_uncovered = *all_values.start();
+                for (range, _) in all_ranges
+                    .iter()
+                    .sorted_unstable_by_key(|(range, _)| range.start)
+                {
+                    if range.start > next_uncovered {
+                        break;
+                    }
+                    next_uncovered = next_uncovered.max(range.end + 1);
+                }
+                next_uncovered > *all_values.end()
+            };
 
             enum_value.generation_style = Some(match (has_fallback, has_bits_covered) {
                 (true, _) => EnumGenerationStyle::Fallback,
@@ -201,15 +260,17 @@ impl Pass for EnumValuesChecked {
             });
 
             // Check if the enum has variants that fall outside of the available bits
-            let too_high_values = seen_values
+            let too_high_values = seen_variants
                 .iter()
-                .filter(|(val, _)| val > all_values.end())
-                .map(|(_, (_, span))| *span)
+                .filter(|(ranges, _, _)| ranges.iter().any(|range| range.end > *all_values.end()))
+                .map(|(_, _, span)| *span)
                 .collect::<Vec<_>>();
-            let too_low_values = seen_values
+            let too_low_values = seen_variants
                 .iter()
-                .filter(|(val, _)| val < all_values.start())
-                .map(|(_, (_, span))| *span)
+                .filter(|(ranges, _, _)| {
+                    ranges.iter().any(|range| range.start < *all_values.start())
+                })
+                .map(|(_, _, span)| *span)
                 .collect::<Vec<_>>();
 
             if !too_high_values.is_empty() {
@@ -294,6 +355,7 @@ mod tests {
     use device_driver_common::{
         identifier::{Identifier, Type},
         span::{Span, SpanExt},
+        specifiers::ValueRange,
     };
 
     use crate::model::{Device, Enum, EnumVariant, Object};
@@ -492,6 +554,51 @@ mod tests {
         assert_eq!(start_mir, end_mir);
     }
 
+    #[test]
+    fn enum_value_ranges_cover_all_values() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::Enum(Enum::new(
+                Default::default(),
+                Identifier::try_parse("MyEnum").unwrap().with_dummy_span(),
+                vec![
+                    EnumVariant {
+                        name: Identifier::try_parse("var0").unwrap().with_dummy_span(),
+                        value: EnumValue::Values(vec![ValueRange { start: 0, end: 5 }]),
+                        ..Default::default()
+                    },
+                    EnumVariant {
+                        name: Identifier::try_parse("var1").unwrap().with_dummy_span(),
+                        value: EnumValue::Unspecified,
+                        ..Default::default()
+                    },
+                    EnumVariant {
+                        name: Identifier::try_parse("var2").unwrap().with_dummy_span(),
+                        value: EnumValue::Values(vec![ValueRange { start: 7, end: 7 }]),
+                        ..Default::default()
+                    },
+                ],
+                BaseType::Unspecified.with_dummy_span(),
+                Some(3),
+                Span::default(),
+            ))],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        EnumValuesChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+
+        assert!(!diagnostics.has_error());
+        let enum_value = start_mir.iter_enums().next().unwrap();
+        assert_eq!(enum_value.variants[1].value, EnumValue::Specified(6));
+        assert_eq!(
+            enum_value.generation_style,
+            Some(EnumGenerationStyle::InfallibleWithinRange)
+        );
+    }
+
     #[test]
     fn enum_values_dont_fit() {
         let mut start_mir = Device {
@@ -10,8 +10,8 @@ use crate::{
 use device_driver_diagnostics::{
     Diagnostics, DynError,
     errors::{
-        ConversionTypeTooBig, DifferentBaseTypes, InvalidConversionType,
-        InvalidInfallibleConversion, ReferencedObjectDoesNotExist,
+        ByteArrayFieldWithConversion, ConversionTypeTooBig, DifferentBaseTypes,
+        InvalidConversionType, InvalidInfallibleConversion, ReferencedObjectDoesNotExist,
     },
 };
 
@@ -33,6 +33,15 @@ impl Pass for FieldConversionValid {
             if let Object::FieldSet(field_set) = object {
                 for field in &field_set.fields {
                     if let Some(conversion) = field.field_conversion.as_ref() {
+                        if field.base_type.is_byte_array() {
+                            diagnostics.add(ByteArrayFieldWithConversion {
+                                base_type: field.base_type.span,
+                                conversion: conversion.type_name.span,
+                            });
+                            removals.insert(field.id_with(field_set.id()));
+                            continue;
+                        }
+
                         let target_object = search_object(manifest, &conversion.type_name);
 
                         match target_object {
@@ -44,13 +53,13 @@ impl Pass for FieldConversionValid {
                                         target_enum.name.original()
                                     )
                                 ))?;
-                                if u64::from(target_enum_size) > field.field_address.len() {
+                                if u64::from(target_enum_size) > field.size_bits() {
                                     diagnostics.add(ConversionTypeTooBig {
                                         field: field.name.span,
-                                        field_address: field.field_address.span,
+                                        field_address: field.field_address_span(),
                                         conversion_type: target_enum.name.span,
                                         conversion: conversion.type_name.span,
-                                        field_len: field.field_address.len(),
+                                        field_len: field.size_bits(),
                                         conversion_len: target_enum_size.into(),
                                     });
                                     removals.insert(field.id_with(field_set.id()));
@@ -91,7 +100,7 @@ impl Pass for FieldConversionValid {
                                             continue;
                                         }
                                         EnumGenerationStyle::InfallibleWithinRange => {
-                                            let field_bits = field.field_address.len() as u32;
+                                            let field_bits = field.size_bits() as u32;
                                             let enum_bits = target_enum.size_bits.expect(
                                                 "Enum size_bits is already set in a previous pass",
                                             );
@@ -104,7 +113,7 @@ impl Pass for FieldConversionValid {
                                                         Cow::from(format!(
                                                                 "The field has a size of {field_bits} bits"
                                                             )).with_span(
-                                                            field.field_address.span,
+                                                            field.field_address_span(),
                                                         ),
                                                         Cow::from(format!(
                                                                 "Target enum only has a size of {enum_bits} bits. This means not all possible field values can be converted to an enum"
@@ -137,13 +146,13 @@ impl Pass for FieldConversionValid {
                                     .map(|v| v.value)
                                     .unwrap_or(u64::from(target_extern_base_type.size_bits()));
 
-                                if target_extern_size > field.field_address.len() {
+                                if target_extern_size > field.size_bits() {
                                     diagnostics.add(ConversionTypeTooBig {
                                         field: field.name.span,
-                                        field_address: field.field_address.span,
+                                        field_address: field.field_address_span(),
                                         conversion_type: target_extern.name.span,
                                         conversion: conversion.type_name.span,
-                                        field_len: field.field_address.len(),
+                                        field_len: field.size_bits(),
                                         conversion_len: target_extern_size,
                                     });
                                     removals.insert(field.id_with(field_set.id()));
@@ -5,9 +5,13 @@ use crate::{
     passes::{Assumption, Pass},
     search_object,
 };
-use device_driver_diagnostics::{Diagnostics, DynError, errors::InvalidFieldsetRef};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{InvalidFieldsetRef, RegisterViewSizeMismatch},
+};
 
 /// Checks whether all registers and commands point to existing fieldsets
+/// and whether the views of registers have the same size as the fieldset of the register
 pub struct FieldsetRefsValid;
 
 impl Pass for FieldsetRefsValid {
@@ -38,6 +42,29 @@ impl Pass for FieldsetRefsValid {
                 removals.insert(object.id());
                 break;
             }
+
+            if let Object::Register(register) = object
+                && !removals.contains(&object.id())
+                && let Some(Object::FieldSet(field_set)) =
+                    search_object(manifest, &register.field_set_ref)
+            {
+                for view in register.views.iter() {
+                    let Some(Object::FieldSet(view_field_set)) = search_object(manifest, view)
+                    else {
+                        continue;
+                    };
+
+                    if view_field_set.size_bytes.value != field_set.size_bytes.value {
+                        diagnostics.add(RegisterViewSizeMismatch {
+                            view: view.span,
+                            view_size_bytes: view_field_set.size_bytes.value,
+                            field_set: field_set.size_bytes.span,
+                            field_set_size_bytes: field_set.size_bytes.value,
+                        });
+                        removals.insert(object.id());
+                    }
+                }
+            }
         }
 
         Ok(removals)
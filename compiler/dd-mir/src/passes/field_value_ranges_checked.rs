@@ -0,0 +1,348 @@
+use std::collections::HashSet;
+
+use device_driver_common::specifiers::{BaseType, ByteOrder, ResetValue, ValueRange};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{ResetValueOutOfRange, ValueRangeOutOfBounds, ValueRangeWrongBaseType},
+};
+
+use crate::{
+    model::{Field, FieldSet, Manifest, Object, Unique, UniqueId},
+    passes::{Assumption, Pass, bit_ranges_validated::get_repeat_iter},
+    search_object,
+};
+
+/// Checks the value ranges of fields. They must be on an integer field and fit in what the field can represent.
+/// The reset values of registers must respect the value ranges of their fields.
+pub struct FieldValueRangesChecked;
+
+impl Pass for FieldValueRangesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::FieldsetRefsValid,
+        Assumption::ByteOrderSpecified,
+        Assumption::ResetValuesConverted,
+        Assumption::RepeatStrideNonZero,
+        Assumption::RepeatEnumRefValid,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let Some(field_set) = object.as_field_set() else {
+                continue;
+            };
+
+            for field in &field_set.fields {
+                let Some(value_range) = field.value_range else {
+                    continue;
+                };
+
+                let BaseType::FixedSize(integer) = field.base_type.value else {
+                    diagnostics.add(ValueRangeWrongBaseType {
+                        range: value_range.span,
+                        base_type: field.base_type,
+                    });
+                    removals.insert(field.id_with(field_set.id()));
+                    continue;
+                };
+
+                let representable_range = ValueRange::representable(
+                    integer,
+                    field.encoding.map(|encoding| encoding.value),
+                    field.size_bits(),
+                );
+
+                if !representable_range.contains_range(&value_range) {
+                    diagnostics.add(ValueRangeOutOfBounds {
+                        range: value_range,
+                        address: field.field_address_span(),
+                        representable_range,
+                    });
+                    removals.insert(field.id_with(field_set.id()));
+                }
+            }
+        }
+
+        for object in manifest.iter_objects() {
+            let Object::Register(register) = object else {
+                continue;
+            };
+            let Some(reset_value) = &register.reset_value else {
+                continue;
+            };
+            let ResetValue::Array(reset_bytes) = &reset_value.value else {
+                continue;
+            };
+
+            let field_set = search_object(manifest, &register.field_set_ref)
+                .expect("All fieldset refs should already be checked and valid here")
+                .as_field_set()
+                .expect("All fieldset refs should already be checked and valid here");
+
+            for field in &field_set.fields {
+                let Some(value_range) = field.value_range else {
+                    continue;
+                };
+                if removals.contains(&field.id_with(field_set.id())) {
+                    continue;
+                }
+
+                let (offsets, _) = get_repeat_iter(manifest, field);
+
+                for offset in offsets {
+                    let Some(field_value) = read_field_value(field_set, field, offset, reset_bytes)
+                    else {
+                        continue;
+                    };
+
+                    if !value_range.contains(field_value) {
+                        diagnostics.add(ResetValueOutOfRange {
+                            reset_value: reset_value.span,
+                            field_name: field.name.original().into(),
+                            field_value,
+                            range: value_range,
+                        });
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+/// Read the value of the field from the reset bytes. Bool fields are read as 0 or 1.
+/// Returns None if the field isn't an integer or bool or doesn't fit in the bytes.
+pub(super) fn read_field_value(
+    field_set: &FieldSet,
+    field: &Field,
+    offset: i128,
+    bytes: &[u8],
+) -> Option<i128> {
+    let signed = match field.base_type.value {
+        BaseType::FixedSize(integer) => integer.is_signed(),
+        BaseType::Bool => false,
+        _ => return None,
+    };
+
+    let size_bits = field.size_bits() as usize;
+
+    let mut raw = 0i128;
+    for (bit, field_bit) in field.field_bits().enumerate() {
+        let bit_index = usize::try_from(i128::from(field_bit) + offset).ok()?;
+        if bit_index >= bytes.len() * 8 {
+            return None;
+        }
+
+        let byte_index = match field_set.byte_order? {
+            ByteOrder::LE => bit_index / 8,
+            ByteOrder::BE => bytes.len() - bit_index / 8 - 1,
+        };
+
+        raw |= i128::from((bytes[byte_index] >> (bit_index % 8)) & 1) << bit;
+    }
+
+    if signed && (raw >> (size_bits - 1)) & 1 == 1 {
+        raw -= 1 << size_bits;
+    }
+
+    Some(match field.encoding {
+        Some(encoding) => encoding.decode(raw, size_bits as u32),
+        None => raw,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use device_driver_common::{
+        identifier::{Identifier, IdentifierRef},
+        span::SpanExt,
+        specifiers::{AddressRange, Encoding, Integer},
+    };
+
+    use crate::model::{Device, Register};
+
+    use super::*;
+
+    #[test]
+    fn ranges_correct() {
+        // The bcd field reads as 20 and is in range
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![
+                Object::Register(Register {
+                    name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                    field_set_ref: IdentifierRef::new("MyFs".into()).with_dummy_span(),
+                    reset_value: Some(ResetValue::Array(vec![10, 0x80, 0x20]).with_dummy_span()),
+                    ..Default::default()
+                }),
+                Object::FieldSet(FieldSet {
+                    name: Identifier::try_parse("MyFs").unwrap().with_dummy_span(),
+                    size_bytes: 3.with_dummy_span(),
+                    byte_order: Some(ByteOrder::LE),
+                    fields: vec![
+                        Field {
+                            name: Identifier::try_parse("low").unwrap().with_dummy_span(),
+                            base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                            field_address: AddressRange { start: 0, end: 7 }.with_dummy_span(),
+                            value_range: Some(
+                                ValueRange {
+                                    start: 10,
+                                    end: 200,
+                                }
+                                .with_dummy_span(),
+                            ),
+                            ..Default::default()
+                        },
+                        Field {
+                            name: Identifier::try_parse("high").unwrap().with_dummy_span(),
+                            base_type: BaseType::FixedSize(Integer::I8).with_dummy_span(),
+                            field_address: AddressRange { start: 8, end: 15 }.with_dummy_span(),
+                            value_range: Some(
+                                ValueRange {
+                                    start: -128,
+                                    end: 0,
+                                }
+                                .with_dummy_span(),
+                            ),
+                            ..Default::default()
+                        },
+                        Field {
+                            name: Identifier::try_parse("bcd").unwrap().with_dummy_span(),
+                            base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                            encoding: Some(Encoding::Bcd.with_dummy_span()),
+                            field_address: AddressRange { start: 16, end: 23 }.with_dummy_span(),
+                            value_range: Some(ValueRange { start: 10, end: 20 }.with_dummy_span()),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        let removals = FieldValueRangesChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(!diagnostics.has_error());
+        assert!(removals.is_empty());
+    }
+
+    #[test]
+    fn ranges_out_of_bounds() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![Object::FieldSet(FieldSet {
+                name: Identifier::try_parse("MyFs").unwrap().with_dummy_span(),
+                size_bytes: 4.with_dummy_span(),
+                byte_order: Some(ByteOrder::LE),
+                fields: vec![
+                    Field {
+                        name: Identifier::try_parse("too_big").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        field_address: AddressRange { start: 0, end: 7 }.with_dummy_span(),
+                        value_range: Some(
+                            ValueRange {
+                                start: 10,
+                                end: 256,
+                            }
+                            .with_dummy_span(),
+                        ),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("sign_magnitude")
+                            .unwrap()
+                            .with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::I8).with_dummy_span(),
+                        encoding: Some(Encoding::SignMagnitude.with_dummy_span()),
+                        field_address: AddressRange { start: 8, end: 15 }.with_dummy_span(),
+                        value_range: Some(
+                            ValueRange {
+                                start: -128,
+                                end: 0,
+                            }
+                            .with_dummy_span(),
+                        ),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("bcd").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        encoding: Some(Encoding::Bcd.with_dummy_span()),
+                        field_address: AddressRange { start: 16, end: 23 }.with_dummy_span(),
+                        value_range: Some(ValueRange { start: 0, end: 100 }.with_dummy_span()),
+                        ..Default::default()
+                    },
+                    Field {
+                        name: Identifier::try_parse("flag").unwrap().with_dummy_span(),
+                        base_type: BaseType::Bool.with_dummy_span(),
+                        field_address: AddressRange { start: 24, end: 24 }.with_dummy_span(),
+                        value_range: Some(ValueRange { start: 0, end: 1 }.with_dummy_span()),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        let removals = FieldValueRangesChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(diagnostics.has_error());
+        assert_eq!(removals.len(), 4);
+    }
+
+    #[test]
+    fn reset_value_out_of_range() {
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            objects: vec![
+                Object::Register(Register {
+                    name: Identifier::try_parse("MyReg").unwrap().with_dummy_span(),
+                    field_set_ref: IdentifierRef::new("MyFs".into()).with_dummy_span(),
+                    reset_value: Some(ResetValue::Array(vec![9]).with_dummy_span()),
+                    ..Default::default()
+                }),
+                Object::FieldSet(FieldSet {
+                    name: Identifier::try_parse("MyFs").unwrap().with_dummy_span(),
+                    size_bytes: 1.with_dummy_span(),
+                    byte_order: Some(ByteOrder::LE),
+                    fields: vec![Field {
+                        name: Identifier::try_parse("low").unwrap().with_dummy_span(),
+                        base_type: BaseType::FixedSize(Integer::U8).with_dummy_span(),
+                        field_address: AddressRange { start: 0, end: 7 }.with_dummy_span(),
+                        value_range: Some(
+                            ValueRange {
+                                start: 10,
+                                end: 200,
+                            }
+                            .with_dummy_span(),
+                        ),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        let removals = FieldValueRangesChecked::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(diagnostics.has_error());
+        assert!(removals.is_empty());
+    }
+}
@@ -0,0 +1,208 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use device_driver_common::{
+    span::Span,
+    specifiers::{Access, BaseType},
+};
+use device_driver_diagnostics::{Diagnostics, DynError, errors::InvalidFifo};
+use itertools::Itertools;
+
+use crate::{
+    model::{Fifo, Manifest, Object, Unique, UniqueId},
+    passes::{Assumption, Pass},
+    search_object,
+};
+
+/// Checks that the fill level of fifos points to a readable unsigned integer field of a register in the same block
+pub struct FifosChecked;
+
+impl Pass for FifosChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::NamesUnique,
+        Assumption::FieldsetRefsValid,
+        Assumption::AccessSet,
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::ChipVariantsPropagated,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let siblings = object.child_objects();
+
+            for fifo in siblings.iter().filter_map(|o| match o {
+                Object::Fifo(fifo) => Some(fifo),
+                _ => None,
+            }) {
+                if !check_fifo_level(manifest, siblings, fifo, diagnostics) {
+                    removals.insert(fifo.id());
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+/// Check the level register and field of the fifo. Returns false if there's an error.
+fn check_fifo_level(
+    manifest: &Manifest,
+    siblings: &[Object],
+    fifo: &Fifo,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let mut report = |problem: Span, pointee: Option<Span>, reason: Cow<'static, str>| {
+        diagnostics.add(InvalidFifo {
+            fifo: fifo.name.span,
+            problem,
+            pointee,
+            reason,
+        });
+        false
+    };
+
+    let (register_ref, field_ref) = match (&fifo.level_register, &fifo.level_field) {
+        (Some(register_ref), Some(field_ref)) => (register_ref, field_ref),
+        (Some(register_ref), None) => {
+            return report(
+                register_ref.span,
+                None,
+                "the field that holds the fill level must be given with `level-field`".into(),
+            );
+        }
+        (None, Some(field_ref)) => {
+            return report(
+                field_ref.span,
+                None,
+                "the register of the field must be given with `level-register`".into(),
+            );
+        }
+        (None, None) => {
+            return match fifo.level_unit {
+                Some(level_unit) => report(
+                    level_unit.span,
+                    None,
+                    "the fifo has no fill level. Set it with `level-register` and `level-field`"
+                        .into(),
+                ),
+                None => true,
+            };
+        }
+    };
+
+    let register = match siblings
+        .iter()
+        .find(|o| register_ref.is_ref_to(o.name()) && o.coexists_with(&fifo.chip_variants))
+    {
+        Some(Object::Register(register)) => register,
+        Some(object) => {
+            return report(
+                register_ref.span,
+                Some(object.name_span()),
+                "expected a register".into(),
+            );
+        }
+        None => {
+            return match search_object(manifest, register_ref) {
+                Some(object) => report(
+                    register_ref.span,
+                    Some(object.name_span()),
+                    "the register must be in the same block as the fifo".into(),
+                ),
+                None => report(
+                    register_ref.span,
+                    None,
+                    "no register found with this name".into(),
+                ),
+            };
+        }
+    };
+
+    if register.repeat.is_some() {
+        return report(
+            register_ref.span,
+            Some(register.name.span),
+            "repeated registers can't hold the fill level".into(),
+        );
+    }
+
+    if !register.access.unwrap_or(Access::RW).is_readable() {
+        return report(
+            register_ref.span,
+            Some(register.name.span),
+            "the register can't be read".into(),
+        );
+    }
+
+    if !register.chip_variants.is_empty()
+        && (fifo.chip_variants.is_empty()
+            || !fifo.chip_variants.iter().all(|variant| {
+                register
+                    .chip_variants
+                    .iter()
+                    .any(|v| v.value == variant.value)
+            }))
+    {
+        return report(
+            register_ref.span,
+            Some(register.name.span),
+            format!(
+                "the register only exists for the chip variants {}. Use `when` to make the fifo conditional on them",
+                register
+                    .chip_variants
+                    .iter()
+                    .map(|v| format!("`{}`", v.value))
+                    .join(", ")
+            )
+            .into(),
+        );
+    }
+
+    let Some(Object::FieldSet(field_set)) = search_object(manifest, &register.field_set_ref) else {
+        // Fieldset refs are already reported
+        return false;
+    };
+
+    let Some(field) = field_set
+        .fields
+        .iter()
+        .find(|f| field_ref.is_ref_to(&f.name))
+    else {
+        return report(
+            field_ref.span,
+            None,
+            format!(
+                "no field with this name in fieldset `{}`",
+                field_set.name.original()
+            )
+            .into(),
+        );
+    };
+
+    let problem = if field.repeat.is_some() {
+        Some("repeated fields can't hold the fill level")
+    } else if field.reserved.is_some() {
+        Some("reserved fields can't hold the fill level")
+    } else if field.field_conversion.is_some() {
+        Some("fields with a conversion can't hold the fill level")
+    } else if field.encoding.is_some() {
+        Some("fields with an encoding can't hold the fill level")
+    } else if !matches!(field.base_type.value, BaseType::FixedSize(integer) if !integer.is_signed())
+    {
+        Some("only unsigned integer fields can hold the fill level")
+    } else if !field.access.unwrap_or(Access::RW).is_readable() {
+        Some("the field can't be read")
+    } else {
+        None
+    };
+
+    match problem {
+        Some(problem) => report(field_ref.span, Some(field.name.span), problem.into()),
+        None => true,
+    }
+}
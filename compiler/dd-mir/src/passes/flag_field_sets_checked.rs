@@ -0,0 +1,121 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use convert_case::Case;
+use device_driver_common::specifiers::BaseType;
+use device_driver_diagnostics::{Diagnostics, DynError, errors::InvalidFlagField};
+
+use crate::{
+    model::{LendingIterator, Manifest, UniqueId},
+    passes::{Assumption, Pass},
+};
+
+/// The methods generated for a set of flags. Fields with these names would collide with them
+const FLAG_METHOD_NAMES: &[&str] = &[
+    "empty",
+    "all",
+    "is_empty",
+    "is_all",
+    "contains",
+    "intersects",
+    "insert",
+    "remove",
+    "toggle",
+    "iter",
+];
+
+/// Check the fieldsets that are generated as a set of flags. All fields must be plain single bits.
+/// If not, the fieldset is generated as a normal fieldset.
+pub struct FlagFieldSetsChecked;
+
+impl Pass for FlagFieldSetsChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[Assumption::FieldBaseTypesSpecified];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut iter = manifest.iter_objects_with_config_mut();
+        while let Some((object, _)) = iter.next() {
+            let Some(field_set) = object.as_field_set_mut() else {
+                continue;
+            };
+            let Some(flags) = field_set.flags else {
+                continue;
+            };
+
+            // The constants of the fields when the fieldset fits in a native integer
+            let generated_constants = field_set
+                .fields
+                .iter()
+                .flat_map(|field| {
+                    let name = field.name.to_case(Case::Constant);
+                    [format!("{name}_MASK"), format!("{name}_SHIFT")]
+                })
+                .collect::<HashSet<_>>();
+
+            let mut ok = true;
+
+            for field in &field_set.fields {
+                let mut report = |span, reason: Cow<'static, str>| {
+                    diagnostics.add(InvalidFlagField {
+                        field: span,
+                        flags,
+                        reason,
+                    });
+                    ok = false;
+                };
+
+                let field_name = field.name.to_case(Case::Snake);
+
+                if let Some(reserved) = field.reserved {
+                    report(reserved.span, "flags can't be reserved".into());
+                } else if field.repeat.is_some() {
+                    report(field.name.span, "flags can't be repeated".into());
+                } else if field.size_bits() != 1 {
+                    report(
+                        field.field_address_span(),
+                        format!(
+                            "flags must be one bit wide, but the field is {} bits",
+                            field.size_bits()
+                        )
+                        .into(),
+                    );
+                } else if !matches!(field.base_type.value, BaseType::Bool)
+                    && !matches!(field.base_type.value, BaseType::FixedSize(integer) if !integer.is_signed())
+                {
+                    report(
+                        field.base_type.span,
+                        "flags must have a bool or unsigned base type".into(),
+                    );
+                } else if let Some(conversion) = &field.field_conversion {
+                    report(
+                        conversion.type_name.span,
+                        "flags can't be converted to another type".into(),
+                    );
+                } else if let Some(encoding) = field.encoding {
+                    report(encoding.span, "flags can't have an encoding".into());
+                } else if FLAG_METHOD_NAMES.contains(&field_name.as_str()) {
+                    report(
+                        field.name.span,
+                        format!("the name collides with the generated `{field_name}` method of the flags")
+                            .into(),
+                    );
+                } else if generated_constants.contains(&field.name.to_case(Case::Constant)) {
+                    report(
+                        field.name.span,
+                        "the name of the flag constant collides with a mask or shift constant of another field"
+                            .into(),
+                    );
+                }
+            }
+
+            if !ok {
+                // To fix for further use, the fieldset is generated as a normal fieldset
+                field_set.flags = None;
+            }
+        }
+
+        Ok(Default::default())
+    }
+}
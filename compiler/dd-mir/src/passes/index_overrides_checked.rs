@@ -0,0 +1,191 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use device_driver_common::{
+    span::Span,
+    specifiers::{Access, RepeatSource},
+};
+use device_driver_diagnostics::{Diagnostics, DynError, errors::InvalidIndexOverride};
+
+use crate::{
+    model::{
+        IndexOverride, LendingIterator, Manifest, Object, Register, RepeatIndex, Unique, UniqueId,
+    },
+    passes::{Assumption, Pass},
+    search_object,
+};
+
+/// Checks that the index overrides of registers point to an index of the repeat of the register,
+/// that every index is overridden at most once and that the access of an override doesn't exceed the access of the register.
+///
+/// Invalid overrides are removed from the register.
+pub struct IndexOverridesChecked;
+
+impl Pass for IndexOverridesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::NamesUnique,
+        Assumption::RepeatEnumRefValid,
+        Assumption::AccessSet,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut bad_overrides = Vec::new();
+
+        for object in manifest.iter_objects() {
+            if let Object::Register(register) = object {
+                let mut seen_indices = Vec::new();
+
+                for (i, index_override) in register.index_overrides.iter().enumerate() {
+                    if !check_index_override(
+                        manifest,
+                        register,
+                        index_override,
+                        &mut seen_indices,
+                        diagnostics,
+                    ) {
+                        bad_overrides.push((register.id(), i));
+                    }
+                }
+            }
+        }
+
+        let mut iter = manifest.iter_objects_with_config_mut();
+        while let Some((object, _)) = iter.next() {
+            if let Object::Register(register) = object {
+                let id = register.id();
+                let mut i = 0;
+                register.index_overrides.retain(|_| {
+                    i += 1;
+                    !bad_overrides.contains(&(id.clone(), i - 1))
+                });
+            }
+        }
+
+        Ok(Default::default())
+    }
+}
+
+/// Check one override of the register. Returns false if there's an error.
+fn check_index_override(
+    manifest: &Manifest,
+    register: &Register,
+    index_override: &IndexOverride,
+    seen_indices: &mut Vec<(i128, Span)>,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let mut report = |problem: Span, pointee: Option<Span>, reason: Cow<'static, str>| {
+        diagnostics.add(InvalidIndexOverride {
+            index: index_override.index.span,
+            problem,
+            pointee,
+            reason,
+        });
+        false
+    };
+
+    let Some(repeat) = &register.repeat else {
+        return report(
+            index_override.index.span,
+            Some(register.name.span),
+            "the register isn't repeated".into(),
+        );
+    };
+
+    if repeat.inner.is_some() {
+        return report(
+            index_override.index.span,
+            Some(repeat.span),
+            "registers repeated in two dimensions can't have index overrides".into(),
+        );
+    }
+
+    let index = match (&repeat.source.value, &index_override.index.value) {
+        (RepeatSource::Count(_) | RepeatSource::Offsets(_), RepeatIndex::Number(index)) => {
+            let len = match &repeat.source.value {
+                RepeatSource::Count(count) => count.get() as i128,
+                RepeatSource::Offsets(offsets) => offsets.len() as i128,
+                RepeatSource::Enum(_) => unreachable!(),
+            };
+
+            if !(0..len).contains(index) {
+                return report(
+                    index_override.index.span,
+                    Some(repeat.source.span),
+                    format!("the index is out of range. The repeat has the indices `0..{len}`")
+                        .into(),
+                );
+            }
+
+            *index
+        }
+        (RepeatSource::Enum(enum_ref), RepeatIndex::Variant(variant)) => {
+            let Some(Object::Enum(enum_value)) = search_object(manifest, enum_ref) else {
+                // Reported by the repeat math pass
+                return false;
+            };
+
+            match enum_value
+                .iter_variants_with_discriminant()
+                .find(|(_, v)| variant.is_ref_to(&v.name))
+            {
+                Some((discriminant, _)) => discriminant,
+                None => {
+                    return report(
+                        index_override.index.span,
+                        Some(enum_value.name.span),
+                        format!(
+                            "no variant with this name in enum `{}`",
+                            enum_value.name.original()
+                        )
+                        .into(),
+                    );
+                }
+            }
+        }
+        (RepeatSource::Enum(_), RepeatIndex::Number(_)) => {
+            return report(
+                index_override.index.span,
+                Some(repeat.source.span),
+                "the register is repeated over an enum, so the index must be a variant".into(),
+            );
+        }
+        (RepeatSource::Count(_) | RepeatSource::Offsets(_), RepeatIndex::Variant(_)) => {
+            return report(
+                index_override.index.span,
+                Some(repeat.source.span),
+                "the register isn't repeated over an enum, so the index must be a number".into(),
+            );
+        }
+    };
+
+    if let Some((_, previous)) = seen_indices.iter().find(|(seen, _)| *seen == index) {
+        return report(
+            index_override.index.span,
+            Some(*previous),
+            "the index is already overridden".into(),
+        );
+    }
+    seen_indices.push((index, index_override.index.span));
+
+    let register_access = register.access.unwrap_or(Access::RW);
+    if let Some(access) = index_override.access
+        && ((access.is_readable() && !register_access.is_readable())
+            || (access.is_writable() && !register_access.is_writable()))
+    {
+        return report(
+            index_override
+                .properties_span
+                .unwrap_or(index_override.index.span),
+            Some(register.name.span),
+            format!(
+                "the access of the index can only be more limited than the access `{register_access}` of the register"
+            )
+            .into(),
+        );
+    }
+
+    true
+}
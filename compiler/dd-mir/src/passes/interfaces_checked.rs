@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use crate::{
+    model::{Manifest, Object, Unique, UniqueId},
+    passes::{Assumption, Pass, addresses_non_overlapping::find_object_addresses},
+};
+use device_driver_diagnostics::{
+    Diagnostics, DynError, ResultExt,
+    errors::{InterfaceAddressInvalid, InvalidInterfaceProperty},
+};
+
+/// Checks if the address bits of interfaces fit in the address that is sent
+/// and that the register addresses of the device can be sent by the interfaces
+pub struct InterfacesChecked;
+
+impl Pass for InterfacesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::RepeatStrideNonZero,
+        Assumption::RepeatEnumRefValid,
+        Assumption::NamesUnique,
+        Assumption::FieldsetRefsValid,
+        Assumption::ChipVariantsPropagated,
+        Assumption::AddressesAssigned,
+        Assumption::BlockRefsValid,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for (device, config) in manifest.iter_devices_with_config() {
+            // The interface only serves the registers, so only those addresses are sent with it
+            let mut register_addresses = None;
+
+            for object in &device.objects {
+                let Object::Interface(interface) = object else {
+                    continue;
+                };
+
+                let address_size_bits = interface
+                    .address_size_bytes
+                    .map(|size_bytes| size_bytes.value)
+                    .unwrap_or(1)
+                    * 8;
+
+                let mut flag_bits_valid = true;
+
+                for (property, bit) in [
+                    ("read-bit", interface.read_bit),
+                    ("write-bit", interface.write_bit),
+                    ("increment-bit", interface.increment_bit),
+                ] {
+                    if let Some(bit) = bit
+                        && bit.value >= address_size_bits
+                    {
+                        diagnostics.add(InvalidInterfaceProperty {
+                            interface_name: interface.name.span,
+                            property,
+                            value: bit.span,
+                            reason: format!(
+                                "the address is only {address_size_bits} bits long. Change `address-size-bytes` to make it bigger"
+                            )
+                            .into(),
+                        });
+                        removals.insert(interface.id());
+                        flag_bits_valid = false;
+                    }
+                }
+
+                // Without valid flag bits there's no point in checking the addresses against them
+                if !flag_bits_valid {
+                    continue;
+                }
+
+                let register_addresses = match &mut register_addresses {
+                    Some(register_addresses) => register_addresses,
+                    None => register_addresses.insert(
+                        find_object_addresses(manifest, device, &config, |o| {
+                            matches!(o, Object::Block(_) | Object::Register(_) | Object::Fifo(_))
+                        })
+                        .with_message(|| "finding register object addresses")?,
+                    ),
+                };
+
+                // Only report the first invalid address of an object so repeats don't flood the output
+                let mut reported_objects = HashSet::new();
+
+                for object_address in register_addresses.iter() {
+                    if reported_objects.contains(&object_address.id) {
+                        continue;
+                    }
+
+                    let address = object_address.address.value;
+
+                    let reason = if address < 0
+                        || (address_size_bits < 127 && address >= 1 << address_size_bits)
+                    {
+                        Some(format!(
+                            "this interface sends the address in {address_size_bits} bits"
+                        ))
+                    } else {
+                        [
+                            ("read-bit", interface.read_bit),
+                            ("write-bit", interface.write_bit),
+                            ("increment-bit", interface.increment_bit),
+                        ]
+                        .into_iter()
+                        .find_map(|(property, bit)| {
+                            bit.filter(|bit| {
+                                address.checked_shr(bit.value).is_some_and(|a| a & 1 != 0)
+                            })
+                            .map(|bit| {
+                                format!(
+                                    "this interface uses bit {} of the address as the {property}",
+                                    bit.value
+                                )
+                            })
+                        })
+                    };
+
+                    if let Some(reason) = reason {
+                        diagnostics.add(InterfaceAddressInvalid {
+                            interface_name: interface.name.span,
+                            address,
+                            object: object_address.id.span(),
+                            object_address: object_address.address.span,
+                            repeat_offset: object_address.repeat_offset,
+                            instance: object_address.instance,
+                            reason: reason.into(),
+                        });
+                        reported_objects.insert(object_address.id.clone());
+                        removals.insert(interface.id());
+                    }
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use crate::{
+    model::{Manifest, Object, Unique, UniqueId},
+    passes::{Assumption, Pass},
+};
+use device_driver_diagnostics::{Diagnostics, DynError, errors::InvalidMemoryProperty};
+
+/// Checks if the sizes of memories are multiples of each other, so every page and erase block holds whole writes
+pub struct MemoriesChecked;
+
+impl Pass for MemoriesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let Object::Memory(memory) = object else {
+                continue;
+            };
+
+            let word_size = memory.word_size_bytes();
+            let write_granularity = memory.write_granularity_bytes();
+            let page_size = memory.page_size_bytes.map(|size| size.value);
+            let erase_size = memory.erase_size_bytes.map(|size| size.value);
+
+            // The property that's checked, and the property and value it must be a multiple of
+            for (property, value, divisor_property, divisor) in [
+                (
+                    "size-bytes",
+                    Some(memory.size_bytes),
+                    "word-size-bytes",
+                    Some(word_size),
+                ),
+                (
+                    "write-granularity-bytes",
+                    memory.write_granularity_bytes,
+                    "word-size-bytes",
+                    Some(word_size),
+                ),
+                (
+                    "page-size-bytes",
+                    memory.page_size_bytes,
+                    "write-granularity-bytes",
+                    Some(write_granularity),
+                ),
+                (
+                    "size-bytes",
+                    Some(memory.size_bytes),
+                    "page-size-bytes",
+                    page_size,
+                ),
+                (
+                    "erase-size-bytes",
+                    memory.erase_size_bytes,
+                    "write-granularity-bytes",
+                    Some(write_granularity),
+                ),
+                (
+                    "size-bytes",
+                    Some(memory.size_bytes),
+                    "erase-size-bytes",
+                    erase_size,
+                ),
+            ] {
+                if let (Some(value), Some(divisor)) = (value, divisor)
+                    && !value.value.is_multiple_of(divisor)
+                {
+                    diagnostics.add(InvalidMemoryProperty {
+                        memory_name: memory.name.span,
+                        property,
+                        value: value.span,
+                        reason: format!(
+                            "value must be a multiple of `{divisor_property}` ({divisor})"
+                        )
+                        .into(),
+                    });
+                    removals.insert(memory.id());
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
@@ -5,17 +5,24 @@ use crate::{
     model::{Manifest, UniqueId},
     passes::{
         access_set::AccessSet, address_types_big_enough::AddressTypesBigEnough,
-        address_types_specified::AddressTypesSpecified,
+        address_types_specified::AddressTypesSpecified, addresses_assigned::AddressesAssigned,
         addresses_non_overlapping::AddressesNonOverlapping,
         base_types_specified::BaseTypesSpecified, bit_ranges_validated::BitRangesValidated,
-        bool_fields_checked::BoolFieldsChecked, byte_order_specified::ByteOrderSpecified,
-        device_configs_owned::DeviceConfigsOwned, device_name_is_pascal::DeviceNameIsPascal,
+        block_refs_valid::BlockRefsValid, bool_fields_checked::BoolFieldsChecked,
+        byte_order_specified::ByteOrderSpecified, chip_variants_checked::ChipVariantsChecked,
+        composites_checked::CompositesChecked, device_configs_owned::DeviceConfigsOwned,
+        device_name_is_pascal::DeviceNameIsPascal, encodings_checked::EncodingsChecked,
         enum_values_checked::EnumValuesChecked, extern_values_checked::ExternValuesChecked,
         field_conversion_valid::FieldConversionValid, field_set_refs_valid::FieldsetRefsValid,
-        names_checked::NamesChecked, names_unique::NamesUnique,
+        field_value_ranges_checked::FieldValueRangesChecked, fifos_checked::FifosChecked,
+        flag_field_sets_checked::FlagFieldSetsChecked,
+        index_overrides_checked::IndexOverridesChecked, interfaces_checked::InterfacesChecked,
+        memories_checked::MemoriesChecked, names_checked::NamesChecked, names_unique::NamesUnique,
         repeat_math_checked::RepeatMathChecked,
         repeat_zero_stride_rejected::RepeatZeroStrideRejected,
+        reserved_fields_checked::ReservedFieldsChecked,
         reserved_names_checked::ReservedNamesChecked, reset_values_converted::ResetValuesConverted,
+        routines_checked::RoutinesChecked, state_machines_checked::StateMachinesChecked,
     },
 };
 use device_driver_common::instant::Instant;
@@ -24,47 +31,75 @@ use device_driver_diagnostics::{Diagnostics, DynError, ResultExt};
 mod access_set;
 mod address_types_big_enough;
 mod address_types_specified;
+mod addresses_assigned;
 mod addresses_non_overlapping;
 mod base_types_specified;
 mod bit_ranges_validated;
+mod block_refs_valid;
 mod bool_fields_checked;
 mod byte_order_specified;
+mod chip_variants_checked;
+mod composites_checked;
 mod device_configs_owned;
 mod device_name_is_pascal;
+mod encodings_checked;
 mod enum_values_checked;
 mod extern_values_checked;
 mod field_conversion_valid;
 mod field_set_refs_valid;
+mod field_value_ranges_checked;
+mod fifos_checked;
+mod flag_field_sets_checked;
+mod index_overrides_checked;
+mod interfaces_checked;
+mod memories_checked;
 mod names_checked;
 mod names_unique;
 mod repeat_math_checked;
 mod repeat_zero_stride_rejected;
+mod reserved_fields_checked;
 mod reserved_names_checked;
 mod reset_values_converted;
+mod routines_checked;
+mod state_machines_checked;
 
 // TODO: Make const when possible in a future Rust version
-fn get_default_passes() -> [PassInfo; 20] {
+fn get_default_passes() -> [PassInfo; 34] {
     [
         PassInfo::get::<DeviceConfigsOwned>(),
         PassInfo::get::<EnumValuesChecked>(),
         PassInfo::get::<ExternValuesChecked>(),
+        PassInfo::get::<MemoriesChecked>(),
         PassInfo::get::<BaseTypesSpecified>(),
         PassInfo::get::<DeviceNameIsPascal>(),
         PassInfo::get::<NamesChecked>(),
         PassInfo::get::<AccessSet>(),
+        PassInfo::get::<ChipVariantsChecked>(),
         PassInfo::get::<NamesUnique>(),
         PassInfo::get::<FieldsetRefsValid>(),
+        PassInfo::get::<BlockRefsValid>(),
         PassInfo::get::<RepeatZeroStrideRejected>(),
         PassInfo::get::<RepeatMathChecked>(),
+        PassInfo::get::<AddressesAssigned>(),
+        PassInfo::get::<IndexOverridesChecked>(),
         PassInfo::get::<FieldConversionValid>(),
         PassInfo::get::<ByteOrderSpecified>(),
         PassInfo::get::<ResetValuesConverted>(),
         PassInfo::get::<BoolFieldsChecked>(),
+        PassInfo::get::<EncodingsChecked>(),
+        PassInfo::get::<FlagFieldSetsChecked>(),
         PassInfo::get::<BitRangesValidated>(),
+        PassInfo::get::<FieldValueRangesChecked>(),
+        PassInfo::get::<ReservedFieldsChecked>(),
         PassInfo::get::<AddressTypesSpecified>(),
         PassInfo::get::<AddressTypesBigEnough>(),
         PassInfo::get::<AddressesNonOverlapping>(),
+        PassInfo::get::<InterfacesChecked>(),
         PassInfo::get::<ReservedNamesChecked>(),
+        PassInfo::get::<RoutinesChecked>(),
+        PassInfo::get::<CompositesChecked>(),
+        PassInfo::get::<FifosChecked>(),
+        PassInfo::get::<StateMachinesChecked>(),
     ]
 }
 
@@ -128,6 +163,10 @@ pub(crate) enum Assumption {
     EnumsNotEmpty,
     RepeatMathChecked,
     AccessSet,
+    ResetValuesConverted,
+    ChipVariantsPropagated,
+    BlockRefsValid,
+    AddressesAssigned,
 
     _End, // Keep as the last element
 }
@@ -148,6 +187,10 @@ impl Assumption {
         Assumption::EnumsNotEmpty,
         Assumption::RepeatMathChecked,
         Assumption::AccessSet,
+        Assumption::ResetValuesConverted,
+        Assumption::ChipVariantsPropagated,
+        Assumption::BlockRefsValid,
+        Assumption::AddressesAssigned,
     ];
 
     const _ALL_ASSUMPTIONS_PRESENT_CHECK: () =
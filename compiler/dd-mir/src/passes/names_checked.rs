@@ -56,6 +56,54 @@ impl Pass for NamesChecked {
                     .retain(|field| !field_removals.contains(&field.id_with(field_set_id.clone())));
             }
 
+            if let Object::StateMachine(state_machine) = object {
+                // The states, transitions and operations are checked together since they're all part of the state machine
+                let mut valid = true;
+                for (span, name) in state_machine
+                    .states
+                    .iter_mut()
+                    .map(|state| (state.name.span, state.name.as_runtime_type_mut()))
+                    .chain(state_machine.transitions.iter_mut().map(|transition| {
+                        (transition.name.span, transition.name.as_runtime_type_mut())
+                    }))
+                    .chain(
+                        state_machine
+                            .allows
+                            .iter_mut()
+                            .map(|allow| (allow.name.span, allow.name.as_runtime_type_mut())),
+                    )
+                {
+                    if let Err(e) = name.apply_boundaries(boundaries).check_validity() {
+                        diagnostics.add(InvalidIdentifier::new(e, span));
+                        valid = false;
+                    }
+                }
+
+                if !valid {
+                    removals.insert(state_machine.id());
+                }
+            }
+
+            if let Object::Composite(composite) = object {
+                // The parts name the registers they're in
+                let mut valid = true;
+                for part in &mut composite.parts {
+                    if let Err(e) = part
+                        .name
+                        .as_runtime_type_mut()
+                        .apply_boundaries(boundaries)
+                        .check_validity()
+                    {
+                        diagnostics.add(InvalidIdentifier::new(e, part.name.span));
+                        valid = false;
+                    }
+                }
+
+                if !valid {
+                    removals.insert(composite.id());
+                }
+            }
+
             if let Object::Enum(enum_value) = object {
                 let mut variant_removals = HashSet::new();
                 let enum_id = enum_value.id();
@@ -1,9 +1,13 @@
 use std::{collections::HashSet, num::NonZeroU32};
 
 use crate::{
-    model::{LendingIterator, Manifest, Object, Unique, UniqueId},
+    model::{LendingIterator, Manifest, Object, Unique, UniqueId, chip_variants_exclusive},
     passes::{Assumption, Pass},
 };
+use device_driver_common::{
+    identifier::{Identifier, RuntimeType},
+    span::{Span, SpanExt, Spanned},
+};
 use device_driver_diagnostics::{Diagnostics, DynError, errors::DuplicateName};
 
 /// Checks if all names are unique to prevent later name collisions.
@@ -11,7 +15,8 @@ use device_driver_diagnostics::{Diagnostics, DynError, errors::DuplicateName};
 pub struct NamesUnique;
 
 impl Pass for NamesUnique {
-    const ASSUMPTIONS_MADE: &[Assumption] = &[Assumption::NamesValid];
+    const ASSUMPTIONS_MADE: &[Assumption] =
+        &[Assumption::NamesValid, Assumption::ChipVariantsPropagated];
     const ASSUMPTIONS_RELEASED: &[Assumption] = &[Assumption::NamesUnique];
 
     fn run_pass(
@@ -28,9 +33,41 @@ impl Pass for NamesUnique {
             NonZeroU32::new(duplicate_id).ok_or_else(|| DynError::new("got too many duplicates"))
         };
 
+        // The chip variants of all seen objects. Objects of mutually exclusive variants may have the same name
+        let mut seen_chip_variants: Vec<(UniqueId, Vec<Spanned<String>>)> = Vec::new();
+        let mut exclusive_id = 0u32;
+        let mut get_exclusive_id = || {
+            exclusive_id = exclusive_id.wrapping_add(1);
+            NonZeroU32::new(exclusive_id)
+                .ok_or_else(|| DynError::new("got too many objects of exclusive chip variants"))
+        };
+
+        // Register views generate methods with the name of their fieldset, which may not have been visited yet
+        let field_set_names: Vec<Identifier<RuntimeType>> = manifest
+            .iter_objects()
+            .filter_map(Object::as_field_set)
+            .map(|field_set| field_set.name.value.clone().to_runtime_type())
+            .collect();
+
+        let mut removals = HashSet::new();
+
         let mut iter = manifest.iter_objects_with_config_mut();
         while let Some((object, _)) = iter.next() {
-            if !seen_ids.insert(object.id()) {
+            let object_id = object.id();
+            let chip_variants = object.chip_variants().cloned().unwrap_or_default();
+            let exclusive_with_seen = seen_chip_variants
+                .iter()
+                .filter(|(id, _)| *id == object_id)
+                .all(|(_, seen_variants)| chip_variants_exclusive(seen_variants, &chip_variants));
+            seen_chip_variants.push((object_id.clone(), chip_variants.clone()));
+
+            let newly_seen = seen_ids.insert(object_id);
+            if !newly_seen && exclusive_with_seen {
+                // The name is shared with objects that never exist at the same time.
+                // It still gets its own id so later passes can't mistake it for one of the others
+                object.name_mut().set_exclusive_id(get_exclusive_id()?);
+                seen_ids.insert(object.id());
+            } else if !newly_seen {
                 let original = seen_ids.get(&object.id()).unwrap();
                 diagnostics.add(DuplicateName {
                     original: original.span(),
@@ -66,6 +103,104 @@ impl Pass for NamesUnique {
                 }
             }
 
+            if let Object::StateMachine(state_machine) = object {
+                let sm_id = state_machine.id();
+                for state in state_machine.states.iter_mut() {
+                    let state_id = state.id_with(sm_id.clone());
+                    if !seen_ids.insert(state_id.clone()) {
+                        let original = seen_ids.get(&state_id).unwrap();
+                        diagnostics.add(DuplicateName {
+                            original: original.span(),
+                            original_value: original.identifier().clone(),
+                            duplicate: state_id.span(),
+                            duplicate_value: state_id.identifier().clone(),
+                        });
+
+                        // Duplicate name found. Let's add to the name to make it unique again so it can contribute to later passes
+                        state.name.set_duplicate_id(get_duplicate_id()?);
+                        // We've also 'seen' this duplicate
+                        seen_ids.insert(state.id_with(sm_id.clone()));
+                    }
+                }
+
+                // Transitions are generated as methods, so they share the namespace with the other operations
+                for transition in state_machine.transitions.iter_mut() {
+                    if !seen_ids.insert(transition.id()) {
+                        let original = seen_ids.get(&transition.id()).unwrap();
+                        diagnostics.add(DuplicateName {
+                            original: original.span(),
+                            original_value: original.identifier().clone(),
+                            duplicate: transition.name.span,
+                            duplicate_value: transition.name.value.clone().to_runtime_type(),
+                        });
+
+                        // Duplicate name found. Let's add to the name to make it unique again so it can contribute to later passes
+                        transition.name.set_duplicate_id(get_duplicate_id()?);
+                        // We've also 'seen' this duplicate
+                        seen_ids.insert(transition.id());
+                    }
+                    // Transitions exist for all chip variants
+                    seen_chip_variants.push((transition.id(), Vec::new()));
+                }
+            }
+
+            if let Object::Register(register) = object {
+                // Views are generated as `<register>_as_<view>` methods, so they share the namespace with the other operations
+                let register_name = register.name.as_runtime_type();
+                let mut colliding_views = Vec::new();
+                for view in &register.views {
+                    let Some(view_name) = field_set_names.iter().find(|name| view.is_ref_to(name))
+                    else {
+                        continue;
+                    };
+                    let suffix: Vec<&str> = std::iter::once("as")
+                        .chain(view_name.words().iter().map(String::as_str))
+                        .collect();
+
+                    if !insert_generated_name(
+                        register_name.with_surrounding_words(&[], &suffix),
+                        view.span,
+                        &chip_variants,
+                        &mut seen_ids,
+                        &mut seen_chip_variants,
+                        diagnostics,
+                    ) {
+                        colliding_views.push(view.value.clone());
+                    }
+                }
+
+                // Don't generate the methods of the colliding views so they can't contribute to later errors
+                register
+                    .views
+                    .retain(|view| !colliding_views.contains(&view.value));
+            }
+
+            if let Object::Composite(composite) = object {
+                // Composites are read and written with generated methods, so they share the namespace with the other operations
+                let composite_name = composite.name.as_runtime_type();
+                let mut collides = false;
+                for (prefix, suffix) in [
+                    ("read", &[][..]),
+                    ("write", &[]),
+                    ("read", &["async"]),
+                    ("write", &["async"]),
+                ] {
+                    collides |= !insert_generated_name(
+                        composite_name.with_surrounding_words(&[prefix], suffix),
+                        composite.name.span,
+                        &chip_variants,
+                        &mut seen_ids,
+                        &mut seen_chip_variants,
+                        diagnostics,
+                    );
+                }
+
+                if collides {
+                    // Remove the composite so its methods aren't generated
+                    removals.insert(composite.id());
+                }
+            }
+
             if let Object::Enum(enum_value) = object {
                 let e_id = enum_value.id();
                 for variant in enum_value.variants.iter_mut() {
@@ -92,9 +227,45 @@ impl Pass for NamesUnique {
             }
         }
 
-        Ok(Default::default())
+        Ok(removals)
     }
 }
+
+/// Insert the name of a method that is generated for an object, like the read method of a composite.
+/// Reports a duplicate name and returns false if the name is already taken by an object that can exist at the same time.
+fn insert_generated_name(
+    name: Identifier<RuntimeType>,
+    span: Span,
+    chip_variants: &[Spanned<String>],
+    seen_ids: &mut EqSet<UniqueId>,
+    seen_chip_variants: &mut Vec<(UniqueId, Vec<Spanned<String>>)>,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let id = UniqueId::Object {
+        object_name: name.with_span(span),
+    };
+
+    let exclusive_with_seen = seen_chip_variants
+        .iter()
+        .filter(|(seen_id, _)| *seen_id == id)
+        .all(|(_, seen_variants)| chip_variants_exclusive(seen_variants, chip_variants));
+    seen_chip_variants.push((id.clone(), chip_variants.to_vec()));
+
+    if seen_ids.insert(id.clone()) || exclusive_with_seen {
+        return true;
+    }
+
+    let original = seen_ids.get(&id).unwrap();
+    diagnostics.add(DuplicateName {
+        original: original.span(),
+        original_value: original.identifier().clone(),
+        duplicate: span,
+        duplicate_value: id.identifier().clone(),
+    });
+
+    false
+}
+
 /// Similar to a hashset in API, but uses the [Eq] trait (and linear scan) instead of [Hash]
 #[derive(Debug)]
 struct EqSet<T: Eq> {
@@ -168,6 +339,45 @@ mod tests {
         assert!(diagnostics.has_error())
     }
 
+    #[test]
+    fn exclusive_object_names_have_own_id() {
+        let global_config = DeviceConfig {
+            name_word_boundaries: Some(Boundary::defaults_from("-")),
+            ..Default::default()
+        };
+
+        let mut start_mir = Device {
+            description: String::new(),
+            name: Identifier::try_parse("Device").unwrap().with_dummy_span(),
+            device_config: global_config,
+            objects: vec![
+                Object::Buffer(Buffer {
+                    name: Identifier::try_parse("MyBuffer").unwrap().with_dummy_span(),
+                    chip_variants: vec!["a".to_string().with_dummy_span()],
+                    ..Default::default()
+                }),
+                Object::Buffer(Buffer {
+                    name: Identifier::try_parse("MyBuffer").unwrap().with_dummy_span(),
+                    chip_variants: vec!["b".to_string().with_dummy_span()],
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        }
+        .into();
+
+        let mut diagnostics = Diagnostics::new();
+        NamesUnique::run_pass(&mut start_mir, &mut diagnostics).unwrap();
+        assert!(!diagnostics.has_error());
+
+        let Object::Device(device) = &start_mir.objects[0] else {
+            unreachable!()
+        };
+        assert_ne!(device.objects[0].id(), device.objects[1].id());
+        // The generated names stay the same
+        assert!(device.objects[1].name().duplicate_id().is_none());
+    }
+
     #[test]
     fn field_names_not_unique() {
         let global_config = DeviceConfig {
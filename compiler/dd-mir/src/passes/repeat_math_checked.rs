@@ -12,7 +12,10 @@ use crate::{
 };
 use device_driver_diagnostics::{
     Diagnostics, DynError,
-    errors::{ReferencedObjectDoesNotExist, RepeatEnumWithCatchAll, RepeatMathOverflow},
+    errors::{
+        ReferencedObjectDoesNotExist, RepeatEnumWithCatchAll, RepeatEnumWithValueRanges,
+        RepeatMathOverflow,
+    },
 };
 
 /// Checks if the enums referenced by repeats actually exist and that the enum is suitable to be used as a repeat source
@@ -60,6 +63,7 @@ impl Pass for RepeatMathChecked {
             {
                 repeat.source.value = RepeatSource::Count(NonZero::new(1).unwrap());
                 repeat.stride.value = 1;
+                repeat.inner = None;
             }
 
             if let Object::FieldSet(fs) = object {
@@ -99,6 +103,15 @@ fn repeat_is_ok(repeat: &Repeat, manifest: &Manifest, diagnostics: &mut Diagnost
                 return false;
             }
 
+            if let Some(variant) = enum_value.variants.iter().find(|v| v.value.is_values()) {
+                diagnostics.add(RepeatEnumWithValueRanges {
+                    repeat_enum: repeat.source.span,
+                    enum_name: enum_value.name.span,
+                    variant: variant.name.span,
+                });
+                return false;
+            }
+
             enum_value
                 .iter_variants_with_discriminant()
                 .map(|(discr, v)| (discr, v.span))
@@ -106,6 +119,11 @@ fn repeat_is_ok(repeat: &Repeat, manifest: &Manifest, diagnostics: &mut Diagnost
                 .expect("enums are not empty")
         }
         RepeatSource::Count(count) => ((count.get() - 1) as i128, repeat.source.span),
+        RepeatSource::Offsets(offsets) => offsets
+            .iter()
+            .map(|offset| (offset.value, offset.span))
+            .max_by_key(|(offset, _)| (*offset * repeat.stride.value).abs())
+            .expect("offset lists are not empty"),
     };
 
     if i32::try_from(biggest_raw_value * repeat.stride.value).is_err() {
@@ -119,7 +137,10 @@ fn repeat_is_ok(repeat: &Repeat, manifest: &Manifest, diagnostics: &mut Diagnost
         return false;
     }
 
-    true
+    repeat
+        .inner
+        .as_deref()
+        .is_none_or(|inner| repeat_is_ok(inner, manifest, diagnostics))
 }
 
 fn enum_catch_all(enum_value: &Enum) -> Option<Span> {
@@ -23,11 +23,16 @@ impl Pass for RepeatZeroStrideRejected {
                 continue;
             };
 
-            if repeat.stride == 0 {
-                diagnostics.add(ZeroStrideRepeat {
-                    stride: repeat.stride.span,
-                });
-                removals.insert(object.id());
+            for dimension in [Some(repeat), repeat.inner.as_deref()]
+                .into_iter()
+                .flatten()
+            {
+                if dimension.stride == 0 {
+                    diagnostics.add(ZeroStrideRepeat {
+                        stride: dimension.stride.span,
+                    });
+                    removals.insert(object.id());
+                }
             }
         }
 
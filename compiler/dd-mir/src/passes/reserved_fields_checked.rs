@@ -0,0 +1,137 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use device_driver_common::specifiers::{BaseType, ResetValue, ValueRange};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{InvalidReservedField, ResetValueWrongReserved},
+};
+
+use crate::{
+    model::{LendingIterator, Manifest, Object, UniqueId},
+    passes::{Assumption, Pass, field_value_ranges_checked::read_field_value},
+    search_object,
+};
+
+/// Checks the reserved fields. Their value must fit in the field and they can't be repeated or converted.
+/// The reset values of registers must have the value of the reserved fields.
+pub struct ReservedFieldsChecked;
+
+impl Pass for ReservedFieldsChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::FieldsetRefsValid,
+        Assumption::ByteOrderSpecified,
+        Assumption::ResetValuesConverted,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut iter = manifest.iter_objects_with_config_mut();
+        while let Some((object, _)) = iter.next() {
+            let Some(field_set) = object.as_field_set_mut() else {
+                continue;
+            };
+
+            for field in &mut field_set.fields {
+                let Some(reserved) = field.reserved else {
+                    continue;
+                };
+
+                let mut report = |problem, reason: Cow<'static, str>| {
+                    diagnostics.add(InvalidReservedField {
+                        reserved: reserved.span,
+                        problem,
+                        reason,
+                    });
+                };
+
+                let representable_range = match field.base_type.value {
+                    BaseType::Bool => Some(ValueRange { start: 0, end: 1 }),
+                    BaseType::FixedSize(integer) => Some(ValueRange::representable(
+                        integer,
+                        field.encoding.map(|encoding| encoding.value),
+                        field.size_bits(),
+                    )),
+                    _ => None,
+                };
+
+                let ok = if field.repeat.is_some() {
+                    report(field.name.span, "reserved fields can't be repeated".into());
+                    false
+                } else if let Some(conversion) = &field.field_conversion {
+                    report(
+                        conversion.type_name.span,
+                        "reserved fields can't be converted to another type".into(),
+                    );
+                    false
+                } else if let Some(representable_range) = representable_range {
+                    if representable_range.contains(reserved.value) {
+                        true
+                    } else {
+                        report(
+                            field.field_address_span(),
+                            format!(
+                                "the value {} doesn't fit in the field, which can hold `{representable_range}`",
+                                reserved.value
+                            )
+                            .into(),
+                        );
+                        false
+                    }
+                } else {
+                    report(
+                        field.base_type.span,
+                        "reserved fields must have an integer or bool base type".into(),
+                    );
+                    false
+                };
+
+                if !ok {
+                    // To fix for further use, the field is generated as a normal field
+                    field.reserved = None;
+                }
+            }
+        }
+
+        for object in manifest.iter_objects() {
+            let Object::Register(register) = object else {
+                continue;
+            };
+            let Some(reset_value) = &register.reset_value else {
+                continue;
+            };
+            let ResetValue::Array(reset_bytes) = &reset_value.value else {
+                continue;
+            };
+
+            let field_set = search_object(manifest, &register.field_set_ref)
+                .expect("All fieldset refs should already be checked and valid here")
+                .as_field_set()
+                .expect("All fieldset refs should already be checked and valid here");
+
+            for field in &field_set.fields {
+                let Some(reserved) = field.reserved else {
+                    continue;
+                };
+
+                let field_value = read_field_value(field_set, field, 0, reset_bytes);
+
+                if let Some(field_value) = field_value
+                    && field_value != reserved.value
+                {
+                    diagnostics.add(ResetValueWrongReserved {
+                        reset_value: reset_value.span,
+                        field_name: field.name.original().into(),
+                        field_value,
+                        reserved,
+                    });
+                }
+            }
+        }
+
+        Ok(Default::default())
+    }
+}
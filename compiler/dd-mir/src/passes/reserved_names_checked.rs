@@ -1,10 +1,14 @@
 use std::collections::{HashMap, HashSet};
 
 use convert_case::Case;
-use device_driver_common::{identifier::RuntimeType, specifiers::Access};
+use device_driver_common::{
+    identifier::{Identifier, RuntimeType},
+    span::Span,
+    specifiers::Access,
+};
 use device_driver_diagnostics::{
     Diagnostics, DynError, ResultExt,
-    errors::{FieldSetterNameCollision, ReservedOperationNameUsed},
+    errors::{FieldSetterNameCollision, ReservedModuleNameUsed, ReservedOperationNameUsed},
 };
 
 use crate::{
@@ -29,12 +33,37 @@ impl Pass for ReservedNamesChecked {
         let mut iter = manifest.iter_objects_with_config_mut();
         while let Some((object, _)) = iter.next() {
             let new_removals = match object {
+                Object::Device(_) | Object::Block(_) | Object::StateMachine(_)
+                    if check_reserved_module_name(
+                        object.name(),
+                        object.name_span(),
+                        diagnostics,
+                    ) =>
+                {
+                    HashSet::from([object.id()])
+                }
                 Object::Device(device) => {
                     check_block_reserved_names(device.iter_objects(), diagnostics)
                 }
                 Object::Block(block) => {
                     check_block_reserved_names(block.iter_objects(), diagnostics)
                 }
+                Object::StateMachine(state_machine) => {
+                    // Transitions are methods on the state machine wrapper, next to `new` and `free`
+                    let mut reserved = false;
+                    for transition in &state_machine.transitions {
+                        reserved |= check_reserved_operation_name(
+                            transition.name.as_runtime_type(),
+                            transition.name.span,
+                            diagnostics,
+                        );
+                    }
+                    if reserved {
+                        HashSet::from([state_machine.id()])
+                    } else {
+                        HashSet::new()
+                    }
+                }
                 Object::FieldSet(field_set) => {
                     check_field_names(field_set, diagnostics).with_message(|| {
                         format!("checking field names of {}", field_set.name.original())
@@ -56,29 +85,64 @@ fn check_block_reserved_names<'a>(
 ) -> HashSet<UniqueId> {
     let mut removals = HashSet::new();
 
-    const RESERVED_NAMES: &[&str] = &["new", "init", "deinit", "free"];
-
     for object in objects {
-        let object_operation_name = object.name().to_case(convert_case::Case::Snake);
-
-        if object
-            .name()
-            .id_type()
-            .shares_namespace_with(RuntimeType::Operation)
-            && RESERVED_NAMES.contains(&object_operation_name.as_str())
-        {
+        if check_reserved_operation_name(object.name(), object.name_span(), diagnostics) {
             removals.insert(object.id());
-            diagnostics.add(ReservedOperationNameUsed {
-                name: object.name_span(),
-                operation_name: object_operation_name,
-                reserved_names: RESERVED_NAMES,
-            });
         }
     }
 
     removals
 }
 
+/// Check if the name is an operation with a reserved name. Returns true if it is.
+fn check_reserved_operation_name(
+    name: &Identifier<RuntimeType>,
+    span: Span,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    const RESERVED_NAMES: &[&str] = &["new", "init", "deinit", "free"];
+
+    let operation_name = name.to_case(convert_case::Case::Snake);
+
+    if name.id_type().shares_namespace_with(RuntimeType::Operation)
+        && RESERVED_NAMES.contains(&operation_name.as_str())
+    {
+        diagnostics.add(ReservedOperationNameUsed {
+            name: span,
+            operation_name,
+            reserved_names: RESERVED_NAMES,
+        });
+        true
+    } else {
+        false
+    }
+}
+
+/// Check if the name generates a module with a reserved name. Returns true if it does.
+///
+/// Devices, blocks and state machines generate a module at the top level of the output.
+/// The `defmt` macros refer to their crate with a relative path, so a module with the same name would shadow it.
+fn check_reserved_module_name(
+    name: &Identifier<RuntimeType>,
+    span: Span,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    const RESERVED_NAMES: &[&str] = &["defmt"];
+
+    let module_name = name.to_case(convert_case::Case::Snake);
+
+    if RESERVED_NAMES.contains(&module_name.as_str()) {
+        diagnostics.add(ReservedModuleNameUsed {
+            name: span,
+            module_name,
+            reserved_names: RESERVED_NAMES,
+        });
+        true
+    } else {
+        false
+    }
+}
+
 fn check_field_names(
     field_set: &mut FieldSet,
     diagnostics: &mut Diagnostics,
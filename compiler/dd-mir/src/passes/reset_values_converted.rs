@@ -1,23 +1,35 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
 
 use device_driver_common::{
     span::{Span, SpanExt, Spanned},
-    specifiers::{ByteOrder, ResetValue},
+    specifiers::{BaseType, ByteOrder, ResetValue, ValueRange},
 };
 
 use crate::{
-    model::{FieldSet, LendingIterator, Manifest, Object, Register, Unique, UniqueId},
-    passes::{Assumption, Pass},
+    model::{
+        Field, FieldSet, FieldValue, LendingIterator, Manifest, Object, Register, Unique, UniqueId,
+    },
+    passes::{Assumption, Pass, bit_ranges_validated::get_repeat_iter},
     search_object,
 };
 use device_driver_diagnostics::{
     Diagnostics, DynError,
-    errors::{ResetValueArrayWrongSize, ResetValueIntTooBig},
+    errors::{
+        InvalidFieldReset, ResetValueArrayWrongSize, ResetValueConflict, ResetValueIntTooBig,
+    },
 };
 
 /// Checks if the reset values of registers are valid.
 /// Also converts integer values to the array representation using the correct bit and byte order.
 ///
+/// The reset values of fields are assembled into the reset value of the registers that don't specify one.
+/// Reserved fields without a reset value get their reserved value.
+/// Registers that do specify one must agree with the reset values of the fields.
+/// The reset values of index overrides are converted like the one of their register.
+///
 /// For the array representation, the rule is that the input must have the same spec as the byte order.
 /// The reset values are left with the specified bit order and byte order.
 ///
@@ -30,18 +42,58 @@ impl Pass for ResetValuesConverted {
         Assumption::FieldsetRefsValid,
         Assumption::ByteOrderSpecified,
         Assumption::NamesUnique,
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::EnumsNotEmpty,
+        Assumption::RepeatStrideNonZero,
+        Assumption::RepeatEnumRefValid,
     ];
-    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[Assumption::ResetValuesConverted];
 
     fn run_pass(
         manifest: &mut Manifest,
         diagnostics: &mut Diagnostics,
     ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut field_resets = HashMap::new();
+
+        for object in manifest.iter_objects() {
+            if let Object::FieldSet(field_set) = object {
+                let resets = get_field_resets(manifest, field_set, diagnostics);
+                if !resets.is_empty() {
+                    field_resets.insert(field_set.id(), resets);
+                }
+            }
+        }
+
         let mut new_reset_values = HashMap::new();
+        let mut reset_from_fields = HashSet::new();
+        let mut new_override_resets = HashMap::new();
 
         for object in manifest.iter_objects() {
             if let Object::Register(reg) = object {
                 let target_field_set = get_target_field_set(reg, manifest);
+                let target_field_resets = field_resets
+                    .get(&target_field_set.id())
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+
+                if !reg.index_overrides.is_empty() {
+                    let override_resets = reg
+                        .index_overrides
+                        .iter()
+                        .map(|index_override| {
+                            index_override.reset_value.clone().and_then(|reset_value| {
+                                convert_reset_value(
+                                    reset_value,
+                                    target_field_set.size_bytes.value,
+                                    target_field_set.byte_order.unwrap(),
+                                    diagnostics,
+                                    reg.name.span,
+                                )
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    new_override_resets.insert(reg.id(), override_resets);
+                }
 
                 if let Some(reset_value) = reg.reset_value.as_ref() {
                     let new_reset_value = convert_reset_value(
@@ -51,21 +103,58 @@ impl Pass for ResetValuesConverted {
                         diagnostics,
                         reg.name.span,
                     );
+
+                    if let Some(new_reset_value) = &new_reset_value {
+                        check_field_resets(
+                            target_field_set,
+                            target_field_resets,
+                            new_reset_value,
+                            diagnostics,
+                        );
+                    }
+
                     assert_eq!(
                         new_reset_values.insert(reg.id(), new_reset_value),
                         None,
                         "All names must be unique"
                     );
+                } else if target_field_resets
+                    .iter()
+                    .any(|field_reset| !field_reset.reserved)
+                {
+                    let mut bytes = vec![0; target_field_set.size_bytes.value as usize];
+                    for field_reset in target_field_resets {
+                        field_reset.write(target_field_set, &mut bytes);
+                    }
+
+                    assert_eq!(
+                        new_reset_values.insert(
+                            reg.id(),
+                            Some(ResetValue::Array(bytes).with_span(reg.name.span))
+                        ),
+                        None,
+                        "All names must be unique"
+                    );
+                    reset_from_fields.insert(reg.id());
                 }
             }
         }
 
         let mut iter = manifest.iter_objects_with_config_mut();
         while let Some((object, _)) = iter.next() {
-            if let Object::Register(register) = object
-                && let Some(new_reset_value) = new_reset_values.remove(&register.id())
-            {
-                register.reset_value = new_reset_value;
+            if let Object::Register(register) = object {
+                if let Some(new_reset_value) = new_reset_values.remove(&register.id()) {
+                    register.reset_value = new_reset_value;
+                    register.reset_from_fields = reset_from_fields.contains(&register.id());
+                }
+
+                if let Some(override_resets) = new_override_resets.remove(&register.id()) {
+                    for (index_override, reset_value) in
+                        register.index_overrides.iter_mut().zip(override_resets)
+                    {
+                        index_override.reset_value = reset_value;
+                    }
+                }
             }
         }
 
@@ -75,6 +164,255 @@ impl Pass for ResetValuesConverted {
     }
 }
 
+/// The raw bits of the reset value of a field
+struct FieldReset {
+    name: String,
+    span: Span,
+    raw: i128,
+    /// The bit positions of the field, from the least to the most significant bit
+    bits: Vec<u32>,
+    offsets: Vec<i128>,
+    /// The value comes from the `reserved` property of a field without a reset value
+    reserved: bool,
+}
+
+impl FieldReset {
+    /// The bit positions of every repetition of the field. None if the field doesn't fit in the bytes.
+    fn repetitions(&self, bytes: &[u8]) -> Option<Vec<Vec<usize>>> {
+        self.offsets
+            .iter()
+            .map(|offset| {
+                self.bits
+                    .iter()
+                    .map(|bit| {
+                        let bit = usize::try_from(i128::from(*bit) + offset).ok()?;
+                        (bit < bytes.len() * 8).then_some(bit)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn write(&self, field_set: &FieldSet, bytes: &mut [u8]) {
+        // Fields that don't fit are reported by another pass
+        let Some(repetitions) = self.repetitions(bytes) else {
+            return;
+        };
+
+        for bits in repetitions {
+            for (value_bit, bit) in bits.into_iter().enumerate() {
+                let (byte_index, bit_index) = bit_position(field_set, bytes.len(), bit);
+                if (self.raw >> value_bit) & 1 == 1 {
+                    bytes[byte_index] |= 1 << bit_index;
+                } else {
+                    bytes[byte_index] &= !(1 << bit_index);
+                }
+            }
+        }
+    }
+
+    /// Read the raw bits of the first repetition of the field that differs from the reset value of the field
+    fn read_conflicting(&self, field_set: &FieldSet, bytes: &[u8]) -> Option<i128> {
+        for bits in self.repetitions(bytes)? {
+            let mut raw = 0;
+            for (value_bit, bit) in bits.into_iter().enumerate() {
+                let (byte_index, bit_index) = bit_position(field_set, bytes.len(), bit);
+                raw |= i128::from((bytes[byte_index] >> bit_index) & 1) << value_bit;
+            }
+
+            if raw != self.raw {
+                return Some(raw);
+            }
+        }
+
+        None
+    }
+}
+
+/// The byte and bit index of a bit of the fieldset
+fn bit_position(field_set: &FieldSet, size_bytes: usize, bit: usize) -> (usize, usize) {
+    let byte_index = match field_set.byte_order.unwrap() {
+        ByteOrder::LE => bit / 8,
+        ByteOrder::BE => size_bytes - bit / 8 - 1,
+    };
+    (byte_index, bit % 8)
+}
+
+/// Get the raw reset values of the fields of the fieldset. Invalid reset values are reported and skipped.
+fn get_field_resets(
+    manifest: &Manifest,
+    field_set: &FieldSet,
+    diagnostics: &mut Diagnostics,
+) -> Vec<FieldReset> {
+    field_set
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let Some(reset_value) = field.reset_value.as_ref() else {
+                // Reserved fields reset to their reserved value. Invalid values are reported by the reserved fields pass
+                let reserved = field.reserved?;
+                let raw = get_field_reset_raw(manifest, field, &FieldValue::Number(reserved.value))
+                    .ok()?;
+
+                return Some(FieldReset {
+                    name: field.name.original().into(),
+                    span: reserved.span,
+                    raw,
+                    bits: field.field_bits().collect(),
+                    offsets: get_repeat_iter(manifest, field).0,
+                    reserved: true,
+                });
+            };
+
+            match get_field_reset_raw(manifest, field, &reset_value.value) {
+                Ok(raw) => Some(FieldReset {
+                    name: field.name.original().into(),
+                    span: reset_value.span,
+                    raw,
+                    bits: field.field_bits().collect(),
+                    offsets: get_repeat_iter(manifest, field).0,
+                    reserved: false,
+                }),
+                Err((context, reason)) => {
+                    diagnostics.add(InvalidFieldReset {
+                        reset: reset_value.span,
+                        context,
+                        reason,
+                    });
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Get the raw bits of the reset value of the field, or the error with an optional context span
+fn get_field_reset_raw(
+    manifest: &Manifest,
+    field: &Field,
+    reset_value: &FieldValue,
+) -> Result<i128, (Option<Span>, Cow<'static, str>)> {
+    let value = match reset_value {
+        FieldValue::Number(value) => *value,
+        FieldValue::Variant(variant) => {
+            let Some(conversion) = &field.field_conversion else {
+                return Err((
+                    Some(field.name.span),
+                    "the field isn't converted to an enum, so the reset value must be a number"
+                        .into(),
+                ));
+            };
+
+            let Some(Object::Enum(enum_value)) = search_object(manifest, &conversion.type_name)
+            else {
+                return Err((
+                    Some(conversion.type_name.span),
+                    "the field isn't converted to an enum, so the reset value must be a number"
+                        .into(),
+                ));
+            };
+
+            match enum_value
+                .iter_variants_with_discriminant()
+                .find(|(_, v)| variant.is_ref_to(&v.name))
+            {
+                Some((_, v)) if v.value.is_catch_all() => {
+                    return Err((
+                        Some(v.name.span),
+                        "the catch-all variant can't be used as a reset value".into(),
+                    ));
+                }
+                Some((_, v)) if v.value.is_values() => {
+                    return Err((
+                        Some(v.name.span),
+                        "variants covering multiple values can't be used as a reset value".into(),
+                    ));
+                }
+                Some((discriminant, _)) => discriminant,
+                None => {
+                    return Err((
+                        Some(enum_value.name.span),
+                        format!(
+                            "no variant with this name in enum `{}`",
+                            enum_value.name.original()
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+    };
+
+    let size_bits = field.size_bits() as u32;
+    let representable_range = match field.base_type.value {
+        BaseType::Bool => ValueRange { start: 0, end: 1 },
+        BaseType::FixedSize(integer) => ValueRange::representable(
+            integer,
+            field.encoding.map(|encoding| encoding.value),
+            field.size_bits(),
+        ),
+        BaseType::Bytes | BaseType::Ascii => {
+            return Err((
+                Some(field.base_type.span),
+                "byte array fields can't have a reset value. Use the reset value of the register"
+                    .into(),
+            ));
+        }
+        // Reported by another pass
+        BaseType::Unspecified | BaseType::Uint | BaseType::Int => {
+            return Err((None, "the base type of the field is unknown".into()));
+        }
+    };
+
+    if !representable_range.contains(value) {
+        return Err((
+            Some(field.field_address_span()),
+            format!(
+                "the value {value} doesn't fit in the field, which can hold `{representable_range}`"
+            )
+            .into(),
+        ));
+    }
+
+    let raw = match field.encoding {
+        Some(encoding) => encoding.encode(value, size_bits),
+        None => value,
+    };
+
+    Ok(match size_bits {
+        128.. => raw,
+        _ => raw & ((1 << size_bits) - 1),
+    })
+}
+
+/// Check that the reset value of the register agrees with the reset values of the fields
+fn check_field_resets(
+    field_set: &FieldSet,
+    field_resets: &[FieldReset],
+    reset_value: &Spanned<ResetValue>,
+    diagnostics: &mut Diagnostics,
+) {
+    let ResetValue::Array(bytes) = &reset_value.value else {
+        return;
+    };
+
+    // Reserved values are checked by the reserved fields pass
+    for field_reset in field_resets
+        .iter()
+        .filter(|field_reset| !field_reset.reserved)
+    {
+        if let Some(register_bits) = field_reset.read_conflicting(field_set, bytes) {
+            diagnostics.add(ResetValueConflict {
+                reset_value: reset_value.span,
+                field_reset: field_reset.span,
+                field_name: field_reset.name.clone(),
+                register_bits,
+                field_bits: field_reset.raw,
+            });
+        }
+    }
+}
+
 fn get_target_field_set<'m>(reg: &Register, manifest: &'m Manifest) -> &'m FieldSet {
     search_object(manifest, &reg.field_set_ref)
         .expect("All fieldset refs should already be checked and valid here")
@@ -0,0 +1,349 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use device_driver_common::{
+    span::Spanned,
+    specifiers::{Access, BaseType, ValueRange},
+};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{InvalidRoutineField, InvalidRoutineTarget},
+};
+use itertools::Itertools;
+
+use crate::{
+    model::{
+        FieldAssignment, FieldSet, FieldValue, Manifest, Object, Statement, TargetStatement,
+        Unique, UniqueId,
+    },
+    passes::{Assumption, Pass},
+    search_object,
+};
+
+/// Checks that the statements of routines point to registers and commands in the same block,
+/// that those can be used the way the statement uses them and that the field values are valid.
+pub struct RoutinesChecked;
+
+impl Pass for RoutinesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::NamesUnique,
+        Assumption::FieldsetRefsValid,
+        Assumption::AccessSet,
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::ChipVariantsPropagated,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let siblings = object.child_objects();
+
+            for routine in siblings.iter().filter_map(|o| match o {
+                Object::Routine(routine) => Some(routine),
+                _ => None,
+            }) {
+                let mut error = false;
+
+                for statement in &routine.statements {
+                    if let Some(target_statement) = statement.as_target_statement() {
+                        error |= !check_statement(
+                            manifest,
+                            siblings,
+                            Some(&routine.chip_variants),
+                            statement.keyword(),
+                            target_statement,
+                            matches!(statement, Statement::Dispatch(_)),
+                            diagnostics,
+                        );
+                    }
+                }
+
+                if error {
+                    removals.insert(routine.id());
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+/// Check a statement that targets a register or a command. Returns false if there's an error.
+///
+/// The chip variants are the variants of the routine. They're None if the statement can't be conditional.
+pub(super) fn check_statement(
+    manifest: &Manifest,
+    siblings: &[Object],
+    chip_variants: Option<&[Spanned<String>]>,
+    keyword: &'static str,
+    statement: &TargetStatement,
+    expects_command: bool,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let mut report = |pointee, reason: Cow<'static, str>| {
+        diagnostics.add(InvalidRoutineTarget {
+            statement: keyword,
+            target: statement.target.span,
+            pointee,
+            reason,
+        });
+        false
+    };
+
+    let Some(target) = siblings.iter().find(|o| {
+        statement.target.is_ref_to(o.name()) && o.coexists_with(chip_variants.unwrap_or_default())
+    }) else {
+        return match search_object(manifest, &statement.target) {
+            Some(target) => report(
+                Some(target.name_span()),
+                "the target must be in the same block as the routine".into(),
+            ),
+            None => report(None, "no register or command found with this name".into()),
+        };
+    };
+
+    if target.repeat().is_some() {
+        return report(
+            Some(target.name_span()),
+            "repeated objects can't be used in routines".into(),
+        );
+    }
+
+    if let Some(target_variants) = target.chip_variants()
+        && !target_variants.is_empty()
+    {
+        let target_variants_list = target_variants
+            .iter()
+            .map(|v| format!("`{}`", v.value))
+            .join(", ");
+
+        match chip_variants {
+            None => {
+                return report(
+                    Some(target.name_span()),
+                    format!(
+                        "the target only exists for the chip variants {target_variants_list}, so it can't be used here"
+                    )
+                    .into(),
+                );
+            }
+            Some(chip_variants)
+                if chip_variants.is_empty()
+                    || !chip_variants.iter().all(|variant| {
+                        target_variants.iter().any(|v| v.value == variant.value)
+                    }) =>
+            {
+                return report(
+                    Some(target.name_span()),
+                    format!(
+                        "the target only exists for the chip variants {target_variants_list}. Use `when` to make the routine conditional on them"
+                    )
+                    .into(),
+                );
+            }
+            Some(_) => {}
+        }
+    }
+
+    let field_set_ref = match (target, expects_command) {
+        (Object::Register(register), false) => {
+            let access = register.access.unwrap_or(Access::RW);
+            let access_ok = match keyword {
+                "write" => access.is_writable(),
+                "modify" => access == Access::RW,
+                _ => access.is_readable(),
+            };
+
+            if !access_ok {
+                return report(
+                    Some(target.name_span()),
+                    format!("`{keyword}` can't be used on a register with access `{access}`")
+                        .into(),
+                );
+            }
+
+            Some(&register.field_set_ref)
+        }
+        (Object::Command(command), true) => {
+            if command.field_set_ref_in.is_none()
+                && let Some(field) = statement.fields.first()
+            {
+                diagnostics.add(InvalidRoutineField {
+                    field: field.field.span,
+                    field_definition: None,
+                    reason: "the command has no input fields".into(),
+                });
+                return false;
+            }
+
+            command.field_set_ref_in.as_ref()
+        }
+        (_, false) => return report(Some(target.name_span()), "expected a register".into()),
+        (_, true) => return report(Some(target.name_span()), "expected a command".into()),
+    };
+
+    let Some(Object::FieldSet(field_set)) =
+        field_set_ref.and_then(|field_set_ref| search_object(manifest, field_set_ref))
+    else {
+        // Commands without input and fieldset refs that are already reported
+        return true;
+    };
+
+    let mut ok = true;
+    for field_assignment in &statement.fields {
+        ok &= check_field(manifest, field_set, keyword, field_assignment, diagnostics);
+    }
+    ok
+}
+
+/// Check that the field exists and can be set to or compared with the value. Returns false if there's an error.
+fn check_field(
+    manifest: &Manifest,
+    field_set: &FieldSet,
+    keyword: &'static str,
+    field_assignment: &FieldAssignment,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let Some(field) = field_set
+        .fields
+        .iter()
+        .find(|f| field_assignment.field.is_ref_to(&f.name))
+    else {
+        diagnostics.add(InvalidRoutineField {
+            field: field_assignment.field.span,
+            field_definition: None,
+            reason: format!(
+                "no field with this name in fieldset `{}`",
+                field_set.name.original()
+            )
+            .into(),
+        });
+        return false;
+    };
+
+    let mut report = |span, reason: Cow<'static, str>| {
+        diagnostics.add(InvalidRoutineField {
+            field: span,
+            field_definition: Some(field.name.span),
+            reason,
+        });
+        false
+    };
+
+    if field.repeat.is_some() {
+        return report(
+            field_assignment.field.span,
+            "repeated fields can't be used in routines".into(),
+        );
+    }
+
+    let access = field.access.unwrap_or(Access::RW);
+    let access_ok = match keyword {
+        "poll" => access.is_readable(),
+        _ => access.is_writable(),
+    };
+    if !access_ok {
+        return report(
+            field_assignment.field.span,
+            format!("`{keyword}` can't be used on a field with access `{access}`").into(),
+        );
+    }
+
+    if keyword != "poll" && field.reserved.is_some() {
+        return report(
+            field_assignment.field.span,
+            "reserved fields can't be set, they always have their reserved value".into(),
+        );
+    }
+
+    let value_span = field_assignment.value.span;
+
+    if let Some(conversion) = &field.field_conversion {
+        return match search_object(manifest, &conversion.type_name) {
+            Some(Object::Enum(enum_value)) => match &field_assignment.value.value {
+                FieldValue::Variant(variant) => {
+                    match enum_value
+                        .variants
+                        .iter()
+                        .find(|v| variant.is_ref_to(&v.name))
+                    {
+                        Some(v) if v.value.is_catch_all() => report(
+                            value_span,
+                            "the catch-all variant can't be used as a value".into(),
+                        ),
+                        Some(v) if v.value.is_values() => report(
+                            value_span,
+                            "variants covering multiple values can't be used as a value".into(),
+                        ),
+                        Some(_) => true,
+                        None => report(
+                            value_span,
+                            format!(
+                                "no variant with this name in enum `{}`",
+                                enum_value.name.original()
+                            )
+                            .into(),
+                        ),
+                    }
+                }
+                FieldValue::Number(_) => report(
+                    value_span,
+                    format!(
+                        "expected the name of a variant of enum `{}`",
+                        enum_value.name.original()
+                    )
+                    .into(),
+                ),
+            },
+            Some(Object::Extern(_)) => report(
+                field_assignment.field.span,
+                "fields that are converted to an extern can't be used in routines".into(),
+            ),
+            // Invalid conversions are reported by another pass
+            _ => true,
+        };
+    }
+
+    let FieldValue::Number(value) = field_assignment.value.value else {
+        return report(value_span, "expected a number".into());
+    };
+
+    match field.base_type.value {
+        BaseType::Bool => {
+            if !(0..=1).contains(&value) {
+                return report(value_span, "expected 0 or 1 for a bool field".into());
+            }
+        }
+        BaseType::FixedSize(integer) => {
+            let mut range = ValueRange::representable(
+                integer,
+                field.encoding.map(|encoding| encoding.value),
+                field.size_bits(),
+            );
+            if let Some(value_range) = field.value_range {
+                range = range.intersection(&value_range);
+            }
+
+            if !range.contains(value) {
+                return report(
+                    value_span,
+                    format!("the value is out of the range `{range}` of the field").into(),
+                );
+            }
+        }
+        BaseType::Bytes | BaseType::Ascii => {
+            return report(
+                field_assignment.field.span,
+                "byte array fields can't be used in routines".into(),
+            );
+        }
+        // Reported by another pass
+        BaseType::Unspecified | BaseType::Uint | BaseType::Int => {}
+    }
+
+    true
+}
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use device_driver_common::{
+    identifier::{All, IdentifierRef},
+    span::{SpanExt, Spanned},
+};
+use device_driver_diagnostics::{
+    Diagnostics, DynError,
+    errors::{DuplicateAllow, InvalidAllowedOperation, InvalidTransition, UnknownState},
+};
+use itertools::Itertools;
+
+use crate::{
+    model::{Manifest, Object, StateMachine, Statement, Transition, Unique, UniqueId},
+    passes::{Assumption, Pass, routines_checked::check_statement},
+    search_object, search_operation,
+};
+
+/// Checks that the states, transitions and allowed operations of state machines refer to existing states and
+/// to routines and operations in the same device
+pub struct StateMachinesChecked;
+
+impl Pass for StateMachinesChecked {
+    const ASSUMPTIONS_MADE: &[Assumption] = &[
+        Assumption::NamesUnique,
+        Assumption::FieldsetRefsValid,
+        Assumption::AccessSet,
+        Assumption::FieldBaseTypesSpecified,
+        Assumption::ChipVariantsPropagated,
+    ];
+    const ASSUMPTIONS_RELEASED: &[Assumption] = &[];
+
+    fn run_pass(
+        manifest: &mut Manifest,
+        diagnostics: &mut Diagnostics,
+    ) -> Result<HashSet<UniqueId>, DynError> {
+        let mut removals = HashSet::new();
+
+        for object in manifest.iter_objects() {
+            let siblings = object.child_objects();
+
+            for state_machine in siblings.iter().filter_map(|o| match o {
+                Object::StateMachine(state_machine) => Some(state_machine),
+                _ => None,
+            }) {
+                let mut ok = true;
+
+                if let Some(initial) = &state_machine.initial {
+                    ok &= check_state_ref(state_machine, initial, diagnostics);
+                }
+
+                for transition in &state_machine.transitions {
+                    ok &= check_transition(
+                        manifest,
+                        siblings,
+                        state_machine,
+                        transition,
+                        diagnostics,
+                    );
+                }
+
+                ok &= check_allows(manifest, siblings, state_machine, diagnostics);
+
+                if !ok {
+                    removals.insert(state_machine.id());
+                }
+            }
+        }
+
+        Ok(removals)
+    }
+}
+
+/// Check that the state exists in the state machine. Returns false if there's an error.
+fn check_state_ref(
+    state_machine: &StateMachine,
+    state: &Spanned<IdentifierRef<All>>,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    if state_machine
+        .states
+        .iter()
+        .any(|s| state.is_ref_to(&s.name))
+    {
+        true
+    } else {
+        diagnostics.add(UnknownState {
+            state: state.span,
+            state_machine: state_machine.name.span,
+        });
+        false
+    }
+}
+
+/// Check that the states are unique and that they exist. Returns false if there's an error.
+fn check_state_refs(
+    state_machine: &StateMachine,
+    states: &[Spanned<IdentifierRef<All>>],
+    diagnostics: &mut Diagnostics,
+    mut report_duplicate: impl FnMut(&Spanned<IdentifierRef<All>>, &mut Diagnostics),
+) -> bool {
+    let mut ok = true;
+
+    for (index, state) in states.iter().enumerate() {
+        if !check_state_ref(state_machine, state, diagnostics) {
+            ok = false;
+        } else if states[..index]
+            .iter()
+            .any(|s| s.original() == state.original())
+        {
+            report_duplicate(state, diagnostics);
+            ok = false;
+        }
+    }
+
+    ok
+}
+
+/// Check the states and the routine or statements of the transition. Returns false if there's an error.
+fn check_transition(
+    manifest: &Manifest,
+    siblings: &[Object],
+    state_machine: &StateMachine,
+    transition: &Transition,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let mut ok = check_state_refs(
+        state_machine,
+        &transition.from,
+        diagnostics,
+        |state, diagnostics| {
+            diagnostics.add(InvalidTransition {
+                transition: transition.name.span,
+                problem: state.span,
+                reason: "the state is given more than once".into(),
+            });
+        },
+    );
+
+    if let Some(to) = &transition.to {
+        ok &= check_state_ref(state_machine, to, diagnostics);
+    }
+
+    if let Some(routine_ref) = &transition.routine {
+        let mut report = |reason: String| {
+            diagnostics.add(InvalidTransition {
+                transition: transition.name.span,
+                problem: routine_ref.span,
+                reason: reason.into(),
+            });
+            false
+        };
+
+        ok &= match siblings.iter().find(|o| routine_ref.is_ref_to(o.name())) {
+            Some(Object::Routine(routine)) if !routine.chip_variants.is_empty() => report(format!(
+                "the routine only exists for the chip variants {}, so it can't be used for a transition",
+                routine
+                    .chip_variants
+                    .iter()
+                    .map(|v| format!("`{}`", v.value))
+                    .join(", ")
+            )),
+            Some(Object::Routine(_)) => true,
+            Some(_) => report("expected a routine".into()),
+            None => match search_object(manifest, routine_ref) {
+                Some(_) => {
+                    report("the routine must be in the same device as the state machine".into())
+                }
+                None => report("no routine found with this name".into()),
+            },
+        };
+    }
+
+    for statement in &transition.statements {
+        if let Some(target_statement) = statement.as_target_statement() {
+            ok &= check_statement(
+                manifest,
+                siblings,
+                None,
+                statement.keyword(),
+                target_statement,
+                matches!(statement, Statement::Dispatch(_)),
+                diagnostics,
+            );
+        }
+    }
+
+    ok
+}
+
+/// Check that the allowed operations exist, are only allowed once and refer to existing states.
+/// Returns false if there's an error.
+fn check_allows(
+    manifest: &Manifest,
+    siblings: &[Object],
+    state_machine: &StateMachine,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    let mut ok = true;
+
+    for (index, allow) in state_machine.allows.iter().enumerate() {
+        if let Some(original) = state_machine.allows[..index]
+            .iter()
+            .find(|a| a.name.value == allow.name.value)
+        {
+            diagnostics.add(DuplicateAllow {
+                original: original.name.span,
+                duplicate: allow.name.span,
+            });
+            ok = false;
+        }
+
+        let operation_ref = allow.name.value.clone().take_ref();
+        let mut report = |pointee, reason: &'static str| {
+            diagnostics.add(InvalidAllowedOperation {
+                operation: allow.name.span,
+                pointee,
+                reason: reason.into(),
+            });
+            false
+        };
+
+        ok &= match siblings.iter().find(|o| operation_ref.is_ref_to(o.name())) {
+            Some(
+                Object::Block(_)
+                | Object::Register(_)
+                | Object::Command(_)
+                | Object::Buffer(_)
+                | Object::Memory(_)
+                | Object::Fifo(_)
+                | Object::Routine(_)
+                | Object::Composite(_),
+            ) => true,
+            Some(object) => report(
+                Some(object.name_span()),
+                "expected a block, register, command, buffer, memory, fifo, routine or composite",
+            ),
+            // Operations in the blocks of the device are gated through the block that leads to them
+            None if search_operation(siblings, &operation_ref).is_some() => true,
+            None => match search_object(manifest, &operation_ref.with_span(allow.name.span)) {
+                Some(object) => report(
+                    Some(object.name_span()),
+                    "the operation must be in the same device as the state machine",
+                ),
+                None => report(None, "no operation found with this name"),
+            },
+        };
+
+        ok &= check_state_refs(
+            state_machine,
+            &allow.states,
+            diagnostics,
+            |state, diagnostics| {
+                diagnostics.add(InvalidAllowedOperation {
+                    operation: state.span,
+                    pointee: None,
+                    reason: "the state is given more than once".into(),
+                });
+            },
+        );
+    }
+
+    ok
+}
@@ -10,15 +10,31 @@ pub fn gen_docs(output_path: &Path) -> Result<(), DynError> {
 
     gen_railroad(&output_path.join("node.svg"), super::node())
         .with_message(|| "generating node railroad diagram")?;
+    gen_railroad(
+        &output_path.join("included-nodes.svg"),
+        super::included_nodes(),
+    )
+    .with_message(|| "generating included-nodes railroad diagram")?;
     gen_railroad(&output_path.join("range.svg"), super::range())
         .with_message(|| "generating range railroad diagram")?;
+    gen_railroad(&output_path.join("value-range.svg"), super::value_range())
+        .with_message(|| "generating value-range railroad diagram")?;
     gen_railroad(&output_path.join("byte-array.svg"), super::byte_array())
         .with_message(|| "generating byte-array railroad diagram")?;
+    gen_railroad(&output_path.join("value-set.svg"), super::value_set())
+        .with_message(|| "generating value-set railroad diagram")?;
     gen_railroad(
         &output_path.join("simple-expression.svg"),
         super::simple_expression(),
     )
     .with_message(|| "generating nodsimple-expressione railroad diagram")?;
+    gen_railroad(
+        &output_path.join("int-expression.svg"),
+        super::int_expression(),
+    )
+    .with_message(|| "generating int-expression railroad diagram")?;
+    gen_railroad(&output_path.join("constant.svg"), super::constant())
+        .with_message(|| "generating constant railroad diagram")?;
     gen_railroad(&output_path.join("repeat.svg"), super::repeat())
         .with_message(|| "generating repeat railroad diagram")?;
     gen_railroad(
@@ -41,15 +57,31 @@ pub fn gen_docs(output_path: &Path) -> Result<(), DynError> {
 
     gen_ebnf(&output_path.join("node.ebnf"), super::node())
         .with_message(|| "generating node railroad diagram")?;
+    gen_ebnf(
+        &output_path.join("included-nodes.ebnf"),
+        super::included_nodes(),
+    )
+    .with_message(|| "generating included-nodes railroad diagram")?;
     gen_ebnf(&output_path.join("range.ebnf"), super::range())
         .with_message(|| "generating range railroad diagram")?;
+    gen_ebnf(&output_path.join("value-range.ebnf"), super::value_range())
+        .with_message(|| "generating value-range railroad diagram")?;
     gen_ebnf(&output_path.join("byte-array.ebnf"), super::byte_array())
         .with_message(|| "generating byte-array railroad diagram")?;
+    gen_ebnf(&output_path.join("value-set.ebnf"), super::value_set())
+        .with_message(|| "generating value-set railroad diagram")?;
     gen_ebnf(
         &output_path.join("simple-expression.ebnf"),
         super::simple_expression(),
     )
     .with_message(|| "generating simple-expression railroad diagram")?;
+    gen_ebnf(
+        &output_path.join("int-expression.ebnf"),
+        super::int_expression(),
+    )
+    .with_message(|| "generating int-expression railroad diagram")?;
+    gen_ebnf(&output_path.join("constant.ebnf"), super::constant())
+        .with_message(|| "generating constant railroad diagram")?;
     gen_ebnf(&output_path.join("repeat.ebnf"), super::repeat())
         .with_message(|| "generating repeat railroad diagram")?;
     gen_ebnf(
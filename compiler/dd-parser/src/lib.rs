@@ -10,12 +10,13 @@ use chumsky::{
 };
 use device_driver_common::{
     span::{Span, SpanExt, Spanned},
-    specifiers::{Access, AddressMode, BaseType, ByteOrder, Integer},
+    specifiers::{Access, AddressMode, BaseType, ByteOrder, Encoding, Integer, ValueRange},
 };
 use device_driver_diagnostics::{Diagnostics, errors::ParsingError};
 use device_driver_lexer::Token;
 
-use crate::parse_num::{ParseIntRadix, ParseIntRadixError, ParseIntRadixErrorKind, parse_num};
+use crate::parse_num::{ParseIntRadix, ParseIntRadixError, ParseIntRadixErrorKind};
+pub use parse_num::parse_num;
 
 #[cfg(feature = "gen-docs")]
 pub mod gen_docs;
@@ -24,23 +25,10 @@ mod parse_num;
 pub fn parse<'src>(tokens: &[Spanned<Token<'src>>], diagnostics: &mut Diagnostics) -> Ast<'src> {
     let (ast, parse_errs) = node()
         .map_with(|ast, e| (ast, e.span()))
-        .parse(
-            tokens.map(
-                tokens
-                    .last()
-                    .map(|t| Span::from(t.span.end..t.span.end))
-                    .unwrap_or_default(),
-                |token| (&token.value, &token.span),
-            ),
-        )
+        .parse(input(tokens))
         .into_output_errors();
 
-    for error in parse_errs {
-        diagnostics.add(ParsingError {
-            reason: error.to_string(),
-            span: *error.span(),
-        });
-    }
+    add_parse_errors(parse_errs, diagnostics);
 
     ast.map(|(root_node, span)| Ast {
         root_node: Some(root_node),
@@ -49,6 +37,37 @@ pub fn parse<'src>(tokens: &[Spanned<Token<'src>>], diagnostics: &mut Diagnostic
     .unwrap_or_default()
 }
 
+/// Parse the tokens of an included file. These files contain a list of nodes instead of a single root node.
+pub fn parse_included<'src>(
+    tokens: &[Spanned<Token<'src>>],
+    diagnostics: &mut Diagnostics,
+) -> Vec<Node<'src>> {
+    let (nodes, parse_errs) = included_nodes().parse(input(tokens)).into_output_errors();
+
+    add_parse_errors(parse_errs, diagnostics);
+
+    nodes.unwrap_or_default()
+}
+
+fn input<'tokens, 'src>(tokens: &'tokens [Spanned<Token<'src>>]) -> InputType<'tokens, 'src> {
+    tokens.map(
+        tokens
+            .last()
+            .map(|t| t.span.collapse_to_end())
+            .unwrap_or_default(),
+        |token| (&token.value, &token.span),
+    )
+}
+
+fn add_parse_errors(parse_errs: Vec<RichErr<'_, '_>>, diagnostics: &mut Diagnostics) {
+    for error in parse_errs {
+        diagnostics.add(ParsingError {
+            reason: error.to_string(),
+            span: *error.span(),
+        });
+    }
+}
+
 // Don't forget to update the book when parsers are added, changed or removed!
 #[derive(Debug, Default)]
 pub struct Ast<'src> {
@@ -85,10 +104,21 @@ impl<'src> Display for Node<'src> {
                 }
             )?;
         }
+        if self.node_type.val == "const"
+            && let [value] = self.short_properties.as_slice()
+        {
+            return write!(
+                f,
+                "{indentation}const {} = {}",
+                self.name.val,
+                value.get_human_string()
+            );
+        }
+
         write!(f, "{indentation}{} {}", self.node_type.val, self.name.val)?;
 
-        if let Some(repeat) = self.repeat {
-            write!(f, "[{} stride {}]", repeat.source, repeat.stride)?;
+        if let Some(repeat) = &self.repeat {
+            write!(f, "{}", repeat.value)?;
         }
 
         for expression in self.short_properties.iter() {
@@ -98,6 +128,10 @@ impl<'src> Display for Node<'src> {
         if let Some(type_specifier) = self.type_specifier.as_ref() {
             write!(f, " -> {}", type_specifier.base_type)?;
 
+            if let Some(encoding) = type_specifier.encoding {
+                write!(f, " {encoding}")?;
+            }
+
             if let Some(conversion) = type_specifier.conversion.as_ref() {
                 write!(f, " as")?;
                 if type_specifier.use_try {
@@ -179,6 +213,7 @@ impl<'src> Display for Node<'src> {
 #[derive(Debug, Clone)]
 pub struct TypeSpecifier<'src> {
     pub base_type: Spanned<BaseType>,
+    pub encoding: Option<Spanned<Encoding>>,
     pub use_try: bool,
     pub conversion: Option<TypeConversion<'src>>,
 }
@@ -198,8 +233,17 @@ pub struct Property<'src> {
 
 #[derive(Debug, Clone)]
 pub enum Expression<'src> {
-    AddressRange { end: i128, start: i128 },
+    AddressRange {
+        end: i128,
+        start: i128,
+    },
+    ValueRange {
+        start: i128,
+        end: i128,
+    },
     ByteArray(Vec<u8>),
+    /// A list of values and value ranges in brackets. A single value is a range with the same start and end
+    ValueSet(Vec<Spanned<ValueRange>>),
     BaseType(BaseType),
     Integer(Integer),
     Allow,
@@ -213,6 +257,8 @@ pub enum Expression<'src> {
     SubNode(Box<Node<'src>>),
     Auto,
     AddressMode(AddressMode),
+    /// An integer expression with at least one operator
+    IntExpression(IntExpression<'src>),
     Error,
 }
 
@@ -284,7 +330,20 @@ impl<'src> Expression<'src> {
     pub fn get_human_string(&self) -> Cow<'static, str> {
         match self {
             Expression::AddressRange { end, start } => format!("{end}:{start}").into(),
+            Expression::ValueRange { start, end } => format!("{start}..={end}").into(),
             Expression::ByteArray(items) => format!("{items:?}").into(),
+            Expression::ValueSet(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(|item| match item.value {
+                        ValueRange { start, end } if start == end => start.to_string(),
+                        ValueRange { start, end } => format!("{start}..={end}"),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .into(),
             Expression::BaseType(base_type) => base_type.to_string().into(),
             Expression::Integer(integer) => integer.to_string().into(),
             Expression::Allow => "allow".into(),
@@ -298,8 +357,9 @@ impl<'src> Expression<'src> {
             Expression::ByteOrder(val) => val.to_string().into(),
             Expression::TypeReference(ident) => ident.val.to_string().into(),
             Expression::SubNode(val) => val.to_string().into(),
-            Expression::Auto => "_".into(),
+            Expression::Auto => "auto".into(),
             Expression::AddressMode(val) => val.to_string().into(),
+            Expression::IntExpression(val) => val.to_string().into(),
             Expression::Error => "ERROR".into(),
         }
     }
@@ -309,7 +369,9 @@ impl<'src> Display for Expression<'src> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Expression::AddressRange { .. } => write!(f, "range"),
+            Expression::ValueRange { .. } => write!(f, "value range"),
             Expression::ByteArray(_) => write!(f, "[bytes]"),
+            Expression::ValueSet(_) => write!(f, "[values]"),
             Expression::BaseType(_) => write!(f, "base type"),
             Expression::Integer(_) => write!(f, "integer type"),
             Expression::Allow => write!(f, "allow"),
@@ -325,21 +387,130 @@ impl<'src> Display for Expression<'src> {
             Expression::SubNode(_) => write!(f, "sub node"),
             Expression::Auto => write!(f, "auto"),
             Expression::AddressMode(_) => write!(f, "address mode"),
+            Expression::IntExpression(_) => write!(f, "integer expression"),
             Expression::Error => write!(f, "error"),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone)]
+pub enum IntExpression<'src> {
+    Number(i128),
+    Constant(Ident<'src>),
+    Binary {
+        operator: Spanned<BinaryOperator>,
+        lhs: Box<Spanned<IntExpression<'src>>>,
+        rhs: Box<Spanned<IntExpression<'src>>>,
+    },
+}
+
+impl<'src> IntExpression<'src> {
+    /// Turn the integer expression into a normal expression.
+    /// Plain numbers and identifiers become a number and a type reference so they are treated like before.
+    pub fn into_expression(expression: Spanned<Self>) -> Spanned<Expression<'src>> {
+        match expression.value {
+            IntExpression::Number(num) => Expression::Number(num),
+            IntExpression::Constant(ident) => Expression::TypeReference(ident),
+            int_expression => Expression::IntExpression(int_expression),
+        }
+        .spanned(expression.span)
+    }
+}
+
+impl Display for IntExpression<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntExpression::Number(num) => write!(f, "{num}"),
+            IntExpression::Constant(ident) => write!(f, "{}", ident.val),
+            IntExpression::Binary { operator, lhs, rhs } => {
+                // Always add parentheses around nested operations so the order is clear
+                let operand = |operand: &IntExpression| match operand {
+                    IntExpression::Binary { .. } => format!("({operand})"),
+                    _ => operand.to_string(),
+                };
+
+                write!(
+                    f,
+                    "{} {} {}",
+                    operand(&lhs.value),
+                    operator.value,
+                    operand(&rhs.value)
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    ShiftLeft,
+    ShiftRight,
+    BitOr,
+    BitAnd,
+}
+
+impl BinaryOperator {
+    /// Operators with a higher precedence bind stronger
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Mul | BinaryOperator::Div => 4,
+            BinaryOperator::Add | BinaryOperator::Sub => 3,
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 2,
+            BinaryOperator::BitAnd => 1,
+            BinaryOperator::BitOr => 0,
+        }
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryOperator::Add => write!(f, "+"),
+            BinaryOperator::Sub => write!(f, "-"),
+            BinaryOperator::Mul => write!(f, "*"),
+            BinaryOperator::Div => write!(f, "/"),
+            BinaryOperator::ShiftLeft => write!(f, "<<"),
+            BinaryOperator::ShiftRight => write!(f, ">>"),
+            BinaryOperator::BitOr => write!(f, "|"),
+            BinaryOperator::BitAnd => write!(f, "&"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Repeat<'src> {
     pub source: Spanned<RepeatSource<'src>>,
-    pub stride: Spanned<i32>,
+    /// The stride of a count or enum repeat. A list of offsets has none.
+    pub stride: Option<Spanned<i32>>,
+    /// The second dimension of a two-dimensional repeat
+    pub inner: Option<Box<Spanned<Repeat<'src>>>>,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl Display for Repeat<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.stride {
+            Some(stride) => write!(f, "[{} stride {}]", self.source, stride)?,
+            None => write!(f, "[{}]", self.source)?,
+        }
+
+        if let Some(inner) = &self.inner {
+            write!(f, "{}", inner.value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum RepeatSource<'src> {
     Count(NonZeroU32),
     Enum(Ident<'src>),
+    /// An explicit list of offsets from the address, one for every index
+    Offsets(Vec<Spanned<i32>>),
 }
 
 impl<'src> Default for RepeatSource<'src> {
@@ -353,6 +524,15 @@ impl Display for RepeatSource<'_> {
         match self {
             RepeatSource::Count(non_zero) => write!(f, "{non_zero}"),
             RepeatSource::Enum(ident) => write!(f, "{}", ident.val),
+            RepeatSource::Offsets(offsets) => write!(
+                f,
+                "at {}",
+                offsets
+                    .iter()
+                    .map(|offset| offset.value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -486,6 +666,103 @@ pub fn range<'tokens, 'src: 'tokens>()
         .labelled("range")
 }
 
+pub fn value_range<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, Expression<'src>, RichExtra<'tokens, 'src>> + Clone
+{
+    num::<i128>()
+        .then_ignore(just(Token::DotDotEq))
+        .then(num::<i128>())
+        .map(|(start, end)| Expression::ValueRange { start, end })
+        .labelled("value-range")
+}
+
+/// Integer expression with numbers, constants, parentheses and the binary operators.
+/// From strongest to weakest binding: `* /`, `+ -`, `<< >>`, `&`, `|`
+pub fn int_expression<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, Spanned<IntExpression<'src>>, RichExtra<'tokens, 'src>>
++ Clone {
+    recursive(|int_expression| {
+        let operand = choice((
+            num::<i128>().map(IntExpression::Number),
+            ident(false).map(IntExpression::Constant),
+        ))
+        .map_with(|expression, extra| expression.spanned(extra.span()))
+        .or(int_expression
+            .labelled("int-expression")
+            .as_non_terminal()
+            .delimited_by(just(Token::ParenOpen), just(Token::ParenClose))
+            // Include the parentheses in the span
+            .map_with(|expression: Spanned<IntExpression>, extra| {
+                expression.value.spanned(extra.span())
+            }));
+
+        let operator = choice((
+            just(Token::Star).to(BinaryOperator::Mul),
+            just(Token::Slash).to(BinaryOperator::Div),
+            just(Token::Plus).to(BinaryOperator::Add),
+            just(Token::Minus).to(BinaryOperator::Sub),
+            just(Token::ShiftLeft).to(BinaryOperator::ShiftLeft),
+            just(Token::ShiftRight).to(BinaryOperator::ShiftRight),
+            just(Token::Ampersand).to(BinaryOperator::BitAnd),
+            just(Token::Pipe).to(BinaryOperator::BitOr),
+        ))
+        .map_with(|operator, extra| operator.spanned(extra.span()));
+
+        operand
+            .clone()
+            .then(operator.then(operand).repeated().collect::<Vec<_>>())
+            .map(|(first, rest)| fold_operations(first, rest))
+    })
+    .labelled("int-expression")
+}
+
+/// Build the expression tree out of a flat list of operations using the precedence of the operators.
+/// Operators with the same precedence are left associative.
+fn fold_operations<'src>(
+    first: Spanned<IntExpression<'src>>,
+    rest: Vec<(Spanned<BinaryOperator>, Spanned<IntExpression<'src>>)>,
+) -> Spanned<IntExpression<'src>> {
+    fn reduce<'src>(
+        operands: &mut Vec<Spanned<IntExpression<'src>>>,
+        operators: &mut Vec<Spanned<BinaryOperator>>,
+    ) {
+        let rhs = operands.pop().unwrap();
+        let lhs = operands.pop().unwrap();
+        let operator = operators.pop().unwrap();
+        let span = lhs.span.to(rhs.span);
+
+        operands.push(
+            IntExpression::Binary {
+                operator,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            }
+            .spanned(span),
+        );
+    }
+
+    let mut operands = vec![first];
+    let mut operators = Vec::<Spanned<BinaryOperator>>::new();
+
+    for (operator, operand) in rest {
+        while operators
+            .last()
+            .is_some_and(|last| last.value.precedence() >= operator.value.precedence())
+        {
+            reduce(&mut operands, &mut operators);
+        }
+
+        operators.push(operator);
+        operands.push(operand);
+    }
+
+    while !operators.is_empty() {
+        reduce(&mut operands, &mut operators);
+    }
+
+    operands.pop().unwrap()
+}
+
 pub fn base_type<'tokens, 'src: 'tokens>()
 -> impl Parser<'tokens, InputType<'tokens, 'src>, BaseType, RichExtra<'tokens, 'src>> + Copy {
     select! { Token::BaseType(bt) => bt }
@@ -493,6 +770,24 @@ pub fn base_type<'tokens, 'src: 'tokens>()
         .as_terminal()
 }
 
+/// The byte array base types. They are identifiers everywhere except after the arrow of a type specifier
+pub fn byte_array_base_type<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, BaseType, RichExtra<'tokens, 'src>> + Copy {
+    select! {
+        Token::Ident("bytes") => BaseType::Bytes,
+        Token::Ident("ascii") => BaseType::Ascii,
+    }
+    .labelled("ByteArrayBaseType")
+    .as_terminal()
+}
+
+pub fn encoding<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, Encoding, RichExtra<'tokens, 'src>> + Copy {
+    select! { Token::Encoding(encoding) => encoding }
+        .labelled("Encoding")
+        .as_terminal()
+}
+
 pub fn integer<'tokens, 'src: 'tokens>()
 -> impl Parser<'tokens, InputType<'tokens, 'src>, Integer, RichExtra<'tokens, 'src>> + Copy {
     select! { Token::Integer(i) => i }
@@ -512,12 +807,36 @@ pub fn byte_array<'tokens, 'src: 'tokens>()
         .labelled("byte-array")
 }
 
+/// A list of values and value ranges like `[0x10..=0x17, 0x1C]`.
+/// Lists with only bytes are parsed as a byte array before this is tried.
+pub fn value_set<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, Expression<'src>, RichExtra<'tokens, 'src>> + Clone
+{
+    num::<i128>()
+        .then(just(Token::DotDotEq).ignore_then(num::<i128>()).or_not())
+        .map_with(|(start, end), extra| {
+            ValueRange {
+                start,
+                end: end.unwrap_or(start),
+            }
+            .with_span(extra.span())
+        })
+        .separated_by(just(Token::Comma))
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(Expression::ValueSet)
+        .then_ignore(just(Token::Comma).or_not())
+        .delimited_by(just(Token::BracketOpen), just(Token::BracketClose))
+        .labelled("value-set")
+}
+
 /// Expression without type reference since that clashes with nodes
 pub fn simple_expression<'tokens, 'src: 'tokens>()
 -> impl Parser<'tokens, InputType<'tokens, 'src>, Spanned<Expression<'src>>, RichExtra<'tokens, 'src>>
 + Clone {
     choice((
         range().labelled("range").as_non_terminal(),
+        value_range().labelled("value-range").as_non_terminal(),
         base_type().map(Expression::BaseType),
         integer().map(Expression::Integer),
         num::<i128>().map(Expression::Number),
@@ -538,6 +857,7 @@ pub fn simple_expression<'tokens, 'src: 'tokens>()
             .map(Expression::CatchAllNumber)
             .labelled("catch-all-number"),
         byte_array().labelled("byte-array").as_non_terminal(),
+        value_set().labelled("value-set").as_non_terminal(),
         just(Token::Allow).map(|_| Expression::Allow),
         select! { Token::Access(val) => val }
             .map(Expression::Access)
@@ -564,18 +884,52 @@ pub fn simple_expression<'tokens, 'src: 'tokens>()
 pub fn repeat<'tokens, 'src: 'tokens>()
 -> impl Parser<'tokens, InputType<'tokens, 'src>, Spanned<Repeat<'src>>, RichExtra<'tokens, 'src>>
 + Clone {
-    choice((
-        num::<NonZeroU32>().map(RepeatSource::Count),
-        ident(false).map(RepeatSource::Enum),
+    let stride = just(Token::Stride)
+        .ignore_then(num::<i32>().map_with(|num, extra| num.with_span(extra.span())));
+
+    let offsets = select! { Token::Ident(val) if val == "at" => () }
+        .labelled("At")
+        .as_terminal()
+        .ignore_then(
+            num::<i32>()
+                .map_with(|num, extra| num.with_span(extra.span()))
+                .separated_by(just(Token::Comma))
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .map(RepeatSource::Offsets)
+        .map_with(|repeat_source, extra| repeat_source.with_span(extra.span()));
+
+    let dimension = choice((
+        offsets.map(|source| (source, None)),
+        choice((
+            num::<NonZeroU32>().map(RepeatSource::Count),
+            ident(false).map(RepeatSource::Enum),
+        ))
+        .map_with(|repeat_source, extra| repeat_source.with_span(extra.span()))
+        .then(stride.map(Some)),
     ))
-    .map_with(|repeat_source, extra| repeat_source.with_span(extra.span()))
-    .then(
-        just(Token::Stride)
-            .ignore_then(num::<i32>().map_with(|num, extra| num.with_span(extra.span()))),
-    )
     .delimited_by(just(Token::BracketOpen), just(Token::BracketClose))
-    .map_with(|(source, stride), extra| Repeat { source, stride }.spanned(extra.span()))
-    .labelled("repeat")
+    .map_with(|(source, stride), extra| {
+        Repeat {
+            source,
+            stride,
+            inner: None,
+        }
+        .spanned(extra.span())
+    });
+
+    dimension
+        .clone()
+        .then(dimension.or_not())
+        .map_with(|(outer, inner), extra| {
+            Repeat {
+                inner: inner.map(Box::new),
+                ..outer.value
+            }
+            .spanned(extra.span())
+        })
+        .labelled("repeat")
 }
 
 pub fn property<'tokens, 'src: 'tokens, 'node>(
@@ -589,6 +943,14 @@ pub fn property<'tokens, 'src: 'tokens, 'node>(
             ident(false)
                 .then(
                     just(Token::Colon).ignore_then(choice((
+                        // Only take the operations. Single numbers and identifiers are parsed as before.
+                        int_expression()
+                            .labelled("int-expression")
+                            .as_non_terminal()
+                            .filter(|expression| {
+                                matches!(expression.value, IntExpression::Binary { .. })
+                            })
+                            .map(IntExpression::into_expression),
                         simple_expression()
                             .labelled("simple-expression")
                             .as_non_terminal(),
@@ -637,14 +999,21 @@ pub fn type_specifier<'tokens, 'src: 'tokens>(
         .ignore_then(
             choice((
                 base_type(),
+                byte_array_base_type(),
                 integer().map(BaseType::FixedSize),
                 just(Token::Underscore).map(|_| BaseType::Unspecified),
             ))
             .map_with(|b, e| b.spanned(e.span())),
         )
+        .then(
+            encoding()
+                .map_with(|encoding, e| encoding.spanned(e.span()))
+                .or_not(),
+        )
         .then(type_conversion.or_not())
-        .map(|(base_type, conversion)| TypeSpecifier {
+        .map(|((base_type, encoding), conversion)| TypeSpecifier {
             base_type,
+            encoding,
             use_try: conversion
                 .as_ref()
                 .map(|(try_token, _)| try_token.is_some())
@@ -697,16 +1066,75 @@ pub fn node_body<'tokens, 'src: 'tokens>(
     .labelled("node-body")
 }
 
+/// The contents of an included file: a list of nodes
+pub fn included_nodes<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, Vec<Node<'src>>, RichExtra<'tokens, 'src>> + Clone
+{
+    node()
+        .labelled("node")
+        .as_non_terminal()
+        .separated_by(just(Token::Comma))
+        .allow_trailing()
+        .collect::<Vec<_>>()
+        .labelled("included-nodes")
+}
+
+/// Definition of a named constant: `const NAME = int-expression`.
+/// It's represented as a node of type `const` with the value as its only short property.
+pub fn constant<'tokens, 'src: 'tokens>()
+-> impl Parser<'tokens, InputType<'tokens, 'src>, Node<'src>, RichExtra<'tokens, 'src>> + Clone {
+    doc_comment()
+        .repeated()
+        .collect()
+        .then(select! { Token::Ident(val) = e if val == "const" => Ident::new(val, e.span()) })
+        .then(ident(false).labelled("constant-name"))
+        .then_ignore(just(Token::Eq))
+        .then(
+            int_expression()
+                .labelled("int-expression")
+                .as_non_terminal(),
+        )
+        .map_with(|(((doc_comments, node_type), name), value), extra| {
+            let mut span: Span = extra.span();
+            span = span.start_from(node_type.span);
+
+            Node {
+                doc_comments,
+                node_type,
+                name,
+                repeat: None,
+                type_specifier: None,
+                short_properties: vec![IntExpression::into_expression(value)],
+                properties: Vec::new(),
+                sub_nodes: Vec::new(),
+                span,
+            }
+        })
+        .labelled("constant")
+}
+
 pub fn node<'tokens, 'src: 'tokens>()
 -> impl Parser<'tokens, InputType<'tokens, 'src>, Node<'src>, RichExtra<'tokens, 'src>> + Clone {
     recursive(|node| {
         let node = node.labelled("node").as_non_terminal();
 
-        doc_comment()
+        let regular_node = doc_comment()
             .repeated()
             .collect()
-            .then(ident(false).labelled("node-type"))
-            .then(ident(true).labelled("node-name"))
+            // Constants have their own syntax
+            .then(
+                ident(false)
+                    .filter(|node_type| node_type.val != "const")
+                    .labelled("node-type"),
+            )
+            .then(
+                ident(true)
+                    // Index nodes are named after the index they override
+                    .or(select! { Token::Num(val) = e => Ident::new(val, e.span()) }
+                        .labelled("Num")
+                        .as_terminal())
+                    .labelled("node-name"),
+            )
             .then(repeat().labelled("repeat").as_non_terminal().or_not())
             .then(
                 simple_expression()
@@ -750,7 +1178,12 @@ pub fn node<'tokens, 'src: 'tokens>()
                         span,
                     }
                 },
-            )
-            .labelled("node")
+            );
+
+        choice((
+            constant().labelled("constant").as_non_terminal(),
+            regular_node,
+        ))
+        .labelled("node")
     })
 }
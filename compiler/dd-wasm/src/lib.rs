@@ -1,6 +1,7 @@
 use clap::Parser;
 use device_driver_core::{
-    CodegenTarget, CompileOptions, GeneralOptions, MirOptions, RustCodegenOptions,
+    CodegenTarget, CompileOptions, GeneralOptions, MemoryFileLoader, MirOptions,
+    RustCodegenOptions, SourceMap,
 };
 use device_driver_diagnostics::{Metadata, ResultExt};
 use wasm_bindgen::prelude::*;
@@ -29,7 +30,13 @@ impl From<RustCompileOptions> for CompileOptions {
 }
 
 #[wasm_bindgen]
-pub fn compile(source: &str, chars_per_line: usize, target: TargetArg, options: &str) -> Output {
+pub fn compile(
+    source: &str,
+    files: &FileMap,
+    chars_per_line: usize,
+    target: TargetArg,
+    options: &str,
+) -> Output {
     let options = options.replace("\r\n", " ").replace('\n', " ");
     let options = options.split(' ').filter(|s| !s.is_empty());
 
@@ -46,28 +53,29 @@ pub fn compile(source: &str, chars_per_line: usize, target: TargetArg, options:
         .into(),
     };
 
-    let (output, diagnostics_string) = match device_driver_core::compile(source, compile_options)
-        .with_message(|| "internal compiler error")
-    {
-        Ok((output, diagnostics)) => {
-            let mut diagnostics_string = String::new();
-            diagnostics
-                .print_to_fmt(
-                    &mut diagnostics_string,
-                    Metadata {
-                        source,
-                        source_path: "input.ddsl",
-                        term_width: Some(chars_per_line),
-                        ansi: true,
-                        unicode: true,
-                        anonymized_line_numbers: false,
-                    },
-                )
-                .unwrap();
-            (output, diagnostics_string)
-        }
-        Err(e) => (String::new(), e.to_report_string()),
-    };
+    let mut source_map = SourceMap::new("input.ddsl", source);
+    let (output, diagnostics_string) =
+        match device_driver_core::compile(&mut source_map, &files.loader, compile_options)
+            .with_message(|| "internal compiler error")
+        {
+            Ok((output, diagnostics)) => {
+                let mut diagnostics_string = String::new();
+                diagnostics
+                    .print_to_fmt(
+                        &mut diagnostics_string,
+                        Metadata {
+                            source_map: &source_map,
+                            term_width: Some(chars_per_line),
+                            ansi: true,
+                            unicode: true,
+                            anonymized_line_numbers: false,
+                        },
+                    )
+                    .unwrap();
+                (output, diagnostics_string)
+            }
+            Err(e) => (String::new(), e.to_report_string()),
+        };
 
     Output {
         code: output,
@@ -75,6 +83,25 @@ pub fn compile(source: &str, chars_per_line: usize, target: TargetArg, options:
     }
 }
 
+/// The virtual files that can be included by the source
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct FileMap {
+    loader: MemoryFileLoader,
+}
+
+#[wasm_bindgen]
+impl FileMap {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, path: &str, source: &str) {
+        self.loader.insert(path, source);
+    }
+}
+
 #[wasm_bindgen(getter_with_clone)]
 pub struct Output {
     pub code: String,
@@ -0,0 +1,102 @@
+use core::fmt::{Debug, Display};
+
+/// A view on the bytes of a field with the `ascii` base type.
+///
+/// The field is not guaranteed to contain valid ascii since it's read from a device.
+/// Use [`Self::as_str`] to get the text or [`Self::as_bytes`] to get the raw bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiStr<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AsciiStr<'a> {
+    #[doc(hidden)]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Get all the bytes of the field, including any zero padding
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the bytes of the field, without the zero padding at the end
+    pub fn trimmed_bytes(&self) -> &'a [u8] {
+        let len = self
+            .bytes
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |last| last + 1);
+
+        &self.bytes[..len]
+    }
+
+    /// Get the text of the field, without the zero padding at the end.
+    ///
+    /// Returns `None` if the field contains non-ascii bytes.
+    pub fn as_str(&self) -> Option<&'a str> {
+        let bytes = self.trimmed_bytes();
+
+        if bytes.is_ascii() {
+            // Ascii is always valid utf8
+            core::str::from_utf8(bytes).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl Debug for AsciiStr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "\"{}\"", self.trimmed_bytes().escape_ascii())
+    }
+}
+
+impl Display for AsciiStr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.trimmed_bytes().escape_ascii())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for AsciiStr<'_> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=[u8]:a}", self.trimmed_bytes())
+    }
+}
+
+/// The error returned by the generated checked setters of fields with the `ascii` base type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InvalidAscii {
+    /// The text is longer than the field
+    TooLong {
+        /// The length of the text in bytes
+        len: usize,
+        /// The size of the field in bytes
+        max: usize,
+        /// The name of the field
+        field: &'static str,
+    },
+    /// The text contains non-ascii characters
+    NotAscii {
+        /// The name of the field
+        field: &'static str,
+    },
+}
+
+impl Display for InvalidAscii {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooLong { len, max, field } => write!(
+                f,
+                "Text of {len} bytes is too long for the {max} bytes of field `{field}`"
+            ),
+            Self::NotAscii { field } => {
+                write!(f, "Text for field `{field}` contains non-ascii characters")
+            }
+        }
+    }
+}
+
+impl core::error::Error for InvalidAscii {}
@@ -212,7 +212,7 @@ where
 
         self.block.interface().dispatch_command(
             self.address,
-            in_fields.as_slice_mut(),
+            in_fields.as_write_slice_mut(),
             &InFieldset::METADATA,
             &mut [],
             &FieldsetMetadata::DEFAULT,
@@ -237,7 +237,7 @@ where
 
         self.block.interface().dispatch_command(
             Repeat::calc_address(self.address, index),
-            in_fields.as_slice_mut(),
+            in_fields.as_write_slice_mut(),
             &InFieldset::METADATA,
             &mut [],
             &FieldsetMetadata::DEFAULT,
@@ -262,7 +262,7 @@ where
             .interface()
             .dispatch_command(
                 self.address,
-                in_fields.as_slice_mut(),
+                in_fields.as_write_slice_mut(),
                 &InFieldset::METADATA,
                 &mut [],
                 &FieldsetMetadata::DEFAULT,
@@ -289,7 +289,7 @@ where
             .interface()
             .dispatch_command(
                 Repeat::calc_address(self.address, index),
-                in_fields.as_slice_mut(),
+                in_fields.as_write_slice_mut(),
                 &InFieldset::METADATA,
                 &mut [],
                 &FieldsetMetadata::DEFAULT,
@@ -414,7 +414,7 @@ where
 
         self.block.interface().dispatch_command(
             self.address,
-            in_fields.as_slice_mut(),
+            in_fields.as_write_slice_mut(),
             &InFieldset::METADATA,
             out_fields.as_slice_mut(),
             &OutFieldset::METADATA,
@@ -443,7 +443,7 @@ where
 
         self.block.interface().dispatch_command(
             Repeat::calc_address(self.address, index),
-            in_fields.as_slice_mut(),
+            in_fields.as_write_slice_mut(),
             &InFieldset::METADATA,
             out_fields.as_slice_mut(),
             &OutFieldset::METADATA,
@@ -472,7 +472,7 @@ where
             .interface()
             .dispatch_command(
                 self.address,
-                in_fields.as_slice_mut(),
+                in_fields.as_write_slice_mut(),
                 &InFieldset::METADATA,
                 out_fields.as_slice_mut(),
                 &OutFieldset::METADATA,
@@ -503,7 +503,7 @@ where
             .interface()
             .dispatch_command(
                 Repeat::calc_address(self.address, index),
-                in_fields.as_slice_mut(),
+                in_fields.as_write_slice_mut(),
                 &InFieldset::METADATA,
                 out_fields.as_slice_mut(),
                 &OutFieldset::METADATA,
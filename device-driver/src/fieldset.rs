@@ -20,11 +20,26 @@ pub unsafe trait Fieldset: Sized {
             )
         }
     }
+
+    /// Set the reserved fields of the fieldset to the values they must have.
+    /// This is done before every write, so these fields can't be clobbered.
+    fn apply_reserved(&mut self) {}
+
+    /// Get the fieldset as a mutable slice for writing it to the device.
+    /// The reserved fields are set to the values they must have first.
+    fn as_write_slice_mut(&mut self) -> &mut [u8] {
+        self.apply_reserved();
+        self.as_slice_mut()
+    }
 }
 
 unsafe impl<T: Fieldset, const N: usize> Fieldset for [T; N] {
     const METADATA: FieldsetMetadata = T::METADATA;
     const ZERO: Self = [T::ZERO; N];
+
+    fn apply_reserved(&mut self) {
+        self.iter_mut().for_each(T::apply_reserved);
+    }
 }
 
 #[doc(hidden)]
@@ -109,6 +124,10 @@ macro_rules! create_fsn {
         unsafe impl<$($tname: Fieldset),*> Fieldset for $name<$($tname),*> {
             const METADATA: FieldsetMetadata = A::METADATA;
             const ZERO: Self = Self($($tname::ZERO),*);
+
+            fn apply_reserved(&mut self) {
+                $(self.$tnum.apply_reserved();)*
+            }
         }
 
         impl<$($tname),*> ToTuple for $name<$($tname),*> {
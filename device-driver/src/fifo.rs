@@ -0,0 +1,320 @@
+use core::marker::PhantomData;
+
+use crate::{
+    Address, AsyncRegisterInterface, Block, Fieldset, RegisterInterface, RegisterInterfaceBase,
+};
+
+/// Object that reads the records of a FIFO from its data register
+///
+/// The records are read with the register interface at the address of the data register.
+/// When the FIFO has a fill level, it's read first so only the available records are read.
+pub struct FifoOperation<'b, B, Record, AddressType, Level>
+where
+    B: Block,
+    B::Interface: RegisterInterfaceBase<AddressType = AddressType>,
+    Record: Fieldset,
+    AddressType: Address,
+{
+    block: &'b mut B,
+    address: AddressType,
+    level: Level,
+    _phantom: PhantomData<Record>,
+}
+
+impl<'b, B, Record, AddressType, Level> FifoOperation<'b, B, Record, AddressType, Level>
+where
+    B: Block,
+    B::Interface: RegisterInterfaceBase<AddressType = AddressType>,
+    Record: Fieldset,
+    AddressType: Address,
+    Level: FifoLevel<AddressType>,
+{
+    #[doc(hidden)]
+    pub fn new(block: &'b mut B, address: AddressType, level: Level) -> Self {
+        Self {
+            block,
+            address,
+            level,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get the address of the data register of the FIFO.
+    pub fn address(&self) -> AddressType {
+        self.address
+    }
+
+    /// Read records from the FIFO into the given slice, returning how many records were read.
+    ///
+    /// When the FIFO has a fill level, only the available records are read, so this can be less than the length of the slice.
+    /// Otherwise the whole slice is filled.
+    /// All records are read in one transaction.
+    pub fn read_records(
+        &mut self,
+        records: &mut [Record],
+    ) -> Result<usize, <B::Interface as RegisterInterfaceBase>::Error>
+    where
+        B::Interface: RegisterInterface,
+    {
+        let count = match self.level.read_available(self.block.interface())? {
+            Some(available) => available.min(records.len()),
+            None => records.len(),
+        };
+
+        if count > 0 {
+            self.block.interface().read_register(
+                self.address,
+                records_as_slice_mut(&mut records[..count]),
+                &Record::METADATA,
+            )?;
+        }
+
+        Ok(count)
+    }
+
+    /// Async version of [`Self::read_records`].
+    pub async fn read_records_async(
+        &mut self,
+        records: &mut [Record],
+    ) -> Result<usize, <B::Interface as RegisterInterfaceBase>::Error>
+    where
+        B::Interface: AsyncRegisterInterface,
+    {
+        let count = match self
+            .level
+            .read_available_async(self.block.interface())
+            .await?
+        {
+            Some(available) => available.min(records.len()),
+            None => records.len(),
+        };
+
+        if count > 0 {
+            self.block
+                .interface()
+                .read_register(
+                    self.address,
+                    records_as_slice_mut(&mut records[..count]),
+                    &Record::METADATA,
+                )
+                .await?;
+        }
+
+        Ok(count)
+    }
+}
+
+impl<'b, B, Record, AddressType, LevelFs>
+    FifoOperation<'b, B, Record, AddressType, RegisterLevel<AddressType, LevelFs>>
+where
+    B: Block,
+    B::Interface: RegisterInterfaceBase<AddressType = AddressType>,
+    Record: Fieldset,
+    AddressType: Address,
+    LevelFs: Fieldset,
+{
+    /// Read the fill level of the FIFO, returning how many records are available.
+    pub fn available(&mut self) -> Result<usize, <B::Interface as RegisterInterfaceBase>::Error>
+    where
+        B::Interface: RegisterInterface,
+    {
+        self.level
+            .read_available(self.block.interface())
+            .map(|available| available.unwrap_or_default())
+    }
+
+    /// Async version of [`Self::available`].
+    pub async fn available_async(
+        &mut self,
+    ) -> Result<usize, <B::Interface as RegisterInterfaceBase>::Error>
+    where
+        B::Interface: AsyncRegisterInterface,
+    {
+        self.level
+            .read_available_async(self.block.interface())
+            .await
+            .map(|available| available.unwrap_or_default())
+    }
+
+    /// Turn the operation into a stream of the records in the FIFO.
+    ///
+    /// The stream reads up to `N` records at a time and only as many as are available.
+    /// It ends when the FIFO is empty.
+    pub fn stream<const N: usize>(self) -> FifoStream<'b, B, Record, AddressType, LevelFs, N> {
+        FifoStream {
+            operation: self,
+            records: [Record::ZERO; N],
+            next: 0,
+            len: 0,
+        }
+    }
+}
+
+/// A stream of the records in a FIFO, created with [`FifoOperation::stream`]
+///
+/// Use [`Self::next_async`] to get the records asynchronously.
+/// With a blocking interface, the stream is also an [`Iterator`].
+///
+/// The stream ends when the FIFO is empty. It can be polled again later to get the records that arrived since.
+pub struct FifoStream<'b, B, Record, AddressType, LevelFs, const N: usize>
+where
+    B: Block,
+    B::Interface: RegisterInterfaceBase<AddressType = AddressType>,
+    Record: Fieldset,
+    AddressType: Address,
+    LevelFs: Fieldset,
+{
+    operation: FifoOperation<'b, B, Record, AddressType, RegisterLevel<AddressType, LevelFs>>,
+    records: [Record; N],
+    next: usize,
+    len: usize,
+}
+
+impl<B, Record, AddressType, LevelFs, const N: usize>
+    FifoStream<'_, B, Record, AddressType, LevelFs, N>
+where
+    B: Block,
+    B::Interface: RegisterInterfaceBase<AddressType = AddressType>,
+    Record: Fieldset,
+    AddressType: Address,
+    LevelFs: Fieldset,
+{
+    /// Get the next record of the FIFO, or `None` if the FIFO is empty.
+    ///
+    /// Records are read from the device when all records read before have been returned.
+    pub async fn next_async(
+        &mut self,
+    ) -> Option<Result<Record, <B::Interface as RegisterInterfaceBase>::Error>>
+    where
+        B::Interface: AsyncRegisterInterface,
+    {
+        if self.next == self.len {
+            match self.operation.read_records_async(&mut self.records).await {
+                Ok(len) => self.refilled(len),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        self.take_next().map(Ok)
+    }
+
+    fn refilled(&mut self, len: usize) {
+        self.next = 0;
+        self.len = len;
+    }
+
+    fn take_next(&mut self) -> Option<Record> {
+        if self.next == self.len {
+            return None;
+        }
+
+        let record = core::mem::replace(&mut self.records[self.next], Record::ZERO);
+        self.next += 1;
+        Some(record)
+    }
+}
+
+impl<B, Record, AddressType, LevelFs, const N: usize> Iterator
+    for FifoStream<'_, B, Record, AddressType, LevelFs, N>
+where
+    B: Block,
+    B::Interface: RegisterInterface<AddressType = AddressType>,
+    Record: Fieldset,
+    AddressType: Address,
+    LevelFs: Fieldset,
+{
+    type Item = Result<Record, <B::Interface as RegisterInterfaceBase>::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.len {
+            match self.operation.read_records(&mut self.records) {
+                Ok(len) => self.refilled(len),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        self.take_next().map(Ok)
+    }
+}
+
+/// The way the fill level of a FIFO is known
+#[doc(hidden)]
+pub trait FifoLevel<AddressType: Address> {
+    /// Read how many records are available, or `None` if that's unknown
+    fn read_available<I>(&self, interface: &mut I) -> Result<Option<usize>, I::Error>
+    where
+        I: RegisterInterface<AddressType = AddressType>;
+
+    /// Async version of [`Self::read_available`]
+    async fn read_available_async<I>(&self, interface: &mut I) -> Result<Option<usize>, I::Error>
+    where
+        I: AsyncRegisterInterface<AddressType = AddressType>;
+}
+
+/// The FIFO has no fill level, so all records that are asked for are read
+#[doc(hidden)]
+pub struct NoLevel;
+
+impl<AddressType: Address> FifoLevel<AddressType> for NoLevel {
+    fn read_available<I>(&self, _interface: &mut I) -> Result<Option<usize>, I::Error>
+    where
+        I: RegisterInterface<AddressType = AddressType>,
+    {
+        Ok(None)
+    }
+
+    async fn read_available_async<I>(&self, _interface: &mut I) -> Result<Option<usize>, I::Error>
+    where
+        I: AsyncRegisterInterface<AddressType = AddressType>,
+    {
+        Ok(None)
+    }
+}
+
+/// The fill level of the FIFO is a field of another register
+#[doc(hidden)]
+pub struct RegisterLevel<AddressType, LevelFs> {
+    address: AddressType,
+    available: fn(&LevelFs) -> usize,
+}
+
+impl<AddressType, LevelFs> RegisterLevel<AddressType, LevelFs> {
+    #[doc(hidden)]
+    pub fn new(address: AddressType, available: fn(&LevelFs) -> usize) -> Self {
+        Self { address, available }
+    }
+}
+
+impl<AddressType: Address, LevelFs: Fieldset> FifoLevel<AddressType>
+    for RegisterLevel<AddressType, LevelFs>
+{
+    fn read_available<I>(&self, interface: &mut I) -> Result<Option<usize>, I::Error>
+    where
+        I: RegisterInterface<AddressType = AddressType>,
+    {
+        let mut level = LevelFs::ZERO;
+        interface.read_register(self.address, level.as_slice_mut(), &LevelFs::METADATA)?;
+        Ok(Some((self.available)(&level)))
+    }
+
+    async fn read_available_async<I>(&self, interface: &mut I) -> Result<Option<usize>, I::Error>
+    where
+        I: AsyncRegisterInterface<AddressType = AddressType>,
+    {
+        let mut level = LevelFs::ZERO;
+        interface
+            .read_register(self.address, level.as_slice_mut(), &LevelFs::METADATA)
+            .await?;
+        Ok(Some((self.available)(&level)))
+    }
+}
+
+fn records_as_slice_mut<Record: Fieldset>(records: &mut [Record]) -> &mut [u8] {
+    // Safety: Fieldsets have a stable ABI without padding, so the records are a contiguous run of bytes
+    unsafe {
+        core::slice::from_raw_parts_mut(
+            records.as_mut_ptr().cast::<u8>(),
+            core::mem::size_of_val(records),
+        )
+    }
+}
@@ -0,0 +1,267 @@
+//! Support for the interfaces defined in the DDSL.
+//! The generated interfaces use these functions to implement the register interface traits.
+
+use crate::ByteOrder;
+
+pub use embedded_hal;
+pub use embedded_hal_async;
+
+use embedded_hal::{i2c, spi};
+
+/// Describes how a register address is sent over the bus
+pub struct Framing {
+    /// The amount of bytes the address is sent with. Max 8
+    pub address_size_bytes: usize,
+    /// The byte order the address is sent in
+    pub address_byte_order: ByteOrder,
+    /// The bit that's set in the address when reading
+    pub read_bit: Option<u32>,
+    /// The bit that's set in the address when writing
+    pub write_bit: Option<u32>,
+    /// The bit that's set in the address when more than one byte is transferred
+    pub increment_bit: Option<u32>,
+    /// The amount of bytes between the address and the data when reading. Max 8
+    pub dummy_bytes: usize,
+}
+
+impl Framing {
+    /// Create the bytes of the address that is sent before the data
+    fn header(&self, address: u64, read: bool, len: usize) -> Header {
+        let mut value = address;
+
+        let flag_bit = if read { self.read_bit } else { self.write_bit };
+        if let Some(bit) = flag_bit {
+            value |= 1 << bit;
+        }
+        if let Some(bit) = self.increment_bit
+            && len > 1
+        {
+            value |= 1 << bit;
+        }
+
+        let bytes = match self.address_byte_order {
+            ByteOrder::LE => {
+                let mut bytes = value.to_le_bytes();
+                bytes.copy_within(..self.address_size_bytes, 0);
+                bytes
+            }
+            ByteOrder::BE => {
+                let mut bytes = value.to_be_bytes();
+                bytes.copy_within(8 - self.address_size_bytes.., 0);
+                bytes
+            }
+        };
+
+        Header {
+            bytes,
+            len: self.address_size_bytes,
+        }
+    }
+
+    /// Write a register over an SPI device
+    pub fn spi_write<S: spi::SpiDevice>(
+        &self,
+        spi: &mut S,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), S::Error> {
+        let header = self.header(address, false, data.len());
+        spi.transaction(&mut [
+            spi::Operation::Write(header.as_slice()),
+            spi::Operation::Write(data),
+        ])
+    }
+
+    /// Read a register over an SPI device
+    pub fn spi_read<S: spi::SpiDevice>(
+        &self,
+        spi: &mut S,
+        address: u64,
+        data: &mut [u8],
+    ) -> Result<(), S::Error> {
+        let header = self.header(address, true, data.len());
+        let mut dummy = [0; 8];
+
+        if self.dummy_bytes == 0 {
+            spi.transaction(&mut [
+                spi::Operation::Write(header.as_slice()),
+                spi::Operation::Read(data),
+            ])
+        } else {
+            spi.transaction(&mut [
+                spi::Operation::Write(header.as_slice()),
+                spi::Operation::Read(&mut dummy[..self.dummy_bytes]),
+                spi::Operation::Read(data),
+            ])
+        }
+    }
+
+    /// Write a register over an SPI device
+    pub async fn spi_write_async<S: embedded_hal_async::spi::SpiDevice>(
+        &self,
+        spi: &mut S,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), S::Error> {
+        let header = self.header(address, false, data.len());
+        spi.transaction(&mut [
+            spi::Operation::Write(header.as_slice()),
+            spi::Operation::Write(data),
+        ])
+        .await
+    }
+
+    /// Read a register over an SPI device
+    pub async fn spi_read_async<S: embedded_hal_async::spi::SpiDevice>(
+        &self,
+        spi: &mut S,
+        address: u64,
+        data: &mut [u8],
+    ) -> Result<(), S::Error> {
+        let header = self.header(address, true, data.len());
+        let mut dummy = [0; 8];
+
+        if self.dummy_bytes == 0 {
+            spi.transaction(&mut [
+                spi::Operation::Write(header.as_slice()),
+                spi::Operation::Read(data),
+            ])
+            .await
+        } else {
+            spi.transaction(&mut [
+                spi::Operation::Write(header.as_slice()),
+                spi::Operation::Read(&mut dummy[..self.dummy_bytes]),
+                spi::Operation::Read(data),
+            ])
+            .await
+        }
+    }
+
+    /// Write a register over an I2C bus
+    pub fn i2c_write<I: i2c::I2c>(
+        &self,
+        i2c: &mut I,
+        device_address: u8,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), I::Error> {
+        let header = self.header(address, false, data.len());
+        i2c.transaction(
+            device_address,
+            &mut [
+                i2c::Operation::Write(header.as_slice()),
+                i2c::Operation::Write(data),
+            ],
+        )
+    }
+
+    /// Read a register over an I2C bus
+    pub fn i2c_read<I: i2c::I2c>(
+        &self,
+        i2c: &mut I,
+        device_address: u8,
+        address: u64,
+        data: &mut [u8],
+    ) -> Result<(), I::Error> {
+        let header = self.header(address, true, data.len());
+        let mut dummy = [0; 8];
+
+        if self.dummy_bytes == 0 {
+            i2c.write_read(device_address, header.as_slice(), data)
+        } else {
+            i2c.transaction(
+                device_address,
+                &mut [
+                    i2c::Operation::Write(header.as_slice()),
+                    i2c::Operation::Read(&mut dummy[..self.dummy_bytes]),
+                    i2c::Operation::Read(data),
+                ],
+            )
+        }
+    }
+
+    /// Write a register over an I2C bus
+    pub async fn i2c_write_async<I: embedded_hal_async::i2c::I2c>(
+        &self,
+        i2c: &mut I,
+        device_address: u8,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), I::Error> {
+        let header = self.header(address, false, data.len());
+        i2c.transaction(
+            device_address,
+            &mut [
+                i2c::Operation::Write(header.as_slice()),
+                i2c::Operation::Write(data),
+            ],
+        )
+        .await
+    }
+
+    /// Read a register over an I2C bus
+    pub async fn i2c_read_async<I: embedded_hal_async::i2c::I2c>(
+        &self,
+        i2c: &mut I,
+        device_address: u8,
+        address: u64,
+        data: &mut [u8],
+    ) -> Result<(), I::Error> {
+        let header = self.header(address, true, data.len());
+        let mut dummy = [0; 8];
+
+        if self.dummy_bytes == 0 {
+            i2c.write_read(device_address, header.as_slice(), data)
+                .await
+        } else {
+            i2c.transaction(
+                device_address,
+                &mut [
+                    i2c::Operation::Write(header.as_slice()),
+                    i2c::Operation::Read(&mut dummy[..self.dummy_bytes]),
+                    i2c::Operation::Read(data),
+                ],
+            )
+            .await
+        }
+    }
+}
+
+/// The address bytes that are sent before the data
+struct Header {
+    bytes: [u8; 8],
+    len: usize,
+}
+
+impl Header {
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAMING: Framing = Framing {
+        address_size_bytes: 2,
+        address_byte_order: ByteOrder::BE,
+        read_bit: Some(15),
+        write_bit: None,
+        increment_bit: Some(14),
+        dummy_bytes: 0,
+    };
+
+    #[test]
+    fn header_sets_flag_bits() {
+        assert_eq!(FRAMING.header(0x12, false, 1).as_slice(), [0x00, 0x12]);
+        assert_eq!(FRAMING.header(0x12, true, 1).as_slice(), [0x80, 0x12]);
+        assert_eq!(FRAMING.header(0x12, true, 2).as_slice(), [0xC0, 0x12]);
+
+        let le = Framing {
+            address_byte_order: ByteOrder::LE,
+            ..FRAMING
+        };
+        assert_eq!(le.header(0x12, false, 2).as_slice(), [0x12, 0x40]);
+    }
+}
@@ -6,16 +6,22 @@
 use core::fmt::{Debug, Display};
 use core::marker::PhantomData;
 
+mod ascii;
 mod buffer;
 mod command;
 mod fieldset;
+mod fifo;
+mod memory;
 mod register;
 
 mod repeats;
 
+pub use ascii::*;
 pub use buffer::*;
 pub use command::*;
 pub use fieldset::*;
+pub use fifo::*;
+pub use memory::*;
 pub use register::*;
 
 pub use repeats::*;
@@ -23,6 +29,10 @@ pub use repeats::*;
 #[doc(hidden)]
 pub mod ops;
 
+#[cfg(feature = "embedded-hal-10")]
+#[doc(hidden)]
+pub mod hal;
+
 #[cfg(feature = "macros")]
 pub use device_driver_macros::*;
 
@@ -122,6 +132,12 @@ pub trait Block: Sized {
     }
 }
 
+/// Trait implemented on the generated blocks that contain memories.
+pub trait MemoryBlock: Block {
+    /// The memory address type
+    type MemoryAddressType: Address;
+}
+
 /// Value representing the byte order
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -155,12 +171,126 @@ impl<T: Display> Display for ConversionError<T> {
 
 impl<T: Display + Debug> core::error::Error for ConversionError<T> {}
 
+/// The error returned by the generated checked field setters.
+/// It contains the base type of the field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValueOutOfRange<T> {
+    /// The value that was tried to be set
+    pub value: T,
+    /// The lowest value the field accepts
+    pub min: T,
+    /// The highest value the field accepts
+    pub max: T,
+    /// The name of the field
+    pub field: &'static str,
+}
+
+impl<T: Display> Display for ValueOutOfRange<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Value `{}` is out of the range `{}..={}` of field `{}`",
+            self.value, self.min, self.max, self.field
+        )
+    }
+}
+
+impl<T: Display + Debug> core::error::Error for ValueOutOfRange<T> {}
+
+/// The error returned by the generated routines that poll a register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RoutineError<E> {
+    /// The interface returned an error
+    Interface(E),
+    /// A poll read the register the maximum amount of times without seeing the expected values
+    Timeout,
+}
+
+impl<E> From<E> for RoutineError<E> {
+    fn from(value: E) -> Self {
+        Self::Interface(value)
+    }
+}
+
+impl<E: Display> Display for RoutineError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Interface(error) => write!(f, "Interface error: {error}"),
+            Self::Timeout => write!(f, "Timed out while polling a register"),
+        }
+    }
+}
+
+impl<E: Display + Debug> core::error::Error for RoutineError<E> {}
+
+/// The error returned by the operations at an index of a register when some of its indices override its access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IndexAccessError<E> {
+    /// The interface returned an error
+    Interface(E),
+    /// The register can't be read at this index
+    NotReadable {
+        /// The value of the index as given by [`Repeating::index_value`]
+        index: i32,
+    },
+    /// The register can't be written at this index
+    NotWritable {
+        /// The value of the index as given by [`Repeating::index_value`]
+        index: i32,
+    },
+}
+
+impl<E> From<E> for IndexAccessError<E> {
+    fn from(value: E) -> Self {
+        Self::Interface(value)
+    }
+}
+
+impl<E: Display> Display for IndexAccessError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Interface(error) => write!(f, "Interface error: {error}"),
+            Self::NotReadable { index } => write!(f, "The register can't be read at index {index}"),
+            Self::NotWritable { index } => {
+                write!(f, "The register can't be written at index {index}")
+            }
+        }
+    }
+}
+
+impl<E: Display + Debug> core::error::Error for IndexAccessError<E> {}
+
+/// The error returned by the generated state machine transitions.
+/// It gives the device back, since the state it's in is unknown after the error.
+#[derive(Debug)]
+pub struct TransitionError<D, E> {
+    /// The device the transition was run on
+    pub device: D,
+    /// The error of the interface
+    pub error: E,
+}
+
+impl<D, E: Display> Display for TransitionError<D, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Could not complete the transition: {}", self.error)
+    }
+}
+
+impl<D: Debug, E: Display + Debug> core::error::Error for TransitionError<D, E> {}
+
 #[doc(hidden)]
 pub struct WO;
 #[doc(hidden)]
 pub struct RO;
 #[doc(hidden)]
 pub struct RW;
+/// The access of a register of which some indices narrow the access.
+/// The operations at an index check the access of that index.
+#[doc(hidden)]
+pub struct PerIndex<A>(PhantomData<A>);
 
 #[doc(hidden)]
 pub trait ReadCapability {}
@@ -174,6 +304,22 @@ impl ReadCapability for RO {}
 impl WriteCapability for RW {}
 impl ReadCapability for RW {}
 
+impl<A: WriteCapability> WriteCapability for PerIndex<A> {}
+impl<A: ReadCapability> ReadCapability for PerIndex<A> {}
+
+/// The access of a register that is the same for all of its indices.
+/// Plans at an index are only offered for these, since a plan can't check the access of its index.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "some indices of this register narrow its access, so it has no plans at an index",
+    note = "use the `_at` operations of the register instead, which check the access of the index"
+)]
+pub trait UniformAccess {}
+
+impl UniformAccess for WO {}
+impl UniformAccess for RO {}
+impl UniformAccess for RW {}
+
 trait SealedAddress {}
 
 /// A trait implemented for the types that can be used as an address
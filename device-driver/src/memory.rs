@@ -0,0 +1,456 @@
+use crate::{Address, Block, ReadCapability, WriteCapability};
+use core::fmt::{Debug, Display};
+use core::marker::PhantomData;
+
+/// Common properties shared by [`MemoryInterface`] & [`AsyncMemoryInterface`]
+pub trait MemoryInterfaceBase {
+    /// The error type
+    type Error;
+    /// The address type used by this interface
+    type AddressType: Address;
+}
+
+impl<T: MemoryInterfaceBase> MemoryInterfaceBase for &mut T {
+    type Error = T::Error;
+    type AddressType = T::AddressType;
+}
+
+#[diagnostic::on_unimplemented(
+    label = "cannot use blocking memory operations when the device interface doesn't know how to read and write memories",
+    note = "to enable memory operations, implement the trait on this type"
+)]
+/// A trait to represent the interface to the device.
+///
+/// This is called to read from and write to memories.
+pub trait MemoryInterface: MemoryInterfaceBase {
+    /// Write the data to the memory at the given address.
+    ///
+    /// When the memory has pages, the data never crosses a page boundary.
+    fn write_memory(&mut self, address: Self::AddressType, data: &[u8]) -> Result<(), Self::Error>;
+    /// Read the memory at the given address into the data.
+    fn read_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
+#[diagnostic::do_not_recommend]
+impl<T: MemoryInterface> MemoryInterface for &mut T {
+    fn write_memory(&mut self, address: Self::AddressType, data: &[u8]) -> Result<(), Self::Error> {
+        (*self).write_memory(address, data)
+    }
+
+    fn read_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        (*self).read_memory(address, data)
+    }
+}
+
+#[diagnostic::on_unimplemented(
+    label = "cannot use async memory operations when the device interface doesn't know how to read and write memories",
+    note = "to enable memory operations, implement the trait on this type"
+)]
+/// A trait to represent the interface to the device.
+///
+/// This is called to read from and write to memories.
+pub trait AsyncMemoryInterface: MemoryInterfaceBase {
+    /// Write the data to the memory at the given address.
+    ///
+    /// When the memory has pages, the data never crosses a page boundary.
+    async fn write_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &[u8],
+    ) -> Result<(), Self::Error>;
+    /// Read the memory at the given address into the data.
+    async fn read_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
+#[diagnostic::do_not_recommend]
+impl<T: AsyncMemoryInterface> AsyncMemoryInterface for &mut T {
+    fn write_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &[u8],
+    ) -> impl Future<Output = Result<(), Self::Error>> {
+        (*self).write_memory(address, data)
+    }
+
+    fn read_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+    ) -> impl Future<Output = Result<(), Self::Error>> {
+        (*self).read_memory(address, data)
+    }
+}
+
+#[diagnostic::on_unimplemented(
+    label = "cannot use blocking memory erase operations when the device interface doesn't know how to erase memories",
+    note = "to enable memory erase operations, implement the trait on this type"
+)]
+/// A trait to represent the interface to the device.
+///
+/// This is called to erase memories that have an erase size.
+pub trait MemoryEraseInterface: MemoryInterfaceBase {
+    /// Erase the block of the memory that starts at the given address.
+    ///
+    /// The length is always the erase size of the memory.
+    fn erase_memory(&mut self, address: Self::AddressType, len: usize) -> Result<(), Self::Error>;
+}
+
+#[diagnostic::do_not_recommend]
+impl<T: MemoryEraseInterface> MemoryEraseInterface for &mut T {
+    fn erase_memory(&mut self, address: Self::AddressType, len: usize) -> Result<(), Self::Error> {
+        (*self).erase_memory(address, len)
+    }
+}
+
+#[diagnostic::on_unimplemented(
+    label = "cannot use async memory erase operations when the device interface doesn't know how to erase memories",
+    note = "to enable memory erase operations, implement the trait on this type"
+)]
+/// A trait to represent the interface to the device.
+///
+/// This is called to erase memories that have an erase size.
+pub trait AsyncMemoryEraseInterface: MemoryInterfaceBase {
+    /// Erase the block of the memory that starts at the given address.
+    ///
+    /// The length is always the erase size of the memory.
+    async fn erase_memory(
+        &mut self,
+        address: Self::AddressType,
+        len: usize,
+    ) -> Result<(), Self::Error>;
+}
+
+#[diagnostic::do_not_recommend]
+impl<T: AsyncMemoryEraseInterface> AsyncMemoryEraseInterface for &mut T {
+    fn erase_memory(
+        &mut self,
+        address: Self::AddressType,
+        len: usize,
+    ) -> impl Future<Output = Result<(), Self::Error>> {
+        (*self).erase_memory(address, len)
+    }
+}
+
+/// The layout of a memory as described in the driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MemoryLayout {
+    /// The size of the memory in bytes
+    pub size_bytes: u32,
+    /// The amount of bytes of one address. Offsets and lengths of reads must be a multiple of this
+    pub word_size_bytes: u32,
+    /// The size of the pages of the memory in bytes. Writes are split at the page boundaries
+    pub page_size_bytes: Option<u32>,
+    /// Offsets and lengths of writes must be a multiple of this
+    pub write_granularity_bytes: u32,
+    /// The size of the blocks that are erased at once in bytes
+    pub erase_size_bytes: Option<u32>,
+}
+
+/// The error returned by the memory operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MemoryError<E> {
+    /// The error of the interface
+    Interface(E),
+    /// The range doesn't fit in the memory
+    OutOfBounds {
+        /// The offset of the range in bytes
+        offset: u32,
+        /// The length of the range in bytes
+        len: usize,
+    },
+    /// The offset or the length of the range isn't a multiple of what the operation needs
+    Unaligned {
+        /// The offset of the range in bytes
+        offset: u32,
+        /// The length of the range in bytes
+        len: usize,
+        /// The amount of bytes the offset and length must be a multiple of
+        alignment: u32,
+    },
+}
+
+impl<E: Display> Display for MemoryError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MemoryError::Interface(error) => write!(f, "Memory interface error: {error}"),
+            MemoryError::OutOfBounds { offset, len } => write!(
+                f,
+                "Range of {len} bytes at offset {offset} doesn't fit in the memory"
+            ),
+            MemoryError::Unaligned {
+                offset,
+                len,
+                alignment,
+            } => write!(
+                f,
+                "Range of {len} bytes at offset {offset} isn't aligned to {alignment} bytes"
+            ),
+        }
+    }
+}
+
+impl<E: Display + Debug> core::error::Error for MemoryError<E> {}
+
+#[doc(hidden)]
+pub struct Erasable;
+
+#[doc(hidden)]
+pub trait EraseCapability {}
+
+impl EraseCapability for Erasable {}
+
+/// Intermediate type for doing memory operations
+///
+/// The offsets are in bytes from the start of the memory.
+pub struct MemoryOperation<'b, B, AddressType, Access, Erase>
+where
+    B: Block,
+    B::Interface: MemoryInterfaceBase<AddressType = AddressType>,
+    AddressType: Address,
+{
+    block: &'b mut B,
+    address: AddressType,
+    layout: MemoryLayout,
+    _phantom: PhantomData<(Access, Erase)>,
+}
+
+impl<'b, B, AddressType, Access, Erase> MemoryOperation<'b, B, AddressType, Access, Erase>
+where
+    B: Block,
+    B::Interface: MemoryInterfaceBase<AddressType = AddressType>,
+    AddressType: Address,
+{
+    #[doc(hidden)]
+    pub fn new(
+        block: &'b mut B,
+        address: <B::Interface as MemoryInterfaceBase>::AddressType,
+        layout: MemoryLayout,
+    ) -> Self {
+        Self {
+            block,
+            address,
+            layout,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The layout of the memory
+    pub fn layout(&self) -> &MemoryLayout {
+        &self.layout
+    }
+
+    /// Read the memory at the given offset into the buffer.
+    ///
+    /// The offset and the length of the buffer must be a multiple of the word size.
+    pub fn read(
+        &mut self,
+        offset: u32,
+        buf: &mut [u8],
+    ) -> Result<(), MemoryError<<B::Interface as MemoryInterfaceBase>::Error>>
+    where
+        B::Interface: MemoryInterface,
+        Access: ReadCapability,
+    {
+        self.check(offset, buf.len(), self.layout.word_size_bytes)?;
+        let address = self.address_of(offset);
+        self.block
+            .interface()
+            .read_memory(address, buf)
+            .map_err(MemoryError::Interface)
+    }
+
+    /// Read the memory at the given offset into the buffer.
+    ///
+    /// The offset and the length of the buffer must be a multiple of the word size.
+    pub async fn read_async(
+        &mut self,
+        offset: u32,
+        buf: &mut [u8],
+    ) -> Result<(), MemoryError<<B::Interface as MemoryInterfaceBase>::Error>>
+    where
+        B::Interface: AsyncMemoryInterface,
+        Access: ReadCapability,
+    {
+        self.check(offset, buf.len(), self.layout.word_size_bytes)?;
+        let address = self.address_of(offset);
+        self.block
+            .interface()
+            .read_memory(address, buf)
+            .await
+            .map_err(MemoryError::Interface)
+    }
+
+    /// Write the data to the memory at the given offset.
+    ///
+    /// The offset and the length of the data must be a multiple of the write granularity.
+    /// The data is split at the page boundaries, so every write to the interface stays within one page.
+    pub fn write(
+        &mut self,
+        mut offset: u32,
+        mut data: &[u8],
+    ) -> Result<(), MemoryError<<B::Interface as MemoryInterfaceBase>::Error>>
+    where
+        B::Interface: MemoryInterface,
+        Access: WriteCapability,
+    {
+        self.check(offset, data.len(), self.layout.write_granularity_bytes)?;
+
+        while !data.is_empty() {
+            let (chunk, rest) = data.split_at(self.page_chunk_len(offset, data.len()));
+            let address = self.address_of(offset);
+            self.block
+                .interface()
+                .write_memory(address, chunk)
+                .map_err(MemoryError::Interface)?;
+
+            // The chunk fits in the memory, so this can't overflow
+            offset += chunk.len() as u32;
+            data = rest;
+        }
+
+        Ok(())
+    }
+
+    /// Write the data to the memory at the given offset.
+    ///
+    /// The offset and the length of the data must be a multiple of the write granularity.
+    /// The data is split at the page boundaries, so every write to the interface stays within one page.
+    pub async fn write_async(
+        &mut self,
+        mut offset: u32,
+        mut data: &[u8],
+    ) -> Result<(), MemoryError<<B::Interface as MemoryInterfaceBase>::Error>>
+    where
+        B::Interface: AsyncMemoryInterface,
+        Access: WriteCapability,
+    {
+        self.check(offset, data.len(), self.layout.write_granularity_bytes)?;
+
+        while !data.is_empty() {
+            let (chunk, rest) = data.split_at(self.page_chunk_len(offset, data.len()));
+            let address = self.address_of(offset);
+            self.block
+                .interface()
+                .write_memory(address, chunk)
+                .await
+                .map_err(MemoryError::Interface)?;
+
+            // The chunk fits in the memory, so this can't overflow
+            offset += chunk.len() as u32;
+            data = rest;
+        }
+
+        Ok(())
+    }
+
+    /// Erase the given range of the memory.
+    ///
+    /// The offset and the length must be a multiple of the erase size.
+    /// Every erase block in the range is erased with a separate call to the interface.
+    pub fn erase(
+        &mut self,
+        mut offset: u32,
+        len: usize,
+    ) -> Result<(), MemoryError<<B::Interface as MemoryInterfaceBase>::Error>>
+    where
+        B::Interface: MemoryEraseInterface,
+        Access: WriteCapability,
+        Erase: EraseCapability,
+    {
+        let erase_size = self.erase_size();
+        self.check(offset, len, erase_size)?;
+
+        for _ in 0..len / erase_size as usize {
+            let address = self.address_of(offset);
+            self.block
+                .interface()
+                .erase_memory(address, erase_size as usize)
+                .map_err(MemoryError::Interface)?;
+            offset += erase_size;
+        }
+
+        Ok(())
+    }
+
+    /// Erase the given range of the memory.
+    ///
+    /// The offset and the length must be a multiple of the erase size.
+    /// Every erase block in the range is erased with a separate call to the interface.
+    pub async fn erase_async(
+        &mut self,
+        mut offset: u32,
+        len: usize,
+    ) -> Result<(), MemoryError<<B::Interface as MemoryInterfaceBase>::Error>>
+    where
+        B::Interface: AsyncMemoryEraseInterface,
+        Access: WriteCapability,
+        Erase: EraseCapability,
+    {
+        let erase_size = self.erase_size();
+        self.check(offset, len, erase_size)?;
+
+        for _ in 0..len / erase_size as usize {
+            let address = self.address_of(offset);
+            self.block
+                .interface()
+                .erase_memory(address, erase_size as usize)
+                .await
+                .map_err(MemoryError::Interface)?;
+            offset += erase_size;
+        }
+
+        Ok(())
+    }
+
+    /// Check that the range fits in the memory and is aligned
+    fn check<E>(&self, offset: u32, len: usize, alignment: u32) -> Result<(), MemoryError<E>> {
+        if u64::from(offset) + len as u64 > u64::from(self.layout.size_bytes) {
+            return Err(MemoryError::OutOfBounds { offset, len });
+        }
+
+        if !offset.is_multiple_of(alignment) || !len.is_multiple_of(alignment as usize) {
+            return Err(MemoryError::Unaligned {
+                offset,
+                len,
+                alignment,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// The address of the word at the given offset
+    fn address_of(&self, offset: u32) -> AddressType {
+        // Memories are at most 2 GiB, so the word index always fits
+        self.address
+            .add((offset / self.layout.word_size_bytes) as i32)
+    }
+
+    /// The length of the next chunk to write, so it doesn't cross a page boundary
+    fn page_chunk_len(&self, offset: u32, len: usize) -> usize {
+        match self.layout.page_size_bytes {
+            Some(page_size) => len.min((page_size - offset % page_size) as usize),
+            None => len,
+        }
+    }
+
+    fn erase_size(&self) -> u32 {
+        self.layout
+            .erase_size_bytes
+            .expect("the generated code only allows erasing memories with an erase size")
+    }
+}
@@ -12,39 +12,64 @@ pub unsafe fn load<T, ByteO: ByteOrder>(data: &[u8], start: usize, end: usize) -
 where
     T: Default + Shl<usize, Output = T> + BitOrAssign + Integer + TruncateToU8,
 {
-    #[inline(never)]
-    unsafe fn inner<T, ByteO: ByteOrder>(data: &[u8], start: usize, end: usize) -> T
-    where
-        T: Default + Shl<usize, Output = T> + BitOrAssign + TruncateToU8,
-    {
-        // Start with 0
-        let mut output = T::default();
-
-        // Go through start..end, but in a while so we have more control over the index
-        let mut i = start;
-        while i <= end {
-            let byte = unsafe { ByteO::get_byte_from_index(data, i) };
-
-            if i.is_multiple_of(8) & (i + 8 <= end) {
-                // We are byte aligned and have a full byte of space left
-                // Do a whole byte in one go for extra performance
-                output |= T::detruncate(byte) << (i - start);
-                i += 8;
-            } else {
-                // Go bit by bit
-                // Move the target bit all the way to the right so we know where it is
-                let bit = (byte >> (i % 8)) & 1;
-                // Shift the bit the proper amount to the left. The bit at `start` should be at index 0
-                output |= T::detruncate(bit) << (i - start);
-                i += 1;
-            }
-        }
+    T::cast_deduplicate_back(unsafe { load_raw::<T::DedupType, ByteO>(data, start, end) })
+        .sign_extend(end - start)
+}
 
-        output
+/// Load an integer from a byte slice where the bits are split over multiple `start`..=`end` ranges.
+/// The first range holds the least significant bits of the integer and every next range holds the bits above it.
+/// The integer is loaded with the [LE] or [BE] byte order generic param and using lsb0 bit order.
+///
+/// ## Safety:
+///
+/// All `start` and `end` pairs must lie in the range `0..=data.len()*8`
+/// and the ranges together must not be larger than the integer
+#[inline(always)]
+#[must_use]
+pub unsafe fn load_split<T, ByteO: ByteOrder>(data: &[u8], parts: &[(usize, usize)]) -> T
+where
+    T: Default + Shl<usize, Output = T> + BitOrAssign + Integer + TruncateToU8,
+{
+    let mut output = T::DedupType::default();
+    let mut offset = 0;
+
+    for &(start, end) in parts {
+        output |= unsafe { load_raw::<T::DedupType, ByteO>(data, start, end) } << offset;
+        offset += end - start + 1;
     }
 
-    T::cast_deduplicate_back(unsafe { inner::<T::DedupType, ByteO>(data, start, end) })
-        .sign_extend(end - start)
+    T::cast_deduplicate_back(output).sign_extend(offset - 1)
+}
+
+#[inline(never)]
+unsafe fn load_raw<T, ByteO: ByteOrder>(data: &[u8], start: usize, end: usize) -> T
+where
+    T: Default + Shl<usize, Output = T> + BitOrAssign + TruncateToU8,
+{
+    // Start with 0
+    let mut output = T::default();
+
+    // Go through start..end, but in a while so we have more control over the index
+    let mut i = start;
+    while i <= end {
+        let byte = unsafe { ByteO::get_byte_from_index(data, i) };
+
+        if i.is_multiple_of(8) & (i + 8 <= end) {
+            // We are byte aligned and have a full byte of space left
+            // Do a whole byte in one go for extra performance
+            output |= T::detruncate(byte) << (i - start);
+            i += 8;
+        } else {
+            // Go bit by bit
+            // Move the target bit all the way to the right so we know where it is
+            let bit = (byte >> (i % 8)) & 1;
+            // Shift the bit the proper amount to the left. The bit at `start` should be at index 0
+            output |= T::detruncate(bit) << (i - start);
+            i += 1;
+        }
+    }
+
+    output
 }
 
 /// Store an integer into a byte slice located at the `start`..=`end` range.
@@ -58,38 +83,132 @@ pub unsafe fn store<T, ByteO: ByteOrder>(value: T, start: usize, end: usize, dat
 where
     T: Copy + TruncateToU8 + Shr<usize, Output = T> + Integer,
 {
-    #[inline(never)]
-    unsafe fn inner<T, ByteO: ByteOrder>(value: T, start: usize, end: usize, data: &mut [u8])
-    where
-        T: Copy + TruncateToU8 + Shr<usize, Output = T>,
-    {
-        // Go through start..end, but in a while so we have more control over the index
-        let mut i = start;
-        while i <= end {
-            let byte = unsafe { ByteO::get_byte_from_index_mut(data, i) };
-
-            if i.is_multiple_of(8) & (i + 8 <= end) {
-                // We are byte aligned and have a full byte of space left
-                // Do a whole byte in one go for extra performance
-                *byte = (value >> (i - start)).truncate();
-                i += 8;
-            } else {
-                // Go bit by bit
-                // Move the target bit all the way to the right so we know where it is
-                let bit = (value >> (i - start)).truncate() & 1;
-
-                // Clear the bit
-                *byte &= !(1 << (i % 8));
-                // If the bit is set, set the bit in the byte
-                // Not if statement here since this is faster and smaller
-                *byte |= bit << (i % 8);
-
-                i += 1;
-            }
+    unsafe { store_raw::<T::DedupType, ByteO>(value.cast_deduplicate(), start, end, data) }
+}
+
+/// Store an integer into a byte slice where the bits are split over multiple `start`..=`end` ranges.
+/// The first range gets the least significant bits of the integer and every next range gets the bits above it.
+/// The integer is stored with the [LE] or [BE] byte order generic param and using lsb0 bit order.
+///
+/// ## Safety:
+///
+/// All `start` and `end` pairs must lie in the range `0..=data.len()*8`
+/// and the ranges together must not be larger than the integer
+#[inline(always)]
+pub unsafe fn store_split<T, ByteO: ByteOrder>(value: T, parts: &[(usize, usize)], data: &mut [u8])
+where
+    T: Copy + TruncateToU8 + Shr<usize, Output = T> + Integer,
+{
+    let value = value.cast_deduplicate();
+    let mut offset = 0;
+
+    for &(start, end) in parts {
+        unsafe { store_raw::<T::DedupType, ByteO>(value >> offset, start, end, data) };
+        offset += end - start + 1;
+    }
+}
+
+#[inline(never)]
+unsafe fn store_raw<T, ByteO: ByteOrder>(value: T, start: usize, end: usize, data: &mut [u8])
+where
+    T: Copy + TruncateToU8 + Shr<usize, Output = T>,
+{
+    // Go through start..end, but in a while so we have more control over the index
+    let mut i = start;
+    while i <= end {
+        let byte = unsafe { ByteO::get_byte_from_index_mut(data, i) };
+
+        if i.is_multiple_of(8) & (i + 8 <= end) {
+            // We are byte aligned and have a full byte of space left
+            // Do a whole byte in one go for extra performance
+            *byte = (value >> (i - start)).truncate();
+            i += 8;
+        } else {
+            // Go bit by bit
+            // Move the target bit all the way to the right so we know where it is
+            let bit = (value >> (i - start)).truncate() & 1;
+
+            // Clear the bit
+            *byte &= !(1 << (i % 8));
+            // If the bit is set, set the bit in the byte
+            // Not if statement here since this is faster and smaller
+            *byte |= bit << (i % 8);
+
+            i += 1;
         }
     }
+}
 
-    unsafe { inner::<T::DedupType, ByteO>(value.cast_deduplicate(), start, end, data) }
+/// Get the bytes of a byte aligned field located at the `start`..=`end` range.
+/// The bytes are returned in the order they are stored in, so the byte order only matters for selecting the bytes.
+///
+/// ## Safety:
+///
+/// `start` and `end` must lie in the range `0..data.len()*8`,
+/// `start` must be a multiple of 8 and `end + 1` must be a multiple of 8
+#[inline(always)]
+#[must_use]
+pub unsafe fn load_bytes<ByteO: ByteOrder>(data: &[u8], start: usize, end: usize) -> &[u8] {
+    debug_assert!(start.is_multiple_of(8) && (end + 1).is_multiple_of(8));
+
+    let first = ByteO::get_byte_index(data.len(), start);
+    let last = ByteO::get_byte_index(data.len(), end);
+
+    unsafe { data.get_unchecked(first.min(last)..=first.max(last)) }
+}
+
+/// Store bytes into a byte aligned field located at the `start`..=`end` range.
+/// The bytes are stored in the order they are given, so the byte order only matters for selecting the bytes.
+///
+/// ## Safety:
+///
+/// `start` and `end` must lie in the range `0..data.len()*8`,
+/// `start` must be a multiple of 8 and `end + 1` must be a multiple of 8.
+/// `N` must be the size of the field in bytes
+#[inline(always)]
+pub unsafe fn store_bytes<ByteO: ByteOrder, const N: usize>(
+    value: [u8; N],
+    start: usize,
+    end: usize,
+    data: &mut [u8],
+) {
+    debug_assert!(start.is_multiple_of(8) && (end + 1).is_multiple_of(8));
+    debug_assert_eq!((end + 1 - start) / 8, N);
+
+    let first = ByteO::get_byte_index(data.len(), start);
+    let last = ByteO::get_byte_index(data.len(), end);
+
+    unsafe { data.get_unchecked_mut(first.min(last)..=first.max(last)) }.copy_from_slice(&value);
+}
+
+/// Store an ascii string into a byte aligned field located at the `start`..=`end` range.
+/// The bytes are stored in the order they are given, so the byte order only matters for selecting the bytes.
+/// When the string is shorter than the field, the remaining bytes are set to zero.
+///
+/// The string is not checked to be ascii.
+///
+/// ## Safety:
+///
+/// `start` and `end` must lie in the range `0..data.len()*8`,
+/// `start` must be a multiple of 8 and `end + 1` must be a multiple of 8.
+/// `value` must not be longer than the field
+#[inline(always)]
+pub unsafe fn store_ascii<ByteO: ByteOrder>(
+    value: &str,
+    start: usize,
+    end: usize,
+    data: &mut [u8],
+) {
+    debug_assert!(start.is_multiple_of(8) && (end + 1).is_multiple_of(8));
+    debug_assert!(value.len() <= (end + 1 - start) / 8);
+
+    let first = ByteO::get_byte_index(data.len(), start);
+    let last = ByteO::get_byte_index(data.len(), end);
+    let target = unsafe { data.get_unchecked_mut(first.min(last)..=first.max(last)) };
+
+    let (used, unused) = unsafe { target.split_at_mut_unchecked(value.len()) };
+    used.copy_from_slice(value.as_bytes());
+    unused.fill(0);
 }
 
 /// Little endian byte order
@@ -233,6 +352,181 @@ impl_integer!(i64, usize, !0, cfg(target_pointer_width = "64"));
 impl_integer!(i128, u128, !0);
 impl_integer!(isize, usize, !0);
 
+/// Decode a raw field value that was loaded from the register with the given [Encoding].
+///
+/// `size_bits` is the size of the field in the register.
+#[inline(always)]
+#[must_use]
+pub fn decode<T, E: Encoding<T>>(raw: T, size_bits: usize) -> T {
+    E::decode(raw, size_bits)
+}
+
+/// Encode a field value with the given [Encoding] so it can be stored in the register.
+///
+/// `size_bits` is the size of the field in the register.
+#[inline(always)]
+#[must_use]
+pub fn encode<T, E: Encoding<T>>(value: T, size_bits: usize) -> T {
+    E::encode(value, size_bits)
+}
+
+/// Binary coded decimal encoding. Every nibble contains one decimal digit.
+///
+/// The encoding saturates: nibbles above 9 are decoded as 9
+/// and values with more digits than the field can hold are encoded as the largest value that fits (all nines).
+pub struct Bcd;
+/// Reflected binary (gray code) encoding
+///
+/// The encoding saturates: values larger than the field can hold are encoded as the largest value that fits.
+pub struct Gray;
+/// Sign-magnitude encoding. The top bit of the field is the sign, the other bits are the magnitude.
+///
+/// The encoding saturates: values with a larger magnitude than the field can hold are encoded
+/// as the largest magnitude that fits, with the sign of the value.
+pub struct SignMagnitude;
+/// Ones' complement encoding. A negative value is stored with all bits of its magnitude inverted.
+///
+/// The encoding saturates: values with a larger magnitude than the field can hold are encoded
+/// as the largest magnitude that fits, with the sign of the value.
+pub struct OnesComplement;
+
+/// Interface to the integer encoding functions
+pub trait Encoding<T> {
+    /// Decode the raw value loaded from the register to the value it represents
+    fn decode(raw: T, size_bits: usize) -> T;
+    /// Encode the value to the raw value that can be stored in the register
+    fn encode(value: T, size_bits: usize) -> T;
+}
+
+macro_rules! impl_unsigned_encodings {
+    ($($target:ty),*) => {
+        $(
+            impl Encoding<$target> for Bcd {
+                #[inline]
+                fn decode(mut raw: $target, _size_bits: usize) -> $target {
+                    let mut value: $target = 0;
+                    let mut multiplier: $target = 1;
+
+                    while raw != 0 {
+                        // A nibble above 9 is not a valid digit. It's read as 9
+                        let digit = (raw & 0xF).min(9);
+                        value = value.wrapping_add(digit.wrapping_mul(multiplier));
+                        multiplier = multiplier.wrapping_mul(10);
+                        raw >>= 4;
+                    }
+
+                    value
+                }
+
+                #[inline]
+                fn encode(value: $target, size_bits: usize) -> $target {
+                    // The largest value the digits of the field can hold
+                    let mut max: $target = 0;
+                    for _ in 0..size_bits / 4 {
+                        max = max.wrapping_mul(10).wrapping_add(9);
+                    }
+
+                    let mut value = value.min(max);
+                    let mut raw: $target = 0;
+                    let mut shift = 0;
+
+                    while value != 0 && shift < <$target>::BITS {
+                        raw |= (value % 10) << shift;
+                        value /= 10;
+                        shift += 4;
+                    }
+
+                    raw
+                }
+            }
+
+            impl Encoding<$target> for Gray {
+                #[inline]
+                fn decode(mut raw: $target, _size_bits: usize) -> $target {
+                    let mut shift = 1;
+
+                    while shift < <$target>::BITS {
+                        raw ^= raw >> shift;
+                        shift <<= 1;
+                    }
+
+                    raw
+                }
+
+                #[inline]
+                fn encode(value: $target, size_bits: usize) -> $target {
+                    // The largest value the field can hold
+                    let max = <$target>::MAX >> (<$target>::BITS as usize - size_bits);
+                    let value = value.min(max);
+
+                    value ^ (value >> 1)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_encodings {
+    ($($target:ty),*) => {
+        $(
+            impl Encoding<$target> for SignMagnitude {
+                #[inline]
+                fn decode(raw: $target, size_bits: usize) -> $target {
+                    // The raw value is already sign extended, so the sign is the sign of the raw value
+                    let magnitude = raw & !(<$target>::MAX << (size_bits - 1));
+
+                    if raw < 0 {
+                        magnitude.wrapping_neg()
+                    } else {
+                        magnitude
+                    }
+                }
+
+                #[inline]
+                fn encode(value: $target, size_bits: usize) -> $target {
+                    // The largest magnitude the field can hold
+                    let max = <$target>::MAX >> (<$target>::BITS as usize - size_bits);
+                    let value = value.clamp(-max, max);
+
+                    if value < 0 {
+                        value.wrapping_neg() | (1 << (size_bits - 1))
+                    } else {
+                        value
+                    }
+                }
+            }
+
+            impl Encoding<$target> for OnesComplement {
+                #[inline]
+                fn decode(raw: $target, _size_bits: usize) -> $target {
+                    // The raw value is already sign extended, so we only need to correct the offset of one
+                    if raw < 0 {
+                        raw.wrapping_add(1)
+                    } else {
+                        raw
+                    }
+                }
+
+                #[inline]
+                fn encode(value: $target, size_bits: usize) -> $target {
+                    // The largest magnitude the field can hold
+                    let max = <$target>::MAX >> (<$target>::BITS as usize - size_bits);
+                    let value = value.clamp(-max, max);
+
+                    if value < 0 {
+                        value.wrapping_sub(1)
+                    } else {
+                        value
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_encodings!(u8, u16, u32, u64, u128);
+impl_signed_encodings!(i8, i16, i32, i64, i128);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +616,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn bytes_keep_their_order() {
+        let mut data = [0, 1, 2, 3];
+
+        assert_eq!(unsafe { load_bytes::<LE>(&data, 8, 23) }, &[1, 2]);
+        assert_eq!(unsafe { load_bytes::<BE>(&data, 8, 23) }, &[1, 2]);
+        assert_eq!(unsafe { load_bytes::<BE>(&data, 0, 7) }, &[3]);
+
+        unsafe { store_bytes::<BE, 2>([5, 0], 16, 31, &mut data) };
+        assert_eq!(data, [5, 0, 2, 3]);
+
+        unsafe { store_ascii::<LE>("ab", 16, 31, &mut data) };
+        assert_eq!(data, [5, 0, b'a', b'b']);
+
+        unsafe { store_ascii::<LE>("c", 16, 31, &mut data) };
+        assert_eq!(data, [5, 0, b'c', 0]);
+    }
+
+    #[test]
+    fn split_round_trip() {
+        let mut data = [0; 3];
+
+        unsafe { store_split::<u16, LE>(0b10_1100_0011, &[(0, 7), (16, 17)], &mut data) };
+        assert_eq!(data, [0b1100_0011, 0, 0b10]);
+        assert_eq!(
+            unsafe { load_split::<u16, LE>(&data, &[(0, 7), (16, 17)]) },
+            0b10_1100_0011
+        );
+
+        let mut data = [0; 3];
+        unsafe { store_split::<i16, BE>(-2, &[(4, 7), (20, 21)], &mut data) };
+        assert_eq!(data, [0b11_0000, 0, 0b1110_0000]);
+        assert_eq!(
+            unsafe { load_split::<i16, BE>(&data, &[(4, 7), (20, 21)]) },
+            -2
+        );
+    }
+
     #[test]
     fn twos_complement() {
         for i in 1..=31 {
@@ -334,4 +666,128 @@ mod tests {
             assert_eq!(read_back, -1);
         }
     }
+
+    #[test]
+    fn bcd_round_trip() {
+        assert_eq!(decode::<u8, Bcd>(0x42, 8), 42);
+        assert_eq!(encode::<u8, Bcd>(42, 8), 0x42);
+        assert_eq!(decode::<u32, Bcd>(0x1234_5678, 32), 12345678);
+
+        for i in 0..10000u16 {
+            assert_eq!(decode::<u16, Bcd>(encode::<u16, Bcd>(i, 16), 16), i);
+        }
+    }
+
+    #[test]
+    fn bcd_saturates() {
+        // Invalid nibbles are read as 9
+        assert_eq!(decode::<u8, Bcd>(0x4F, 8), 49);
+        assert_eq!(decode::<u8, Bcd>(0xA0, 8), 90);
+        assert_eq!(decode::<u16, Bcd>(0xFFFF, 16), 9999);
+
+        // Values that don't fit are written as all nines
+        assert_eq!(encode::<u8, Bcd>(100, 8), 0x99);
+        assert_eq!(encode::<u8, Bcd>(u8::MAX, 8), 0x99);
+        assert_eq!(encode::<u16, Bcd>(1234, 12), 0x999);
+        assert_eq!(encode::<u16, Bcd>(999, 12), 0x999);
+        assert_eq!(encode::<u128, Bcd>(u128::MAX, 128), u128::MAX / 15 * 9);
+    }
+
+    #[test]
+    fn gray_saturates() {
+        assert_eq!(encode::<u8, Gray>(8, 3), encode::<u8, Gray>(7, 3));
+        assert_eq!(decode::<u8, Gray>(encode::<u8, Gray>(u8::MAX, 3), 3), 7);
+        assert_eq!(
+            decode::<u128, Gray>(encode::<u128, Gray>(u128::MAX, 128), 128),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn gray_round_trip() {
+        assert_eq!(encode::<u8, Gray>(2, 3), 0b011);
+        assert_eq!(decode::<u8, Gray>(0b100, 3), 7);
+
+        for i in 0..=u16::MAX {
+            assert_eq!(decode::<u16, Gray>(encode::<u16, Gray>(i, 16), 16), i);
+        }
+    }
+
+    #[test]
+    fn sign_magnitude_round_trip() {
+        for i in 2..=31 {
+            println!("Bit width: {i}");
+            let max = (1 << (i - 1)) - 1;
+
+            for value in [-max, -1, 0, 1, max] {
+                let mut data = [0; 4];
+
+                unsafe {
+                    store::<i32, LE>(encode::<i32, SignMagnitude>(value, i), 0, i - 1, &mut data)
+                };
+                let read_back =
+                    decode::<i32, SignMagnitude>(unsafe { load::<i32, LE>(&data, 0, i - 1) }, i);
+
+                assert_eq!(read_back, value);
+            }
+        }
+
+        let mut data = [0; 1];
+        unsafe { store::<i8, LE>(encode::<i8, SignMagnitude>(-5, 4), 0, 3, &mut data) };
+        assert_eq!(data, [0b1101]);
+    }
+
+    #[test]
+    fn sign_magnitude_saturates() {
+        for (value, expected) in [(8, 7), (-9, -7), (i8::MAX, 7), (i8::MIN, -7)] {
+            let mut data = [0; 1];
+            unsafe { store::<i8, LE>(encode::<i8, SignMagnitude>(value, 4), 0, 3, &mut data) };
+            let read_back = decode::<i8, SignMagnitude>(unsafe { load::<i8, LE>(&data, 0, 3) }, 4);
+            assert_eq!(read_back, expected);
+        }
+
+        assert_eq!(
+            encode::<i8, SignMagnitude>(i8::MIN, 8),
+            encode::<i8, SignMagnitude>(-127, 8)
+        );
+    }
+
+    #[test]
+    fn ones_complement_saturates() {
+        for (value, expected) in [(8, 7), (-9, -7), (i8::MAX, 7), (i8::MIN, -7)] {
+            let mut data = [0; 1];
+            unsafe { store::<i8, LE>(encode::<i8, OnesComplement>(value, 4), 0, 3, &mut data) };
+            let read_back = decode::<i8, OnesComplement>(unsafe { load::<i8, LE>(&data, 0, 3) }, 4);
+            assert_eq!(read_back, expected);
+        }
+
+        assert_eq!(
+            encode::<i8, OnesComplement>(i8::MIN, 8),
+            encode::<i8, OnesComplement>(-127, 8)
+        );
+    }
+
+    #[test]
+    fn ones_complement_round_trip() {
+        for i in 2..=31 {
+            println!("Bit width: {i}");
+            let max = (1 << (i - 1)) - 1;
+
+            for value in [-max, -1, 0, 1, max] {
+                let mut data = [0; 4];
+
+                unsafe {
+                    store::<i32, LE>(encode::<i32, OnesComplement>(value, i), 0, i - 1, &mut data)
+                };
+                let read_back =
+                    decode::<i32, OnesComplement>(unsafe { load::<i32, LE>(&data, 0, i - 1) }, i);
+
+                assert_eq!(read_back, value);
+            }
+        }
+
+        let mut data = [0; 1];
+        unsafe { store::<i8, LE>(encode::<i8, OnesComplement>(-5, 4), 0, 3, &mut data) };
+        assert_eq!(data, [0b1010]);
+    }
 }
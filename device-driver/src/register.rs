@@ -1,8 +1,9 @@
 use core::marker::PhantomData;
 
 use crate::{
-    Address, AddressMode, Append, ArrayRepeating, Block, Fieldset, FieldsetMetadata, NotRepeating,
-    RO, RW, ReadCapability, Repeating, ToTuple, WO, WriteCapability,
+    Address, AddressMode, Append, ArrayRepeating, Block, Fieldset, FieldsetMetadata,
+    IndexAccessError, NotRepeating, PerIndex, RO, RW, ReadCapability, Repeating, ToTuple,
+    UniformAccess, WO, WriteCapability,
 };
 
 #[cfg(feature = "defmt")]
@@ -124,9 +125,105 @@ where
     block: &'b mut B,
     address: AddressType,
     register_new_with_reset: fn() -> RegisterFs,
+    index_overrides: &'b [IndexOverride<RegisterFs>],
     _phantom: PhantomData<(RegisterFs, Access, Repeat)>,
 }
 
+/// The reset value and access of one index of a repeated register that differ from the other indices
+#[doc(hidden)]
+pub struct IndexOverride<RegisterFs> {
+    /// The value of the index as given by [`Repeating::index_value`]
+    pub index: i32,
+    /// The reset value of the index, or `None` if it's the reset value of the register
+    pub reset: Option<fn() -> RegisterFs>,
+    pub readable: bool,
+    pub writable: bool,
+}
+
+/// How the operations at an index of a register check the access of that index
+#[doc(hidden)]
+pub trait IndexAccess {
+    /// The error of the operations at an index, given the error of the interface
+    type Error<E>;
+
+    fn interface_error<E>(error: E) -> Self::Error<E>;
+
+    /// Check that the `len` indices starting at `index_value` can be read and/or written
+    fn check_index_access<E, RegisterFs>(
+        index_overrides: &[IndexOverride<RegisterFs>],
+        index_value: Option<i32>,
+        len: usize,
+        read: bool,
+        write: bool,
+    ) -> Result<(), Self::Error<E>>;
+}
+
+// The indices of these registers all have the access of the register, so there's nothing to check
+macro_rules! impl_index_access_unchecked {
+    ($($access:ty),*) => {
+        $(
+            impl IndexAccess for $access {
+                type Error<E> = E;
+
+                fn interface_error<E>(error: E) -> Self::Error<E> {
+                    error
+                }
+
+                fn check_index_access<E, RegisterFs>(
+                    _index_overrides: &[IndexOverride<RegisterFs>],
+                    _index_value: Option<i32>,
+                    _len: usize,
+                    _read: bool,
+                    _write: bool,
+                ) -> Result<(), Self::Error<E>> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_index_access_unchecked!(RO, WO, RW);
+
+impl<A> IndexAccess for PerIndex<A> {
+    type Error<E> = IndexAccessError<E>;
+
+    fn interface_error<E>(error: E) -> Self::Error<E> {
+        IndexAccessError::Interface(error)
+    }
+
+    fn check_index_access<E, RegisterFs>(
+        index_overrides: &[IndexOverride<RegisterFs>],
+        index_value: Option<i32>,
+        len: usize,
+        read: bool,
+        write: bool,
+    ) -> Result<(), Self::Error<E>> {
+        let Some(index_value) = index_value else {
+            return Ok(());
+        };
+        let index_values = index_value..index_value + len as i32;
+
+        for index_override in index_overrides
+            .iter()
+            .filter(|index_override| index_values.contains(&index_override.index))
+        {
+            if read && !index_override.readable {
+                return Err(IndexAccessError::NotReadable {
+                    index: index_override.index,
+                });
+            }
+            if write && !index_override.writable {
+                return Err(IndexAccessError::NotWritable {
+                    index: index_override.index,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<'b, B, RegisterFs, AddressType, Access, Repeat>
     RegisterOperation<'b, B, RegisterFs, AddressType, Access, Repeat>
 where
@@ -145,10 +242,20 @@ where
             block,
             address,
             register_new_with_reset,
+            index_overrides: &[],
             _phantom: PhantomData,
         }
     }
 
+    #[doc(hidden)]
+    pub fn with_index_overrides(
+        mut self,
+        index_overrides: &'b [IndexOverride<RegisterFs>],
+    ) -> Self {
+        self.index_overrides = index_overrides;
+        self
+    }
+
     /// Get the register's address.
     pub fn address(&self) -> AddressType {
         self.address
@@ -159,6 +266,16 @@ where
         (self.register_new_with_reset)()
     }
 
+    /// Get the register's reset value at a given index.
+    ///
+    /// This differs from [`Self::reset_value`] when the index overrides the reset value of the register.
+    pub fn reset_value_at(&self, index: Repeat::Index) -> RegisterFs
+    where
+        Repeat: Repeating,
+    {
+        self.reset_value_of(Repeat::index_value(&index))
+    }
+
     /// Get a plan to read, write or modify for bulk register operations
     pub fn plan(&self) -> Plan<AddressType, RegisterFs, Access>
     where
@@ -172,14 +289,19 @@ where
     }
 
     /// Get a plan to read, write or modify for bulk register operations at a given index
+    ///
+    /// Not available on registers with indices that narrow the access, since the plan can't check the access of its index.
     #[track_caller]
     pub fn plan_at(&self, index: Repeat::Index) -> Plan<AddressType, RegisterFs, Access>
     where
         Repeat: Repeating,
+        Access: UniformAccess,
     {
+        let index_value = Repeat::index_value(&index);
+
         Plan {
             address: Repeat::calc_address(self.address, index),
-            value: self.reset_value(),
+            value: self.reset_value_of(index_value),
             _phantom: PhantomData,
         }
     }
@@ -203,6 +325,7 @@ where
     pub fn plan_with_zero_at(&self, index: Repeat::Index) -> Plan<AddressType, RegisterFs, Access>
     where
         Repeat: Repeating,
+        Access: UniformAccess,
         Access: WriteCapability,
     {
         Plan {
@@ -213,6 +336,8 @@ where
     }
 
     /// Get a plan to read, write or modify an array of registers for bulk register operations with a given start index and length
+    ///
+    /// Not available on registers with indices that narrow the access, since the plan can't check the access of its indices.
     #[track_caller]
     pub fn plan_array_at<const N: usize>(
         self,
@@ -221,15 +346,20 @@ where
     where
         Repeat: ArrayRepeating,
         B::RegisterAddressMode: AddressMode,
+        Access: UniformAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
         Plan {
             address,
-            value: core::array::from_fn(|_| self.reset_value()),
+            value: core::array::from_fn(|i| {
+                self.reset_value_of(index_value.map(|index_value| index_value + i as i32))
+            }),
             _phantom: PhantomData,
         }
     }
@@ -243,6 +373,7 @@ where
     where
         Repeat: ArrayRepeating,
         B::RegisterAddressMode: AddressMode,
+        Access: UniformAccess,
         Access: WriteCapability,
     {
         Repeat::assert_len_and_index(N, index.clone());
@@ -276,7 +407,7 @@ where
 
         self.block.interface().write_register(
             self.address,
-            register.as_slice_mut(),
+            register.as_write_slice_mut(),
             &RegisterFs::METADATA,
         )
     }
@@ -290,20 +421,26 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut RegisterFs),
-    ) -> Result<(), <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: Repeating,
         B::Interface: RegisterInterface,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
-        let mut register = (self.register_new_with_reset)();
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, 1, false, true)?;
+
+        let mut register = self.reset_value_of(index_value);
         f(&mut register);
 
-        self.block.interface().write_register(
-            Repeat::calc_address(self.address, index),
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )
+        self.block
+            .interface()
+            .write_register(
+                Repeat::calc_address(self.address, index),
+                register.as_write_slice_mut(),
+                &RegisterFs::METADATA,
+            )
+            .map_err(Access::interface_error)
     }
 
     /// Write to an array of register at the given index and N length.
@@ -315,26 +452,34 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut [RegisterFs; N]),
-    ) -> Result<(), <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: ArrayRepeating,
         B::Interface: RegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
-        let mut register = core::array::from_fn(|_| (self.register_new_with_reset)());
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, N, false, true)?;
+
+        let mut register = core::array::from_fn(|i| {
+            self.reset_value_of(index_value.map(|index_value| index_value + i as i32))
+        });
         f(&mut register);
 
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
-        self.block.interface().write_register(
-            address,
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )
+        self.block
+            .interface()
+            .write_register(
+                address,
+                register.as_write_slice_mut(),
+                &RegisterFs::METADATA,
+            )
+            .map_err(Access::interface_error)
     }
 
     /// Write to the register.
@@ -357,7 +502,11 @@ where
         async move {
             self.block
                 .interface()
-                .write_register(self.address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    self.address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
         }
     }
@@ -371,22 +520,32 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut RegisterFs),
-    ) -> impl Future<Output = Result<(), <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<Output = Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>>
     where
         Repeat: Repeating,
         B::Interface: AsyncRegisterInterface,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
-        let mut register = (self.register_new_with_reset)();
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, 1, false, true);
+
+        let mut register = self.reset_value_of(index_value);
         f(&mut register);
 
         let address = Repeat::calc_address(self.address, index);
 
         async move {
+            access?;
+
             self.block
                 .interface()
-                .write_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
+                .map_err(Access::interface_error)
         }
     }
 
@@ -399,26 +558,38 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut [RegisterFs; N]),
-    ) -> impl Future<Output = Result<(), <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<Output = Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>>
     where
         Repeat: ArrayRepeating,
         B::Interface: AsyncRegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
-        let mut register = core::array::from_fn(|_| (self.register_new_with_reset)());
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, N, false, true);
+
+        let mut register = core::array::from_fn(|i| {
+            self.reset_value_of(index_value.map(|index_value| index_value + i as i32))
+        });
         f(&mut register);
 
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
         async move {
+            access?;
+
             self.block
                 .interface()
-                .write_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
+                .map_err(Access::interface_error)
         }
     }
 
@@ -440,7 +611,7 @@ where
 
         self.block.interface().write_register(
             self.address,
-            register.as_slice_mut(),
+            register.as_write_slice_mut(),
             &RegisterFs::METADATA,
         )
     }
@@ -453,20 +624,26 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut RegisterFs),
-    ) -> Result<(), <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: Repeating,
         B::Interface: RegisterInterface,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, 1, false, true)?;
+
         let mut register = RegisterFs::ZERO;
         f(&mut register);
 
-        self.block.interface().write_register(
-            Repeat::calc_address(self.address, index),
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )
+        self.block
+            .interface()
+            .write_register(
+                Repeat::calc_address(self.address, index),
+                register.as_write_slice_mut(),
+                &RegisterFs::METADATA,
+            )
+            .map_err(Access::interface_error)
     }
 
     /// Write to an array of registers at a given index and length.
@@ -477,26 +654,32 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut [RegisterFs; N]),
-    ) -> Result<(), <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: ArrayRepeating,
         B::Interface: RegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, N, false, true)?;
+
         let mut register = <[RegisterFs; N] as Fieldset>::ZERO;
         f(&mut register);
 
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
-        self.block.interface().write_register(
-            address,
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )
+        self.block
+            .interface()
+            .write_register(
+                address,
+                register.as_write_slice_mut(),
+                &RegisterFs::METADATA,
+            )
+            .map_err(Access::interface_error)
     }
 
     /// Write to the register.
@@ -518,7 +701,11 @@ where
         async move {
             self.block
                 .interface()
-                .write_register(self.address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    self.address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
         }
     }
@@ -531,22 +718,32 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut RegisterFs),
-    ) -> impl Future<Output = Result<(), <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<Output = Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>>
     where
         Repeat: Repeating,
         B::Interface: AsyncRegisterInterface,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, 1, false, true);
+
         let mut register = RegisterFs::ZERO;
         f(&mut register);
 
         let address = Repeat::calc_address(self.address, index);
 
         async move {
+            access?;
+
             self.block
                 .interface()
-                .write_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
+                .map_err(Access::interface_error)
         }
     }
 
@@ -558,15 +755,18 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut [RegisterFs; N]),
-    ) -> impl Future<Output = Result<(), <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<Output = Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>>
     where
         Repeat: ArrayRepeating,
         B::Interface: AsyncRegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: WriteCapability,
+        Access: WriteCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, N, false, true);
+
         let mut register = <[RegisterFs; N] as Fieldset>::ZERO;
         f(&mut register);
 
@@ -574,10 +774,17 @@ where
         Self::assert_array_op_legal(address);
 
         async move {
+            access?;
+
             self.block
                 .interface()
-                .write_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
+                .map_err(Access::interface_error)
         }
     }
 
@@ -602,12 +809,15 @@ where
     pub fn read_at(
         self,
         index: Repeat::Index,
-    ) -> Result<RegisterFs, <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<RegisterFs, Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: Repeating,
         B::Interface: RegisterInterface,
-        Access: ReadCapability,
+        Access: ReadCapability + IndexAccess,
     {
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, 1, true, false)?;
+
         let mut register = RegisterFs::ZERO;
 
         self.block
@@ -618,6 +828,7 @@ where
                 &RegisterFs::METADATA,
             )
             .map(|_| register)
+            .map_err(Access::interface_error)
     }
 
     /// Read an array of registers from the device at a given index and length
@@ -625,15 +836,18 @@ where
     pub fn read_array_at<const N: usize>(
         self,
         index: Repeat::Index,
-    ) -> Result<[RegisterFs; N], <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<[RegisterFs; N], Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: ArrayRepeating,
         B::Interface: RegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: ReadCapability,
+        Access: ReadCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, N, true, false)?;
+
         let mut register = <[RegisterFs; N] as Fieldset>::ZERO;
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
@@ -642,6 +856,7 @@ where
             .interface()
             .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
             .map(|_| register)
+            .map_err(Access::interface_error)
     }
 
     /// Read the register from the device
@@ -669,22 +884,30 @@ where
     pub fn read_at_async(
         self,
         index: Repeat::Index,
-    ) -> impl Future<Output = Result<RegisterFs, <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<
+        Output = Result<RegisterFs, Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>,
+    >
     where
         Repeat: Repeating,
         B::Interface: AsyncRegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: ReadCapability,
+        Access: ReadCapability + IndexAccess,
     {
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, 1, true, false);
+
         let mut register = RegisterFs::ZERO;
         let address = Repeat::calc_address(self.address, index);
 
         async move {
+            access?;
+
             self.block
                 .interface()
                 .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
                 .await
                 .map(|_| register)
+                .map_err(Access::interface_error)
         }
     }
 
@@ -692,25 +915,36 @@ where
     pub fn read_array_at_async<const N: usize>(
         self,
         index: Repeat::Index,
-    ) -> impl Future<Output = Result<[RegisterFs; N], <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<
+        Output = Result<
+            [RegisterFs; N],
+            Access::Error<<B::Interface as RegisterInterfaceBase>::Error>,
+        >,
+    >
     where
         Repeat: ArrayRepeating,
         B::Interface: AsyncRegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: ReadCapability,
+        Access: ReadCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, N, true, false);
+
         let mut register = <[RegisterFs; N] as Fieldset>::ZERO;
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
         async move {
+            access?;
+
             self.block
                 .interface()
                 .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
                 .await
                 .map(|_| register)
+                .map_err(Access::interface_error)
         }
     }
 
@@ -740,7 +974,7 @@ where
 
         self.block.interface().write_register(
             self.address,
-            register.as_slice_mut(),
+            register.as_write_slice_mut(),
             &RegisterFs::METADATA,
         )
     }
@@ -754,28 +988,33 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut RegisterFs),
-    ) -> Result<(), <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: Repeating,
         B::Interface: RegisterInterface,
-        Access: ReadCapability + WriteCapability,
+        Access: ReadCapability + WriteCapability + IndexAccess,
     {
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, 1, true, true)?;
+
         let mut register = RegisterFs::ZERO;
         let address = Repeat::calc_address(self.address, index);
 
-        self.block.interface().read_register(
-            address,
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )?;
+        self.block
+            .interface()
+            .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+            .map_err(Access::interface_error)?;
 
         f(&mut register);
 
-        self.block.interface().write_register(
-            address,
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )
+        self.block
+            .interface()
+            .write_register(
+                address,
+                register.as_write_slice_mut(),
+                &RegisterFs::METADATA,
+            )
+            .map_err(Access::interface_error)
     }
 
     /// Modify an array of existing register values at a given start index and length.
@@ -787,33 +1026,38 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut [RegisterFs; N]),
-    ) -> Result<(), <B::Interface as RegisterInterfaceBase>::Error>
+    ) -> Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>
     where
         Repeat: ArrayRepeating,
         B::Interface: RegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: ReadCapability + WriteCapability,
+        Access: ReadCapability + WriteCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+        Access::check_index_access(self.index_overrides, index_value, N, true, true)?;
+
         let mut register = <[RegisterFs; N] as Fieldset>::ZERO;
 
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
-        self.block.interface().read_register(
-            address,
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )?;
+        self.block
+            .interface()
+            .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+            .map_err(Access::interface_error)?;
 
         f(&mut register);
 
-        self.block.interface().write_register(
-            address,
-            register.as_slice_mut(),
-            &RegisterFs::METADATA,
-        )
+        self.block
+            .interface()
+            .write_register(
+                address,
+                register.as_write_slice_mut(),
+                &RegisterFs::METADATA,
+            )
+            .map_err(Access::interface_error)
     }
 
     /// Modify the existing register value.
@@ -842,7 +1086,11 @@ where
 
             self.block
                 .interface()
-                .write_register(self.address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    self.address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
         }
     }
@@ -856,27 +1104,38 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut RegisterFs),
-    ) -> impl Future<Output = Result<(), <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<Output = Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>>
     where
         Repeat: Repeating,
         B::Interface: AsyncRegisterInterface,
-        Access: ReadCapability + WriteCapability,
+        Access: ReadCapability + WriteCapability + IndexAccess,
     {
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, 1, true, true);
+
         let mut register = RegisterFs::ZERO;
         let address = Repeat::calc_address(self.address, index);
 
         async move {
+            access?;
+
             self.block
                 .interface()
                 .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
-                .await?;
+                .await
+                .map_err(Access::interface_error)?;
 
             f(&mut register);
 
             self.block
                 .interface()
-                .write_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
+                .map_err(Access::interface_error)
         }
     }
 
@@ -889,32 +1148,55 @@ where
         self,
         index: Repeat::Index,
         f: impl FnOnce(&mut [RegisterFs; N]),
-    ) -> impl Future<Output = Result<(), <B::Interface as RegisterInterfaceBase>::Error>>
+    ) -> impl Future<Output = Result<(), Access::Error<<B::Interface as RegisterInterfaceBase>::Error>>>
     where
         Repeat: ArrayRepeating,
         B::Interface: AsyncRegisterInterface,
         B::RegisterAddressMode: AddressMode,
-        Access: ReadCapability + WriteCapability,
+        Access: ReadCapability + WriteCapability + IndexAccess,
     {
         Repeat::assert_len_and_index(N, index.clone());
 
+        let index_value = Repeat::index_value(&index);
+        let access = Access::check_index_access(self.index_overrides, index_value, N, true, true);
+
         let mut register = <[RegisterFs; N] as Fieldset>::ZERO;
 
         let address = Repeat::calc_address(self.address, index);
         Self::assert_array_op_legal(address);
 
         async move {
+            access?;
+
             self.block
                 .interface()
                 .read_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
-                .await?;
+                .await
+                .map_err(Access::interface_error)?;
 
             f(&mut register);
 
             self.block
                 .interface()
-                .write_register(address, register.as_slice_mut(), &RegisterFs::METADATA)
+                .write_register(
+                    address,
+                    register.as_write_slice_mut(),
+                    &RegisterFs::METADATA,
+                )
                 .await
+                .map_err(Access::interface_error)
+        }
+    }
+
+    fn reset_value_of(&self, index_value: Option<i32>) -> RegisterFs {
+        match self
+            .index_overrides
+            .iter()
+            .find(|index_override| Some(index_override.index) == index_value)
+            .and_then(|index_override| index_override.reset)
+        {
+            Some(reset) => reset(),
+            None => (self.register_new_with_reset)(),
         }
     }
 
@@ -1177,7 +1459,7 @@ where
 
         self.block.interface().write_register(
             self.start_address.unwrap(),
-            self.field_sets.as_slice_mut(),
+            self.field_sets.as_write_slice_mut(),
             &Fieldsets::METADATA,
         )
     }
@@ -1204,7 +1486,7 @@ where
                 .interface()
                 .write_register(
                     self.start_address.unwrap(),
-                    self.field_sets.as_slice_mut(),
+                    self.field_sets.as_write_slice_mut(),
                     &Fieldsets::METADATA,
                 )
                 .await
@@ -1251,7 +1533,7 @@ where
 
         self.block.interface().write_register(
             self.start_address.unwrap(),
-            self.field_sets.as_slice_mut(),
+            self.field_sets.as_write_slice_mut(),
             &Fieldsets::METADATA,
         )
     }
@@ -1286,7 +1568,7 @@ where
             .interface()
             .write_register(
                 self.start_address.unwrap(),
-                self.field_sets.as_slice_mut(),
+                self.field_sets.as_write_slice_mut(),
                 &Fieldsets::METADATA,
             )
             .await
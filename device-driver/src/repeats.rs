@@ -12,6 +12,12 @@ pub trait Repeating {
     /// Calculate an address with the index
     #[allow(private_bounds)]
     fn calc_address<AddressType: Address>(start: AddressType, index: Self::Index) -> AddressType;
+
+    /// The value of the index that index overrides are matched against.
+    /// Repeats that can't have index overrides keep the default of `None`.
+    fn index_value(_index: &Self::Index) -> Option<i32> {
+        None
+    }
 }
 
 #[diagnostic::on_unimplemented(
@@ -48,6 +54,11 @@ impl<const COUNT: usize, const STRIDE: i32> Repeating for ArrayRepeat<COUNT, STR
         let offset = index as i32 * STRIDE;
         start.add(offset)
     }
+
+    #[inline]
+    fn index_value(index: &Self::Index) -> Option<i32> {
+        Some(*index as i32)
+    }
 }
 impl<const COUNT: usize, const STRIDE: i32> ArrayRepeating for ArrayRepeat<COUNT, STRIDE> {
     const COUNT: usize = COUNT;
@@ -86,6 +97,11 @@ impl<const END: usize, const START: usize, const STRIDE: i32> Repeating
         let offset = (START as i32 + index as i32) * STRIDE;
         start.add(offset)
     }
+
+    #[inline]
+    fn index_value(index: &Self::Index) -> Option<i32> {
+        Some(*index as i32)
+    }
 }
 impl<const END: usize, const START: usize, const STRIDE: i32> ArrayRepeating
     for RangeRepeat<END, START, STRIDE>
@@ -124,4 +140,63 @@ impl<T: Clone + EnumIndex, const STRIDE: i32> Repeating for EnumRepeat<T, STRIDE
         let offset = index.index() * STRIDE;
         start.add(offset)
     }
+    #[inline]
+    fn index_value(index: &Self::Index) -> Option<i32> {
+        Some(index.index())
+    }
+}
+
+/// The offsets of a repeat with an explicit offset for every index
+#[doc(hidden)]
+pub trait RepeatOffsets {
+    const OFFSETS: &'static [i32];
+}
+
+#[doc(hidden)]
+pub struct ListRepeat<O: RepeatOffsets>(PhantomData<O>);
+impl<O: RepeatOffsets> Repeating for ListRepeat<O> {
+    type Index = usize;
+
+    #[track_caller]
+    #[inline]
+    fn calc_address<AddressType: Address>(start: AddressType, index: Self::Index) -> AddressType {
+        assert!(
+            index < O::OFFSETS.len(),
+            "Index out of range: {index} (array len: {})",
+            O::OFFSETS.len()
+        );
+        start.add(O::OFFSETS[index])
+    }
+
+    #[inline]
+    fn index_value(index: &Self::Index) -> Option<i32> {
+        Some(*index as i32)
+    }
+}
+
+/// A repeat in two dimensions. The index is a tuple of the outer and the inner index.
+///
+/// Array operations run over the inner dimension.
+#[doc(hidden)]
+pub struct NestedRepeat<Outer: Repeating, Inner: Repeating>(PhantomData<(Outer, Inner)>);
+impl<Outer: Repeating, Inner: Repeating> Repeating for NestedRepeat<Outer, Inner> {
+    type Index = (Outer::Index, Inner::Index);
+
+    #[track_caller]
+    #[inline]
+    fn calc_address<AddressType: Address>(start: AddressType, index: Self::Index) -> AddressType {
+        Inner::calc_address(Outer::calc_address(start, index.0), index.1)
+    }
+
+    // Nested repeats can't have index overrides, so they keep the default `index_value`
+}
+impl<Outer: Repeating, Inner: ArrayRepeating> ArrayRepeating for NestedRepeat<Outer, Inner> {
+    const COUNT: usize = Inner::COUNT;
+    const STRIDE: i32 = Inner::STRIDE;
+
+    #[track_caller]
+    #[inline]
+    fn assert_len_and_index(len: usize, index: Self::Index) {
+        Inner::assert_len_and_index(len, index.1);
+    }
 }
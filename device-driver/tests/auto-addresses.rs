@@ -0,0 +1,120 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 16],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Control {
+                address: _,
+                fields: fieldset Wide {
+                    size-bytes: 2,
+
+                    field value 15:0 -> uint,
+                },
+            },
+
+            register Channel[3 stride 1] {
+                address: _,
+                fields: fieldset Narrow {
+                    size-bytes: 1,
+
+                    field value 7:0 -> uint,
+                },
+            },
+
+            register Threshold {
+                address: auto,
+                fields: Wide,
+            },
+
+            register Id {
+                address: 0x0C,
+                fields: Narrow,
+            },
+
+            register Revision {
+                address: _,
+                fields: Narrow,
+            },
+
+            block Trailer {
+                address-offset: 0x0E,
+                default-address: auto,
+
+                register First {
+                    fields: Narrow,
+                },
+
+                register Second {
+                    fields: Narrow,
+                },
+            },
+        }
+    "
+);
+
+#[test]
+fn addresses_follow_the_previous_register() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 16],
+    });
+
+    device.control().write(|reg| reg.set_value(0x1122)).unwrap();
+    device
+        .channel()
+        .write_at(2, |reg| reg.set_value(0x33))
+        .unwrap();
+    device
+        .threshold()
+        .write(|reg| reg.set_value(0x4455))
+        .unwrap();
+    device.revision().write(|reg| reg.set_value(0x66)).unwrap();
+    device
+        .trailer()
+        .second()
+        .write(|reg| reg.set_value(0x77))
+        .unwrap();
+
+    assert_eq!(
+        device.interface.device_memory,
+        [
+            0x22, 0x11, 0x00, 0x00, 0x33, 0x55, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x66,
+            0x00, 0x77
+        ]
+    );
+}
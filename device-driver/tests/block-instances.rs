@@ -0,0 +1,105 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 256],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-byte-order: LE,
+            default-access: RW,
+
+            block Uart {
+                address-offset: 0x10,
+
+                register Data {
+                    address: 0,
+                    fields: fieldset Data {
+                        size-bytes: 1,
+
+                        field value 7:0 -> uint,
+                    },
+                },
+
+                register Baud {
+                    address: 2,
+                    fields: fieldset Baud {
+                        size-bytes: 2,
+
+                        field divider 15:0 -> uint,
+                    },
+                },
+            },
+
+            block DebugUart {
+                block: Uart,
+                address-offset: 0x48,
+            },
+
+            block ModemUart {
+                block: Uart,
+                address-offset: 0xC4,
+            },
+        }
+    "
+);
+
+#[test]
+fn instances_share_the_block() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 256],
+    });
+
+    device
+        .uart()
+        .data()
+        .write(|reg| reg.set_value(0x11))
+        .unwrap();
+    device
+        .debug_uart()
+        .data()
+        .write(|reg| reg.set_value(0x22))
+        .unwrap();
+    device
+        .modem_uart()
+        .baud()
+        .write(|reg| reg.set_divider(0x1234))
+        .unwrap();
+
+    assert_eq!(device.interface.device_memory[0x10], 0x11);
+    assert_eq!(device.interface.device_memory[0x48], 0x22);
+    assert_eq!(device.interface.device_memory[0xC6..][..2], [0x34, 0x12]);
+
+    let _: Uart<'_, DeviceInterface> = device.modem_uart();
+    assert_eq!(device.debug_uart().data().read().unwrap().value(), 0x22);
+}
@@ -0,0 +1,140 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 16],
+}
+
+impl Default for DeviceInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeviceInterface {
+    pub const fn new() -> Self {
+        Self {
+            device_memory: [0; 16],
+        }
+    }
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-access: RW,
+
+            register Id {
+                address: 0,
+                fields: fieldset IdFields {
+                    byte-order: LE,
+                    size-bytes: 8,
+
+                    field model 47:0 -> ascii,
+                    field serial 63:48 -> bytes,
+                }
+            },
+            register Uid {
+                address: 8,
+                fields: fieldset UidFields {
+                    byte-order: BE,
+                    size-bytes: 6,
+
+                    field part[3 stride 16] 15:0 -> bytes,
+                }
+            },
+        }
+    "
+);
+
+#[test]
+fn ascii_round_trip() {
+    let mut device = MyTestDevice::new(DeviceInterface::new());
+
+    device
+        .id()
+        .write(|reg| {
+            reg.try_set_model("DD01").unwrap();
+            reg.set_serial([0xAB, 0xCD]);
+        })
+        .unwrap();
+
+    assert_eq!(
+        &device.interface.device_memory[..8],
+        &[b'D', b'D', b'0', b'1', 0, 0, 0xAB, 0xCD]
+    );
+
+    let id = device.id().read().unwrap();
+    assert_eq!(id.model().as_str(), Some("DD01"));
+    assert_eq!(id.model().as_bytes(), b"DD01\0\0");
+    assert_eq!(id.serial(), [0xAB, 0xCD]);
+    assert_eq!(
+        format!("{id:?}"),
+        "IdFields { model: \"DD01\", serial: [171, 205] }"
+    );
+}
+
+#[test]
+fn repeated_bytes_keep_order() {
+    let mut device = MyTestDevice::new(DeviceInterface::new());
+
+    device
+        .uid()
+        .write(|reg| {
+            reg.set_part(0, [1, 2]);
+            reg.set_part(2, [5, 0]);
+        })
+        .unwrap();
+
+    // Big endian, so index 0 is at the end
+    assert_eq!(&device.interface.device_memory[8..14], &[5, 0, 0, 0, 1, 2]);
+    assert_eq!(device.uid().read().unwrap().part(0), [1, 2]);
+}
+
+#[test]
+fn invalid_text_is_rejected() {
+    let mut reg = IdFields::default();
+    reg.set_model(*b"DD01\0\0");
+
+    assert_eq!(
+        reg.try_set_model("DEVICE01"),
+        Err(device_driver::InvalidAscii::TooLong {
+            len: 8,
+            max: 6,
+            field: "model"
+        })
+    );
+    assert_eq!(
+        reg.try_set_model("é"),
+        Err(device_driver::InvalidAscii::NotAscii { field: "model" })
+    );
+    // The field is left unchanged
+    assert_eq!(reg.model().as_str(), Some("DD01"));
+}
@@ -0,0 +1,89 @@
+// The chip variants are not features of this crate
+#![allow(unexpected_cfgs)]
+
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 8],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+// None of the variants are enabled in this test, so only the shared registers exist.
+// The registers of an enabled variant are tested in the `tests/chip-variant` crate
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+            variant: chip-a,
+            variant: chip-b,
+
+            register Shared {
+                address: 0,
+                fields: Small,
+            },
+
+            register Config {
+                when: chip-a,
+                address: 1,
+                fields: Small,
+            },
+
+            // Same name and address, but a different layout
+            register Config {
+                when: chip-b,
+                address: 1,
+                fields: fieldset ConfigB {
+                    size-bytes: 2,
+
+                    field value 15:0 -> uint,
+                },
+            },
+
+            fieldset Small {
+                size-bytes: 1,
+
+                field value 7:0 -> uint,
+            },
+        }
+    "
+);
+
+#[test]
+fn shared_registers_exist_without_variant() {
+    assert_eq!(my_test_device::shared::ADDRESS, 0);
+
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 8],
+    });
+
+    device.shared().write(|reg| reg.set_value(0x12)).unwrap();
+    assert_eq!(device.interface.device_memory[0], 0x12);
+}
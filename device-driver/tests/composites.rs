@@ -0,0 +1,267 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Write(u8, Vec<u8>),
+    Read(u8, usize),
+}
+
+pub struct DeviceInterface {
+    memory: [u8; 64],
+    events: Vec<Event>,
+}
+
+impl Default for DeviceInterface {
+    fn default() -> Self {
+        Self {
+            memory: [0; 64],
+            events: Vec::new(),
+        }
+    }
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Write(address, data.to_vec()));
+        self.memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Read(address, data.len()));
+        data.copy_from_slice(&self.memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+impl device_driver::AsyncRegisterInterface for DeviceInterface {
+    async fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        RegisterInterface::write_register(self, address, data, metadata)
+    }
+
+    async fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        RegisterInterface::read_register(self, address, data, metadata)
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-byte-order: LE,
+            default-access: RW,
+
+            /// The timer counter
+            composite timer -> u16 {
+                part TimerLow { field: count },
+                part TimerHigh { field: count },
+            },
+
+            /// Reading the high byte latches the low byte
+            composite latched {
+                latch: LatchHigh,
+                part LatchLow { field: count },
+                part LatchHigh { field: count },
+            },
+
+            composite offset -> int {
+                part OffsetLow { field: value },
+                part OffsetHigh { field: value },
+            },
+
+            /// The high register has another field, so the registers are read before they're written
+            composite level {
+                part LevelLow { field: count },
+                part LevelHigh { field: value },
+            },
+
+            register TimerLow {
+                address: 0x10,
+                fields: fieldset Byte {
+                    size-bytes: 1,
+
+                    field count 7:0 -> uint,
+                },
+            },
+
+            register TimerHigh {
+                address: 0x11,
+                fields: Byte,
+            },
+
+            register LatchLow {
+                address: 0x20,
+                fields: Byte,
+            },
+
+            register LatchHigh {
+                address: 0x21,
+                fields: Byte,
+            },
+
+            register OffsetLow {
+                address: 0x30,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field value 7:0 -> uint,
+                },
+            },
+
+            register OffsetHigh {
+                address: 0x32,
+                reset: 0x80,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field value 3:0 -> uint,
+                    field enable 7 -> bool,
+                },
+            },
+
+            register LevelLow {
+                address: 0x38,
+                fields: Byte,
+            },
+
+            register LevelHigh {
+                address: 0x39,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field value 3:0 -> uint,
+                    field enable 7 -> bool,
+                },
+            },
+        }
+    "
+);
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn consecutive_registers_use_bulk_transactions() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device.write_timer(0x1234).unwrap();
+    assert_eq!(device.read_timer(), Ok(0x1234));
+
+    assert_eq!(
+        device.interface.events,
+        [Event::Write(0x10, vec![0x34, 0x12]), Event::Read(0x10, 2)]
+    );
+}
+
+#[test]
+fn latch_is_read_first_and_written_last() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device.write_latched(0xABCD).unwrap();
+    let value: u16 = device.read_latched().unwrap();
+    assert_eq!(value, 0xABCD);
+
+    assert_eq!(
+        device.interface.events,
+        [
+            // Low before high, so they can be written in one go
+            Event::Write(0x20, vec![0xCD, 0xAB]),
+            Event::Read(0x21, 1),
+            Event::Read(0x20, 1),
+        ]
+    );
+}
+
+#[test]
+fn signed_values_are_sign_extended() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device.interface.memory[0x32] = 0x80;
+
+    device.write_offset(-2).unwrap();
+    let value: i16 = device.read_offset().unwrap();
+    assert_eq!(value, -2);
+
+    // The registers aren't consecutive and the other field of the high register keeps its value
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Write(0x30, vec![0xFE]),
+            Event::Read(0x32, 1),
+            Event::Write(0x32, vec![0x8F]),
+            Event::Read(0x30, 1),
+            Event::Read(0x32, 1),
+        ]
+    );
+
+    device.write_offset(0x7FF).unwrap();
+    assert_eq!(device.read_offset(), Ok(0x7FF));
+}
+
+#[test]
+fn other_fields_keep_their_value_in_bulk_writes() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+    device.interface.memory[0x39] = 0x80;
+
+    device.write_level(0x123).unwrap();
+    assert_eq!(device.read_level(), Ok(0x123));
+    assert!(device.level_high().read().unwrap().enable());
+
+    assert_eq!(
+        device.interface.events[..3],
+        [
+            Event::Read(0x38, 2),
+            Event::Write(0x38, vec![0x23, 0x81]),
+            Event::Read(0x38, 2),
+        ]
+    );
+}
+
+#[test]
+fn composite_async() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    block_on(device.write_timer_async(0xBEEF)).unwrap();
+    assert_eq!(block_on(device.read_timer_async()), Ok(0xBEEF));
+    assert_eq!(block_on(device.read_latched_async()), Ok(0));
+}
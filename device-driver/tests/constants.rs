@@ -0,0 +1,107 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 64],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            const CH_BASE = 0x20,
+            const CH_STRIDE = 0x08,
+            const WIDTH = 16,
+
+            register Control {
+                address: CH_BASE - 1,
+                reset: (1 << 7) | 0x0F,
+                fields: Control,
+            },
+
+            block Channel2 {
+                address-offset: CH_BASE + CH_STRIDE * 2,
+
+                register Config {
+                    address: 0,
+                    fields: Config,
+                },
+            },
+
+            // Left associative: (100 - 10) - 5
+            register Associative {
+                address: 100 - 10 - 5,
+                fields: Control,
+            },
+
+            // Precedence: 2 | ((1 << 2) & 0x0F)
+            register Precedence {
+                address: 2 | 1 << 2 & 0x0F,
+                fields: Control,
+            },
+
+            fieldset Control {
+                size-bytes: 1,
+
+                field enable 7 -> bool,
+                field level 3:0 -> uint,
+            },
+
+            fieldset Config {
+                size-bytes: WIDTH / 8,
+
+                field value 15:0 -> uint,
+            },
+        }
+    "
+);
+
+#[test]
+fn constants_are_evaluated() {
+    assert_eq!(my_test_device::control::ADDRESS, 0x1F);
+    assert_eq!(my_test_device::control::RESET, 0x8F);
+    assert_eq!(my_test_device::control::SIZE_BYTES, 1);
+    assert_eq!(channel_2::config::SIZE_BYTES, 2);
+    assert_eq!(my_test_device::associative::ADDRESS, 85);
+    assert_eq!(my_test_device::precedence::ADDRESS, 6);
+
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 64],
+    });
+
+    device
+        .channel_2()
+        .config()
+        .write(|reg| reg.set_value(0x1234))
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0x30..0x32], [0x34, 0x12]);
+}
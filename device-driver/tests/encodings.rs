@@ -0,0 +1,151 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 16],
+}
+
+impl Default for DeviceInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeviceInterface {
+    pub const fn new() -> Self {
+        Self {
+            device_memory: [0; 16],
+        }
+    }
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-access: RW,
+            default-byte-order: LE,
+
+            register Rtc {
+                address: 0,
+                fields: fieldset RtcFields {
+                    size-bytes: 4,
+
+                    field seconds 7:0 -> uint bcd,
+                    field position 15:8 -> uint gray,
+                    field offset 23:16 -> int sign-magnitude,
+                    field trim 27:24 -> int ones-complement,
+                    field day 31:28 -> u8 bcd as Day,
+                }
+            },
+            register Limits {
+                address: 4,
+                fields: fieldset LimitsFields {
+                    size-bytes: 1,
+
+                    field level 3:0 -> int sign-magnitude,
+                    field step 6:4 -> uint gray,
+                }
+            },
+            enum Day -> u8 {
+                Monday: 1,
+                Tuesday: _,
+                Wednesday: _,
+                Thursday: _,
+                Friday: _,
+                Saturday: _,
+                Sunday: _,
+                Unknown: default _,
+            },
+        }
+    "
+);
+
+#[test]
+fn encodings_round_trip() {
+    let mut device = MyTestDevice::new(DeviceInterface::new());
+
+    device
+        .rtc()
+        .write(|reg| {
+            reg.set_seconds(59);
+            reg.set_position(2);
+            reg.set_offset(-5);
+            reg.set_trim(-3);
+            reg.set_day(Day::Sunday);
+        })
+        .unwrap();
+
+    assert_eq!(
+        &device.interface.device_memory[..4],
+        &[0x59, 0b0000_0011, 0b1000_0101, 0b0111_1100]
+    );
+
+    let rtc = device.rtc().read().unwrap();
+    assert_eq!(rtc.seconds(), 59);
+    assert_eq!(rtc.position(), 2);
+    assert_eq!(rtc.offset(), -5);
+    assert_eq!(rtc.trim(), -3);
+    assert_eq!(rtc.day(), Day::Sunday);
+}
+
+#[test]
+fn raw_values_are_decoded() {
+    let rtc = RtcFields::from([0x12, 0b1000_0000, 0b1000_0000, 0b1000_1111]);
+
+    assert_eq!(rtc.seconds(), 12);
+    assert_eq!(rtc.position(), 255);
+    // Negative zero
+    assert_eq!(rtc.offset(), 0);
+    assert_eq!(rtc.trim(), 0);
+    assert_eq!(rtc.day(), Day::Unknown);
+}
+
+#[test]
+fn values_that_dont_fit_saturate() {
+    let mut rtc = RtcFields::default();
+    rtc.set_seconds(100);
+    rtc.set_offset(i8::MIN);
+    rtc.set_trim(8);
+    assert_eq!(rtc.seconds(), 99);
+    assert_eq!(rtc.offset(), -127);
+    assert_eq!(rtc.trim(), 7);
+
+    rtc.set_trim(-9);
+    assert_eq!(rtc.trim(), -7);
+
+    let mut limits = LimitsFields::default();
+    limits.set_level(8);
+    limits.set_step(8);
+    assert_eq!(limits.level(), 7);
+    assert_eq!(limits.step(), 7);
+
+    limits.set_level(-9);
+    assert_eq!(limits.level(), -7);
+}
@@ -0,0 +1,135 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 4],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Config {
+                address: 0,
+                fields: fieldset Config {
+                    size-bytes: 1,
+
+                    field speed 4:0 -> uint as Speed,
+                    field mode 7:5 -> uint as try Mode,
+                },
+            },
+
+            enum Speed {
+                Reserved: [0x00..=0x07, 0x09..=0x0F],
+                Fast: 0x08,
+                Custom: 0x10..=0x1F,
+            },
+
+            enum Mode -> u8 {
+                Off: 0,
+                On: [1, 2, 4],
+                Test: _,
+            },
+
+            enum Level -> u8 {
+                Low: 0..=0x7F,
+                High: catch-all _,
+            },
+        }
+    "
+);
+
+#[test]
+fn ranges_carry_the_raw_value() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 4],
+    });
+
+    device.interface.device_memory[0] = 0x13;
+    let config = device.config().read().unwrap();
+    assert_eq!(config.speed(), Speed::Custom(0x13));
+    assert_eq!(config.mode(), Ok(Mode::Off));
+
+    device
+        .config()
+        .write(|reg| {
+            reg.set_speed(Speed::Fast);
+            reg.set_mode(Mode::On(4));
+        })
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0], 0x88);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Speed::try_from(0x0A), Ok(Speed::Reserved(0x0A)));
+    assert_eq!(Speed::try_from(0x08), Ok(Speed::Fast));
+    assert!(Speed::try_from(0x20).is_err());
+
+    assert_eq!(Mode::try_from(2), Ok(Mode::On(2)));
+    assert_eq!(Mode::try_from(5), Ok(Mode::Test));
+    assert!(Mode::try_from(3).is_err());
+    assert_eq!(u8::from(Mode::On(4)), 4);
+    assert_eq!(u8::from(Mode::Test), 5);
+
+    assert_eq!(Level::from(0x42), Level::Low(0x42));
+    assert_eq!(Level::from(0x80), Level::High(0x80));
+    assert_eq!(u8::from(Level::Low(0x10)), 0x10);
+}
+
+#[test]
+fn values_outside_the_set_read_back_as_another_variant() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 4],
+    });
+
+    // The payload isn't checked, so the value ends up in another variant or fails to convert
+    device
+        .config()
+        .write(|reg| {
+            reg.set_speed(Speed::Custom(5));
+            reg.set_mode(Mode::On(3));
+        })
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0], 0x65);
+
+    let config = device.config().read().unwrap();
+    assert_eq!(config.speed(), Speed::Reserved(5));
+    assert!(config.mode().is_err());
+
+    // The constructors check the value
+    assert_eq!(Speed::new_custom(5), None);
+    assert_eq!(Speed::new_custom(0x13), Some(Speed::Custom(0x13)));
+    assert_eq!(Mode::new_on(3), None);
+    assert_eq!(Mode::new_on(4), Some(Mode::On(4)));
+    assert_eq!(Level::new_low(0x80), None);
+}
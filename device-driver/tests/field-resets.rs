@@ -0,0 +1,109 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+#[derive(Default)]
+pub struct DeviceInterface {
+    memory: [u8; 4],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            default-byte-order: LE,
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-access: RW,
+
+            register Config {
+                address: 0,
+                fields: fieldset ConfigFields {
+                    size-bytes: 2,
+
+                    field enable 0 -> bool {
+                        reset: 1,
+                    },
+                    field mode 3:1 -> uint as Mode {
+                        reset: Fast,
+                    },
+                    field offset 15:8 -> int {
+                        reset: -2,
+                    },
+                }
+            },
+            register Status {
+                address: 2,
+                reset: 0x8012,
+                fields: fieldset StatusFields {
+                    size-bytes: 2,
+                    byte-order: BE,
+
+                    field ready 15 -> bool {
+                        reset: 1,
+                    },
+                    field count 7:0 -> uint,
+                }
+            },
+            enum Mode -> u8 {
+                Slow: 2,
+                Fast: 5,
+                Other: default _,
+            },
+        }
+    "
+);
+
+#[test]
+fn reset_assembled_from_fields() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    let reset = device.config().reset_value();
+    assert!(reset.enable());
+    assert_eq!(reset.mode(), Mode::Fast);
+    assert_eq!(reset.offset(), -2);
+    assert_eq!(reset, ConfigFields::from([0b0000_1011, 0xFE]));
+}
+
+#[test]
+fn write_starts_from_field_resets() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device
+        .config()
+        .write(|config| config.set_offset(3))
+        .unwrap();
+    assert_eq!(device.interface.memory[..2], [0b0000_1011, 3]);
+}
+
+#[test]
+fn register_reset_agrees_with_field_resets() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    let reset = device.status().reset_value();
+    assert!(reset.ready());
+    assert_eq!(reset.count(), 0x12);
+}
@@ -0,0 +1,83 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 8],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Ctrl1 {
+                address: 0,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field enable 0 -> bool,
+                    field mode 2:1 -> uint,
+                },
+            },
+
+            register Ctrl2 {
+                address: 1,
+                fields: fieldset _ {
+                    extends: Ctrl1,
+                    remove: mode,
+                    size-bytes: 2,
+
+                    field speed 2:1 -> uint,
+                    field extra 15:8 -> uint,
+                },
+            },
+        }
+    "
+);
+
+#[test]
+fn extended_field_set_has_base_and_new_fields() {
+    assert_eq!(my_test_device::ctrl_2::SIZE_BYTES, 2);
+
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 8],
+    });
+
+    device
+        .ctrl_2()
+        .write(|reg| {
+            reg.set_enable(true);
+            reg.set_speed(3);
+            reg.set_extra(0xAB);
+        })
+        .unwrap();
+    assert_eq!(device.interface.device_memory[1..3], [0x07, 0xAB]);
+}
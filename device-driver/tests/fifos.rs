@@ -0,0 +1,258 @@
+use std::{
+    collections::VecDeque,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Read(u8, usize),
+}
+
+/// Pretends to be a device with one fifo of samples that can be read at 0x10, 0x20 and 0x30,
+/// with its fill level in records at 0x11 and in bytes at 0x12
+#[derive(Default)]
+pub struct DeviceInterface {
+    fifo: VecDeque<u8>,
+    events: Vec<Event>,
+}
+
+impl DeviceInterface {
+    fn push_sample(&mut self, x: i16, y: i16, z: i16) {
+        for axis in [x, y, z] {
+            self.fifo.extend(axis.to_le_bytes());
+        }
+    }
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        _address: Self::AddressType,
+        _data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Err(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Read(address, data.len()));
+
+        match address {
+            0x10 | 0x20 | 0x30 => data
+                .iter_mut()
+                .for_each(|byte| *byte = self.fifo.pop_front().unwrap_or(0xFF)),
+            0x11 => data[0] = (self.fifo.len() / 6) as u8,
+            0x12 => data.copy_from_slice(&(self.fifo.len() as u16).to_le_bytes()),
+            _ => return Err(()),
+        }
+        Ok(())
+    }
+}
+
+impl device_driver::AsyncRegisterInterface for DeviceInterface {
+    async fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        RegisterInterface::write_register(self, address, data, metadata)
+    }
+
+    async fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        RegisterInterface::read_register(self, address, data, metadata)
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RO,
+
+            /// The accelerometer samples
+            fifo Samples {
+                address: 0x10,
+                record: fieldset Sample {
+                    size-bytes: 6,
+
+                    field x 15:0 -> int,
+                    field y 31:16 -> int,
+                    field z 47:32 -> int,
+                },
+                level-register: FifoCount,
+                level-field: count,
+            },
+
+            fifo SampleBytes {
+                address: 0x20,
+                record: Sample,
+                level-register: FifoBytes,
+                level-field: length,
+                level-unit: byte,
+            },
+
+            fifo RawSamples {
+                address: 0x30,
+                record: Sample,
+            },
+
+            register FifoCount {
+                address: 0x11,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field count 6:0 -> uint,
+                },
+            },
+
+            register FifoBytes {
+                address: 0x12,
+                fields: fieldset _ {
+                    size-bytes: 2,
+
+                    field length 10:0 -> uint,
+                },
+            },
+        }
+    "
+);
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn only_available_records_are_read() {
+    let mut interface = DeviceInterface::default();
+    interface.push_sample(1, -2, 3);
+    interface.push_sample(4, -5, 6);
+    let mut device = MyTestDevice::new(interface);
+
+    assert_eq!(device.samples().available(), Ok(2));
+
+    let mut samples = [Sample::default(); 4];
+    assert_eq!(device.samples().read_records(&mut samples), Ok(2));
+    assert_eq!((samples[1].x(), samples[1].y(), samples[1].z()), (4, -5, 6));
+    assert_eq!(device.samples().read_records(&mut samples), Ok(0));
+
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Read(0x11, 1),
+            Event::Read(0x11, 1),
+            Event::Read(0x10, 12),
+            Event::Read(0x11, 1),
+        ]
+    );
+}
+
+#[test]
+fn level_in_bytes() {
+    let mut interface = DeviceInterface::default();
+    interface.push_sample(1, 2, 3);
+    interface.push_sample(4, 5, 6);
+    interface.push_sample(7, 8, 9);
+    let mut device = MyTestDevice::new(interface);
+
+    let mut samples = [Sample::default(); 2];
+    assert_eq!(device.sample_bytes().read_records(&mut samples), Ok(2));
+    assert_eq!(device.sample_bytes().available(), Ok(1));
+    assert_eq!(my_test_device::sample_bytes::RECORD_SIZE_BYTES, 6);
+
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Read(0x12, 2),
+            Event::Read(0x20, 12),
+            Event::Read(0x12, 2)
+        ]
+    );
+}
+
+#[test]
+fn without_level_all_records_are_read() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    let mut samples = [Sample::default(); 3];
+    assert_eq!(device.raw_samples().read_records(&mut samples), Ok(3));
+    assert_eq!(samples[2].x(), -1);
+
+    assert_eq!(device.interface.events, [Event::Read(0x30, 18)]);
+}
+
+#[test]
+fn stream_reads_until_empty() {
+    let mut interface = DeviceInterface::default();
+    for i in 0..5 {
+        interface.push_sample(i, 0, 0);
+    }
+    let mut device = MyTestDevice::new(interface);
+
+    let xs = device
+        .samples()
+        .stream::<2>()
+        .map(|sample| sample.map(|sample| sample.x()))
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(xs, Ok(vec![0, 1, 2, 3, 4]));
+
+    // Three chunks and the read of the empty level that ends the stream
+    assert_eq!(
+        device
+            .interface
+            .events
+            .iter()
+            .filter(|event| **event == Event::Read(0x11, 1))
+            .count(),
+        4
+    );
+}
+
+#[test]
+fn fifo_async() {
+    let mut interface = DeviceInterface::default();
+    interface.push_sample(1, 2, 3);
+    interface.push_sample(4, 5, 6);
+    let mut device = MyTestDevice::new(interface);
+
+    let mut stream = device.samples().stream::<8>();
+    let first = block_on(stream.next_async()).unwrap().unwrap();
+    assert_eq!(first.z(), 3);
+    let second = block_on(stream.next_async()).unwrap().unwrap();
+    assert_eq!(second.z(), 6);
+    assert!(block_on(stream.next_async()).is_none());
+
+    assert_eq!(block_on(device.samples().available_async()), Ok(0));
+    let mut samples = [Sample::default(); 1];
+    assert_eq!(
+        block_on(device.raw_samples().read_records_async(&mut samples)),
+        Ok(1)
+    );
+}
@@ -0,0 +1,130 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            default-byte-order: LE,
+            register-address-type: u8,
+            default-access: RW,
+
+            register Interrupts {
+                address: 0,
+                fields: fieldset Irq {
+                    size-bytes: 1,
+                    flags: allow,
+
+                    field rx 0 -> bool,
+                    field tx 1 -> bool,
+                    field timeout 4 -> uint,
+                }
+            },
+            register Status {
+                address: 1,
+                fields: fieldset StatusFlags {
+                    size-bytes: 2,
+                    byte-order: BE,
+                    flags: allow,
+
+                    field ready 0 -> bool,
+                    field busy 9 -> bool,
+                }
+            }
+        }
+    "
+);
+
+#[derive(Default)]
+pub struct DeviceInterface {
+    memory: [u8; 3],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+#[test]
+fn flag_constants_match_fields() {
+    assert!(Irq::RX.rx());
+    assert!(!Irq::RX.tx());
+    assert_eq!(Irq::TIMEOUT.timeout(), 1);
+    assert_eq!(<[u8; 1]>::from(Irq::all()), [0b1_0011]);
+
+    assert!(StatusFlags::READY.ready());
+    assert!(StatusFlags::BUSY.busy());
+    assert_eq!(<[u8; 2]>::from(StatusFlags::all()), [0x02, 0x01]);
+}
+
+#[test]
+fn set_operations() {
+    let mut irq = Irq::RX | Irq::TX;
+
+    assert!(irq.contains(Irq::RX));
+    assert!(!irq.contains(Irq::RX | Irq::TIMEOUT));
+    assert!(irq.intersects(Irq::RX | Irq::TIMEOUT));
+    assert!(!irq.is_empty());
+    assert!(!irq.is_all());
+
+    irq.insert(Irq::TIMEOUT);
+    assert!(irq.is_all());
+
+    irq.remove(Irq::RX | Irq::TX);
+    assert_eq!(irq, Irq::TIMEOUT);
+
+    irq.toggle(Irq::TIMEOUT | Irq::TX);
+    assert_eq!(irq, Irq::TX);
+
+    assert!(Irq::empty().is_empty());
+    assert_eq!(!Irq::all() & Irq::all(), Irq::empty());
+}
+
+#[test]
+fn iterate_flags() {
+    let irq = Irq::RX | Irq::TIMEOUT;
+
+    assert_eq!(irq.iter().collect::<Vec<_>>(), [Irq::RX, Irq::TIMEOUT]);
+    assert_eq!(irq.iter().collect::<Irq>(), irq);
+    assert_eq!(Irq::empty().iter().count(), 0);
+}
+
+#[test]
+fn debug_lists_flags() {
+    assert_eq!(format!("{:?}", Irq::RX | Irq::TIMEOUT), "Irq(RX | TIMEOUT)");
+    assert_eq!(format!("{:?}", Irq::empty()), "Irq()");
+    assert_eq!(format!("{:?}", StatusFlags::BUSY), "StatusFlags(BUSY)");
+}
+
+#[test]
+fn flags_in_registers() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device.interrupts().write(|irq| *irq = Irq::TX).unwrap();
+    device
+        .status()
+        .write(|status| status.set_busy(true))
+        .unwrap();
+
+    assert_eq!(device.interrupts().read().unwrap(), Irq::TX);
+    assert!(device.status().read().unwrap().contains(StatusFlags::BUSY));
+}
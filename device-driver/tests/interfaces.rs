@@ -0,0 +1,156 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use embedded_hal::{i2c, spi};
+
+/// Records every transaction and answers reads with incrementing bytes
+#[derive(Default)]
+struct FakeBus {
+    writes: Vec<Vec<u8>>,
+    reads: Vec<usize>,
+}
+
+impl FakeBus {
+    fn read(&mut self, buf: &mut [u8]) {
+        self.reads.push(buf.len());
+        for (i, byte) in buf.iter_mut().enumerate() {
+            *byte = i as u8 + 1;
+        }
+    }
+}
+
+impl spi::ErrorType for FakeBus {
+    type Error = spi::ErrorKind;
+}
+
+impl spi::SpiDevice for FakeBus {
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            match operation {
+                spi::Operation::Write(data) => self.writes.push(data.to_vec()),
+                spi::Operation::Read(buf) => self.read(buf),
+                _ => unimplemented!(),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl embedded_hal_async::spi::SpiDevice for FakeBus {
+    async fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        spi::SpiDevice::transaction(self, operations)
+    }
+}
+
+impl i2c::ErrorType for FakeBus {
+    type Error = i2c::ErrorKind;
+}
+
+impl i2c::I2c for FakeBus {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        assert_eq!(address, 0x42);
+
+        for operation in operations {
+            match operation {
+                i2c::Operation::Write(data) => self.writes.push(data.to_vec()),
+                i2c::Operation::Read(buf) => self.read(buf),
+            }
+        }
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            interface Spi {
+                bus: spi,
+                read-bit: 7,
+                increment-bit: 6,
+                dummy-bytes: 1,
+            },
+
+            interface Twi {
+                bus: i2c,
+                address-size-bytes: 2,
+                address-byte-order: LE,
+            },
+
+            register Foo {
+                address: 0x12,
+                fields: fieldset _ {
+                    size-bytes: 2,
+
+                    field value 15:0 -> uint,
+                },
+            },
+        }
+    "
+);
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn spi_interface_frames_address() {
+    let mut device = MyTestDevice::new(Spi::new(FakeBus::default()));
+
+    device.foo().write(|reg| reg.set_value(0xABCD)).unwrap();
+    assert_eq!(device.foo().read().unwrap().value(), 0x0201);
+
+    let bus = device.free().free();
+    assert_eq!(bus.writes, [vec![0x52], vec![0xCD, 0xAB], vec![0xD2]]);
+    // One dummy byte before the data
+    assert_eq!(bus.reads, [1, 2]);
+}
+
+#[test]
+fn spi_interface_async() {
+    let mut device = MyTestDevice::new(Spi::new(FakeBus::default()));
+
+    block_on(device.foo().write_async(|reg| reg.set_value(0xABCD))).unwrap();
+    assert_eq!(block_on(device.foo().read_async()).unwrap().value(), 0x0201);
+
+    let bus = device.free().free();
+    assert_eq!(bus.writes, [vec![0x52], vec![0xCD, 0xAB], vec![0xD2]]);
+}
+
+#[test]
+fn i2c_interface_frames_address() {
+    let mut device = MyTestDevice::new(Twi::new(FakeBus::default(), 0x42));
+
+    device.foo().write(|reg| reg.set_value(0xABCD)).unwrap();
+    assert_eq!(device.foo().read().unwrap().value(), 0x0201);
+
+    let bus = device.free().free();
+    assert_eq!(
+        bus.writes,
+        [vec![0x12, 0x00], vec![0xCD, 0xAB], vec![0x12, 0x00]]
+    );
+    assert_eq!(bus.reads, [2]);
+}
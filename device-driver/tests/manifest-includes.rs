@@ -0,0 +1,47 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 2],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    manifest: "tests/manifest-includes.ddsl"
+);
+
+#[test]
+fn included_register_is_generated() {
+    let mut device = IncludingDevice::new(DeviceInterface {
+        device_memory: [0x00, 0x80],
+    });
+
+    device.control().write(|reg| reg.set_enable(true)).unwrap();
+    assert_eq!(device.interface.device_memory[0], 0x01);
+
+    assert!(device.status().read().unwrap().ready());
+}
@@ -0,0 +1,210 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use device_driver::{
+    AsyncMemoryInterface, MemoryEraseInterface, MemoryError, MemoryInterface, MemoryInterfaceBase,
+};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Write(u16, Vec<u8>),
+    Read(u16, usize),
+    Erase(u16, usize),
+}
+
+#[derive(Default)]
+pub struct DeviceInterface {
+    events: Vec<Event>,
+}
+
+impl MemoryInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u16;
+}
+
+impl MemoryInterface for DeviceInterface {
+    fn write_memory(&mut self, address: Self::AddressType, data: &[u8]) -> Result<(), Self::Error> {
+        self.events.push(Event::Write(address, data.to_vec()));
+        Ok(())
+    }
+
+    fn read_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Read(address, data.len()));
+        data.fill(0xAA);
+        Ok(())
+    }
+}
+
+impl AsyncMemoryInterface for DeviceInterface {
+    async fn write_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        MemoryInterface::write_memory(self, address, data)
+    }
+
+    async fn read_memory(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        MemoryInterface::read_memory(self, address, data)
+    }
+}
+
+impl MemoryEraseInterface for DeviceInterface {
+    fn erase_memory(&mut self, address: Self::AddressType, len: usize) -> Result<(), Self::Error> {
+        self.events.push(Event::Erase(address, len));
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            memory-address-type: u16,
+            default-access: RW,
+
+            /// The EEPROM array
+            memory Eeprom {
+                address: 0x100,
+                size-bytes: 64,
+                page-size-bytes: 8,
+            },
+
+            memory Flash {
+                address: 0x1000,
+                size-bytes: 0x400,
+                word-size-bytes: 2,
+                page-size-bytes: 16,
+                write-granularity-bytes: 4,
+                erase-size-bytes: 0x100,
+            },
+
+            memory Rom {
+                access: RO,
+                address: 0x2000,
+                size-bytes: 16,
+            },
+        }
+    "
+);
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn writes_are_split_at_page_boundaries() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device
+        .eeprom()
+        .write(5, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+        .unwrap();
+
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Write(0x105, vec![1, 2, 3]),
+            Event::Write(0x108, vec![4, 5, 6, 7, 8, 9, 10, 11]),
+            Event::Write(0x110, vec![12]),
+        ]
+    );
+}
+
+#[test]
+fn word_addresses() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    let mut buf = [0; 4];
+    device.flash().read(6, &mut buf).unwrap();
+    assert_eq!(buf, [0xAA; 4]);
+
+    device.flash().write(12, &[0; 8]).unwrap();
+    device.flash().erase(0x100, 0x200).unwrap();
+
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Read(0x1003, 4),
+            Event::Write(0x1006, vec![0; 4]),
+            Event::Write(0x1008, vec![0; 4]),
+            Event::Erase(0x1080, 0x100),
+            Event::Erase(0x1100, 0x100),
+        ]
+    );
+}
+
+#[test]
+fn ranges_are_checked() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    assert_eq!(
+        device.eeprom().write(60, &[0; 5]),
+        Err(MemoryError::OutOfBounds { offset: 60, len: 5 })
+    );
+    assert_eq!(
+        device.flash().read(1, &mut [0; 2]),
+        Err(MemoryError::Unaligned {
+            offset: 1,
+            len: 2,
+            alignment: 2
+        })
+    );
+    assert_eq!(
+        device.flash().write(0, &[0; 2]),
+        Err(MemoryError::Unaligned {
+            offset: 0,
+            len: 2,
+            alignment: 4
+        })
+    );
+    assert_eq!(
+        device.flash().erase(0, 0x80),
+        Err(MemoryError::Unaligned {
+            offset: 0,
+            len: 0x80,
+            alignment: 0x100
+        })
+    );
+    assert!(device.interface.events.is_empty());
+
+    let mut buf = [0; 16];
+    device.rom().read(0, &mut buf).unwrap();
+    assert_eq!(device.rom().layout().size_bytes, 16);
+    assert_eq!(my_test_device::flash::ERASE_SIZE_BYTES, 0x100);
+}
+
+#[test]
+fn memory_async() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    block_on(device.eeprom().write_async(6, &[1, 2, 3])).unwrap();
+    let mut buf = [0; 2];
+    block_on(device.eeprom().read_async(0, &mut buf)).unwrap();
+
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Write(0x106, vec![1, 2]),
+            Event::Write(0x108, vec![3]),
+            Event::Read(0x100, 2),
+        ]
+    );
+}
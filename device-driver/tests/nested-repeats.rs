@@ -0,0 +1,159 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 256],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Pixel[4 stride 0x10][8 stride 1] {
+                address: 0x40,
+                reset: 0x07,
+                fields: fieldset Color {
+                    size-bytes: 1,
+
+                    field red 2:0 -> uint,
+                    field green 5:3 -> uint,
+                    field blue 7:6 -> uint,
+                },
+            },
+
+            register Gain[Axis stride 0x04][2 stride 1] {
+                address: 0x80,
+                fields: fieldset Level {
+                    size-bytes: 1,
+
+                    field value 7:0 -> uint,
+                },
+            },
+
+            register Trim[2 stride 0x08][Axis stride 2] {
+                address: 0xA0,
+                fields: Level,
+            },
+
+            enum Axis {
+                X: _,
+                Y: _,
+                Z: _,
+            },
+        }
+    "
+);
+
+fn new_device() -> MyTestDevice<DeviceInterface> {
+    MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 256],
+    })
+}
+
+#[test]
+fn count_dimensions() {
+    let mut device = new_device();
+
+    device
+        .pixel()
+        .write_at((3, 5), |reg| reg.set_green(2))
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0x40 + 3 * 0x10 + 5], 0x17);
+    assert_eq!(device.pixel().read_at((3, 5)).unwrap().green(), 2);
+    assert_eq!(device.pixel().read_at((1, 0)).unwrap().green(), 0);
+}
+
+#[test]
+fn arrays_run_over_the_inner_dimension() {
+    let mut device = new_device();
+
+    device
+        .pixel()
+        .write_array_at::<4>((1, 2), |regs| {
+            for (i, reg) in regs.iter_mut().enumerate() {
+                reg.set_blue(i as u8);
+            }
+        })
+        .unwrap();
+    assert_eq!(
+        device.interface.device_memory[0x52..][..4],
+        [0x07, 0x47, 0x87, 0xC7]
+    );
+
+    let pixels = device.pixel().read_array_at::<8>((1, 0)).unwrap();
+    assert_eq!(pixels[4].blue(), 2);
+    assert_eq!(pixels[0].blue(), 0);
+}
+
+#[test]
+#[should_panic]
+fn array_overflowing_inner_dimension() {
+    let mut device = new_device();
+    let _ = device.pixel().read_array_at::<4>((0, 6));
+}
+
+#[test]
+fn enum_dimensions() {
+    let mut device = new_device();
+
+    device
+        .gain()
+        .write_at((Axis::Z, 1), |reg| reg.set_value(0x12))
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0x80 + 2 * 0x04 + 1], 0x12);
+
+    device
+        .trim()
+        .write_at((1, Axis::Y), |reg| reg.set_value(0x34))
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0xA0 + 0x08 + 2], 0x34);
+    assert_eq!(device.trim().read_at((1, Axis::Y)).unwrap().value(), 0x34);
+}
+
+#[test]
+fn nested_indices_are_not_packed() {
+    type Nested = device_driver::NestedRepeat<
+        device_driver::ArrayRepeat<2, 0x10000>,
+        device_driver::ArrayRepeat<70_000, 1>,
+    >;
+
+    // Nested repeats can't have index overrides, so large inner indices can't collide with them
+    assert_eq!(
+        <Nested as device_driver::Repeating>::index_value(&(0, 65_536)),
+        None
+    );
+    assert_eq!(
+        <Nested as device_driver::Repeating>::index_value(&(1, 0)),
+        None
+    );
+}
@@ -0,0 +1,130 @@
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            command-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Control {
+                address: 2,
+                reset: 0x0A_05,
+                fields: fieldset ControlFields {
+                    byte-order: LE,
+                    size-bytes: 2,
+
+                    field low 3:0 -> u8,
+                    field high 15:4 -> u16,
+                },
+            },
+
+            register Status {
+                address: 4,
+                reset: [0x12, 0x34, 0x56],
+                fields: fieldset StatusFields {
+                    byte-order: BE,
+                    size-bytes: 3,
+
+                    field value 19:4 -> u16,
+                    field flag 23 -> bool,
+                },
+            },
+
+            command Sleep {
+                address: 8,
+                fields-in: fieldset SleepIn {
+                    size-bytes: 1,
+                },
+            },
+
+            block Channel {
+                address-offset: 0x10,
+
+                register Data {
+                    address: 1,
+                    fields: fieldset DataFields {
+                        size-bytes: 1,
+                    },
+                },
+            },
+
+            /// Its module is named like the `core` crate
+            block Core {
+                address-offset: 0x20,
+
+                register Id {
+                    address: 0,
+                    fields: fieldset IdFields {
+                        size-bytes: 1,
+
+                        field value 7:0 -> uint,
+                    },
+                },
+            },
+        }
+    "
+);
+
+#[test]
+fn operation_constants() {
+    assert_eq!(my_test_device::control::ADDRESS, 2);
+    assert_eq!(my_test_device::control::SIZE_BYTES, 2);
+    assert_eq!(my_test_device::control::RESET, 0x0A05);
+
+    assert_eq!(my_test_device::status::ADDRESS, 4);
+    assert_eq!(my_test_device::status::SIZE_BYTES, 3);
+    assert_eq!(my_test_device::status::RESET, 0x123456);
+
+    assert_eq!(my_test_device::sleep::ADDRESS, 8);
+    assert_eq!(my_test_device::sleep::SIZE_BYTES_IN, 1);
+
+    assert_eq!(channel::data::ADDRESS, 1);
+    assert_eq!(channel::data::RESET, 0);
+
+    assert_eq!(crate::core::id::ADDRESS, 0);
+}
+
+#[test]
+fn core_module_does_not_shadow_the_crate() {
+    assert_eq!(
+        format!("{:?}", IdFields::default()),
+        "IdFields { value: 0 }"
+    );
+}
+
+#[test]
+fn field_constants_le() {
+    assert_eq!(ControlFields::LOW_MASK, 0x000F);
+    assert_eq!(ControlFields::LOW_SHIFT, 0);
+    assert_eq!(ControlFields::HIGH_MASK, 0xFFF0);
+    assert_eq!(ControlFields::HIGH_SHIFT, 4);
+
+    let mut fields = ControlFields::default();
+    fields.set_high(0xABC);
+    let raw = u16::from_le_bytes(fields.into());
+    assert_eq!(
+        (raw & ControlFields::HIGH_MASK) >> ControlFields::HIGH_SHIFT,
+        0xABC
+    );
+    assert_eq!(raw & ControlFields::LOW_MASK, 0);
+}
+
+#[test]
+fn field_constants_be() {
+    assert_eq!(StatusFields::VALUE_MASK, 0x0F_FFF0);
+    assert_eq!(StatusFields::VALUE_SHIFT, 4);
+    assert_eq!(StatusFields::FLAG_MASK, 0x80_0000);
+    assert_eq!(StatusFields::FLAG_SHIFT, 23);
+
+    let mut fields = StatusFields::default();
+    fields.set_value(0xBEEF);
+    fields.set_flag(true);
+    let bytes: [u8; 3] = fields.into();
+    let raw = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+    assert_eq!(
+        (raw & StatusFields::VALUE_MASK) >> StatusFields::VALUE_SHIFT,
+        0xBEEF
+    );
+    assert_eq!(raw & StatusFields::FLAG_MASK, StatusFields::FLAG_MASK);
+}
@@ -0,0 +1,82 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 8],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Data {
+                address: 2,
+                reset: 0x81,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field raw 7:0 -> uint,
+                },
+                view: fieldset Measurement {
+                    size-bytes: 1,
+
+                    field value 6:0 -> uint,
+                    field valid 7 -> bool,
+                },
+            },
+        }
+    "
+);
+
+#[test]
+fn views_share_address_and_reset_value() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 8],
+    });
+
+    device
+        .data_as_measurement()
+        .write(|reg| {
+            assert_eq!(reg.value(), 1);
+            assert!(reg.valid());
+            reg.set_value(0x42);
+            reg.set_valid(false);
+        })
+        .unwrap();
+    assert_eq!(device.interface.device_memory[2], 0x42);
+    assert_eq!(device.data().read().unwrap().raw(), 0x42);
+
+    device.data().write(|reg| reg.set_raw(0x85)).unwrap();
+    let measurement = device.data_as_measurement().read().unwrap();
+    assert_eq!(measurement.value(), 5);
+    assert!(measurement.valid());
+}
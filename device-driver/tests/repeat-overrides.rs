@@ -0,0 +1,171 @@
+use device_driver::{FieldsetMetadata, IndexAccessError, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 64],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-byte-order: LE,
+            default-access: RW,
+
+            register Channel[at 0, 8, 0x20] {
+                address: 0x10,
+                reset: 0x11,
+                fields: fieldset ChannelConfig {
+                    size-bytes: 1,
+
+                    field gain 3:0 -> uint,
+                    field enabled 7 -> bool,
+                },
+
+                index 1 {
+                    reset: 0x82,
+                },
+                index 2 {
+                    access: RO,
+                },
+            },
+
+            register Threshold[3 stride 1] {
+                address: 0x04,
+                reset: 0x10,
+                fields: fieldset Level {
+                    size-bytes: 1,
+
+                    field value 7:0 -> uint,
+                },
+
+                index 2 {
+                    reset: 0x20,
+                },
+            },
+
+            register Offset[Axis stride 1] {
+                address: 0x08,
+                fields: Level,
+
+                index Z {
+                    reset: 0x30,
+                },
+            },
+
+            enum Axis {
+                X: _,
+                Y: _,
+                Z: _,
+            },
+        }
+    "
+);
+
+#[test]
+fn offsets_are_used_for_the_addresses() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 64],
+    });
+
+    device.channel().write_at(0, |reg| reg.set_gain(1)).unwrap();
+    device.channel().write_at(1, |reg| reg.set_gain(2)).unwrap();
+    device.interface.device_memory[0x30] = 0x05;
+
+    assert_eq!(device.interface.device_memory[0x10], 0x11);
+    assert_eq!(device.interface.device_memory[0x18], 0x82);
+    assert_eq!(device.channel().read_at(2).unwrap().gain(), 5);
+
+    assert_eq!(my_test_device::channel::OFFSETS, [0, 8, 0x20]);
+}
+
+#[test]
+fn overridden_reset_values() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 64],
+    });
+
+    assert_eq!(device.channel().reset_value_at(0).gain(), 1);
+    assert_eq!(device.channel().reset_value_at(1).gain(), 2);
+    assert!(device.channel().reset_value_at(1).enabled());
+    assert_eq!(device.channel().reset_value_at(2).gain(), 1);
+
+    assert_eq!(device.offset().reset_value_at(Axis::Y).value(), 0);
+    assert_eq!(device.offset().reset_value_at(Axis::Z).value(), 0x30);
+
+    device
+        .threshold()
+        .write_array_at::<3>(0, |regs| {
+            assert_eq!(regs.map(|reg| reg.value()), [0x10, 0x10, 0x20]);
+        })
+        .unwrap();
+    assert_eq!(device.interface.device_memory[4..7], [0x10, 0x10, 0x20]);
+}
+
+#[test]
+fn overridden_access_is_checked() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 64],
+    });
+
+    assert_eq!(
+        device.channel().write_at(2, |reg| reg.set_gain(1)),
+        Err(IndexAccessError::NotWritable { index: 2 })
+    );
+    assert_eq!(
+        device.channel().modify_at(2, |reg| reg.set_gain(1)),
+        Err(IndexAccessError::NotWritable { index: 2 })
+    );
+    assert_eq!(device.interface.device_memory[0x30], 0);
+
+    // Index 2 can still be read, and the other indices written
+    assert_eq!(device.channel().read_at(2).unwrap().gain(), 0);
+    device.channel().write_at(1, |reg| reg.set_gain(1)).unwrap();
+}
+
+#[test]
+fn plans_use_overridden_reset_values() {
+    use device_driver::Block;
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 64],
+    });
+
+    // Threshold only overrides a reset value, so its access is the same for every index and it has plans
+    device
+        .bulk_write()
+        .with(|d| d.threshold().plan_at(0))
+        .with(|d| d.threshold().plan_array_at::<2>(1))
+        .execute(|(first, [_, last])| {
+            first.set_value(1);
+            assert_eq!(last.value(), 0x20);
+        })
+        .unwrap();
+    assert_eq!(device.interface.device_memory[4..7], [0x01, 0x10, 0x20]);
+}
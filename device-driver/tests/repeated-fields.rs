@@ -0,0 +1,62 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            default-byte-order: LE,
+            register-address-type: u8,
+            default-access: RW,
+
+            register Foo {
+                address: 0,
+
+                fields: fieldset FooFieldSet {
+                    size-bytes: 1,
+
+                    field value[4 stride 2] 1:0 -> uint
+                }
+            }
+        }
+    "
+);
+
+pub struct DeviceInterface;
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        _address: Self::AddressType,
+        _data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+
+    fn read_register(
+        &mut self,
+        _address: Self::AddressType,
+        _data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn repeated_fields_only_cover_their_own_bits() {
+    let mut foo = FooFieldSet::from([0]);
+
+    foo.set_value(1, 0b01);
+    foo.set_value(0, 0b11);
+    foo.set_value(3, 0b10);
+
+    assert_eq!(foo.value(0), 0b11);
+    assert_eq!(foo.value(1), 0b01);
+    assert_eq!(foo.value(2), 0b00);
+    assert_eq!(foo.value(3), 0b10);
+    assert_eq!(<[u8; 1]>::from(foo), [0b1000_0111]);
+}
@@ -0,0 +1,164 @@
+use device_driver::{
+    Block, CommandInterface, CommandInterfaceBase, FieldsetMetadata, RegisterInterface,
+    RegisterInterfaceBase,
+};
+
+#[derive(Default)]
+pub struct DeviceInterface {
+    memory: [u8; 4],
+    last_input: Vec<u8>,
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+impl CommandInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl CommandInterface for DeviceInterface {
+    fn dispatch_command(
+        &mut self,
+        _address: Self::AddressType,
+        input: &mut [u8],
+        _input_metadata: &FieldsetMetadata,
+        _output: &mut [u8],
+        _output_metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.last_input = input.to_vec();
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            default-byte-order: LE,
+            register-address-type: u8,
+            register-address-mode: mapped,
+            command-address-type: u8,
+            default-access: RW,
+
+            register Config {
+                address: 0,
+                reset: 0x81,
+                fields: fieldset ConfigFields {
+                    size-bytes: 1,
+
+                    field enable 0 -> bool,
+                    field mode 3:1 -> uint,
+                    field reserved 7:6 -> uint {
+                        reserved: 0b10,
+                    },
+                }
+            },
+            register Control {
+                address: 1,
+                fields: fieldset ControlFields {
+                    size-bytes: 1,
+
+                    field start 0 -> bool,
+                    field always_set 7 -> bool {
+                        reserved: 1,
+                    },
+                }
+            },
+            command Reset {
+                address: 0x10,
+                fields-in: ControlFields,
+            },
+        }
+    "
+);
+
+#[test]
+fn write_forces_reserved() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device
+        .config()
+        .write(|config| *config = ConfigFields::from([0xFF]))
+        .unwrap();
+    assert_eq!(device.interface.memory[0], 0b1011_1111);
+
+    device.config().write_with_zero(|_| {}).unwrap();
+    assert_eq!(device.interface.memory[0], 0b1000_0000);
+
+    device.control().write_with_zero(|_| {}).unwrap();
+    assert_eq!(device.interface.memory[1], 0b1000_0000);
+}
+
+#[test]
+fn modify_forces_reserved() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device.interface.memory[0] = 0b0100_0001;
+    device.config().modify(|config| config.set_mode(3)).unwrap();
+    assert_eq!(device.interface.memory[0], 0b1000_0111);
+}
+
+#[test]
+fn bulk_write_forces_reserved() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device
+        .bulk_write()
+        .with(|d| d.config().plan_with_zero())
+        .with(|d| d.control().plan_with_zero())
+        .execute(|(config, control)| {
+            config.set_enable(true);
+            control.set_start(true);
+        })
+        .unwrap();
+    assert_eq!(device.interface.memory[..2], [0b1000_0001, 0b1000_0001]);
+}
+
+#[test]
+fn command_input_forces_reserved() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device
+        .reset()
+        .dispatch_in(|input| input.set_start(true))
+        .unwrap();
+    assert_eq!(device.interface.last_input, [0b1000_0001]);
+}
+
+#[test]
+fn debug_shows_wrong_reserved_values() {
+    assert_eq!(
+        format!("{:?}", ConfigFields::from([0b1000_0000])),
+        "ConfigFields { enable: false, mode: 0, reserved: 2 }"
+    );
+    assert_eq!(
+        format!("{:?}", ConfigFields::from([0b0100_0000])),
+        "ConfigFields { enable: false, mode: 0, reserved: 1 (reserved, must be 2) }"
+    );
+    assert_eq!(
+        format!("{:?}", ControlFields::from([0])),
+        "ControlFields { start: false, always_set: false (reserved, must be true) }"
+    );
+}
@@ -0,0 +1,297 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use device_driver::{
+    CommandInterface, CommandInterfaceBase, FieldsetMetadata, RegisterInterface,
+    RegisterInterfaceBase, RoutineError,
+};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Write(u8, u8),
+    Read(u8),
+    Dispatch(u8, Vec<u8>),
+}
+
+#[derive(Default)]
+pub struct DeviceInterface {
+    memory: [u8; 4],
+    /// The amount of status reads before the device reports it's ready
+    busy_reads: u8,
+    events: Vec<Event>,
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Write(address, data[0]));
+        self.memory[address as usize] = data[0];
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Read(address));
+
+        if address == 1 {
+            if self.busy_reads > 0 {
+                self.busy_reads -= 1;
+                self.memory[1] = 0;
+            } else {
+                self.memory[1] = 0b101;
+            }
+        }
+
+        data[0] = self.memory[address as usize];
+        Ok(())
+    }
+}
+
+impl CommandInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+
+impl CommandInterface for DeviceInterface {
+    fn dispatch_command(
+        &mut self,
+        address: Self::AddressType,
+        input: &mut [u8],
+        _input_metadata: &FieldsetMetadata,
+        _output: &mut [u8],
+        _output_metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Dispatch(address, input.to_vec()));
+        Ok(())
+    }
+}
+
+impl device_driver::AsyncRegisterInterface for DeviceInterface {
+    async fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        RegisterInterface::write_register(self, address, data, metadata)
+    }
+
+    async fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        RegisterInterface::read_register(self, address, data, metadata)
+    }
+}
+
+impl device_driver::AsyncCommandInterface for DeviceInterface {
+    async fn dispatch_command(
+        &mut self,
+        address: Self::AddressType,
+        input: &mut [u8],
+        input_metadata: &FieldsetMetadata,
+        output: &mut [u8],
+        output_metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        CommandInterface::dispatch_command(
+            self,
+            address,
+            input,
+            input_metadata,
+            output,
+            output_metadata,
+        )
+    }
+}
+
+/// Sums up all requested delays
+#[derive(Default)]
+struct FakeDelay {
+    total_ns: u64,
+}
+
+impl embedded_hal::delay::DelayNs for FakeDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += u64::from(ns);
+    }
+}
+
+impl embedded_hal_async::delay::DelayNs for FakeDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += u64::from(ns);
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            command-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            const GAIN = 5,
+
+            /// Reset and configure the device
+            routine setup {
+                write Control { reset: 1 },
+                delay us 150,
+                poll Status { ready: 1, mode: Active, interval-us: 10, timeout-us: 40 },
+                modify Config { gain: GAIN, mode: Sleep },
+                dispatch Apply { slot: 2 },
+                return Status,
+            },
+
+            routine commit {
+                dispatch Apply,
+            },
+
+            enum Mode -> u8 {
+                Idle: 0,
+                Active: 2,
+                Sleep: 3,
+            },
+
+            register Control {
+                address: 0,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field reset 0 -> bool,
+                },
+            },
+
+            register Status {
+                access: RO,
+                address: 1,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field ready 0 -> bool,
+                    field mode 2:1 -> u8 as try Mode,
+                },
+            },
+
+            register Config {
+                address: 2,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field gain 3:0 -> uint,
+                    field mode 5:4 -> u8 as try Mode,
+                },
+            },
+
+            command Apply {
+                address: 7,
+                fields-in: fieldset _ {
+                    size-bytes: 1,
+
+                    field slot 1:0 -> uint,
+                },
+            },
+        }
+    "
+);
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn expected_setup_events() -> Vec<Event> {
+    vec![
+        Event::Write(0, 0x01),
+        Event::Read(1),
+        Event::Read(1),
+        Event::Read(1),
+        Event::Read(2),
+        Event::Write(2, 0x35),
+        Event::Dispatch(7, vec![0x02]),
+        Event::Read(1),
+    ]
+}
+
+#[test]
+fn routine_runs_statements_in_order() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        busy_reads: 2,
+        ..Default::default()
+    });
+    let mut delay = FakeDelay::default();
+
+    let status = device.setup(&mut delay).unwrap();
+    assert!(status.ready());
+    assert_eq!(status.mode(), Ok(Mode::Active));
+    assert_eq!(delay.total_ns, 170_000);
+    assert_eq!(device.interface.events, expected_setup_events());
+}
+
+#[test]
+fn routine_async() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        busy_reads: 2,
+        ..Default::default()
+    });
+    let mut delay = FakeDelay::default();
+
+    let status = block_on(device.setup_async(&mut delay)).unwrap();
+    assert!(status.ready());
+    assert_eq!(delay.total_ns, 170_000);
+    assert_eq!(device.interface.events, expected_setup_events());
+}
+
+#[test]
+fn routine_poll_times_out() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        busy_reads: 10,
+        ..Default::default()
+    });
+    let mut delay = FakeDelay::default();
+
+    assert_eq!(device.setup(&mut delay).unwrap_err(), RoutineError::Timeout);
+    assert_eq!(delay.total_ns, 190_000);
+    assert_eq!(
+        device.interface.events,
+        [
+            Event::Write(0, 0x01),
+            Event::Read(1),
+            Event::Read(1),
+            Event::Read(1),
+            Event::Read(1),
+            Event::Read(1),
+        ]
+    );
+}
+
+#[test]
+fn routine_without_delay() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device.commit().unwrap();
+    assert_eq!(device.interface.events, [Event::Dispatch(7, vec![0x00])]);
+}
@@ -0,0 +1,111 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+#[derive(Default)]
+pub struct DeviceInterface {
+    memory: [u8; 8],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    unstable_ddsl: "
+        device MyTestDevice {
+            default-byte-order: LE,
+            register-address-type: u8,
+            register-address-mode: mapped,
+            default-access: RW,
+
+            register Threshold {
+                address: 0,
+                fields: fieldset ThresholdFields {
+                    size-bytes: 3,
+
+                    field threshold 7:0 17:16 -> uint,
+                    field offset 11:8 23:22 -> int,
+                    field level[2 stride 1] 12 18 -> uint,
+                }
+            },
+            register Time {
+                address: 4,
+                fields: fieldset TimeFields {
+                    size-bytes: 2,
+                    byte-order: BE,
+
+                    field minutes 15:12 3:0 -> uint bcd {
+                        reset: 42,
+                    },
+                }
+            },
+        }
+    "
+);
+
+#[test]
+fn split_value_is_assembled() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    device
+        .threshold()
+        .write(|threshold| threshold.set_threshold(0b10_1100_0011))
+        .unwrap();
+    assert_eq!(device.interface.memory[..3], [0b1100_0011, 0, 0b10]);
+    assert_eq!(
+        device.threshold().read().unwrap().threshold(),
+        0b10_1100_0011
+    );
+}
+
+#[test]
+fn split_signed_value() {
+    let mut fields = ThresholdFields::default();
+
+    fields.set_offset(-3);
+    assert_eq!(<[u8; 3]>::from(fields), [0, 0b1101, 0b1100_0000]);
+    assert_eq!(fields.offset(), -3);
+
+    fields.set_offset(31);
+    assert_eq!(fields.offset(), 31);
+}
+
+#[test]
+fn split_repeated_field() {
+    let mut fields = ThresholdFields::default();
+
+    fields.set_level(1, 0b11);
+    assert_eq!(<[u8; 3]>::from(fields), [0, 0b0010_0000, 0b1000]);
+    assert_eq!(fields.level(0), 0);
+    assert_eq!(fields.level(1), 0b11);
+}
+
+#[test]
+fn split_encoded_field() {
+    let mut device = MyTestDevice::new(DeviceInterface::default());
+
+    let reset = device.time().reset_value();
+    assert_eq!(<[u8; 2]>::from(reset), [0x20, 0x04]);
+    assert_eq!(reset.minutes(), 42);
+}
@@ -0,0 +1,264 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use device_driver::{
+    CommandInterface, CommandInterfaceBase, FieldsetMetadata, RegisterInterface,
+    RegisterInterfaceBase,
+};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Write(u8, u8),
+    Read(u8),
+    Dispatch(u8),
+}
+
+#[derive(Debug, Default)]
+pub struct DeviceInterface {
+    memory: [u8; 3],
+    events: Vec<Event>,
+    /// Every access fails when set
+    broken: bool,
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        if self.broken {
+            return Err(());
+        }
+
+        self.events.push(Event::Write(address, data[0]));
+        self.memory[address as usize] = data[0];
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        if self.broken {
+            return Err(());
+        }
+
+        self.events.push(Event::Read(address));
+        data.copy_from_slice(&self.memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+impl CommandInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+
+impl CommandInterface for DeviceInterface {
+    fn dispatch_command(
+        &mut self,
+        address: Self::AddressType,
+        _input: &mut [u8],
+        _input_metadata: &FieldsetMetadata,
+        _output: &mut [u8],
+        _output_metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.events.push(Event::Dispatch(address));
+        Ok(())
+    }
+}
+
+impl device_driver::AsyncCommandInterface for DeviceInterface {
+    async fn dispatch_command(
+        &mut self,
+        address: Self::AddressType,
+        input: &mut [u8],
+        input_metadata: &FieldsetMetadata,
+        output: &mut [u8],
+        output_metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        CommandInterface::dispatch_command(
+            self,
+            address,
+            input,
+            input_metadata,
+            output,
+            output_metadata,
+        )
+    }
+}
+
+/// Sums up all requested delays
+#[derive(Default)]
+struct FakeDelay {
+    total_ns: u64,
+}
+
+impl embedded_hal::delay::DelayNs for FakeDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += u64::from(ns);
+    }
+}
+
+impl embedded_hal_async::delay::DelayNs for FakeDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.total_ns += u64::from(ns);
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            command-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            states Power {
+                initial: Sleep,
+
+                state Sleep,
+                state Standby,
+                state Measuring,
+
+                transition wake {
+                    from: Sleep,
+                    to: Standby,
+                    routine: wake_up,
+                },
+
+                transition start {
+                    from: Standby,
+                    to: Measuring,
+                    write Control { measure: 1 },
+                },
+
+                transition sleep {
+                    from: Standby,
+                    from: Measuring,
+                    to: Sleep,
+                    dispatch PowerDown,
+                    delay us 100,
+                },
+
+                allowed Data {
+                    state: Measuring,
+                },
+            },
+
+            routine wake_up {
+                write Control { awake: 1 },
+            },
+
+            register Control {
+                address: 0,
+                fields: fieldset _ {
+                    size-bytes: 1,
+
+                    field measure 0 -> bool,
+                    field awake 1 -> bool,
+                },
+            },
+
+            register Data {
+                access: RO,
+                address: 1,
+                fields: fieldset _ {
+                    size-bytes: 2,
+
+                    field value 15:0 -> uint,
+                },
+            },
+
+            command PowerDown {
+                address: 3,
+            },
+        }
+    "
+);
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn transitions_run_in_order() {
+    let device = Power::new(MyTestDevice::new(DeviceInterface::default()));
+    let mut delay = FakeDelay::default();
+
+    let device = device.wake().unwrap();
+    let mut device = device.start().unwrap();
+    device.data().read().unwrap();
+    let device = device.sleep(&mut delay).unwrap();
+
+    assert_eq!(delay.total_ns, 100_000);
+    assert_eq!(
+        device.free().free().events,
+        [
+            Event::Write(0, 0b10),
+            Event::Write(0, 0b01),
+            Event::Read(1),
+            Event::Dispatch(3),
+        ]
+    );
+}
+
+#[test]
+fn operations_allowed_in_every_state() {
+    let mut device = Power::new(MyTestDevice::new(DeviceInterface::default()));
+
+    device.control().write(|reg| reg.set_awake(true)).unwrap();
+    device.power_down().dispatch().unwrap();
+
+    let mut device = device.wake().unwrap();
+    assert!(device.control().read().unwrap().awake());
+}
+
+#[test]
+fn transition_async() {
+    let device = Power::new(MyTestDevice::new(DeviceInterface::default()));
+    let mut delay = FakeDelay::default();
+
+    let device = device.wake().unwrap();
+    let device: Power<_, power::Sleep> = block_on(device.sleep_async(&mut delay)).unwrap();
+
+    assert_eq!(delay.total_ns, 100_000);
+    assert_eq!(
+        device.free().free().events,
+        [Event::Write(0, 0b10), Event::Dispatch(3)]
+    );
+}
+
+#[test]
+fn failed_transition_returns_device() {
+    let device = Power::new(MyTestDevice::new(DeviceInterface {
+        broken: true,
+        ..Default::default()
+    }));
+
+    let error = device.wake().unwrap_err();
+    assert_eq!(error.error, ());
+
+    let mut device = error.device;
+    device.interface.broken = false;
+    assert!(device.control().read().is_ok());
+}
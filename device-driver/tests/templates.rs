@@ -0,0 +1,102 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 32],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+
+            template Channel {
+                address: _,
+                config-name: _,
+                config-reset: 0,
+                config-access: RW,
+
+                block _ {
+                    address-offset: address,
+
+                    register config-name {
+                        address: 1,
+                        access: config-access,
+                        reset: config-reset,
+                        fields: ChannelConfig,
+                    },
+                }
+            },
+
+            fieldset ChannelConfig {
+                size-bytes: 1,
+
+                field gain 3:0 -> uint,
+                field enable 7 -> bool,
+            },
+
+            Channel Channel0 {
+                address: 0x00,
+                config-name: Channel0Config,
+            },
+            Channel Channel1 {
+                address: 0x10,
+                config-name: Channel1Config,
+                config-reset: 0x85,
+                config-access: RO,
+            },
+        }
+    "
+);
+
+#[test]
+fn instances_use_their_arguments() {
+    assert_eq!(channel_0::channel_0_config::ADDRESS, 1);
+    assert_eq!(channel_0::channel_0_config::RESET, 0x00);
+    assert_eq!(channel_1::channel_1_config::ADDRESS, 1);
+    assert_eq!(channel_1::channel_1_config::RESET, 0x85);
+
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 32],
+    });
+    device.interface.device_memory[0x11] = 0x83;
+
+    device
+        .channel_0()
+        .channel_0_config()
+        .write(|reg| reg.set_gain(5))
+        .unwrap();
+    assert_eq!(device.interface.device_memory[0x01], 0x05);
+
+    let reg = device.channel_1().channel_1_config().read().unwrap();
+    assert_eq!(reg.gain(), 3);
+    assert!(reg.enable());
+}
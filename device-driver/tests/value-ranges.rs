@@ -0,0 +1,119 @@
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 16],
+}
+
+impl Default for DeviceInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeviceInterface {
+    pub const fn new() -> Self {
+        Self {
+            device_memory: [0; 16],
+        }
+    }
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+device_driver::compile!(
+    options: "--rust-debug-assert-setters",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-access: RW,
+            default-byte-order: LE,
+
+            register Control {
+                address: 0,
+                reset: 0x00_0A,
+                fields: fieldset ControlFields {
+                    size-bytes: 2,
+
+                    field threshold 7:0 -> uint {
+                        range: 10..=200,
+                    },
+                    field gain 11:8 -> int,
+                    field mode 15:12 -> uint bcd,
+                }
+            },
+        }
+    "
+);
+
+#[test]
+fn checked_setters() {
+    let mut reg = ControlFields::default();
+
+    assert_eq!(reg.try_set_threshold(200), Ok(()));
+    assert_eq!(
+        reg.try_set_threshold(201),
+        Err(device_driver::ValueOutOfRange {
+            value: 201,
+            min: 10,
+            max: 200,
+            field: "threshold",
+        })
+    );
+    assert_eq!(reg.threshold(), 200);
+
+    assert_eq!(reg.try_set_gain(-8), Ok(()));
+    assert!(reg.try_set_gain(8).is_err());
+    assert_eq!(reg.gain(), -8);
+
+    assert_eq!(reg.try_set_mode(9), Ok(()));
+    assert!(reg.try_set_mode(10).is_err());
+    assert_eq!(reg.mode(), 9);
+}
+
+#[test]
+fn checked_setters_in_write() {
+    let mut device = MyTestDevice::new(DeviceInterface::new());
+
+    let mut result = Ok(());
+    device
+        .control()
+        .write(|reg| result = reg.try_set_threshold(250))
+        .unwrap();
+
+    assert!(result.is_err());
+
+    // The reset value is written
+    assert_eq!(&device.interface.device_memory[..2], &[10, 0]);
+}
+
+#[test]
+#[should_panic]
+fn debug_asserted_setter_panics() {
+    let mut reg = ControlFields::default();
+    reg.set_threshold(201);
+}
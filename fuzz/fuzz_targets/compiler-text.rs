@@ -1,6 +1,8 @@
 #![no_main]
 
-use device_driver_core::{CodegenTarget, CompileOptions, GeneralOptions, MirOptions};
+use device_driver_core::{
+    CodegenTarget, CompileOptions, GeneralOptions, MemoryFileLoader, MirOptions, SourceMap,
+};
 use libfuzzer_sys::fuzz_target;
 
 #[derive(Debug, arbitrary::Arbitrary)]
@@ -11,8 +13,10 @@ struct Input<'a> {
 
 // Goal: Don't have panics or ICE's
 fuzz_target!(|input: Input<'_>| {
+    let mut source_map = SourceMap::new("fuzz.ddsl", input.source);
     match device_driver_core::compile(
-        input.source,
+        &mut source_map,
+        &MemoryFileLoader::default(),
         CompileOptions {
             general_options: GeneralOptions::default(),
             mir_options: MirOptions {
@@ -29,8 +33,7 @@ fuzz_target!(|input: Input<'_>| {
                 .print_to_fmt(
                     &mut d,
                     device_driver_core::Metadata {
-                        source: input.source,
-                        source_path: "fuzz.ddsl",
+                        source_map: &source_map,
                         term_width: None,
                         ansi: false,
                         unicode: false,
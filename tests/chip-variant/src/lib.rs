@@ -0,0 +1,3 @@
+//! A crate to test the chip variants of a device with one of the variants enabled.
+//! The chip variants are cargo features, so they can't be enabled in the tests of device-driver itself.
+//! The tests are in the `tests` folder.
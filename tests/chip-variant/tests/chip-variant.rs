@@ -0,0 +1,125 @@
+// The tests are written for the default chip variant
+#![cfg(feature = "chip-b")]
+
+use device_driver::{FieldsetMetadata, RegisterInterface, RegisterInterfaceBase};
+
+pub struct DeviceInterface {
+    device_memory: [u8; 8],
+}
+
+impl RegisterInterfaceBase for DeviceInterface {
+    type Error = ();
+    type AddressType = u8;
+}
+impl RegisterInterface for DeviceInterface {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        self.device_memory[address as usize..][..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.device_memory[address as usize..][..data.len()]);
+        Ok(())
+    }
+}
+
+// This crate enables `chip-b` by default, so the registers of `chip-b` exist
+device_driver::compile!(
+    options: "",
+    unstable_ddsl: "
+        device MyTestDevice {
+            register-address-type: u8,
+            default-byte-order: LE,
+            default-access: RW,
+            variant: chip-a,
+            variant: chip-b,
+
+            register Shared {
+                address: 0,
+                fields: Small,
+            },
+
+            register Config {
+                when: chip-a,
+                address: 1,
+                fields: Small,
+            },
+
+            // Same name and address, but a different layout
+            register Config {
+                when: chip-b,
+                address: 1,
+                fields: fieldset ConfigB {
+                    size-bytes: 2,
+
+                    field value 15:0 -> uint,
+                },
+            },
+
+            routine configure {
+                when: chip-a,
+                write Config { value: 0x12 },
+            },
+
+            routine configure {
+                when: chip-b,
+                write Config { value: 0x3456 },
+            },
+
+            block Extra {
+                when: chip-b,
+                address-offset: 4,
+
+                register Id {
+                    address: 0,
+                    access: RO,
+                    fields: Small,
+                },
+            },
+
+            fieldset Small {
+                size-bytes: 1,
+
+                field value 7:0 -> uint,
+            },
+        }
+    "
+);
+
+#[test]
+fn variant_registers_exist() {
+    assert_eq!(my_test_device::config::ADDRESS, 1);
+    assert_eq!(my_test_device::config::SIZE_BYTES, 2);
+
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 8],
+    });
+
+    // The register of chip B is 2 bytes wide
+    device.config().write(|reg| reg.set_value(0xABCD)).unwrap();
+    assert_eq!(device.interface.device_memory[1..3], [0xCD, 0xAB]);
+    assert_eq!(device.config().read().unwrap().value(), 0xABCD);
+
+    device.interface.device_memory[4] = 0x42;
+    assert_eq!(device.extra().id().read().unwrap().value(), 0x42);
+}
+
+#[test]
+fn variant_routines_use_variant_registers() {
+    let mut device = MyTestDevice::new(DeviceInterface {
+        device_memory: [0; 8],
+    });
+
+    device.configure().unwrap();
+    assert_eq!(device.interface.device_memory[1..3], [0x56, 0x34]);
+}
@@ -64,7 +64,7 @@ fn generate_test_function(test_dir: DirEntry) -> String {
 #[test]
 {ignore_tag}
 fn {test_name}() {{
-    crate::run_test(&[{input_paths}], &Path::new(r\"{output_path}\"));
+    crate::run_test(&[{input_paths}], Path::new(r\"{output_path}\"));
 }}"
     )
 }
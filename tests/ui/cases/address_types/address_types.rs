@@ -89,6 +89,26 @@ impl<I> ::device_driver::Block for Device<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Device`
+pub mod device {
+    /// Raw constants of the `foo` register
+    pub mod foo {
+        /// The address of the register
+        pub const ADDRESS: u16 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `bar` command
+    pub mod bar {
+        /// The address of the command
+        pub const ADDRESS: i32 = 0;
+    }
+    /// Raw constants of the `quux` buffer
+    pub mod quux {
+        /// The address of the buffer
+        pub const ADDRESS: i8 = 0;
+    }
+}
 #[derive(Copy, Clone, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct FooFieldSet {
@@ -117,62 +137,62 @@ impl From<FooFieldSet> for [u8; 0] {
         val.bits
     }
 }
-impl core::fmt::Debug for FooFieldSet {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for FooFieldSet {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("FooFieldSet");
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for FooFieldSet {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "FooFieldSet {{ ");
-        defmt::write!(f, "}}");
+impl ::defmt::Format for FooFieldSet {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FooFieldSet {{ ");
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for FooFieldSet {
+impl ::core::ops::BitAnd for FooFieldSet {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for FooFieldSet {
+impl ::core::ops::BitAndAssign for FooFieldSet {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for FooFieldSet {
+impl ::core::ops::BitOr for FooFieldSet {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for FooFieldSet {
+impl ::core::ops::BitOrAssign for FooFieldSet {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for FooFieldSet {
+impl ::core::ops::BitXor for FooFieldSet {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for FooFieldSet {
+impl ::core::ops::BitXorAssign for FooFieldSet {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for FooFieldSet {
+impl ::core::ops::Not for FooFieldSet {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -0,0 +1,1119 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+/// Root block of the AutoAddresses driver
+#[derive(Debug)]
+pub struct AutoAddresses<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<I> AutoAddresses<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Starts at 0
+    ///
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Control")]
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+    /// Placed after the 2 bytes of the control register at 2
+    ///
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Placed at 3 to 6
+    ///
+    /// Register operation:
+    /// - Address: `3`
+    /// - Reset value: `0`
+    /// - Index range: `0..4`
+    #[doc(alias = "Channel")]
+    pub fn channel(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        ::device_driver::ArrayRepeat<4, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 3;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `16`
+    /// - Reset value: `0`
+    #[doc(alias = "Id")]
+    pub fn id(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 16;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+    /// Placed after the id register at 0x12
+    ///
+    /// Fifo operation:
+    /// - Address: `18`
+    /// - Record size: `1` bytes
+    #[doc(alias = "Samples")]
+    pub fn samples(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<'_, Self, Status, u8, ::device_driver::NoLevel>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 18;
+        ::device_driver::FifoOperation::new(
+            self,
+            address as u8,
+            ::device_driver::NoLevel,
+        )
+    }
+    /// Block operation:
+    /// - Address: `64`
+    #[doc(alias = "Bank")]
+    pub fn bank(&mut self) -> Bank<'_, I> {
+        let address = self.base_address + 64;
+        Bank::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// The objects don't need an address
+    ///
+    /// Block operation:
+    /// - Address: `96`
+    #[doc(alias = "Extended")]
+    pub fn extended(&mut self) -> Extended<'_, I> {
+        let address = self.base_address + 96;
+        Extended::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Both variants of the config start at 0x84 and the extra register goes after the largest one at 0x86
+    ///
+    /// Block operation:
+    /// - Address: `128`
+    #[doc(alias = "Variants")]
+    pub fn variants(&mut self) -> Variants<'_, I> {
+        let address = self.base_address + 128;
+        Variants::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Command operation:
+    /// - Address: `0`
+    #[doc(alias = "Reset")]
+    pub fn reset(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Command operation:
+    /// - Address: `1`
+    #[doc(alias = "Sleep")]
+    pub fn sleep(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Buffer operation:
+    /// - Address: `0`
+    #[doc(alias = "Tx")]
+    pub fn tx(
+        &mut self,
+    ) -> ::device_driver::BufferOperation<'_, Self, u8, ::device_driver::RW>
+    where
+        I: ::device_driver::BufferInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::BufferOperation::new(self, address as u8)
+    }
+    /// Memory operation:
+    /// - Address: `256`
+    /// - Size: `256` bytes
+    #[doc(alias = "Eeprom")]
+    pub fn eeprom(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 256;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 256,
+                word_size_bytes: 1,
+                page_size_bytes: None,
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+    /// Memory operation:
+    /// - Address: `512`
+    /// - Size: `32` bytes
+    #[doc(alias = "Otp")]
+    pub fn otp(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 512;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 32,
+                word_size_bytes: 1,
+                page_size_bytes: None,
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+}
+impl<I> ::device_driver::Block for AutoAddresses<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+impl<I> ::device_driver::MemoryBlock for AutoAddresses<I> {
+    type MemoryAddressType = u16;
+}
+/// Raw constants of the operations of `AutoAddresses`
+pub mod auto_addresses {
+    /// Raw constants of the `control` register
+    pub mod control {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `channel` register
+    pub mod channel {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 3;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `id` register
+    pub mod id {
+        /// The address of the register
+        pub const ADDRESS: u8 = 16;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `samples` fifo
+    pub mod samples {
+        /// The address of the data register of the fifo
+        pub const ADDRESS: u8 = 18;
+        /// The size of one record in bytes
+        pub const RECORD_SIZE_BYTES: usize = 1;
+    }
+    /// Raw constants of the `reset` command
+    pub mod reset {
+        /// The address of the command
+        pub const ADDRESS: u8 = 0;
+    }
+    /// Raw constants of the `sleep` command
+    pub mod sleep {
+        /// The address of the command
+        pub const ADDRESS: u8 = 1;
+    }
+    /// Raw constants of the `tx` buffer
+    pub mod tx {
+        /// The address of the buffer
+        pub const ADDRESS: u8 = 0;
+    }
+    /// Raw constants of the `eeprom` memory
+    pub mod eeprom {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 256;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 256;
+    }
+    /// Raw constants of the `otp` memory
+    pub mod otp {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 512;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 32;
+    }
+}
+#[derive(Debug)]
+pub struct Bank<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<'i, I> Bank<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u16) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// The first object of a block starts at the block offset
+    ///
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Gain")]
+    pub fn gain(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Offset")]
+    pub fn offset(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Bank<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Bank`
+///
+/// The addresses are relative to the address of the block.
+pub mod bank {
+    /// Raw constants of the `gain` register
+    pub mod gain {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `offset` register
+    pub mod offset {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+/// The objects don't need an address
+#[derive(Debug)]
+pub struct Extended<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<'i, I> Extended<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u16) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Placed at 0x60
+    ///
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Scale")]
+    pub fn scale(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Placed at 0x61
+    ///
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Trim")]
+    pub fn trim(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Extended<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Extended`
+///
+/// The addresses are relative to the address of the block.
+pub mod extended {
+    /// Raw constants of the `scale` register
+    pub mod scale {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `trim` register
+    pub mod trim {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// Both variants of the config start at 0x84 and the extra register goes after the largest one at 0x86
+#[derive(Debug)]
+pub struct Variants<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<'i, I> Variants<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u16) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Base")]
+    pub fn base(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Word, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Word::default)
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "Config")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-b`
+    #[doc(alias = "Config")]
+    #[cfg(any(feature = "chip-b"))]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+    /// Register operation:
+    /// - Address: `6`
+    /// - Reset value: `0`
+    #[doc(alias = "Extra")]
+    pub fn extra(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 6;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Variants<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Variants`
+///
+/// The addresses are relative to the address of the block.
+pub mod variants {
+    /// Raw constants of the `base` register
+    pub mod base {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x0;
+    }
+    /// Raw constants of the `config` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `config` register
+    #[cfg(any(feature = "chip-b"))]
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `extra` register
+    pub mod extra {
+        /// The address of the register
+        pub const ADDRESS: u8 = 6;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Word {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 4],
+}
+unsafe impl ::device_driver::Fieldset for Word {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 4] };
+}
+impl Word {
+    /// The mask of the `value` field when the fieldset is read as one `u32` in LE byte order
+    pub const VALUE_MASK: u32 = 0xFFFFFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u32` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `31:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        let start = 0;
+        let end = 31;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u32,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `31:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u32) {
+        let start = 0;
+        let end = 31;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u32,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Word {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 4]> for Word {
+    fn from(bits: [u8; 4]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Word> for [u8; 4] {
+    fn from(val: Word) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Word {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Word");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Word {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Word {{ ");
+        ::defmt::write!(f, "value: {=u32}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Word {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Word {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Word {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Word {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Word {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Word {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Word {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Control {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Control {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Control {
+    /// The mask of the `enable` field when the fieldset is read as one `u16` in LE byte order
+    pub const ENABLE_MASK: u16 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u16` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_MASK: u16 = 0xE;
+    /// The shift of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `3:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> u8 {
+        let start = 1;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: u8) {
+        let start = 1;
+        let end = 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_mode(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 3;
+        let raw = value;
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Control {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Control {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Control> for [u8; 2] {
+    fn from(val: Control) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Control {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Control");
+        d.field("enable", &self.enable());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Control {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Control {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "mode: {=u8}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Control {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Control {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Control {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Control {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Control {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Control {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Control {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
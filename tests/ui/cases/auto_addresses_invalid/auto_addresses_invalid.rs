@@ -0,0 +1,590 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the NoMode driver
+#[derive(Debug)]
+pub struct NoMode<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> NoMode<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Command operation:
+    /// - Address: `0`
+    #[doc(alias = "Reset")]
+    pub fn reset(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+}
+impl<I> ::device_driver::Block for NoMode<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `NoMode`
+pub mod no_mode {
+    /// Raw constants of the `reset` command
+    pub mod reset {
+        /// The address of the command
+        pub const ADDRESS: u8 = 0;
+    }
+}
+/// Root block of the Overlapping driver
+#[derive(Debug)]
+pub struct Overlapping<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Overlapping<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Config::default)
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    #[doc(alias = "Mode")]
+    pub fn mode(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u8, Config::default)
+    }
+    /// Register operation:
+    /// - Address: `6`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 6;
+        ::device_driver::RegisterOperation::new(self, address as u8, Config::default)
+    }
+}
+impl<I> ::device_driver::Block for Overlapping<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Overlapping`
+pub mod overlapping {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x0;
+    }
+    /// Raw constants of the `mode` register
+    pub mod mode {
+        /// The address of the register
+        pub const ADDRESS: u8 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x0;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 6;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x0;
+    }
+}
+/// Root block of the NoDefault driver
+#[derive(Debug)]
+pub struct NoDefault<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> NoDefault<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+}
+impl<I> ::device_driver::Block for NoDefault<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Control {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Control {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Control {
+    /// The mask of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_MASK: u8 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Control {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Control {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Control> for [u8; 1] {
+    fn from(val: Control) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Control {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Control");
+        d.field("enable", &self.enable());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Control {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Control {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Control {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Control {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Control {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Control {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Control {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Control {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Control {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Config {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 4],
+}
+unsafe impl ::device_driver::Fieldset for Config {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 4] };
+}
+impl Config {
+    /// The mask of the `value` field when the fieldset is read as one `u32` in LE byte order
+    pub const VALUE_MASK: u32 = 0xFFFFFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u32` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `31:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        let start = 0;
+        let end = 31;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u32,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `31:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u32) {
+        let start = 0;
+        let end = 31;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u32,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Config {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 4]> for Config {
+    fn from(bits: [u8; 4]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Config> for [u8; 4] {
+    fn from(val: Config) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Config {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Config");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Config {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Config {{ ");
+        ::defmt::write!(f, "value: {=u32}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Config {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Config {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Config {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Config {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Config {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Config {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Config {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Forgotten {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Forgotten {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Forgotten {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Forgotten {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Forgotten {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Forgotten> for [u8; 1] {
+    fn from(val: Forgotten) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Forgotten {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Forgotten");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Forgotten {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Forgotten {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Forgotten {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Forgotten {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Forgotten {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Forgotten {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Forgotten {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Forgotten {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Forgotten {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -75,6 +75,21 @@ impl<I> ::device_driver::Block for Device<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Device`
+pub mod device {
+    /// Raw constants of the `foo` command
+    pub mod foo {
+        /// The address of the command
+        pub const ADDRESS: u8 = 0;
+        /// The size of the input of the command in bytes
+        pub const SIZE_BYTES_IN: usize = 3;
+    }
+    /// Raw constants of the `bar` command
+    pub mod bar {
+        /// The address of the command at index 0
+        pub const ADDRESS: u8 = 1;
+    }
+}
 #[derive(Copy, Clone, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct FooFieldSetIn {
@@ -88,6 +103,10 @@ unsafe impl ::device_driver::Fieldset for FooFieldSetIn {
     const ZERO: Self = Self { bits: [0; 3] };
 }
 impl FooFieldSetIn {
+    /// The mask of the `value` field when the fieldset is read as one `u32` in LE byte order
+    pub const VALUE_MASK: u32 = 0xFFFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u32` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
     /// `23:0` - Read the `value` field.
     ///
     #[must_use]
@@ -108,12 +127,42 @@ impl FooFieldSetIn {
         let start = 0;
         let end = 23;
         let raw = value;
+        debug_assert!(
+            (0..= 16777215).contains(& raw),
+            "value is out of the range `0..=16777215` of field `value`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u32,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `23:0` - Set the `value` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=16777215`.
+    pub fn try_set_value(
+        &mut self,
+        value: u32,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u32>> {
+        let start = 0;
+        let end = 23;
+        let raw = value;
+        if !(0..=16777215).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 16777215,
+                field: "value",
+            });
+        }
         unsafe {
             ::device_driver::ops::store::<
                 u32,
                 ::device_driver::ops::LE,
             >(raw, start, end, &mut self.bits)
         };
+        Ok(())
     }
 }
 impl Default for FooFieldSetIn {
@@ -131,64 +180,64 @@ impl From<FooFieldSetIn> for [u8; 3] {
         val.bits
     }
 }
-impl core::fmt::Debug for FooFieldSetIn {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for FooFieldSetIn {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("FooFieldSetIn");
         d.field("value", &self.value());
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for FooFieldSetIn {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "FooFieldSetIn {{ ");
-        defmt::write!(f, "value: {=u32}, ", & self.value());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for FooFieldSetIn {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FooFieldSetIn {{ ");
+        ::defmt::write!(f, "value: {=u32}, ", & self.value());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for FooFieldSetIn {
+impl ::core::ops::BitAnd for FooFieldSetIn {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for FooFieldSetIn {
+impl ::core::ops::BitAndAssign for FooFieldSetIn {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for FooFieldSetIn {
+impl ::core::ops::BitOr for FooFieldSetIn {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for FooFieldSetIn {
+impl ::core::ops::BitOrAssign for FooFieldSetIn {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for FooFieldSetIn {
+impl ::core::ops::BitXor for FooFieldSetIn {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for FooFieldSetIn {
+impl ::core::ops::BitXorAssign for FooFieldSetIn {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for FooFieldSetIn {
+impl ::core::ops::Not for FooFieldSetIn {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
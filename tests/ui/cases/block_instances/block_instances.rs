@@ -0,0 +1,459 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "Small", feature = "Large"))]
+compile_error!(
+    "the chip variants `Small` and `Large` can't be enabled at the same time"
+);
+/// Root block of the BlockInstances driver
+#[derive(Debug)]
+pub struct BlockInstances<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<I> BlockInstances<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// The first uart. All other uarts share its block
+    ///
+    /// Block operation:
+    /// - Address: `256`
+    #[doc(alias = "Uart0")]
+    pub fn uart_0(&mut self) -> Uart0<'_, I> {
+        let address = self.base_address + 256;
+        Uart0::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Block operation:
+    /// - Address: `832`
+    #[doc(alias = "Uart1")]
+    pub fn uart_1(&mut self) -> Uart0<'_, I> {
+        let address = self.base_address + 832;
+        Uart0::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Block operation:
+    /// - Address: `4096`
+    /// - Chip variants: `Large`
+    #[doc(alias = "Uart2")]
+    #[cfg(any(feature = "Large"))]
+    pub fn uart_2(&mut self) -> Uart0<'_, I> {
+        let address = self.base_address + 4096;
+        Uart0::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Block operation:
+    /// - Address: `8192`
+    /// - Index range: `0..2`
+    #[doc(alias = "Spi")]
+    pub fn spi(&mut self, index: usize) -> Uart0<'_, I> {
+        let address = {
+            assert!(index < 2);
+            self.base_address + 8192 + index as u16 * 32
+        };
+        Uart0::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+}
+impl<I> ::device_driver::Block for BlockInstances<I> {
+    type Interface = I;
+    type RegisterAddressType = u16;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// The first uart. All other uarts share its block
+#[derive(Debug)]
+pub struct Uart0<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<'i, I> Uart0<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u16) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Data")]
+    pub fn data(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Data, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u16, Data::default)
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u16,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u16, Status::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Uart0<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u16;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Uart0`
+///
+/// The addresses are relative to the address of the block.
+pub mod uart_0 {
+    /// Raw constants of the `data` register
+    pub mod data {
+        /// The address of the register
+        pub const ADDRESS: u16 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u16 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `tx_empty` field when the fieldset is read as one `u8` in LE byte order
+    pub const TX_EMPTY_MASK: u8 = 0x1;
+    /// The shift of the `tx_empty` field when the fieldset is read as one `u8` in LE byte order
+    pub const TX_EMPTY_SHIFT: u32 = 0;
+    /// The mask of the `rx_full` field when the fieldset is read as one `u8` in LE byte order
+    pub const RX_FULL_MASK: u8 = 0x2;
+    /// The shift of the `rx_full` field when the fieldset is read as one `u8` in LE byte order
+    pub const RX_FULL_SHIFT: u32 = 1;
+    /// `bit 0` - Read the `tx_empty` field.
+    ///
+    #[doc(alias = "tx-empty")]
+    #[must_use]
+    pub fn tx_empty(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 1` - Read the `rx_full` field.
+    ///
+    #[doc(alias = "rx-full")]
+    #[must_use]
+    pub fn rx_full(&self) -> bool {
+        let start = 1;
+        let end = 1;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `tx_empty` field.
+    ///
+    #[doc(alias = "tx-empty")]
+    pub fn set_tx_empty(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 1` - Set the `rx_full` field.
+    ///
+    #[doc(alias = "rx-full")]
+    pub fn set_rx_full(&mut self, value: bool) {
+        let start = 1;
+        let end = 1;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("tx_empty", &self.tx_empty());
+        d.field("rx_full", &self.rx_full());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "tx_empty: {=bool}, ", & self.tx_empty());
+        ::defmt::write!(f, "rx_full: {=bool}, ", & self.rx_full());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Data {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Data {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Data {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Data {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Data {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Data> for [u8; 1] {
+    fn from(val: Data) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Data {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Data");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Data {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Data {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Data {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Data {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Data {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Data {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Data {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Data {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Data {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,277 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Overlapping driver
+#[derive(Debug)]
+pub struct Overlapping<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Overlapping<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Block operation:
+    /// - Address: `16`
+    #[doc(alias = "Port")]
+    pub fn port(&mut self) -> Port<'_, I> {
+        let address = self.base_address + 16;
+        Port::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Overlaps with `Clash`
+    ///
+    /// Block operation:
+    /// - Address: `64`
+    #[doc(alias = "PortCopy")]
+    pub fn port_copy(&mut self) -> Port<'_, I> {
+        let address = self.base_address + 64;
+        Port::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Register operation:
+    /// - Address: `64`
+    /// - Reset value: `0`
+    #[doc(alias = "Clash")]
+    pub fn clash(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Value, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 64;
+        ::device_driver::RegisterOperation::new(self, address as u8, Value::default)
+    }
+    /// Overlaps with `Port` itself
+    ///
+    /// Block operation:
+    /// - Address: `16`
+    #[doc(alias = "SamePort")]
+    pub fn same_port(&mut self) -> Port<'_, I> {
+        let address = self.base_address + 16;
+        Port::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+}
+impl<I> ::device_driver::Block for Overlapping<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Overlapping`
+pub mod overlapping {
+    /// Raw constants of the `clash` register
+    pub mod clash {
+        /// The address of the register
+        pub const ADDRESS: u8 = 64;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Debug)]
+pub struct Port<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Port<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Value")]
+    pub fn value(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Value, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Value::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Port<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Port`
+///
+/// The addresses are relative to the address of the block.
+pub mod port {
+    /// Raw constants of the `value` register
+    pub mod value {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Value {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Value {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Value {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Value {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Value {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Value> for [u8; 1] {
+    fn from(val: Value) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Value {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Value");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Value {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Value {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Value {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Value {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Value {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Value {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Value {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Value {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Value {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
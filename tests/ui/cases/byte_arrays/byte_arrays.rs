@@ -0,0 +1,622 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the ByteArrays driver
+#[derive(Debug)]
+pub struct ByteArrays<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> ByteArrays<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `[0x44, 0x44, 0x30, 0x31, 0x00, 0x00, 0xAB, 0xCD]`
+    #[doc(alias = "Id")]
+    pub fn id(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        IdFields,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || IdFields::from([68, 68, 48, 49, 0, 0, 171, 205]),
+        )
+    }
+    /// Register operation:
+    /// - Address: `8`
+    /// - Reset value: `0`
+    #[doc(alias = "Uid")]
+    pub fn uid(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        UidFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 8;
+        ::device_driver::RegisterOperation::new(self, address as u8, UidFields::default)
+    }
+    /// Register operation:
+    /// - Address: `14`
+    /// - Reset value: `0`
+    #[doc(alias = "Names")]
+    pub fn names(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        NamesFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 14;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            NamesFields::default,
+        )
+    }
+}
+impl<I> ::device_driver::Block for ByteArrays<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `ByteArrays`
+pub mod byte_arrays {
+    /// Raw constants of the `id` register
+    pub mod id {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 8;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u64 = 0xCDAB000031304444;
+    }
+    /// Raw constants of the `uid` register
+    pub mod uid {
+        /// The address of the register
+        pub const ADDRESS: u8 = 8;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 6;
+        /// The reset value of the register read as one integer in BE byte order
+        pub const RESET: u64 = 0x0;
+    }
+    /// Raw constants of the `names` register
+    pub mod names {
+        /// The address of the register
+        pub const ADDRESS: u8 = 14;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct NamesFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for NamesFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl NamesFields {
+    /// The mask of the `bytes` field when the fieldset is read as one `u16` in LE byte order
+    pub const BYTES_MASK: u16 = 0xFF;
+    /// The shift of the `bytes` field when the fieldset is read as one `u16` in LE byte order
+    pub const BYTES_SHIFT: u32 = 0;
+    /// The mask of the `ascii` field when the fieldset is read as one `u16` in LE byte order
+    pub const ASCII_MASK: u16 = 0xFF00;
+    /// The shift of the `ascii` field when the fieldset is read as one `u16` in LE byte order
+    pub const ASCII_SHIFT: u32 = 8;
+    /// `7:0` - Read the `bytes` field.
+    ///
+    /// Byte array base types are only keywords after the arrow
+    #[must_use]
+    pub fn bytes(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:8` - Read the `ascii` field.
+    ///
+    #[must_use]
+    pub fn ascii(&self) -> [u8; 1] {
+        let start = 8;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load_bytes::<
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `7:0` - Set the `bytes` field.
+    ///
+    /// Byte array base types are only keywords after the arrow
+    pub fn set_bytes(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `15:8` - Set the `ascii` field.
+    ///
+    pub fn set_ascii(&mut self, value: [u8; 1]) {
+        let start = 8;
+        let end = 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store_bytes::<
+                ::device_driver::ops::LE,
+                1,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for NamesFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for NamesFields {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<NamesFields> for [u8; 2] {
+    fn from(val: NamesFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for NamesFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("NamesFields");
+        d.field("bytes", &self.bytes());
+        d.field("ascii", &self.ascii());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for NamesFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "NamesFields {{ ");
+        ::defmt::write!(f, "bytes: {=u8}, ", & self.bytes());
+        ::defmt::write!(f, "ascii: {}, ", & self.ascii());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for NamesFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for NamesFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for NamesFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for NamesFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for NamesFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for NamesFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for NamesFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct UidFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 6],
+}
+unsafe impl ::device_driver::Fieldset for UidFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::BE);
+    const ZERO: Self = Self { bits: [0; 6] };
+}
+impl UidFields {
+    /// `15:0` - Read the `part` field.
+    ///
+    #[must_use]
+    pub fn part(&self, index: usize) -> [u8; 2] {
+        assert!(index < 3);
+        let start = 0 + index * 16;
+        let end = start + 15;
+        let raw = unsafe {
+            ::device_driver::ops::load_bytes::<
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `15:0` - Set the `part` field.
+    ///
+    pub fn set_part(&mut self, index: usize, value: [u8; 2]) {
+        assert!(index < 3);
+        let start = 0 + index * 16;
+        let end = start + 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store_bytes::<
+                ::device_driver::ops::BE,
+                2,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for UidFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 6]> for UidFields {
+    fn from(bits: [u8; 6]) -> Self {
+        Self { bits }
+    }
+}
+impl From<UidFields> for [u8; 6] {
+    fn from(val: UidFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for UidFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("UidFields");
+        d.field("part[0]", &self.part(0));
+        d.field("part[1]", &self.part(1));
+        d.field("part[2]", &self.part(2));
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for UidFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "UidFields {{ ");
+        ::defmt::write!(f, "part: {}, [0]", & self.part(0));
+        ::defmt::write!(f, "part: {}, [1]", & self.part(1));
+        ::defmt::write!(f, "part: {}, [2]", & self.part(2));
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for UidFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for UidFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for UidFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for UidFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for UidFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for UidFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for UidFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct IdFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 8],
+}
+unsafe impl ::device_driver::Fieldset for IdFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 8] };
+}
+impl IdFields {
+    /// The mask of the `model` field when the fieldset is read as one `u64` in LE byte order
+    pub const MODEL_MASK: u64 = 0xFFFFFFFFFFFF;
+    /// The shift of the `model` field when the fieldset is read as one `u64` in LE byte order
+    pub const MODEL_SHIFT: u32 = 0;
+    /// The mask of the `serial` field when the fieldset is read as one `u64` in LE byte order
+    pub const SERIAL_MASK: u64 = 0xFFFF000000000000;
+    /// The shift of the `serial` field when the fieldset is read as one `u64` in LE byte order
+    pub const SERIAL_SHIFT: u32 = 48;
+    /// `47:0` - Read the `model` field.
+    ///
+    /// The model name
+    #[must_use]
+    pub fn model(&self) -> ::device_driver::AsciiStr<'_> {
+        let start = 0;
+        let end = 47;
+        let raw = unsafe {
+            ::device_driver::ops::load_bytes::<
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        ::device_driver::AsciiStr::new(raw)
+    }
+    /// `63:48` - Read the `serial` field.
+    ///
+    #[must_use]
+    pub fn serial(&self) -> [u8; 2] {
+        let start = 48;
+        let end = 63;
+        let raw = unsafe {
+            ::device_driver::ops::load_bytes::<
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `47:0` - Set the `model` field.
+    ///
+    /// The model name
+    ///
+    /// The bytes aren't checked to be ascii. Use [`Self::try_set_model`] to set the field from a `&str`.
+    pub fn set_model(&mut self, value: [u8; 6]) {
+        let start = 0;
+        let end = 47;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store_bytes::<
+                ::device_driver::ops::LE,
+                6,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `47:0` - Set the `model` field.
+    ///
+    /// The model name
+    ///
+    /// Text shorter than 6 bytes is padded with zeroes.
+    /// Returns an error and leaves the field unchanged if the text is longer or isn't ascii.
+    pub fn try_set_model(
+        &mut self,
+        value: &str,
+    ) -> Result<(), ::device_driver::InvalidAscii> {
+        let start = 0;
+        let end = 47;
+        if !value.is_ascii() {
+            return Err(::device_driver::InvalidAscii::NotAscii {
+                field: "model",
+            });
+        }
+        if value.len() > 6 {
+            return Err(::device_driver::InvalidAscii::TooLong {
+                len: value.len(),
+                max: 6,
+                field: "model",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store_ascii::<
+                ::device_driver::ops::LE,
+            >(value, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `63:48` - Set the `serial` field.
+    ///
+    pub fn set_serial(&mut self, value: [u8; 2]) {
+        let start = 48;
+        let end = 63;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store_bytes::<
+                ::device_driver::ops::LE,
+                2,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for IdFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 8]> for IdFields {
+    fn from(bits: [u8; 8]) -> Self {
+        Self { bits }
+    }
+}
+impl From<IdFields> for [u8; 8] {
+    fn from(val: IdFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for IdFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("IdFields");
+        d.field("model", &self.model());
+        d.field("serial", &self.serial());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for IdFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "IdFields {{ ");
+        ::defmt::write!(f, "model: {}, ", & self.model());
+        ::defmt::write!(f, "serial: {}, ", & self.serial());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for IdFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for IdFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for IdFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for IdFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for IdFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for IdFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for IdFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,615 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+#[cfg(all(feature = "chip-a", feature = "chip-c"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-c` can't be enabled at the same time"
+);
+#[cfg(all(feature = "chip-b", feature = "chip-c"))]
+compile_error!(
+    "the chip variants `chip-b` and `chip-c` can't be enabled at the same time"
+);
+/// Root block of the ChipVariants driver
+#[derive(Debug)]
+pub struct ChipVariants<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> ChipVariants<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Exists on all chips
+    ///
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Only exists on chip A
+    ///
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "Temperature")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn temperature(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "Config")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// The config register has a different layout on chip B and C
+    ///
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-b`, `chip-c`
+    #[doc(alias = "Config")]
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ConfigBc,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, ConfigBc::default)
+    }
+    /// Block operation:
+    /// - Address: `16`
+    /// - Chip variants: `chip-b`, `chip-c`
+    #[doc(alias = "Extra")]
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub fn extra(&mut self) -> Extra<'_, I> {
+        let address = self.base_address + 16;
+        Extra::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Each variant writes its own config register
+    ///
+    /// Routine:
+    /// - Write [`config`](Self::config): `ready = true`
+    /// - Chip variants: `chip-a`
+    #[cfg(any(feature = "chip-a"))]
+    pub fn configure_a(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.config()
+            .write(|fields| {
+                fields.set_ready(true);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`configure_a`](Self::configure_a).
+    ///
+    /// Each variant writes its own config register
+    ///
+    /// Routine:
+    /// - Write [`config`](Self::config): `ready = true`
+    /// - Chip variants: `chip-a`
+    #[cfg(any(feature = "chip-a"))]
+    pub async fn configure_a_async(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.config()
+            .write_async(|fields| {
+                fields.set_ready(true);
+            })
+            .await?;
+        Ok(())
+    }
+    /// Routine:
+    /// - Write [`config`](Self::config): `value = 4660`
+    /// - Chip variants: `chip-b`, `chip-c`
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub fn configure_bc(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.config()
+            .write(|fields| {
+                fields.set_value(4660);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`configure_bc`](Self::configure_bc).
+    ///
+    /// Routine:
+    /// - Write [`config`](Self::config): `value = 4660`
+    /// - Chip variants: `chip-b`, `chip-c`
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub async fn configure_bc_async(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.config()
+            .write_async(|fields| {
+                fields.set_value(4660);
+            })
+            .await?;
+        Ok(())
+    }
+}
+impl<I> ::device_driver::Block for ChipVariants<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `ChipVariants`
+pub mod chip_variants {
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `temperature` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod temperature {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `config` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `config` register
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[cfg(any(feature = "chip-b", feature = "chip-c"))]
+#[derive(Debug)]
+pub struct Extra<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+#[cfg(any(feature = "chip-b", feature = "chip-c"))]
+impl<'i, I> Extra<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-b`, `chip-c`
+    #[doc(alias = "Id")]
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub fn id(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Command operation:
+    /// - Address: `1`
+    /// - Chip variants: `chip-c`
+    #[doc(alias = "Reset")]
+    #[cfg(any(feature = "chip-c"))]
+    pub fn reset(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+}
+#[cfg(any(feature = "chip-b", feature = "chip-c"))]
+impl<'i, I> ::device_driver::Block for Extra<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Extra`
+///
+/// The addresses are relative to the address of the block.
+#[cfg(any(feature = "chip-b", feature = "chip-c"))]
+pub mod extra {
+    /// Raw constants of the `id` register
+    #[cfg(any(feature = "chip-b", feature = "chip-c"))]
+    pub mod id {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `reset` command
+    #[cfg(any(feature = "chip-c"))]
+    pub mod reset {
+        /// The address of the command
+        pub const ADDRESS: u8 = 1;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ConfigBc {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for ConfigBc {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl ConfigBc {
+    /// The mask of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_MASK: u16 = 0xFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `15:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        let start = 0;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u16) {
+        let start = 0;
+        let end = 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for ConfigBc {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for ConfigBc {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ConfigBc> for [u8; 2] {
+    fn from(val: ConfigBc) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ConfigBc {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ConfigBc");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ConfigBc {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ConfigBc {{ ");
+        ::defmt::write!(f, "value: {=u16}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ConfigBc {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ConfigBc {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ConfigBc {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ConfigBc {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ConfigBc {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ConfigBc {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ConfigBc {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,292 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+#[cfg(all(feature = "chip-a", feature = "chip-a"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-a` can't be enabled at the same time"
+);
+#[cfg(all(feature = "chip-b", feature = "chip-a"))]
+compile_error!(
+    "the chip variants `chip-b` and `chip-a` can't be enabled at the same time"
+);
+/// Root block of the ChipVariantsInvalid driver
+#[derive(Debug)]
+pub struct ChipVariantsInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> ChipVariantsInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "Overlap")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn overlap(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`, `chip-b`
+    #[doc(alias = "Overlap")]
+    #[cfg(any(feature = "chip-a", feature = "chip-b"))]
+    pub fn overlap_dup_1(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Block operation:
+    /// - Address: `16`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "Only-a")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn only_a(&mut self) -> OnlyA<'_, I> {
+        let address = self.base_address + 16;
+        OnlyA::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+}
+impl<I> ::device_driver::Block for ChipVariantsInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `ChipVariantsInvalid`
+pub mod chip_variants_invalid {
+    /// Raw constants of the `overlap` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod overlap {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `overlap_dup_1` register
+    #[cfg(any(feature = "chip-a", feature = "chip-b"))]
+    pub mod overlap_dup_1 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[doc(alias = "Only-a")]
+#[cfg(any(feature = "chip-a"))]
+#[derive(Debug)]
+pub struct OnlyA<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+#[cfg(any(feature = "chip-a"))]
+impl<'i, I> OnlyA<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+}
+#[cfg(any(feature = "chip-a"))]
+impl<'i, I> ::device_driver::Block for OnlyA<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -253,6 +253,79 @@ impl<I> ::device_driver::Block for Device<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Device`
+pub mod device {
+    /// Raw constants of the `foo_0` register
+    pub mod foo_0 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_1` register
+    pub mod foo_1 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_2` register
+    pub mod foo_2 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_3` register
+    pub mod foo_3 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 3;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_4` register
+    pub mod foo_4 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_5` register
+    pub mod foo_5 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 5;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_6` register
+    pub mod foo_6 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 6;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_7` register
+    pub mod foo_7 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 7;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_8` register
+    pub mod foo_8 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 8;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+    /// Raw constants of the `foo_9` register
+    pub mod foo_9 {
+        /// The address of the register at index 0
+        pub const ADDRESS: u32 = 9;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 0;
+    }
+}
 #[derive(Copy, Clone, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct Foo {
@@ -281,62 +354,62 @@ impl From<Foo> for [u8; 0] {
         val.bits
     }
 }
-impl core::fmt::Debug for Foo {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for Foo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("Foo");
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for Foo {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "Foo {{ ");
-        defmt::write!(f, "}}");
+impl ::defmt::Format for Foo {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Foo {{ ");
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for Foo {
+impl ::core::ops::BitAnd for Foo {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for Foo {
+impl ::core::ops::BitAndAssign for Foo {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for Foo {
+impl ::core::ops::BitOr for Foo {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for Foo {
+impl ::core::ops::BitOrAssign for Foo {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for Foo {
+impl ::core::ops::BitXor for Foo {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for Foo {
+impl ::core::ops::BitXorAssign for Foo {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for Foo {
+impl ::core::ops::Not for Foo {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -0,0 +1,1729 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+/// Root block of the Composites driver
+#[derive(Debug)]
+pub struct Composites<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Composites<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "TimerLow")]
+    pub fn timer_low(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Byte, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Byte::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "TimerMid")]
+    pub fn timer_mid(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Byte, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Byte::default)
+    }
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    #[doc(alias = "TimerHigh")]
+    pub fn timer_high(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Byte, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, Byte::default)
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    #[doc(alias = "CaptureLow")]
+    pub fn capture_low(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        CaptureLow,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u8, CaptureLow::default)
+    }
+    /// Register operation:
+    /// - Address: `6`
+    /// - Reset value: `0`
+    #[doc(alias = "CaptureHigh")]
+    pub fn capture_high(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        CaptureHigh,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 6;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            CaptureHigh::default,
+        )
+    }
+    /// Register operation:
+    /// - Address: `8`
+    /// - Reset value: `0`
+    #[doc(alias = "StatusA")]
+    pub fn status_a(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        StatusA,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 8;
+        ::device_driver::RegisterOperation::new(self, address as u8, StatusA::default)
+    }
+    /// Register operation:
+    /// - Address: `10`
+    /// - Reset value: `0`
+    #[doc(alias = "StatusB")]
+    pub fn status_b(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        StatusB,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 10;
+        ::device_driver::RegisterOperation::new(self, address as u8, StatusB::default)
+    }
+    /// Register operation:
+    /// - Address: `12`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "ThresholdLow")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn threshold_low(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Byte, u8, ::device_driver::WO, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 12;
+        ::device_driver::RegisterOperation::new(self, address as u8, Byte::default)
+    }
+    /// Register operation:
+    /// - Address: `13`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "ThresholdHigh")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn threshold_high(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Byte, u8, ::device_driver::WO, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 13;
+        ::device_driver::RegisterOperation::new(self, address as u8, Byte::default)
+    }
+    /// The free running timer
+    ///
+    /// Composite value, least significant part first:
+    /// - `7:0`: `count` of [`timer_low`](Self::timer_low)
+    /// - `15:8`: `count` of [`timer_mid`](Self::timer_mid)
+    /// - `23:16`: `count` of [`timer_high`](Self::timer_high)
+    /// - Read in one bulk transaction: [`timer_low`](Self::timer_low), [`timer_mid`](Self::timer_mid), [`timer_high`](Self::timer_high)
+    pub fn read_timer(
+        &mut self,
+    ) -> Result<u32, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let (timer_low, timer_mid, timer_high) = ::device_driver::Block::bulk_read(self)
+            .with(|d| d.timer_low().plan())
+            .with(|d| d.timer_mid().plan())
+            .with(|d| d.timer_high().plan())
+            .execute()?;
+        Ok(
+            (timer_low.count() as u32) | (timer_mid.count() as u32) << 8
+                | (timer_high.count() as u32) << 16,
+        )
+    }
+    /// Async version of [`read_timer`](Self::read_timer).
+    ///
+    /// The free running timer
+    ///
+    /// Composite value, least significant part first:
+    /// - `7:0`: `count` of [`timer_low`](Self::timer_low)
+    /// - `15:8`: `count` of [`timer_mid`](Self::timer_mid)
+    /// - `23:16`: `count` of [`timer_high`](Self::timer_high)
+    /// - Read in one bulk transaction: [`timer_low`](Self::timer_low), [`timer_mid`](Self::timer_mid), [`timer_high`](Self::timer_high)
+    pub async fn read_timer_async(
+        &mut self,
+    ) -> Result<u32, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let (timer_low, timer_mid, timer_high) = ::device_driver::Block::bulk_read(self)
+            .with(|d| d.timer_low().plan())
+            .with(|d| d.timer_mid().plan())
+            .with(|d| d.timer_high().plan())
+            .execute_async()
+            .await?;
+        Ok(
+            (timer_low.count() as u32) | (timer_mid.count() as u32) << 8
+                | (timer_high.count() as u32) << 16,
+        )
+    }
+    /// The free running timer
+    ///
+    /// Composite value, least significant part first:
+    /// - `7:0`: `count` of [`timer_low`](Self::timer_low)
+    /// - `15:8`: `count` of [`timer_mid`](Self::timer_mid)
+    /// - `23:16`: `count` of [`timer_high`](Self::timer_high)
+    /// - Written in one bulk transaction: [`timer_low`](Self::timer_low), [`timer_mid`](Self::timer_mid), [`timer_high`](Self::timer_high)
+    pub fn write_timer(
+        &mut self,
+        timer: u32,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        ::device_driver::Block::bulk_write(self)
+            .with(|d| d.timer_low().plan())
+            .with(|d| d.timer_mid().plan())
+            .with(|d| d.timer_high().plan())
+            .execute(|(timer_low, timer_mid, timer_high)| {
+                timer_low.set_count(timer as u8);
+                timer_mid.set_count((timer >> 8) as u8);
+                timer_high.set_count((timer >> 16) as u8);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`write_timer`](Self::write_timer).
+    ///
+    /// The free running timer
+    ///
+    /// Composite value, least significant part first:
+    /// - `7:0`: `count` of [`timer_low`](Self::timer_low)
+    /// - `15:8`: `count` of [`timer_mid`](Self::timer_mid)
+    /// - `23:16`: `count` of [`timer_high`](Self::timer_high)
+    /// - Written in one bulk transaction: [`timer_low`](Self::timer_low), [`timer_mid`](Self::timer_mid), [`timer_high`](Self::timer_high)
+    pub async fn write_timer_async(
+        &mut self,
+        timer: u32,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        ::device_driver::Block::bulk_write(self)
+            .with(|d| d.timer_low().plan())
+            .with(|d| d.timer_mid().plan())
+            .with(|d| d.timer_high().plan())
+            .execute_async(|(timer_low, timer_mid, timer_high)| {
+                timer_low.set_count(timer as u8);
+                timer_mid.set_count((timer >> 8) as u8);
+                timer_high.set_count((timer >> 16) as u8);
+            })
+            .await?;
+        Ok(())
+    }
+    /// Reading the high register latches the low register
+    ///
+    /// Composite value, least significant part first:
+    /// - `11:0`: `value` of [`capture_low`](Self::capture_low)
+    /// - `15:12`: `value` of [`capture_high`](Self::capture_high)
+    /// - Read in this order: [`capture_high`](Self::capture_high), [`capture_low`](Self::capture_low)
+    pub fn read_capture(
+        &mut self,
+    ) -> Result<u16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let capture_high = self.capture_high().read()?;
+        let capture_low = self.capture_low().read()?;
+        Ok(capture_low.value() | (capture_high.value() as u16) << 12)
+    }
+    /// Async version of [`read_capture`](Self::read_capture).
+    ///
+    /// Reading the high register latches the low register
+    ///
+    /// Composite value, least significant part first:
+    /// - `11:0`: `value` of [`capture_low`](Self::capture_low)
+    /// - `15:12`: `value` of [`capture_high`](Self::capture_high)
+    /// - Read in this order: [`capture_high`](Self::capture_high), [`capture_low`](Self::capture_low)
+    pub async fn read_capture_async(
+        &mut self,
+    ) -> Result<u16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let capture_high = self.capture_high().read_async().await?;
+        let capture_low = self.capture_low().read_async().await?;
+        Ok(capture_low.value() | (capture_high.value() as u16) << 12)
+    }
+    /// A signed temperature split over the status registers
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `temperature` of [`status_a`](Self::status_a)
+    /// - `11:7`: `temperature` of [`status_b`](Self::status_b)
+    /// - Read in this order: [`status_a`](Self::status_a), [`status_b`](Self::status_b)
+    pub fn read_temperature(
+        &mut self,
+    ) -> Result<i16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let status_a = self.status_a().read()?;
+        let status_b = self.status_b().read()?;
+        Ok(
+            (((status_a.temperature() as i16) | (status_b.temperature() as i16) << 7)
+                << 4) >> 4,
+        )
+    }
+    /// Async version of [`read_temperature`](Self::read_temperature).
+    ///
+    /// A signed temperature split over the status registers
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `temperature` of [`status_a`](Self::status_a)
+    /// - `11:7`: `temperature` of [`status_b`](Self::status_b)
+    /// - Read in this order: [`status_a`](Self::status_a), [`status_b`](Self::status_b)
+    pub async fn read_temperature_async(
+        &mut self,
+    ) -> Result<i16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let status_a = self.status_a().read_async().await?;
+        let status_b = self.status_b().read_async().await?;
+        Ok(
+            (((status_a.temperature() as i16) | (status_b.temperature() as i16) << 7)
+                << 4) >> 4,
+        )
+    }
+    /// A signed temperature split over the status registers
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `temperature` of [`status_a`](Self::status_a)
+    /// - `11:7`: `temperature` of [`status_b`](Self::status_b)
+    /// - Written in this order: [`status_a`](Self::status_a), [`status_b`](Self::status_b)
+    /// - Read first to keep their other fields: [`status_a`](Self::status_a)
+    pub fn write_temperature(
+        &mut self,
+        temperature: i16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.status_a()
+            .modify(|status_a| {
+                status_a.set_temperature((temperature as u8) & 0x7f);
+            })?;
+        self.status_b()
+            .write(|status_b| {
+                status_b.set_temperature(((temperature >> 7) as u8) & 0x1f);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`write_temperature`](Self::write_temperature).
+    ///
+    /// A signed temperature split over the status registers
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `temperature` of [`status_a`](Self::status_a)
+    /// - `11:7`: `temperature` of [`status_b`](Self::status_b)
+    /// - Written in this order: [`status_a`](Self::status_a), [`status_b`](Self::status_b)
+    /// - Read first to keep their other fields: [`status_a`](Self::status_a)
+    pub async fn write_temperature_async(
+        &mut self,
+        temperature: i16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.status_a()
+            .modify_async(|status_a| {
+                status_a.set_temperature((temperature as u8) & 0x7f);
+            })
+            .await?;
+        self.status_b()
+            .write_async(|status_b| {
+                status_b.set_temperature(((temperature >> 7) as u8) & 0x1f);
+            })
+            .await?;
+        Ok(())
+    }
+    /// Composite value, least significant part first:
+    /// - `7:0`: `count` of [`threshold_low`](Self::threshold_low)
+    /// - `15:8`: `count` of [`threshold_high`](Self::threshold_high)
+    /// - Written in one bulk transaction: [`threshold_low`](Self::threshold_low), [`threshold_high`](Self::threshold_high)
+    /// - Chip variants: `chip-a`
+    #[cfg(any(feature = "chip-a"))]
+    pub fn write_threshold(
+        &mut self,
+        threshold: u16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        ::device_driver::Block::bulk_write(self)
+            .with(|d| d.threshold_low().plan())
+            .with(|d| d.threshold_high().plan())
+            .execute(|(threshold_low, threshold_high)| {
+                threshold_low.set_count(threshold as u8);
+                threshold_high.set_count((threshold >> 8) as u8);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`write_threshold`](Self::write_threshold).
+    ///
+    /// Composite value, least significant part first:
+    /// - `7:0`: `count` of [`threshold_low`](Self::threshold_low)
+    /// - `15:8`: `count` of [`threshold_high`](Self::threshold_high)
+    /// - Written in one bulk transaction: [`threshold_low`](Self::threshold_low), [`threshold_high`](Self::threshold_high)
+    /// - Chip variants: `chip-a`
+    #[cfg(any(feature = "chip-a"))]
+    pub async fn write_threshold_async(
+        &mut self,
+        threshold: u16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        ::device_driver::Block::bulk_write(self)
+            .with(|d| d.threshold_low().plan())
+            .with(|d| d.threshold_high().plan())
+            .execute_async(|(threshold_low, threshold_high)| {
+                threshold_low.set_count(threshold as u8);
+                threshold_high.set_count((threshold >> 8) as u8);
+            })
+            .await?;
+        Ok(())
+    }
+}
+impl<I> ::device_driver::Block for Composites<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ::device_driver::MappedAddressMode;
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Composites`
+pub mod composites {
+    /// Raw constants of the `timer_low` register
+    pub mod timer_low {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `timer_mid` register
+    pub mod timer_mid {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `timer_high` register
+    pub mod timer_high {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `capture_low` register
+    pub mod capture_low {
+        /// The address of the register
+        pub const ADDRESS: u8 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `capture_high` register
+    pub mod capture_high {
+        /// The address of the register
+        pub const ADDRESS: u8 = 6;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `status_a` register
+    pub mod status_a {
+        /// The address of the register
+        pub const ADDRESS: u8 = 8;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `status_b` register
+    pub mod status_b {
+        /// The address of the register
+        pub const ADDRESS: u8 = 10;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `threshold_low` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod threshold_low {
+        /// The address of the register
+        pub const ADDRESS: u8 = 12;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `threshold_high` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod threshold_high {
+        /// The address of the register
+        pub const ADDRESS: u8 = 13;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// The states of [`Power`]
+pub mod power {
+    #[derive(Debug)]
+    pub struct Off;
+    #[derive(Debug)]
+    pub struct On;
+    /// Implemented by the states [`timer`](super::Composites::timer) may be used in
+    pub trait AllowsTimer {}
+    impl AllowsTimer for On {}
+}
+/// Typestate wrapper around [`Composites`] in one of the states of [`power`].
+/// Operations that are only allowed in some states can't be called in the other states.
+#[derive(Debug)]
+pub struct Power<I, S> {
+    device: Composites<I>,
+    state: ::core::marker::PhantomData<S>,
+}
+impl<I> Power<I, power::Off> {
+    /// Wrap the device, which must be in the initial [`Off`](power::Off) state
+    pub const fn new(device: Composites<I>) -> Self {
+        Self {
+            device,
+            state: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<I, S> Power<I, S> {
+    /// Drop the wrapper and reclaim the device
+    pub fn free(self) -> Composites<I> {
+        self.device
+    }
+    /// See [`Composites::timer_low`].
+    pub fn timer_low(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        Byte,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.timer_low()
+    }
+    /// See [`Composites::timer_mid`].
+    pub fn timer_mid(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        Byte,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.timer_mid()
+    }
+    /// See [`Composites::timer_high`].
+    pub fn timer_high(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        Byte,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.timer_high()
+    }
+    /// See [`Composites::capture_low`].
+    pub fn capture_low(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        CaptureLow,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.capture_low()
+    }
+    /// See [`Composites::capture_high`].
+    pub fn capture_high(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        CaptureHigh,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.capture_high()
+    }
+    /// See [`Composites::status_a`].
+    pub fn status_a(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        StatusA,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.status_a()
+    }
+    /// See [`Composites::status_b`].
+    pub fn status_b(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        StatusB,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.status_b()
+    }
+    /// See [`Composites::threshold_low`].
+    #[cfg(any(feature = "chip-a"))]
+    pub fn threshold_low(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        Byte,
+        u8,
+        ::device_driver::WO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.threshold_low()
+    }
+    /// See [`Composites::threshold_high`].
+    #[cfg(any(feature = "chip-a"))]
+    pub fn threshold_high(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Composites<I>,
+        Byte,
+        u8,
+        ::device_driver::WO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.threshold_high()
+    }
+    /// See [`Composites::read_capture`].
+    pub fn read_capture(
+        &mut self,
+    ) -> Result<u16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.read_capture()
+    }
+    /// See [`Composites::read_capture_async`].
+    pub async fn read_capture_async(
+        &mut self,
+    ) -> Result<u16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.read_capture_async().await
+    }
+    /// See [`Composites::read_temperature`].
+    pub fn read_temperature(
+        &mut self,
+    ) -> Result<i16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.read_temperature()
+    }
+    /// See [`Composites::write_temperature`].
+    pub fn write_temperature(
+        &mut self,
+        temperature: i16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.write_temperature(temperature)
+    }
+    /// See [`Composites::read_temperature_async`].
+    pub async fn read_temperature_async(
+        &mut self,
+    ) -> Result<i16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.read_temperature_async().await
+    }
+    /// See [`Composites::write_temperature_async`].
+    pub async fn write_temperature_async(
+        &mut self,
+        temperature: i16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.write_temperature_async(temperature).await
+    }
+    /// See [`Composites::write_threshold`].
+    #[cfg(any(feature = "chip-a"))]
+    pub fn write_threshold(
+        &mut self,
+        threshold: u16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.write_threshold(threshold)
+    }
+    /// See [`Composites::write_threshold_async`].
+    #[cfg(any(feature = "chip-a"))]
+    pub async fn write_threshold_async(
+        &mut self,
+        threshold: u16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.write_threshold_async(threshold).await
+    }
+}
+impl<I, S: power::AllowsTimer> Power<I, S> {
+    /// See [`Composites::read_timer`].
+    pub fn read_timer(
+        &mut self,
+    ) -> Result<u32, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.read_timer()
+    }
+    /// See [`Composites::write_timer`].
+    pub fn write_timer(
+        &mut self,
+        timer: u32,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.write_timer(timer)
+    }
+    /// See [`Composites::read_timer_async`].
+    pub async fn read_timer_async(
+        &mut self,
+    ) -> Result<u32, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.read_timer_async().await
+    }
+    /// See [`Composites::write_timer_async`].
+    pub async fn write_timer_async(
+        &mut self,
+        timer: u32,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.write_timer_async(timer).await
+    }
+}
+impl<I> Power<I, power::Off> {
+    /// Transition to [`On`](power::On).
+    ///
+    /// Routine:
+    /// - Write [`timer_high`](Composites::timer_high): `count = 0`
+    pub fn enable(
+        mut self,
+    ) -> Result<
+        Power<I, power::On>,
+        ::device_driver::TransitionError<
+            Composites<I>,
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let result = (|| -> Result<
+            (),
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        > {
+            self.device
+                .timer_high()
+                .write(|fields| {
+                    fields.set_count(0);
+                })?;
+            Ok(())
+        })();
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Async version of [`enable`](Self::enable).
+    ///
+    /// Transition to [`On`](power::On).
+    ///
+    /// Routine:
+    /// - Write [`timer_high`](Composites::timer_high): `count = 0`
+    pub async fn enable_async(
+        mut self,
+    ) -> Result<
+        Power<I, power::On>,
+        ::device_driver::TransitionError<
+            Composites<I>,
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let result: Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error> = async {
+            self.device
+                .timer_high()
+                .write_async(|fields| {
+                    fields.set_count(0);
+                })
+                .await?;
+            Ok(())
+        }
+            .await;
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct StatusB {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for StatusB {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl StatusB {
+    /// The mask of the `temperature` field when the fieldset is read as one `u8` in LE byte order
+    pub const TEMPERATURE_MASK: u8 = 0x1F;
+    /// The shift of the `temperature` field when the fieldset is read as one `u8` in LE byte order
+    pub const TEMPERATURE_SHIFT: u32 = 0;
+    /// `4:0` - Read the `temperature` field.
+    ///
+    #[must_use]
+    pub fn temperature(&self) -> u8 {
+        let start = 0;
+        let end = 4;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `4:0` - Set the `temperature` field.
+    ///
+    pub fn set_temperature(&mut self, value: u8) {
+        let start = 0;
+        let end = 4;
+        let raw = value;
+        debug_assert!(
+            (0..= 31).contains(& raw),
+            "value is out of the range `0..=31` of field `temperature`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `4:0` - Set the `temperature` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=31`.
+    pub fn try_set_temperature(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 4;
+        let raw = value;
+        if !(0..=31).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 31,
+                field: "temperature",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for StatusB {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for StatusB {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<StatusB> for [u8; 1] {
+    fn from(val: StatusB) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for StatusB {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("StatusB");
+        d.field("temperature", &self.temperature());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for StatusB {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "StatusB {{ ");
+        ::defmt::write!(f, "temperature: {=u8}, ", & self.temperature());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for StatusB {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for StatusB {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for StatusB {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for StatusB {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for StatusB {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for StatusB {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for StatusB {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct StatusA {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for StatusA {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl StatusA {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// The mask of the `temperature` field when the fieldset is read as one `u8` in LE byte order
+    pub const TEMPERATURE_MASK: u8 = 0xFE;
+    /// The shift of the `temperature` field when the fieldset is read as one `u8` in LE byte order
+    pub const TEMPERATURE_SHIFT: u32 = 1;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `7:1` - Read the `temperature` field.
+    ///
+    #[must_use]
+    pub fn temperature(&self) -> u8 {
+        let start = 1;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:1` - Set the `temperature` field.
+    ///
+    pub fn set_temperature(&mut self, value: u8) {
+        let start = 1;
+        let end = 7;
+        let raw = value;
+        debug_assert!(
+            (0..= 127).contains(& raw),
+            "value is out of the range `0..=127` of field `temperature`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:1` - Set the `temperature` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=127`.
+    pub fn try_set_temperature(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 7;
+        let raw = value;
+        if !(0..=127).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 127,
+                field: "temperature",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for StatusA {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for StatusA {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<StatusA> for [u8; 1] {
+    fn from(val: StatusA) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for StatusA {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("StatusA");
+        d.field("ready", &self.ready());
+        d.field("temperature", &self.temperature());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for StatusA {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "StatusA {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "temperature: {=u8}, ", & self.temperature());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for StatusA {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for StatusA {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for StatusA {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for StatusA {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for StatusA {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for StatusA {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for StatusA {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct CaptureHigh {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for CaptureHigh {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl CaptureHigh {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `3:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `3:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `value`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:0` - Set the `value` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_value(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 3;
+        let raw = value;
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "value",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for CaptureHigh {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for CaptureHigh {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<CaptureHigh> for [u8; 1] {
+    fn from(val: CaptureHigh) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for CaptureHigh {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("CaptureHigh");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for CaptureHigh {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "CaptureHigh {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for CaptureHigh {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for CaptureHigh {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for CaptureHigh {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for CaptureHigh {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for CaptureHigh {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for CaptureHigh {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for CaptureHigh {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct CaptureLow {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for CaptureLow {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl CaptureLow {
+    /// The mask of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_MASK: u16 = 0xFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `11:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        let start = 0;
+        let end = 11;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `11:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u16) {
+        let start = 0;
+        let end = 11;
+        let raw = value;
+        debug_assert!(
+            (0..= 4095).contains(& raw),
+            "value is out of the range `0..=4095` of field `value`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `11:0` - Set the `value` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=4095`.
+    pub fn try_set_value(
+        &mut self,
+        value: u16,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u16>> {
+        let start = 0;
+        let end = 11;
+        let raw = value;
+        if !(0..=4095).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 4095,
+                field: "value",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for CaptureLow {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for CaptureLow {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<CaptureLow> for [u8; 2] {
+    fn from(val: CaptureLow) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for CaptureLow {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("CaptureLow");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for CaptureLow {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "CaptureLow {{ ");
+        ::defmt::write!(f, "value: {=u16}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for CaptureLow {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for CaptureLow {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for CaptureLow {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for CaptureLow {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for CaptureLow {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for CaptureLow {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for CaptureLow {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Byte {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Byte {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Byte {
+    /// The mask of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_MASK: u8 = 0xFF;
+    /// The shift of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// `7:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `count` field.
+    ///
+    pub fn set_count(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Byte {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Byte {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Byte> for [u8; 1] {
+    fn from(val: Byte) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Byte {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Byte");
+        d.field("count", &self.count());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Byte {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Byte {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Byte {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Byte {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Byte {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Byte {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Byte {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Byte {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Byte {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,1818 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+/// Root block of the CompositesInvalid driver
+#[derive(Debug)]
+pub struct CompositesInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> CompositesInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `13`
+    /// - Reset value: `0`
+    #[doc(alias = "ReadTimer")]
+    pub fn read_timer_dup_1(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ReadTimer,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 13;
+        ::device_driver::RegisterOperation::new(self, address as u8, ReadTimer::default)
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Data")]
+    pub fn data(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Data, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Data::default)
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    #[doc(alias = "Other")]
+    pub fn other(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Other, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u8, Other::default)
+    }
+    /// Register operation:
+    /// - Address: `5`
+    /// - Reset value: `0`
+    #[doc(alias = "ReadOnly")]
+    pub fn read_only(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ReadOnly,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 5;
+        ::device_driver::RegisterOperation::new(self, address as u8, ReadOnly::default)
+    }
+    /// Register operation:
+    /// - Address: `6`
+    /// - Reset value: `0`
+    #[doc(alias = "WriteOnly")]
+    pub fn write_only(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        WriteOnly,
+        u8,
+        ::device_driver::WO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 6;
+        ::device_driver::RegisterOperation::new(self, address as u8, WriteOnly::default)
+    }
+    /// Register operation:
+    /// - Address: `8`
+    /// - Reset value: `0`
+    /// - Index range: `0..4`
+    #[doc(alias = "Channels")]
+    pub fn channels(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Channels,
+        u8,
+        ::device_driver::RW,
+        ::device_driver::ArrayRepeat<4, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 8;
+        ::device_driver::RegisterOperation::new(self, address as u8, Channels::default)
+    }
+    /// Register operation:
+    /// - Address: `12`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "OnlyA")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn only_a(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, OnlyA, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 12;
+        ::device_driver::RegisterOperation::new(self, address as u8, OnlyA::default)
+    }
+    /// Command operation:
+    /// - Address: `0`
+    #[doc(alias = "Sync")]
+    pub fn sync(&mut self) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Block operation:
+    /// - Address: `16`
+    #[doc(alias = "Inner")]
+    pub fn inner(&mut self) -> Inner<'_, I> {
+        let address = self.base_address + 16;
+        Inner::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Routine:
+    /// - Write [`other`](Self::other): `value = 1`
+    pub fn write_timer_dup_2(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.other()
+            .write(|fields| {
+                fields.set_value(1);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`write_timer_dup_2`](Self::write_timer_dup_2).
+    ///
+    /// Routine:
+    /// - Write [`other`](Self::other): `value = 1`
+    pub async fn write_timer_dup_2_async(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.other()
+            .write_async(|fields| {
+                fields.set_value(1);
+            })
+            .await?;
+        Ok(())
+    }
+    /// Its methods collide with `ReadTimer` and `write_timer`
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `low` of [`data`](Self::data)
+    /// - `14:7`: `value` of [`other`](Self::other)
+    /// - Read in this order: [`data`](Self::data), [`other`](Self::other)
+    pub fn read_timer(
+        &mut self,
+    ) -> Result<u16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let data = self.data().read()?;
+        let other = self.other().read()?;
+        Ok((data.low() as u16) | (other.value() as u16) << 7)
+    }
+    /// Async version of [`read_timer`](Self::read_timer).
+    ///
+    /// Its methods collide with `ReadTimer` and `write_timer`
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `low` of [`data`](Self::data)
+    /// - `14:7`: `value` of [`other`](Self::other)
+    /// - Read in this order: [`data`](Self::data), [`other`](Self::other)
+    pub async fn read_timer_async(
+        &mut self,
+    ) -> Result<u16, <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let data = self.data().read_async().await?;
+        let other = self.other().read_async().await?;
+        Ok((data.low() as u16) | (other.value() as u16) << 7)
+    }
+    /// Its methods collide with `ReadTimer` and `write_timer`
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `low` of [`data`](Self::data)
+    /// - `14:7`: `value` of [`other`](Self::other)
+    /// - Written in this order: [`data`](Self::data), [`other`](Self::other)
+    /// - Read first to keep their other fields: [`data`](Self::data)
+    pub fn write_timer(
+        &mut self,
+        timer: u16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.data()
+            .modify(|data| {
+                data.set_low((timer as u8) & 0x7f);
+            })?;
+        self.other()
+            .write(|other| {
+                other.set_value((timer >> 7) as u8);
+            })?;
+        Ok(())
+    }
+    /// Async version of [`write_timer`](Self::write_timer).
+    ///
+    /// Its methods collide with `ReadTimer` and `write_timer`
+    ///
+    /// Composite value, least significant part first:
+    /// - `6:0`: `low` of [`data`](Self::data)
+    /// - `14:7`: `value` of [`other`](Self::other)
+    /// - Written in this order: [`data`](Self::data), [`other`](Self::other)
+    /// - Read first to keep their other fields: [`data`](Self::data)
+    pub async fn write_timer_async(
+        &mut self,
+        timer: u16,
+    ) -> Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.data()
+            .modify_async(|data| {
+                data.set_low((timer as u8) & 0x7f);
+            })
+            .await?;
+        self.other()
+            .write_async(|other| {
+                other.set_value((timer >> 7) as u8);
+            })
+            .await?;
+        Ok(())
+    }
+}
+impl<I> ::device_driver::Block for CompositesInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `CompositesInvalid`
+pub mod composites_invalid {
+    /// Raw constants of the `read_timer_dup_1` register
+    pub mod read_timer_dup_1 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 13;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `data` register
+    pub mod data {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x0;
+    }
+    /// Raw constants of the `other` register
+    pub mod other {
+        /// The address of the register
+        pub const ADDRESS: u8 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `read_only` register
+    pub mod read_only {
+        /// The address of the register
+        pub const ADDRESS: u8 = 5;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `write_only` register
+    pub mod write_only {
+        /// The address of the register
+        pub const ADDRESS: u8 = 6;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `channels` register
+    pub mod channels {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 8;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `only_a` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod only_a {
+        /// The address of the register
+        pub const ADDRESS: u8 = 12;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `sync` command
+    pub mod sync {
+        /// The address of the command
+        pub const ADDRESS: u8 = 0;
+    }
+}
+#[derive(Debug)]
+pub struct Inner<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Inner<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Nested")]
+    pub fn nested(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Nested,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Nested::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Inner<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Inner`
+///
+/// The addresses are relative to the address of the block.
+pub mod inner {
+    /// Raw constants of the `nested` register
+    pub mod nested {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Nested {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Nested {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Nested {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Nested {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Nested {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Nested> for [u8; 1] {
+    fn from(val: Nested) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Nested {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Nested");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Nested {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Nested {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Nested {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Nested {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Nested {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Nested {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Nested {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Nested {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Nested {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct OnlyA {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for OnlyA {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl OnlyA {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for OnlyA {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for OnlyA {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<OnlyA> for [u8; 1] {
+    fn from(val: OnlyA) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for OnlyA {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("OnlyA");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for OnlyA {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "OnlyA {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for OnlyA {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for OnlyA {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for OnlyA {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for OnlyA {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for OnlyA {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for OnlyA {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for OnlyA {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Channels {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Channels {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Channels {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Channels {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Channels {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Channels> for [u8; 1] {
+    fn from(val: Channels) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Channels {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Channels");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Channels {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Channels {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Channels {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Channels {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Channels {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Channels {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Channels {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Channels {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Channels {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WriteOnly {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for WriteOnly {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl WriteOnly {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for WriteOnly {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for WriteOnly {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<WriteOnly> for [u8; 1] {
+    fn from(val: WriteOnly) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for WriteOnly {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("WriteOnly");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for WriteOnly {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "WriteOnly {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for WriteOnly {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for WriteOnly {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for WriteOnly {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for WriteOnly {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for WriteOnly {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for WriteOnly {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for WriteOnly {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ReadOnly {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for ReadOnly {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl ReadOnly {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for ReadOnly {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for ReadOnly {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ReadOnly> for [u8; 1] {
+    fn from(val: ReadOnly) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ReadOnly {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ReadOnly");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ReadOnly {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ReadOnly {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ReadOnly {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ReadOnly {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ReadOnly {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ReadOnly {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ReadOnly {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ReadOnly {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ReadOnly {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Other {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Other {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Other {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Other {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Other {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Other> for [u8; 1] {
+    fn from(val: Other) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Other {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Other");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Other {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Other {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Other {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Other {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Other {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Other {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Other {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Other {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Other {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Data {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 4],
+}
+unsafe impl ::device_driver::Fieldset for Data {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 4] };
+    fn apply_reserved(&mut self) {
+        {
+            let start = 22;
+            let end = 23;
+            let raw: u8 = 0;
+            unsafe {
+                ::device_driver::ops::store::<
+                    u8,
+                    ::device_driver::ops::LE,
+                >(raw, start, end, &mut self.bits)
+            };
+        }
+    }
+}
+impl Data {
+    /// The mask of the `flag` field when the fieldset is read as one `u32` in LE byte order
+    pub const FLAG_MASK: u32 = 0x1;
+    /// The shift of the `flag` field when the fieldset is read as one `u32` in LE byte order
+    pub const FLAG_SHIFT: u32 = 0;
+    /// The mask of the `low` field when the fieldset is read as one `u32` in LE byte order
+    pub const LOW_MASK: u32 = 0xFE;
+    /// The shift of the `low` field when the fieldset is read as one `u32` in LE byte order
+    pub const LOW_SHIFT: u32 = 1;
+    /// The mask of the `high` field when the fieldset is read as one `u32` in LE byte order
+    pub const HIGH_MASK: u32 = 0xFF00;
+    /// The shift of the `high` field when the fieldset is read as one `u32` in LE byte order
+    pub const HIGH_SHIFT: u32 = 8;
+    /// The mask of the `signed` field when the fieldset is read as one `u32` in LE byte order
+    pub const SIGNED_MASK: u32 = 0xF0000;
+    /// The shift of the `signed` field when the fieldset is read as one `u32` in LE byte order
+    pub const SIGNED_SHIFT: u32 = 16;
+    /// The mask of the `mode` field when the fieldset is read as one `u32` in LE byte order
+    pub const MODE_MASK: u32 = 0x300000;
+    /// The shift of the `mode` field when the fieldset is read as one `u32` in LE byte order
+    pub const MODE_SHIFT: u32 = 20;
+    /// The mask of the `fixed` field when the fieldset is read as one `u32` in LE byte order
+    pub const FIXED_MASK: u32 = 0xC00000;
+    /// The shift of the `fixed` field when the fieldset is read as one `u32` in LE byte order
+    pub const FIXED_SHIFT: u32 = 22;
+    /// `bit 0` - Read the `flag` field.
+    ///
+    #[must_use]
+    pub fn flag(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `7:1` - Read the `low` field.
+    ///
+    #[must_use]
+    pub fn low(&self) -> u8 {
+        let start = 1;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:8` - Read the `high` field.
+    ///
+    #[must_use]
+    pub fn high(&self) -> u8 {
+        let start = 8;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `19:16` - Read the `signed` field.
+    ///
+    #[must_use]
+    pub fn signed(&self) -> i8 {
+        let start = 16;
+        let end = 19;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `21:20` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let start = 20;
+        let end = 21;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `23:22` - Read the `fixed` field.
+    ///
+    /// Reserved, always written as `0`.
+    #[must_use]
+    pub fn fixed(&self) -> u8 {
+        let start = 22;
+        let end = 23;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `25:24` - Read the `repeated` field.
+    ///
+    #[must_use]
+    pub fn repeated(&self, index: usize) -> u8 {
+        assert!(index < 2);
+        let start = 24 + index * 2;
+        let end = start + 1;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `flag` field.
+    ///
+    pub fn set_flag(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:1` - Set the `low` field.
+    ///
+    pub fn set_low(&mut self, value: u8) {
+        let start = 1;
+        let end = 7;
+        let raw = value;
+        debug_assert!(
+            (0..= 127).contains(& raw),
+            "value is out of the range `0..=127` of field `low`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:1` - Set the `low` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=127`.
+    pub fn try_set_low(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 7;
+        let raw = value;
+        if !(0..=127).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 127,
+                field: "low",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `15:8` - Set the `high` field.
+    ///
+    pub fn set_high(&mut self, value: u8) {
+        let start = 8;
+        let end = 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `19:16` - Set the `signed` field.
+    ///
+    pub fn set_signed(&mut self, value: i8) {
+        let start = 16;
+        let end = 19;
+        let raw = value;
+        debug_assert!(
+            (- 8..= 7).contains(& raw),
+            "value is out of the range `-8..=7` of field `signed`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `19:16` - Set the `signed` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `-8..=7`.
+    pub fn try_set_signed(
+        &mut self,
+        value: i8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<i8>> {
+        let start = 16;
+        let end = 19;
+        let raw = value;
+        if !(-8..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: -8,
+                max: 7,
+                field: "signed",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `21:20` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 20;
+        let end = 21;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `21:20` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 20;
+        let end = 21;
+        let raw = value.into();
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `25:24` - Set the `repeated` field.
+    ///
+    pub fn set_repeated(&mut self, index: usize, value: u8) {
+        assert!(index < 2);
+        let start = 24 + index * 2;
+        let end = start + 1;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `repeated`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `25:24` - Set the `repeated` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_repeated(
+        &mut self,
+        index: usize,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        assert!(index < 2);
+        let start = 24 + index * 2;
+        let end = start + 1;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "repeated",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Data {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 4]> for Data {
+    fn from(bits: [u8; 4]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Data> for [u8; 4] {
+    fn from(val: Data) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Data {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Data");
+        d.field("flag", &self.flag());
+        d.field("low", &self.low());
+        d.field("high", &self.high());
+        d.field("signed", &self.signed());
+        d.field("mode", &self.mode());
+        let value = self.fixed();
+        if value == 0 {
+            d.field("fixed", &value);
+        } else {
+            d.field("fixed", &format_args!("{value:?} (reserved, must be 0)"));
+        }
+        d.field("repeated[0]", &self.repeated(0));
+        d.field("repeated[1]", &self.repeated(1));
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Data {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Data {{ ");
+        ::defmt::write!(f, "flag: {=bool}, ", & self.flag());
+        ::defmt::write!(f, "low: {=u8}, ", & self.low());
+        ::defmt::write!(f, "high: {=u8}, ", & self.high());
+        ::defmt::write!(f, "signed: {=i8}, ", & self.signed());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "fixed: {=u8}, ", & self.fixed());
+        ::defmt::write!(f, "repeated: {=u8}, [0]", & self.repeated(0));
+        ::defmt::write!(f, "repeated: {=u8}, [1]", & self.repeated(1));
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Data {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Data {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Data {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Data {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Data {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Data {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Data {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ReadTimer {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for ReadTimer {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl ReadTimer {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for ReadTimer {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for ReadTimer {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ReadTimer> for [u8; 1] {
+    fn from(val: ReadTimer) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ReadTimer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ReadTimer");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ReadTimer {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ReadTimer {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ReadTimer {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ReadTimer {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ReadTimer {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ReadTimer {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ReadTimer {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ReadTimer {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ReadTimer {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Off = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+}
+impl ::core::convert::TryFrom<u8> for Mode {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::Low),
+            2 => Ok(Self::Medium),
+            3 => Ok(Self::High),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Mode",
+                })
+            }
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Off => 0,
+            Mode::Low => 1,
+            Mode::Medium => 2,
+            Mode::High => 3,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -0,0 +1,524 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Constants driver
+#[derive(Debug)]
+pub struct Constants<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Constants<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `63`
+    /// - Reset value: `(1 << 7) | 0x0F`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 63;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || Status::from([143]),
+        )
+    }
+    /// Block operation:
+    /// - Address: `68`
+    #[doc(alias = "Channel1")]
+    pub fn channel_1(&mut self) -> Channel1<'_, I> {
+        let address = self.base_address + 68;
+        Channel1::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Register operation:
+    /// - Address: `79`
+    /// - Reset value: `0`
+    #[doc(alias = "Last")]
+    pub fn last(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 79;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+}
+impl<I> ::device_driver::Block for Constants<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Constants`
+pub mod constants {
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 63;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x8F;
+    }
+    /// Raw constants of the `last` register
+    pub mod last {
+        /// The address of the register
+        pub const ADDRESS: u8 = 79;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Debug)]
+pub struct Channel1<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Channel1<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Config::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Channel1<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Channel1`
+///
+/// The addresses are relative to the address of the block.
+pub mod channel_1 {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x80;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 7;
+    /// The mask of the `level` field when the fieldset is read as one `u8` in LE byte order
+    pub const LEVEL_MASK: u8 = 0xF;
+    /// The shift of the `level` field when the fieldset is read as one `u8` in LE byte order
+    pub const LEVEL_SHIFT: u32 = 0;
+    /// `bit 7` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 7;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `3:0` - Read the `level` field.
+    ///
+    #[must_use]
+    pub fn level(&self) -> u8 {
+        let start = 0;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 7` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 7;
+        let end = 7;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:0` - Set the `level` field.
+    ///
+    pub fn set_level(&mut self, value: u8) {
+        let start = 0;
+        let end = 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `level`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:0` - Set the `level` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_level(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 3;
+        let raw = value;
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "level",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.field("level", &self.level());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "level: {=u8}, ", & self.level());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Config {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Config {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Config {
+    /// The mask of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_MASK: u16 = 0xFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `15:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        let start = 0;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u16) {
+        let start = 0;
+        let end = 15;
+        let raw = value;
+        debug_assert!(
+            (0..= 1000).contains(& raw),
+            "value is out of the range `0..=1000` of field `value`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `15:0` - Set the `value` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=1000`.
+    pub fn try_set_value(
+        &mut self,
+        value: u16,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u16>> {
+        let start = 0;
+        let end = 15;
+        let raw = value;
+        if !(0..=1000).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 1000,
+                field: "value",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Config {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Config {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Config> for [u8; 2] {
+    fn from(val: Config) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Config {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Config");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Config {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Config {{ ");
+        ::defmt::write!(f, "value: {=u16}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Config {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Config {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Config {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Config {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Config {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Config {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Config {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -63,6 +63,18 @@ impl<I> ::device_driver::Block for Y<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Y`
+pub mod y {
+    /// Raw constants of the `z` register
+    pub mod z {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
 #[derive(Debug)]
 pub struct B<'i, I> {
     #[doc(hidden)]
@@ -104,6 +116,20 @@ impl<'i, I> ::device_driver::Block for B<'i, I> {
         self.interface
     }
 }
+/// Raw constants of the operations of `B`
+///
+/// The addresses are relative to the address of the block.
+pub mod b {
+    /// Raw constants of the `c` register
+    pub mod c {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
 /// Root block of the D driver
 #[derive(Debug)]
 pub struct D<I> {
@@ -157,6 +183,23 @@ impl<I> ::device_driver::Block for D<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `D`
+pub mod d {
+    /// Raw constants of the `e` register
+    pub mod e {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `g` buffer
+    pub mod g {
+        /// The address of the buffer
+        pub const ADDRESS: u8 = 0;
+    }
+}
 #[derive(Copy, Clone, Eq, PartialEq)]
 #[repr(transparent)]
 pub struct A {
@@ -170,6 +213,10 @@ unsafe impl ::device_driver::Fieldset for A {
     const ZERO: Self = Self { bits: [0; 1] };
 }
 impl A {
+    /// The mask of the `a` field when the fieldset is read as one `u8` in LE byte order
+    pub const A_MASK: u8 = 0x1;
+    /// The shift of the `a` field when the fieldset is read as one `u8` in LE byte order
+    pub const A_SHIFT: u32 = 0;
     /// `bit 0` - Set the `a` field.
     ///
     pub fn set_a(&mut self, value: bool) {
@@ -199,62 +246,62 @@ impl From<A> for [u8; 1] {
         val.bits
     }
 }
-impl core::fmt::Debug for A {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for A {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("A");
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for A {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "A {{ ");
-        defmt::write!(f, "}}");
+impl ::defmt::Format for A {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "A {{ ");
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for A {
+impl ::core::ops::BitAnd for A {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for A {
+impl ::core::ops::BitAndAssign for A {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for A {
+impl ::core::ops::BitOr for A {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for A {
+impl ::core::ops::BitOrAssign for A {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for A {
+impl ::core::ops::BitXor for A {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for A {
+impl ::core::ops::BitXorAssign for A {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for A {
+impl ::core::ops::Not for A {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -276,6 +323,10 @@ unsafe impl ::device_driver::Fieldset for F {
     const ZERO: Self = Self { bits: [0; 1] };
 }
 impl F {
+    /// The mask of the `a` field when the fieldset is read as one `u8` in LE byte order
+    pub const A_MASK: u8 = 0x1;
+    /// The shift of the `a` field when the fieldset is read as one `u8` in LE byte order
+    pub const A_SHIFT: u32 = 0;
     /// `bit 0` - Read the `a` field.
     ///
     #[must_use]
@@ -319,64 +370,64 @@ impl From<F> for [u8; 1] {
         val.bits
     }
 }
-impl core::fmt::Debug for F {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for F {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("F");
         d.field("a", &self.a());
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for F {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "F {{ ");
-        defmt::write!(f, "a: {=bool}, ", & self.a());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for F {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "F {{ ");
+        ::defmt::write!(f, "a: {=bool}, ", & self.a());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for F {
+impl ::core::ops::BitAnd for F {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for F {
+impl ::core::ops::BitAndAssign for F {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for F {
+impl ::core::ops::BitOr for F {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for F {
+impl ::core::ops::BitOrAssign for F {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for F {
+impl ::core::ops::BitXor for F {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for F {
+impl ::core::ops::BitXorAssign for F {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for F {
+impl ::core::ops::Not for F {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
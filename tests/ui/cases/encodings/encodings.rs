@@ -0,0 +1,540 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Encodings driver
+#[derive(Debug)]
+pub struct Encodings<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Encodings<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Rtc")]
+    pub fn rtc(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        RtcFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, RtcFields::default)
+    }
+}
+impl<I> ::device_driver::Block for Encodings<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Encodings`
+pub mod encodings {
+    /// Raw constants of the `rtc` register
+    pub mod rtc {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct RtcFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 4],
+}
+unsafe impl ::device_driver::Fieldset for RtcFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 4] };
+}
+impl RtcFields {
+    /// The mask of the `seconds` field when the fieldset is read as one `u32` in LE byte order
+    pub const SECONDS_MASK: u32 = 0xFF;
+    /// The shift of the `seconds` field when the fieldset is read as one `u32` in LE byte order
+    pub const SECONDS_SHIFT: u32 = 0;
+    /// The mask of the `position` field when the fieldset is read as one `u32` in LE byte order
+    pub const POSITION_MASK: u32 = 0xFF00;
+    /// The shift of the `position` field when the fieldset is read as one `u32` in LE byte order
+    pub const POSITION_SHIFT: u32 = 8;
+    /// The mask of the `offset` field when the fieldset is read as one `u32` in LE byte order
+    pub const OFFSET_MASK: u32 = 0xFF0000;
+    /// The shift of the `offset` field when the fieldset is read as one `u32` in LE byte order
+    pub const OFFSET_SHIFT: u32 = 16;
+    /// The mask of the `trim` field when the fieldset is read as one `u32` in LE byte order
+    pub const TRIM_MASK: u32 = 0xF000000;
+    /// The shift of the `trim` field when the fieldset is read as one `u32` in LE byte order
+    pub const TRIM_SHIFT: u32 = 24;
+    /// The mask of the `day` field when the fieldset is read as one `u32` in LE byte order
+    pub const DAY_MASK: u32 = 0xF0000000;
+    /// The shift of the `day` field when the fieldset is read as one `u32` in LE byte order
+    pub const DAY_SHIFT: u32 = 28;
+    /// `7:0` - Read the `seconds` field.
+    ///
+    #[must_use]
+    pub fn seconds(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Bcd>(raw, 8);
+        raw
+    }
+    /// `15:8` - Read the `position` field.
+    ///
+    #[must_use]
+    pub fn position(&self) -> u8 {
+        let start = 8;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Gray>(raw, 8);
+        raw
+    }
+    /// `23:16` - Read the `offset` field.
+    ///
+    #[must_use]
+    pub fn offset(&self) -> i8 {
+        let start = 16;
+        let end = 23;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<
+            i8,
+            ::device_driver::ops::SignMagnitude,
+        >(raw, 8);
+        raw
+    }
+    /// `27:24` - Read the `trim` field.
+    ///
+    #[must_use]
+    pub fn trim(&self) -> i8 {
+        let start = 24;
+        let end = 27;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<
+            i8,
+            ::device_driver::ops::OnesComplement,
+        >(raw, 4);
+        raw
+    }
+    /// `31:28` - Read the `day` field.
+    ///
+    #[must_use]
+    pub fn day(&self) -> Day {
+        let start = 28;
+        let end = 31;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        raw.into()
+    }
+    /// `7:0` - Set the `seconds` field.
+    ///
+    pub fn set_seconds(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        debug_assert!(
+            (0..= 99).contains(& raw),
+            "value is out of the range `0..=99` of field `seconds`",
+        );
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 8);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:0` - Set the `seconds` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=99`.
+    pub fn try_set_seconds(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        if !(0..=99).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 99,
+                field: "seconds",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 8);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `15:8` - Set the `position` field.
+    ///
+    pub fn set_position(&mut self, value: u8) {
+        let start = 8;
+        let end = 15;
+        let raw = value;
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Gray>(raw, 8);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `23:16` - Set the `offset` field.
+    ///
+    pub fn set_offset(&mut self, value: i8) {
+        let start = 16;
+        let end = 23;
+        let raw = value;
+        debug_assert!(
+            (- 127..= 127).contains(& raw),
+            "value is out of the range `-127..=127` of field `offset`",
+        );
+        let raw = ::device_driver::ops::encode::<
+            i8,
+            ::device_driver::ops::SignMagnitude,
+        >(raw, 8);
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `23:16` - Set the `offset` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `-127..=127`.
+    pub fn try_set_offset(
+        &mut self,
+        value: i8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<i8>> {
+        let start = 16;
+        let end = 23;
+        let raw = value;
+        if !(-127..=127).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: -127,
+                max: 127,
+                field: "offset",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<
+            i8,
+            ::device_driver::ops::SignMagnitude,
+        >(raw, 8);
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `27:24` - Set the `trim` field.
+    ///
+    pub fn set_trim(&mut self, value: i8) {
+        let start = 24;
+        let end = 27;
+        let raw = value;
+        debug_assert!(
+            (- 7..= 7).contains(& raw),
+            "value is out of the range `-7..=7` of field `trim`",
+        );
+        let raw = ::device_driver::ops::encode::<
+            i8,
+            ::device_driver::ops::OnesComplement,
+        >(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `27:24` - Set the `trim` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `-7..=7`.
+    pub fn try_set_trim(
+        &mut self,
+        value: i8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<i8>> {
+        let start = 24;
+        let end = 27;
+        let raw = value;
+        if !(-7..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: -7,
+                max: 7,
+                field: "trim",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<
+            i8,
+            ::device_driver::ops::OnesComplement,
+        >(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `31:28` - Set the `day` field.
+    ///
+    pub fn set_day(&mut self, value: Day) {
+        let start = 28;
+        let end = 31;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 9).contains(& raw), "value is out of the range `0..=9` of field `day`",
+        );
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `31:28` - Set the `day` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=9`.
+    pub fn try_set_day(
+        &mut self,
+        value: Day,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 28;
+        let end = 31;
+        let raw = value.into();
+        if !(0..=9).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 9,
+                field: "day",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for RtcFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 4]> for RtcFields {
+    fn from(bits: [u8; 4]) -> Self {
+        Self { bits }
+    }
+}
+impl From<RtcFields> for [u8; 4] {
+    fn from(val: RtcFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for RtcFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("RtcFields");
+        d.field("seconds", &self.seconds());
+        d.field("position", &self.position());
+        d.field("offset", &self.offset());
+        d.field("trim", &self.trim());
+        d.field("day", &self.day());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for RtcFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "RtcFields {{ ");
+        ::defmt::write!(f, "seconds: {=u8}, ", & self.seconds());
+        ::defmt::write!(f, "position: {=u8}, ", & self.position());
+        ::defmt::write!(f, "offset: {=i8}, ", & self.offset());
+        ::defmt::write!(f, "trim: {=i8}, ", & self.trim());
+        ::defmt::write!(f, "day: {}, ", & self.day());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for RtcFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for RtcFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for RtcFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for RtcFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for RtcFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for RtcFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for RtcFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Day {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 7,
+    Unknown = 8,
+}
+impl Default for Day {
+    fn default() -> Self {
+        Self::Unknown
+    }
+}
+impl From<u8> for Day {
+    fn from(val: u8) -> Self {
+        match val {
+            1 => Self::Monday,
+            2 => Self::Tuesday,
+            3 => Self::Wednesday,
+            4 => Self::Thursday,
+            5 => Self::Friday,
+            6 => Self::Saturday,
+            7 => Self::Sunday,
+            _ => Self::default(),
+        }
+    }
+}
+impl From<Day> for u8 {
+    fn from(val: Day) -> Self {
+        match val {
+            Day::Monday => 1,
+            Day::Tuesday => 2,
+            Day::Wednesday => 3,
+            Day::Thursday => 4,
+            Day::Friday => 5,
+            Day::Saturday => 6,
+            Day::Sunday => 7,
+            Day::Unknown => 8,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Day {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
@@ -0,0 +1,415 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the EnumValueSets driver
+#[derive(Debug)]
+pub struct EnumValueSets<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> EnumValueSets<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Config::default)
+    }
+}
+impl<I> ::device_driver::Block for EnumValueSets<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `EnumValueSets`
+pub mod enum_value_sets {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Config {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Config {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Config {
+    /// The mask of the `speed` field when the fieldset is read as one `u8` in LE byte order
+    pub const SPEED_MASK: u8 = 0x1F;
+    /// The shift of the `speed` field when the fieldset is read as one `u8` in LE byte order
+    pub const SPEED_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0xE0;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 5;
+    /// `4:0` - Read the `speed` field.
+    ///
+    #[must_use]
+    pub fn speed(&self) -> Speed {
+        let start = 0;
+        let end = 4;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `7:5` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Result<Mode, <Mode as TryFrom<u8>>::Error> {
+        let start = 5;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw.try_into()
+    }
+    /// `4:0` - Set the `speed` field.
+    ///
+    pub fn set_speed(&mut self, value: Speed) {
+        let start = 0;
+        let end = 4;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 31).contains(& raw),
+            "value is out of the range `0..=31` of field `speed`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `4:0` - Set the `speed` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=31`.
+    pub fn try_set_speed(
+        &mut self,
+        value: Speed,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 4;
+        let raw = value.into();
+        if !(0..=31).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 31,
+                field: "speed",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `7:5` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 5;
+        let end = 7;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:5` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 5;
+        let end = 7;
+        let raw = value.into();
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Config {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Config {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Config> for [u8; 1] {
+    fn from(val: Config) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Config {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Config");
+        d.field("speed", &self.speed());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Config {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Config {{ ");
+        ::defmt::write!(f, "speed: {}, ", & self.speed());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Config {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Config {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Config {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Config {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Config {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Config {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Config {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+/// Covers all 5 bit values, so converting is infallible within range
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Speed {
+    /// Holds the values `0..=7 | 9..=15`.
+    /// Other values aren't checked and don't read back as this variant,
+    /// so use [`Self::new_reserved`] to create it from a value that may be outside of them.
+    Reserved(u8) = 0,
+    Fast = 8,
+    /// Freely configurable speeds
+    ///
+    /// Holds the values `16..=31`.
+    /// Other values aren't checked and don't read back as this variant,
+    /// so use [`Self::new_custom`] to create it from a value that may be outside of them.
+    Custom(u8) = 16,
+}
+impl Speed {
+    /// Create a [`Self::Reserved`] if the value is one of `0..=7 | 9..=15`
+    pub const fn new_reserved(val: u8) -> Option<Self> {
+        match val {
+            0..=7 | 9..=15 => Some(Self::Reserved(val)),
+            _ => None,
+        }
+    }
+    /// Create a [`Self::Custom`] if the value is one of `16..=31`
+    pub const fn new_custom(val: u8) -> Option<Self> {
+        match val {
+            16..=31 => Some(Self::Custom(val)),
+            _ => None,
+        }
+    }
+}
+impl ::core::convert::TryFrom<u8> for Speed {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0..=7 | 9..=15 => Ok(Self::Reserved(val)),
+            8 => Ok(Self::Fast),
+            16..=31 => Ok(Self::Custom(val)),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Speed",
+                })
+            }
+        }
+    }
+}
+impl From<Speed> for u8 {
+    fn from(val: Speed) -> Self {
+        match val {
+            Speed::Reserved(num) => num,
+            Speed::Fast => 8,
+            Speed::Custom(num) => num,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Speed {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Off = 0,
+    /// Holds the values `1..=2 | 4`.
+    /// Other values aren't checked and don't read back as this variant,
+    /// so use [`Self::new_on`] to create it from a value that may be outside of them.
+    On(u8) = 1,
+    Test = 5,
+}
+impl Mode {
+    /// Create a [`Self::On`] if the value is one of `1..=2 | 4`
+    pub const fn new_on(val: u8) -> Option<Self> {
+        match val {
+            1..=2 | 4 => Some(Self::On(val)),
+            _ => None,
+        }
+    }
+}
+impl ::core::convert::TryFrom<u8> for Mode {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::Off),
+            1..=2 | 4 => Ok(Self::On(val)),
+            5 => Ok(Self::Test),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Mode",
+                })
+            }
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Off => 0,
+            Mode::On(num) => num,
+            Mode::Test => 5,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
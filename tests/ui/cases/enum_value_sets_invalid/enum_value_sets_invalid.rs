@@ -0,0 +1,339 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the EnumValueSetsInvalid driver
+#[derive(Debug)]
+pub struct EnumValueSetsInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> EnumValueSetsInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    /// - Index range: `0..1`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        ::device_driver::ArrayRepeat<1, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || Config::from([0]),
+        )
+    }
+}
+impl<I> ::device_driver::Block for EnumValueSetsInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `EnumValueSetsInvalid`
+pub mod enum_value_sets_invalid {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Config {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Config {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Config {
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0xF;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 0;
+    /// `3:0` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Result<Mode, <Mode as TryFrom<u8>>::Error> {
+        let start = 0;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw.try_into()
+    }
+    /// `3:0` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 0;
+        let end = 3;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:0` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 3;
+        let raw = value.into();
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Config {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Config {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Config> for [u8; 1] {
+    fn from(val: Config) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Config {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Config");
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Config {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Config {{ ");
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Config {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Config {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Config {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Config {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Config {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Config {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Config {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Off = 0,
+    /// Holds the values `1..=3 | 8..=15`.
+    /// Other values aren't checked and don't read back as this variant,
+    /// so use [`Self::new_custom`] to create it from a value that may be outside of them.
+    Custom(u8) = 1,
+}
+impl Mode {
+    /// Create a [`Self::Custom`] if the value is one of `1..=3 | 8..=15`
+    pub const fn new_custom(val: u8) -> Option<Self> {
+        match val {
+            1..=3 | 8..=15 => Some(Self::Custom(val)),
+            _ => None,
+        }
+    }
+}
+impl ::core::convert::TryFrom<u8> for Mode {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::Off),
+            1..=3 | 8..=15 => Ok(Self::Custom(val)),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Mode",
+                })
+            }
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Off => 0,
+            Mode::Custom(num) => num,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Channel {
+    Main = 0,
+    /// Holds the values `1..=3`.
+    /// Other values aren't checked and don't read back as this variant,
+    /// so use [`Self::new_aux`] to create it from a value that may be outside of them.
+    Aux(u8) = 1,
+}
+impl Channel {
+    /// Create a [`Self::Aux`] if the value is one of `1..=3`
+    pub const fn new_aux(val: u8) -> Option<Self> {
+        match val {
+            1..=3 => Some(Self::Aux(val)),
+            _ => None,
+        }
+    }
+}
+impl ::core::convert::TryFrom<u8> for Channel {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::Main),
+            1..=3 => Ok(Self::Aux(val)),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Channel",
+                })
+            }
+        }
+    }
+}
+impl From<Channel> for u8 {
+    fn from(val: Channel) -> Self {
+        match val {
+            Channel::Main => 0,
+            Channel::Aux(num) => num,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Channel {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
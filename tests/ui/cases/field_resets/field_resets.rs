@@ -0,0 +1,776 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the FieldResets driver
+#[derive(Debug)]
+pub struct FieldResets<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> FieldResets<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `[0x0B, 0x40, 0xFD, 0x70, 0x0F, 0x00, 0x00, 0x00]` (from the field resets)
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ConfigFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ConfigFields::from([11, 64, 253, 112, 15, 0, 0, 0]),
+        )
+    }
+    /// The register reset agrees with the field resets
+    ///
+    /// Register operation:
+    /// - Address: `8`
+    /// - Reset value: `0x0000_000F_70FD_400B`
+    #[doc(alias = "ConfigCopy")]
+    pub fn config_copy(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ConfigFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 8;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ConfigFields::from([11, 64, 253, 112, 15, 0, 0, 0]),
+        )
+    }
+    /// Register operation:
+    /// - Address: `16`
+    /// - Reset value: `[0x82, 0x12]` (from the field resets)
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        StatusFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 16;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || StatusFields::from([130, 18]),
+        )
+    }
+}
+impl<I> ::device_driver::Block for FieldResets<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `FieldResets`
+pub mod field_resets {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 8;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u64 = 0xF70FD400B;
+    }
+    /// Raw constants of the `config_copy` register
+    pub mod config_copy {
+        /// The address of the register
+        pub const ADDRESS: u8 = 8;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 8;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u64 = 0xF70FD400B;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 16;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in BE byte order
+        pub const RESET: u16 = 0x8212;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct StatusFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for StatusFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::BE);
+    const ZERO: Self = Self { bits: [0; 2] };
+    fn apply_reserved(&mut self) {
+        {
+            let start = 8;
+            let end = 9;
+            let raw: u8 = 2;
+            unsafe {
+                ::device_driver::ops::store::<
+                    u8,
+                    ::device_driver::ops::BE,
+                >(raw, start, end, &mut self.bits)
+            };
+        }
+    }
+}
+impl StatusFields {
+    /// The mask of the `ready` field when the fieldset is read as one `u16` in BE byte order
+    pub const READY_MASK: u16 = 0x8000;
+    /// The shift of the `ready` field when the fieldset is read as one `u16` in BE byte order
+    pub const READY_SHIFT: u32 = 15;
+    /// The mask of the `count` field when the fieldset is read as one `u16` in BE byte order
+    pub const COUNT_MASK: u16 = 0xFF;
+    /// The shift of the `count` field when the fieldset is read as one `u16` in BE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// The mask of the `fixed` field when the fieldset is read as one `u16` in BE byte order
+    pub const FIXED_MASK: u16 = 0x300;
+    /// The shift of the `fixed` field when the fieldset is read as one `u16` in BE byte order
+    pub const FIXED_SHIFT: u32 = 8;
+    /// `bit 15` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 15;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `7:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `9:8` - Read the `fixed` field.
+    ///
+    /// Reserved, always written as `2`.
+    #[must_use]
+    pub fn fixed(&self) -> u8 {
+        let start = 8;
+        let end = 9;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 15` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 15;
+        let end = 15;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:0` - Set the `count` field.
+    ///
+    pub fn set_count(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for StatusFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for StatusFields {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<StatusFields> for [u8; 2] {
+    fn from(val: StatusFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for StatusFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("StatusFields");
+        d.field("ready", &self.ready());
+        d.field("count", &self.count());
+        let value = self.fixed();
+        if value == 2 {
+            d.field("fixed", &value);
+        } else {
+            d.field("fixed", &format_args!("{value:?} (reserved, must be 2)"));
+        }
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for StatusFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "StatusFields {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "fixed: {=u8}, ", & self.fixed());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for StatusFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for StatusFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for StatusFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for StatusFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for StatusFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for StatusFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for StatusFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ConfigFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 8],
+}
+unsafe impl ::device_driver::Fieldset for ConfigFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 8] };
+}
+impl ConfigFields {
+    /// The mask of the `enable` field when the fieldset is read as one `u64` in LE byte order
+    pub const ENABLE_MASK: u64 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u64` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u64` in LE byte order
+    pub const MODE_MASK: u64 = 0xE;
+    /// The shift of the `mode` field when the fieldset is read as one `u64` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// The mask of the `divider` field when the fieldset is read as one `u64` in LE byte order
+    pub const DIVIDER_MASK: u64 = 0xFF00;
+    /// The shift of the `divider` field when the fieldset is read as one `u64` in LE byte order
+    pub const DIVIDER_SHIFT: u32 = 8;
+    /// The mask of the `offset` field when the fieldset is read as one `u64` in LE byte order
+    pub const OFFSET_MASK: u64 = 0xFF0000;
+    /// The shift of the `offset` field when the fieldset is read as one `u64` in LE byte order
+    pub const OFFSET_SHIFT: u32 = 16;
+    /// The mask of the `day` field when the fieldset is read as one `u64` in LE byte order
+    pub const DAY_MASK: u64 = 0xF0000000;
+    /// The shift of the `day` field when the fieldset is read as one `u64` in LE byte order
+    pub const DAY_SHIFT: u32 = 28;
+    /// The mask of the `threshold` field when the fieldset is read as one `u64` in LE byte order
+    pub const THRESHOLD_MASK: u64 = 0xFFFF000000000000;
+    /// The shift of the `threshold` field when the fieldset is read as one `u64` in LE byte order
+    pub const THRESHOLD_SHIFT: u32 = 48;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `3:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let start = 1;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw.into()
+    }
+    /// `15:8` - Read the `divider` field.
+    ///
+    #[must_use]
+    pub fn divider(&self) -> u8 {
+        let start = 8;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `23:16` - Read the `offset` field.
+    ///
+    #[must_use]
+    pub fn offset(&self) -> i8 {
+        let start = 16;
+        let end = 23;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `31:28` - Read the `day` field.
+    ///
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        let start = 28;
+        let end = 31;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        raw
+    }
+    /// `bit 32` - Read the `channels` field.
+    ///
+    #[must_use]
+    pub fn channels(&self, index: usize) -> bool {
+        assert!(index < 4);
+        let start = 32 + index * 1;
+        let end = start + 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `63:48` - Read the `threshold` field.
+    ///
+    #[must_use]
+    pub fn threshold(&self) -> u16 {
+        let start = 48;
+        let end = 63;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 1;
+        let end = 3;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 3;
+        let raw = value.into();
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `15:8` - Set the `divider` field.
+    ///
+    pub fn set_divider(&mut self, value: u8) {
+        let start = 8;
+        let end = 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `23:16` - Set the `offset` field.
+    ///
+    pub fn set_offset(&mut self, value: i8) {
+        let start = 16;
+        let end = 23;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `31:28` - Set the `day` field.
+    ///
+    pub fn set_day(&mut self, value: u8) {
+        let start = 28;
+        let end = 31;
+        let raw = value;
+        debug_assert!(
+            (0..= 9).contains(& raw), "value is out of the range `0..=9` of field `day`",
+        );
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `31:28` - Set the `day` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=9`.
+    pub fn try_set_day(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 28;
+        let end = 31;
+        let raw = value;
+        if !(0..=9).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 9,
+                field: "day",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 32` - Set the `channels` field.
+    ///
+    pub fn set_channels(&mut self, index: usize, value: bool) {
+        assert!(index < 4);
+        let start = 32 + index * 1;
+        let end = start + 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `63:48` - Set the `threshold` field.
+    ///
+    pub fn set_threshold(&mut self, value: u16) {
+        let start = 48;
+        let end = 63;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for ConfigFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 8]> for ConfigFields {
+    fn from(bits: [u8; 8]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ConfigFields> for [u8; 8] {
+    fn from(val: ConfigFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ConfigFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ConfigFields");
+        d.field("enable", &self.enable());
+        d.field("mode", &self.mode());
+        d.field("divider", &self.divider());
+        d.field("offset", &self.offset());
+        d.field("day", &self.day());
+        d.field("channels[0]", &self.channels(0));
+        d.field("channels[1]", &self.channels(1));
+        d.field("channels[2]", &self.channels(2));
+        d.field("channels[3]", &self.channels(3));
+        d.field("threshold", &self.threshold());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ConfigFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ConfigFields {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "divider: {=u8}, ", & self.divider());
+        ::defmt::write!(f, "offset: {=i8}, ", & self.offset());
+        ::defmt::write!(f, "day: {=u8}, ", & self.day());
+        ::defmt::write!(f, "channels: {=bool}, [0]", & self.channels(0));
+        ::defmt::write!(f, "channels: {=bool}, [1]", & self.channels(1));
+        ::defmt::write!(f, "channels: {=bool}, [2]", & self.channels(2));
+        ::defmt::write!(f, "channels: {=bool}, [3]", & self.channels(3));
+        ::defmt::write!(f, "threshold: {=u16}, ", & self.threshold());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ConfigFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ConfigFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ConfigFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ConfigFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ConfigFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ConfigFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ConfigFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Slow = 2,
+    Fast = 5,
+    Other = 6,
+}
+impl Default for Mode {
+    fn default() -> Self {
+        Self::Other
+    }
+}
+impl From<u8> for Mode {
+    fn from(val: u8) -> Self {
+        match val {
+            2 => Self::Slow,
+            5 => Self::Fast,
+            _ => Self::default(),
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Slow => 2,
+            Mode::Fast => 5,
+            Mode::Other => 6,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
@@ -0,0 +1,909 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the FieldSetExtends driver
+#[derive(Debug)]
+pub struct FieldSetExtends<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> FieldSetExtends<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Ctrl1")]
+    pub fn ctrl_1(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Ctrl1, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Ctrl1::default)
+    }
+    /// Same as CTRL1 plus two more fields
+    ///
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Ctrl2")]
+    pub fn ctrl_2(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Ctrl2, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Ctrl2::default)
+    }
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    #[doc(alias = "Ctrl3")]
+    pub fn ctrl_3(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Ctrl3, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, Ctrl3::default)
+    }
+}
+impl<I> ::device_driver::Block for FieldSetExtends<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `FieldSetExtends`
+pub mod field_set_extends {
+    /// Raw constants of the `ctrl_1` register
+    pub mod ctrl_1 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `ctrl_2` register
+    pub mod ctrl_2 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `ctrl_3` register
+    pub mod ctrl_3 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+/// CTRL2 with the mode field renamed and the level made smaller
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Ctrl3 {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Ctrl3 {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Ctrl3 {
+    /// The mask of the `enable` field when the fieldset is read as one `u16` in LE byte order
+    pub const ENABLE_MASK: u16 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u16` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `irq` field when the fieldset is read as one `u16` in LE byte order
+    pub const IRQ_MASK: u16 = 0x8;
+    /// The shift of the `irq` field when the fieldset is read as one `u16` in LE byte order
+    pub const IRQ_SHIFT: u32 = 3;
+    /// The mask of the `level` field when the fieldset is read as one `u16` in LE byte order
+    pub const LEVEL_MASK: u16 = 0x30;
+    /// The shift of the `level` field when the fieldset is read as one `u16` in LE byte order
+    pub const LEVEL_SHIFT: u32 = 4;
+    /// The mask of the `speed` field when the fieldset is read as one `u16` in LE byte order
+    pub const SPEED_MASK: u16 = 0x6;
+    /// The shift of the `speed` field when the fieldset is read as one `u16` in LE byte order
+    pub const SPEED_SHIFT: u32 = 1;
+    /// The mask of the `extra` field when the fieldset is read as one `u16` in LE byte order
+    pub const EXTRA_MASK: u16 = 0xFF00;
+    /// The shift of the `extra` field when the fieldset is read as one `u16` in LE byte order
+    pub const EXTRA_SHIFT: u32 = 8;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 3` - Read the `irq` field.
+    ///
+    #[must_use]
+    pub fn irq(&self) -> bool {
+        let start = 3;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `5:4` - Read the `level` field.
+    ///
+    #[must_use]
+    pub fn level(&self) -> u8 {
+        let start = 4;
+        let end = 5;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `2:1` - Read the `speed` field.
+    ///
+    #[must_use]
+    pub fn speed(&self) -> u8 {
+        let start = 1;
+        let end = 2;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:8` - Read the `extra` field.
+    ///
+    #[must_use]
+    pub fn extra(&self) -> u8 {
+        let start = 8;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 3` - Set the `irq` field.
+    ///
+    pub fn set_irq(&mut self, value: bool) {
+        let start = 3;
+        let end = 3;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `5:4` - Set the `level` field.
+    ///
+    pub fn set_level(&mut self, value: u8) {
+        let start = 4;
+        let end = 5;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `level`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `5:4` - Set the `level` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_level(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 4;
+        let end = 5;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "level",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `2:1` - Set the `speed` field.
+    ///
+    pub fn set_speed(&mut self, value: u8) {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `speed`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `speed` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_speed(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "speed",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `15:8` - Set the `extra` field.
+    ///
+    pub fn set_extra(&mut self, value: u8) {
+        let start = 8;
+        let end = 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Ctrl3 {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Ctrl3 {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Ctrl3> for [u8; 2] {
+    fn from(val: Ctrl3) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Ctrl3 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Ctrl3");
+        d.field("enable", &self.enable());
+        d.field("irq", &self.irq());
+        d.field("level", &self.level());
+        d.field("speed", &self.speed());
+        d.field("extra", &self.extra());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Ctrl3 {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Ctrl3 {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "irq: {=bool}, ", & self.irq());
+        ::defmt::write!(f, "level: {=u8}, ", & self.level());
+        ::defmt::write!(f, "speed: {=u8}, ", & self.speed());
+        ::defmt::write!(f, "extra: {=u8}, ", & self.extra());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Ctrl3 {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Ctrl3 {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Ctrl3 {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Ctrl3 {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Ctrl3 {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Ctrl3 {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Ctrl3 {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Ctrl2 {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Ctrl2 {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Ctrl2 {
+    /// The mask of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_MASK: u8 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0x6;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// The mask of the `irq` field when the fieldset is read as one `u8` in LE byte order
+    pub const IRQ_MASK: u8 = 0x8;
+    /// The shift of the `irq` field when the fieldset is read as one `u8` in LE byte order
+    pub const IRQ_SHIFT: u32 = 3;
+    /// The mask of the `level` field when the fieldset is read as one `u8` in LE byte order
+    pub const LEVEL_MASK: u8 = 0xF0;
+    /// The shift of the `level` field when the fieldset is read as one `u8` in LE byte order
+    pub const LEVEL_SHIFT: u32 = 4;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `2:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> u8 {
+        let start = 1;
+        let end = 2;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 3` - Read the `irq` field.
+    ///
+    #[must_use]
+    pub fn irq(&self) -> bool {
+        let start = 3;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `7:4` - Read the `level` field.
+    ///
+    #[must_use]
+    pub fn level(&self) -> u8 {
+        let start = 4;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: u8) {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_mode(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 3` - Set the `irq` field.
+    ///
+    pub fn set_irq(&mut self, value: bool) {
+        let start = 3;
+        let end = 3;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:4` - Set the `level` field.
+    ///
+    pub fn set_level(&mut self, value: u8) {
+        let start = 4;
+        let end = 7;
+        let raw = value;
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `level`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:4` - Set the `level` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_level(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 4;
+        let end = 7;
+        let raw = value;
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "level",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Ctrl2 {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Ctrl2 {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Ctrl2> for [u8; 1] {
+    fn from(val: Ctrl2) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Ctrl2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Ctrl2");
+        d.field("enable", &self.enable());
+        d.field("mode", &self.mode());
+        d.field("irq", &self.irq());
+        d.field("level", &self.level());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Ctrl2 {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Ctrl2 {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "mode: {=u8}, ", & self.mode());
+        ::defmt::write!(f, "irq: {=bool}, ", & self.irq());
+        ::defmt::write!(f, "level: {=u8}, ", & self.level());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Ctrl2 {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Ctrl2 {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Ctrl2 {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Ctrl2 {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Ctrl2 {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Ctrl2 {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Ctrl2 {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Ctrl1 {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Ctrl1 {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Ctrl1 {
+    /// The mask of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_MASK: u8 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0x6;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `2:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> u8 {
+        let start = 1;
+        let end = 2;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: u8) {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_mode(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Ctrl1 {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Ctrl1 {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Ctrl1> for [u8; 1] {
+    fn from(val: Ctrl1) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Ctrl1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Ctrl1");
+        d.field("enable", &self.enable());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Ctrl1 {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Ctrl1 {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "mode: {=u8}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Ctrl1 {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Ctrl1 {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Ctrl1 {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Ctrl1 {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Ctrl1 {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Ctrl1 {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Ctrl1 {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
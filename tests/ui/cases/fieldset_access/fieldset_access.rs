@@ -112,6 +112,36 @@ impl<I> ::device_driver::Block for Device<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Device`
+pub mod device {
+    /// Raw constants of the `foo_ro` register
+    pub mod foo_ro {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 8;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u64 = 0x0;
+    }
+    /// Raw constants of the `foo_rw` register
+    pub mod foo_rw {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 8;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u64 = 0x0;
+    }
+    /// Raw constants of the `foo_wo` register
+    pub mod foo_wo {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 8;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u64 = 0x0;
+    }
+}
 #[doc(alias = "foo_woFieldSet")]
 #[derive(Copy, Clone, Eq, PartialEq)]
 #[repr(transparent)]
@@ -126,6 +156,18 @@ unsafe impl ::device_driver::Fieldset for FooWoFieldSet {
     const ZERO: Self = Self { bits: [0; 8] };
 }
 impl FooWoFieldSet {
+    /// The mask of the `value_ro` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RO_MASK: u64 = 0xFFFF;
+    /// The shift of the `value_ro` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RO_SHIFT: u32 = 0;
+    /// The mask of the `value_rw` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RW_MASK: u64 = 0xFFFF0000;
+    /// The shift of the `value_rw` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RW_SHIFT: u32 = 16;
+    /// The mask of the `value_wo` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_WO_MASK: u64 = 0x100000000;
+    /// The shift of the `value_wo` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_WO_SHIFT: u32 = 32;
     /// `15:0` - Read the `value_ro` field.
     ///
     #[must_use]
@@ -196,8 +238,8 @@ impl From<FooWoFieldSet> for [u8; 8] {
         val.bits
     }
 }
-impl core::fmt::Debug for FooWoFieldSet {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for FooWoFieldSet {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("FooWoFieldSet");
         d.field("value_ro", &self.value_ro());
         d.field("value_rw", &self.value_rw());
@@ -205,57 +247,57 @@ impl core::fmt::Debug for FooWoFieldSet {
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for FooWoFieldSet {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "FooWoFieldSet {{ ");
-        defmt::write!(f, "value_ro: {=u16}, ", & self.value_ro());
-        defmt::write!(f, "value_rw: {=i16}, ", & self.value_rw());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for FooWoFieldSet {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FooWoFieldSet {{ ");
+        ::defmt::write!(f, "value_ro: {=u16}, ", & self.value_ro());
+        ::defmt::write!(f, "value_rw: {=i16}, ", & self.value_rw());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for FooWoFieldSet {
+impl ::core::ops::BitAnd for FooWoFieldSet {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for FooWoFieldSet {
+impl ::core::ops::BitAndAssign for FooWoFieldSet {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for FooWoFieldSet {
+impl ::core::ops::BitOr for FooWoFieldSet {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for FooWoFieldSet {
+impl ::core::ops::BitOrAssign for FooWoFieldSet {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for FooWoFieldSet {
+impl ::core::ops::BitXor for FooWoFieldSet {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for FooWoFieldSet {
+impl ::core::ops::BitXorAssign for FooWoFieldSet {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for FooWoFieldSet {
+impl ::core::ops::Not for FooWoFieldSet {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -278,6 +320,18 @@ unsafe impl ::device_driver::Fieldset for FooRwFieldSet {
     const ZERO: Self = Self { bits: [0; 8] };
 }
 impl FooRwFieldSet {
+    /// The mask of the `value_ro` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RO_MASK: u64 = 0xFFFF;
+    /// The shift of the `value_ro` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RO_SHIFT: u32 = 0;
+    /// The mask of the `value_rw` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RW_MASK: u64 = 0xFFFF0000;
+    /// The shift of the `value_rw` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RW_SHIFT: u32 = 16;
+    /// The mask of the `value_wo` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_WO_MASK: u64 = 0x100000000;
+    /// The shift of the `value_wo` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_WO_SHIFT: u32 = 32;
     /// `15:0` - Read the `value_ro` field.
     ///
     #[must_use]
@@ -348,8 +402,8 @@ impl From<FooRwFieldSet> for [u8; 8] {
         val.bits
     }
 }
-impl core::fmt::Debug for FooRwFieldSet {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for FooRwFieldSet {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("FooRwFieldSet");
         d.field("value_ro", &self.value_ro());
         d.field("value_rw", &self.value_rw());
@@ -357,57 +411,57 @@ impl core::fmt::Debug for FooRwFieldSet {
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for FooRwFieldSet {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "FooRwFieldSet {{ ");
-        defmt::write!(f, "value_ro: {=u16}, ", & self.value_ro());
-        defmt::write!(f, "value_rw: {=i16}, ", & self.value_rw());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for FooRwFieldSet {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FooRwFieldSet {{ ");
+        ::defmt::write!(f, "value_ro: {=u16}, ", & self.value_ro());
+        ::defmt::write!(f, "value_rw: {=i16}, ", & self.value_rw());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for FooRwFieldSet {
+impl ::core::ops::BitAnd for FooRwFieldSet {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for FooRwFieldSet {
+impl ::core::ops::BitAndAssign for FooRwFieldSet {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for FooRwFieldSet {
+impl ::core::ops::BitOr for FooRwFieldSet {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for FooRwFieldSet {
+impl ::core::ops::BitOrAssign for FooRwFieldSet {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for FooRwFieldSet {
+impl ::core::ops::BitXor for FooRwFieldSet {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for FooRwFieldSet {
+impl ::core::ops::BitXorAssign for FooRwFieldSet {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for FooRwFieldSet {
+impl ::core::ops::Not for FooRwFieldSet {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -430,6 +484,18 @@ unsafe impl ::device_driver::Fieldset for FooRoFieldSet {
     const ZERO: Self = Self { bits: [0; 8] };
 }
 impl FooRoFieldSet {
+    /// The mask of the `value_ro` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RO_MASK: u64 = 0xFFFF;
+    /// The shift of the `value_ro` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RO_SHIFT: u32 = 0;
+    /// The mask of the `value_rw` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RW_MASK: u64 = 0xFFFF0000;
+    /// The shift of the `value_rw` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_RW_SHIFT: u32 = 16;
+    /// The mask of the `value_wo` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_WO_MASK: u64 = 0x100000000;
+    /// The shift of the `value_wo` field when the fieldset is read as one `u64` in LE byte order
+    pub const VALUE_WO_SHIFT: u32 = 32;
     /// `15:0` - Read the `value_ro` field.
     ///
     #[must_use]
@@ -500,8 +566,8 @@ impl From<FooRoFieldSet> for [u8; 8] {
         val.bits
     }
 }
-impl core::fmt::Debug for FooRoFieldSet {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for FooRoFieldSet {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("FooRoFieldSet");
         d.field("value_ro", &self.value_ro());
         d.field("value_rw", &self.value_rw());
@@ -509,57 +575,57 @@ impl core::fmt::Debug for FooRoFieldSet {
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for FooRoFieldSet {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "FooRoFieldSet {{ ");
-        defmt::write!(f, "value_ro: {=u16}, ", & self.value_ro());
-        defmt::write!(f, "value_rw: {=i16}, ", & self.value_rw());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for FooRoFieldSet {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FooRoFieldSet {{ ");
+        ::defmt::write!(f, "value_ro: {=u16}, ", & self.value_ro());
+        ::defmt::write!(f, "value_rw: {=i16}, ", & self.value_rw());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for FooRoFieldSet {
+impl ::core::ops::BitAnd for FooRoFieldSet {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for FooRoFieldSet {
+impl ::core::ops::BitAndAssign for FooRoFieldSet {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for FooRoFieldSet {
+impl ::core::ops::BitOr for FooRoFieldSet {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for FooRoFieldSet {
+impl ::core::ops::BitOrAssign for FooRoFieldSet {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for FooRoFieldSet {
+impl ::core::ops::BitXor for FooRoFieldSet {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for FooRoFieldSet {
+impl ::core::ops::BitXorAssign for FooRoFieldSet {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for FooRoFieldSet {
+impl ::core::ops::Not for FooRoFieldSet {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
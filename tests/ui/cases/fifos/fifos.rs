@@ -0,0 +1,1345 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+/// Root block of the Fifos driver
+#[derive(Debug)]
+pub struct Fifos<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Fifos<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Accelerometer samples, read only as far as the fifo is filled
+    ///
+    /// Fifo operation:
+    /// - Address: `63`
+    /// - Record size: `6` bytes
+    /// - Fill level: `frames` of the register at `58`, counting records
+    #[doc(alias = "Accel")]
+    pub fn accel(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Self,
+        AccelSample,
+        u8,
+        ::device_driver::RegisterLevel<u8, FifoStatus>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 63;
+        ::device_driver::FifoOperation::new(
+            self,
+            address as u8,
+            ::device_driver::RegisterLevel::new(
+                (self.base_address + 58) as u8,
+                |level: &FifoStatus| level.frames() as usize,
+            ),
+        )
+    }
+    /// The same samples, but the level counts bytes
+    ///
+    /// Fifo operation:
+    /// - Address: `64`
+    /// - Record size: `6` bytes
+    /// - Fill level: `length` of the register at `59`, counting bytes
+    /// - Chip variants: `chip-b`
+    #[doc(alias = "AccelBytes")]
+    #[cfg(any(feature = "chip-b"))]
+    pub fn accel_bytes(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Self,
+        AccelSample,
+        u8,
+        ::device_driver::RegisterLevel<u8, FifoLength>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 64;
+        ::device_driver::FifoOperation::new(
+            self,
+            address as u8,
+            ::device_driver::RegisterLevel::new(
+                (self.base_address + 59) as u8,
+                |level: &FifoLength| level.length() as usize / 6,
+            ),
+        )
+    }
+    /// Temperature samples without a fill level
+    ///
+    /// Fifo operation:
+    /// - Address: `80`
+    /// - Record size: `2` bytes
+    #[doc(alias = "Temperature")]
+    pub fn temperature(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Self,
+        Temperature,
+        u8,
+        ::device_driver::NoLevel,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 80;
+        ::device_driver::FifoOperation::new(
+            self,
+            address as u8,
+            ::device_driver::NoLevel,
+        )
+    }
+    /// Register operation:
+    /// - Address: `58`
+    /// - Reset value: `0`
+    #[doc(alias = "FifoStatus")]
+    pub fn fifo_status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        FifoStatus,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 58;
+        ::device_driver::RegisterOperation::new(self, address as u8, FifoStatus::default)
+    }
+    /// Register operation:
+    /// - Address: `59`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-b`
+    #[doc(alias = "FifoLength")]
+    #[cfg(any(feature = "chip-b"))]
+    pub fn fifo_length(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        FifoLength,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 59;
+        ::device_driver::RegisterOperation::new(self, address as u8, FifoLength::default)
+    }
+    /// Block operation:
+    /// - Address: `128`
+    #[doc(alias = "Aux")]
+    pub fn aux(&mut self) -> Aux<'_, I> {
+        let address = self.base_address + 128;
+        Aux::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Register operation:
+    /// - Address: `48`
+    /// - Reset value: `0`
+    #[doc(alias = "Control")]
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 48;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+}
+impl<I> ::device_driver::Block for Fifos<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Fifos`
+pub mod fifos {
+    /// Raw constants of the `accel` fifo
+    pub mod accel {
+        /// The address of the data register of the fifo
+        pub const ADDRESS: u8 = 63;
+        /// The size of one record in bytes
+        pub const RECORD_SIZE_BYTES: usize = 6;
+    }
+    /// Raw constants of the `accel_bytes` fifo
+    #[cfg(any(feature = "chip-b"))]
+    pub mod accel_bytes {
+        /// The address of the data register of the fifo
+        pub const ADDRESS: u8 = 64;
+        /// The size of one record in bytes
+        pub const RECORD_SIZE_BYTES: usize = 6;
+    }
+    /// Raw constants of the `temperature` fifo
+    pub mod temperature {
+        /// The address of the data register of the fifo
+        pub const ADDRESS: u8 = 80;
+        /// The size of one record in bytes
+        pub const RECORD_SIZE_BYTES: usize = 2;
+    }
+    /// Raw constants of the `fifo_status` register
+    pub mod fifo_status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 58;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `fifo_length` register
+    #[cfg(any(feature = "chip-b"))]
+    pub mod fifo_length {
+        /// The address of the register
+        pub const ADDRESS: u8 = 59;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `control` register
+    pub mod control {
+        /// The address of the register
+        pub const ADDRESS: u8 = 48;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Debug)]
+pub struct Aux<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Aux<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Fifo operation:
+    /// - Address: `16`
+    /// - Record size: `6` bytes
+    /// - Fill level: `count` of the register at `17`, counting records
+    #[doc(alias = "Magnetometer")]
+    pub fn magnetometer(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Self,
+        AccelSample,
+        u8,
+        ::device_driver::RegisterLevel<u8, AuxStatus>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 16;
+        ::device_driver::FifoOperation::new(
+            self,
+            address as u8,
+            ::device_driver::RegisterLevel::new(
+                (self.base_address + 17) as u8,
+                |level: &AuxStatus| level.count() as usize,
+            ),
+        )
+    }
+    /// Register operation:
+    /// - Address: `17`
+    /// - Reset value: `0`
+    #[doc(alias = "AuxStatus")]
+    pub fn aux_status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        AuxStatus,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 17;
+        ::device_driver::RegisterOperation::new(self, address as u8, AuxStatus::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Aux<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Aux`
+///
+/// The addresses are relative to the address of the block.
+pub mod aux {
+    /// Raw constants of the `magnetometer` fifo
+    pub mod magnetometer {
+        /// The address of the data register of the fifo
+        pub const ADDRESS: u8 = 16;
+        /// The size of one record in bytes
+        pub const RECORD_SIZE_BYTES: usize = 6;
+    }
+    /// Raw constants of the `aux_status` register
+    pub mod aux_status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 17;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// The states of [`Power`]
+pub mod power {
+    #[derive(Debug)]
+    pub struct Idle;
+    #[derive(Debug)]
+    pub struct Measuring;
+    /// Implemented by the states [`accel`](super::Fifos::accel) may be used in
+    pub trait AllowsAccel {}
+    impl AllowsAccel for Measuring {}
+}
+/// Typestate wrapper around [`Fifos`] in one of the states of [`power`].
+/// Operations that are only allowed in some states can't be called in the other states.
+#[derive(Debug)]
+pub struct Power<I, S> {
+    device: Fifos<I>,
+    state: ::core::marker::PhantomData<S>,
+}
+impl<I> Power<I, power::Idle> {
+    /// Wrap the device, which must be in the initial [`Idle`](power::Idle) state
+    pub const fn new(device: Fifos<I>) -> Self {
+        Self {
+            device,
+            state: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<I, S> Power<I, S> {
+    /// Drop the wrapper and reclaim the device
+    pub fn free(self) -> Fifos<I> {
+        self.device
+    }
+    /// See [`Fifos::accel_bytes`].
+    #[cfg(any(feature = "chip-b"))]
+    pub fn accel_bytes(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Fifos<I>,
+        AccelSample,
+        u8,
+        ::device_driver::RegisterLevel<u8, FifoLength>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.accel_bytes()
+    }
+    /// See [`Fifos::temperature`].
+    pub fn temperature(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Fifos<I>,
+        Temperature,
+        u8,
+        ::device_driver::NoLevel,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.temperature()
+    }
+    /// See [`Fifos::fifo_status`].
+    pub fn fifo_status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Fifos<I>,
+        FifoStatus,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.fifo_status()
+    }
+    /// See [`Fifos::fifo_length`].
+    #[cfg(any(feature = "chip-b"))]
+    pub fn fifo_length(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Fifos<I>,
+        FifoLength,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.fifo_length()
+    }
+    /// See [`Fifos::aux`].
+    pub fn aux(&mut self) -> Aux<'_, I> {
+        self.device.aux()
+    }
+    /// See [`Fifos::control`].
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Fifos<I>,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.control()
+    }
+}
+impl<I, S: power::AllowsAccel> Power<I, S> {
+    /// See [`Fifos::accel`].
+    pub fn accel(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<
+        '_,
+        Fifos<I>,
+        AccelSample,
+        u8,
+        ::device_driver::RegisterLevel<u8, FifoStatus>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.accel()
+    }
+}
+impl<I> Power<I, power::Idle> {
+    /// Transition to [`Measuring`](power::Measuring).
+    ///
+    /// Routine:
+    /// - Write [`control`](Fifos::control): `fifo_enable = true`
+    pub fn start(
+        mut self,
+    ) -> Result<
+        Power<I, power::Measuring>,
+        ::device_driver::TransitionError<
+            Fifos<I>,
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let result = (|| -> Result<
+            (),
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        > {
+            self.device
+                .control()
+                .write(|fields| {
+                    fields.set_fifo_enable(true);
+                })?;
+            Ok(())
+        })();
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Async version of [`start`](Self::start).
+    ///
+    /// Transition to [`Measuring`](power::Measuring).
+    ///
+    /// Routine:
+    /// - Write [`control`](Fifos::control): `fifo_enable = true`
+    pub async fn start_async(
+        mut self,
+    ) -> Result<
+        Power<I, power::Measuring>,
+        ::device_driver::TransitionError<
+            Fifos<I>,
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let result: Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error> = async {
+            self.device
+                .control()
+                .write_async(|fields| {
+                    fields.set_fifo_enable(true);
+                })
+                .await?;
+            Ok(())
+        }
+            .await;
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct AuxStatus {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for AuxStatus {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl AuxStatus {
+    /// The mask of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_MASK: u8 = 0xF;
+    /// The shift of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// `3:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for AuxStatus {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for AuxStatus {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<AuxStatus> for [u8; 1] {
+    fn from(val: AuxStatus) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for AuxStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("AuxStatus");
+        d.field("count", &self.count());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for AuxStatus {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "AuxStatus {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for AuxStatus {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for AuxStatus {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for AuxStatus {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for AuxStatus {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for AuxStatus {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for AuxStatus {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for AuxStatus {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Control {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Control {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Control {
+    /// The mask of the `fifo_enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const FIFO_ENABLE_MASK: u8 = 0x1;
+    /// The shift of the `fifo_enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const FIFO_ENABLE_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `fifo_enable` field.
+    ///
+    #[doc(alias = "fifo-enable")]
+    #[must_use]
+    pub fn fifo_enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `fifo_enable` field.
+    ///
+    #[doc(alias = "fifo-enable")]
+    pub fn set_fifo_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Control {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Control {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Control> for [u8; 1] {
+    fn from(val: Control) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Control {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Control");
+        d.field("fifo_enable", &self.fifo_enable());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Control {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Control {{ ");
+        ::defmt::write!(f, "fifo_enable: {=bool}, ", & self.fifo_enable());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Control {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Control {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Control {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Control {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Control {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Control {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Control {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct FifoLength {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for FifoLength {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl FifoLength {
+    /// The mask of the `length` field when the fieldset is read as one `u16` in LE byte order
+    pub const LENGTH_MASK: u16 = 0x7FF;
+    /// The shift of the `length` field when the fieldset is read as one `u16` in LE byte order
+    pub const LENGTH_SHIFT: u32 = 0;
+    /// `10:0` - Read the `length` field.
+    ///
+    #[must_use]
+    pub fn length(&self) -> u16 {
+        let start = 0;
+        let end = 10;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for FifoLength {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for FifoLength {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<FifoLength> for [u8; 2] {
+    fn from(val: FifoLength) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for FifoLength {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("FifoLength");
+        d.field("length", &self.length());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for FifoLength {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FifoLength {{ ");
+        ::defmt::write!(f, "length: {=u16}, ", & self.length());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for FifoLength {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for FifoLength {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for FifoLength {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for FifoLength {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for FifoLength {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for FifoLength {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for FifoLength {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct FifoStatus {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for FifoStatus {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl FifoStatus {
+    /// The mask of the `frames` field when the fieldset is read as one `u8` in LE byte order
+    pub const FRAMES_MASK: u8 = 0x7F;
+    /// The shift of the `frames` field when the fieldset is read as one `u8` in LE byte order
+    pub const FRAMES_SHIFT: u32 = 0;
+    /// The mask of the `overrun` field when the fieldset is read as one `u8` in LE byte order
+    pub const OVERRUN_MASK: u8 = 0x80;
+    /// The shift of the `overrun` field when the fieldset is read as one `u8` in LE byte order
+    pub const OVERRUN_SHIFT: u32 = 7;
+    /// `6:0` - Read the `frames` field.
+    ///
+    #[must_use]
+    pub fn frames(&self) -> u8 {
+        let start = 0;
+        let end = 6;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 7` - Read the `overrun` field.
+    ///
+    #[must_use]
+    pub fn overrun(&self) -> bool {
+        let start = 7;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+}
+impl Default for FifoStatus {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for FifoStatus {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<FifoStatus> for [u8; 1] {
+    fn from(val: FifoStatus) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for FifoStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("FifoStatus");
+        d.field("frames", &self.frames());
+        d.field("overrun", &self.overrun());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for FifoStatus {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "FifoStatus {{ ");
+        ::defmt::write!(f, "frames: {=u8}, ", & self.frames());
+        ::defmt::write!(f, "overrun: {=bool}, ", & self.overrun());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for FifoStatus {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for FifoStatus {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for FifoStatus {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for FifoStatus {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for FifoStatus {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for FifoStatus {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for FifoStatus {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Temperature {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Temperature {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Temperature {
+    /// The mask of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_MASK: u16 = 0xFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// The mask of the `valid` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALID_MASK: u16 = 0x8000;
+    /// The shift of the `valid` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALID_SHIFT: u32 = 15;
+    /// `11:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> i16 {
+        let start = 0;
+        let end = 11;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 15` - Read the `valid` field.
+    ///
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        let start = 15;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+}
+impl Default for Temperature {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Temperature {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Temperature> for [u8; 2] {
+    fn from(val: Temperature) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Temperature {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Temperature");
+        d.field("value", &self.value());
+        d.field("valid", &self.valid());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Temperature {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Temperature {{ ");
+        ::defmt::write!(f, "value: {=i16}, ", & self.value());
+        ::defmt::write!(f, "valid: {=bool}, ", & self.valid());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Temperature {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Temperature {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Temperature {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Temperature {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Temperature {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Temperature {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Temperature {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct AccelSample {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 6],
+}
+unsafe impl ::device_driver::Fieldset for AccelSample {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 6] };
+}
+impl AccelSample {
+    /// The mask of the `x` field when the fieldset is read as one `u64` in LE byte order
+    pub const X_MASK: u64 = 0xFFFF;
+    /// The shift of the `x` field when the fieldset is read as one `u64` in LE byte order
+    pub const X_SHIFT: u32 = 0;
+    /// The mask of the `y` field when the fieldset is read as one `u64` in LE byte order
+    pub const Y_MASK: u64 = 0xFFFF0000;
+    /// The shift of the `y` field when the fieldset is read as one `u64` in LE byte order
+    pub const Y_SHIFT: u32 = 16;
+    /// The mask of the `z` field when the fieldset is read as one `u64` in LE byte order
+    pub const Z_MASK: u64 = 0xFFFF00000000;
+    /// The shift of the `z` field when the fieldset is read as one `u64` in LE byte order
+    pub const Z_SHIFT: u32 = 32;
+    /// `15:0` - Read the `x` field.
+    ///
+    #[must_use]
+    pub fn x(&self) -> i16 {
+        let start = 0;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `31:16` - Read the `y` field.
+    ///
+    #[must_use]
+    pub fn y(&self) -> i16 {
+        let start = 16;
+        let end = 31;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `47:32` - Read the `z` field.
+    ///
+    #[must_use]
+    pub fn z(&self) -> i16 {
+        let start = 32;
+        let end = 47;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for AccelSample {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 6]> for AccelSample {
+    fn from(bits: [u8; 6]) -> Self {
+        Self { bits }
+    }
+}
+impl From<AccelSample> for [u8; 6] {
+    fn from(val: AccelSample) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for AccelSample {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("AccelSample");
+        d.field("x", &self.x());
+        d.field("y", &self.y());
+        d.field("z", &self.z());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for AccelSample {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "AccelSample {{ ");
+        ::defmt::write!(f, "x: {=i16}, ", & self.x());
+        ::defmt::write!(f, "y: {=i16}, ", & self.y());
+        ::defmt::write!(f, "z: {=i16}, ", & self.z());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for AccelSample {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for AccelSample {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for AccelSample {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for AccelSample {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for AccelSample {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for AccelSample {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for AccelSample {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,1088 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+/// Root block of the FifosInvalid driver
+#[derive(Debug)]
+pub struct FifosInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> FifosInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Fifo operation:
+    /// - Address: `48`
+    /// - Record size: `2` bytes
+    #[doc(alias = "Overlapping")]
+    pub fn overlapping(
+        &mut self,
+    ) -> ::device_driver::FifoOperation<'_, Self, Sample, u8, ::device_driver::NoLevel>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 48;
+        ::device_driver::FifoOperation::new(
+            self,
+            address as u8,
+            ::device_driver::NoLevel,
+        )
+    }
+    /// Register operation:
+    /// - Address: `48`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 48;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `49`
+    /// - Reset value: `0`
+    #[doc(alias = "Trigger")]
+    pub fn trigger(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Trigger,
+        u8,
+        ::device_driver::WO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 49;
+        ::device_driver::RegisterOperation::new(self, address as u8, Trigger::default)
+    }
+    /// Register operation:
+    /// - Address: `50`
+    /// - Reset value: `0`
+    /// - Index range: `0..2`
+    #[doc(alias = "Channels")]
+    pub fn channels(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Channels,
+        u8,
+        ::device_driver::RO,
+        ::device_driver::ArrayRepeat<2, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 50;
+        ::device_driver::RegisterOperation::new(self, address as u8, Channels::default)
+    }
+    /// Register operation:
+    /// - Address: `52`
+    /// - Reset value: `0`
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "OnlyA")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn only_a(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, OnlyA, u8, ::device_driver::RO, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 52;
+        ::device_driver::RegisterOperation::new(self, address as u8, OnlyA::default)
+    }
+    /// Block operation:
+    /// - Address: `64`
+    #[doc(alias = "Inner")]
+    pub fn inner(&mut self) -> Inner<'_, I> {
+        let address = self.base_address + 64;
+        Inner::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+}
+impl<I> ::device_driver::Block for FifosInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `FifosInvalid`
+pub mod fifos_invalid {
+    /// Raw constants of the `overlapping` fifo
+    pub mod overlapping {
+        /// The address of the data register of the fifo
+        pub const ADDRESS: u8 = 48;
+        /// The size of one record in bytes
+        pub const RECORD_SIZE_BYTES: usize = 2;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 48;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `trigger` register
+    pub mod trigger {
+        /// The address of the register
+        pub const ADDRESS: u8 = 49;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `channels` register
+    pub mod channels {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 50;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `only_a` register
+    #[cfg(any(feature = "chip-a"))]
+    pub mod only_a {
+        /// The address of the register
+        pub const ADDRESS: u8 = 52;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Debug)]
+pub struct Inner<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Inner<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Nested")]
+    pub fn nested(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Nested,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Nested::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Inner<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Inner`
+///
+/// The addresses are relative to the address of the block.
+pub mod inner {
+    /// Raw constants of the `nested` register
+    pub mod nested {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Sample {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Sample {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Sample {
+    /// The mask of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_MASK: u16 = 0xFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `15:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        let start = 0;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for Sample {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Sample {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Sample> for [u8; 2] {
+    fn from(val: Sample) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Sample {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Sample");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Sample {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Sample {{ ");
+        ::defmt::write!(f, "value: {=u16}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Sample {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Sample {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Sample {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Sample {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Sample {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Sample {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Sample {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Nested {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Nested {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Nested {
+    /// The mask of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_MASK: u8 = 0xFF;
+    /// The shift of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// `7:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for Nested {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Nested {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Nested> for [u8; 1] {
+    fn from(val: Nested) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Nested {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Nested");
+        d.field("count", &self.count());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Nested {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Nested {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Nested {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Nested {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Nested {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Nested {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Nested {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Nested {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Nested {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct OnlyA {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for OnlyA {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl OnlyA {
+    /// The mask of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_MASK: u8 = 0xFF;
+    /// The shift of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// `7:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for OnlyA {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for OnlyA {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<OnlyA> for [u8; 1] {
+    fn from(val: OnlyA) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for OnlyA {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("OnlyA");
+        d.field("count", &self.count());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for OnlyA {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "OnlyA {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for OnlyA {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for OnlyA {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for OnlyA {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for OnlyA {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for OnlyA {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for OnlyA {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for OnlyA {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Channels {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Channels {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Channels {
+    /// The mask of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_MASK: u8 = 0xFF;
+    /// The shift of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// `7:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for Channels {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Channels {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Channels> for [u8; 1] {
+    fn from(val: Channels) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Channels {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Channels");
+        d.field("count", &self.count());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Channels {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Channels {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Channels {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Channels {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Channels {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Channels {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Channels {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Channels {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Channels {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Trigger {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Trigger {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Trigger {
+    /// The mask of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_MASK: u8 = 0xFF;
+    /// The shift of the `count` field when the fieldset is read as one `u8` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// `7:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for Trigger {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Trigger {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Trigger> for [u8; 1] {
+    fn from(val: Trigger) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Trigger {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Trigger");
+        d.field("count", &self.count());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Trigger {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Trigger {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Trigger {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Trigger {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Trigger {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Trigger {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Trigger {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Trigger {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Trigger {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Status {
+    /// The mask of the `count` field when the fieldset is read as one `u16` in LE byte order
+    pub const COUNT_MASK: u16 = 0xF;
+    /// The shift of the `count` field when the fieldset is read as one `u16` in LE byte order
+    pub const COUNT_SHIFT: u32 = 0;
+    /// The mask of the `signed` field when the fieldset is read as one `u16` in LE byte order
+    pub const SIGNED_MASK: u16 = 0xF0;
+    /// The shift of the `signed` field when the fieldset is read as one `u16` in LE byte order
+    pub const SIGNED_SHIFT: u32 = 4;
+    /// The mask of the `flag` field when the fieldset is read as one `u16` in LE byte order
+    pub const FLAG_MASK: u16 = 0x100;
+    /// The shift of the `flag` field when the fieldset is read as one `u16` in LE byte order
+    pub const FLAG_SHIFT: u32 = 8;
+    /// The mask of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_MASK: u16 = 0x600;
+    /// The shift of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_SHIFT: u32 = 9;
+    /// The mask of the `hidden` field when the fieldset is read as one `u16` in LE byte order
+    pub const HIDDEN_MASK: u16 = 0xF000;
+    /// The shift of the `hidden` field when the fieldset is read as one `u16` in LE byte order
+    pub const HIDDEN_SHIFT: u32 = 12;
+    /// `3:0` - Read the `count` field.
+    ///
+    #[must_use]
+    pub fn count(&self) -> u8 {
+        let start = 0;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:4` - Read the `signed` field.
+    ///
+    #[must_use]
+    pub fn signed(&self) -> i8 {
+        let start = 4;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 8` - Read the `flag` field.
+    ///
+    #[must_use]
+    pub fn flag(&self) -> bool {
+        let start = 8;
+        let end = 8;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `10:9` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let start = 9;
+        let end = 10;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `15:12` - Set the `hidden` field.
+    ///
+    pub fn set_hidden(&mut self, value: u8) {
+        let start = 12;
+        let end = 15;
+        let raw = value;
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `hidden`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `15:12` - Set the `hidden` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_hidden(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 12;
+        let end = 15;
+        let raw = value;
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "hidden",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Status {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 2] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("count", &self.count());
+        d.field("signed", &self.signed());
+        d.field("flag", &self.flag());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "count: {=u8}, ", & self.count());
+        ::defmt::write!(f, "signed: {=i8}, ", & self.signed());
+        ::defmt::write!(f, "flag: {=bool}, ", & self.flag());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Burst = 2,
+    Test = 3,
+}
+impl ::core::convert::TryFrom<u8> for Mode {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::On),
+            2 => Ok(Self::Burst),
+            3 => Ok(Self::Test),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Mode",
+                })
+            }
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Off => 0,
+            Mode::On => 1,
+            Mode::Burst => 2,
+            Mode::Test => 3,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -0,0 +1,701 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Flags driver
+#[derive(Debug)]
+pub struct Flags<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Flags<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// The interrupts of the device
+    ///
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Interrupts")]
+    pub fn interrupts(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Irq, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Irq::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        StatusFlags,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            StatusFlags::default,
+        )
+    }
+}
+impl<I> ::device_driver::Block for Flags<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Flags`
+pub mod flags {
+    /// Raw constants of the `interrupts` register
+    pub mod interrupts {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in BE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct StatusFlags {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for StatusFlags {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::BE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl StatusFlags {
+    /// The mask of the `ready` field when the fieldset is read as one `u16` in BE byte order
+    pub const READY_MASK: u16 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u16` in BE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// The mask of the `busy` field when the fieldset is read as one `u16` in BE byte order
+    pub const BUSY_MASK: u16 = 0x200;
+    /// The shift of the `busy` field when the fieldset is read as one `u16` in BE byte order
+    pub const BUSY_SHIFT: u32 = 9;
+    /// The mask of the `error` field when the fieldset is read as one `u16` in BE byte order
+    pub const ERROR_MASK: u16 = 0x8000;
+    /// The shift of the `error` field when the fieldset is read as one `u16` in BE byte order
+    pub const ERROR_SHIFT: u32 = 15;
+    /// `bit 0` - The `ready` flag.
+    ///
+    #[doc(alias = "ready")]
+    pub const READY: Self = Self { bits: [0, 1] };
+    /// `bit 9` - The `busy` flag.
+    ///
+    #[doc(alias = "busy")]
+    pub const BUSY: Self = Self { bits: [2, 0] };
+    /// `bit 15` - The `error` flag.
+    ///
+    #[doc(alias = "error")]
+    pub const ERROR: Self = Self { bits: [128, 0] };
+    /// The set without any flags
+    pub const fn empty() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+    /// The set with all flags
+    pub const fn all() -> Self {
+        Self { bits: [130, 1] }
+    }
+    /// Returns true if no flag is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        (*self & Self::all()) == Self::empty()
+    }
+    /// Returns true if all flags are set
+    #[must_use]
+    pub fn is_all(&self) -> bool {
+        self.contains(Self::all())
+    }
+    /// Returns true if all flags of `other` are set in `self`
+    #[must_use]
+    pub fn contains(&self, other: Self) -> bool {
+        (*self & other) == other
+    }
+    /// Returns true if any flag of `other` is set in `self`
+    #[must_use]
+    pub fn intersects(&self, other: Self) -> bool {
+        !(*self & other).is_empty()
+    }
+    /// Set the flags of `other`
+    pub fn insert(&mut self, other: Self) {
+        *self |= other;
+    }
+    /// Clear the flags of `other`
+    pub fn remove(&mut self, other: Self) {
+        *self &= !other;
+    }
+    /// Flip the flags of `other`
+    pub fn toggle(&mut self, other: Self) {
+        *self ^= other;
+    }
+    /// Iterate over the flags that are set
+    pub fn iter(&self) -> impl Iterator<Item = Self> {
+        let this = *self;
+        [Self::READY, Self::BUSY, Self::ERROR]
+            .into_iter()
+            .filter(move |flag| this.contains(*flag))
+    }
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 9` - Read the `busy` field.
+    ///
+    #[must_use]
+    pub fn busy(&self) -> bool {
+        let start = 9;
+        let end = 9;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 15` - Read the `error` field.
+    ///
+    #[must_use]
+    pub fn error(&self) -> u8 {
+        let start = 15;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 9` - Set the `busy` field.
+    ///
+    pub fn set_busy(&mut self, value: bool) {
+        let start = 9;
+        let end = 9;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 15` - Set the `error` field.
+    ///
+    pub fn set_error(&mut self, value: u8) {
+        let start = 15;
+        let end = 15;
+        let raw = value;
+        debug_assert!(
+            (0..= 1).contains(& raw),
+            "value is out of the range `0..=1` of field `error`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 15` - Set the `error` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=1`.
+    pub fn try_set_error(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 15;
+        let end = 15;
+        let raw = value;
+        if !(0..=1).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 1,
+                field: "error",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for StatusFlags {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for StatusFlags {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<StatusFlags> for [u8; 2] {
+    fn from(val: StatusFlags) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for StatusFlags {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        f.write_str("StatusFlags(")?;
+        let mut separator = "";
+        for (name, flag) in [
+            ("READY", Self::READY),
+            ("BUSY", Self::BUSY),
+            ("ERROR", Self::ERROR),
+        ] {
+            if self.contains(flag) {
+                f.write_str(separator)?;
+                f.write_str(name)?;
+                separator = " | ";
+            }
+        }
+        f.write_str(")")
+    }
+}
+impl ::core::iter::FromIterator<Self> for StatusFlags {
+    fn from_iter<T: IntoIterator<Item = Self>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::empty(), |set, flag| set | flag)
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for StatusFlags {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "StatusFlags {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "busy: {=bool}, ", & self.busy());
+        ::defmt::write!(f, "error: {=u8}, ", & self.error());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for StatusFlags {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for StatusFlags {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for StatusFlags {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for StatusFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for StatusFlags {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for StatusFlags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for StatusFlags {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Irq {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Irq {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Irq {
+    /// The mask of the `rx` field when the fieldset is read as one `u8` in LE byte order
+    pub const RX_MASK: u8 = 0x1;
+    /// The shift of the `rx` field when the fieldset is read as one `u8` in LE byte order
+    pub const RX_SHIFT: u32 = 0;
+    /// The mask of the `tx` field when the fieldset is read as one `u8` in LE byte order
+    pub const TX_MASK: u8 = 0x2;
+    /// The shift of the `tx` field when the fieldset is read as one `u8` in LE byte order
+    pub const TX_SHIFT: u32 = 1;
+    /// The mask of the `timeout` field when the fieldset is read as one `u8` in LE byte order
+    pub const TIMEOUT_MASK: u8 = 0x10;
+    /// The shift of the `timeout` field when the fieldset is read as one `u8` in LE byte order
+    pub const TIMEOUT_SHIFT: u32 = 4;
+    /// `bit 0` - The `rx` flag.
+    ///
+    /// A packet was received
+    #[doc(alias = "rx")]
+    pub const RX: Self = Self { bits: [1] };
+    /// `bit 1` - The `tx` flag.
+    ///
+    /// A packet was sent
+    #[doc(alias = "tx")]
+    pub const TX: Self = Self { bits: [2] };
+    /// `bit 4` - The `timeout` flag.
+    ///
+    #[doc(alias = "timeout")]
+    pub const TIMEOUT: Self = Self { bits: [16] };
+    /// The set without any flags
+    pub const fn empty() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+    /// The set with all flags
+    pub const fn all() -> Self {
+        Self { bits: [19] }
+    }
+    /// Returns true if no flag is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        (*self & Self::all()) == Self::empty()
+    }
+    /// Returns true if all flags are set
+    #[must_use]
+    pub fn is_all(&self) -> bool {
+        self.contains(Self::all())
+    }
+    /// Returns true if all flags of `other` are set in `self`
+    #[must_use]
+    pub fn contains(&self, other: Self) -> bool {
+        (*self & other) == other
+    }
+    /// Returns true if any flag of `other` is set in `self`
+    #[must_use]
+    pub fn intersects(&self, other: Self) -> bool {
+        !(*self & other).is_empty()
+    }
+    /// Set the flags of `other`
+    pub fn insert(&mut self, other: Self) {
+        *self |= other;
+    }
+    /// Clear the flags of `other`
+    pub fn remove(&mut self, other: Self) {
+        *self &= !other;
+    }
+    /// Flip the flags of `other`
+    pub fn toggle(&mut self, other: Self) {
+        *self ^= other;
+    }
+    /// Iterate over the flags that are set
+    pub fn iter(&self) -> impl Iterator<Item = Self> {
+        let this = *self;
+        [Self::RX, Self::TX, Self::TIMEOUT]
+            .into_iter()
+            .filter(move |flag| this.contains(*flag))
+    }
+    /// `bit 0` - Read the `rx` field.
+    ///
+    /// A packet was received
+    #[must_use]
+    pub fn rx(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 1` - Read the `tx` field.
+    ///
+    /// A packet was sent
+    #[must_use]
+    pub fn tx(&self) -> bool {
+        let start = 1;
+        let end = 1;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 4` - Read the `timeout` field.
+    ///
+    #[must_use]
+    pub fn timeout(&self) -> u8 {
+        let start = 4;
+        let end = 4;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `rx` field.
+    ///
+    /// A packet was received
+    pub fn set_rx(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 1` - Set the `tx` field.
+    ///
+    /// A packet was sent
+    pub fn set_tx(&mut self, value: bool) {
+        let start = 1;
+        let end = 1;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 4` - Set the `timeout` field.
+    ///
+    pub fn set_timeout(&mut self, value: u8) {
+        let start = 4;
+        let end = 4;
+        let raw = value;
+        debug_assert!(
+            (0..= 1).contains(& raw),
+            "value is out of the range `0..=1` of field `timeout`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 4` - Set the `timeout` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=1`.
+    pub fn try_set_timeout(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 4;
+        let end = 4;
+        let raw = value;
+        if !(0..=1).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 1,
+                field: "timeout",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Irq {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Irq {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Irq> for [u8; 1] {
+    fn from(val: Irq) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Irq {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        f.write_str("Irq(")?;
+        let mut separator = "";
+        for (name, flag) in [
+            ("RX", Self::RX),
+            ("TX", Self::TX),
+            ("TIMEOUT", Self::TIMEOUT),
+        ] {
+            if self.contains(flag) {
+                f.write_str(separator)?;
+                f.write_str(name)?;
+                separator = " | ";
+            }
+        }
+        f.write_str(")")
+    }
+}
+impl ::core::iter::FromIterator<Self> for Irq {
+    fn from_iter<T: IntoIterator<Item = Self>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::empty(), |set, flag| set | flag)
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Irq {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Irq {{ ");
+        ::defmt::write!(f, "rx: {=bool}, ", & self.rx());
+        ::defmt::write!(f, "tx: {=bool}, ", & self.tx());
+        ::defmt::write!(f, "timeout: {=u8}, ", & self.timeout());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Irq {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Irq {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Irq {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Irq {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Irq {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Irq {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Irq {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,589 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the FlagsInvalid driver
+#[derive(Debug)]
+pub struct FlagsInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> FlagsInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Interrupts")]
+    pub fn interrupts(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Irq, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Irq::default)
+    }
+}
+impl<I> ::device_driver::Block for FlagsInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `FlagsInvalid`
+pub mod flags_invalid {
+    /// Raw constants of the `interrupts` register
+    pub mod interrupts {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Irq {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Irq {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Irq {
+    /// The mask of the `rx` field when the fieldset is read as one `u16` in LE byte order
+    pub const RX_MASK: u16 = 0x1;
+    /// The shift of the `rx` field when the fieldset is read as one `u16` in LE byte order
+    pub const RX_SHIFT: u32 = 0;
+    /// The mask of the `level` field when the fieldset is read as one `u16` in LE byte order
+    pub const LEVEL_MASK: u16 = 0xE;
+    /// The shift of the `level` field when the fieldset is read as one `u16` in LE byte order
+    pub const LEVEL_SHIFT: u32 = 1;
+    /// The mask of the `signed` field when the fieldset is read as one `u16` in LE byte order
+    pub const SIGNED_MASK: u16 = 0x10;
+    /// The shift of the `signed` field when the fieldset is read as one `u16` in LE byte order
+    pub const SIGNED_SHIFT: u32 = 4;
+    /// The mask of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_MASK: u16 = 0x20;
+    /// The shift of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_SHIFT: u32 = 5;
+    /// The mask of the `coded` field when the fieldset is read as one `u16` in LE byte order
+    pub const CODED_MASK: u16 = 0x40;
+    /// The shift of the `coded` field when the fieldset is read as one `u16` in LE byte order
+    pub const CODED_SHIFT: u32 = 6;
+    /// The mask of the `contains` field when the fieldset is read as one `u16` in LE byte order
+    pub const CONTAINS_MASK: u16 = 0x400;
+    /// The shift of the `contains` field when the fieldset is read as one `u16` in LE byte order
+    pub const CONTAINS_SHIFT: u32 = 10;
+    /// The mask of the `rx_mask` field when the fieldset is read as one `u16` in LE byte order
+    pub const RX_MASK_MASK: u16 = 0x800;
+    /// The shift of the `rx_mask` field when the fieldset is read as one `u16` in LE byte order
+    pub const RX_MASK_SHIFT: u32 = 11;
+    /// `bit 0` - Read the `rx` field.
+    ///
+    #[must_use]
+    pub fn rx(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `3:1` - Read the `level` field.
+    ///
+    #[must_use]
+    pub fn level(&self) -> u8 {
+        let start = 1;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 4` - Read the `signed` field.
+    ///
+    #[must_use]
+    pub fn signed(&self) -> i8 {
+        let start = 4;
+        let end = 4;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 5` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let start = 5;
+        let end = 5;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `bit 6` - Read the `coded` field.
+    ///
+    #[must_use]
+    pub fn coded(&self) -> u8 {
+        let start = 6;
+        let end = 6;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Gray>(raw, 1);
+        raw
+    }
+    /// `bit 7` - Read the `repeated` field.
+    ///
+    #[must_use]
+    pub fn repeated(&self, index: usize) -> bool {
+        assert!(index < 2);
+        let start = 7 + index * 1;
+        let end = start + 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 10` - Read the `contains` field.
+    ///
+    #[must_use]
+    pub fn contains(&self) -> bool {
+        let start = 10;
+        let end = 10;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 11` - Read the `rx_mask` field.
+    ///
+    #[must_use]
+    pub fn rx_mask(&self) -> bool {
+        let start = 11;
+        let end = 11;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `rx` field.
+    ///
+    pub fn set_rx(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `level` field.
+    ///
+    pub fn set_level(&mut self, value: u8) {
+        let start = 1;
+        let end = 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `level`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `level` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_level(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 3;
+        let raw = value;
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "level",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 4` - Set the `signed` field.
+    ///
+    pub fn set_signed(&mut self, value: i8) {
+        let start = 4;
+        let end = 4;
+        let raw = value;
+        debug_assert!(
+            (- 1..= 0).contains(& raw),
+            "value is out of the range `-1..=0` of field `signed`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 4` - Set the `signed` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `-1..=0`.
+    pub fn try_set_signed(
+        &mut self,
+        value: i8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<i8>> {
+        let start = 4;
+        let end = 4;
+        let raw = value;
+        if !(-1..=0).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: -1,
+                max: 0,
+                field: "signed",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 5` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 5;
+        let end = 5;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 1).contains(& raw),
+            "value is out of the range `0..=1` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 5` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=1`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 5;
+        let end = 5;
+        let raw = value.into();
+        if !(0..=1).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 1,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 6` - Set the `coded` field.
+    ///
+    pub fn set_coded(&mut self, value: u8) {
+        let start = 6;
+        let end = 6;
+        let raw = value;
+        debug_assert!(
+            (0..= 1).contains(& raw),
+            "value is out of the range `0..=1` of field `coded`",
+        );
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Gray>(raw, 1);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 6` - Set the `coded` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=1`.
+    pub fn try_set_coded(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 6;
+        let end = 6;
+        let raw = value;
+        if !(0..=1).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 1,
+                field: "coded",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Gray>(raw, 1);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 7` - Set the `repeated` field.
+    ///
+    pub fn set_repeated(&mut self, index: usize, value: bool) {
+        assert!(index < 2);
+        let start = 7 + index * 1;
+        let end = start + 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 10` - Set the `contains` field.
+    ///
+    pub fn set_contains(&mut self, value: bool) {
+        let start = 10;
+        let end = 10;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `bit 11` - Set the `rx_mask` field.
+    ///
+    pub fn set_rx_mask(&mut self, value: bool) {
+        let start = 11;
+        let end = 11;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Irq {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Irq {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Irq> for [u8; 2] {
+    fn from(val: Irq) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Irq {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Irq");
+        d.field("rx", &self.rx());
+        d.field("level", &self.level());
+        d.field("signed", &self.signed());
+        d.field("mode", &self.mode());
+        d.field("coded", &self.coded());
+        d.field("repeated[0]", &self.repeated(0));
+        d.field("repeated[1]", &self.repeated(1));
+        d.field("contains", &self.contains());
+        d.field("rx_mask", &self.rx_mask());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Irq {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Irq {{ ");
+        ::defmt::write!(f, "rx: {=bool}, ", & self.rx());
+        ::defmt::write!(f, "level: {=u8}, ", & self.level());
+        ::defmt::write!(f, "signed: {=i8}, ", & self.signed());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "coded: {=u8}, ", & self.coded());
+        ::defmt::write!(f, "repeated: {=bool}, [0]", & self.repeated(0));
+        ::defmt::write!(f, "repeated: {=bool}, [1]", & self.repeated(1));
+        ::defmt::write!(f, "contains: {=bool}, ", & self.contains());
+        ::defmt::write!(f, "rx_mask: {=bool}, ", & self.rx_mask());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Irq {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Irq {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Irq {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Irq {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Irq {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Irq {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Irq {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    A = 0,
+    B = 1,
+}
+impl ::core::convert::TryFrom<u8> for Mode {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::A),
+            1 => Ok(Self::B),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Mode",
+                })
+            }
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::A => 0,
+            Mode::B => 1,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
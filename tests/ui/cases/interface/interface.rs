@@ -0,0 +1,346 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false, features=["embedded-hal-10"] }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Interfaces driver
+#[derive(Debug)]
+pub struct Interfaces<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Interfaces<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Foo")]
+    pub fn foo(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Foo, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u16, Foo::default)
+    }
+}
+impl<I> ::device_driver::Block for Interfaces<I> {
+    type Interface = I;
+    type RegisterAddressType = u16;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Interfaces`
+pub mod interfaces {
+    /// Raw constants of the `foo` register
+    pub mod foo {
+        /// The address of the register
+        pub const ADDRESS: u16 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// The SPI connection of the device
+///
+/// Register interface over an embedded-hal SPI device:
+/// - Address: 2 byte(s) in BE byte order
+/// - Read bit: `15`
+/// - Increment bit: `14`
+/// - Dummy bytes when reading: `1`
+#[derive(Debug)]
+pub struct Spi<B> {
+    spi: B,
+}
+impl<B> Spi<B> {
+    const FRAMING: ::device_driver::hal::Framing = ::device_driver::hal::Framing {
+        address_size_bytes: 2,
+        address_byte_order: ::device_driver::ByteOrder::BE,
+        read_bit: Some(15),
+        write_bit: None,
+        increment_bit: Some(14),
+        dummy_bytes: 1,
+    };
+    /// Create a new interface over the SPI device
+    pub const fn new(spi: B) -> Self {
+        Self { spi }
+    }
+    /// Drop the interface and reclaim the bus
+    pub fn free(self) -> B {
+        self.spi
+    }
+}
+impl<
+    B: ::device_driver::hal::embedded_hal::spi::ErrorType,
+> ::device_driver::RegisterInterfaceBase for Spi<B> {
+    type Error = B::Error;
+    type AddressType = u16;
+}
+impl<
+    B: ::device_driver::hal::embedded_hal::spi::SpiDevice,
+> ::device_driver::RegisterInterface for Spi<B> {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING.spi_write(&mut self.spi, address as u64, data)
+    }
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING.spi_read(&mut self.spi, address as u64, data)
+    }
+}
+impl<
+    B: ::device_driver::hal::embedded_hal_async::spi::SpiDevice,
+> ::device_driver::AsyncRegisterInterface for Spi<B> {
+    async fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING.spi_write_async(&mut self.spi, address as u64, data).await
+    }
+    async fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING.spi_read_async(&mut self.spi, address as u64, data).await
+    }
+}
+/// Register interface over an embedded-hal I2C bus:
+/// - Address: 1 byte(s) in BE byte order
+#[doc(alias = "I2c")]
+#[derive(Debug)]
+pub struct I2C<B> {
+    i2c: B,
+    address: u8,
+}
+impl<B> I2C<B> {
+    const FRAMING: ::device_driver::hal::Framing = ::device_driver::hal::Framing {
+        address_size_bytes: 1,
+        address_byte_order: ::device_driver::ByteOrder::BE,
+        read_bit: None,
+        write_bit: None,
+        increment_bit: None,
+        dummy_bytes: 0,
+    };
+    /// Create a new interface over the I2C bus for the device with the given 7-bit address
+    pub const fn new(i2c: B, address: u8) -> Self {
+        Self { i2c, address }
+    }
+    /// Drop the interface and reclaim the bus
+    pub fn free(self) -> B {
+        self.i2c
+    }
+}
+impl<
+    B: ::device_driver::hal::embedded_hal::i2c::ErrorType,
+> ::device_driver::RegisterInterfaceBase for I2C<B> {
+    type Error = B::Error;
+    type AddressType = u16;
+}
+impl<B: ::device_driver::hal::embedded_hal::i2c::I2c> ::device_driver::RegisterInterface
+for I2C<B> {
+    fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING.i2c_write(&mut self.i2c, self.address, address as u64, data)
+    }
+    fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING.i2c_read(&mut self.i2c, self.address, address as u64, data)
+    }
+}
+impl<
+    B: ::device_driver::hal::embedded_hal_async::i2c::I2c,
+> ::device_driver::AsyncRegisterInterface for I2C<B> {
+    async fn write_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING
+            .i2c_write_async(&mut self.i2c, self.address, address as u64, data)
+            .await
+    }
+    async fn read_register(
+        &mut self,
+        address: Self::AddressType,
+        data: &mut [u8],
+        _metadata: &::device_driver::FieldsetMetadata,
+    ) -> Result<(), Self::Error> {
+        Self::FRAMING
+            .i2c_read_async(&mut self.i2c, self.address, address as u64, data)
+            .await
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Foo {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Foo {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Foo {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Foo {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Foo {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Foo> for [u8; 1] {
+    fn from(val: Foo) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Foo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Foo");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Foo {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Foo {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Foo {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Foo {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Foo {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Foo {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Foo {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Foo {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Foo {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
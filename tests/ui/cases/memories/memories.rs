@@ -0,0 +1,242 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+#[cfg(all(feature = "chip-a", feature = "chip-b"))]
+compile_error!(
+    "the chip variants `chip-a` and `chip-b` can't be enabled at the same time"
+);
+/// Root block of the Memories driver
+#[derive(Debug)]
+pub struct Memories<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<I> Memories<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// The configuration EEPROM
+    ///
+    /// Memory operation:
+    /// - Address: `0`
+    /// - Size: `256` bytes
+    /// - Page size: `16` bytes
+    #[doc(alias = "Eeprom")]
+    pub fn eeprom(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 256,
+                word_size_bytes: 1,
+                page_size_bytes: Some(16),
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+    /// The program flash, addressed per 16-bit word
+    ///
+    /// Memory operation:
+    /// - Address: `4096`
+    /// - Size: `8192` bytes
+    /// - Word size: `2` bytes
+    /// - Page size: `64` bytes
+    /// - Write granularity: `8` bytes
+    /// - Erase size: `1024` bytes
+    #[doc(alias = "Flash")]
+    pub fn flash(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<
+        '_,
+        Self,
+        u16,
+        ::device_driver::RW,
+        ::device_driver::Erasable,
+    >
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 4096;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 8192,
+                word_size_bytes: 2,
+                page_size_bytes: Some(64),
+                write_granularity_bytes: 8,
+                erase_size_bytes: Some(1024),
+            },
+        )
+    }
+    /// Memory operation:
+    /// - Address: `16384`
+    /// - Size: `32` bytes
+    /// - Chip variants: `chip-a`
+    #[doc(alias = "Otp")]
+    #[cfg(any(feature = "chip-a"))]
+    pub fn otp(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RO, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 16384;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 32,
+                word_size_bytes: 1,
+                page_size_bytes: None,
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+    /// Block operation:
+    /// - Address: `32768`
+    #[doc(alias = "Bank")]
+    pub fn bank(&mut self) -> Bank<'_, I> {
+        let address = self.base_address + 32768;
+        Bank::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+}
+impl<I> ::device_driver::Block for Memories<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+impl<I> ::device_driver::MemoryBlock for Memories<I> {
+    type MemoryAddressType = u16;
+}
+/// Raw constants of the operations of `Memories`
+pub mod memories {
+    /// Raw constants of the `eeprom` memory
+    pub mod eeprom {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 0;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 256;
+        /// The size of the pages of the memory in bytes
+        pub const PAGE_SIZE_BYTES: u32 = 16;
+    }
+    /// Raw constants of the `flash` memory
+    pub mod flash {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 4096;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 8192;
+        /// The size of the pages of the memory in bytes
+        pub const PAGE_SIZE_BYTES: u32 = 64;
+        /// The size of the blocks that are erased at once in bytes
+        pub const ERASE_SIZE_BYTES: u32 = 1024;
+    }
+    /// Raw constants of the `otp` memory
+    #[cfg(any(feature = "chip-a"))]
+    pub mod otp {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 16384;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 32;
+    }
+}
+#[derive(Debug)]
+pub struct Bank<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<'i, I> Bank<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u16) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Memory operation:
+    /// - Address: `0`
+    /// - Size: `2048` bytes
+    #[doc(alias = "Ram")]
+    pub fn ram(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 2048,
+                word_size_bytes: 1,
+                page_size_bytes: None,
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+}
+impl<'i, I> ::device_driver::Block for Bank<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+impl<'i, I> ::device_driver::MemoryBlock for Bank<'i, I> {
+    type MemoryAddressType = u16;
+}
+/// Raw constants of the operations of `Bank`
+///
+/// The addresses are relative to the address of the block.
+pub mod bank {
+    /// Raw constants of the `ram` memory
+    pub mod ram {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 0;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 2048;
+    }
+}
@@ -0,0 +1,112 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the MemoriesInvalid driver
+#[derive(Debug)]
+pub struct MemoriesInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<I> MemoriesInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Memory operation:
+    /// - Address: `1024`
+    /// - Size: `64` bytes
+    #[doc(alias = "Base")]
+    pub fn base(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 1024;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 64,
+                word_size_bytes: 1,
+                page_size_bytes: None,
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+    /// Memory operation:
+    /// - Address: `1072`
+    /// - Size: `16` bytes
+    #[doc(alias = "Overlapping")]
+    pub fn overlapping(
+        &mut self,
+    ) -> ::device_driver::MemoryOperation<'_, Self, u16, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::MemoryInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 1072;
+        ::device_driver::MemoryOperation::new(
+            self,
+            address as u16,
+            ::device_driver::MemoryLayout {
+                size_bytes: 16,
+                word_size_bytes: 1,
+                page_size_bytes: None,
+                write_granularity_bytes: 1,
+                erase_size_bytes: None,
+            },
+        )
+    }
+}
+impl<I> ::device_driver::Block for MemoriesInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+impl<I> ::device_driver::MemoryBlock for MemoriesInvalid<I> {
+    type MemoryAddressType = u16;
+}
+/// Raw constants of the operations of `MemoriesInvalid`
+pub mod memories_invalid {
+    /// Raw constants of the `base` memory
+    pub mod base {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 1024;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 64;
+    }
+    /// Raw constants of the `overlapping` memory
+    pub mod overlapping {
+        /// The address of the first word of the memory
+        pub const ADDRESS: u16 = 1072;
+        /// The size of the memory in bytes
+        pub const SIZE_BYTES: u32 = 16;
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -56,6 +56,18 @@ impl<I> ::device_driver::Block for Foo<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Foo`
+pub mod foo {
+    /// Raw constants of the `bar` register
+    pub mod bar {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
 /// Root block of the FooDup1 driver
 #[doc(alias = "Foo")]
 #[derive(Debug)]
@@ -98,6 +110,18 @@ impl<I> ::device_driver::Block for FooDup1<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `FooDup1`
+pub mod foo_dup_1 {
+    /// Raw constants of the `bar_dup_2` register
+    pub mod bar_dup_2 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
 /// Root block of the Blah driver
 #[derive(Debug)]
 pub struct Blah<I> {
@@ -152,6 +176,14 @@ impl<I> ::device_driver::Block for Blah<I> {
         &mut self.interface
     }
 }
+/// Raw constants of the operations of `Blah`
+pub mod blah {
+    /// Raw constants of the `wheee_dup_5` buffer
+    pub mod wheee_dup_5 {
+        /// The address of the buffer
+        pub const ADDRESS: u8 = 0;
+    }
+}
 #[derive(Debug)]
 pub struct Wheee<'i, I> {
     #[doc(hidden)]
@@ -221,6 +253,10 @@ unsafe impl ::device_driver::Fieldset for Bar {
     const ZERO: Self = Self { bits: [0; 1] };
 }
 impl Bar {
+    /// The mask of the `quux` field when the fieldset is read as one `u8` in LE byte order
+    pub const QUUX_MASK: u8 = 0xFF;
+    /// The shift of the `quux` field when the fieldset is read as one `u8` in LE byte order
+    pub const QUUX_SHIFT: u32 = 0;
     /// `7:0` - Read the `quux` field.
     ///
     #[must_use]
@@ -264,64 +300,64 @@ impl From<Bar> for [u8; 1] {
         val.bits
     }
 }
-impl core::fmt::Debug for Bar {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for Bar {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("Bar");
         d.field("quux", &self.quux());
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for Bar {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "Bar {{ ");
-        defmt::write!(f, "quux: {}, ", & self.quux());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for Bar {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Bar {{ ");
+        ::defmt::write!(f, "quux: {}, ", & self.quux());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for Bar {
+impl ::core::ops::BitAnd for Bar {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for Bar {
+impl ::core::ops::BitAndAssign for Bar {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for Bar {
+impl ::core::ops::BitOr for Bar {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for Bar {
+impl ::core::ops::BitOrAssign for Bar {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for Bar {
+impl ::core::ops::BitXor for Bar {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for Bar {
+impl ::core::ops::BitXorAssign for Bar {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for Bar {
+impl ::core::ops::Not for Bar {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -344,6 +380,10 @@ unsafe impl ::device_driver::Fieldset for BarDup4 {
     const ZERO: Self = Self { bits: [0; 1] };
 }
 impl BarDup4 {
+    /// The mask of the `quux` field when the fieldset is read as one `u8` in LE byte order
+    pub const QUUX_MASK: u8 = 0xFF;
+    /// The shift of the `quux` field when the fieldset is read as one `u8` in LE byte order
+    pub const QUUX_SHIFT: u32 = 0;
     /// `7:0` - Read the `quux` field.
     ///
     #[must_use]
@@ -387,64 +427,64 @@ impl From<BarDup4> for [u8; 1] {
         val.bits
     }
 }
-impl core::fmt::Debug for BarDup4 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for BarDup4 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("BarDup4");
         d.field("quux", &self.quux());
         d.finish()
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for BarDup4 {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "BarDup4 {{ ");
-        defmt::write!(f, "quux: {}, ", & self.quux());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for BarDup4 {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "BarDup4 {{ ");
+        ::defmt::write!(f, "quux: {}, ", & self.quux());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for BarDup4 {
+impl ::core::ops::BitAnd for BarDup4 {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for BarDup4 {
+impl ::core::ops::BitAndAssign for BarDup4 {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for BarDup4 {
+impl ::core::ops::BitOr for BarDup4 {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for BarDup4 {
+impl ::core::ops::BitOrAssign for BarDup4 {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for BarDup4 {
+impl ::core::ops::BitXor for BarDup4 {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for BarDup4 {
+impl ::core::ops::BitXorAssign for BarDup4 {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for BarDup4 {
+impl ::core::ops::Not for BarDup4 {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -456,7 +496,7 @@ impl core::ops::Not for BarDup4 {
 #[doc(alias = "quux")]
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub enum Quux {
     #[doc(alias = "quux")]
     Quux = 0,
@@ -465,7 +505,7 @@ pub enum Quux {
     #[doc(alias = "foo")]
     Foo = 2,
 }
-impl core::convert::TryFrom<u8> for Quux {
+impl ::core::convert::TryFrom<u8> for Quux {
     type Error = ::device_driver::ConversionError<u8>;
     fn try_from(val: u8) -> Result<Self, Self::Error> {
         match val {
@@ -501,7 +541,7 @@ impl ::device_driver::EnumIndex for Quux {
 #[doc(alias = "quux")]
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub enum QuuxDup3 {
     #[doc(alias = "quux")]
     Quux = 0,
@@ -510,7 +550,7 @@ pub enum QuuxDup3 {
     #[doc(alias = "foo")]
     Foo = 2,
 }
-impl core::convert::TryFrom<u8> for QuuxDup3 {
+impl ::core::convert::TryFrom<u8> for QuuxDup3 {
     type Error = ::device_driver::ConversionError<u8>;
     fn try_from(val: u8) -> Result<Self, Self::Error> {
         match val {
@@ -546,11 +586,11 @@ impl ::device_driver::EnumIndex for QuuxDup3 {
 #[doc(alias = "Wheee2")]
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub enum Wheee2Dup6 {
     A = 0,
 }
-impl core::convert::TryFrom<u8> for Wheee2Dup6 {
+impl ::core::convert::TryFrom<u8> for Wheee2Dup6 {
     type Error = ::device_driver::ConversionError<u8>;
     fn try_from(val: u8) -> Result<Self, Self::Error> {
         match val {
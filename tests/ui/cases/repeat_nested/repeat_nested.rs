@@ -0,0 +1,597 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the RepeatNested driver
+#[derive(Debug)]
+pub struct RepeatNested<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u16,
+}
+impl<I> RepeatNested<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// A grid of pixels with a row every 0x20 addresses
+    ///
+    /// Register operation:
+    /// - Address: `256`
+    /// - Reset value: `0`
+    /// - Index range: `(0..16, 0..8)`
+    #[doc(alias = "Pixel")]
+    pub fn pixel(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Color,
+        u16,
+        ::device_driver::RW,
+        ::device_driver::NestedRepeat<
+            ::device_driver::ArrayRepeat<16, 32>,
+            ::device_driver::ArrayRepeat<8, 1>,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 256;
+        ::device_driver::RegisterOperation::new(self, address as u16, Color::default)
+    }
+    /// Register operation:
+    /// - Address: `1024`
+    /// - Reset value: `0`
+    /// - Index range: `(Axis, 0..2)`
+    #[doc(alias = "Gain")]
+    pub fn gain(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Level,
+        u16,
+        ::device_driver::RW,
+        ::device_driver::NestedRepeat<
+            ::device_driver::EnumRepeat<Axis, 16>,
+            ::device_driver::ArrayRepeat<2, 4>,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 1024;
+        ::device_driver::RegisterOperation::new(self, address as u16, Level::default)
+    }
+    /// Register operation:
+    /// - Address: `1280`
+    /// - Reset value: `0`
+    /// - Index range: `(0..4, Axis)`
+    #[doc(alias = "Trim")]
+    pub fn trim(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Level,
+        u16,
+        ::device_driver::RW,
+        ::device_driver::NestedRepeat<
+            ::device_driver::ArrayRepeat<4, 2>,
+            ::device_driver::EnumRepeat<Axis, 64>,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u16>,
+    {
+        let address = self.base_address + 1280;
+        ::device_driver::RegisterOperation::new(self, address as u16, Level::default)
+    }
+}
+impl<I> ::device_driver::Block for RepeatNested<I> {
+    type Interface = I;
+    type RegisterAddressType = u16;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `RepeatNested`
+pub mod repeat_nested {
+    /// Raw constants of the `pixel` register
+    pub mod pixel {
+        /// The address of the register at index 0
+        pub const ADDRESS: u16 = 256;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `gain` register
+    pub mod gain {
+        /// The address of the register at index 0
+        pub const ADDRESS: u16 = 1024;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `trim` register
+    pub mod trim {
+        /// The address of the register at index 0
+        pub const ADDRESS: u16 = 1280;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Level {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Level {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Level {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Level {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Level {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Level> for [u8; 1] {
+    fn from(val: Level) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Level {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Level");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Level {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Level {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Level {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Level {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Level {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Level {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Level {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Level {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Level {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Color {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Color {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Color {
+    /// The mask of the `red` field when the fieldset is read as one `u8` in LE byte order
+    pub const RED_MASK: u8 = 0x7;
+    /// The shift of the `red` field when the fieldset is read as one `u8` in LE byte order
+    pub const RED_SHIFT: u32 = 0;
+    /// The mask of the `green` field when the fieldset is read as one `u8` in LE byte order
+    pub const GREEN_MASK: u8 = 0x38;
+    /// The shift of the `green` field when the fieldset is read as one `u8` in LE byte order
+    pub const GREEN_SHIFT: u32 = 3;
+    /// The mask of the `blue` field when the fieldset is read as one `u8` in LE byte order
+    pub const BLUE_MASK: u8 = 0xC0;
+    /// The shift of the `blue` field when the fieldset is read as one `u8` in LE byte order
+    pub const BLUE_SHIFT: u32 = 6;
+    /// `2:0` - Read the `red` field.
+    ///
+    #[must_use]
+    pub fn red(&self) -> u8 {
+        let start = 0;
+        let end = 2;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `5:3` - Read the `green` field.
+    ///
+    #[must_use]
+    pub fn green(&self) -> u8 {
+        let start = 3;
+        let end = 5;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:6` - Read the `blue` field.
+    ///
+    #[must_use]
+    pub fn blue(&self) -> u8 {
+        let start = 6;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `2:0` - Set the `red` field.
+    ///
+    pub fn set_red(&mut self, value: u8) {
+        let start = 0;
+        let end = 2;
+        let raw = value;
+        debug_assert!(
+            (0..= 7).contains(& raw), "value is out of the range `0..=7` of field `red`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:0` - Set the `red` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_red(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 2;
+        let raw = value;
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "red",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `5:3` - Set the `green` field.
+    ///
+    pub fn set_green(&mut self, value: u8) {
+        let start = 3;
+        let end = 5;
+        let raw = value;
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `green`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `5:3` - Set the `green` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_green(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 3;
+        let end = 5;
+        let raw = value;
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "green",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `7:6` - Set the `blue` field.
+    ///
+    pub fn set_blue(&mut self, value: u8) {
+        let start = 6;
+        let end = 7;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `blue`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:6` - Set the `blue` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_blue(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 6;
+        let end = 7;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "blue",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Color {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Color {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Color> for [u8; 1] {
+    fn from(val: Color) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Color {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Color");
+        d.field("red", &self.red());
+        d.field("green", &self.green());
+        d.field("blue", &self.blue());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Color {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Color {{ ");
+        ::defmt::write!(f, "red: {=u8}, ", & self.red());
+        ::defmt::write!(f, "green: {=u8}, ", & self.green());
+        ::defmt::write!(f, "blue: {=u8}, ", & self.blue());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Color {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Color {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Color {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Color {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Color {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Color {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Color {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Axis {
+    X = 0,
+    Y = 1,
+    Z = 2,
+}
+impl ::core::convert::TryFrom<u8> for Axis {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::X),
+            1 => Ok(Self::Y),
+            2 => Ok(Self::Z),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Axis",
+                })
+            }
+        }
+    }
+}
+impl From<Axis> for u8 {
+    fn from(val: Axis) -> Self {
+        match val {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Axis {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
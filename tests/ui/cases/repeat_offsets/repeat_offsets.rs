@@ -0,0 +1,563 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the RepeatOffsets driver
+#[derive(Debug)]
+pub struct RepeatOffsets<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> RepeatOffsets<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// The channels are spread over the address space
+    ///
+    /// Register operation:
+    /// - Address: `16`
+    /// - Reset value: `0x11`
+    /// - Index range: `0..3`
+    #[doc(alias = "Channel")]
+    pub fn channel(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ChannelConfig,
+        u8,
+        ::device_driver::PerIndex<::device_driver::RW>,
+        ::device_driver::ListRepeat<repeat_offsets::channel::Offsets>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 16;
+        ::device_driver::RegisterOperation::new(
+                self,
+                address as u8,
+                || ChannelConfig::from([17, 0]),
+            )
+            .with_index_overrides({
+                const OVERRIDES: &[::device_driver::IndexOverride<ChannelConfig>] = &[
+                    ::device_driver::IndexOverride {
+                        index: 2,
+                        reset: Some(|| ChannelConfig::from([1, 1])),
+                        readable: true,
+                        writable: false,
+                    },
+                ];
+                OVERRIDES
+            })
+    }
+    /// Register operation:
+    /// - Address: `4`
+    /// - Reset value: `0`
+    #[doc(alias = "Threshold")]
+    pub fn threshold(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Level,
+        u8,
+        ::device_driver::RW,
+        ::device_driver::EnumRepeat<Axis, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::RegisterOperation::new(self, address as u8, Level::default)
+            .with_index_overrides({
+                const OVERRIDES: &[::device_driver::IndexOverride<Level>] = &[
+                    ::device_driver::IndexOverride {
+                        index: 2,
+                        reset: Some(|| Level::from([32])),
+                        readable: true,
+                        writable: true,
+                    },
+                ];
+                OVERRIDES
+            })
+    }
+    /// Command operation:
+    /// - Address: `64`
+    /// - Index range: `0..2`
+    #[doc(alias = "Trigger")]
+    pub fn trigger(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<
+        '_,
+        Self,
+        u8,
+        (),
+        (),
+        ::device_driver::ListRepeat<repeat_offsets::trigger::Offsets>,
+    >
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 64;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Block operation:
+    /// - Address: `0`
+    /// - Index range: `0..2`
+    #[doc(alias = "Bank")]
+    pub fn bank(&mut self, index: usize) -> Bank<'_, I> {
+        let address = self.base_address + 0 + [128, 192][index];
+        Bank::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+}
+impl<I> ::device_driver::Block for RepeatOffsets<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `RepeatOffsets`
+pub mod repeat_offsets {
+    /// Raw constants of the `channel` register
+    pub mod channel {
+        /// The address of the register, which the offsets are relative to
+        pub const ADDRESS: u8 = 16;
+        /// The offsets of the indices from the address
+        pub const OFFSETS: [i32; 3] = [0, 8, 32];
+        /// The repeat of the indices at [`OFFSETS`]
+        #[doc(hidden)]
+        pub struct Offsets;
+        impl ::device_driver::RepeatOffsets for Offsets {
+            const OFFSETS: &'static [i32] = &OFFSETS;
+        }
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x11;
+    }
+    /// Raw constants of the `threshold` register
+    pub mod threshold {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 4;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `trigger` command
+    pub mod trigger {
+        /// The address of the command, which the offsets are relative to
+        pub const ADDRESS: u8 = 64;
+        /// The offsets of the indices from the address
+        pub const OFFSETS: [i32; 2] = [1, 3];
+        /// The repeat of the indices at [`OFFSETS`]
+        #[doc(hidden)]
+        pub struct Offsets;
+        impl ::device_driver::RepeatOffsets for Offsets {
+            const OFFSETS: &'static [i32] = &OFFSETS;
+        }
+    }
+}
+#[derive(Debug)]
+pub struct Bank<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Bank<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Level, u8, ::device_driver::RO, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Level::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Bank<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Bank`
+///
+/// The addresses are relative to the address of the block.
+pub mod bank {
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Level {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Level {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Level {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Level {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Level {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Level> for [u8; 1] {
+    fn from(val: Level) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Level {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Level");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Level {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Level {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Level {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Level {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Level {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Level {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Level {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Level {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Level {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ChannelConfig {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for ChannelConfig {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl ChannelConfig {
+    /// `3:0` - Read the `gain` field.
+    ///
+    #[must_use]
+    pub fn gain(&self, index: usize) -> u8 {
+        let start = 0 + [0, 4, 12][index];
+        let end = start + 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `3:0` - Set the `gain` field.
+    ///
+    pub fn set_gain(&mut self, index: usize, value: u8) {
+        let start = 0 + [0, 4, 12][index];
+        let end = start + 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `gain`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:0` - Set the `gain` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_gain(
+        &mut self,
+        index: usize,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0 + [0, 4, 12][index];
+        let end = start + 3;
+        let raw = value;
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "gain",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for ChannelConfig {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ChannelConfig> for [u8; 2] {
+    fn from(val: ChannelConfig) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ChannelConfig {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ChannelConfig");
+        d.field("gain[0]", &self.gain(0));
+        d.field("gain[1]", &self.gain(1));
+        d.field("gain[2]", &self.gain(2));
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ChannelConfig {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ChannelConfig {{ ");
+        ::defmt::write!(f, "gain: {=u8}, [0]", & self.gain(0));
+        ::defmt::write!(f, "gain: {=u8}, [1]", & self.gain(1));
+        ::defmt::write!(f, "gain: {=u8}, [2]", & self.gain(2));
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ChannelConfig {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ChannelConfig {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ChannelConfig {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ChannelConfig {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ChannelConfig {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ChannelConfig {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ChannelConfig {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Axis {
+    X = 0,
+    Y = 1,
+    Z = 2,
+}
+impl ::core::convert::TryFrom<u8> for Axis {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::X),
+            1 => Ok(Self::Y),
+            2 => Ok(Self::Z),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Axis",
+                })
+            }
+        }
+    }
+}
+impl From<Axis> for u8 {
+    fn from(val: Axis) -> Self {
+        match val {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Axis {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
@@ -0,0 +1,336 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the RepeatOverridesInvalid driver
+#[derive(Debug)]
+pub struct RepeatOverridesInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> RepeatOverridesInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "NotRepeated")]
+    pub fn not_repeated(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Level, u8, ::device_driver::RO, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Level::default)
+    }
+    /// Register operation:
+    /// - Address: `16`
+    /// - Reset value: `0`
+    /// - Index range: `0..4`
+    #[doc(alias = "Counted")]
+    pub fn counted(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Level,
+        u8,
+        ::device_driver::RO,
+        ::device_driver::ArrayRepeat<4, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 16;
+        ::device_driver::RegisterOperation::new(self, address as u8, Level::default)
+            .with_index_overrides({
+                const OVERRIDES: &[::device_driver::IndexOverride<Level>] = &[
+                    ::device_driver::IndexOverride {
+                        index: 1,
+                        reset: Some(|| Level::from([1])),
+                        readable: true,
+                        writable: false,
+                    },
+                    ::device_driver::IndexOverride {
+                        index: 3,
+                        reset: None,
+                        readable: true,
+                        writable: false,
+                    },
+                ];
+                OVERRIDES
+            })
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    /// - Index range: `0..2`
+    #[doc(alias = "Listed")]
+    pub fn listed(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Level,
+        u8,
+        ::device_driver::RO,
+        ::device_driver::ListRepeat<repeat_overrides_invalid::listed::Offsets>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Level::default)
+    }
+    /// Register operation:
+    /// - Address: `64`
+    /// - Reset value: `0`
+    #[doc(alias = "Enumerated")]
+    pub fn enumerated(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Level,
+        u8,
+        ::device_driver::RO,
+        ::device_driver::EnumRepeat<Axis, 1>,
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 64;
+        ::device_driver::RegisterOperation::new(self, address as u8, Level::default)
+    }
+}
+impl<I> ::device_driver::Block for RepeatOverridesInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `RepeatOverridesInvalid`
+pub mod repeat_overrides_invalid {
+    /// Raw constants of the `not_repeated` register
+    pub mod not_repeated {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `counted` register
+    pub mod counted {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 16;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `listed` register
+    pub mod listed {
+        /// The address of the register, which the offsets are relative to
+        pub const ADDRESS: u8 = 0;
+        /// The offsets of the indices from the address
+        pub const OFFSETS: [i32; 2] = [32, 48];
+        /// The repeat of the indices at [`OFFSETS`]
+        #[doc(hidden)]
+        pub struct Offsets;
+        impl ::device_driver::RepeatOffsets for Offsets {
+            const OFFSETS: &'static [i32] = &OFFSETS;
+        }
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `enumerated` register
+    pub mod enumerated {
+        /// The address of the register at index 0
+        pub const ADDRESS: u8 = 64;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Level {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Level {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Level {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+}
+impl Default for Level {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Level {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Level> for [u8; 1] {
+    fn from(val: Level) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Level {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Level");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Level {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Level {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Level {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Level {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Level {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Level {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Level {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Level {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Level {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Axis {
+    X = 0,
+    Y = 1,
+}
+impl ::core::convert::TryFrom<u8> for Axis {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::X),
+            1 => Ok(Self::Y),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Axis",
+                })
+            }
+        }
+    }
+}
+impl From<Axis> for u8 {
+    fn from(val: Axis) -> Self {
+        match val {
+            Axis::X => 0,
+            Axis::Y => 1,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Axis {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -163,12 +163,12 @@ impl<'i, I> ::device_driver::Block for Quux<'i, I> {
 #[doc(alias = "too_big")]
 #[repr(u32)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub enum TooBig {
     JustOk = 2147483647,
     TooBig = 2147483648,
 }
-impl core::convert::TryFrom<u32> for TooBig {
+impl ::core::convert::TryFrom<u32> for TooBig {
     type Error = ::device_driver::ConversionError<u32>;
     fn try_from(val: u32) -> Result<Self, Self::Error> {
         match val {
@@ -202,7 +202,7 @@ impl ::device_driver::EnumIndex for TooBig {
 #[doc(alias = "small")]
 #[repr(i8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub enum Small {
     #[doc(alias = "a")]
     A = -3,
@@ -213,7 +213,7 @@ pub enum Small {
     #[doc(alias = "d")]
     D = 0,
 }
-impl core::convert::TryFrom<i8> for Small {
+impl ::core::convert::TryFrom<i8> for Small {
     type Error = ::device_driver::ConversionError<i8>;
     fn try_from(val: i8) -> Result<Self, Self::Error> {
         match val {
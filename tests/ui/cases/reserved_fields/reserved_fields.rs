@@ -0,0 +1,546 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Reserved driver
+#[derive(Debug)]
+pub struct Reserved<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Reserved<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0x80`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ConfigFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ConfigFields::from([128]),
+        )
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Control")]
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ControlFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            ControlFields::default,
+        )
+    }
+}
+impl<I> ::device_driver::Block for Reserved<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Reserved`
+pub mod reserved {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x80;
+    }
+    /// Raw constants of the `control` register
+    pub mod control {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in BE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ControlFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for ControlFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::BE);
+    const ZERO: Self = Self { bits: [0; 2] };
+    fn apply_reserved(&mut self) {
+        {
+            let start = 15;
+            let end = 15;
+            let raw: u8 = 1;
+            unsafe {
+                ::device_driver::ops::store::<
+                    u8,
+                    ::device_driver::ops::BE,
+                >(raw, start, end, &mut self.bits)
+            };
+        }
+        {
+            let start = 8;
+            let end = 11;
+            let raw: i8 = -2;
+            unsafe {
+                ::device_driver::ops::store::<
+                    i8,
+                    ::device_driver::ops::BE,
+                >(raw, start, end, &mut self.bits)
+            };
+        }
+    }
+}
+impl ControlFields {
+    /// The mask of the `start` field when the fieldset is read as one `u16` in BE byte order
+    pub const START_MASK: u16 = 0x1;
+    /// The shift of the `start` field when the fieldset is read as one `u16` in BE byte order
+    pub const START_SHIFT: u32 = 0;
+    /// The mask of the `always_set` field when the fieldset is read as one `u16` in BE byte order
+    pub const ALWAYS_SET_MASK: u16 = 0x8000;
+    /// The shift of the `always_set` field when the fieldset is read as one `u16` in BE byte order
+    pub const ALWAYS_SET_SHIFT: u32 = 15;
+    /// The mask of the `offset` field when the fieldset is read as one `u16` in BE byte order
+    pub const OFFSET_MASK: u16 = 0xF00;
+    /// The shift of the `offset` field when the fieldset is read as one `u16` in BE byte order
+    pub const OFFSET_SHIFT: u32 = 8;
+    /// `bit 0` - Read the `start` field.
+    ///
+    #[must_use]
+    pub fn start(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 15` - Read the `always_set` field.
+    ///
+    /// Reserved, always written as `true`.
+    #[must_use]
+    pub fn always_set(&self) -> bool {
+        let start = 15;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `11:8` - Read the `offset` field.
+    ///
+    /// Reserved, always written as `-2`.
+    #[must_use]
+    pub fn offset(&self) -> i8 {
+        let start = 8;
+        let end = 11;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::BE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `start` field.
+    ///
+    pub fn set_start(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for ControlFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for ControlFields {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ControlFields> for [u8; 2] {
+    fn from(val: ControlFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ControlFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ControlFields");
+        d.field("start", &self.start());
+        let value = self.always_set();
+        if value {
+            d.field("always_set", &value);
+        } else {
+            d.field("always_set", &format_args!("{value:?} (reserved, must be true)"));
+        }
+        let value = self.offset();
+        if value == -2 {
+            d.field("offset", &value);
+        } else {
+            d.field("offset", &format_args!("{value:?} (reserved, must be -2)"));
+        }
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ControlFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ControlFields {{ ");
+        ::defmt::write!(f, "start: {=bool}, ", & self.start());
+        ::defmt::write!(f, "always_set: {=bool}, ", & self.always_set());
+        ::defmt::write!(f, "offset: {=i8}, ", & self.offset());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ControlFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ControlFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ControlFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ControlFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ControlFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ControlFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ControlFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ConfigFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for ConfigFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+    fn apply_reserved(&mut self) {
+        {
+            let start = 6;
+            let end = 7;
+            let raw: u8 = 2;
+            unsafe {
+                ::device_driver::ops::store::<
+                    u8,
+                    ::device_driver::ops::LE,
+                >(raw, start, end, &mut self.bits)
+            };
+        }
+    }
+}
+impl ConfigFields {
+    /// The mask of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_MASK: u8 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0xE;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// The mask of the `reserved` field when the fieldset is read as one `u8` in LE byte order
+    pub const RESERVED_MASK: u8 = 0xC0;
+    /// The shift of the `reserved` field when the fieldset is read as one `u8` in LE byte order
+    pub const RESERVED_SHIFT: u32 = 6;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `3:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> u8 {
+        let start = 1;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:6` - Read the `reserved` field.
+    ///
+    /// Must be written as 0b10
+    ///
+    /// Reserved, always written as `2`.
+    #[must_use]
+    pub fn reserved(&self) -> u8 {
+        let start = 6;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: u8) {
+        let start = 1;
+        let end = 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_mode(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 3;
+        let raw = value;
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for ConfigFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for ConfigFields {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ConfigFields> for [u8; 1] {
+    fn from(val: ConfigFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ConfigFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ConfigFields");
+        d.field("enable", &self.enable());
+        d.field("mode", &self.mode());
+        let value = self.reserved();
+        if value == 2 {
+            d.field("reserved", &value);
+        } else {
+            d.field("reserved", &format_args!("{value:?} (reserved, must be 2)"));
+        }
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ConfigFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ConfigFields {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "mode: {=u8}, ", & self.mode());
+        ::defmt::write!(f, "reserved: {=u8}, ", & self.reserved());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ConfigFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ConfigFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ConfigFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ConfigFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ConfigFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ConfigFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ConfigFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,477 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the ReservedInvalid driver
+#[derive(Debug)]
+pub struct ReservedInvalid<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> ReservedInvalid<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0x40`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ConfigFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ConfigFields::from([64, 0, 0, 0]),
+        )
+    }
+}
+impl<I> ::device_driver::Block for ReservedInvalid<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `ReservedInvalid`
+pub mod reserved_invalid {
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x40;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ConfigFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 4],
+}
+unsafe impl ::device_driver::Fieldset for ConfigFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 4] };
+    fn apply_reserved(&mut self) {
+        {
+            let start = 6;
+            let end = 7;
+            let raw: u8 = 3;
+            unsafe {
+                ::device_driver::ops::store::<
+                    u8,
+                    ::device_driver::ops::LE,
+                >(raw, start, end, &mut self.bits)
+            };
+        }
+    }
+}
+impl ConfigFields {
+    /// The mask of the `enable` field when the fieldset is read as one `u32` in LE byte order
+    pub const ENABLE_MASK: u32 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u32` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u32` in LE byte order
+    pub const MODE_MASK: u32 = 0xE;
+    /// The shift of the `mode` field when the fieldset is read as one `u32` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// The mask of the `too_big` field when the fieldset is read as one `u32` in LE byte order
+    pub const TOO_BIG_MASK: u32 = 0x30;
+    /// The shift of the `too_big` field when the fieldset is read as one `u32` in LE byte order
+    pub const TOO_BIG_SHIFT: u32 = 4;
+    /// The mask of the `must_be_one` field when the fieldset is read as one `u32` in LE byte order
+    pub const MUST_BE_ONE_MASK: u32 = 0xC0;
+    /// The shift of the `must_be_one` field when the fieldset is read as one `u32` in LE byte order
+    pub const MUST_BE_ONE_SHIFT: u32 = 6;
+    /// The mask of the `id` field when the fieldset is read as one `u32` in LE byte order
+    pub const ID_MASK: u32 = 0xFFFF0000;
+    /// The shift of the `id` field when the fieldset is read as one `u32` in LE byte order
+    pub const ID_SHIFT: u32 = 16;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `3:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let start = 1;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw.into()
+    }
+    /// `5:4` - Read the `too_big` field.
+    ///
+    #[must_use]
+    pub fn too_big(&self) -> u8 {
+        let start = 4;
+        let end = 5;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:6` - Read the `must_be_one` field.
+    ///
+    /// Reserved, always written as `3`.
+    #[must_use]
+    pub fn must_be_one(&self) -> u8 {
+        let start = 6;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `bit 8` - Read the `channels` field.
+    ///
+    #[must_use]
+    pub fn channels(&self, index: usize) -> bool {
+        assert!(index < 2);
+        let start = 8 + index * 1;
+        let end = start + 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `31:16` - Read the `id` field.
+    ///
+    #[must_use]
+    pub fn id(&self) -> [u8; 2] {
+        let start = 16;
+        let end = 31;
+        let raw = unsafe {
+            ::device_driver::ops::load_bytes::<
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 1;
+        let end = 3;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 3;
+        let raw = value.into();
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `5:4` - Set the `too_big` field.
+    ///
+    pub fn set_too_big(&mut self, value: u8) {
+        let start = 4;
+        let end = 5;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `too_big`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `5:4` - Set the `too_big` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_too_big(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 4;
+        let end = 5;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "too_big",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `bit 8` - Set the `channels` field.
+    ///
+    pub fn set_channels(&mut self, index: usize, value: bool) {
+        assert!(index < 2);
+        let start = 8 + index * 1;
+        let end = start + 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `31:16` - Set the `id` field.
+    ///
+    pub fn set_id(&mut self, value: [u8; 2]) {
+        let start = 16;
+        let end = 31;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store_bytes::<
+                ::device_driver::ops::LE,
+                2,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for ConfigFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 4]> for ConfigFields {
+    fn from(bits: [u8; 4]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ConfigFields> for [u8; 4] {
+    fn from(val: ConfigFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ConfigFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ConfigFields");
+        d.field("enable", &self.enable());
+        d.field("mode", &self.mode());
+        d.field("too_big", &self.too_big());
+        let value = self.must_be_one();
+        if value == 3 {
+            d.field("must_be_one", &value);
+        } else {
+            d.field("must_be_one", &format_args!("{value:?} (reserved, must be 3)"));
+        }
+        d.field("channels[0]", &self.channels(0));
+        d.field("channels[1]", &self.channels(1));
+        d.field("id", &self.id());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ConfigFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ConfigFields {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "too_big: {=u8}, ", & self.too_big());
+        ::defmt::write!(f, "must_be_one: {=u8}, ", & self.must_be_one());
+        ::defmt::write!(f, "channels: {=bool}, [0]", & self.channels(0));
+        ::defmt::write!(f, "channels: {=bool}, [1]", & self.channels(1));
+        ::defmt::write!(f, "id: {}, ", & self.id());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ConfigFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ConfigFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ConfigFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ConfigFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ConfigFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ConfigFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ConfigFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    Other = 2,
+}
+impl Default for Mode {
+    fn default() -> Self {
+        Self::Other
+    }
+}
+impl From<u8> for Mode {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => Self::A,
+            1 => Self::B,
+            _ => Self::default(),
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::A => 0,
+            Mode::B => 1,
+            Mode::Other => 2,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
+compile_error!("The device driver input has errors that need to be solved!");
@@ -56,6 +56,22 @@ unsafe impl ::device_driver::Fieldset for Bar {
     const ZERO: Self = Self { bits: [0; 1] };
 }
 impl Bar {
+    /// The mask of the `my_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const MY_FIELD_MASK: u8 = 0x1;
+    /// The shift of the `my_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const MY_FIELD_SHIFT: u32 = 0;
+    /// The mask of the `set_my_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const SET_MY_FIELD_MASK: u8 = 0x2;
+    /// The shift of the `set_my_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const SET_MY_FIELD_SHIFT: u32 = 1;
+    /// The mask of the `other_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const OTHER_FIELD_MASK: u8 = 0x4;
+    /// The shift of the `other_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const OTHER_FIELD_SHIFT: u32 = 2;
+    /// The mask of the `set_other_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const SET_OTHER_FIELD_MASK: u8 = 0x8;
+    /// The shift of the `set_other_field` field when the fieldset is read as one `u8` in LE byte order
+    pub const SET_OTHER_FIELD_SHIFT: u32 = 3;
     /// `bit 0` - Read the `my_field` field.
     ///
     #[must_use]
@@ -154,8 +170,8 @@ impl From<Bar> for [u8; 1] {
         val.bits
     }
 }
-impl core::fmt::Debug for Bar {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+impl ::core::fmt::Debug for Bar {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
         let mut d = f.debug_struct("Bar");
         d.field("my_field", &self.my_field());
         d.field("set_my_field", &self.set_my_field());
@@ -165,59 +181,59 @@ impl core::fmt::Debug for Bar {
     }
 }
 #[cfg(feature = "defmt")]
-impl defmt::Format for Bar {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "Bar {{ ");
-        defmt::write!(f, "my_field: {=bool}, ", & self.my_field());
-        defmt::write!(f, "set_my_field: {=bool}, ", & self.set_my_field());
-        defmt::write!(f, "other_field: {=bool}, ", & self.other_field());
-        defmt::write!(f, "set_other_field: {=bool}, ", & self.set_other_field());
-        defmt::write!(f, "}}");
+impl ::defmt::Format for Bar {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Bar {{ ");
+        ::defmt::write!(f, "my_field: {=bool}, ", & self.my_field());
+        ::defmt::write!(f, "set_my_field: {=bool}, ", & self.set_my_field());
+        ::defmt::write!(f, "other_field: {=bool}, ", & self.other_field());
+        ::defmt::write!(f, "set_other_field: {=bool}, ", & self.set_other_field());
+        ::defmt::write!(f, "}}");
     }
 }
-impl core::ops::BitAnd for Bar {
+impl ::core::ops::BitAnd for Bar {
     type Output = Self;
     fn bitand(mut self, rhs: Self) -> Self::Output {
         self &= rhs;
         self
     }
 }
-impl core::ops::BitAndAssign for Bar {
+impl ::core::ops::BitAndAssign for Bar {
     fn bitand_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l &= *r;
         }
     }
 }
-impl core::ops::BitOr for Bar {
+impl ::core::ops::BitOr for Bar {
     type Output = Self;
     fn bitor(mut self, rhs: Self) -> Self::Output {
         self |= rhs;
         self
     }
 }
-impl core::ops::BitOrAssign for Bar {
+impl ::core::ops::BitOrAssign for Bar {
     fn bitor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l |= *r;
         }
     }
 }
-impl core::ops::BitXor for Bar {
+impl ::core::ops::BitXor for Bar {
     type Output = Self;
     fn bitxor(mut self, rhs: Self) -> Self::Output {
         self ^= rhs;
         self
     }
 }
-impl core::ops::BitXorAssign for Bar {
+impl ::core::ops::BitXorAssign for Bar {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
             *l ^= *r;
         }
     }
 }
-impl core::ops::Not for Bar {
+impl ::core::ops::Not for Bar {
     type Output = Self;
     fn not(mut self) -> Self::Output {
         for val in self.bits.iter_mut() {
@@ -0,0 +1,1093 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false, features=["embedded-hal-10"] }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Routines driver
+#[derive(Debug)]
+pub struct Routines<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Routines<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Control")]
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    #[doc(alias = "Config")]
+    pub fn config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Config,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, Config::default)
+    }
+    /// Command operation:
+    /// - Address: `3`
+    #[doc(alias = "Sync")]
+    pub fn sync(&mut self) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 3;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Command operation:
+    /// - Address: `4`
+    #[doc(alias = "SetThreshold")]
+    pub fn set_threshold(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Self, u8, SetThreshold, (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 4;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Reset the device and wait until it's ready
+    ///
+    /// Routine:
+    /// - Write [`control`](Self::control): `reset = true`
+    /// - Delay `10` ms
+    /// - Poll [`status`](Self::status) until `ready == true`, `mode == Mode::Idle`, at most `51` time(s) with `100` us in between
+    /// - Modify [`config`](Self::config): `gain = 3`, `mode = Mode::Active`
+    /// - Dispatch [`sync`](Self::sync)
+    /// - Dispatch [`set_threshold`](Self::set_threshold): `threshold = -5`
+    /// - Return [`status`](Self::status)
+    pub fn initialize(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        Status,
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>
+            + ::device_driver::CommandInterface<
+                AddressType = u8,
+                Error = <I as ::device_driver::RegisterInterfaceBase>::Error,
+            >,
+    {
+        self.control()
+            .write(|fields| {
+                fields.set_reset(true);
+            })?;
+        delay.delay_ms(10);
+        {
+            let mut attempts_left: u32 = 51;
+            loop {
+                let reg = self.status().read()?;
+                if reg.ready() && matches!(reg.mode(), Mode::Idle) {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(100);
+            }
+        }
+        self.config()
+            .modify(|fields| {
+                fields.set_gain(3);
+                fields.set_mode(Mode::Active);
+            })?;
+        self.sync().dispatch()?;
+        self.set_threshold()
+            .dispatch_in(|fields| {
+                fields.set_threshold(-5);
+            })?;
+        Ok(self.status().read()?)
+    }
+    /// Async version of [`initialize`](Self::initialize).
+    ///
+    /// Reset the device and wait until it's ready
+    ///
+    /// Routine:
+    /// - Write [`control`](Self::control): `reset = true`
+    /// - Delay `10` ms
+    /// - Poll [`status`](Self::status) until `ready == true`, `mode == Mode::Idle`, at most `51` time(s) with `100` us in between
+    /// - Modify [`config`](Self::config): `gain = 3`, `mode = Mode::Active`
+    /// - Dispatch [`sync`](Self::sync)
+    /// - Dispatch [`set_threshold`](Self::set_threshold): `threshold = -5`
+    /// - Return [`status`](Self::status)
+    pub async fn initialize_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        Status,
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>
+            + ::device_driver::AsyncCommandInterface<
+                AddressType = u8,
+                Error = <I as ::device_driver::RegisterInterfaceBase>::Error,
+            >,
+    {
+        self.control()
+            .write_async(|fields| {
+                fields.set_reset(true);
+            })
+            .await?;
+        delay.delay_ms(10).await;
+        {
+            let mut attempts_left: u32 = 51;
+            loop {
+                let reg = self.status().read_async().await?;
+                if reg.ready() && matches!(reg.mode(), Mode::Idle) {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(100).await;
+            }
+        }
+        self.config()
+            .modify_async(|fields| {
+                fields.set_gain(3);
+                fields.set_mode(Mode::Active);
+            })
+            .await?;
+        self.sync().dispatch_async().await?;
+        self.set_threshold()
+            .dispatch_in_async(|fields| {
+                fields.set_threshold(-5);
+            })
+            .await?;
+        Ok(self.status().read_async().await?)
+    }
+    /// Routine:
+    /// - Dispatch [`sync`](Self::sync)
+    pub fn power_down(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::CommandInterfaceBase>::Error>
+    where
+        I: ::device_driver::CommandInterface<AddressType = u8>,
+    {
+        self.sync().dispatch()?;
+        Ok(())
+    }
+    /// Async version of [`power_down`](Self::power_down).
+    ///
+    /// Routine:
+    /// - Dispatch [`sync`](Self::sync)
+    pub async fn power_down_async(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::CommandInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncCommandInterface<AddressType = u8>,
+    {
+        self.sync().dispatch_async().await?;
+        Ok(())
+    }
+    /// Routine:
+    /// - Write [`control`](Self::control)
+    /// - Modify [`config`](Self::config): `mode = Mode::Active`
+    /// - Poll [`config`](Self::config) until `mode == Mode::Active`, at most `3` time(s) with `1` ms in between
+    pub fn wake(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.control().write(|_| {})?;
+        self.config()
+            .modify(|fields| {
+                fields.set_mode(Mode::Active);
+            })?;
+        {
+            let mut attempts_left: u32 = 3;
+            loop {
+                let reg = self.config().read()?;
+                if matches!(reg.mode(), Ok(Mode::Active)) {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_ms(1);
+            }
+        }
+        Ok(())
+    }
+    /// Async version of [`wake`](Self::wake).
+    ///
+    /// Routine:
+    /// - Write [`control`](Self::control)
+    /// - Modify [`config`](Self::config): `mode = Mode::Active`
+    /// - Poll [`config`](Self::config) until `mode == Mode::Active`, at most `3` time(s) with `1` ms in between
+    pub async fn wake_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.control().write_async(|_| {}).await?;
+        self.config()
+            .modify_async(|fields| {
+                fields.set_mode(Mode::Active);
+            })
+            .await?;
+        {
+            let mut attempts_left: u32 = 3;
+            loop {
+                let reg = self.config().read_async().await?;
+                if matches!(reg.mode(), Ok(Mode::Active)) {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_ms(1).await;
+            }
+        }
+        Ok(())
+    }
+}
+impl<I> ::device_driver::Block for Routines<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Routines`
+pub mod routines {
+    /// Raw constants of the `control` register
+    pub mod control {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `config` register
+    pub mod config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `sync` command
+    pub mod sync {
+        /// The address of the command
+        pub const ADDRESS: u8 = 3;
+    }
+    /// Raw constants of the `set_threshold` command
+    pub mod set_threshold {
+        /// The address of the command
+        pub const ADDRESS: u8 = 4;
+        /// The size of the input of the command in bytes
+        pub const SIZE_BYTES_IN: usize = 1;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct SetThreshold {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for SetThreshold {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl SetThreshold {
+    /// The mask of the `threshold` field when the fieldset is read as one `u8` in LE byte order
+    pub const THRESHOLD_MASK: u8 = 0xFF;
+    /// The shift of the `threshold` field when the fieldset is read as one `u8` in LE byte order
+    pub const THRESHOLD_SHIFT: u32 = 0;
+    /// `7:0` - Read the `threshold` field.
+    ///
+    #[must_use]
+    pub fn threshold(&self) -> i8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `threshold` field.
+    ///
+    pub fn set_threshold(&mut self, value: i8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for SetThreshold {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for SetThreshold {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<SetThreshold> for [u8; 1] {
+    fn from(val: SetThreshold) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for SetThreshold {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("SetThreshold");
+        d.field("threshold", &self.threshold());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for SetThreshold {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "SetThreshold {{ ");
+        ::defmt::write!(f, "threshold: {=i8}, ", & self.threshold());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for SetThreshold {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for SetThreshold {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for SetThreshold {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for SetThreshold {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for SetThreshold {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for SetThreshold {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for SetThreshold {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Config {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Config {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Config {
+    /// The mask of the `gain` field when the fieldset is read as one `u8` in LE byte order
+    pub const GAIN_MASK: u8 = 0xF;
+    /// The shift of the `gain` field when the fieldset is read as one `u8` in LE byte order
+    pub const GAIN_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0x30;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 4;
+    /// `3:0` - Read the `gain` field.
+    ///
+    #[must_use]
+    pub fn gain(&self) -> u8 {
+        let start = 0;
+        let end = 3;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `5:4` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Result<Mode, <Mode as TryFrom<u8>>::Error> {
+        let start = 4;
+        let end = 5;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw.try_into()
+    }
+    /// `3:0` - Set the `gain` field.
+    ///
+    pub fn set_gain(&mut self, value: u8) {
+        let start = 0;
+        let end = 3;
+        let raw = value;
+        debug_assert!(
+            (0..= 15).contains(& raw),
+            "value is out of the range `0..=15` of field `gain`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `3:0` - Set the `gain` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=15`.
+    pub fn try_set_gain(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 3;
+        let raw = value;
+        if !(0..=15).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 15,
+                field: "gain",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `5:4` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 4;
+        let end = 5;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `5:4` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 4;
+        let end = 5;
+        let raw = value.into();
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Config {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Config {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Config> for [u8; 1] {
+    fn from(val: Config) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Config {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Config");
+        d.field("gain", &self.gain());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Config {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Config {{ ");
+        ::defmt::write!(f, "gain: {=u8}, ", & self.gain());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Config {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Config {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Config {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Config {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Config {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Config {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Config {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0x6;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 1;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `2:1` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let start = 1;
+        let end = 2;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        unsafe { raw.try_into().unwrap_unchecked() }
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let start = 1;
+        let end = 2;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 2;
+        let raw = value.into();
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Control {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Control {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Control {
+    /// The mask of the `reset` field when the fieldset is read as one `u8` in LE byte order
+    pub const RESET_MASK: u8 = 0x1;
+    /// The shift of the `reset` field when the fieldset is read as one `u8` in LE byte order
+    pub const RESET_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `reset` field.
+    ///
+    #[must_use]
+    pub fn reset(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `reset` field.
+    ///
+    pub fn set_reset(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Control {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Control {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Control> for [u8; 1] {
+    fn from(val: Control) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Control {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Control");
+        d.field("reset", &self.reset());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Control {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Control {{ ");
+        ::defmt::write!(f, "reset: {=bool}, ", & self.reset());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Control {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Control {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Control {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Control {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Control {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Control {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Control {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Idle = 0,
+    Active = 1,
+    Sleep = 2,
+    Fault = 3,
+}
+impl ::core::convert::TryFrom<u8> for Mode {
+    type Error = ::device_driver::ConversionError<u8>;
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(Self::Idle),
+            1 => Ok(Self::Active),
+            2 => Ok(Self::Sleep),
+            3 => Ok(Self::Fault),
+            val => {
+                Err(::device_driver::ConversionError {
+                    source: val,
+                    target: "Mode",
+                })
+            }
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Idle => 0,
+            Mode::Active => 1,
+            Mode::Sleep => 2,
+            Mode::Fault => 3,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
@@ -0,0 +1,403 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false, features=["embedded-hal-10"] }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the PolledDevice driver
+#[derive(Debug)]
+pub struct PolledDevice<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> PolledDevice<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Gives up after the amount of reads
+    ///
+    /// Routine:
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `20` time(s) with `50` us in between
+    pub fn wait_attempts(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        {
+            let mut attempts_left: u32 = 20;
+            loop {
+                let reg = self.status().read()?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(50);
+            }
+        }
+        Ok(())
+    }
+    /// Async version of [`wait_attempts`](Self::wait_attempts).
+    ///
+    /// Gives up after the amount of reads
+    ///
+    /// Routine:
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `20` time(s) with `50` us in between
+    pub async fn wait_attempts_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        {
+            let mut attempts_left: u32 = 20;
+            loop {
+                let reg = self.status().read_async().await?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(50).await;
+            }
+        }
+        Ok(())
+    }
+    /// Gives up after the time has passed
+    ///
+    /// Routine:
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `9` time(s) with `250` us in between
+    pub fn wait_timeout(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        {
+            let mut attempts_left: u32 = 9;
+            loop {
+                let reg = self.status().read()?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(250);
+            }
+        }
+        Ok(())
+    }
+    /// Async version of [`wait_timeout`](Self::wait_timeout).
+    ///
+    /// Gives up after the time has passed
+    ///
+    /// Routine:
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `9` time(s) with `250` us in between
+    pub async fn wait_timeout_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        {
+            let mut attempts_left: u32 = 9;
+            loop {
+                let reg = self.status().read_async().await?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(250).await;
+            }
+        }
+        Ok(())
+    }
+    /// The lowest of the two limits is used
+    ///
+    /// Routine:
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `4` time(s) with `1` ms in between
+    /// - Return [`status`](Self::status)
+    pub fn wait_both(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        Status,
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        {
+            let mut attempts_left: u32 = 4;
+            loop {
+                let reg = self.status().read()?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_ms(1);
+            }
+        }
+        Ok(self.status().read()?)
+    }
+    /// Async version of [`wait_both`](Self::wait_both).
+    ///
+    /// The lowest of the two limits is used
+    ///
+    /// Routine:
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `4` time(s) with `1` ms in between
+    /// - Return [`status`](Self::status)
+    pub async fn wait_both_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        Status,
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        {
+            let mut attempts_left: u32 = 4;
+            loop {
+                let reg = self.status().read_async().await?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_ms(1).await;
+            }
+        }
+        Ok(self.status().read_async().await?)
+    }
+}
+impl<I> ::device_driver::Block for PolledDevice<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `PolledDevice`
+pub mod polled_device {
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
@@ -0,0 +1,664 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the SplitFields driver
+#[derive(Debug)]
+pub struct SplitFields<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> SplitFields<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `[0xC3, 0x00, 0x02, 0x01]` (from the field resets)
+    #[doc(alias = "Thresholds")]
+    pub fn thresholds(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ThresholdFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ThresholdFields::from([195, 0, 2, 1]),
+        )
+    }
+    /// Register operation:
+    /// - Address: `3`
+    /// - Reset value: `0`
+    #[doc(alias = "Time")]
+    pub fn time(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        TimeFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 3;
+        ::device_driver::RegisterOperation::new(self, address as u8, TimeFields::default)
+    }
+}
+impl<I> ::device_driver::Block for SplitFields<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `SplitFields`
+pub mod split_fields {
+    /// Raw constants of the `thresholds` register
+    pub mod thresholds {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 4;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u32 = 0x10200C3;
+    }
+    /// Raw constants of the `time` register
+    pub mod time {
+        /// The address of the register
+        pub const ADDRESS: u8 = 3;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in BE byte order
+        pub const RESET: u16 = 0x0;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct TimeFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for TimeFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::BE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl TimeFields {
+    /// `15:12, 3:0` - Read the `minutes` field.
+    ///
+    #[must_use]
+    pub fn minutes(&self) -> u8 {
+        let parts = [(12, 15), (0, 3)];
+        let raw = unsafe {
+            ::device_driver::ops::load_split::<
+                u8,
+                ::device_driver::ops::BE,
+            >(&self.bits, &parts)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Bcd>(raw, 8);
+        raw
+    }
+    /// `15:12, 3:0` - Set the `minutes` field.
+    ///
+    pub fn set_minutes(&mut self, value: u8) {
+        let parts = [(12, 15), (0, 3)];
+        let raw = value;
+        debug_assert!(
+            (0..= 99).contains(& raw),
+            "value is out of the range `0..=99` of field `minutes`",
+        );
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 8);
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, &parts, &mut self.bits)
+        };
+    }
+    /// `15:12, 3:0` - Set the `minutes` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=99`.
+    pub fn try_set_minutes(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let parts = [(12, 15), (0, 3)];
+        let raw = value;
+        if !(0..=99).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 99,
+                field: "minutes",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 8);
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u8,
+                ::device_driver::ops::BE,
+            >(raw, &parts, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for TimeFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for TimeFields {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<TimeFields> for [u8; 2] {
+    fn from(val: TimeFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for TimeFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("TimeFields");
+        d.field("minutes", &self.minutes());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for TimeFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "TimeFields {{ ");
+        ::defmt::write!(f, "minutes: {=u8}, ", & self.minutes());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for TimeFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for TimeFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for TimeFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for TimeFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for TimeFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for TimeFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for TimeFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ThresholdFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 4],
+}
+unsafe impl ::device_driver::Fieldset for ThresholdFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 4] };
+    fn apply_reserved(&mut self) {
+        {
+            let parts = [(19, 19), (24, 24)];
+            let raw: u8 = 2;
+            unsafe {
+                ::device_driver::ops::store_split::<
+                    u8,
+                    ::device_driver::ops::LE,
+                >(raw, &parts, &mut self.bits)
+            };
+        }
+    }
+}
+impl ThresholdFields {
+    /// `7:0, 17:16` - Read the `threshold` field.
+    ///
+    /// A 10 bit threshold with its upper bits far away
+    #[must_use]
+    pub fn threshold(&self) -> u16 {
+        let parts = [(0, 7), (16, 17)];
+        let raw = unsafe {
+            ::device_driver::ops::load_split::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, &parts)
+        };
+        raw
+    }
+    /// `11:8, 23:22` - Read the `offset` field.
+    ///
+    #[must_use]
+    pub fn offset(&self) -> i8 {
+        let parts = [(8, 11), (22, 23)];
+        let raw = unsafe {
+            ::device_driver::ops::load_split::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, &parts)
+        };
+        raw
+    }
+    /// `13:12, 18:18` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        let parts = [(12, 13), (18, 18)];
+        let raw = unsafe {
+            ::device_driver::ops::load_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, &parts)
+        };
+        raw.into()
+    }
+    /// `14:14, 20:20` - Read the `level` field.
+    ///
+    #[must_use]
+    pub fn level(&self, index: usize) -> u8 {
+        assert!(index < 2);
+        let offset = index * 1;
+        let parts = [(14 + offset, 14 + offset), (20 + offset, 20 + offset)];
+        let raw = unsafe {
+            ::device_driver::ops::load_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, &parts)
+        };
+        raw
+    }
+    /// `19:19, 24:24` - Read the `trim` field.
+    ///
+    /// Reserved, always written as `2`.
+    #[must_use]
+    pub fn trim(&self) -> u8 {
+        let parts = [(19, 19), (24, 24)];
+        let raw = unsafe {
+            ::device_driver::ops::load_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, &parts)
+        };
+        raw
+    }
+    /// `7:0, 17:16` - Set the `threshold` field.
+    ///
+    /// A 10 bit threshold with its upper bits far away
+    pub fn set_threshold(&mut self, value: u16) {
+        let parts = [(0, 7), (16, 17)];
+        let raw = value;
+        debug_assert!(
+            (0..= 1023).contains(& raw),
+            "value is out of the range `0..=1023` of field `threshold`",
+        );
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+    }
+    /// `7:0, 17:16` - Set the `threshold` field.
+    ///
+    /// A 10 bit threshold with its upper bits far away
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=1023`.
+    pub fn try_set_threshold(
+        &mut self,
+        value: u16,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u16>> {
+        let parts = [(0, 7), (16, 17)];
+        let raw = value;
+        if !(0..=1023).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 1023,
+                field: "threshold",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `11:8, 23:22` - Set the `offset` field.
+    ///
+    pub fn set_offset(&mut self, value: i8) {
+        let parts = [(8, 11), (22, 23)];
+        let raw = value;
+        debug_assert!(
+            (- 32..= 31).contains(& raw),
+            "value is out of the range `-32..=31` of field `offset`",
+        );
+        unsafe {
+            ::device_driver::ops::store_split::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+    }
+    /// `11:8, 23:22` - Set the `offset` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `-32..=31`.
+    pub fn try_set_offset(
+        &mut self,
+        value: i8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<i8>> {
+        let parts = [(8, 11), (22, 23)];
+        let raw = value;
+        if !(-32..=31).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: -32,
+                max: 31,
+                field: "offset",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store_split::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `13:12, 18:18` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: Mode) {
+        let parts = [(12, 13), (18, 18)];
+        let raw = value.into();
+        debug_assert!(
+            (0..= 7).contains(& raw),
+            "value is out of the range `0..=7` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+    }
+    /// `13:12, 18:18` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=7`.
+    pub fn try_set_mode(
+        &mut self,
+        value: Mode,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let parts = [(12, 13), (18, 18)];
+        let raw = value.into();
+        if !(0..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 7,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `14:14, 20:20` - Set the `level` field.
+    ///
+    pub fn set_level(&mut self, index: usize, value: u8) {
+        assert!(index < 2);
+        let offset = index * 1;
+        let parts = [(14 + offset, 14 + offset), (20 + offset, 20 + offset)];
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `level`",
+        );
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+    }
+    /// `14:14, 20:20` - Set the `level` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_level(
+        &mut self,
+        index: usize,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        assert!(index < 2);
+        let offset = index * 1;
+        let parts = [(14 + offset, 14 + offset), (20 + offset, 20 + offset)];
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "level",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store_split::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, &parts, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for ThresholdFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 4]> for ThresholdFields {
+    fn from(bits: [u8; 4]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ThresholdFields> for [u8; 4] {
+    fn from(val: ThresholdFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ThresholdFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ThresholdFields");
+        d.field("threshold", &self.threshold());
+        d.field("offset", &self.offset());
+        d.field("mode", &self.mode());
+        d.field("level[0]", &self.level(0));
+        d.field("level[1]", &self.level(1));
+        let value = self.trim();
+        if value == 2 {
+            d.field("trim", &value);
+        } else {
+            d.field("trim", &format_args!("{value:?} (reserved, must be 2)"));
+        }
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ThresholdFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ThresholdFields {{ ");
+        ::defmt::write!(f, "threshold: {=u16}, ", & self.threshold());
+        ::defmt::write!(f, "offset: {=i8}, ", & self.offset());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "level: {=u8}, [0]", & self.level(0));
+        ::defmt::write!(f, "level: {=u8}, [1]", & self.level(1));
+        ::defmt::write!(f, "trim: {=u8}, ", & self.trim());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ThresholdFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ThresholdFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ThresholdFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ThresholdFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ThresholdFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ThresholdFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ThresholdFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Mode {
+    Off = 0,
+    On = 5,
+    Other = 6,
+}
+impl Default for Mode {
+    fn default() -> Self {
+        Self::Other
+    }
+}
+impl From<u8> for Mode {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => Self::Off,
+            5 => Self::On,
+            _ => Self::default(),
+        }
+    }
+}
+impl From<Mode> for u8 {
+    fn from(val: Mode) -> Self {
+        match val {
+            Mode::Off => 0,
+            Mode::On => 5,
+            Mode::Other => 6,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for Mode {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
@@ -0,0 +1,1459 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false, features=["embedded-hal-10"] }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Sensor driver
+#[derive(Debug)]
+pub struct Sensor<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Sensor<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Control")]
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Control::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Status")]
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Status::default)
+    }
+    /// Register operation:
+    /// - Address: `2`
+    /// - Reset value: `0`
+    #[doc(alias = "Data")]
+    pub fn data(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Data, u8, ::device_driver::RO, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 2;
+        ::device_driver::RegisterOperation::new(self, address as u8, Data::default)
+    }
+    /// Command operation:
+    /// - Address: `3`
+    #[doc(alias = "PowerDown")]
+    pub fn power_down(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Self, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 3;
+        ::device_driver::CommandOperation::new(self, address as u8)
+    }
+    /// Block operation:
+    /// - Address: `16`
+    #[doc(alias = "Calibration")]
+    pub fn calibration(&mut self) -> Calibration<'_, I> {
+        let address = self.base_address + 16;
+        Calibration::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Block operation:
+    /// - Address: `32`
+    #[doc(alias = "CalibrationCopy")]
+    pub fn calibration_copy(&mut self) -> Calibration<'_, I> {
+        let address = self.base_address + 32;
+        Calibration::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// Routine:
+    /// - Write [`control`](Self::control)
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `10` time(s) with `50` us in between
+    pub fn wake_up(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.control().write(|_| {})?;
+        {
+            let mut attempts_left: u32 = 10;
+            loop {
+                let reg = self.status().read()?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(50);
+            }
+        }
+        Ok(())
+    }
+    /// Async version of [`wake_up`](Self::wake_up).
+    ///
+    /// Routine:
+    /// - Write [`control`](Self::control)
+    /// - Poll [`status`](Self::status) until `ready == true`, at most `10` time(s) with `50` us in between
+    pub async fn wake_up_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.control().write_async(|_| {}).await?;
+        {
+            let mut attempts_left: u32 = 10;
+            loop {
+                let reg = self.status().read_async().await?;
+                if reg.ready() {
+                    break;
+                }
+                attempts_left -= 1;
+                if attempts_left == 0 {
+                    return Err(::device_driver::RoutineError::Timeout);
+                }
+                delay.delay_us(50).await;
+            }
+        }
+        Ok(())
+    }
+    /// Routine:
+    /// - Dispatch [`power_down`](Self::power_down)
+    pub fn calibrate(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::CommandInterfaceBase>::Error>
+    where
+        I: ::device_driver::CommandInterface<AddressType = u8>,
+    {
+        self.power_down().dispatch()?;
+        Ok(())
+    }
+    /// Async version of [`calibrate`](Self::calibrate).
+    ///
+    /// Routine:
+    /// - Dispatch [`power_down`](Self::power_down)
+    pub async fn calibrate_async(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::CommandInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncCommandInterface<AddressType = u8>,
+    {
+        self.power_down().dispatch_async().await?;
+        Ok(())
+    }
+}
+impl<I> ::device_driver::Block for Sensor<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Sensor`
+pub mod sensor {
+    /// Raw constants of the `control` register
+    pub mod control {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `status` register
+    pub mod status {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `data` register
+    pub mod data {
+        /// The address of the register
+        pub const ADDRESS: u8 = 2;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0x0;
+    }
+    /// Raw constants of the `power_down` command
+    pub mod power_down {
+        /// The address of the command
+        pub const ADDRESS: u8 = 3;
+    }
+}
+#[derive(Debug)]
+pub struct Calibration<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Calibration<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Offset")]
+    pub fn offset(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        Offset,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(self, address as u8, Offset::default)
+    }
+    /// Register operation:
+    /// - Address: `1`
+    /// - Reset value: `0`
+    #[doc(alias = "Gain")]
+    pub fn gain(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<'_, Self, Gain, u8, ::device_driver::RW, ()>
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 1;
+        ::device_driver::RegisterOperation::new(self, address as u8, Gain::default)
+    }
+}
+impl<'i, I> ::device_driver::Block for Calibration<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Calibration`
+///
+/// The addresses are relative to the address of the block.
+pub mod calibration {
+    /// Raw constants of the `offset` register
+    pub mod offset {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `gain` register
+    pub mod gain {
+        /// The address of the register
+        pub const ADDRESS: u8 = 1;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// The states of [`Power`]
+pub mod power {
+    /// Everything is powered down
+    #[derive(Debug)]
+    pub struct Sleep;
+    #[derive(Debug)]
+    pub struct Standby;
+    #[derive(Debug)]
+    pub struct Measuring;
+    /// Implemented by the states [`data`](super::Sensor::data) may be used in
+    pub trait AllowsData {}
+    impl AllowsData for Measuring {}
+    impl AllowsData for Standby {}
+    /// Implemented by the states [`calibrate`](super::Sensor::calibrate) may be used in
+    pub trait AllowsCalibrate {}
+    impl AllowsCalibrate for Standby {}
+    /// Implemented by the states `offset` may be used in.
+    /// The blocks that lead to it are only available in these states
+    pub trait AllowsOffset {}
+    impl AllowsOffset for Standby {}
+    /// Implemented by the states `gain` may be used in.
+    /// The blocks that lead to it are only available in these states
+    pub trait AllowsGain {}
+    impl AllowsGain for Standby {}
+    impl AllowsGain for Measuring {}
+}
+/// The power modes of the sensor
+///
+/// Typestate wrapper around [`Sensor`] in one of the states of [`power`].
+/// Operations that are only allowed in some states can't be called in the other states.
+#[derive(Debug)]
+pub struct Power<I, S> {
+    device: Sensor<I>,
+    state: ::core::marker::PhantomData<S>,
+}
+impl<I> Power<I, power::Sleep> {
+    /// Wrap the device, which must be in the initial [`Sleep`](power::Sleep) state
+    pub const fn new(device: Sensor<I>) -> Self {
+        Self {
+            device,
+            state: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<I, S> Power<I, S> {
+    /// Drop the wrapper and reclaim the device
+    pub fn free(self) -> Sensor<I> {
+        self.device
+    }
+    /// See [`Sensor::control`].
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Sensor<I>,
+        Control,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.control()
+    }
+    /// See [`Sensor::status`].
+    pub fn status(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Sensor<I>,
+        Status,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.status()
+    }
+    /// See [`Sensor::power_down`].
+    pub fn power_down(
+        &mut self,
+    ) -> ::device_driver::CommandOperation<'_, Sensor<I>, u8, (), (), ()>
+    where
+        I: ::device_driver::CommandInterfaceBase<AddressType = u8>,
+    {
+        self.device.power_down()
+    }
+    /// See [`Sensor::wake_up`].
+    pub fn wake_up(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        self.device.wake_up(delay)
+    }
+    /// See [`Sensor::wake_up_async`].
+    pub async fn wake_up_async(
+        &mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        (),
+        ::device_driver::RoutineError<
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        self.device.wake_up_async(delay).await
+    }
+}
+impl<I, S: power::AllowsCalibrate> Power<I, S> {
+    /// See [`Sensor::calibrate`].
+    pub fn calibrate(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::CommandInterfaceBase>::Error>
+    where
+        I: ::device_driver::CommandInterface<AddressType = u8>,
+    {
+        self.device.calibrate()
+    }
+    /// See [`Sensor::calibrate_async`].
+    pub async fn calibrate_async(
+        &mut self,
+    ) -> Result<(), <I as ::device_driver::CommandInterfaceBase>::Error>
+    where
+        I: ::device_driver::AsyncCommandInterface<AddressType = u8>,
+    {
+        self.device.calibrate_async().await
+    }
+}
+impl<I, S: power::AllowsData> Power<I, S> {
+    /// See [`Sensor::data`].
+    pub fn data(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Sensor<I>,
+        Data,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        self.device.data()
+    }
+}
+impl<I, S: power::AllowsOffset + power::AllowsGain> Power<I, S> {
+    /// See [`Sensor::calibration`].
+    pub fn calibration(&mut self) -> Calibration<'_, I> {
+        self.device.calibration()
+    }
+    /// See [`Sensor::calibration_copy`].
+    pub fn calibration_copy(&mut self) -> Calibration<'_, I> {
+        self.device.calibration_copy()
+    }
+}
+impl<I> Power<I, power::Sleep> {
+    /// Wake the sensor up
+    ///
+    /// Transition to [`Standby`](power::Standby).
+    /// Runs [`wake_up`](Sensor::wake_up).
+    pub fn wake(
+        mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        Power<I, power::Standby>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            ::device_driver::RoutineError<
+                <I as ::device_driver::RegisterInterfaceBase>::Error,
+            >,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let result = self.device.wake_up(delay);
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Async version of [`wake`](Self::wake).
+    ///
+    /// Wake the sensor up
+    ///
+    /// Transition to [`Standby`](power::Standby).
+    /// Runs [`wake_up`](Sensor::wake_up).
+    pub async fn wake_async(
+        mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        Power<I, power::Standby>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            ::device_driver::RoutineError<
+                <I as ::device_driver::RegisterInterfaceBase>::Error,
+            >,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let result = self.device.wake_up_async(delay).await;
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+}
+impl<I> Power<I, power::Standby> {
+    /// Transition to [`Measuring`](power::Measuring).
+    ///
+    /// Routine:
+    /// - Write [`control`](Sensor::control): `measure = true`
+    pub fn start(
+        mut self,
+    ) -> Result<
+        Power<I, power::Measuring>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::RegisterInterface<AddressType = u8>,
+    {
+        let result = (|| -> Result<
+            (),
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        > {
+            self.device
+                .control()
+                .write(|fields| {
+                    fields.set_measure(true);
+                })?;
+            Ok(())
+        })();
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Async version of [`start`](Self::start).
+    ///
+    /// Transition to [`Measuring`](power::Measuring).
+    ///
+    /// Routine:
+    /// - Write [`control`](Sensor::control): `measure = true`
+    pub async fn start_async(
+        mut self,
+    ) -> Result<
+        Power<I, power::Measuring>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            <I as ::device_driver::RegisterInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncRegisterInterface<AddressType = u8>,
+    {
+        let result: Result<(), <I as ::device_driver::RegisterInterfaceBase>::Error> = async {
+            self.device
+                .control()
+                .write_async(|fields| {
+                    fields.set_measure(true);
+                })
+                .await?;
+            Ok(())
+        }
+            .await;
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Transition to [`Sleep`](power::Sleep).
+    ///
+    /// Routine:
+    /// - Dispatch [`power_down`](Sensor::power_down)
+    /// - Delay `100` us
+    pub fn sleep(
+        mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        Power<I, power::Sleep>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            <I as ::device_driver::CommandInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::CommandInterface<AddressType = u8>,
+    {
+        let result = (|| -> Result<
+            (),
+            <I as ::device_driver::CommandInterfaceBase>::Error,
+        > {
+            self.device.power_down().dispatch()?;
+            delay.delay_us(100);
+            Ok(())
+        })();
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Async version of [`sleep`](Self::sleep).
+    ///
+    /// Transition to [`Sleep`](power::Sleep).
+    ///
+    /// Routine:
+    /// - Dispatch [`power_down`](Sensor::power_down)
+    /// - Delay `100` us
+    pub async fn sleep_async(
+        mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        Power<I, power::Sleep>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            <I as ::device_driver::CommandInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncCommandInterface<AddressType = u8>,
+    {
+        let result: Result<(), <I as ::device_driver::CommandInterfaceBase>::Error> = async {
+            self.device.power_down().dispatch_async().await?;
+            delay.delay_us(100).await;
+            Ok(())
+        }
+            .await;
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+}
+impl<I> Power<I, power::Measuring> {
+    /// Transition to [`Sleep`](power::Sleep).
+    ///
+    /// Routine:
+    /// - Dispatch [`power_down`](Sensor::power_down)
+    /// - Delay `100` us
+    pub fn sleep(
+        mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal::delay::DelayNs,
+    ) -> Result<
+        Power<I, power::Sleep>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            <I as ::device_driver::CommandInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::CommandInterface<AddressType = u8>,
+    {
+        let result = (|| -> Result<
+            (),
+            <I as ::device_driver::CommandInterfaceBase>::Error,
+        > {
+            self.device.power_down().dispatch()?;
+            delay.delay_us(100);
+            Ok(())
+        })();
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+    /// Async version of [`sleep`](Self::sleep).
+    ///
+    /// Transition to [`Sleep`](power::Sleep).
+    ///
+    /// Routine:
+    /// - Dispatch [`power_down`](Sensor::power_down)
+    /// - Delay `100` us
+    pub async fn sleep_async(
+        mut self,
+        delay: &mut impl ::device_driver::hal::embedded_hal_async::delay::DelayNs,
+    ) -> Result<
+        Power<I, power::Sleep>,
+        ::device_driver::TransitionError<
+            Sensor<I>,
+            <I as ::device_driver::CommandInterfaceBase>::Error,
+        >,
+    >
+    where
+        I: ::device_driver::AsyncCommandInterface<AddressType = u8>,
+    {
+        let result: Result<(), <I as ::device_driver::CommandInterfaceBase>::Error> = async {
+            self.device.power_down().dispatch_async().await?;
+            delay.delay_us(100).await;
+            Ok(())
+        }
+            .await;
+        match result {
+            Ok(_) => {
+                Ok(Power {
+                    device: self.device,
+                    state: ::core::marker::PhantomData,
+                })
+            }
+            Err(error) => {
+                Err(::device_driver::TransitionError {
+                    device: self.device,
+                    error,
+                })
+            }
+        }
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Gain {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Gain {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Gain {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Gain {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Gain {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Gain> for [u8; 1] {
+    fn from(val: Gain) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Gain {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Gain");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Gain {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Gain {{ ");
+        ::defmt::write!(f, "value: {=u8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Gain {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Gain {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Gain {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Gain {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Gain {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Gain {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Gain {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Offset {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Offset {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Offset {
+    /// The mask of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_MASK: u8 = 0xFF;
+    /// The shift of the `value` field when the fieldset is read as one `u8` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `7:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> i8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: i8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Offset {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Offset {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Offset> for [u8; 1] {
+    fn from(val: Offset) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Offset {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Offset");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Offset {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Offset {{ ");
+        ::defmt::write!(f, "value: {=i8}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Offset {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Offset {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Offset {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Offset {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Offset {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Offset {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Offset {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Data {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for Data {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl Data {
+    /// The mask of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_MASK: u16 = 0xFFFF;
+    /// The shift of the `value` field when the fieldset is read as one `u16` in LE byte order
+    pub const VALUE_SHIFT: u32 = 0;
+    /// `15:0` - Read the `value` field.
+    ///
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        let start = 0;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u16,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:0` - Set the `value` field.
+    ///
+    pub fn set_value(&mut self, value: u16) {
+        let start = 0;
+        let end = 15;
+        let raw = value;
+        unsafe {
+            ::device_driver::ops::store::<
+                u16,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Data {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for Data {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Data> for [u8; 2] {
+    fn from(val: Data) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Data {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Data");
+        d.field("value", &self.value());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Data {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Data {{ ");
+        ::defmt::write!(f, "value: {=u16}, ", & self.value());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Data {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Data {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Data {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Data {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Data {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Data {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Data {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Status {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Status {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Status {
+    /// The mask of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_MASK: u8 = 0x1;
+    /// The shift of the `ready` field when the fieldset is read as one `u8` in LE byte order
+    pub const READY_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `ready` field.
+    ///
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `ready` field.
+    ///
+    pub fn set_ready(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Status {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Status {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Status> for [u8; 1] {
+    fn from(val: Status) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Status {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Status");
+        d.field("ready", &self.ready());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Status {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Status {{ ");
+        ::defmt::write!(f, "ready: {=bool}, ", & self.ready());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Status {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Status {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Status {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Status {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Status {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Status {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Status {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct Control {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for Control {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl Control {
+    /// The mask of the `measure` field when the fieldset is read as one `u8` in LE byte order
+    pub const MEASURE_MASK: u8 = 0x1;
+    /// The shift of the `measure` field when the fieldset is read as one `u8` in LE byte order
+    pub const MEASURE_SHIFT: u32 = 0;
+    /// `bit 0` - Read the `measure` field.
+    ///
+    #[must_use]
+    pub fn measure(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `bit 0` - Set the `measure` field.
+    ///
+    pub fn set_measure(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+}
+impl Default for Control {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for Control {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<Control> for [u8; 1] {
+    fn from(val: Control) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for Control {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("Control");
+        d.field("measure", &self.measure());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Control {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "Control {{ ");
+        ::defmt::write!(f, "measure: {=bool}, ", & self.measure());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for Control {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for Control {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for Control {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for Control {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for Control {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for Control {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for Control {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
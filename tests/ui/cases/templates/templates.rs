@@ -0,0 +1,628 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the Templates driver
+#[derive(Debug)]
+pub struct Templates<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> Templates<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// A channel of the device
+    ///
+    /// Block operation:
+    /// - Address: `0`
+    #[doc(alias = "Channel0")]
+    pub fn channel_0(&mut self) -> Channel0<'_, I> {
+        let address = self.base_address + 0;
+        Channel0::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// The second channel has a different reset value
+    ///
+    /// Block operation:
+    /// - Address: `16`
+    #[doc(alias = "Channel1")]
+    pub fn channel_1(&mut self) -> Channel1<'_, I> {
+        let address = self.base_address + 16;
+        Channel1::<'_, I>::new(::device_driver::Block::interface(self), address)
+    }
+    /// A register placed with an expression of the parameters
+    ///
+    /// Register operation:
+    /// - Address: `32`
+    /// - Reset value: `0`
+    #[doc(alias = "Extra0")]
+    pub fn extra_0(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ChannelConfig,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 32;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            ChannelConfig::default,
+        )
+    }
+    /// A register placed with an expression of the parameters
+    ///
+    /// Register operation:
+    /// - Address: `34`
+    /// - Reset value: `0`
+    #[doc(alias = "Extra1")]
+    pub fn extra_1(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ChannelConfig,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 34;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            ChannelConfig::default,
+        )
+    }
+}
+impl<I> ::device_driver::Block for Templates<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `Templates`
+pub mod templates {
+    /// Raw constants of the `extra_0` register
+    pub mod extra_0 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 32;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+    /// Raw constants of the `extra_1` register
+    pub mod extra_1 {
+        /// The address of the register
+        pub const ADDRESS: u8 = 34;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// A channel of the device
+#[derive(Debug)]
+pub struct Channel0<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Channel0<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0`
+    #[doc(alias = "Channel0Config")]
+    pub fn channel_0_config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ChannelConfig,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ChannelConfig::from([0]),
+        )
+    }
+}
+impl<'i, I> ::device_driver::Block for Channel0<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Channel0`
+///
+/// The addresses are relative to the address of the block.
+pub mod channel_0 {
+    /// Raw constants of the `channel_0_config` register
+    pub mod channel_0_config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0x0;
+    }
+}
+/// The second channel has a different reset value
+#[derive(Debug)]
+pub struct Channel1<'i, I> {
+    #[doc(hidden)]
+    interface: &'i mut I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<'i, I> Channel1<'i, I> {
+    /// Create a new instance of the block based on device interface
+    #[doc(hidden)]
+    fn new(interface: &'i mut I, base_address: u8) -> Self {
+        Self {
+            interface,
+            base_address: base_address,
+        }
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0x0D`
+    #[doc(alias = "Channel1Config")]
+    pub fn channel_1_config(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ChannelConfig,
+        u8,
+        ::device_driver::RO,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ChannelConfig::from([13]),
+        )
+    }
+}
+impl<'i, I> ::device_driver::Block for Channel1<'i, I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        self.interface
+    }
+}
+/// Raw constants of the operations of `Channel1`
+///
+/// The addresses are relative to the address of the block.
+pub mod channel_1 {
+    /// Raw constants of the `channel_1_config` register
+    pub mod channel_1_config {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 1;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u8 = 0xD;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ChannelConfig {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 1],
+}
+unsafe impl ::device_driver::Fieldset for ChannelConfig {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 1] };
+}
+impl ChannelConfig {
+    /// The mask of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_MASK: u8 = 0x1;
+    /// The shift of the `enable` field when the fieldset is read as one `u8` in LE byte order
+    pub const ENABLE_SHIFT: u32 = 0;
+    /// The mask of the `low` field when the fieldset is read as one `u8` in LE byte order
+    pub const LOW_MASK: u8 = 0x6;
+    /// The shift of the `low` field when the fieldset is read as one `u8` in LE byte order
+    pub const LOW_SHIFT: u32 = 1;
+    /// The mask of the `high` field when the fieldset is read as one `u8` in LE byte order
+    pub const HIGH_MASK: u8 = 0x38;
+    /// The shift of the `high` field when the fieldset is read as one `u8` in LE byte order
+    pub const HIGH_SHIFT: u32 = 3;
+    /// The mask of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_MASK: u8 = 0xC0;
+    /// The shift of the `mode` field when the fieldset is read as one `u8` in LE byte order
+    pub const MODE_SHIFT: u32 = 6;
+    /// `bit 0` - Read the `enable` field.
+    ///
+    #[must_use]
+    pub fn enable(&self) -> bool {
+        let start = 0;
+        let end = 0;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw > 0
+    }
+    /// `2:1` - Read the `low` field.
+    ///
+    /// A level with a limited range
+    #[must_use]
+    pub fn low(&self) -> u8 {
+        let start = 1;
+        let end = 2;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `5:3` - Read the `high` field.
+    ///
+    /// A level with a limited range
+    #[must_use]
+    pub fn high(&self) -> u8 {
+        let start = 3;
+        let end = 5;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `7:6` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> ModeA {
+        let start = 6;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw.into()
+    }
+    /// `bit 0` - Set the `enable` field.
+    ///
+    pub fn set_enable(&mut self, value: bool) {
+        let start = 0;
+        let end = 0;
+        let raw = value as _;
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `low` field.
+    ///
+    /// A level with a limited range
+    pub fn set_low(&mut self, value: u8) {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        debug_assert!(
+            (0..= 3).contains(& raw), "value is out of the range `0..=3` of field `low`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `2:1` - Set the `low` field.
+    ///
+    /// A level with a limited range
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_low(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 1;
+        let end = 2;
+        let raw = value;
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "low",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `5:3` - Set the `high` field.
+    ///
+    /// A level with a limited range
+    pub fn set_high(&mut self, value: u8) {
+        let start = 3;
+        let end = 5;
+        let raw = value;
+        debug_assert!(
+            (0..= 6).contains(& raw),
+            "value is out of the range `0..=6` of field `high`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `5:3` - Set the `high` field.
+    ///
+    /// A level with a limited range
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=6`.
+    pub fn try_set_high(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 3;
+        let end = 5;
+        let raw = value;
+        if !(0..=6).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 6,
+                field: "high",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `7:6` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: ModeA) {
+        let start = 6;
+        let end = 7;
+        let raw = value.into();
+        debug_assert!(
+            (0..= 3).contains(& raw),
+            "value is out of the range `0..=3` of field `mode`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:6` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=3`.
+    pub fn try_set_mode(
+        &mut self,
+        value: ModeA,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 6;
+        let end = 7;
+        let raw = value.into();
+        if !(0..=3).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 3,
+                field: "mode",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 1]> for ChannelConfig {
+    fn from(bits: [u8; 1]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ChannelConfig> for [u8; 1] {
+    fn from(val: ChannelConfig) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ChannelConfig {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ChannelConfig");
+        d.field("enable", &self.enable());
+        d.field("low", &self.low());
+        d.field("high", &self.high());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ChannelConfig {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ChannelConfig {{ ");
+        ::defmt::write!(f, "enable: {=bool}, ", & self.enable());
+        ::defmt::write!(f, "low: {=u8}, ", & self.low());
+        ::defmt::write!(f, "high: {=u8}, ", & self.high());
+        ::defmt::write!(f, "mode: {}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ChannelConfig {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ChannelConfig {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ChannelConfig {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ChannelConfig {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ChannelConfig {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ChannelConfig {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ChannelConfig {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum ModeA {
+    Off = 0,
+    On = 1,
+}
+impl Default for ModeA {
+    fn default() -> Self {
+        Self::On
+    }
+}
+impl From<u8> for ModeA {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => Self::Off,
+            _ => Self::default(),
+        }
+    }
+}
+impl From<ModeA> for u8 {
+    fn from(val: ModeA) -> Self {
+        match val {
+            ModeA::Off => 0,
+            ModeA::On => 1,
+        }
+    }
+}
+#[doc(hidden)]
+impl ::device_driver::EnumIndex for ModeA {
+    #[track_caller]
+    fn index(&self) -> i32 {
+        let index = u8::from(*self);
+        index.try_into().unwrap()
+    }
+}
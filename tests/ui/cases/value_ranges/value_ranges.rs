@@ -0,0 +1,366 @@
+#!/usr/bin/env cargo
+---
+[package]
+edition = "2024"
+[dependencies]
+device-driver = { path="../../../../device-driver", default-features=false }
+---
+#![deny(warnings)]
+#![allow(unexpected_cfgs)]
+fn main() {}
+
+// This code was generated using device-driver `xx.xx.xx` (xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx),
+// a tool distributed under MIT OR Apache-2.0 by Dion Dokter <dev@diondokter.nl>
+// This version was built for xxxx-xxxx-xxxx using rustc 1.xx.x (xxxxxxxxx xxxx-xx-xx)
+// 
+// For more information about device-driver, visit the website: https://device-driver.com
+
+/// Root block of the ValueRanges driver
+#[derive(Debug)]
+pub struct ValueRanges<I> {
+    interface: I,
+    #[doc(hidden)]
+    #[allow(unused)]
+    base_address: u8,
+}
+impl<I> ValueRanges<I> {
+    /// Create a new instance of the device
+    pub const fn new(interface: I) -> Self {
+        Self { interface, base_address: 0 }
+    }
+    /// Drop the driver instance and reclaim the interface
+    pub fn free(self) -> I {
+        self.interface
+    }
+    /// Register operation:
+    /// - Address: `0`
+    /// - Reset value: `0x0A_0A`
+    #[doc(alias = "Control")]
+    pub fn control(
+        &mut self,
+    ) -> ::device_driver::RegisterOperation<
+        '_,
+        Self,
+        ControlFields,
+        u8,
+        ::device_driver::RW,
+        (),
+    >
+    where
+        I: ::device_driver::RegisterInterfaceBase<AddressType = u8>,
+    {
+        let address = self.base_address + 0;
+        ::device_driver::RegisterOperation::new(
+            self,
+            address as u8,
+            || ControlFields::from([10, 10]),
+        )
+    }
+}
+impl<I> ::device_driver::Block for ValueRanges<I> {
+    type Interface = I;
+    type RegisterAddressType = u8;
+    type CommandAddressType = u8;
+    type BufferAddressType = u8;
+    type RegisterAddressMode = ();
+    fn interface(&mut self) -> &mut Self::Interface {
+        &mut self.interface
+    }
+}
+/// Raw constants of the operations of `ValueRanges`
+pub mod value_ranges {
+    /// Raw constants of the `control` register
+    pub mod control {
+        /// The address of the register
+        pub const ADDRESS: u8 = 0;
+        /// The size of the register in bytes
+        pub const SIZE_BYTES: usize = 2;
+        /// The reset value of the register read as one integer in LE byte order
+        pub const RESET: u16 = 0xA0A;
+    }
+}
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ControlFields {
+    #[doc(hidden)]
+    /// The internal bits
+    bits: [u8; 2],
+}
+unsafe impl ::device_driver::Fieldset for ControlFields {
+    const METADATA: ::device_driver::FieldsetMetadata = ::device_driver::FieldsetMetadata::new()
+        .with_byte_order(::device_driver::ByteOrder::LE);
+    const ZERO: Self = Self { bits: [0; 2] };
+}
+impl ControlFields {
+    /// The mask of the `threshold` field when the fieldset is read as one `u16` in LE byte order
+    pub const THRESHOLD_MASK: u16 = 0xFF;
+    /// The shift of the `threshold` field when the fieldset is read as one `u16` in LE byte order
+    pub const THRESHOLD_SHIFT: u32 = 0;
+    /// The mask of the `gain` field when the fieldset is read as one `u16` in LE byte order
+    pub const GAIN_MASK: u16 = 0xF00;
+    /// The shift of the `gain` field when the fieldset is read as one `u16` in LE byte order
+    pub const GAIN_SHIFT: u32 = 8;
+    /// The mask of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_MASK: u16 = 0xF000;
+    /// The shift of the `mode` field when the fieldset is read as one `u16` in LE byte order
+    pub const MODE_SHIFT: u32 = 12;
+    /// `7:0` - Read the `threshold` field.
+    ///
+    #[must_use]
+    pub fn threshold(&self) -> u8 {
+        let start = 0;
+        let end = 7;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `11:8` - Read the `gain` field.
+    ///
+    #[must_use]
+    pub fn gain(&self) -> i8 {
+        let start = 8;
+        let end = 11;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                i8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        raw
+    }
+    /// `15:12` - Read the `mode` field.
+    ///
+    #[must_use]
+    pub fn mode(&self) -> u8 {
+        let start = 12;
+        let end = 15;
+        let raw = unsafe {
+            ::device_driver::ops::load::<
+                u8,
+                ::device_driver::ops::LE,
+            >(&self.bits, start, end)
+        };
+        let raw = ::device_driver::ops::decode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        raw
+    }
+    /// `7:0` - Set the `threshold` field.
+    ///
+    pub fn set_threshold(&mut self, value: u8) {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        debug_assert!(
+            (10..= 200).contains(& raw),
+            "value is out of the range `10..=200` of field `threshold`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `7:0` - Set the `threshold` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `10..=200`.
+    pub fn try_set_threshold(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 0;
+        let end = 7;
+        let raw = value;
+        if !(10..=200).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 10,
+                max: 200,
+                field: "threshold",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `11:8` - Set the `gain` field.
+    ///
+    pub fn set_gain(&mut self, value: i8) {
+        let start = 8;
+        let end = 11;
+        let raw = value;
+        debug_assert!(
+            (- 8..= 7).contains(& raw),
+            "value is out of the range `-8..=7` of field `gain`",
+        );
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `11:8` - Set the `gain` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `-8..=7`.
+    pub fn try_set_gain(
+        &mut self,
+        value: i8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<i8>> {
+        let start = 8;
+        let end = 11;
+        let raw = value;
+        if !(-8..=7).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: -8,
+                max: 7,
+                field: "gain",
+            });
+        }
+        unsafe {
+            ::device_driver::ops::store::<
+                i8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+    /// `15:12` - Set the `mode` field.
+    ///
+    pub fn set_mode(&mut self, value: u8) {
+        let start = 12;
+        let end = 15;
+        let raw = value;
+        debug_assert!(
+            (0..= 5).contains(& raw),
+            "value is out of the range `0..=5` of field `mode`",
+        );
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+    }
+    /// `15:12` - Set the `mode` field.
+    ///
+    /// Returns an error and leaves the field unchanged if the value is out of the range `0..=5`.
+    pub fn try_set_mode(
+        &mut self,
+        value: u8,
+    ) -> Result<(), ::device_driver::ValueOutOfRange<u8>> {
+        let start = 12;
+        let end = 15;
+        let raw = value;
+        if !(0..=5).contains(&raw) {
+            return Err(::device_driver::ValueOutOfRange {
+                value: raw,
+                min: 0,
+                max: 5,
+                field: "mode",
+            });
+        }
+        let raw = ::device_driver::ops::encode::<u8, ::device_driver::ops::Bcd>(raw, 4);
+        unsafe {
+            ::device_driver::ops::store::<
+                u8,
+                ::device_driver::ops::LE,
+            >(raw, start, end, &mut self.bits)
+        };
+        Ok(())
+    }
+}
+impl Default for ControlFields {
+    fn default() -> Self {
+        <Self as ::device_driver::Fieldset>::ZERO
+    }
+}
+impl From<[u8; 2]> for ControlFields {
+    fn from(bits: [u8; 2]) -> Self {
+        Self { bits }
+    }
+}
+impl From<ControlFields> for [u8; 2] {
+    fn from(val: ControlFields) -> Self {
+        val.bits
+    }
+}
+impl ::core::fmt::Debug for ControlFields {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> Result<(), ::core::fmt::Error> {
+        let mut d = f.debug_struct("ControlFields");
+        d.field("threshold", &self.threshold());
+        d.field("gain", &self.gain());
+        d.field("mode", &self.mode());
+        d.finish()
+    }
+}
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for ControlFields {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(f, "ControlFields {{ ");
+        ::defmt::write!(f, "threshold: {=u8}, ", & self.threshold());
+        ::defmt::write!(f, "gain: {=i8}, ", & self.gain());
+        ::defmt::write!(f, "mode: {=u8}, ", & self.mode());
+        ::defmt::write!(f, "}}");
+    }
+}
+impl ::core::ops::BitAnd for ControlFields {
+    type Output = Self;
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+impl ::core::ops::BitAndAssign for ControlFields {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l &= *r;
+        }
+    }
+}
+impl ::core::ops::BitOr for ControlFields {
+    type Output = Self;
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+impl ::core::ops::BitOrAssign for ControlFields {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l |= *r;
+        }
+    }
+}
+impl ::core::ops::BitXor for ControlFields {
+    type Output = Self;
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+impl ::core::ops::BitXorAssign for ControlFields {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (l, r) in self.bits.iter_mut().zip(&rhs.bits) {
+            *l ^= *r;
+        }
+    }
+}
+impl ::core::ops::Not for ControlFields {
+    type Output = Self;
+    fn not(mut self) -> Self::Output {
+        for val in self.bits.iter_mut() {
+            *val = !*val;
+        }
+        self
+    }
+}
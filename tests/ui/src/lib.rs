@@ -1,10 +1,30 @@
 use std::{path::Path, sync::LazyLock};
 
-use device_driver_core::{CodegenTarget, CompileOptions};
+use device_driver_core::{CodegenTarget, CompileOptions, FsFileLoader, SourceMap};
 use device_driver_diagnostics::Metadata;
 use regex::Regex;
 
-pub const OUTPUT_HEADER: &str = include_str!("output_header.txt");
+const OUTPUT_HEADER: &str = include_str!("output_header.txt");
+
+/// The header of the output of a test case.
+/// Cases that need features of device-driver list them in a `features.txt` file, one per line.
+/// All other cases are compiled without any features.
+#[must_use]
+pub fn output_header(test_case_dir: &Path) -> String {
+    let features = std::fs::read_to_string(test_case_dir.join("features.txt"))
+        .map(|features| {
+            let features = features
+                .lines()
+                .map(str::trim)
+                .filter(|feature| !feature.is_empty())
+                .map(|feature| format!("\"{feature}\""))
+                .collect::<Vec<_>>();
+            format!(", features=[{}]", features.join(", "))
+        })
+        .unwrap_or_default();
+
+    OUTPUT_HEADER.replace("{features}", &features)
+}
 
 pub fn get_compile_options() -> CompileOptions {
     CompileOptions {
@@ -18,6 +38,7 @@ pub fn get_compile_options() -> CompileOptions {
         },
         target: CodegenTarget::Rust(device_driver_core::RustCodegenOptions {
             defmt_feature: Some("defmt".into()),
+            debug_assert_setters: true,
         }),
     }
 }
@@ -29,12 +50,17 @@ pub fn run_test(source_paths: &[&Path], output_path: &Path) {
 
     for source_path in source_paths {
         let source = std::fs::read_to_string(source_path).unwrap();
+        let mut source_map = SourceMap::new("input.ddsl", source);
 
         let input_extension = source_path.extension().unwrap().display().to_string();
         let (transformed, diagnostics) = match &*input_extension {
             "ddsl" => {
-                let (transformed, diagnostics) =
-                    device_driver_core::compile(&source, get_compile_options()).unwrap();
+                let (transformed, diagnostics) = device_driver_core::compile(
+                    &mut source_map,
+                    &FsFileLoader::new(source_path.parent().unwrap()),
+                    get_compile_options(),
+                )
+                .unwrap();
 
                 let mut diagnostics_output = String::new();
 
@@ -42,8 +68,7 @@ pub fn run_test(source_paths: &[&Path], output_path: &Path) {
                     .print_to_fmt(
                         &mut diagnostics_output,
                         Metadata {
-                            source: &source,
-                            source_path: "input.ddsl",
+                            source_map: &source_map,
                             term_width: None,
                             ansi: false,
                             unicode: false,
@@ -56,7 +81,7 @@ pub fn run_test(source_paths: &[&Path], output_path: &Path) {
             e => panic!("Unrecognized extension: {e:?}"),
         };
 
-        let output = OUTPUT_HEADER.to_string() + &transformed;
+        let output = output_header(output_path.parent().unwrap()) + &transformed;
 
         let diagnostics_path = source_path
             .with_file_name("diagnostics")
@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use device_driver_core::{FsFileLoader, SourceMap};
 use device_driver_diagnostics::Metadata;
 use device_driver_tests::get_compile_options;
 
@@ -55,20 +56,24 @@ fn accept(cases_dir: &Path) {
 
         for source_path in source_paths {
             let source = std::fs::read_to_string(&source_path).unwrap();
+            let mut source_map = SourceMap::new("input.ddsl", source);
 
             let source_extension = source_path.extension().unwrap().display().to_string();
             let (transformed, diagnostics) = match &*source_extension {
                 "ddsl" => {
-                    let (transformed, diagnostics) =
-                        device_driver_core::compile(&source, get_compile_options()).unwrap();
+                    let (transformed, diagnostics) = device_driver_core::compile(
+                        &mut source_map,
+                        &FsFileLoader::new(source_path.parent().unwrap()),
+                        get_compile_options(),
+                    )
+                    .unwrap();
                     let mut diagnostics_output = String::new();
 
                     diagnostics
                         .print_to_fmt(
                             &mut diagnostics_output,
                             Metadata {
-                                source: &source,
-                                source_path: "input.ddsl",
+                                source_map: &source_map,
                                 term_width: None,
                                 ansi: false,
                                 unicode: false,
@@ -90,7 +95,7 @@ fn accept(cases_dir: &Path) {
             )
             .unwrap();
 
-            let output = device_driver_tests::OUTPUT_HEADER.to_string() + &transformed;
+            let output = device_driver_tests::output_header(&test_case.path()) + &transformed;
             let output_name = format!("{}.rs", test_case.file_name().display());
             let output_path = test_case.path().join(output_name);
 
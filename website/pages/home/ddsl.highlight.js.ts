@@ -2,13 +2,13 @@ import { HLJSApi, Language } from "highlight.js";
 
 export function ddslLanguage(hljs: HLJSApi): Language {
     const nodeTypes = [
-        'manifest', 'device', 'register', 'command', 'buffer', 'block', 'extern', 'enum', 'field', 'fieldset'
+        'manifest', 'device', 'register', 'command', 'buffer', 'block', 'extern', 'enum', 'field', 'fieldset', 'template', 'const', 'interface', 'routine', 'states', 'state', 'transition', 'allowed', 'composite', 'part', 'memory', 'fifo', 'index'
     ];
     const KEYWORDS = [
-        'default', 'catch-all', 'allow', 'as', 'stride', 'try'
+        'default', 'catch-all', 'allow', 'as', 'stride', 'at', 'try'
     ];
     const typeKeywords = [
-        'bool', 'int', 'uint', 'u8', 'u16', 'u32', 'u64', 'i8', 'i16', 'i32', 'i64', 'LE', 'BE', '_', 'RO', 'RW', 'WO', 'mapped', 'indexed'
+        'bool', 'int', 'uint', 'bytes', 'ascii', 'bcd', 'gray', 'sign-magnitude', 'ones-complement', 'u8', 'u16', 'u32', 'u64', 'i8', 'i16', 'i32', 'i64', 'LE', 'BE', '_', 'RO', 'RW', 'WO', 'mapped', 'indexed'
     ];
 
     return {
@@ -35,7 +35,7 @@ export function ddslLanguage(hljs: HLJSApi): Language {
             },
             {
                 className: "punctuation",
-                begin: /(->)|\*|,/
+                begin: /(->)|(\.\.=)|(<<)|(>>)|[*+\-\/|&=()]|,/
             },
             {
                 className: 'title',
@@ -6,16 +6,19 @@ export const config: monaco.languages.LanguageConfiguration = {
   },
   brackets: [
     ['[', ']'],
-    ['{', '}']
+    ['{', '}'],
+    ['(', ')']
   ],
   surroundingPairs: [
     { open: '{', close: '}' },
     { open: '[', close: ']' },
+    { open: '(', close: ')' },
     { open: '"', close: '"' }
   ],
   autoClosingPairs: [
     { open: '{', close: '}' },
     { open: '[', close: ']' },
+    { open: '(', close: ')' },
     { open: '"', close: '"' }
   ]
 };
@@ -25,15 +28,15 @@ export const language: monaco.languages.IMonarchLanguage = {
   defaultToken: 'invalid',
 
   nodeTypes: [
-    'manifest', 'device', 'register', 'command', 'buffer', 'block', 'extern', 'enum', 'field', 'fieldset'
+    'manifest', 'device', 'register', 'command', 'buffer', 'block', 'extern', 'enum', 'field', 'fieldset', 'template', 'const', 'interface', 'routine', 'states', 'state', 'transition', 'allowed', 'composite', 'part', 'memory', 'fifo', 'index'
   ],
 
   keywords: [
-    'default', 'catch-all', 'allow', 'as', 'stride', 'try'
+    'default', 'catch-all', 'allow', 'as', 'stride', 'at', 'try'
   ],
 
   typeKeywords: [
-    'bool', 'int', 'uint', 'u8', 'u16', 'u32', 'u64', 'i8', 'i16', 'i32', 'i64', 'LE', 'BE', '_', 'RO', 'RW', 'WO', 'mapped', 'indexed'
+    'bool', 'int', 'uint', 'bytes', 'ascii', 'bcd', 'gray', 'sign-magnitude', 'ones-complement', 'u8', 'u16', 'u32', 'u64', 'i8', 'i16', 'i32', 'i64', 'LE', 'BE', '_', 'RO', 'RW', 'WO', 'mapped', 'indexed'
   ],
 
   // The main tokenizer for our languages
@@ -61,8 +64,10 @@ export const language: monaco.languages.IMonarchLanguage = {
       { include: '@whitespace' },
 
       // delimiters and operators
-      [/[{}\[\]]/, 'delimiter'],
-      [/[->*]/, 'operator'],
+      [/[{}\[\]()]/, 'delimiter'],
+      [/<<|>>/, 'operator'],
+      [/[->*+\/|&=]/, 'operator'],
+      [/\.\.=/, 'operator'],
 
 
       // delimiter: after number because of .\d floats
@@ -107,6 +107,9 @@ const DEFAULT_CODE = `device Tmp102 {
 }
 `
 const DEFAULT_OPTIONS = `--rust-defmt-feature=defmt`;
+/** The name of the file that's compiled. The other files can be included from it */
+const MAIN_FILE = "input.ddsl";
+const VALID_FILE_NAME = /^[\w\-]+(\/[\w\-]+)*\.ddsl$/;
 
 function setup(): PageContext {
     let draggingSetup = setupDragging();
@@ -134,10 +137,13 @@ function setup(): PageContext {
     darkThemeMq.addEventListener('change', onThemeChange);
     let theme = darkThemeMq.matches ? Theme.Dark : Theme.Light;
 
-    let editors = setup_monaco(startCode, theme);
+    let startFiles: Record<string, string> = JSON.parse(localStorage.getItem("files-session") ?? "{}");
+
+    let editors = setup_monaco(theme);
 
     let recompile = () => {
-        let source = (editors.codeEditor.getModel() ?? throwExpression("No code-editor model")).getValue();
+        let source = files.mainSource();
+        let extraFiles = files.extraFiles();
         let target = device_driver_wasm.TargetArg[targetPickerSelect.value as keyof typeof device_driver_wasm.TargetArg];
         if (target == undefined) {
             console.error("Got an undefined target_arg: " + targetPickerSelect.value);
@@ -147,11 +153,12 @@ function setup(): PageContext {
 
         let charsPerLine = elementCharWidth(diagnostics);
 
-        let output = compile(source, target, compilerOptionsInput.value, charsPerLine);
+        let output = compile(source, extraFiles, target, compilerOptionsInput.value, charsPerLine);
         (editors.outputEditor.getModel() ?? throwExpression("No output-editor model")).setValue(output.generated);
         diagnostics.innerHTML = output.diagnostics;
 
         localStorage.setItem("code-session", source);
+        localStorage.setItem("files-session", JSON.stringify(Object.fromEntries(extraFiles)));
         localStorage.setItem("target", targetPickerSelect.value);
         localStorage.setItem("compile-options", compilerOptionsInput.value);
 
@@ -165,7 +172,8 @@ function setup(): PageContext {
         compilerOptionsInput.addEventListener(event, recompile);
     });
 
-    (editors.codeEditor.getModel() ?? throwExpression("No code-editor model")).onDidChangeContent(recompile);
+    const fileTabs = document.getElementById('file-tabs') ?? throwExpression("No file-tabs");
+    let files = new VirtualFiles(editors.codeEditor, fileTabs, startCode, startFiles, recompile);
     let reset_timeout: any = null;
     const ro = new ResizeObserver(_ => {
         if (reset_timeout != null) {
@@ -184,6 +192,7 @@ function setup(): PageContext {
 
     return {
         editors,
+        files,
         compilerOptionsInput,
         targetPickerSelect,
         currentTheme: theme,
@@ -191,16 +200,16 @@ function setup(): PageContext {
     };
 }
 
-function setup_monaco(start_code: string, theme: Theme): Editors {
+function setup_monaco(theme: Theme): Editors {
     monaco.languages.register({ id: 'ddsl' })
     monaco.languages.onLanguage('ddsl', () => {
         monaco.languages.setMonarchTokensProvider('ddsl', DDSLMonarch.language);
         monaco.languages.setLanguageConfiguration('ddsl', DDSLMonarch.config);
     });
 
+    // The models of the files are set by the `VirtualFiles`
     let codeEditor = monaco.editor.create(document.getElementById('code-editor') ?? throwExpression("No code-editor"), {
-        value: start_code,
-        language: 'ddsl',
+        model: null,
         theme: monacoThemeString(theme),
         automaticLayout: true,
     });
@@ -224,14 +233,153 @@ type Editors = {
     outputEditor: monaco.editor.IStandaloneCodeEditor,
 };
 
-function compile(source: string, target: device_driver_wasm.TargetArg, options: string, diagnosticsCharsPerLine: number): CompileOutput {
+/** The main file and the extra files it can include. Every file has its own model and tab in the code editor */
+class VirtualFiles {
+    private files: Map<string, monaco.editor.ITextModel> = new Map();
+    private active: string = MAIN_FILE;
+
+    constructor(
+        private editor: monaco.editor.IStandaloneCodeEditor,
+        private tabs: HTMLElement,
+        mainSource: string,
+        extraFiles: Record<string, string>,
+        private onChange: () => void,
+    ) {
+        this.addModel(MAIN_FILE, mainSource);
+        for (const [name, source] of Object.entries(extraFiles)) {
+            if (name != MAIN_FILE && VALID_FILE_NAME.test(name)) {
+                this.addModel(name, source);
+            }
+        }
+        this.open(MAIN_FILE);
+    }
+
+    mainSource(): string {
+        return this.model(MAIN_FILE).getValue();
+    }
+
+    /** All files except the main file, with their source */
+    extraFiles(): [string, string][] {
+        return [...this.files.entries()]
+            .filter(([name, _]) => name != MAIN_FILE)
+            .map(([name, model]): [string, string] => [name, model.getValue()]);
+    }
+
+    open(name: string) {
+        if (!this.files.has(name)) {
+            return;
+        }
+
+        this.active = name;
+        this.editor.setModel(this.model(name));
+        this.render();
+    }
+
+    /** Ask for a name and add an empty file with it */
+    add() {
+        const name = prompt(`Name of the new file. It can be included with \`include: "<name>"\``, "registers.ddsl");
+        if (name == null) {
+            return;
+        }
+        if (!VALID_FILE_NAME.test(name)) {
+            alert(`\`${name}\` is not a valid file name. Use letters, numbers, '-', '_' and '/' and end it with '.ddsl'`);
+            return;
+        }
+        if (this.files.has(name)) {
+            alert(`The file \`${name}\` already exists`);
+            return;
+        }
+
+        this.addModel(name, "");
+        this.open(name);
+        this.onChange();
+    }
+
+    remove(name: string) {
+        if (name == MAIN_FILE || !confirm(`Remove the file \`${name}\`?`)) {
+            return;
+        }
+
+        this.model(name).dispose();
+        this.files.delete(name);
+        if (this.active == name) {
+            this.open(MAIN_FILE);
+        } else {
+            this.render();
+        }
+        this.onChange();
+    }
+
+    /** Remove all extra files and set the source of the main file */
+    reset(mainSource: string) {
+        for (const [name, model] of this.files) {
+            if (name != MAIN_FILE) {
+                model.dispose();
+                this.files.delete(name);
+            }
+        }
+        this.open(MAIN_FILE);
+        this.model(MAIN_FILE).setValue(mainSource);
+    }
+
+    private model(name: string): monaco.editor.ITextModel {
+        return this.files.get(name) ?? throwExpression(`No model for file ${name}`);
+    }
+
+    private addModel(name: string, source: string) {
+        const model = monaco.editor.createModel(source, 'ddsl');
+        model.onDidChangeContent(this.onChange);
+        this.files.set(name, model);
+    }
+
+    private render() {
+        this.tabs.replaceChildren();
+
+        for (const name of this.files.keys()) {
+            const tab = document.createElement("a");
+            tab.className = name == this.active ? "file-tab active" : "file-tab";
+            tab.textContent = name;
+            tab.onclick = () => this.open(name);
+
+            if (name != MAIN_FILE) {
+                const close = document.createElement("span");
+                close.className = "close";
+                close.textContent = "×";
+                close.title = "Remove file";
+                close.onclick = (event) => {
+                    event.stopPropagation();
+                    this.remove(name);
+                };
+                tab.appendChild(close);
+            }
+
+            this.tabs.appendChild(tab);
+        }
+
+        const add = document.createElement("a");
+        add.className = "file-tab";
+        add.textContent = "+";
+        add.title = "Add a file that can be included";
+        add.onclick = () => this.add();
+        this.tabs.appendChild(add);
+    }
+}
+
+function compile(source: string, extraFiles: [string, string][], target: device_driver_wasm.TargetArg, options: string, diagnosticsCharsPerLine: number): CompileOutput {
+    // The extra files are only loaded by the compiler when they're included
+    let files = new device_driver_wasm.FileMap();
+    for (const [name, fileSource] of extraFiles) {
+        files.insert(name, fileSource);
+    }
     let output = device_driver_wasm
         .compile(
             source,
+            files,
             diagnosticsCharsPerLine,
             target,
             options
         );
+    files.free();
 
     let ansi_up = new AU.AnsiUp();
     let diagnostics = replace_paths_with_links(ansi_up.ansi_to_html(output.diagnostics));
@@ -267,12 +415,13 @@ function elementCharWidth(element: Element): number {
  * @returns {String}
  * */
 function replace_paths_with_links(diagnostics: string): string {
-    return diagnostics.replace(/\[.+.ddsl:\d+:\d+]/gm, (path_block) => { // For miette reports
-        let splits = path_block.replace("]", "").split(":");
-        return `<a href="javascript:Website.then((w) => w.scroll_to(${Number.parseInt(splits[1])}, ${Number.parseInt(splits[2])}))">${path_block}</a>`;
-    }).replace(/\w+.ddsl:\d+:\d+/gm, (path_block) => { // For annotate-snippets reports
+    // The file names only contain the characters of `VALID_FILE_NAME`, so they can be quoted as is
+    return diagnostics.replace(/\[[\w\-/]+\.ddsl:\d+:\d+]/gm, (path_block) => { // For miette reports
+        let splits = path_block.replace("[", "").replace("]", "").split(":");
+        return `<a href="javascript:Website.then((w) => w.scroll_to('${splits[0]}', ${Number.parseInt(splits[1])}, ${Number.parseInt(splits[2])}))">${path_block}</a>`;
+    }).replace(/[\w\-/]+\.ddsl:\d+:\d+/gm, (path_block) => { // For annotate-snippets reports
         let splits = path_block.split(":");
-        return `<a href="javascript:Website.then((w) => w.scroll_to(${Number.parseInt(splits[1])}, ${Number.parseInt(splits[2])}))">${path_block}</a>`;
+        return `<a href="javascript:Website.then((w) => w.scroll_to('${splits[0]}', ${Number.parseInt(splits[1])}, ${Number.parseInt(splits[2])}))">${path_block}</a>`;
     });
 }
 
@@ -351,6 +500,7 @@ let page_ctx = setup();
 
 interface PageContext {
     editors: Editors,
+    files: VirtualFiles,
     targetPickerSelect: HTMLSelectElement,
     compilerOptionsInput: HTMLTextAreaElement,
     currentTheme: Theme,
@@ -370,14 +520,15 @@ function monacoThemeString(theme: Theme): string {
     }
 }
 
-export function scroll_to(line: number, column: number) {
+export function scroll_to(file: string, line: number, column: number) {
+    page_ctx.files.open(file);
     page_ctx.editors.codeEditor.setPosition({ lineNumber: line, column: column });
     page_ctx.editors.codeEditor.revealPositionInCenterIfOutsideViewport({ lineNumber: line, column: column });
     page_ctx.editors.codeEditor.focus();
 }
 
 export function reset() {
-    page_ctx.editors.codeEditor.setValue(DEFAULT_CODE);
+    page_ctx.files.reset(DEFAULT_CODE);
     page_ctx.targetPickerSelect.selectedIndex = 0;
     page_ctx.compilerOptionsInput.value = DEFAULT_OPTIONS;
     page_ctx.recompile();